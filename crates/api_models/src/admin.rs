@@ -457,6 +457,25 @@ pub struct PrimaryBusinessDetails {
 pub struct PaymentLinkConfig {
     pub merchant_logo: Option<String>,
     pub color_scheme: Option<PaymentLinkColorSchema>,
+    /// Name of the merchant displayed on the payment link
+    #[schema(max_length = 64, example = "Ekart Retail")]
+    pub display_name: Option<String>,
+    /// Font family used on the payment link
+    #[schema(example = "Montserrat, sans-serif")]
+    pub font_family: Option<String>,
+    /// Custom CSS applied to the payment link
+    pub custom_css: Option<String>,
+    /// Contact details of the merchant support displayed on the payment link
+    pub support_contact: Option<PaymentLinkSupportContact>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkSupportContact {
+    #[schema(example = "support@ekart.com")]
+    pub email: Option<String>,
+    #[schema(example = "+1 555 0100")]
+    pub phone: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize, PartialEq)]
@@ -604,6 +623,10 @@ pub struct MerchantConnectorCreate {
     pub profile_id: Option<String>,
 
     pub pm_auth_config: Option<serde_json::Value>,
+
+    /// Country and currency restrictions for payment method types and card networks of this
+    /// connector, taking precedence over the default restrictions configured for the connector
+    pub pm_filters: Option<Vec<PaymentMethodFilter>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -709,6 +732,10 @@ pub struct MerchantConnectorResponse {
     pub applepay_verified_domains: Option<Vec<String>>,
 
     pub pm_auth_config: Option<serde_json::Value>,
+
+    /// Country and currency restrictions for payment method types and card networks of this
+    /// connector, taking precedence over the default restrictions configured for the connector
+    pub pm_filters: Option<Vec<PaymentMethodFilter>>,
 }
 
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
@@ -783,6 +810,10 @@ pub struct MerchantConnectorUpdate {
     pub connector_webhook_details: Option<MerchantConnectorWebhookDetails>,
 
     pub pm_auth_config: Option<serde_json::Value>,
+
+    /// Country and currency restrictions for payment method types and card networks of this
+    /// connector, taking precedence over the default restrictions configured for the connector
+    pub pm_filters: Option<Vec<PaymentMethodFilter>>,
 }
 
 ///Details of FrmConfigs are mentioned here... it should be passed in payment connector create api call, and stored in merchant_connector_table
@@ -824,6 +855,26 @@ pub struct FrmPaymentMethodType {
     #[schema(value_type = FrmAction)]
     pub action: api_enums::FrmAction,
 }
+
+/// Country and currency restrictions for a payment method type, or for a card network in case of
+/// card payments. Exactly one of `payment_method_type` and `card_network` must be given.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodFilter {
+    /// The payment method type the restrictions apply to
+    #[schema(value_type = Option<PaymentMethodType>, example = "klarna")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The card network the restrictions apply to
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<api_enums::CardNetwork>,
+    /// The billing countries the payment method is available in, all countries if not given
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US", "GB"]))]
+    pub countries: Option<Vec<api_enums::CountryAlpha2>>,
+    /// The currencies the payment method is available in, all currencies if not given
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["USD", "GBP"]))]
+    pub currencies: Option<Vec<api_enums::Currency>>,
+}
+
 /// Details of all the payment methods enabled for the connector for the given merchant account
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -890,6 +941,19 @@ pub struct ToggleKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantKeyRotationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Version of the key used to encrypt the data of the merchant from now on
+    #[schema(example = 2)]
+    pub key_version: i32,
+    /// Version of the key which is retired once the data of the merchant has been re-encrypted
+    #[schema(example = 1)]
+    pub previous_key_version: i32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleKVRequest {
     #[serde(skip_deserializing)]
@@ -996,6 +1060,9 @@ pub struct BusinessProfileCreate {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Branding of the payment links of the profile, takes precedence over the payment link config of the merchant account
+    pub payment_link_config: Option<PaymentLinkConfig>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -1060,6 +1127,9 @@ pub struct BusinessProfileResponse {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Branding of the payment links of the profile, takes precedence over the payment link config of the merchant account
+    pub payment_link_config: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1117,4 +1187,7 @@ pub struct BusinessProfileUpdate {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Branding of the payment links of the profile, takes precedence over the payment link config of the merchant account
+    pub payment_link_config: Option<PaymentLinkConfig>,
 }
@@ -4,12 +4,17 @@ use common_utils::events::ApiEventMetric;
 use time::PrimitiveDateTime;
 
 use self::{
+    connector_performance::{ConnectorPerformanceDimensions, ConnectorPerformanceMetrics},
+    disputes::{DisputeDimensions, DisputeMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
 };
 
+pub mod connector_performance;
+pub mod disputes;
 pub mod payments;
 pub mod refunds;
+pub mod reports;
 
 #[derive(Debug, serde::Serialize)]
 pub struct NameDescription {
@@ -89,6 +94,38 @@ pub struct GetRefundMetricRequest {
 
 impl ApiEventMetric for GetRefundMetricRequest {}
 
+#[derive(Clone, Debug, serde::Deserialize, masking::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDisputeMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<DisputeDimensions>,
+    #[serde(default)]
+    pub filters: disputes::DisputeFilters,
+    pub metrics: HashSet<DisputeMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
+impl ApiEventMetric for GetDisputeMetricRequest {}
+
+#[derive(Clone, Debug, serde::Deserialize, masking::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorPerformanceMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<ConnectorPerformanceDimensions>,
+    #[serde(default)]
+    pub filters: connector_performance::ConnectorPerformanceFilters,
+    pub metrics: HashSet<ConnectorPerformanceMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
+impl ApiEventMetric for GetConnectorPerformanceMetricRequest {}
+
 #[derive(Debug, serde::Serialize)]
 pub struct AnalyticsMetadata {
     pub current_time_range: TimeRange,
@@ -144,6 +181,56 @@ pub struct RefundFilterValue {
     pub values: Vec<String>,
 }
 
+#[derive(Debug, serde::Deserialize, masking::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDisputeFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<DisputeDimensions>,
+}
+
+impl ApiEventMetric for GetDisputeFilterRequest {}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeFiltersResponse {
+    pub query_data: Vec<DisputeFilterValue>,
+}
+
+impl ApiEventMetric for DisputeFiltersResponse {}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeFilterValue {
+    pub dimension: DisputeDimensions,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, serde::Deserialize, masking::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorPerformanceFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<ConnectorPerformanceDimensions>,
+}
+
+impl ApiEventMetric for GetConnectorPerformanceFilterRequest {}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorPerformanceFiltersResponse {
+    pub query_data: Vec<ConnectorPerformanceFilterValue>,
+}
+
+impl ApiEventMetric for ConnectorPerformanceFiltersResponse {}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorPerformanceFilterValue {
+    pub dimension: ConnectorPerformanceDimensions,
+    pub values: Vec<String>,
+}
+
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MetricsResponse<T> {
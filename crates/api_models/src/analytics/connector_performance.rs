@@ -0,0 +1,200 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use common_enums::enums::{AuthenticationType, Currency, PaymentMethod};
+use common_utils::events::ApiEventMetric;
+
+use super::{NameDescription, TimeRange};
+use crate::{analytics::MetricsResponse, enums::Connector};
+
+#[derive(Clone, Debug, Default, serde::Deserialize, masking::Serialize)]
+pub struct ConnectorPerformanceFilters {
+    #[serde(default)]
+    pub connector: Vec<Connector>,
+    #[serde(default)]
+    pub currency: Vec<Currency>,
+    #[serde(default)]
+    pub payment_method: Vec<PaymentMethod>,
+    #[serde(default)]
+    pub auth_type: Vec<AuthenticationType>,
+    #[serde(default)]
+    pub error_code: Vec<String>,
+    #[serde(default)]
+    pub gsm_decision: Vec<String>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorPerformanceDimensions {
+    Connector,
+    PaymentMethod,
+    Currency,
+    #[strum(serialize = "authentication_type")]
+    #[serde(rename = "authentication_type")]
+    AuthType,
+    ErrorCode,
+    /// The decision configured in the gateway status mapping for the error returned by the
+    /// connector
+    GsmDecision,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorPerformanceMetrics {
+    AttemptCount,
+    ConnectorLatencyP50,
+    ConnectorLatencyP95,
+    ErrorRate,
+    RetryRate,
+    StepUpRate,
+    ThreeDsChallengeRate,
+}
+
+pub mod metric_behaviour {
+    pub struct AttemptCount;
+    pub struct ConnectorLatencyP50;
+    pub struct ConnectorLatencyP95;
+    pub struct ErrorRate;
+    pub struct RetryRate;
+    pub struct StepUpRate;
+    pub struct ThreeDsChallengeRate;
+}
+
+impl From<ConnectorPerformanceMetrics> for NameDescription {
+    fn from(value: ConnectorPerformanceMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<ConnectorPerformanceDimensions> for NameDescription {
+    fn from(value: ConnectorPerformanceDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct ConnectorPerformanceMetricsBucketIdentifier {
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub currency: Option<Currency>,
+    #[serde(rename = "authentication_type")]
+    pub auth_type: Option<AuthenticationType>,
+    pub error_code: Option<String>,
+    pub gsm_decision: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for ConnectorPerformanceMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector.hash(state);
+        self.payment_method.hash(state);
+        self.currency.hash(state);
+        self.auth_type.map(|i| i.to_string()).hash(state);
+        self.error_code.hash(state);
+        self.gsm_decision.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for ConnectorPerformanceMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl ConnectorPerformanceMetricsBucketIdentifier {
+    pub fn new(
+        connector: Option<String>,
+        payment_method: Option<String>,
+        currency: Option<Currency>,
+        auth_type: Option<AuthenticationType>,
+        error_code: Option<String>,
+        gsm_decision: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector,
+            payment_method,
+            currency,
+            auth_type,
+            error_code,
+            gsm_decision,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorPerformanceMetricsBucketValue {
+    pub attempt_count: Option<u64>,
+    /// The median time taken by the connector to respond to authorization requests, in
+    /// milliseconds
+    pub connector_latency_p50: Option<f64>,
+    /// The 95th percentile of the time taken by the connector to respond to authorization
+    /// requests, in milliseconds
+    pub connector_latency_p95: Option<f64>,
+    /// The percentage of the attempts which failed with an error from the connector
+    pub error_rate: Option<f64>,
+    /// The percentage of the attempts which failed and were followed by another attempt for the
+    /// same payment
+    pub retry_rate: Option<f64>,
+    /// The percentage of the attempts which failed without 3DS and were followed by a 3DS attempt
+    /// for the same payment
+    pub step_up_rate: Option<f64>,
+    /// The percentage of the 3DS attempts for which the customer was challenged
+    pub three_ds_challenge_rate: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorPerformanceMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: ConnectorPerformanceMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: ConnectorPerformanceMetricsBucketIdentifier,
+}
+
+impl ApiEventMetric for ConnectorPerformanceMetricsBucketResponse {}
+impl ApiEventMetric for MetricsResponse<ConnectorPerformanceMetricsBucketResponse> {}
@@ -0,0 +1,168 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use common_enums::enums::{Currency, DisputeStage, DisputeStatus};
+use common_utils::events::ApiEventMetric;
+
+use super::{NameDescription, TimeRange};
+use crate::analytics::MetricsResponse;
+
+#[derive(Clone, Debug, Default, serde::Deserialize, masking::Serialize)]
+pub struct DisputeFilters {
+    #[serde(default)]
+    pub currency: Vec<Currency>,
+    #[serde(default)]
+    pub dispute_stage: Vec<DisputeStage>,
+    #[serde(default)]
+    pub dispute_status: Vec<DisputeStatus>,
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub connector_reason: Vec<String>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeDimensions {
+    Connector,
+    Currency,
+    DisputeStage,
+    DisputeStatus,
+    ConnectorReason,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeMetrics {
+    DisputeCount,
+    TotalAmountDisputed,
+    DisputeWinRate,
+}
+
+pub mod metric_behaviour {
+    pub struct DisputeCount;
+    pub struct TotalAmountDisputed;
+    pub struct DisputeWinRate;
+}
+
+impl From<DisputeMetrics> for NameDescription {
+    fn from(value: DisputeMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<DisputeDimensions> for NameDescription {
+    fn from(value: DisputeDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct DisputeMetricsBucketIdentifier {
+    pub connector: Option<String>,
+    pub currency: Option<String>,
+    pub dispute_stage: Option<DisputeStage>,
+    pub dispute_status: Option<DisputeStatus>,
+    pub connector_reason: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for DisputeMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector.hash(state);
+        self.currency.hash(state);
+        self.dispute_stage.hash(state);
+        self.dispute_status.hash(state);
+        self.connector_reason.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for DisputeMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl DisputeMetricsBucketIdentifier {
+    pub fn new(
+        connector: Option<String>,
+        currency: Option<String>,
+        dispute_stage: Option<DisputeStage>,
+        dispute_status: Option<DisputeStatus>,
+        connector_reason: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector,
+            currency,
+            dispute_stage,
+            dispute_status,
+            connector_reason,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DisputeMetricsBucketValue {
+    pub dispute_count: Option<u64>,
+    /// The total amount disputed, in the minor unit of the currency
+    pub total_amount_disputed: Option<u64>,
+    /// The percentage of the disputes decided in the merchant's favour, among the ones decided
+    pub dispute_win_rate: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DisputeMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: DisputeMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: DisputeMetricsBucketIdentifier,
+}
+
+impl ApiEventMetric for DisputeMetricsBucketResponse {}
+impl ApiEventMetric for MetricsResponse<DisputeMetricsBucketResponse> {}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use common_enums::enums::{ReportExportType, ReportFrequency};
+use common_utils::{events::ApiEventMetric, pii};
+use time::PrimitiveDateTime;
+
+use super::{
+    connector_performance::{
+        ConnectorPerformanceDimensions, ConnectorPerformanceFilters, ConnectorPerformanceMetrics,
+    },
+    disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics},
+    payments::{PaymentDimensions, PaymentFilters, PaymentMetrics},
+    refunds::{RefundDimensions, RefundFilters, RefundMetrics},
+    Granularity, TimeRange,
+};
+
+/// The analytics domain queried by a report, along with the metrics, dimensions and filters of
+/// the query
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "domain", rename_all = "snake_case")]
+pub enum ReportQuery {
+    #[serde(rename_all = "camelCase")]
+    Payments {
+        metrics: HashSet<PaymentMetrics>,
+        #[serde(default)]
+        group_by_names: Vec<PaymentDimensions>,
+        #[serde(default)]
+        filters: PaymentFilters,
+    },
+    #[serde(rename_all = "camelCase")]
+    Refunds {
+        metrics: HashSet<RefundMetrics>,
+        #[serde(default)]
+        group_by_names: Vec<RefundDimensions>,
+        #[serde(default)]
+        filters: RefundFilters,
+    },
+    #[serde(rename_all = "camelCase")]
+    Disputes {
+        metrics: HashSet<DisputeMetrics>,
+        #[serde(default)]
+        group_by_names: Vec<DisputeDimensions>,
+        #[serde(default)]
+        filters: DisputeFilters,
+    },
+    #[serde(rename_all = "camelCase")]
+    ConnectorPerformance {
+        metrics: HashSet<ConnectorPerformanceMetrics>,
+        #[serde(default)]
+        group_by_names: Vec<ConnectorPerformanceDimensions>,
+        #[serde(default)]
+        filters: ConnectorPerformanceFilters,
+    },
+}
+
+impl ReportQuery {
+    pub fn has_metrics(&self) -> bool {
+        match self {
+            Self::Payments { metrics, .. } => !metrics.is_empty(),
+            Self::Refunds { metrics, .. } => !metrics.is_empty(),
+            Self::Disputes { metrics, .. } => !metrics.is_empty(),
+            Self::ConnectorPerformance { metrics, .. } => !metrics.is_empty(),
+        }
+    }
+}
+
+/// The query run every time a report is generated
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportDefinition {
+    #[serde(flatten)]
+    pub query: ReportQuery,
+    /// Splits the metrics into time buckets of this size, the metrics are aggregated over the
+    /// whole time range of the report when not set
+    pub granularity: Option<Granularity>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportCreateRequest {
+    pub name: String,
+    #[serde(flatten)]
+    pub definition: ReportDefinition,
+    pub frequency: ReportFrequency,
+    /// The number of days of data included in each report, defaults to the period between two
+    /// runs of the report
+    pub lookback_days: Option<u16>,
+    /// The time of the first run of the report, defaults to one period from now
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub first_run_at: Option<PrimitiveDateTime>,
+    /// The generated reports are emailed to these recipients
+    pub email_recipients: Option<Vec<pii::Email>>,
+}
+
+impl ApiEventMetric for ReportCreateRequest {}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportResponse {
+    pub report_id: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub definition: ReportDefinition,
+    pub frequency: ReportFrequency,
+    pub lookback_days: Option<i32>,
+    pub email_recipients: Option<Vec<String>>,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub next_run_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for ReportResponse {}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReportId {
+    pub report_id: String,
+}
+
+impl ApiEventMetric for ReportId {}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReportExportId {
+    pub export_id: String,
+}
+
+impl ApiEventMetric for ReportExportId {}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentsExportRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub filters: PaymentFilters,
+}
+
+impl ApiEventMetric for PaymentsExportRequest {}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundsExportRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub filters: RefundFilters,
+}
+
+impl ApiEventMetric for RefundsExportRequest {}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportExportResponse {
+    pub export_id: String,
+    pub report_id: Option<String>,
+    pub export_type: ReportExportType,
+    /// The id of the CSV file, which can also be retrieved from the files API
+    pub file_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for ReportExportResponse {}
@@ -73,6 +73,10 @@ pub struct CustomerResponse {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The identifier of the payment method used by default for off session payments of the
+    /// customer
+    #[schema(max_length = 64, example = "card_rGK4Vi5iSW70MY7J2mIy")]
+    pub default_payment_method_id: Option<String>,
 }
 
 #[derive(Default, Clone, Debug, Deserialize, Serialize)]
@@ -43,6 +43,8 @@ pub struct DisputeResponse {
     /// Time at which dispute is received
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    /// Whether the evidence deadline of the dispute passed without any action being taken on it
+    pub is_deadline_missed: bool,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -5,6 +5,7 @@ pub mod payment;
 pub mod payouts;
 pub mod refund;
 pub mod routing;
+pub mod subscriptions;
 pub mod user;
 
 use common_utils::{
@@ -44,6 +45,7 @@ impl_misc_api_event_type!(
     RevokeApiKeyResponse,
     ToggleKVResponse,
     ToggleKVRequest,
+    MerchantKeyRotationResponse,
     MerchantAccountDeleteResponse,
     MerchantAccountUpdate,
     CardInfoResponse,
@@ -0,0 +1,65 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::subscriptions::{
+    SubscriptionCancelRequest, SubscriptionCreateRequest, SubscriptionId,
+    SubscriptionPlanCreateRequest, SubscriptionPlanId, SubscriptionPlanResponse,
+    SubscriptionResponse, SubscriptionUpdateRequest,
+};
+
+impl ApiEventMetric for SubscriptionPlanCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan)
+    }
+}
+
+impl ApiEventMetric for SubscriptionCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: None,
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: Some(self.subscription_id.clone()),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionCancelRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: Some(self.subscription_id.clone()),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: Some(self.subscription_id.clone()),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: Some(self.subscription_id.clone()),
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::user::{ConnectAccountRequest, ConnectAccountResponse};
+use crate::user::{ConnectAccountRequest, ConnectAccountResponse, VerifyEmailRequest};
 
 impl ApiEventMetric for ConnectAccountResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -12,3 +12,5 @@ impl ApiEventMetric for ConnectAccountResponse {
 }
 
 impl ApiEventMetric for ConnectAccountRequest {}
+
+impl ApiEventMetric for VerifyEmailRequest {}
@@ -21,6 +21,7 @@ pub mod payments;
 pub mod payouts;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
 pub mod user;
 pub mod verifications;
 pub mod webhooks;
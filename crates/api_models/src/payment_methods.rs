@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use cards::CardNumber;
+use cards::{CardExpirationMonth, CardExpirationYear, CardNumber};
 use common_utils::{
     consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH, crypto::OptionalEncryptableName, pii,
     types::Percentage,
@@ -75,6 +75,19 @@ pub struct PaymentMethodUpdate {
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// A label given by the customer to the saved card. Providing only the nick name and metadata
+    /// updates the payment method in place, without saving the card again
+    #[schema(value_type = Option<String>, example = "Work card")]
+    pub nick_name: Option<masking::Secret<String>>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerPaymentMethodUpdate {
+    /// A label given by the customer to the saved card
+    #[schema(value_type = String, example = "Work card")]
+    pub nick_name: masking::Secret<String>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -86,11 +99,11 @@ pub struct CardDetail {
 
     /// Card Expiry Month
     #[schema(value_type = String,example = "10")]
-    pub card_exp_month: masking::Secret<String>,
+    pub card_exp_month: CardExpirationMonth,
 
     /// Card Expiry Year
     #[schema(value_type = String,example = "25")]
-    pub card_exp_year: masking::Secret<String>,
+    pub card_exp_year: CardExpirationYear,
 
     /// Card Holder Name
     #[schema(value_type = String,example = "John Doe")]
@@ -101,7 +114,7 @@ pub struct CardDetail {
     pub nick_name: Option<masking::Secret<String>>,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864")]
@@ -811,6 +824,77 @@ pub struct CustomerPaymentMethod {
     /// Whether this payment method requires CVV to be collected
     #[schema(example = true)]
     pub requires_cvv: bool,
+
+    /// Whether the saved card has expired. Expired cards are kept up to date by the card account
+    /// updater when the issuer reports a reissued card
+    #[schema(example = false)]
+    pub is_expired: bool,
+
+    /// The unique identifier of the payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIy")]
+    pub payment_method_id: String,
+
+    /// Whether this is the default payment method of the customer, used for off session payments
+    /// made without a payment method
+    #[schema(example = false)]
+    pub is_default: bool,
+
+    /// A timestamp (ISO 8601 code) of the last successful payment made with the payment method
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<time::PrimitiveDateTime>,
+
+    /// Whether the payment method can be used for the payment, based on its currency and amount.
+    /// Only computed when the payment methods are listed for a payment, using its client secret
+    pub eligibility: Option<PaymentMethodEligibility>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodEligibility {
+    /// Whether the payment method can be used for the payment
+    #[schema(example = true)]
+    pub is_eligible: bool,
+
+    /// The reasons the payment method cannot be used for the payment, empty when it is eligible
+    pub ineligibility_reasons: Vec<PaymentMethodIneligibilityReason>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodIneligibilityReason {
+    /// The saved card has expired
+    Expired,
+    /// The payment method is not enabled on any connector for the payment
+    PaymentMethodNotEnabled,
+    /// The payment method does not accept the currency of the payment
+    CurrencyNotSupported,
+    /// The amount of the payment is outside the limits configured for the payment method
+    AmountNotSupported,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DefaultPaymentMethod {
+    pub customer_id: String,
+    pub payment_method_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CustomerDefaultPaymentMethodResponse {
+    /// The unique identifier of the customer.
+    #[schema(example = "cus_meowerunwiuwiwqw")]
+    pub customer_id: String,
+
+    /// The unique identifier of the default payment method of the customer
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIy")]
+    pub default_payment_method_id: Option<String>,
+
+    /// The type of payment method use for the payment.
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// This is a sub-category of payment method.
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
 }
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PaymentMethodId {
@@ -1,6 +1,6 @@
 use std::num::NonZeroI64;
 
-use cards::CardNumber;
+use cards::{CardExpirationMonth, CardExpirationYear, CardNumber, CardSecurityCode};
 use common_utils::{
     crypto,
     ext_traits::Encode,
@@ -655,7 +655,7 @@ pub struct OnlineMandate {
     pub user_agent: String,
 }
 
-#[derive(Default, Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct Card {
     /// The card number
     #[schema(value_type = String, example = "4242424242424242")]
@@ -663,19 +663,20 @@ pub struct Card {
 
     /// The card's expiry month
     #[schema(value_type = String, example = "24")]
-    pub card_exp_month: Secret<String>,
+    pub card_exp_month: CardExpirationMonth,
 
     /// The card's expiry year
     #[schema(value_type = String, example = "24")]
-    pub card_exp_year: Secret<String>,
+    pub card_exp_year: CardExpirationYear,
 
     /// The card holder's name
     #[schema(value_type = String, example = "John Test")]
     pub card_holder_name: Secret<String>,
 
-    /// The CVC number for the card
+    /// The CVC number for the card, required for a new card. Cards fetched from the locker do
+    /// not carry it
     #[schema(value_type = String, example = "242")]
-    pub card_cvc: Secret<String>,
+    pub card_cvc: Option<CardSecurityCode>,
 
     /// The name of the issuer of card
     #[schema(example = "chase")]
@@ -2157,6 +2158,10 @@ pub struct PaymentsResponse {
 
     /// Identifier of the connector ( merchant connector account ) which was chosen to make the payment
     pub merchant_connector_id: Option<String>,
+
+    /// Fingerprint of the card used for the payment. Payments made with the same card have the same fingerprint, which can be used to filter the payments list
+    #[auth_based]
+    pub card_fingerprint: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -2263,6 +2268,8 @@ pub struct PaymentListFilterConstraints {
     pub payment_method_type: Option<Vec<enums::PaymentMethodType>>,
     /// The list of authentication types to filter payments list
     pub authentication_type: Option<Vec<enums::AuthenticationType>>,
+    /// The card fingerprint to filter payments list, lists every payment made with the same card
+    pub card_fingerprint: Option<String>,
 }
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct PaymentListFilters {
@@ -3150,6 +3157,10 @@ pub struct PaymentLinkObject {
     pub merchant_custom_domain_name: Option<String>,
     /// Custom merchant name for payment link
     pub custom_merchant_name: Option<String>,
+    /// A reusable payment link creates a new payment for the same amount on every visit, instead of
+    /// being closed once the payment is completed
+    #[serde(default)]
+    pub is_reusable: bool,
 }
 
 #[derive(Default, Debug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
@@ -3178,6 +3189,7 @@ pub struct RetrievePaymentLinkResponse {
     pub last_modified_at: PrimitiveDateTime,
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub link_expiry: Option<PrimitiveDateTime>,
+    pub is_reusable: bool,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -3201,4 +3213,19 @@ pub struct PaymentLinkDetails {
     pub order_details: Option<Vec<OrderDetailsWithAmount>>,
     pub max_items_visible_after_collapse: i8,
     pub sdk_theme: Option<String>,
+    pub locale: String,
+    pub support_email: Option<String>,
+    pub support_phone: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentLinkStatusDetails {
+    pub payment_id: String,
+    pub merchant_name: String,
+    pub merchant_logo: String,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expiry: Option<PrimitiveDateTime>,
+    pub locale: String,
+    pub support_email: Option<String>,
+    pub support_phone: Option<String>,
 }
@@ -1,4 +1,4 @@
-use cards::CardNumber;
+use cards::{CardExpirationMonth, CardExpirationYear, CardNumber};
 use common_utils::{
     crypto,
     pii::{self, Email},
@@ -159,13 +159,7 @@ pub enum PayoutMethodData {
     Bank(Bank),
 }
 
-impl Default for PayoutMethodData {
-    fn default() -> Self {
-        Self::Card(Card::default())
-    }
-}
-
-#[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[derive(Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct Card {
     /// The card number
     #[schema(value_type = String, example = "4242424242424242")]
@@ -173,11 +167,11 @@ pub struct Card {
 
     /// The card's expiry month
     #[schema(value_type = String)]
-    pub expiry_month: Secret<String>,
+    pub expiry_month: CardExpirationMonth,
 
     /// The card's expiry year
     #[schema(value_type = String)]
-    pub expiry_year: Secret<String>,
+    pub expiry_year: CardExpirationYear,
 
     /// The card holder's name
     #[schema(value_type = String, example = "John Doe")]
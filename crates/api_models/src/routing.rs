@@ -1,15 +1,26 @@
 use std::fmt::Debug;
+#[cfg(feature = "ast_parser")]
+use std::str::FromStr;
 
 use common_utils::errors::ParsingError;
 use error_stack::IntoReport;
+#[cfg(feature = "ast_parser")]
+use euclid::frontend::ast::parser::{self, EuclidParsable, ParseResult};
 use euclid::{
     dssa::types::EuclidAnalysable,
     enums as euclid_enums,
     frontend::{
-        ast,
+        ast::{
+            self,
+            printer::{self, EuclidPrintable},
+        },
         dir::{DirKeyKind, EuclidDirFilter},
     },
 };
+#[cfg(feature = "ast_parser")]
+use nom::{
+    branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::enums::{self, RoutableConnectors};
@@ -37,7 +48,65 @@ pub struct RoutingConfigRequest {
     pub name: Option<String>,
     pub description: Option<String>,
     pub algorithm: Option<RoutingAlgorithm>,
+    /// An advanced routing program in the text form of the routing DSL, used when `algorithm`
+    /// is not given
+    pub program_text: Option<String>,
+    /// Test cases for advanced routing algorithms, which have to pass for the algorithm to be
+    /// created and activated
+    pub test_cases: Option<Vec<RoutingTestCase>>,
     pub profile_id: Option<String>,
+    /// Reject advanced routing programs having rules that can never be selected, such as
+    /// shadowed or duplicate rules, instead of returning them as warnings
+    pub strict_validation: Option<bool>,
+}
+
+/// A sample payment along with the routing decision expected for it
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingTestCase {
+    pub name: String,
+    pub input: euclid::backend::inputs::BackendInput,
+    /// The connector expected to be tried first. For volume splits, a connector that the traffic
+    /// is expected to be split across.
+    pub expected_connector: Option<RoutableConnectorChoice>,
+    /// The name of the rule expected to match the payment
+    pub expected_rule_name: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingTestCaseResult {
+    pub name: String,
+    pub passed: bool,
+    /// The rule that matched the payment, `None` if the default selection was used
+    pub rule_name: Option<String>,
+    /// The selected connectors that are eligible for the payment with the merchant's current
+    /// connector accounts
+    pub connectors: Vec<RoutableConnectorChoice>,
+    pub failure_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingTestReport {
+    pub algorithm_id: String,
+    pub passed: bool,
+    pub results: Vec<RoutingTestCaseResult>,
+}
+
+impl RoutingTestReport {
+    /// Describes every failed test case, for use in error messages.
+    pub fn get_failure_summary(&self) -> String {
+        self.results
+            .iter()
+            .filter(|result| !result.passed)
+            .map(|result| {
+                format!(
+                    "'{}': {}",
+                    result.name,
+                    result.failure_reason.as_deref().unwrap_or("failed")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -81,6 +150,9 @@ pub struct MerchantRoutingAlgorithm {
     pub name: String,
     pub description: String,
     pub algorithm: RoutingAlgorithm,
+    /// The canonical text form of the routing DSL for advanced routing algorithms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub program_text: Option<String>,
     pub created_at: i64,
     pub modified_at: i64,
 }
@@ -109,6 +181,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
+        DirKeyKind::CardFingerprint,
         DirKeyKind::MetaData,
         DirKeyKind::RewardType,
         DirKeyKind::VoucherType,
@@ -154,6 +227,122 @@ impl EuclidAnalysable for ConnectorSelection {
     }
 }
 
+/// Connector selections are written as `[stripe, adyen("label")]` for priority lists and as
+/// `[stripe: 60%, adyen("label"): 40%]` for volume splits, where the optional label is the
+/// merchant connector account id or the sub label of the connector.
+#[cfg(feature = "ast_parser")]
+impl EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        let split = error::context(
+            "volume_split",
+            combinator::map(
+                sequence::pair(
+                    connector_choice,
+                    sequence::preceded(
+                        parser::skip_ws(complete::tag(":")),
+                        parser::skip_ws(split_percentage),
+                    ),
+                ),
+                |(connector, split)| ConnectorVolumeSplit { connector, split },
+            ),
+        );
+
+        error::context(
+            "connector_selection",
+            branch::alt((
+                combinator::map(connector_list(split), Self::VolumeSplit),
+                combinator::map(connector_list(connector_choice), Self::Priority),
+            )),
+        )(input)
+    }
+}
+
+#[cfg(feature = "ast_parser")]
+fn connector_list<'a, F: 'a, O>(item: F) -> impl FnMut(&'a str) -> ParseResult<&'a str, Vec<O>>
+where
+    F: FnMut(&'a str) -> ParseResult<&'a str, O>,
+{
+    sequence::delimited(
+        parser::skip_ws(complete::tag("[")),
+        multi::separated_list1(parser::skip_ws(complete::tag(",")), item),
+        parser::skip_ws(complete::tag("]")),
+    )
+}
+
+#[cfg(feature = "ast_parser")]
+fn split_percentage(input: &str) -> ParseResult<&str, u8> {
+    error::context(
+        "volume_split_percentage",
+        sequence::terminated(
+            combinator::map_res(pchar::digit1, u8::from_str),
+            complete::tag("%"),
+        ),
+    )(input)
+}
+
+#[cfg(feature = "ast_parser")]
+fn connector_choice(input: &str) -> ParseResult<&str, RoutableConnectorChoice> {
+    let label = sequence::delimited(
+        parser::skip_ws(complete::tag("(")),
+        parser::skip_ws(parser::string_str),
+        parser::skip_ws(complete::tag(")")),
+    );
+
+    error::context(
+        "connector_choice",
+        combinator::map(
+            sequence::pair(
+                parser::skip_ws(combinator::map_res(
+                    parser::enum_value_string,
+                    |connector: String| RoutableConnectors::from_str(&connector),
+                )),
+                combinator::opt(label),
+            ),
+            |(connector, label)| RoutableConnectorChoice {
+                #[cfg(feature = "connector_choice_bcompat")]
+                choice_kind: RoutableChoiceKind::FullStruct,
+                connector,
+                #[cfg(feature = "connector_choice_mca_id")]
+                merchant_connector_id: label,
+                #[cfg(not(feature = "connector_choice_mca_id"))]
+                sub_label: label,
+            },
+        ),
+    )(input)
+}
+
+fn print_connector_choice(choice: &RoutableConnectorChoice) -> String {
+    #[cfg(feature = "connector_choice_mca_id")]
+    let label = choice.merchant_connector_id.as_ref();
+    #[cfg(not(feature = "connector_choice_mca_id"))]
+    let label = choice.sub_label.as_ref();
+
+    match label {
+        Some(label) => format!("{}({})", choice.connector, printer::quote(label)),
+        None => choice.connector.to_string(),
+    }
+}
+
+impl EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> String {
+        let choices: Vec<String> = match self {
+            Self::Priority(list) => list.iter().map(print_connector_choice).collect(),
+            Self::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| {
+                    format!(
+                        "{}: {}%",
+                        print_connector_choice(&split.connector),
+                        split.split
+                    )
+                })
+                .collect(),
+        };
+
+        format!("[{}]", choices.join(", "))
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ConnectorVolumeSplit {
     pub connector: RoutableConnectorChoice,
@@ -416,6 +605,22 @@ pub enum RoutingAlgorithm {
     Advanced(euclid::frontend::ast::Program<ConnectorSelection>),
 }
 
+impl RoutingAlgorithm {
+    /// Parses an advanced routing program from the text form of the routing DSL.
+    #[cfg(feature = "ast_parser")]
+    pub fn from_program_text(program_text: &str) -> Result<Self, parser::ParseDiagnostic> {
+        parser::parse_program(program_text).map(Self::Advanced)
+    }
+
+    /// Returns the canonical text form of advanced routing programs.
+    pub fn get_program_text(&self) -> Option<String> {
+        match self {
+            Self::Advanced(program) => Some(printer::program(program)),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithmSerde {
@@ -590,6 +795,10 @@ pub struct RoutingDictionaryRecord {
     pub description: String,
     pub created_at: i64,
     pub modified_at: i64,
+    /// Issues found in the routing program which did not prevent the config from being created,
+    /// such as rules that can never be selected
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -610,3 +819,31 @@ pub enum RoutingKind {
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[serde(transparent)]
 pub struct RoutingAlgorithmId(pub String);
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod program_text_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_program_text_round_trip() {
+        let program_text = r#"default: [stripe, adyen("eu")]
+
+card_split: [stripe: 70%, adyen("eu"): 30%]
+{
+    payment_method = card & amount > 1000
+}
+"#;
+
+        let algorithm = RoutingAlgorithm::from_program_text(program_text).unwrap();
+        assert!(matches!(
+            &algorithm,
+            RoutingAlgorithm::Advanced(program)
+                if matches!(
+                    program.rules.first().map(|rule| &rule.connector_selection),
+                    Some(ConnectorSelection::VolumeSplit(splits)) if splits.len() == 2
+                )
+        ));
+        assert_eq!(algorithm.get_program_text().unwrap(), program_text);
+    }
+}
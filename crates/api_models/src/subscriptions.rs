@@ -0,0 +1,158 @@
+use common_utils::pii;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanCreateRequest {
+    /// The name of the plan
+    #[schema(max_length = 64, example = "Premium Monthly")]
+    pub name: String,
+    /// A description of the plan
+    #[schema(max_length = 255, example = "Premium plan billed every month")]
+    pub description: Option<String>,
+    /// The amount to be charged for every billing cycle, in the lowest denomination of the currency
+    #[schema(example = 1999)]
+    pub amount: i64,
+    /// The currency in which the plan is billed
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The unit of time between two billing cycles
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+    /// The number of `interval`s between two billing cycles
+    #[schema(example = 1)]
+    pub interval_count: Option<u16>,
+    /// The number of days a customer is not charged for after subscribing to the plan
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionPlanResponse {
+    /// The identifier for the plan
+    #[schema(example = "plan_cfEtYzC5ucTgyd2GQxcS")]
+    pub plan_id: String,
+    /// The name of the plan
+    pub name: String,
+    /// A description of the plan
+    pub description: Option<String>,
+    /// The amount charged for every billing cycle, in the lowest denomination of the currency
+    pub amount: i64,
+    /// The currency in which the plan is billed
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// The unit of time between two billing cycles
+    #[schema(value_type = SubscriptionInterval)]
+    pub interval: api_enums::SubscriptionInterval,
+    /// The number of `interval`s between two billing cycles
+    pub interval_count: i32,
+    /// The number of days a customer is not charged for after subscribing to the plan
+    pub trial_period_days: Option<i32>,
+    /// Whether new subscriptions can be created for the plan
+    pub is_active: bool,
+    /// Metadata associated with the plan
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// The time at which the plan was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SubscriptionPlanId {
+    pub plan_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the customer subscribing to the plan
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The identifier for the plan the customer subscribes to
+    #[schema(example = "plan_cfEtYzC5ucTgyd2GQxcS")]
+    pub plan_id: String,
+    /// The identifier for an active multi use mandate of the customer, which is used to charge the customer for every billing cycle
+    #[schema(example = "man_jdkgfsdgkjdsfg")]
+    pub mandate_id: String,
+    /// The identifier for the business profile used to send the outgoing webhooks of the subscription
+    pub profile_id: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionUpdateRequest {
+    /// The identifier for the subscription
+    #[serde(skip)]
+    pub subscription_id: String,
+    /// The identifier for the plan the subscription is moved to. The unused part of the current billing period is credited, and the remaining part of the billing period on the new plan is charged, on the next billing cycle
+    #[schema(example = "plan_cfEtYzC5ucTgyd2GQxcS")]
+    pub plan_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCancelRequest {
+    /// The identifier for the subscription
+    #[serde(skip)]
+    pub subscription_id: String,
+    /// If true, the subscription stays active until the end of the current billing period, otherwise it is cancelled immediately
+    #[serde(default)]
+    pub at_period_end: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SubscriptionId {
+    pub subscription_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier for the subscription
+    #[schema(example = "sub_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub subscription_id: String,
+    /// The identifier for the customer
+    pub customer_id: String,
+    /// The identifier for the plan
+    pub plan_id: String,
+    /// The identifier for the mandate used to charge the customer
+    pub mandate_id: String,
+    /// The status of the subscription
+    #[schema(value_type = SubscriptionStatus)]
+    pub status: api_enums::SubscriptionStatus,
+    /// The start of the current billing period
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+    /// The end of the current billing period, at which the customer is charged for the next billing period
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+    /// The end of the trial period, if the plan has one
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    /// Whether the subscription is cancelled at the end of the current billing period
+    pub cancel_at_period_end: bool,
+    /// The time at which the subscription was cancelled
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    /// The amount added to (or deducted from, if negative) the next billing cycle due to plan changes
+    pub proration_amount: i64,
+    /// The identifier for the payment made for the latest billing cycle
+    pub latest_payment_id: Option<String>,
+    /// Metadata associated with the subscription
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// The time at which the subscription was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
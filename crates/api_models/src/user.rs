@@ -19,3 +19,8 @@ pub struct ConnectAccountResponse {
     #[serde(skip_serializing)]
     pub user_id: String,
 }
+
+#[derive(serde::Deserialize, Debug, Clone, serde::Serialize)]
+pub struct VerifyEmailRequest {
+    pub token: Secret<String>,
+}
@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{
+    disputes, enums as api_enums, mandates, payment_methods, payments, refunds, subscriptions,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -152,6 +154,15 @@ pub struct OutgoingWebhook {
 
     /// The time at which webhook was sent
     pub timestamp: PrimitiveDateTime,
+
+    /// The state of the object before the event, for webhook formats reporting the attributes
+    /// changed by the event
+    #[serde(skip)]
+    pub previous_content: Option<OutgoingWebhookContent>,
+
+    /// Whether the event happened for a merchant processing live payments
+    #[serde(skip)]
+    pub livemode: bool,
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -165,6 +176,10 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse)]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[schema(value_type = SubscriptionResponse)]
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
+    #[schema(value_type = PaymentMethodResponse)]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -0,0 +1,4 @@
+fn main() {
+    #[cfg(feature = "vergen")]
+    router_env::vergen::generate_cargo_instructions();
+}
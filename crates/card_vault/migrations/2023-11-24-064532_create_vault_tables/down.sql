@@ -0,0 +1,5 @@
+DROP TABLE vault_audit_log;
+
+DROP TABLE locker;
+
+DROP TABLE merchant_key;
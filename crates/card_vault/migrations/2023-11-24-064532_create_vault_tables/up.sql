@@ -0,0 +1,29 @@
+-- Data encryption keys of every merchant, encrypted with the vault master key
+CREATE TABLE merchant_key (
+    merchant_id VARCHAR(255) PRIMARY KEY,
+    enc_key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+-- Card data encrypted with the data encryption key of the merchant
+CREATE TABLE locker (
+    card_reference VARCHAR(255) PRIMARY KEY,
+    merchant_id VARCHAR(255) NOT NULL,
+    customer_id VARCHAR(255) NOT NULL,
+    enc_data BYTEA NOT NULL,
+    hash_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX locker_merchant_id_customer_id_hash_id_index ON locker (merchant_id, customer_id, hash_id);
+
+CREATE TABLE vault_audit_log (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(255) NOT NULL,
+    customer_id VARCHAR(255) NOT NULL,
+    card_reference VARCHAR(255) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX vault_audit_log_merchant_id_card_reference_index ON vault_audit_log (merchant_id, card_reference);
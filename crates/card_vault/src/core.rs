@@ -0,0 +1,219 @@
+use diesel_models::{errors::DatabaseError, PgPooledConn};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    crypto,
+    errors::{self, ApiResult},
+    routes::AppState,
+    storage::{
+        audit_log::{AuditAction, VaultAuditLogNew},
+        locker::{Locker, LockerNew, LockerUpdate},
+        merchant_key::{MerchantKey, MerchantKeyNew},
+    },
+    types,
+};
+
+#[instrument(skip_all)]
+pub async fn add_card(
+    state: &AppState,
+    request: types::StoreRequest,
+) -> ApiResult<types::StoreCardResponse> {
+    let conn = state.store.get_conn().await?;
+    let (merchant_id, customer_id, data) = request.into_parts();
+
+    let key = get_or_create_merchant_key(state, &conn, &merchant_id).await?;
+    let serialized_data = serde_json::to_vec(&data)
+        .into_report()
+        .change_context(errors::ApiError::CryptoError)
+        .attach_printable("Failed to serialize the data to be stored")?;
+    let hash_id = crypto::hash_data(key.peek(), &data.dedup_key(&serialized_data))?;
+    let data = serialized_data;
+
+    let existing = Locker::find_optional_by_hash_id(&conn, &merchant_id, &customer_id, &hash_id)
+        .await
+        .change_context(errors::ApiError::DatabaseError)?;
+
+    let (card_reference, duplicate) = match existing {
+        // The card may be stored again with a new expiry, name or nickname, which replace the
+        // stored details
+        Some(locker) => {
+            let locker = Locker::update_by_card_reference(
+                &conn,
+                &locker.card_reference,
+                LockerUpdate {
+                    enc_data: crypto::encrypt_data(key.peek(), &data)?,
+                },
+            )
+            .await
+            .change_context(errors::ApiError::DatabaseError)?;
+            (locker.card_reference, true)
+        }
+        None => {
+            let new = LockerNew {
+                card_reference: uuid::Uuid::new_v4().to_string(),
+                merchant_id: merchant_id.clone(),
+                customer_id: customer_id.clone(),
+                enc_data: crypto::encrypt_data(key.peek(), &data)?,
+                hash_id: hash_id.clone(),
+            };
+            match new.insert(&conn).await {
+                Ok(locker) => (locker.card_reference, false),
+                // The same data was stored by a concurrent request
+                Err(error) if matches!(error.current_context(), DatabaseError::UniqueViolation) => {
+                    let locker = Locker::find_optional_by_hash_id(
+                        &conn,
+                        &merchant_id,
+                        &customer_id,
+                        &hash_id,
+                    )
+                    .await
+                    .change_context(errors::ApiError::DatabaseError)?
+                    .ok_or(errors::ApiError::DatabaseError)
+                    .into_report()
+                    .attach_printable("Duplicate entry not found after unique violation")?;
+                    (locker.card_reference, true)
+                }
+                Err(error) => return Err(error.change_context(errors::ApiError::DatabaseError)),
+            }
+        }
+    };
+
+    let action = if duplicate {
+        AuditAction::AddDuplicate
+    } else {
+        AuditAction::Add
+    };
+    audit(&conn, &merchant_id, &customer_id, &card_reference, action).await?;
+
+    Ok(types::StoreCardResponse {
+        card_reference,
+        duplicate: Some(duplicate),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_card(
+    state: &AppState,
+    request: types::CardReferenceRequest,
+) -> ApiResult<types::RetrieveCardResponse> {
+    let conn = state.store.get_conn().await?;
+
+    let locker = Locker::find_by_card_reference(
+        &conn,
+        &request.merchant_id,
+        &request.merchant_customer_id,
+        &request.card_reference,
+    )
+    .await
+    .map_err(|error| match error.current_context() {
+        DatabaseError::NotFound => error.change_context(errors::ApiError::CardNotFound),
+        _ => error.change_context(errors::ApiError::DatabaseError),
+    })?;
+
+    let key = get_merchant_key(state, &conn, &request.merchant_id)
+        .await?
+        .ok_or(errors::ApiError::CryptoError)
+        .into_report()
+        .attach_printable("Data key of the merchant not found")?;
+    let data = crypto::decrypt_data(key.peek(), locker.enc_data)?;
+    let data: types::StoredData = serde_json::from_slice(&data)
+        .into_report()
+        .change_context(errors::ApiError::CryptoError)
+        .attach_printable("Failed to deserialize the stored data")?;
+
+    audit(
+        &conn,
+        &request.merchant_id,
+        &request.merchant_customer_id,
+        &request.card_reference,
+        AuditAction::Retrieve,
+    )
+    .await?;
+
+    Ok(data.into())
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card(state: &AppState, request: types::CardReferenceRequest) -> ApiResult<()> {
+    let conn = state.store.get_conn().await?;
+
+    Locker::delete_by_card_reference(
+        &conn,
+        &request.merchant_id,
+        &request.merchant_customer_id,
+        &request.card_reference,
+    )
+    .await
+    .map_err(|error| match error.current_context() {
+        DatabaseError::NotFound => error.change_context(errors::ApiError::CardNotFound),
+        _ => error.change_context(errors::ApiError::DatabaseError),
+    })?;
+
+    audit(
+        &conn,
+        &request.merchant_id,
+        &request.merchant_customer_id,
+        &request.card_reference,
+        AuditAction::Delete,
+    )
+    .await
+}
+
+async fn audit(
+    conn: &PgPooledConn,
+    merchant_id: &str,
+    customer_id: &str,
+    card_reference: &str,
+    action: AuditAction,
+) -> ApiResult<()> {
+    VaultAuditLogNew::new(merchant_id, customer_id, card_reference, action)
+        .insert(conn)
+        .await
+        .change_context(errors::ApiError::DatabaseError)
+        .attach_printable("Failed to write the audit log")?;
+    Ok(())
+}
+
+async fn get_merchant_key(
+    state: &AppState,
+    conn: &PgPooledConn,
+    merchant_id: &str,
+) -> ApiResult<Option<Secret<Vec<u8>>>> {
+    MerchantKey::find_optional_by_merchant_id(conn, merchant_id)
+        .await
+        .change_context(errors::ApiError::DatabaseError)?
+        .map(|merchant_key| {
+            crypto::decrypt_data(state.master_key.peek(), merchant_key.enc_key).map(Secret::new)
+        })
+        .transpose()
+}
+
+async fn get_or_create_merchant_key(
+    state: &AppState,
+    conn: &PgPooledConn,
+    merchant_id: &str,
+) -> ApiResult<Secret<Vec<u8>>> {
+    if let Some(key) = get_merchant_key(state, conn, merchant_id).await? {
+        return Ok(key);
+    }
+
+    let key = crypto::generate_data_key();
+    let new = MerchantKeyNew {
+        merchant_id: merchant_id.to_owned(),
+        enc_key: crypto::encrypt_data(state.master_key.peek(), key.peek())?,
+    };
+    match new.insert(conn).await {
+        Ok(_) => Ok(key),
+        // The key was created by a concurrent request
+        Err(error) if matches!(error.current_context(), DatabaseError::UniqueViolation) => {
+            get_merchant_key(state, conn, merchant_id)
+                .await?
+                .ok_or(errors::ApiError::DatabaseError)
+                .into_report()
+                .attach_printable("Data key of the merchant not found after unique violation")
+        }
+        Err(error) => Err(error.change_context(errors::ApiError::DatabaseError)),
+    }
+}
@@ -0,0 +1,81 @@
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use thiserror::Error;
+
+use crate::types;
+
+#[derive(Debug, Error)]
+pub enum VaultError {
+    #[error("Error in parsing config : {0}")]
+    ConfigParsingError(String),
+    #[error("Application configuration error: {0}")]
+    ConfigurationError(config::ConfigError),
+    #[error("I/O: {0}")]
+    IoError(std::io::Error),
+}
+
+pub type VaultResult<T> = error_stack::Result<T, VaultError>;
+
+impl From<config::ConfigError> for VaultError {
+    fn from(err: config::ConfigError) -> Self {
+        Self::ConfigurationError(err)
+    }
+}
+
+impl From<std::io::Error> for VaultError {
+    fn from(err: std::io::Error) -> Self {
+        Self::IoError(err)
+    }
+}
+
+/// Errors returned to the tenant by the vault endpoints. These are sent as plain JSON, since a
+/// request that could not be decrypted has no verified tenant to encrypt the response for.
+#[derive(Clone, Debug, Error)]
+pub enum ApiError {
+    #[error("Failed to decrypt or verify the request")]
+    RequestDecryptionFailed,
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("Card not found")]
+    CardNotFound,
+    #[error("Failed to encrypt the response")]
+    ResponseEncryptionFailed,
+    #[error("Failed while encrypting or decrypting vault data")]
+    CryptoError,
+    #[error("Failed while executing a database operation")]
+    DatabaseError,
+}
+
+pub type ApiResult<T> = error_stack::Result<T, ApiError>;
+
+impl ApiError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::RequestDecryptionFailed => "VE_01",
+            Self::InvalidRequest(_) => "VE_02",
+            Self::CardNotFound => "VE_03",
+            Self::ResponseEncryptionFailed => "VE_04",
+            Self::CryptoError => "VE_05",
+            Self::DatabaseError => "VE_06",
+        }
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::RequestDecryptionFailed => StatusCode::UNAUTHORIZED,
+            Self::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+            Self::CardNotFound => StatusCode::NOT_FOUND,
+            Self::ResponseEncryptionFailed | Self::CryptoError | Self::DatabaseError => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(types::VaultResponse::<()>::error(
+            self.error_code(),
+            self.to_string(),
+        ))
+    }
+}
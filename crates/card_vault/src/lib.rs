@@ -0,0 +1,32 @@
+pub mod core;
+pub mod crypto;
+pub mod errors;
+pub mod logger;
+pub mod routes;
+pub mod settings;
+pub mod storage;
+pub mod types;
+
+use actix_web::{dev::Server, web};
+
+use crate::routes::AppState;
+
+pub async fn start_server(conf: settings::Settings) -> errors::VaultResult<Server> {
+    logger::debug!(startup_config=?conf);
+    let server = conf.server.clone();
+    let state = AppState::new(conf).await?;
+    let request_body_limit = server.request_body_limit;
+    let server = actix_web::HttpServer::new(move || {
+        actix_web::App::new()
+            .app_data(web::JsonConfig::default().limit(request_body_limit))
+            .service(routes::Health::server(state.clone()))
+            .service(routes::Vault::server(state.clone(), "/data"))
+            .service(routes::Vault::server(state.clone(), "/cards"))
+    })
+    .bind((server.host.as_str(), server.port))
+    .map_err(errors::VaultError::from)?
+    .workers(server.workers)
+    .shutdown_timeout(server.shutdown_timeout)
+    .run();
+    Ok(server)
+}
@@ -0,0 +1,36 @@
+use card_vault::{
+    errors::{VaultError, VaultResult},
+    logger, settings,
+};
+
+#[tokio::main]
+async fn main() -> VaultResult<()> {
+    let cmd_line = <settings::CmdLineConf as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = settings::Settings::with_config_path(cmd_line.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate card vault configuration");
+
+    let _guard = router_env::setup(
+        &conf.log,
+        router_env::service_name!(),
+        [router_env::service_name!(), "actix_server"],
+    );
+
+    logger::info!("Card vault started [{:?}] [{:?}]", conf.server, conf.log);
+
+    #[allow(clippy::expect_used)]
+    let server = Box::pin(card_vault::start_server(conf))
+        .await
+        .expect("Failed to create the server");
+    let _ = server.await;
+
+    Err(VaultError::from(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        "Server shut down",
+    ))
+    .into())
+}
@@ -0,0 +1,146 @@
+use std::future::Future;
+
+use actix_web::{web, HttpResponse, Responder, Scope};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    core, crypto,
+    errors::{self, ApiResult, VaultResult},
+    settings, storage, types,
+};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub store: storage::Store,
+    pub conf: std::sync::Arc<settings::Settings>,
+    pub master_key: Secret<Vec<u8>>,
+}
+
+impl AppState {
+    pub async fn new(conf: settings::Settings) -> VaultResult<Self> {
+        let master_key = hex::decode(conf.secrets.master_key.peek())
+            .into_report()
+            .change_context(errors::VaultError::ConfigParsingError(
+                "master key must be hex encoded".into(),
+            ))?;
+        let store = storage::Store::new(&conf.database).await;
+
+        Ok(Self {
+            store,
+            conf: std::sync::Arc::new(conf),
+            master_key: Secret::new(master_key),
+        })
+    }
+}
+
+pub struct Health;
+
+impl Health {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/health").route(web::get().to(health)))
+    }
+}
+
+/// Locker endpoints. These are served both under `/data`, and under `/cards`, which is where the
+/// router sends card requests.
+pub struct Vault;
+
+impl Vault {
+    pub fn server(state: AppState, path: &str) -> Scope {
+        web::scope(path)
+            .app_data(web::Data::new(state))
+            .service(web::resource("/add").route(web::post().to(add_card)))
+            .service(web::resource("/retrieve").route(web::post().to(retrieve_card)))
+            .service(web::resource("/delete").route(web::post().to(delete_card)))
+    }
+}
+
+pub async fn health() -> impl Responder {
+    logger::info!("Health was called");
+    HttpResponse::Ok().body("health is good")
+}
+
+#[instrument(skip_all)]
+pub async fn add_card(
+    state: web::Data<AppState>,
+    body: web::Json<crypto::JweBody>,
+) -> Result<web::Json<crypto::JweBody>, errors::ApiError> {
+    server_wrap(state, body.into_inner(), |state, request| async move {
+        core::add_card(&state, request).await.map(Some)
+    })
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_card(
+    state: web::Data<AppState>,
+    body: web::Json<crypto::JweBody>,
+) -> Result<web::Json<crypto::JweBody>, errors::ApiError> {
+    server_wrap(state, body.into_inner(), |state, request| async move {
+        core::retrieve_card(&state, request).await.map(Some)
+    })
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card(
+    state: web::Data<AppState>,
+    body: web::Json<crypto::JweBody>,
+) -> Result<web::Json<crypto::JweBody>, errors::ApiError> {
+    server_wrap(state, body.into_inner(), |state, request| async move {
+        core::delete_card(&state, request)
+            .await
+            .map(|()| None::<()>)
+    })
+    .await
+}
+
+/// Decrypts and verifies the request, runs `func` on it, and signs and encrypts the response.
+async fn server_wrap<Req, Resp, F, Fut>(
+    state: web::Data<AppState>,
+    body: crypto::JweBody,
+    func: F,
+) -> Result<web::Json<crypto::JweBody>, errors::ApiError>
+where
+    Req: DeserializeOwned,
+    Resp: Serialize,
+    F: FnOnce(web::Data<AppState>, Req) -> Fut,
+    Fut: Future<Output = ApiResult<Option<Resp>>>,
+{
+    let result = async {
+        let secrets = &state.conf.secrets;
+        let request = crypto::decrypt_request(
+            &body,
+            secrets.locker_private_key.peek(),
+            secrets.tenant_public_key.peek(),
+        )?;
+        let request: Req = serde_json::from_slice(&request)
+            .into_report()
+            .map_err(|error| {
+                let message = error.current_context().to_string();
+                error.change_context(errors::ApiError::InvalidRequest(message))
+            })?;
+
+        let payload = func(state.clone(), request).await?;
+        let response = serde_json::to_vec(&types::VaultResponse::ok(payload))
+            .into_report()
+            .change_context(errors::ApiError::ResponseEncryptionFailed)?;
+
+        crypto::encrypt_response(
+            &response,
+            secrets.locker_private_key.peek(),
+            secrets.tenant_public_key.peek(),
+        )
+    }
+    .await;
+
+    result.map(web::Json).map_err(|error| {
+        logger::error!(?error);
+        error.current_context().clone()
+    })
+}
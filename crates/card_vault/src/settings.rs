@@ -0,0 +1,211 @@
+use std::path::PathBuf;
+
+use common_utils::ext_traits::ConfigExt;
+use config::{Environment, File};
+use masking::{PeekInterface, Secret};
+pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
+use router_env::{env, logger};
+use serde::Deserialize;
+
+use crate::errors;
+
+#[derive(clap::Parser, Default)]
+#[cfg_attr(feature = "vergen", command(version = router_env::version!()))]
+pub struct CmdLineConf {
+    /// Config file.
+    /// Application will look for "config/card_vault.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    pub config_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Settings {
+    pub server: Server,
+    pub database: Database,
+    pub secrets: Secrets,
+    pub log: Log,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Server {
+    pub port: u16,
+    pub workers: usize,
+    pub host: String,
+    pub request_body_limit: usize,
+    pub shutdown_timeout: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Database {
+    pub username: String,
+    pub password: Secret<String>,
+    pub host: String,
+    pub port: u16,
+    pub dbname: String,
+    pub pool_size: u32,
+    pub connection_timeout: u64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Secrets {
+    /// Hex encoded 256 bit key that encrypts the data keys of the merchants.
+    pub master_key: Secret<String>,
+    /// PEM encoded RSA private key of the vault. Requests are encrypted and responses are signed
+    /// with the matching public key, configured as `jwekey.vault_encryption_key` in the router.
+    pub locker_private_key: Secret<String>,
+    /// PEM encoded RSA public key of the tenant (the router), used to verify request signatures
+    /// and to encrypt responses.
+    pub tenant_public_key: Secret<String>,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self {
+            port: 8090,
+            workers: num_cpus::get_physical(),
+            host: "localhost".into(),
+            request_body_limit: 16 * 1024, // POST request body is limited to 16KiB
+            shutdown_timeout: 30,
+        }
+    }
+}
+
+impl Default for Database {
+    fn default() -> Self {
+        Self {
+            username: String::new(),
+            password: Secret::default(),
+            host: "localhost".into(),
+            port: 5432,
+            dbname: String::new(),
+            pool_size: 5,
+            connection_timeout: 10,
+        }
+    }
+}
+
+impl Server {
+    fn validate(&self) -> Result<(), errors::VaultError> {
+        common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "server host must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl Database {
+    fn validate(&self) -> Result<(), errors::VaultError> {
+        use common_utils::fp_utils::when;
+
+        when(self.host.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "database host must not be empty".into(),
+            ))
+        })?;
+
+        when(self.dbname.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "database name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.username.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "database user username must not be empty".into(),
+            ))
+        })?;
+
+        when(self.password.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "database user password must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl Secrets {
+    fn validate(&self) -> Result<(), errors::VaultError> {
+        use common_utils::fp_utils::when;
+
+        when(self.master_key.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "master key must not be empty".into(),
+            ))
+        })?;
+
+        let master_key = hex::decode(self.master_key.peek()).map_err(|_| {
+            errors::VaultError::ConfigParsingError("master key must be hex encoded".into())
+        })?;
+        when(master_key.len() != 32, || {
+            Err(errors::VaultError::ConfigParsingError(
+                "master key must be 256 bits long".into(),
+            ))
+        })?;
+
+        when(self.locker_private_key.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "locker private key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.tenant_public_key.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "tenant public key must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl Settings {
+    pub fn new() -> Result<Self, errors::VaultError> {
+        Self::with_config_path(None)
+    }
+
+    pub fn with_config_path(config_path: Option<PathBuf>) -> Result<Self, errors::VaultError> {
+        // Configuration values are picked up in the following priority order (1 being least
+        // priority):
+        // 1. Defaults from the implementation of the `Default` trait.
+        // 2. Values from the config file, `config/card_vault.toml` unless a path is specified.
+        // 3. Environment variables prefixed with `CARD_VAULT` and each level separated by double
+        //    underscores.
+        //
+        // Values in config file override the defaults in `Default` trait, and the values set using
+        // environment variables override both the defaults and the config file values.
+
+        let environment = env::which();
+        let config_path = config_path.unwrap_or_else(|| {
+            let mut config_path = env::workspace_path();
+            config_path.push("config");
+            config_path.push("card_vault.toml");
+            config_path
+        });
+
+        let config = router_env::Config::builder(&environment.to_string())?
+            .add_source(File::from(config_path).required(false))
+            .add_source(
+                Environment::with_prefix("CARD_VAULT")
+                    .try_parsing(true)
+                    .separator("__"),
+            )
+            .build()?;
+
+        serde_path_to_error::deserialize(config).map_err(|error| {
+            logger::error!(%error, "Unable to deserialize application configuration");
+            eprintln!("Unable to deserialize application configuration: {error}");
+            errors::VaultError::from(error.into_inner())
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), errors::VaultError> {
+        self.server.validate()?;
+        self.database.validate()?;
+        self.secrets.validate()?;
+
+        Ok(())
+    }
+}
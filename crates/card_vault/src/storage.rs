@@ -0,0 +1,58 @@
+pub mod audit_log;
+pub mod locker;
+pub mod merchant_key;
+pub mod schema;
+
+use bb8::PooledConnection;
+use diesel::PgConnection;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+
+use crate::{
+    errors::{self, ApiResult},
+    settings::Database,
+};
+
+pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
+
+#[derive(Clone)]
+pub struct Store {
+    pub master_pool: PgPool,
+}
+
+impl Store {
+    /// # Panics
+    ///
+    /// Will panic if could not create a db pool
+    #[allow(clippy::expect_used)]
+    pub async fn new(database: &Database) -> Self {
+        let database_url = format!(
+            "postgres://{}:{}@{}:{}/{}",
+            database.username,
+            database.password.peek(),
+            database.host,
+            database.port,
+            database.dbname
+        );
+        let manager = async_bb8_diesel::ConnectionManager::<PgConnection>::new(database_url);
+        let master_pool = bb8::Pool::builder()
+            .max_size(database.pool_size)
+            .connection_timeout(std::time::Duration::from_secs(database.connection_timeout))
+            .build(manager)
+            .await
+            .expect("Failed to create PostgreSQL connection pool");
+
+        Self { master_pool }
+    }
+
+    pub async fn get_conn(
+        &self,
+    ) -> ApiResult<PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>> {
+        self.master_pool
+            .get()
+            .await
+            .into_report()
+            .change_context(errors::ApiError::DatabaseError)
+            .attach_printable("Failed to get a PostgreSQL connection from the pool")
+    }
+}
@@ -0,0 +1,57 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use diesel_models::{query::generics, PgPooledConn, StorageResult};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::schema::vault_audit_log;
+
+/// Operations recorded in the audit log.
+#[derive(Clone, Copy, Debug, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    Add,
+    AddDuplicate,
+    Retrieve,
+    Delete,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = vault_audit_log)]
+pub struct VaultAuditLog {
+    pub id: i32,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_reference: String,
+    pub action: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = vault_audit_log)]
+pub struct VaultAuditLogNew {
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_reference: String,
+    pub action: String,
+}
+
+impl VaultAuditLogNew {
+    pub fn new(
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+        action: AuditAction,
+    ) -> Self {
+        Self {
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.to_owned(),
+            card_reference: card_reference.to_owned(),
+            action: action.to_string(),
+        }
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultAuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
@@ -0,0 +1,119 @@
+use diesel::{
+    associations::HasTable, AsChangeset, BoolExpressionMethods, ExpressionMethods, Identifiable,
+    Insertable, Queryable,
+};
+use diesel_models::{query::generics, PgPooledConn, StorageResult};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::schema::locker::{self, dsl};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = locker)]
+#[diesel(primary_key(card_reference))]
+pub struct Locker {
+    pub card_reference: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    /// Stored data, encrypted with the data key of the merchant.
+    pub enc_data: Vec<u8>,
+    /// Keyed hash identifying the stored data, unique per customer. Cards are identified by their
+    /// card number only, so that storing the same card again finds the existing entry.
+    pub hash_id: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = locker)]
+pub struct LockerNew {
+    pub card_reference: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub enc_data: Vec<u8>,
+    pub hash_id: String,
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = locker)]
+pub struct LockerUpdate {
+    pub enc_data: Vec<u8>,
+}
+
+impl LockerNew {
+    #[instrument(skip_all)]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Locker> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Locker {
+    #[instrument(skip(conn))]
+    pub async fn find_by_card_reference(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::card_reference.eq(card_reference.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_hash_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        hash_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::hash_id.eq(hash_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn, locker_update))]
+    pub async fn update_by_card_reference(
+        conn: &PgPooledConn,
+        card_reference: &str,
+        locker_update: LockerUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::card_reference.eq(card_reference.to_owned()),
+            locker_update,
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_card_reference(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::card_reference.eq(card_reference.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,44 @@
+use diesel::{associations::HasTable, ExpressionMethods, Identifiable, Insertable, Queryable};
+use diesel_models::{query::generics, PgPooledConn, StorageResult};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::schema::merchant_key::{self, dsl};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = merchant_key)]
+#[diesel(primary_key(merchant_id))]
+pub struct MerchantKey {
+    pub merchant_id: String,
+    /// Data key of the merchant, encrypted with the vault master key.
+    pub enc_key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = merchant_key)]
+pub struct MerchantKeyNew {
+    pub merchant_id: String,
+    pub enc_key: Vec<u8>,
+}
+
+impl MerchantKeyNew {
+    #[instrument(skip_all)]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MerchantKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl MerchantKey {
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+        )
+        .await
+    }
+}
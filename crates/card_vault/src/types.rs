@@ -0,0 +1,191 @@
+//! Request and response payloads of the locker protocol. These mirror the types the router
+//! sends and expects in `core::payment_methods::transformers`, and are exchanged signed and
+//! encrypted.
+
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum StoreRequest {
+    Card(StoreCardRequest),
+    Generic(StoreGenericRequest),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreCardRequest {
+    pub merchant_id: String,
+    pub merchant_customer_id: String,
+    pub card: Card,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreGenericRequest {
+    pub merchant_id: String,
+    pub merchant_customer_id: String,
+    pub enc_card_data: Secret<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Card {
+    pub card_number: cards::CardNumber,
+    pub name_on_card: Option<Secret<String>>,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+    pub card_brand: Option<String>,
+    pub card_isin: Option<String>,
+    pub nick_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CardReferenceRequest {
+    pub merchant_id: String,
+    pub merchant_customer_id: String,
+    pub card_reference: String,
+}
+
+/// Data held in the vault, stored encrypted with the data key of the merchant.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StoredData {
+    Card(Card),
+    EncCardData(Secret<String>),
+}
+
+impl StoredData {
+    /// Bytes identifying the stored data for deduplication. A card is identified by its card number
+    /// alone, so that storing it again with new details finds the existing entry.
+    pub fn dedup_key(&self, serialized_data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Card(card) => card.card_number.peek().as_bytes().to_vec(),
+            Self::EncCardData(_) => serialized_data.to_vec(),
+        }
+    }
+}
+
+impl StoreRequest {
+    pub fn into_parts(self) -> (String, String, StoredData) {
+        match self {
+            Self::Card(request) => (
+                request.merchant_id,
+                request.merchant_customer_id,
+                StoredData::Card(request.card),
+            ),
+            Self::Generic(request) => (
+                request.merchant_id,
+                request.merchant_customer_id,
+                StoredData::EncCardData(request.enc_card_data),
+            ),
+        }
+    }
+}
+
+/// Status of a response, serialized as the status strings the router expects from the locker.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ResponseStatus {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultResponse<T> {
+    pub status: ResponseStatus,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<T>,
+}
+
+impl<T> VaultResponse<T> {
+    pub fn ok(payload: Option<T>) -> Self {
+        Self {
+            status: ResponseStatus::Success,
+            error_message: None,
+            error_code: None,
+            payload,
+        }
+    }
+
+    pub fn error(error_code: &str, error_message: String) -> Self {
+        Self {
+            status: ResponseStatus::Failure,
+            error_message: Some(error_message),
+            error_code: Some(error_code.to_owned()),
+            payload: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct StoreCardResponse {
+    pub card_reference: String,
+    pub duplicate: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RetrieveCardResponse {
+    pub card: Option<Card>,
+    pub enc_card_data: Option<Secret<String>>,
+}
+
+impl From<StoredData> for RetrieveCardResponse {
+    fn from(data: StoredData) -> Self {
+        match data {
+            StoredData::Card(card) => Self {
+                card: Some(card),
+                enc_card_data: None,
+            },
+            StoredData::EncCardData(enc_card_data) => Self {
+                card: None,
+                enc_card_data: Some(enc_card_data),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn card(card_exp_year: &str, nick_name: Option<&str>) -> StoredData {
+        StoredData::Card(Card {
+            card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            name_on_card: None,
+            card_exp_month: Secret::new("10".to_string()),
+            card_exp_year: Secret::new(card_exp_year.to_string()),
+            card_brand: None,
+            card_isin: None,
+            nick_name: nick_name.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn test_card_dedup_key_is_the_card_number() {
+        let stored_card = card("2030", None);
+        let updated_card = card("2031", Some("Travel card"));
+        assert_eq!(
+            stored_card.dedup_key(&serde_json::to_vec(&stored_card).unwrap()),
+            updated_card.dedup_key(&serde_json::to_vec(&updated_card).unwrap())
+        );
+
+        let generic = StoredData::EncCardData(Secret::new("abcd".to_string()));
+        let serialized_generic = serde_json::to_vec(&generic).unwrap();
+        assert_eq!(generic.dedup_key(&serialized_generic), serialized_generic);
+    }
+
+    #[test]
+    fn test_response_status_uses_locker_status_strings() {
+        let response = serde_json::to_value(VaultResponse::<()>::ok(None)).unwrap();
+        assert_eq!(response["status"], "SUCCESS");
+
+        let response =
+            serde_json::to_value(VaultResponse::<()>::error("VE_03", "Card not found".into()))
+                .unwrap();
+        assert_eq!(response["status"], "FAILURE");
+    }
+}
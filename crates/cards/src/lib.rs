@@ -1,22 +1,39 @@
+pub mod network;
 pub mod validate;
-use std::ops::Deref;
+use std::{ops::Deref, str::FromStr};
 
 use common_utils::{date_time, errors};
 use error_stack::report;
-use masking::{PeekInterface, StrongSecret};
+use masking::{PeekInterface, Secret, StrongSecret};
 use serde::{de, Deserialize, Serialize};
 use time::{util::days_in_year_month, Date, Duration, PrimitiveDateTime, Time};
 
 pub use crate::validate::{CCValError, CardNumber, CardNumberStrategy};
 
-#[derive(Serialize)]
-pub struct CardSecurityCode(StrongSecret<u16>);
+/// Card security code of three or four digits.
+///
+/// The number of digits is kept along with the code, so that codes with leading zeros are passed
+/// on as received.
+#[derive(Clone, Debug)]
+pub struct CardSecurityCode {
+    code: StrongSecret<u16>,
+    digits: usize,
+}
+
+impl CardSecurityCode {
+    pub fn number_of_digits(&self) -> usize {
+        self.digits
+    }
+}
 
 impl TryFrom<u16> for CardSecurityCode {
     type Error = error_stack::Report<errors::ValidationError>;
     fn try_from(csc: u16) -> Result<Self, Self::Error> {
-        if (0..=9999).contains(&csc) {
-            Ok(Self(StrongSecret::new(csc)))
+        if (100..=9999).contains(&csc) {
+            Ok(Self {
+                code: StrongSecret::new(csc),
+                digits: csc.to_string().len(),
+            })
         } else {
             Err(report!(errors::ValidationError::InvalidValue {
                 message: "invalid card security code".to_string()
@@ -25,17 +42,64 @@ impl TryFrom<u16> for CardSecurityCode {
     }
 }
 
+impl FromStr for CardSecurityCode {
+    type Err = error_stack::Report<errors::ValidationError>;
+    /// Unlike [`CardSecurityCode::try_from`], this accepts codes with leading zeros since their
+    /// number of digits is known.
+    fn from_str(csc: &str) -> Result<Self, Self::Err> {
+        if !(3..=4).contains(&csc.len()) || !csc.chars().all(|c| c.is_ascii_digit()) {
+            return Err(report!(errors::ValidationError::InvalidValue {
+                message: "invalid card security code".to_string()
+            }));
+        }
+        csc.parse::<u16>()
+            .map(|code| Self {
+                code: StrongSecret::new(code),
+                digits: csc.len(),
+            })
+            .map_err(|_| {
+                report!(errors::ValidationError::InvalidValue {
+                    message: "invalid card security code".to_string()
+                })
+            })
+    }
+}
+
+impl Serialize for CardSecurityCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let code = *self.code.peek();
+        // a number would lose the leading zeros
+        if code.to_string().len() == self.digits {
+            serializer.serialize_u16(code)
+        } else {
+            serializer.serialize_str(&format!("{code:0width$}", width = self.digits))
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for CardSecurityCode {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let csc = u16::deserialize(deserializer)?;
-        csc.try_into().map_err(de::Error::custom)
+        match NumberOrString::<u16>::deserialize(deserializer)? {
+            NumberOrString::Number(csc) => csc.try_into(),
+            NumberOrString::String(csc) => csc.parse(),
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
+impl From<CardSecurityCode> for Secret<String> {
+    fn from(csc: CardSecurityCode) -> Self {
+        Self::new(format!("{:0width$}", csc.code.peek(), width = csc.digits))
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct CardExpirationMonth(StrongSecret<u8>);
 
 impl CardExpirationMonth {
@@ -57,17 +121,45 @@ impl TryFrom<u8> for CardExpirationMonth {
     }
 }
 
+impl FromStr for CardExpirationMonth {
+    type Err = error_stack::Report<errors::ValidationError>;
+    fn from_str(month: &str) -> Result<Self, Self::Err> {
+        if !(1..=2).contains(&month.len()) || !month.chars().all(|c| c.is_ascii_digit()) {
+            return Err(report!(errors::ValidationError::InvalidValue {
+                message: "invalid card expiration month".to_string()
+            }));
+        }
+        month
+            .parse::<u8>()
+            .map_err(|_| {
+                report!(errors::ValidationError::InvalidValue {
+                    message: "invalid card expiration month".to_string()
+                })
+            })
+            .and_then(Self::try_from)
+    }
+}
+
 impl<'de> Deserialize<'de> for CardExpirationMonth {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let month = u8::deserialize(deserializer)?;
-        month.try_into().map_err(de::Error::custom)
+        match NumberOrString::<u8>::deserialize(deserializer)? {
+            NumberOrString::Number(month) => month.try_into(),
+            NumberOrString::String(month) => month.parse(),
+        }
+        .map_err(de::Error::custom)
     }
 }
 
-#[derive(Serialize)]
+impl From<CardExpirationMonth> for Secret<String> {
+    fn from(month: CardExpirationMonth) -> Self {
+        Self::new(month.two_digits())
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
 pub struct CardExpirationYear(StrongSecret<u16>);
 
 impl CardExpirationYear {
@@ -77,7 +169,7 @@ impl CardExpirationYear {
 
     pub fn two_digits(&self) -> String {
         let year = self.peek() % 100;
-        year.to_string()
+        format!("{year:02}")
     }
 }
 
@@ -100,17 +192,60 @@ impl TryFrom<u16> for CardExpirationYear {
     }
 }
 
+impl FromStr for CardExpirationYear {
+    type Err = error_stack::Report<errors::ValidationError>;
+    /// Accepts years of either two or four digits, two digit years are taken to be in this
+    /// century.
+    fn from_str(year: &str) -> Result<Self, Self::Err> {
+        if !matches!(year.len(), 2 | 4) || !year.chars().all(|c| c.is_ascii_digit()) {
+            return Err(report!(errors::ValidationError::InvalidValue {
+                message: "invalid card expiration year".to_string()
+            }));
+        }
+        let four_digit_year = if year.len() == 2 {
+            format!("20{year}")
+        } else {
+            year.to_string()
+        };
+        four_digit_year
+            .parse::<u16>()
+            .map_err(|_| {
+                report!(errors::ValidationError::InvalidValue {
+                    message: "invalid card expiration year".to_string()
+                })
+            })
+            .and_then(Self::try_from)
+    }
+}
+
 impl<'de> Deserialize<'de> for CardExpirationYear {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let year = u16::deserialize(deserializer)?;
-        year.try_into().map_err(de::Error::custom)
+        match NumberOrString::<u16>::deserialize(deserializer)? {
+            NumberOrString::Number(year) => year.try_into(),
+            NumberOrString::String(year) => year.parse(),
+        }
+        .map_err(de::Error::custom)
     }
 }
 
-#[derive(Serialize, Deserialize)]
+impl From<CardExpirationYear> for Secret<String> {
+    fn from(year: CardExpirationYear) -> Self {
+        Self::new(year.four_digits())
+    }
+}
+
+/// Card details are received as strings through the API, and as numbers when serialized by us
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString<T> {
+    Number(T),
+    String(String),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CardExpiration {
     pub month: CardExpirationMonth,
     pub year: CardExpirationYear,
@@ -171,7 +306,7 @@ impl TryFrom<(u8, u16)> for CardExpiration {
 impl Deref for CardSecurityCode {
     type Target = StrongSecret<u16>;
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.code
     }
 }
 
@@ -188,3 +323,27 @@ impl Deref for CardExpirationYear {
         &self.0
     }
 }
+
+impl PartialEq for CardSecurityCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.code.peek() == other.code.peek() && self.digits == other.digits
+    }
+}
+
+impl Eq for CardSecurityCode {}
+
+impl PartialEq for CardExpirationMonth {
+    fn eq(&self, other: &Self) -> bool {
+        self.peek() == other.peek()
+    }
+}
+
+impl Eq for CardExpirationMonth {}
+
+impl PartialEq for CardExpirationYear {
+    fn eq(&self, other: &Self) -> bool {
+        self.peek() == other.peek()
+    }
+}
+
+impl Eq for CardExpirationYear {}
@@ -0,0 +1,322 @@
+use common_enums::CardNetwork;
+
+/// An issuer identification number range, expressed over the first six digits of a card number.
+///
+/// The first network in `networks` is the scheme that owns the range, the remaining ones are
+/// co-badged networks that are also able to process cards issued from it.
+struct IinRange {
+    start: u32,
+    end: u32,
+    networks: &'static [CardNetwork],
+}
+
+const IIN_LENGTH: usize = 6;
+
+// Ranges are matched in order, so narrower ranges (and co-badged ranges) must be listed before the
+// broader ranges they overlap with.
+const IIN_RANGES: &[IinRange] = &[
+    // RuPay
+    IinRange {
+        start: 508_500,
+        end: 508_999,
+        networks: &[CardNetwork::RuPay],
+    },
+    IinRange {
+        start: 606_985,
+        end: 607_984,
+        networks: &[CardNetwork::RuPay],
+    },
+    IinRange {
+        start: 608_001,
+        end: 608_500,
+        networks: &[CardNetwork::RuPay],
+    },
+    IinRange {
+        start: 652_150,
+        end: 653_149,
+        networks: &[CardNetwork::RuPay, CardNetwork::Discover],
+    },
+    // UnionPay
+    IinRange {
+        start: 622_126,
+        end: 622_925,
+        networks: &[CardNetwork::UnionPay, CardNetwork::Discover],
+    },
+    IinRange {
+        start: 620_000,
+        end: 629_999,
+        networks: &[CardNetwork::UnionPay],
+    },
+    IinRange {
+        start: 810_000,
+        end: 817_199,
+        networks: &[CardNetwork::UnionPay],
+    },
+    // Discover
+    IinRange {
+        start: 601_100,
+        end: 601_199,
+        networks: &[CardNetwork::Discover],
+    },
+    IinRange {
+        start: 644_000,
+        end: 659_999,
+        networks: &[CardNetwork::Discover],
+    },
+    // American Express
+    IinRange {
+        start: 340_000,
+        end: 349_999,
+        networks: &[CardNetwork::AmericanExpress],
+    },
+    IinRange {
+        start: 370_000,
+        end: 379_999,
+        networks: &[CardNetwork::AmericanExpress],
+    },
+    // Diners Club
+    IinRange {
+        start: 300_000,
+        end: 305_999,
+        networks: &[CardNetwork::DinersClub],
+    },
+    IinRange {
+        start: 309_500,
+        end: 309_599,
+        networks: &[CardNetwork::DinersClub],
+    },
+    IinRange {
+        start: 360_000,
+        end: 369_999,
+        networks: &[CardNetwork::DinersClub],
+    },
+    IinRange {
+        start: 380_000,
+        end: 399_999,
+        networks: &[CardNetwork::DinersClub],
+    },
+    // JCB
+    IinRange {
+        start: 352_800,
+        end: 358_999,
+        networks: &[CardNetwork::JCB],
+    },
+    // Visa
+    IinRange {
+        start: 497_000,
+        end: 497_999,
+        networks: &[CardNetwork::Visa, CardNetwork::CartesBancaires],
+    },
+    IinRange {
+        start: 400_000,
+        end: 499_999,
+        networks: &[CardNetwork::Visa],
+    },
+    // Mastercard
+    IinRange {
+        start: 222_100,
+        end: 272_099,
+        networks: &[CardNetwork::Mastercard],
+    },
+    IinRange {
+        start: 510_000,
+        end: 559_999,
+        networks: &[CardNetwork::Mastercard],
+    },
+    // Maestro
+    IinRange {
+        start: 501_800,
+        end: 501_899,
+        networks: &[CardNetwork::Maestro],
+    },
+    IinRange {
+        start: 502_000,
+        end: 502_099,
+        networks: &[CardNetwork::Maestro],
+    },
+    IinRange {
+        start: 503_800,
+        end: 503_899,
+        networks: &[CardNetwork::Maestro],
+    },
+    IinRange {
+        start: 589_300,
+        end: 589_399,
+        networks: &[CardNetwork::Maestro],
+    },
+    IinRange {
+        start: 630_400,
+        end: 630_499,
+        networks: &[CardNetwork::Maestro],
+    },
+    IinRange {
+        start: 675_900,
+        end: 675_999,
+        networks: &[CardNetwork::Maestro],
+    },
+    IinRange {
+        start: 676_100,
+        end: 676_399,
+        networks: &[CardNetwork::Maestro],
+    },
+];
+
+/// Card number and security code lengths allowed by a card network
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CardNetworkRules {
+    pub card_number_lengths: &'static [usize],
+    pub cvc_length: usize,
+}
+
+impl CardNetworkRules {
+    pub fn is_valid_card_number_length(&self, length: usize) -> bool {
+        self.card_number_lengths.contains(&length)
+    }
+
+    pub fn is_valid_cvc_length(&self, length: usize) -> bool {
+        self.cvc_length == length
+    }
+}
+
+pub fn get_network_rules(network: &CardNetwork) -> CardNetworkRules {
+    match network {
+        CardNetwork::Visa => CardNetworkRules {
+            card_number_lengths: &[13, 16, 19],
+            cvc_length: 3,
+        },
+        CardNetwork::Mastercard | CardNetwork::RuPay | CardNetwork::Interac => CardNetworkRules {
+            card_number_lengths: &[16],
+            cvc_length: 3,
+        },
+        CardNetwork::AmericanExpress => CardNetworkRules {
+            card_number_lengths: &[15],
+            cvc_length: 4,
+        },
+        CardNetwork::DinersClub => CardNetworkRules {
+            card_number_lengths: &[14, 15, 16, 17, 18, 19],
+            cvc_length: 3,
+        },
+        CardNetwork::Discover
+        | CardNetwork::JCB
+        | CardNetwork::UnionPay
+        | CardNetwork::CartesBancaires => CardNetworkRules {
+            card_number_lengths: &[16, 17, 18, 19],
+            cvc_length: 3,
+        },
+        CardNetwork::Maestro => CardNetworkRules {
+            card_number_lengths: &[12, 13, 14, 15, 16, 17, 18, 19],
+            cvc_length: 3,
+        },
+    }
+}
+
+/// Detect the networks a card number belongs to from its issuer identification number.
+///
+/// The owning network comes first, followed by any co-badged networks. An empty list is returned
+/// if the number does not fall in any known range.
+pub fn get_card_networks(card_number: &str) -> Vec<CardNetwork> {
+    let iin = card_number
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .take(IIN_LENGTH)
+        .collect::<String>();
+
+    if iin.len() < IIN_LENGTH {
+        return Vec::new();
+    }
+
+    iin.parse::<u32>()
+        .ok()
+        .and_then(|iin| {
+            IIN_RANGES
+                .iter()
+                .find(|range| (range.start..=range.end).contains(&iin))
+        })
+        .map(|range| range.networks.to_vec())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_network_detection() {
+        assert_eq!(
+            get_card_networks("4242424242424242"),
+            vec![CardNetwork::Visa]
+        );
+        assert_eq!(
+            get_card_networks("5555555555554444"),
+            vec![CardNetwork::Mastercard]
+        );
+        assert_eq!(
+            get_card_networks("2223003122003222"),
+            vec![CardNetwork::Mastercard]
+        );
+        assert_eq!(
+            get_card_networks("378282246310005"),
+            vec![CardNetwork::AmericanExpress]
+        );
+        assert_eq!(
+            get_card_networks("6011111111111117"),
+            vec![CardNetwork::Discover]
+        );
+        assert_eq!(
+            get_card_networks("3566002020360505"),
+            vec![CardNetwork::JCB]
+        );
+        assert_eq!(
+            get_card_networks("30569309025904"),
+            vec![CardNetwork::DinersClub]
+        );
+        assert_eq!(
+            get_card_networks("6200000000000005"),
+            vec![CardNetwork::UnionPay]
+        );
+        assert_eq!(
+            get_card_networks("6070000000000002"),
+            vec![CardNetwork::RuPay]
+        );
+        assert_eq!(
+            get_card_networks("6759649826438453"),
+            vec![CardNetwork::Maestro]
+        );
+    }
+
+    #[test]
+    fn test_co_badged_network_detection() {
+        assert_eq!(
+            get_card_networks("4970 1012 3456 7893"),
+            vec![CardNetwork::Visa, CardNetwork::CartesBancaires]
+        );
+        assert_eq!(
+            get_card_networks("6521500000000000"),
+            vec![CardNetwork::RuPay, CardNetwork::Discover]
+        );
+        assert_eq!(
+            get_card_networks("6221260000000000"),
+            vec![CardNetwork::UnionPay, CardNetwork::Discover]
+        );
+    }
+
+    #[test]
+    fn test_unknown_network_detection() {
+        assert!(get_card_networks("9999999999999995").is_empty());
+        assert!(get_card_networks("4242").is_empty());
+    }
+
+    #[test]
+    fn test_network_rules() {
+        let amex = get_network_rules(&CardNetwork::AmericanExpress);
+        assert!(amex.is_valid_card_number_length(15));
+        assert!(!amex.is_valid_card_number_length(16));
+        assert!(amex.is_valid_cvc_length(4));
+        assert!(!amex.is_valid_cvc_length(3));
+
+        let visa = get_network_rules(&CardNetwork::Visa);
+        assert!(visa.is_valid_card_number_length(13));
+        assert!(!visa.is_valid_card_number_length(15));
+        assert!(visa.is_valid_cvc_length(3));
+    }
+}
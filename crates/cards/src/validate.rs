@@ -1,9 +1,12 @@
 use std::{fmt, ops::Deref, str::FromStr};
 
+use common_enums::CardNetwork;
 use masking::{PeekInterface, Strategy, StrongSecret, WithType};
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
+use crate::network;
+
 #[derive(Debug, Deserialize, Serialize, Error)]
 #[error("not a valid credit card number")]
 pub struct CCValError;
@@ -33,6 +36,32 @@ impl CardNumber {
             .rev()
             .collect::<String>()
     }
+
+    /// All networks able to process this card, the owning network first
+    pub fn get_card_networks(&self) -> Vec<CardNetwork> {
+        network::get_card_networks(self.0.peek())
+    }
+
+    pub fn get_card_network(&self) -> Option<CardNetwork> {
+        self.get_card_networks().into_iter().next()
+    }
+
+    /// Checks the number of digits against the rules of the owning network.
+    ///
+    /// This is not part of parsing, so that card numbers already stored with us or in the locker
+    /// keep deserializing; call it when validating card numbers received through the API.
+    pub fn validate_length(&self) -> Result<(), CCValError> {
+        let card_number = self.0.peek();
+        let is_valid_length = self.get_card_network().map_or(true, |card_network| {
+            network::get_network_rules(&card_network).is_valid_card_number_length(card_number.len())
+        });
+
+        if is_valid_length {
+            Ok(())
+        } else {
+            Err(CCValError)
+        }
+    }
 }
 
 impl FromStr for CardNumber {
@@ -124,6 +153,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invalid_card_number_length_for_network() {
+        // passes the luhn check, but american express card numbers are 15 digits long
+        let s = "3782822463100003";
+        assert_eq!(
+            CardNumber::from_str(s)
+                .unwrap()
+                .validate_length()
+                .unwrap_err()
+                .to_string(),
+            "not a valid credit card number".to_string()
+        );
+        assert!(CardNumber::from_str("378282246310005")
+            .unwrap()
+            .validate_length()
+            .is_ok());
+    }
+
+    #[test]
+    fn card_number_deserialization_ignores_network_length() {
+        // card numbers already stored in the locker must keep deserializing
+        let card_number = serde_json::from_str::<CardNumber>(r#""3782822463100003""#);
+        assert!(card_number.is_ok());
+    }
+
+    #[test]
+    fn card_number_network() {
+        let card_number = CardNumber::from_str("4970 1012 3456 7893").unwrap();
+        assert_eq!(card_number.get_card_network(), Some(CardNetwork::Visa));
+        assert_eq!(
+            card_number.get_card_networks(),
+            vec![CardNetwork::Visa, CardNetwork::CartesBancaires]
+        );
+    }
+
     #[test]
     fn test_valid_card_number_masking() {
         let secret: Secret<String, CardNumberStrategy> =
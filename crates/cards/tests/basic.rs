@@ -1,8 +1,10 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
+use std::str::FromStr;
+
 use cards::{CardExpiration, CardExpirationMonth, CardExpirationYear, CardSecurityCode};
 use common_utils::date_time;
-use masking::PeekInterface;
+use masking::{PeekInterface, Secret};
 
 #[test]
 fn test_card_security_code() {
@@ -99,3 +101,48 @@ fn test_card_expiration() {
     let invalid_deserialization = serde_json::from_str::<CardExpiration>(invalid_serialized_string);
     assert!(invalid_deserialization.is_err());
 }
+
+#[test]
+fn test_card_security_code_from_str() {
+    let card_security_code = CardSecurityCode::from_str("123").unwrap();
+    assert_eq!(*card_security_code.peek(), 123);
+
+    let card_security_code = CardSecurityCode::from_str("012").unwrap();
+    assert_eq!(*card_security_code.peek(), 12);
+    assert_eq!(card_security_code.number_of_digits(), 3);
+
+    let serialized = serde_json::to_string(&card_security_code).unwrap();
+    assert_eq!(serialized, r#""012""#);
+    let deserialized = serde_json::from_str::<CardSecurityCode>(&serialized).unwrap();
+    assert_eq!(deserialized, card_security_code);
+    assert_eq!(Secret::<String>::from(deserialized).peek(), "012");
+
+    assert!(CardSecurityCode::from_str("12").is_err());
+    assert!(CardSecurityCode::from_str("12345").is_err());
+    assert!(CardSecurityCode::from_str("12a").is_err());
+}
+
+#[test]
+fn test_card_expiration_from_str() {
+    let curr_year = u16::try_from(date_time::now().year()).expect("valid year");
+
+    let card_exp_month = serde_json::from_str::<CardExpirationMonth>(r#""3""#).unwrap();
+    assert_eq!(Secret::<String>::from(card_exp_month).peek(), "03");
+    assert!(CardExpirationMonth::from_str("13").is_err());
+    assert!(CardExpirationMonth::from_str("003").is_err());
+
+    let two_digit_year = format!("{:02}", curr_year % 100);
+    let card_exp_year =
+        serde_json::from_str::<CardExpirationYear>(&format!(r#""{two_digit_year}""#)).unwrap();
+    assert_eq!(*card_exp_year.peek(), curr_year);
+    assert_eq!(
+        Secret::<String>::from(card_exp_year).peek(),
+        &curr_year.to_string()
+    );
+
+    // both constructors reject years in the past
+    let past_year = (curr_year - 1).to_string();
+    assert!(CardExpirationYear::from_str(&past_year).is_err());
+    assert!(CardExpirationYear::try_from(curr_year - 1).is_err());
+    assert!(CardExpirationYear::from_str("20301").is_err());
+}
@@ -805,8 +805,17 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeEvidenceDue,
+    DisputeDeadlineMissed,
     MandateActive,
     MandateRevoked,
+    SubscriptionCycleSucceeded,
+    SubscriptionCycleFailed,
+    SubscriptionPastDue,
+    SubscriptionUnpaid,
+    SubscriptionCancelled,
+    PaymentMethodUpdated,
+    PaymentMethodAccountClosed,
 }
 
 // TODO: This decision about using KV mode or not,
@@ -1164,6 +1173,110 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The status of a subscription, which indicates whether the customer will be charged at the end of
+/// the current billing period
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "pg_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The first payment of the subscription is yet to be made
+    #[default]
+    Incomplete,
+    /// The subscription is in its trial period, the customer is not charged until the trial ends
+    Trialing,
+    /// The last payment of the subscription succeeded
+    Active,
+    /// The last payment of the subscription failed and is being retried
+    PastDue,
+    /// All the retries for the last payment of the subscription failed
+    Unpaid,
+    /// The subscription was cancelled, the customer will not be charged anymore
+    Cancelled,
+}
+
+/// The frequency at which the customer is charged for a subscription plan
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "pg_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// The frequency at which a saved analytics report is generated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "pg_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// The data exported into an analytics export file
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportExportType {
+    /// The metrics of a saved analytics report
+    Report,
+    /// The raw list of payment attempts
+    Payments,
+    /// The raw list of refunds
+    Refunds,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -41,3 +41,6 @@ pub const DEFAULT_PRODUCT_IMG: &str = "https://i.imgur.com/On3VtKF.png";
 
 /// Default Merchant Logo Link
 pub const DEFAULT_MERCHANT_LOGO: &str = "https://i.imgur.com/RfxPFQo.png";
+
+/// Default Payment Link Font Family
+pub const DEFAULT_FONT_FAMILY: &str = "Montserrat";
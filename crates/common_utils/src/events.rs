@@ -42,6 +42,10 @@ pub enum ApiEventsType {
     ResourceListAPI,
     PaymentRedirectionResponse,
     Gsm,
+    SubscriptionPlan,
+    Subscription {
+        subscription_id: Option<String>,
+    },
     // TODO: This has to be removed once the corresponding apiEventTypes are created
     Miscellaneous,
 }
@@ -72,6 +72,17 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
 
+    /// Lists the payment attempts of a merchant, newest first, paginated using the attempts
+    /// identified by `starting_after_id` or `ending_before_id` as cursors.
+    async fn list_payment_attempts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        starting_after_id: Option<i32>,
+        ending_before_id: Option<i32>,
+        limit: i64,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
+
     async fn get_filters_for_payments(
         &self,
         pi: &[PaymentIntent],
@@ -88,6 +99,15 @@ pub trait PaymentAttemptInterface {
         payment_method: Option<Vec<storage_enums::PaymentMethod>>,
         payment_method_type: Option<Vec<storage_enums::PaymentMethodType>>,
         authentication_type: Option<Vec<storage_enums::AuthenticationType>>,
+        card_fingerprint: Option<String>,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<i64, errors::StorageError>;
+
+    async fn get_payment_attempt_count_by_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        card_fingerprint: &str,
+        created_after: PrimitiveDateTime,
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<i64, errors::StorageError>;
 }
@@ -145,6 +165,10 @@ pub struct PaymentAttempt {
     pub authentication_data: Option<serde_json::Value>,
     pub encoded_data: Option<String>,
     pub merchant_connector_id: Option<String>,
+    /// Time taken by the connector to respond to the payment request, in milliseconds
+    pub connector_latency: Option<i64>,
+    /// Keyed fingerprint of the card used for the attempt, if the attempt was made with a card
+    pub card_fingerprint: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -207,6 +231,10 @@ pub struct PaymentAttemptNew {
     pub authentication_data: Option<serde_json::Value>,
     pub encoded_data: Option<String>,
     pub merchant_connector_id: Option<String>,
+    /// Time taken by the connector to respond to the payment request, in milliseconds
+    pub connector_latency: Option<i64>,
+    /// Keyed fingerprint of the card used for the attempt, if the attempt was made with a card
+    pub card_fingerprint: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -261,6 +289,7 @@ pub enum PaymentAttemptUpdate {
         amount_capturable: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        card_fingerprint: Option<String>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -292,6 +321,7 @@ pub enum PaymentAttemptUpdate {
         updated_by: String,
         authentication_data: Option<serde_json::Value>,
         encoded_data: Option<String>,
+        connector_latency: Option<i64>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -303,6 +333,7 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
         updated_by: String,
+        connector_latency: Option<i64>,
     },
     StatusUpdate {
         status: storage_enums::AttemptStatus,
@@ -316,6 +347,7 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         amount_capturable: Option<i64>,
         updated_by: String,
+        connector_latency: Option<i64>,
     },
     MultipleCaptureCountUpdate {
         multiple_capture_count: i16,
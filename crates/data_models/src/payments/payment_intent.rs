@@ -182,6 +182,10 @@ pub enum PaymentIntentUpdate {
         surcharge_applicable: bool,
         updated_by: String,
     },
+    PaymentLinkUpdate {
+        payment_link_id: String,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -213,6 +217,7 @@ pub struct PaymentIntentUpdateInternal {
 
     pub updated_by: String,
     pub surcharge_applicable: Option<bool>,
+    pub payment_link_id: Option<String>,
 }
 
 impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
@@ -369,6 +374,15 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentIntentUpdate::PaymentLinkUpdate {
+                payment_link_id,
+                updated_by,
+            } => Self {
+                payment_link_id: Some(payment_link_id),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -388,6 +402,7 @@ pub struct PaymentIntentListParams {
     pub payment_method: Option<Vec<storage_enums::PaymentMethod>>,
     pub payment_method_type: Option<Vec<storage_enums::PaymentMethodType>>,
     pub authentication_type: Option<Vec<storage_enums::AuthenticationType>>,
+    pub card_fingerprint: Option<String>,
     pub profile_id: Option<String>,
     pub customer_id: Option<String>,
     pub starting_after_id: Option<String>,
@@ -407,6 +422,7 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             payment_method: None,
             payment_method_type: None,
             authentication_type: None,
+            card_fingerprint: None,
             profile_id: None,
             customer_id: value.customer_id,
             starting_after_id: value.starting_after,
@@ -428,6 +444,7 @@ impl From<api_models::payments::TimeRange> for PaymentIntentFetchConstraints {
             payment_method: None,
             payment_method_type: None,
             authentication_type: None,
+            card_fingerprint: None,
             profile_id: None,
             customer_id: None,
             starting_after_id: None,
@@ -452,6 +469,7 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
                 payment_method: value.payment_method,
                 payment_method_type: value.payment_method_type,
                 authentication_type: value.authentication_type,
+                card_fingerprint: value.card_fingerprint,
                 profile_id: value.profile_id,
                 customer_id: value.customer_id,
                 starting_after_id: None,
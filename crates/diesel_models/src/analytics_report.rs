@@ -0,0 +1,117 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{analytics_report, analytics_report_export},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = analytics_report, primary_key(report_id))]
+pub struct AnalyticsReport {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    /// The metrics, dimensions, filters and granularity of the report
+    pub definition: serde_json::Value,
+    pub frequency: storage_enums::ReportFrequency,
+    /// The number of days of data included in each run, defaults to the report frequency
+    pub lookback_days: Option<i32>,
+    pub email_recipients: Option<Vec<String>>,
+    pub is_active: bool,
+    pub next_run_at: PrimitiveDateTime,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_report)]
+pub struct AnalyticsReportNew {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub definition: serde_json::Value,
+    pub frequency: storage_enums::ReportFrequency,
+    pub lookback_days: Option<i32>,
+    pub email_recipients: Option<Vec<String>>,
+    pub is_active: bool,
+    pub next_run_at: PrimitiveDateTime,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsReportUpdate {
+    /// The report was generated, the next run is scheduled at `next_run_at`
+    RunUpdate {
+        last_run_at: PrimitiveDateTime,
+        next_run_at: PrimitiveDateTime,
+    },
+    DeactivateUpdate,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_report)]
+pub struct AnalyticsReportUpdateInternal {
+    is_active: Option<bool>,
+    next_run_at: Option<PrimitiveDateTime>,
+    last_run_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<AnalyticsReportUpdate> for AnalyticsReportUpdateInternal {
+    fn from(report_update: AnalyticsReportUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match report_update {
+            AnalyticsReportUpdate::RunUpdate {
+                last_run_at,
+                next_run_at,
+            } => Self {
+                last_run_at: Some(last_run_at),
+                next_run_at: Some(next_run_at),
+                modified_at,
+                ..Default::default()
+            },
+            AnalyticsReportUpdate::DeactivateUpdate => Self {
+                is_active: Some(false),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = analytics_report_export, primary_key(export_id))]
+pub struct AnalyticsReportExport {
+    pub export_id: String,
+    pub merchant_id: String,
+    /// The report the export was generated for, not set for ad-hoc exports
+    pub report_id: Option<String>,
+    pub export_type: storage_enums::ReportExportType,
+    pub file_id: String,
+    pub start_time: PrimitiveDateTime,
+    pub end_time: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_report_export)]
+pub struct AnalyticsReportExportNew {
+    pub export_id: String,
+    pub merchant_id: String,
+    pub report_id: Option<String>,
+    pub export_type: storage_enums::ReportExportType,
+    pub file_id: String,
+    pub start_time: PrimitiveDateTime,
+    pub end_time: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AnalyticsReportTrackingData {
+    pub report_id: String,
+    pub merchant_id: String,
+}
@@ -19,6 +19,7 @@ pub struct CustomerNew {
     pub created_at: PrimitiveDateTime,
     pub modified_at: PrimitiveDateTime,
     pub address_id: Option<String>,
+    pub default_payment_method_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Identifiable, Queryable)]
@@ -37,6 +38,7 @@ pub struct Customer {
     pub connector_customer: Option<serde_json::Value>,
     pub modified_at: PrimitiveDateTime,
     pub address_id: Option<String>,
+    pub default_payment_method_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -51,4 +53,5 @@ pub struct CustomerUpdateInternal {
     pub modified_at: Option<PrimitiveDateTime>,
     pub connector_customer: Option<serde_json::Value>,
     pub address_id: Option<String>,
+    pub default_payment_method_id: Option<Option<String>>,
 }
@@ -59,6 +59,7 @@ pub struct Dispute {
     pub evidence: Secret<serde_json::Value>,
     pub profile_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub is_deadline_missed: bool,
 }
 
 #[derive(Debug)]
@@ -79,6 +80,9 @@ pub enum DisputeUpdate {
     EvidenceUpdate {
         evidence: Secret<serde_json::Value>,
     },
+    DeadlineMissedUpdate {
+        is_deadline_missed: bool,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -93,6 +97,7 @@ pub struct DisputeUpdateInternal {
     connector_updated_at: Option<PrimitiveDateTime>,
     modified_at: Option<PrimitiveDateTime>,
     evidence: Option<Secret<serde_json::Value>>,
+    is_deadline_missed: Option<bool>,
 }
 
 impl From<DisputeUpdate> for DisputeUpdateInternal {
@@ -130,6 +135,17 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 evidence: Some(evidence),
                 ..Default::default()
             },
+            DisputeUpdate::DeadlineMissedUpdate { is_deadline_missed } => Self {
+                is_deadline_missed: Some(is_deadline_missed),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Serialize)]
+pub struct DisputeDeadlineTrackingData {
+    pub dispute_id: String,
+    pub merchant_id: String,
+}
@@ -14,7 +14,8 @@ pub mod diesel_exports {
         DbPaymentType as PaymentType, DbPayoutStatus as PayoutStatus, DbPayoutType as PayoutType,
         DbProcessTrackerStatus as ProcessTrackerStatus, DbReconStatus as ReconStatus,
         DbRefundStatus as RefundStatus, DbRefundType as RefundType,
-        DbRoutingAlgorithmKind as RoutingAlgorithmKind,
+        DbReportFrequency as ReportFrequency, DbRoutingAlgorithmKind as RoutingAlgorithmKind,
+        DbSubscriptionInterval as SubscriptionInterval, DbSubscriptionStatus as SubscriptionStatus,
     };
 }
 pub use common_enums::*;
@@ -62,6 +63,8 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    Subscriptions,
+    PaymentMethods,
 }
 
 #[derive(
@@ -83,6 +86,8 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    SubscriptionDetails,
+    PaymentMethodDetails,
 }
 
 #[derive(
@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_report;
 pub mod api_keys;
 pub mod business_profile;
 pub mod capture;
@@ -38,6 +39,7 @@ pub mod reverse_lookup;
 pub mod routing_algorithm;
 #[allow(unused_qualifications)]
 pub mod schema;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
 
@@ -20,6 +20,9 @@ pub struct MerchantKeyStore {
     pub key: Encryption,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    pub previous_key: Option<Encryption>,
+    pub previous_key_version: Option<i32>,
 }
 
 #[derive(
@@ -30,13 +33,93 @@ pub struct MerchantKeyStoreNew {
     pub merchant_id: String,
     pub key: Encryption,
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
 }
 
-#[derive(
-    Clone, Debug, serde::Serialize, serde::Deserialize, AsChangeset, router_derive::DebugAsDisplay,
-)]
+#[derive(Clone, Debug)]
+pub enum MerchantKeyStoreUpdate {
+    /// Makes a new key the active key, the current key is kept for decrypting the data which has not
+    /// been re-encrypted yet
+    RotateKey {
+        key: Encryption,
+        key_version: i32,
+        previous_key: Encryption,
+        previous_key_version: i32,
+    },
+    RetirePreviousKey,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = merchant_key_store)]
 pub struct MerchantKeyStoreUpdateInternal {
+    pub key: Option<Encryption>,
+    pub key_version: Option<i32>,
+    pub previous_key: Option<Option<Encryption>>,
+    pub previous_key_version: Option<Option<i32>>,
+}
+
+impl From<MerchantKeyStoreUpdate> for MerchantKeyStoreUpdateInternal {
+    fn from(merchant_key_store_update: MerchantKeyStoreUpdate) -> Self {
+        match merchant_key_store_update {
+            MerchantKeyStoreUpdate::RotateKey {
+                key,
+                key_version,
+                previous_key,
+                previous_key_version,
+            } => Self {
+                key: Some(key),
+                key_version: Some(key_version),
+                previous_key: Some(Some(previous_key)),
+                previous_key_version: Some(Some(previous_key_version)),
+            },
+            MerchantKeyStoreUpdate::RetirePreviousKey => Self {
+                key: None,
+                key_version: None,
+                previous_key: Some(None),
+                previous_key_version: Some(None),
+            },
+        }
+    }
+}
+
+impl MerchantKeyStoreUpdateInternal {
+    pub fn apply_changeset(self, source: MerchantKeyStore) -> MerchantKeyStore {
+        MerchantKeyStore {
+            key: self.key.unwrap_or(source.key),
+            key_version: self.key_version.unwrap_or(source.key_version),
+            previous_key: self.previous_key.unwrap_or(source.previous_key),
+            previous_key_version: self
+                .previous_key_version
+                .unwrap_or(source.previous_key_version),
+            ..source
+        }
+    }
+}
+
+/// Kind of records being re-encrypted by the merchant key rotation task, in the order in which
+/// they are processed
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MerchantKeyRotationStage {
+    MerchantAccount,
+    MerchantConnectorAccounts,
+    Customers,
+    Addresses,
+    PaymentMethods,
+    /// Payment method data held in the locker, encrypted with the merchant key
+    LockerData,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MerchantKeyRotationTrackingData {
     pub merchant_id: String,
-    pub key: Encryption,
+    pub stage: MerchantKeyRotationStage,
+    /// Id of the last record of the current stage which has been processed, records are
+    /// processed in the order of their ids
+    #[serde(default)]
+    pub last_id: Option<i32>,
+    /// Whether the current pass over the merchant data has found records which were not
+    /// encrypted with the active key
+    #[serde(default)]
+    pub found_stale_records: bool,
 }
@@ -61,6 +61,8 @@ pub struct PaymentAttempt {
     pub merchant_connector_id: Option<String>,
     pub authentication_data: Option<serde_json::Value>,
     pub encoded_data: Option<String>,
+    pub connector_latency: Option<i64>,
+    pub card_fingerprint: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Queryable, Serialize, Deserialize)]
@@ -124,6 +126,8 @@ pub struct PaymentAttemptNew {
     pub merchant_connector_id: Option<String>,
     pub authentication_data: Option<serde_json::Value>,
     pub encoded_data: Option<String>,
+    pub connector_latency: Option<i64>,
+    pub card_fingerprint: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -178,6 +182,7 @@ pub enum PaymentAttemptUpdate {
         amount_capturable: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        card_fingerprint: Option<String>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -209,6 +214,7 @@ pub enum PaymentAttemptUpdate {
         updated_by: String,
         authentication_data: Option<serde_json::Value>,
         encoded_data: Option<String>,
+        connector_latency: Option<i64>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -220,6 +226,7 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
         updated_by: String,
+        connector_latency: Option<i64>,
     },
     StatusUpdate {
         status: storage_enums::AttemptStatus,
@@ -233,6 +240,7 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         amount_capturable: Option<i64>,
         updated_by: String,
+        connector_latency: Option<i64>,
     },
     MultipleCaptureCountUpdate {
         multiple_capture_count: i16,
@@ -298,6 +306,8 @@ pub struct PaymentAttemptUpdateInternal {
     merchant_connector_id: Option<String>,
     authentication_data: Option<serde_json::Value>,
     encoded_data: Option<String>,
+    connector_latency: Option<i64>,
+    card_fingerprint: Option<String>,
 }
 
 impl PaymentAttemptUpdate {
@@ -352,6 +362,8 @@ impl PaymentAttemptUpdate {
             merchant_connector_id: pa_update.merchant_connector_id,
             authentication_data: pa_update.authentication_data.or(source.authentication_data),
             encoded_data: pa_update.encoded_data.or(source.encoded_data),
+            connector_latency: pa_update.connector_latency.or(source.connector_latency),
+            card_fingerprint: pa_update.card_fingerprint.or(source.card_fingerprint),
             ..source
         }
     }
@@ -425,6 +437,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id,
+                card_fingerprint,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -445,6 +458,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id,
+                card_fingerprint,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -488,6 +502,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 updated_by,
                 authentication_data,
                 encoded_data,
+                connector_latency,
             } => Self {
                 status: Some(status),
                 connector,
@@ -508,6 +523,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 authentication_data,
                 encoded_data,
+                connector_latency,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -518,6 +534,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 amount_capturable,
                 updated_by,
+                connector_latency,
             } => Self {
                 connector,
                 status: Some(status),
@@ -527,6 +544,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 amount_capturable,
                 updated_by,
+                connector_latency,
                 ..Default::default()
             },
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -564,6 +582,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             } => Self {
                 status: Some(status),
                 connector,
@@ -575,6 +594,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
                 ..Default::default()
             },
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -181,6 +181,10 @@ pub enum PaymentIntentUpdate {
         surcharge_applicable: Option<bool>,
         updated_by: String,
     },
+    PaymentLinkUpdate {
+        payment_link_id: String,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -213,6 +217,7 @@ pub struct PaymentIntentUpdateInternal {
 
     pub updated_by: String,
     pub surcharge_applicable: Option<bool>,
+    pub payment_link_id: Option<String>,
 }
 
 impl PaymentIntentUpdate {
@@ -261,6 +266,7 @@ impl PaymentIntentUpdate {
                 .payment_confirm_source
                 .or(source.payment_confirm_source),
             updated_by: internal_update.updated_by,
+            payment_link_id: internal_update.payment_link_id.or(source.payment_link_id),
             ..source
         }
     }
@@ -420,6 +426,15 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentIntentUpdate::PaymentLinkUpdate {
+                payment_link_id,
+                updated_by,
+            } => Self {
+                payment_link_id: Some(payment_link_id),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -21,6 +21,7 @@ pub struct PaymentLink {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub fulfilment_time: Option<PrimitiveDateTime>,
     pub custom_merchant_name: Option<String>,
+    pub is_reusable: bool,
 }
 #[derive(
     Clone,
@@ -48,4 +49,5 @@ pub struct PaymentLinkNew {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub fulfilment_time: Option<PrimitiveDateTime>,
     pub custom_merchant_name: Option<String>,
+    pub is_reusable: bool,
 }
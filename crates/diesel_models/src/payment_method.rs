@@ -33,6 +33,11 @@ pub struct PaymentMethod {
     pub payment_method_issuer_code: Option<storage_enums::PaymentMethodIssuerCode>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub payment_method_data: Option<Encryption>,
+    pub card_fingerprint: Option<String>,
+    pub network_token_reference: Option<String>,
+    pub network_token_data: Option<Encryption>,
+    pub locker_id: Option<String>,
+    pub last_used_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable, Queryable, router_derive::DebugAsDisplay)]
@@ -59,6 +64,19 @@ pub struct PaymentMethodNew {
     pub last_modified: PrimitiveDateTime,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub payment_method_data: Option<Encryption>,
+    pub card_fingerprint: Option<String>,
+    pub network_token_reference: Option<String>,
+    pub network_token_data: Option<Encryption>,
+    pub locker_id: Option<String>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+impl PaymentMethod {
+    /// Returns the reference of the card in the locker. Payment methods saved before the locker
+    /// reference could change are stored in the locker under the payment method ID.
+    pub fn get_locker_id(&self) -> &str {
+        self.locker_id.as_deref().unwrap_or(&self.payment_method_id)
+    }
 }
 
 impl Default for PaymentMethodNew {
@@ -87,6 +105,11 @@ impl Default for PaymentMethodNew {
             last_modified: now,
             metadata: Option::default(),
             payment_method_data: Option::default(),
+            card_fingerprint: Option::default(),
+            network_token_reference: Option::default(),
+            network_token_data: Option::default(),
+            locker_id: Option::default(),
+            last_used_at: Option::default(),
         }
     }
 }
@@ -97,6 +120,13 @@ pub struct TokenizeCoreWorkflow {
     pub pm: storage_enums::PaymentMethod,
 }
 
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct CardAccountUpdaterTrackingData {
+    pub merchant_id: String,
+    /// The batch submitted to the account updater provider, for which results are awaited
+    pub batch_id: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum PaymentMethodUpdate {
     MetadataUpdate {
@@ -105,6 +135,25 @@ pub enum PaymentMethodUpdate {
     PaymentMethodDataUpdate {
         payment_method_data: Option<Encryption>,
     },
+    NetworkTokenDataUpdate {
+        network_token_reference: Option<String>,
+        network_token_data: Option<Encryption>,
+    },
+    CardAccountUpdate {
+        locker_id: Option<String>,
+        payment_method_data: Option<Encryption>,
+        card_fingerprint: Option<String>,
+    },
+    DetailsUpdate {
+        metadata: Option<serde_json::Value>,
+        payment_method_data: Option<Encryption>,
+    },
+    LastUsedUpdate {
+        last_used_at: PrimitiveDateTime,
+    },
+    LockerIdUpdate {
+        locker_id: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -112,13 +161,29 @@ pub enum PaymentMethodUpdate {
 pub struct PaymentMethodUpdateInternal {
     metadata: Option<serde_json::Value>,
     payment_method_data: Option<Encryption>,
+    network_token_reference: Option<String>,
+    network_token_data: Option<Encryption>,
+    locker_id: Option<String>,
+    card_fingerprint: Option<String>,
+    last_used_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentMethodUpdateInternal {
     pub fn create_payment_method(self, source: PaymentMethod) -> PaymentMethod {
-        let metadata = self.metadata.map(Secret::new);
+        let metadata = self.metadata.map(Secret::new).or(source.metadata);
 
-        PaymentMethod { metadata, ..source }
+        PaymentMethod {
+            metadata,
+            payment_method_data: self.payment_method_data.or(source.payment_method_data),
+            card_fingerprint: self.card_fingerprint.or(source.card_fingerprint),
+            locker_id: self.locker_id.or(source.locker_id),
+            last_used_at: self.last_used_at.or(source.last_used_at),
+            network_token_reference: self
+                .network_token_reference
+                .or(source.network_token_reference),
+            network_token_data: self.network_token_data.or(source.network_token_data),
+            ..source
+        }
     }
 }
 
@@ -127,13 +192,47 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
         match payment_method_update {
             PaymentMethodUpdate::MetadataUpdate { metadata } => Self {
                 metadata,
-                payment_method_data: None,
+                ..Default::default()
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
             } => Self {
-                metadata: None,
                 payment_method_data,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::NetworkTokenDataUpdate {
+                network_token_reference,
+                network_token_data,
+            } => Self {
+                network_token_reference,
+                network_token_data,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::CardAccountUpdate {
+                locker_id,
+                payment_method_data,
+                card_fingerprint,
+            } => Self {
+                locker_id,
+                payment_method_data,
+                card_fingerprint,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::DetailsUpdate {
+                metadata,
+                payment_method_data,
+            } => Self {
+                metadata,
+                payment_method_data,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
+                last_used_at: Some(last_used_at),
+                ..Default::default()
+            },
+            PaymentMethodUpdate::LockerIdUpdate { locker_id } => Self {
+                locker_id,
+                ..Default::default()
             },
         }
     }
@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_report;
 pub mod api_keys;
 pub mod business_profile;
 mod capture;
@@ -28,5 +29,6 @@ pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
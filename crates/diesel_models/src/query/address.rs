@@ -110,6 +110,26 @@ impl Address {
         .await
     }
 
+    /// Lists the records of the merchant in the order of their ids, starting after `last_id`
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.gt(last_id.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::id),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_merchant_id_payment_id_address_id<'a>(
         conn: &PgPooledConn,
@@ -0,0 +1,120 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    analytics_report::*,
+    errors,
+    schema::{analytics_report::dsl, analytics_report_export::dsl as export_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl AnalyticsReportNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsReport {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_id.eq(report_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+        report: AnalyticsReportUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_id.eq(report_id.to_owned())),
+            AnalyticsReportUpdateInternal::from(report),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while updating analytics report")
+        })
+    }
+}
+
+impl AnalyticsReportExportNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsReportExport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsReportExport {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_export_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            export_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(export_dsl::export_id.eq(export_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            export_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(export_dsl::report_id.eq(report_id.to_owned())),
+            None,
+            None,
+            Some(export_dsl::created_at.desc()),
+        )
+        .await
+    }
+}
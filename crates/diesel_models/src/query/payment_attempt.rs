@@ -7,6 +7,7 @@ use diesel::{
 };
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -206,6 +207,48 @@ impl PaymentAttempt {
         .await
     }
 
+    /// Lists the payment attempts of a merchant, newest first, after `starting_after_id` or
+    /// before `ending_before_id` when a cursor is provided.
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        starting_after_id: Option<i32>,
+        ending_before_id: Option<i32>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match ending_before_id {
+            Some(ending_before_id) => {
+                // Fetch the attempts closest to the cursor, and return them newest first
+                let mut attempts: Vec<Self> =
+                    generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                        conn,
+                        dsl::merchant_id
+                            .eq(merchant_id.to_owned())
+                            .and(dsl::id.gt(ending_before_id)),
+                        Some(limit),
+                        None,
+                        Some(dsl::id.asc()),
+                    )
+                    .await?;
+                attempts.reverse();
+                Ok(attempts)
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::id.lt(starting_after_id.unwrap_or(i32::MAX))),
+                    Some(limit),
+                    None,
+                    Some(dsl::id.desc()),
+                )
+                .await
+            }
+        }
+    }
+
     pub async fn get_filters_for_payments(
         conn: &PgPooledConn,
         pi: &[PaymentIntent],
@@ -308,6 +351,7 @@ impl PaymentAttempt {
             filter_authentication_type,
         ))
     }
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_total_count_of_attempts(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -316,6 +360,7 @@ impl PaymentAttempt {
         payment_method: Option<Vec<enums::PaymentMethod>>,
         payment_method_type: Option<Vec<enums::PaymentMethodType>>,
         authentication_type: Option<Vec<enums::AuthenticationType>>,
+        card_fingerprint: Option<String>,
     ) -> StorageResult<i64> {
         let mut filter = <Self as HasTable>::table()
             .count()
@@ -336,6 +381,9 @@ impl PaymentAttempt {
         if let Some(authentication_type) = authentication_type.clone() {
             filter = filter.filter(dsl::authentication_type.eq_any(authentication_type));
         }
+        if let Some(card_fingerprint) = card_fingerprint {
+            filter = filter.filter(dsl::card_fingerprint.eq(card_fingerprint));
+        }
         router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
 
         db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
@@ -347,4 +395,27 @@ impl PaymentAttempt {
         .change_context(errors::DatabaseError::Others)
         .attach_printable("Error filtering count of payments")
     }
+
+    pub async fn get_count_by_merchant_id_card_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        card_fingerprint: &str,
+        created_after: PrimitiveDateTime,
+    ) -> StorageResult<i64> {
+        let filter = <Self as HasTable>::table()
+            .count()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::card_fingerprint.eq(card_fingerprint.to_owned()))
+            .filter(dsl::created_at.ge(created_after));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting payment attempts by card fingerprint")
+    }
 }
@@ -76,6 +76,44 @@ impl PaymentMethod {
         .await
     }
 
+    /// Finds the payment methods of the merchant in the order of their ids, starting after
+    /// `last_id`
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.gt(last_id.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::id),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_paginated(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::id),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_customer_id_merchant_id(
         conn: &PgPooledConn,
@@ -99,6 +137,23 @@ impl PaymentMethod {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_customer_id_merchant_id_card_fingerprint(
+        conn: &PgPooledConn,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::customer_id
+                .eq(customer_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .and(dsl::card_fingerprint.eq(card_fingerprint.to_owned())),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -0,0 +1,125 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    schema::{subscription::dsl, subscription_plan::dsl as plan_dsl},
+    subscription::*,
+    PgPooledConn, StorageResult,
+};
+
+impl SubscriptionPlanNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPlan> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPlan {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        plan_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::plan_id.eq(plan_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            plan_dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+}
+
+impl SubscriptionNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Subscription> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Subscription {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription: SubscriptionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+            SubscriptionUpdateInternal::from(subscription),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while updating subscription")
+        })
+    }
+}
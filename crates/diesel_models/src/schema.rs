@@ -1,5 +1,49 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_report (report_id) {
+        #[max_length = 64]
+        report_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        definition -> Jsonb,
+        frequency -> ReportFrequency,
+        lookback_days -> Nullable<Int4>,
+        email_recipients -> Nullable<Array<Nullable<Text>>>,
+        is_active -> Bool,
+        next_run_at -> Timestamp,
+        last_run_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_report_export (export_id) {
+        #[max_length = 64]
+        export_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        report_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        export_type -> Varchar,
+        #[max_length = 64]
+        file_id -> Varchar,
+        start_time -> Timestamp,
+        end_time -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -83,6 +127,7 @@ diesel::table! {
         payout_routing_algorithm -> Nullable<Jsonb>,
         is_recon_enabled -> Bool,
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
+        payment_link_config -> Nullable<Jsonb>,
     }
 }
 
@@ -180,6 +225,8 @@ diesel::table! {
         modified_at -> Timestamp,
         #[max_length = 64]
         address_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        default_payment_method_id -> Nullable<Varchar>,
     }
 }
 
@@ -223,6 +270,7 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        is_deadline_missed -> Bool,
     }
 }
 
@@ -272,6 +320,8 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        checksum -> Nullable<Varchar>,
     }
 }
 
@@ -492,6 +542,7 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
         pm_auth_config -> Nullable<Jsonb>,
+        pm_filters -> Nullable<Jsonb>,
     }
 }
 
@@ -504,6 +555,9 @@ diesel::table! {
         merchant_id -> Varchar,
         key -> Bytea,
         created_at -> Timestamp,
+        key_version -> Int4,
+        previous_key -> Nullable<Bytea>,
+        previous_key_version -> Nullable<Int4>,
     }
 }
 
@@ -584,6 +638,9 @@ diesel::table! {
         merchant_connector_id -> Nullable<Varchar>,
         authentication_data -> Nullable<Json>,
         encoded_data -> Nullable<Text>,
+        connector_latency -> Nullable<Int8>,
+        #[max_length = 64]
+        card_fingerprint -> Nullable<Varchar>,
     }
 }
 
@@ -668,6 +725,7 @@ diesel::table! {
         fulfilment_time -> Nullable<Timestamp>,
         #[max_length = 64]
         custom_merchant_name -> Nullable<Varchar>,
+        is_reusable -> Bool,
     }
 }
 
@@ -710,6 +768,14 @@ diesel::table! {
         payment_method_issuer_code -> Nullable<PaymentMethodIssuerCode>,
         metadata -> Nullable<Json>,
         payment_method_data -> Nullable<Bytea>,
+        #[max_length = 64]
+        card_fingerprint -> Nullable<Varchar>,
+        #[max_length = 128]
+        network_token_reference -> Nullable<Varchar>,
+        network_token_data -> Nullable<Bytea>,
+        #[max_length = 64]
+        locker_id -> Nullable<Varchar>,
+        last_used_at -> Nullable<Timestamp>,
     }
 }
 
@@ -900,6 +966,63 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (subscription_id) {
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        mandate_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        status -> SubscriptionStatus,
+        current_period_start -> Timestamp,
+        current_period_end -> Timestamp,
+        trial_end -> Nullable<Timestamp>,
+        cancel_at_period_end -> Bool,
+        cancelled_at -> Nullable<Timestamp>,
+        proration_amount -> Int8,
+        #[max_length = 64]
+        latest_payment_id -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_plan (plan_id) {
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        billing_interval -> SubscriptionInterval,
+        billing_interval_count -> Int4,
+        trial_period_days -> Nullable<Int4>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -947,6 +1070,8 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    analytics_report,
+    analytics_report_export,
     api_keys,
     business_profile,
     captures,
@@ -974,6 +1099,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     refund,
     reverse_lookup,
     routing_algorithm,
+    subscription,
+    subscription_plan,
     user_roles,
     users,
 );
@@ -0,0 +1,190 @@
+use common_utils::pii;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{subscription, subscription_plan},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = subscription_plan, primary_key(plan_id))]
+pub struct SubscriptionPlan {
+    pub plan_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub billing_interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanNew {
+    pub plan_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub billing_interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = subscription, primary_key(subscription_id))]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub plan_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::SubscriptionStatus,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub proration_amount: i64,
+    pub latest_payment_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub plan_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::SubscriptionStatus,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub proration_amount: i64,
+    pub latest_payment_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    /// The subscription was moved to a different plan, `proration_amount` is the amount to be
+    /// added to (or deducted from, if negative) the next billing cycle
+    PlanUpdate {
+        plan_id: String,
+        proration_amount: i64,
+    },
+    /// A billing cycle was completed and the subscription moved on to the next billing period
+    PeriodUpdate {
+        status: storage_enums::SubscriptionStatus,
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+        proration_amount: i64,
+        latest_payment_id: Option<String>,
+    },
+    StatusUpdate {
+        status: storage_enums::SubscriptionStatus,
+        latest_payment_id: Option<String>,
+    },
+    CancelAtPeriodEndUpdate {
+        cancel_at_period_end: bool,
+    },
+    CancelledUpdate {
+        cancelled_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    plan_id: Option<String>,
+    status: Option<storage_enums::SubscriptionStatus>,
+    current_period_start: Option<PrimitiveDateTime>,
+    current_period_end: Option<PrimitiveDateTime>,
+    cancel_at_period_end: Option<bool>,
+    cancelled_at: Option<PrimitiveDateTime>,
+    proration_amount: Option<i64>,
+    latest_payment_id: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match subscription_update {
+            SubscriptionUpdate::PlanUpdate {
+                plan_id,
+                proration_amount,
+            } => Self {
+                plan_id: Some(plan_id),
+                proration_amount: Some(proration_amount),
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PeriodUpdate {
+                status,
+                current_period_start,
+                current_period_end,
+                proration_amount,
+                latest_payment_id,
+            } => Self {
+                status: Some(status),
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                proration_amount: Some(proration_amount),
+                latest_payment_id,
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::StatusUpdate {
+                status,
+                latest_payment_id,
+            } => Self {
+                status: Some(status),
+                latest_payment_id,
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::CancelAtPeriodEndUpdate {
+                cancel_at_period_end,
+            } => Self {
+                cancel_at_period_end: Some(cancel_at_period_end),
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::CancelledUpdate { cancelled_at } => Self {
+                status: Some(storage_enums::SubscriptionStatus::Cancelled),
+                cancelled_at: Some(cancelled_at),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct SubscriptionBillingTrackingData {
+    pub subscription_id: String,
+    pub merchant_id: String,
+}
@@ -17,13 +17,18 @@ pub struct StoreConfig {
 }
 
 impl Store {
+    #[allow(clippy::expect_used)]
     pub async fn new(config: &crate::settings::Settings, test_transaction: bool) -> Self {
         Self {
             master_pool: diesel_make_pg_pool(
                 &config.master_database,
                 test_transaction,
-                #[cfg(feature = "kms")]
-                external_services::kms::get_kms_client(&config.kms).await,
+                #[cfg(feature = "encryption_service")]
+                external_services::encryption_management::get_encryption_management_client(
+                    &config.encryption_management,
+                )
+                .await
+                .expect("Failed to initialize encryption management client"),
             )
             .await,
             redis_conn: Arc::new(crate::connection::redis_connection(config).await),
@@ -2,8 +2,11 @@ use std::path::PathBuf;
 
 use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "encryption_service")]
+use external_services::{
+    encryption_management::{EncryptionManagementConfig, LegacyKmsConfig},
+    secrets_management,
+};
 use redis_interface as redis;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use router_env::{env, logger};
@@ -11,9 +14,9 @@ use serde::Deserialize;
 
 use crate::errors;
 
-#[cfg(feature = "kms")]
-pub type Password = kms::KmsValue;
-#[cfg(not(feature = "kms"))]
+#[cfg(feature = "encryption_service")]
+pub type Password = secrets_management::KmsValue;
+#[cfg(not(feature = "encryption_service"))]
 pub type Password = masking::Secret<String>;
 
 #[derive(clap::Parser, Default)]
@@ -32,8 +35,11 @@ pub struct Settings {
     pub redis: redis::RedisSettings,
     pub log: Log,
     pub drainer: DrainerSettings,
-    #[cfg(feature = "kms")]
-    pub kms: kms::KmsConfig,
+    #[cfg(feature = "encryption_service")]
+    pub encryption_management: EncryptionManagementConfig,
+    /// Deprecated, superseded by `encryption_management`
+    #[cfg(feature = "encryption_service")]
+    pub kms: Option<LegacyKmsConfig>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -157,11 +163,27 @@ impl Settings {
             )
             .build()?;
 
-        serde_path_to_error::deserialize(config).map_err(|error| {
+        #[allow(unused_mut)]
+        let mut settings: Self = serde_path_to_error::deserialize(config).map_err(|error| {
             logger::error!(%error, "Unable to deserialize application configuration");
             eprintln!("Unable to deserialize application configuration: {error}");
             errors::DrainerError::from(error.into_inner())
-        })
+        })?;
+
+        #[cfg(feature = "encryption_service")]
+        {
+            if settings.kms.is_some() {
+                logger::warn!(
+                    "The `[kms]` configuration section is deprecated, use `[encryption_management]` instead"
+                );
+            }
+            settings.encryption_management = settings
+                .encryption_management
+                .with_legacy_kms_config(settings.kms.take())
+                .map_err(|error| errors::DrainerError::ConfigParsingError(error.into()))?;
+        }
+
+        Ok(settings)
     }
 
     pub fn validate(&self) -> Result<(), errors::DrainerError> {
@@ -171,6 +193,10 @@ impl Settings {
             errors::DrainerError::ConfigParsingError("invalid Redis configuration".into())
         })?;
         self.drainer.validate()?;
+        #[cfg(feature = "encryption_service")]
+        self.encryption_management
+            .validate()
+            .map_err(|error| errors::DrainerError::ConfigParsingError(error.into()))?;
 
         Ok(())
     }
@@ -39,6 +39,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
         payment: inputs::PaymentInput {
             amount: 32,
             card_bin: None,
+            card_fingerprint: None,
             currency: enums::Currency::USD,
             authentication_type: Some(enums::AuthenticationType::NoThreeDs),
             capture_method: Some(enums::CaptureMethod::Automatic),
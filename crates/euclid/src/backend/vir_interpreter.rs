@@ -127,6 +127,7 @@ mod test {
             payment: inputs::PaymentInput {
                 amount: 32,
                 card_bin: None,
+                card_fingerprint: None,
                 currency: enums::Currency::USD,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
@@ -168,6 +169,7 @@ mod test {
                 amount: 32,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
+                card_fingerprint: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
@@ -209,6 +211,7 @@ mod test {
                 amount: 32,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
+                card_fingerprint: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
@@ -250,6 +253,7 @@ mod test {
                 amount: 32,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
+                card_fingerprint: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
@@ -291,6 +295,7 @@ mod test {
                 amount: 32,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
+                card_fingerprint: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
@@ -315,6 +320,55 @@ mod test {
         assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
     }
     #[test]
+    fn test_card_fingerprint() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           card_fingerprint="9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+        }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let mut inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: 32,
+                currency: enums::Currency::USD,
+                card_bin: None,
+                card_fingerprint: Some(
+                    "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08".to_string(),
+                ),
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result = backend.execute(inp.clone()).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
+
+        inp.payment.card_fingerprint =
+            Some("60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752".to_string());
+        let result = backend.execute(inp).expect("Execution");
+        assert!(result.rule_name.is_none());
+    }
+    #[test]
     fn test_payment_amount() {
         let program_str = r#"
         default: ["stripe", "adyen"]
@@ -332,6 +386,7 @@ mod test {
                 amount: 32,
                 currency: enums::Currency::USD,
                 card_bin: None,
+                card_fingerprint: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
@@ -373,6 +428,7 @@ mod test {
                 amount: 32,
                 currency: enums::Currency::USD,
                 card_bin: None,
+                card_fingerprint: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
@@ -414,6 +470,7 @@ mod test {
                 amount: 32,
                 currency: enums::Currency::USD,
                 card_bin: None,
+                card_fingerprint: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
@@ -456,6 +513,7 @@ mod test {
             payment: inputs::PaymentInput {
                 amount: 32,
                 card_bin: None,
+                card_fingerprint: None,
                 currency: enums::Currency::USD,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
@@ -497,6 +555,7 @@ mod test {
             payment: inputs::PaymentInput {
                 amount: 150,
                 card_bin: None,
+                card_fingerprint: None,
                 currency: enums::Currency::USD,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
@@ -547,6 +606,7 @@ mod test {
             payment: inputs::PaymentInput {
                 amount: 120,
                 card_bin: None,
+                card_fingerprint: None,
                 currency: enums::Currency::USD,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
                 capture_method: Some(enums::CaptureMethod::Automatic),
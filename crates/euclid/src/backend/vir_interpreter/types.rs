@@ -92,6 +92,11 @@ impl Context {
         if let Some(card_bin) = payment.card_bin {
             enum_values.insert(EuclidValue::CardBin(StrValue { value: card_bin }));
         }
+        if let Some(card_fingerprint) = payment.card_fingerprint {
+            enum_values.insert(EuclidValue::CardFingerprint(StrValue {
+                value: card_fingerprint,
+            }));
+        }
         if let Some(business_label) = payment.business_label {
             enum_values.insert(EuclidValue::BusinessLabel(StrValue {
                 value: business_label,
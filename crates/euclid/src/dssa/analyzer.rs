@@ -17,6 +17,9 @@ use crate::{
     types::{DataType, Metadata},
 };
 
+/// Key under which analysis errors reference the name of the rule they were raised for.
+pub const RULE_NAME_METADATA_KEY: &str = "rule_name";
+
 /// Analyses conflicting assertions on the same key in a conjunctive context.
 ///
 /// For example,
@@ -201,37 +204,312 @@ fn perform_condition_analyses(
     Ok(())
 }
 
-fn perform_context_analyses(
+fn perform_graph_analysis(
     context: &types::ConjunctiveContext<'_>,
     knowledge_graph: &graph::KnowledgeGraph<'_>,
 ) -> Result<(), types::AnalysisError> {
-    perform_condition_analyses(context)?;
     let mut memo = Memoization::new();
     knowledge_graph
         .perform_context_analysis(context, &mut memo)
         .map_err(|err| types::AnalysisError {
             error_type: types::AnalysisErrorType::GraphAnalysis(err, memo),
             metadata: Default::default(),
-        })?;
-    Ok(())
+        })
 }
 
-pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
+/// Builds the error metadata that points an analysis error back to the rule it was raised for.
+fn rule_back_reference(rule_name: &str) -> Metadata {
+    Metadata::from_iter([(
+        RULE_NAME_METADATA_KEY.to_string(),
+        serde_json::Value::String(rule_name.to_string()),
+    )])
+}
+
+/// A conjunction of comparisons along one path from a top level statement of a rule down to a
+/// leaf statement. A rule is selected if any of its paths holds.
+type ConditionPath<'a> = Vec<&'a dir::DirComparison>;
+
+fn collect_condition_paths<'a>(
+    statement: &'a dir::DirIfStatement,
+    prefix: &ConditionPath<'a>,
+    paths: &mut Vec<ConditionPath<'a>>,
+) {
+    let mut path = prefix.clone();
+    path.extend(statement.condition.iter());
+
+    match &statement.nested {
+        None => paths.push(path),
+        Some(nested) => {
+            for nested_statement in nested {
+                collect_condition_paths(nested_statement, &path, paths);
+            }
+        }
+    }
+}
+
+fn get_condition_paths<O>(rule: &dir::DirRule<O>) -> Vec<ConditionPath<'_>> {
+    let mut paths = Vec::new();
+    for statement in &rule.statements {
+        collect_condition_paths(statement, &Vec::new(), &mut paths);
+    }
+    paths
+}
+
+fn collect_comparison_metadata(statements: &[dir::DirIfStatement], metadata: &mut Vec<Metadata>) {
+    for statement in statements {
+        metadata.extend(
+            statement
+                .condition
+                .iter()
+                .map(|comparison| comparison.metadata.clone()),
+        );
+
+        if let Some(nested) = &statement.nested {
+            collect_comparison_metadata(nested, metadata);
+        }
+    }
+}
+
+fn get_comparison_metadata<O>(rule: &dir::DirRule<O>) -> Vec<Metadata> {
+    let mut metadata = Vec::new();
+    collect_comparison_metadata(&rule.statements, &mut metadata);
+    metadata
+}
+
+/// Checks whether `value` holding guarantees that `implied` holds as well.
+fn value_implies(value: &dir::DirValue, implied: &dir::DirValue) -> bool {
+    if value == implied {
+        return true;
+    }
+
+    match (value.get_num_value(), implied.get_num_value()) {
+        (Some(num), Some(implied_num)) => {
+            value.get_key() == implied.get_key() && implied_num.fits(&num)
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether `comparison` holding guarantees that `implied` holds as well. Numeric
+/// negations are never considered implied, so the check errs on the side of reporting nothing.
+fn comparison_implies(comparison: &dir::DirComparison, implied: &dir::DirComparison) -> bool {
+    let key = if let Some(value) = comparison.values.first() {
+        value.get_key()
+    } else {
+        return false;
+    };
+
+    if implied.values.first().map(|value| value.get_key()).as_ref() != Some(&key) {
+        return false;
+    }
+
+    match (&comparison.logic, &implied.logic) {
+        (
+            dir::DirComparisonLogic::PositiveDisjunction,
+            dir::DirComparisonLogic::PositiveDisjunction,
+        ) => comparison.values.iter().all(|value| {
+            implied
+                .values
+                .iter()
+                .any(|implied_value| value_implies(value, implied_value))
+        }),
+
+        (
+            dir::DirComparisonLogic::PositiveDisjunction,
+            dir::DirComparisonLogic::NegativeConjunction,
+        ) => comparison
+            .values
+            .iter()
+            .all(|value| value.get_num_value().is_none() && !implied.values.contains(value)),
+
+        (
+            dir::DirComparisonLogic::NegativeConjunction,
+            dir::DirComparisonLogic::NegativeConjunction,
+        ) => implied
+            .values
+            .iter()
+            .all(|implied_value| comparison.values.contains(implied_value)),
+
+        (
+            dir::DirComparisonLogic::NegativeConjunction,
+            dir::DirComparisonLogic::PositiveDisjunction,
+        ) => key.kind.get_value_set().map_or(false, |value_set| {
+            value_set
+                .iter()
+                .filter(|value| !comparison.values.contains(value))
+                .all(|value| implied.values.contains(value))
+        }),
+    }
+}
+
+fn path_implies(path: &ConditionPath<'_>, implied: &ConditionPath<'_>) -> bool {
+    implied.iter().all(|implied_comparison| {
+        path.iter()
+            .any(|comparison| comparison_implies(comparison, implied_comparison))
+    })
+}
+
+/// Checks whether every path of a rule implies some path of another rule, i.e. whenever the
+/// first rule would be selected the second one would be selected as well.
+fn rule_implies(paths: &[ConditionPath<'_>], implied: &[ConditionPath<'_>]) -> bool {
+    !paths.is_empty()
+        && paths.iter().all(|path| {
+            implied
+                .iter()
+                .any(|implied_path| path_implies(path, implied_path))
+        })
+}
+
+/// Finds the rules that can never be selected, skipping the rules in `unreachable_rules` which
+/// are already known to be unreachable and hence cannot shadow later rules either.
+fn find_unselectable_rules<O>(
+    program: &dir::DirProgram<O>,
+    unreachable_rules: &FxHashSet<String>,
+) -> Vec<types::AnalysisError> {
+    let rule_paths: Vec<Vec<ConditionPath<'_>>> =
+        program.rules.iter().map(get_condition_paths).collect();
+    let mut findings = Vec::new();
+
+    for (rule_idx, (rule, paths)) in program.rules.iter().zip(rule_paths.iter()).enumerate() {
+        if unreachable_rules.contains(&rule.name) {
+            continue;
+        }
+
+        if paths.is_empty() {
+            findings.push(types::AnalysisError {
+                error_type: types::AnalysisErrorType::UnreachableRule {
+                    rule_name: rule.name.clone(),
+                    rule_metadata: Vec::new(),
+                },
+                metadata: rule_back_reference(&rule.name),
+            });
+            continue;
+        }
+
+        let shadowing_rule = program
+            .rules
+            .iter()
+            .zip(rule_paths.iter())
+            .take(rule_idx)
+            .filter(|(earlier_rule, _)| !unreachable_rules.contains(&earlier_rule.name))
+            .find(|(_, earlier_paths)| rule_implies(paths, earlier_paths));
+
+        if let Some((earlier_rule, earlier_paths)) = shadowing_rule {
+            let error_type = if rule_implies(earlier_paths, paths) {
+                types::AnalysisErrorType::DuplicateRule {
+                    rule_name: rule.name.clone(),
+                    duplicate_of: earlier_rule.name.clone(),
+                    rule_metadata: get_comparison_metadata(rule),
+                    duplicate_of_metadata: get_comparison_metadata(earlier_rule),
+                }
+            } else {
+                types::AnalysisErrorType::ShadowedRule {
+                    rule_name: rule.name.clone(),
+                    shadowed_by: earlier_rule.name.clone(),
+                    rule_metadata: get_comparison_metadata(rule),
+                    shadowed_by_metadata: get_comparison_metadata(earlier_rule),
+                }
+            };
+
+            findings.push(types::AnalysisError {
+                error_type,
+                metadata: rule_back_reference(&rule.name),
+            });
+        }
+    }
+
+    findings
+}
+
+/// Analyses whether every rule in the program can actually be selected, returning a finding for
+/// each rule that cannot.
+///
+/// Rules are evaluated in order and the first matching rule wins, so a rule is reported if
+///  - it has no paths through its statements,
+///  - an earlier rule matches whenever it matches (the rule is shadowed), or
+///  - an earlier rule matches exactly when it matches (the rule is a duplicate).
+pub fn analyze_rule_reachability<O>(program: &dir::DirProgram<O>) -> Vec<types::AnalysisError> {
+    find_unselectable_rules(program, &FxHashSet::default())
+}
+
+/// Analyses a program like [`analyze`], except that rules which can never be selected are
+/// returned as warnings alongside the lowered program instead of failing the analysis.
+///
+/// Besides the findings of [`analyze_rule_reachability`], a rule is unreachable if the knowledge
+/// graph rejects every context the rule can be selected in, for example when none of its
+/// connectors supports the payment methods it matches.
+pub fn analyze_with_warnings<O: EuclidAnalysable + EuclidDirFilter>(
     program: ast::Program<O>,
     knowledge_graph: Option<&graph::KnowledgeGraph<'_>>,
-) -> Result<vir::ValuedProgram<O>, types::AnalysisError> {
+) -> Result<(vir::ValuedProgram<O>, Vec<types::AnalysisError>), types::AnalysisError> {
     let dir_program = ast::lowering::lower_program(program)?;
+    let knowledge_graph = knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH);
+    let mut warnings = Vec::new();
 
     let selection_data = state_machine::make_connector_selection_data(&dir_program);
-    let mut ctx_manager = state_machine::AnalysisContextManager::new(&dir_program, &selection_data);
-    while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
-        metadata: Default::default(),
-        error_type: types::AnalysisErrorType::StateMachine(err),
-    })? {
-        perform_context_analyses(ctx, knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH))?;
+    for (rule, rule_selection_data) in dir_program.rules.iter().zip(selection_data.iter()) {
+        let mut ctx_manager = state_machine::RuleContextManager::new(rule, rule_selection_data);
+        let mut context_count = 0;
+        let mut graph_errors = Vec::new();
+
+        while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+            metadata: rule_back_reference(&rule.name),
+            error_type: types::AnalysisErrorType::StateMachine(err),
+        })? {
+            context_count += 1;
+            perform_condition_analyses(ctx)
+                .and_then(|()| perform_graph_analysis(ctx, knowledge_graph))
+                .or_else(|err| match err.error_type {
+                    types::AnalysisErrorType::GraphAnalysis(..) => {
+                        graph_errors.push(err);
+                        Ok(())
+                    }
+                    _ => Err(err),
+                })
+                .map_err(|mut err| {
+                    err.metadata.extend(rule_back_reference(&rule.name));
+                    err
+                })?;
+        }
+
+        if context_count > 0 && graph_errors.len() == context_count {
+            warnings.push(types::AnalysisError {
+                error_type: types::AnalysisErrorType::UnreachableRule {
+                    rule_name: rule.name.clone(),
+                    rule_metadata: get_comparison_metadata(rule),
+                },
+                metadata: rule_back_reference(&rule.name),
+            });
+        } else if let Some(mut err) = graph_errors.into_iter().next() {
+            err.metadata.extend(rule_back_reference(&rule.name));
+            return Err(err);
+        }
     }
 
-    dir::lowering::lower_program(dir_program)
+    let unreachable_rules = warnings
+        .iter()
+        .filter_map(|warning| match &warning.error_type {
+            types::AnalysisErrorType::UnreachableRule { rule_name, .. } => Some(rule_name.clone()),
+            _ => None,
+        })
+        .collect::<FxHashSet<_>>();
+    warnings.extend(find_unselectable_rules(&dir_program, &unreachable_rules));
+
+    Ok((dir::lowering::lower_program(dir_program)?, warnings))
+}
+
+/// Analyses a program, failing on the first issue found, including rules that can never be
+/// selected.
+pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&graph::KnowledgeGraph<'_>>,
+) -> Result<vir::ValuedProgram<O>, types::AnalysisError> {
+    let (valued_program, warnings) = analyze_with_warnings(program, knowledge_graph)?;
+
+    match warnings.into_iter().next() {
+        Some(warning) => Err(warning),
+        None => Ok(valued_program),
+    }
 }
 
 #[cfg(all(test, feature = "ast_parser"))]
@@ -444,4 +722,211 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_shadowed_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = (card, wallet)
+            }
+
+            rule_2: ["stripe"]
+            {
+                payment_method = card & amount > 500
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type:
+                types::AnalysisErrorType::ShadowedRule {
+                    rule_name,
+                    shadowed_by,
+                    ..
+                },
+            metadata,
+        }) = analysis_result
+        {
+            assert_eq!(rule_name, "rule_2");
+            assert_eq!(shadowed_by, "rule_1");
+            assert_eq!(
+                metadata.get(RULE_NAME_METADATA_KEY),
+                Some(&serde_json::json!("rule_2"))
+            );
+        } else {
+            panic!("Expected shadowed rule error");
+        }
+    }
+
+    #[test]
+    fn test_amount_shadowing_respects_rule_order() {
+        let shadowed_program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                amount > 500
+            }
+
+            rule_2: ["stripe"]
+            {
+                amount > 1000 & currency = USD
+            }
+        "#;
+
+        let (_, program) =
+            ast::parser::program::<DummyOutput>(shadowed_program_str).expect("Program");
+        assert!(matches!(
+            analyze(program, None),
+            Err(types::AnalysisError {
+                error_type: types::AnalysisErrorType::ShadowedRule { .. },
+                ..
+            })
+        ));
+
+        let valid_program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                amount > 1000 & currency = USD
+            }
+
+            rule_2: ["stripe"]
+            {
+                amount > 500
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(valid_program_str).expect("Program");
+        assert!(analyze(program, None).is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = card {
+                    amount > 500
+                }
+            }
+
+            rule_2: ["stripe"]
+            {
+                amount > 500 & payment_method = card
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type:
+                types::AnalysisErrorType::DuplicateRule {
+                    rule_name,
+                    duplicate_of,
+                    ..
+                },
+            ..
+        }) = analysis_result
+        {
+            assert_eq!(rule_name, "rule_2");
+            assert_eq!(duplicate_of, "rule_1");
+        } else {
+            panic!("Expected duplicate rule error");
+        }
+    }
+
+    #[test]
+    fn test_unreachable_rule_detection() {
+        let graph = knowledge! {crate
+            CaptureMethod(Automatic) ->> PaymentMethod(Card);
+        };
+
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = card & capture_method = manual
+            }
+
+            rule_2: ["stripe"]
+            {
+                payment_method = card & capture_method = manual & amount > 500
+            }
+
+            rule_3: ["stripe"]
+            {
+                payment_method = wallet
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let (_, warnings) = analyze_with_warnings(program, Some(&graph)).expect("Analysis");
+
+        let unreachable_rules = warnings
+            .iter()
+            .filter_map(|warning| match &warning.error_type {
+                types::AnalysisErrorType::UnreachableRule { rule_name, .. } => {
+                    Some(rule_name.as_str())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(unreachable_rules, vec!["rule_1", "rule_2"]);
+        assert_eq!(
+            warnings.len(),
+            2,
+            "An unreachable rule should not be reported as shadowing later rules"
+        );
+    }
+
+    #[test]
+    fn test_shadowed_rules_are_warnings() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = card
+            }
+
+            rule_2: ["stripe"]
+            {
+                payment_method = card & amount > 500
+            }
+
+            rule_3: ["stripe"]
+            {
+                payment_method = card
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let (valued_program, warnings) = analyze_with_warnings(program, None).expect("Analysis");
+
+        assert_eq!(valued_program.rules.len(), 3);
+        assert!(matches!(
+            warnings.as_slice(),
+            [
+                types::AnalysisError {
+                    error_type: types::AnalysisErrorType::ShadowedRule { .. },
+                    ..
+                },
+                types::AnalysisError {
+                    error_type: types::AnalysisErrorType::DuplicateRule { .. },
+                    ..
+                },
+            ]
+        ));
+    }
 }
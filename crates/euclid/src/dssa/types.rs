@@ -139,6 +139,25 @@ pub enum AnalysisErrorType {
         assertion_metadata: Metadata,
         negation_metadata: Metadata,
     },
+    #[error("Rule '{rule_name}' is shadowed by the earlier rule '{shadowed_by}' and can never be selected")]
+    ShadowedRule {
+        rule_name: String,
+        shadowed_by: String,
+        rule_metadata: Vec<Metadata>,
+        shadowed_by_metadata: Vec<Metadata>,
+    },
+    #[error("Rule '{rule_name}' has the same conditions as the earlier rule '{duplicate_of}'")]
+    DuplicateRule {
+        rule_name: String,
+        duplicate_of: String,
+        rule_metadata: Vec<Metadata>,
+        duplicate_of_metadata: Vec<Metadata>,
+    },
+    #[error("Rule '{rule_name}' has no conditions that can ever be satisfied")]
+    UnreachableRule {
+        rule_name: String,
+        rule_metadata: Vec<Metadata>,
+    },
     #[error("Graph analysis error: {0:#?}")]
     GraphAnalysis(graph::AnalysisError, graph::Memoization),
     #[error("State machine error")]
@@ -1,6 +1,7 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+pub mod printer;
 
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +10,14 @@ use crate::{
     types::{DataType, Metadata},
 };
 
+/// Metadata key holding the line comments that precede a program or an if statement in the text
+/// form. For if statements they are stored on the first comparison of the condition.
+pub const COMMENTS_METADATA_KEY: &str = "comments";
+
+/// Program metadata key holding the line comments that precede each rule, as one list of comments
+/// per rule in program order. Rule names are not unique, so they are not used as keys.
+pub const RULE_COMMENTS_METADATA_KEY: &str = "rule_comments";
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct ConnectorChoice {
     pub connector: Connector,
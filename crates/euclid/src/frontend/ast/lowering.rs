@@ -289,6 +289,21 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value),
 
+        dir::DirKeyKind::CardFingerprint => {
+            let validation_closure = |st: &String| -> Result<(), AnalysisErrorType> {
+                if st.len() == 64 && st.chars().all(|x| x.is_ascii_hexdigit()) {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::CardFingerprint,
+                        value: st.clone(),
+                        message: Some("Expected 64 hexadecimal characters".to_string()),
+                    })
+                }
+            };
+            lower_str!(CardFingerprint, value, validation_closure)
+        }
+
         dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value),
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
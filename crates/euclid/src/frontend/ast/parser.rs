@@ -2,7 +2,10 @@ use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
 
-use crate::{frontend::ast, types::DummyOutput};
+use crate::{
+    frontend::ast,
+    types::{DummyOutput, Metadata},
+};
 pub type ParseResult<T, U> = nom::IResult<T, U, nom::error::VerboseError<T>>;
 
 pub enum EuclidError {
@@ -54,7 +57,46 @@ pub fn skip_ws<'a, F: 'a, O>(inner: F) -> impl FnMut(&'a str) -> ParseResult<&st
 where
     F: FnMut(&'a str) -> ParseResult<&str, O>,
 {
-    sequence::preceded(pchar::multispace0, inner)
+    sequence::preceded(ws_and_comments, inner)
+}
+
+/// Parses a `//` line comment, returning the text after the slashes.
+pub fn line_comment(input: &str) -> ParseResult<&str, &str> {
+    sequence::preceded(complete::tag("//"), complete::take_till(|c| c == '\n'))(input)
+}
+
+/// Skips any whitespace and line comments. Comments in positions that cannot carry metadata are
+/// dropped.
+pub fn ws_and_comments(input: &str) -> ParseResult<&str, ()> {
+    combinator::value(
+        (),
+        multi::many0(branch::alt((pchar::multispace1, line_comment))),
+    )(input)
+}
+
+/// Collects the line comments directly preceding a program, rule or if statement so that they can
+/// be preserved in the metadata of the parsed program.
+pub fn leading_comments(input: &str) -> ParseResult<&str, Vec<String>> {
+    combinator::map(
+        multi::many0(sequence::preceded(pchar::multispace0, line_comment)),
+        |comments: Vec<&str>| {
+            comments
+                .into_iter()
+                .map(|comment| comment.trim().to_string())
+                .collect()
+        },
+    )(input)
+}
+
+fn comments_metadata(comments: Vec<String>) -> Metadata {
+    if comments.is_empty() {
+        Metadata::new()
+    } else {
+        Metadata::from_iter([(
+            ast::COMMENTS_METADATA_KEY.to_string(),
+            serde_json::Value::from(comments),
+        )])
+    }
 }
 
 pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
@@ -73,13 +115,17 @@ pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
 pub fn string_str(input: &str) -> ParseResult<&str, String> {
     error::context(
         "String",
-        combinator::map(
-            sequence::delimited(
-                complete::tag("\""),
-                complete::take_while1(|c: char| c != '"'),
-                complete::tag("\""),
+        sequence::delimited(
+            complete::tag("\""),
+            complete::escaped_transform(
+                complete::is_not("\"\\"),
+                '\\',
+                branch::alt((
+                    combinator::value("\\", complete::tag("\\")),
+                    combinator::value("\"", complete::tag("\"")),
+                )),
             ),
-            |val: &str| val.to_string(),
+            complete::tag("\""),
         ),
     )(input)
 }
@@ -331,7 +377,10 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
 pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            branch::alt((comparison, arbitrary_comparison)),
+        )),
     );
 
     let full_sequence = sequence::pair(
@@ -362,10 +411,21 @@ pub fn if_statement(input: &str) -> ParseResult<&str, ast::IfStatement> {
     error::context(
         "if_statement",
         combinator::map(
-            sequence::pair(comparison_array, combinator::opt(nested_block)),
-            |tup: (ast::IfCondition, Option<Vec<ast::IfStatement>>)| ast::IfStatement {
-                condition: tup.0,
-                nested: tup.1,
+            sequence::tuple((
+                leading_comments,
+                comparison_array,
+                combinator::opt(nested_block),
+            )),
+            |tup: (Vec<String>, ast::IfCondition, Option<Vec<ast::IfStatement>>)| {
+                let mut condition = tup.1;
+                if let Some(first) = condition.first_mut() {
+                    first.metadata.extend(comments_metadata(tup.0));
+                }
+
+                ast::IfStatement {
+                    condition,
+                    nested: tup.2,
+                }
             },
         ),
     )(input)
@@ -426,16 +486,122 @@ pub fn default_output<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&
     )(input)
 }
 
+pub fn commented_rule<O: EuclidParsable>(
+    input: &str,
+) -> ParseResult<&str, (Vec<String>, ast::Rule<O>)> {
+    sequence::pair(leading_comments, rule::<O>)(input)
+}
+
 pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, ast::Program<O>> {
     error::context(
         "program",
         combinator::map(
-            sequence::pair(default_output, multi::many1(skip_ws(rule::<O>))),
-            |tup: (O, Vec<ast::Rule<O>>)| ast::Program {
-                default_selection: tup.0,
-                rules: tup.1,
-                metadata: std::collections::HashMap::new(),
+            sequence::tuple((
+                leading_comments,
+                default_output,
+                multi::many1(commented_rule::<O>),
+            )),
+            |tup: (Vec<String>, O, Vec<(Vec<String>, ast::Rule<O>)>)| {
+                let (rule_comments, rules): (Vec<Vec<String>>, Vec<ast::Rule<O>>) =
+                    tup.2.into_iter().unzip();
+
+                let mut metadata = comments_metadata(tup.0);
+                if rule_comments.iter().any(|comments| !comments.is_empty()) {
+                    metadata.insert(
+                        ast::RULE_COMMENTS_METADATA_KEY.to_string(),
+                        serde_json::Value::from(rule_comments),
+                    );
+                }
+
+                ast::Program {
+                    default_selection: tup.1,
+                    rules,
+                    metadata,
+                }
             },
         ),
     )(input)
 }
+
+/// A parse error pointing at the line and column (both starting at 1) where parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ParseDiagnostic {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ParseDiagnostic {
+    fn at(input: &str, remaining: &str, message: String) -> Self {
+        let offset = input.len().saturating_sub(remaining.len());
+        let consumed = input.get(..offset).unwrap_or(input);
+        let line_start = consumed.rfind('\n').map_or(0, |idx| idx + 1);
+
+        Self {
+            message,
+            line: consumed.matches('\n').count() + 1,
+            column: consumed.get(line_start..).unwrap_or("").chars().count() + 1,
+        }
+    }
+
+    fn from_error(input: &str, err: &error::VerboseError<&str>) -> Self {
+        let remaining = err.errors.first().map_or("", |(remaining, _)| *remaining);
+        let context = err.errors.iter().find_map(|(_, kind)| match kind {
+            error::VerboseErrorKind::Context(context) => Some(*context),
+            _ => None,
+        });
+
+        let message = match (err.errors.first(), context) {
+            (Some((_, error::VerboseErrorKind::Char(expected))), _) => {
+                format!("Expected '{expected}'")
+            }
+            (_, Some(context)) => format!("Invalid {context}"),
+            _ => "Invalid syntax".to_string(),
+        };
+
+        Self::at(input, remaining, message)
+    }
+}
+
+impl std::fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.column
+        )
+    }
+}
+
+/// Parses a complete program, reporting the position of the first syntax error.
+pub fn parse_program<O: EuclidParsable + 'static>(
+    input: &str,
+) -> Result<ast::Program<O>, ParseDiagnostic> {
+    let (remaining, parsed) = match program::<O>(input) {
+        Ok(parsed) => parsed,
+        Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => {
+            return Err(ParseDiagnostic::from_error(input, &err))
+        }
+        Err(nom::Err::Incomplete(_)) => {
+            return Err(ParseDiagnostic::at(
+                input,
+                "",
+                "Unexpected end of input".to_string(),
+            ))
+        }
+    };
+
+    let remaining = ws_and_comments(remaining).map_or(remaining, |(rest, _)| rest);
+    if remaining.is_empty() {
+        return Ok(parsed);
+    }
+
+    // The rule list stops at the first rule that fails to parse, so parse it again on its own
+    // to point at the actual error instead of the start of the rule.
+    Err(match commented_rule::<O>(remaining) {
+        Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => {
+            ParseDiagnostic::from_error(input, &err)
+        }
+        _ => ParseDiagnostic::at(input, remaining, "Unexpected input".to_string()),
+    })
+}
@@ -0,0 +1,298 @@
+//! Pretty printer for the Euclid Rule DSL
+//!
+//! Turns an [`ast::Program`] back into the canonical text form accepted by the parser. The output
+//! is stable, so printing a parsed program and parsing it again yields the same text, and line
+//! comments stored in the program [`Metadata`] are written back in place.
+use std::fmt::Write;
+
+use crate::{
+    frontend::ast,
+    types::{DummyOutput, Metadata},
+};
+
+const INDENT: &str = "    ";
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> String;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> String {
+        let outputs: Vec<String> = self
+            .outputs
+            .iter()
+            .map(|output| format!("\"{output}\""))
+            .collect();
+        format!("[{}]", outputs.join(", "))
+    }
+}
+
+/// Quotes a string literal, escaping the characters the parser treats specially.
+pub fn quote(string: &str) -> String {
+    format!("\"{}\"", string.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn get_comments<'a>(metadata: &'a Metadata, key: &str) -> impl Iterator<Item = &'a str> {
+    metadata
+        .get(key)
+        .and_then(|comments| comments.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|comment| comment.as_str())
+}
+
+fn print_comments<'a>(out: &mut String, comments: impl Iterator<Item = &'a str>, depth: usize) {
+    for comment in comments {
+        out.push_str(&INDENT.repeat(depth));
+        out.push_str("//");
+        if !comment.is_empty() {
+            out.push(' ');
+            out.push_str(comment);
+        }
+        out.push('\n');
+    }
+}
+
+fn comparison_operator(comparison: &ast::ComparisonType) -> &'static str {
+    match comparison {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+fn value(value: &ast::ValueType) -> String {
+    match value {
+        ast::ValueType::Number(number) => number.to_string(),
+        ast::ValueType::EnumVariant(variant) => variant.clone(),
+        ast::ValueType::MetadataVariant(metadata) => quote(&metadata.value),
+        ast::ValueType::StrValue(string) => quote(string),
+        ast::ValueType::NumberArray(numbers) => {
+            let numbers: Vec<String> = numbers.iter().map(ToString::to_string).collect();
+            format!("({})", numbers.join(", "))
+        }
+        ast::ValueType::EnumVariantArray(variants) => format!("({})", variants.join(", ")),
+        ast::ValueType::NumberComparisonArray(comparisons) => {
+            let comparisons: Vec<String> = comparisons
+                .iter()
+                .map(|comparison| {
+                    format!(
+                        "{}{}",
+                        comparison_operator(&comparison.comparison_type),
+                        comparison.number
+                    )
+                })
+                .collect();
+            format!("({})", comparisons.join(", "))
+        }
+    }
+}
+
+pub fn comparison(comparison: &ast::Comparison) -> String {
+    let lhs = match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) => quote(&metadata.key),
+        _ => comparison.lhs.clone(),
+    };
+
+    format!(
+        "{lhs} {} {}",
+        comparison_operator(&comparison.comparison),
+        value(&comparison.value)
+    )
+}
+
+fn if_statement(out: &mut String, statement: &ast::IfStatement, depth: usize) {
+    if let Some(first) = statement.condition.first() {
+        print_comments(
+            out,
+            get_comments(&first.metadata, ast::COMMENTS_METADATA_KEY),
+            depth,
+        );
+    }
+
+    let condition: Vec<String> = statement.condition.iter().map(comparison).collect();
+    out.push_str(&INDENT.repeat(depth));
+    out.push_str(&condition.join(" & "));
+
+    if let Some(nested) = &statement.nested {
+        out.push_str(" {\n");
+        for nested_statement in nested {
+            if_statement(out, nested_statement, depth + 1);
+        }
+        out.push_str(&INDENT.repeat(depth));
+        out.push('}');
+    }
+    out.push('\n');
+}
+
+pub fn rule<O: EuclidPrintable>(out: &mut String, rule: &ast::Rule<O>) {
+    // Writing into a `String` never fails
+    let _ = writeln!(
+        out,
+        "{}: {}",
+        rule.name,
+        rule.connector_selection.print_output()
+    );
+    out.push_str("{\n");
+    for statement in &rule.statements {
+        if_statement(out, statement, 1);
+    }
+    out.push_str("}\n");
+}
+
+/// Prints the program in its canonical text form.
+pub fn program<O: EuclidPrintable>(program: &ast::Program<O>) -> String {
+    let mut out = String::new();
+    print_comments(
+        &mut out,
+        get_comments(&program.metadata, ast::COMMENTS_METADATA_KEY),
+        0,
+    );
+    let _ = writeln!(out, "default: {}", program.default_selection.print_output());
+
+    let rule_comments = program
+        .metadata
+        .get(ast::RULE_COMMENTS_METADATA_KEY)
+        .and_then(|comments| comments.as_array());
+
+    for (idx, program_rule) in program.rules.iter().enumerate() {
+        out.push('\n');
+        print_comments(
+            &mut out,
+            rule_comments
+                .and_then(|comments| comments.get(idx))
+                .and_then(|comments| comments.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|comment| comment.as_str()),
+            0,
+        );
+        rule(&mut out, program_rule);
+    }
+
+    out
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    #[test]
+    fn test_program_round_trip() {
+        let program_str = r#"
+            // Routing for the EU storefront
+            default: ["stripe", "adyen"]
+
+            // Prefer adyen for large card payments
+            large_cards: ["adyen"]
+            {
+                payment_method = card & amount >= 1000 {
+                    // Debit cards go through the cheaper route
+                    card_type = debit
+                    card_type = credit & "segment" = "premium"
+                }
+            }
+
+            wallets: ["stripe", "adyen"]
+            {
+                payment_method = (wallet, pay_later) & amount = (>500, <=10000)
+                currency /= (USD, GBP)
+            }
+        "#;
+
+        let parsed = parser::parse_program::<DummyOutput>(program_str).expect("Program");
+        let printed = program(&parsed);
+
+        let expected = r#"// Routing for the EU storefront
+default: ["stripe", "adyen"]
+
+// Prefer adyen for large card payments
+large_cards: ["adyen"]
+{
+    payment_method = card & amount >= 1000 {
+        // Debit cards go through the cheaper route
+        card_type = debit
+        card_type = credit & "segment" = "premium"
+    }
+}
+
+wallets: ["stripe", "adyen"]
+{
+    payment_method = (wallet, pay_later) & amount = (>500, <=10000)
+    currency /= (USD, GBP)
+}
+"#;
+        assert_eq!(printed, expected);
+
+        let reparsed = parser::parse_program::<DummyOutput>(&printed).expect("Printed program");
+        assert_eq!(program(&reparsed), printed);
+    }
+
+    #[test]
+    fn test_string_escapes_round_trip() {
+        let program_str = r#"default: ["stripe"]
+
+rule_1: ["adyen"]
+{
+    "say \"hi\"" = "back\\slash" & card_bin = "42\"42"
+}
+"#;
+
+        let parsed = parser::parse_program::<DummyOutput>(program_str).expect("Program");
+        let condition = &parsed.rules[0].statements[0].condition;
+        assert_eq!(
+            condition[0].value,
+            ast::ValueType::MetadataVariant(ast::MetadataValue {
+                key: r#"say "hi""#.to_string(),
+                value: r"back\slash".to_string(),
+            })
+        );
+        assert_eq!(
+            condition[1].value,
+            ast::ValueType::StrValue(r#"42"42"#.to_string())
+        );
+        assert_eq!(program(&parsed), program_str);
+    }
+
+    #[test]
+    fn test_rule_comments_follow_rule_position() {
+        let program_str = r#"default: ["stripe"]
+
+// Adyen for cards
+cards: ["adyen"]
+{
+    payment_method = card
+}
+
+cards: ["stripe"]
+{
+    currency = USD
+}
+
+// Checkout for euro cards
+cards: ["checkout"]
+{
+    currency = EUR
+}
+"#;
+
+        let parsed = parser::parse_program::<DummyOutput>(program_str).expect("Program");
+        assert_eq!(program(&parsed), program_str);
+    }
+
+    #[test]
+    fn test_parse_diagnostic_position() {
+        let program_str =
+            "default: [\"stripe\"]\n\nrule_1: [\"adyen\"]\n{\n    payment_method == card\n}\n";
+
+        let diagnostic = parser::parse_program::<DummyOutput>(program_str)
+            .expect_err("Program should not parse");
+        assert_eq!(diagnostic.line, 5);
+    }
+}
@@ -133,6 +133,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_bin")]
     CardBin,
+    #[strum(
+        serialize = "card_fingerprint",
+        detailed_message = "Keyed fingerprint identifying a payment card without its number",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_fingerprint")]
+    CardFingerprint,
     #[strum(
         serialize = "card_type",
         detailed_message = "Type of the payment card - eg. credit, debit",
@@ -333,6 +340,7 @@ impl DirKeyKind {
         match self {
             Self::PaymentMethod => types::DataType::EnumVariant,
             Self::CardBin => types::DataType::StrValue,
+            Self::CardFingerprint => types::DataType::StrValue,
             Self::CardType => types::DataType::EnumVariant,
             Self::CardNetwork => types::DataType::EnumVariant,
             Self::MetaData => types::DataType::MetadataValue,
@@ -369,6 +377,7 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::CardBin => None,
+            Self::CardFingerprint => None,
             Self::CardType => Some(enums::CardType::iter().map(DirValue::CardType).collect()),
             Self::MandateAcceptanceType => Some(
                 euclid_enums::MandateAcceptanceType::iter()
@@ -498,6 +507,8 @@ pub enum DirValue {
     PaymentMethod(enums::PaymentMethod),
     #[serde(rename = "card_bin")]
     CardBin(types::StrValue),
+    #[serde(rename = "card_fingerprint")]
+    CardFingerprint(types::StrValue),
     #[serde(rename = "card_type")]
     CardType(enums::CardType),
     #[serde(rename = "card_network")]
@@ -557,6 +568,7 @@ impl DirValue {
         let (kind, data) = match self {
             Self::PaymentMethod(_) => (DirKeyKind::PaymentMethod, None),
             Self::CardBin(_) => (DirKeyKind::CardBin, None),
+            Self::CardFingerprint(_) => (DirKeyKind::CardFingerprint, None),
             Self::RewardType(_) => (DirKeyKind::RewardType, None),
             Self::BusinessCountry(_) => (DirKeyKind::BusinessCountry, None),
             Self::BillingCountry(_) => (DirKeyKind::CardBin, None),
@@ -592,6 +604,7 @@ impl DirValue {
             Self::MetaData(val) => Some(val.clone()),
             Self::PaymentMethod(_) => None,
             Self::CardBin(_) => None,
+            Self::CardFingerprint(_) => None,
             Self::CardType(_) => None,
             Self::CardNetwork(_) => None,
             Self::PayLaterType(_) => None,
@@ -623,6 +636,7 @@ impl DirValue {
     pub fn get_str_val(&self) -> Option<types::StrValue> {
         match self {
             Self::CardBin(val) => Some(val.clone()),
+            Self::CardFingerprint(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -658,6 +672,7 @@ impl DirValue {
             (Self::RewardType(rt1), Self::RewardType(rt2)) => rt1 == rt2,
             (Self::Connector(c1), Self::Connector(c2)) => c1 == c2,
             (Self::BusinessLabel(bl1), Self::BusinessLabel(bl2)) => bl1 == bl2,
+            (Self::CardFingerprint(cf1), Self::CardFingerprint(cf2)) => cf1 == cf2,
             (Self::SetupFutureUsage(sfu1), Self::SetupFutureUsage(sfu2)) => sfu1 == sfu2,
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
@@ -31,6 +31,8 @@ pub enum EuclidKey {
     PaymentMethod,
     #[strum(serialize = "card_bin")]
     CardBin,
+    #[strum(serialize = "card_fingerprint")]
+    CardFingerprint,
     #[strum(serialize = "metadata")]
     Metadata,
     #[strum(serialize = "mandate_type")]
@@ -69,6 +71,7 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::CaptureMethod,
         DirKeyKind::AuthenticationType,
         DirKeyKind::CardBin,
+        DirKeyKind::CardFingerprint,
         DirKeyKind::PayLaterType,
         DirKeyKind::PaymentAmount,
         DirKeyKind::MetaData,
@@ -123,6 +126,7 @@ impl EuclidKey {
         match self {
             Self::PaymentMethod => DataType::EnumVariant,
             Self::CardBin => DataType::StrValue,
+            Self::CardFingerprint => DataType::StrValue,
             Self::Metadata => DataType::MetadataValue,
             Self::PaymentMethodType => DataType::EnumVariant,
             Self::CardNetwork => DataType::EnumVariant,
@@ -238,6 +242,7 @@ impl NumValue {
 pub enum EuclidValue {
     PaymentMethod(enums::PaymentMethod),
     CardBin(StrValue),
+    CardFingerprint(StrValue),
     Metadata(MetadataValue),
     PaymentMethodType(enums::PaymentMethodType),
     CardNetwork(enums::CardNetwork),
@@ -266,6 +271,7 @@ impl EuclidValue {
         match self {
             Self::PaymentMethod(_) => EuclidKey::PaymentMethod,
             Self::CardBin(_) => EuclidKey::CardBin,
+            Self::CardFingerprint(_) => EuclidKey::CardFingerprint,
             Self::Metadata(_) => EuclidKey::Metadata,
             Self::PaymentMethodType(_) => EuclidKey::PaymentMethodType,
             Self::MandateType(_) => EuclidKey::MandateType,
@@ -125,11 +125,31 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&valid_connectors)?)
 }
 
+/// Analyses a program, failing on the first error that makes the program invalid. Otherwise
+/// returns every finding of the analysis, such as the rules that can never be selected, which is
+/// empty for a program without issues.
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
-    analyzer::analyze(program, SEED_DATA.get().map(|sd| &sd.kgraph)).err_to_js()?;
-    Ok(JsValue::NULL)
+    let (_, warnings) =
+        analyzer::analyze_with_warnings(program, SEED_DATA.get().map(|sd| &sd.kgraph))
+            .err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
+}
+
+/// Prints a program in the canonical text form of the routing DSL.
+#[wasm_bindgen(js_name = formatProgram)]
+pub fn format_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    Ok(JsValue::from_str(&ast::printer::program(&program)))
+}
+
+/// Parses the text form of the routing DSL into a program. On failure, the error holds a
+/// diagnostic with the line and column where parsing failed.
+#[wasm_bindgen(js_name = parseProgram)]
+pub fn parse_program(program_text: &str) -> JsResult {
+    let program = ast::parser::parse_program::<ConnectorSelection>(program_text).err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&program)?)
 }
 
 #[wasm_bindgen(js_name = runProgram)]
@@ -202,6 +222,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
+        | dir::DirKeyKind::CardFingerprint
         | dir::DirKeyKind::BusinessLabel
         | dir::DirKeyKind::MetaData => Err("Key does not have variants".to_string())?,
         dir::DirKeyKind::BankDebitType => dir_enums::BankDebitType::VARIANTS,
@@ -0,0 +1,142 @@
+//! Interactions with card account updater providers
+
+use std::sync::Arc;
+
+use common_utils::errors::CustomResult;
+use masking::Secret;
+
+pub mod file_batch;
+
+/// A saved card submitted to the account updater provider, to be checked against the issuer's
+/// records.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AccountUpdateRequest {
+    /// Reference identifying the card in the batch, echoed back in the response
+    pub reference: String,
+
+    /// The primary account number of the card
+    pub card_number: Secret<String>,
+
+    /// The expiry month of the card
+    pub card_exp_month: Secret<String>,
+
+    /// The expiry year of the card
+    pub card_exp_year: Secret<String>,
+}
+
+/// The outcome of an account update check for a single card.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AccountUpdateResponse {
+    /// Reference of the card, as provided in the request
+    pub reference: String,
+
+    /// The update reported by the issuer
+    #[serde(flatten)]
+    pub result: AccountUpdateResult,
+}
+
+/// Updates that may be reported by the issuer for a card.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum AccountUpdateResult {
+    /// The card details are up to date
+    NoChange,
+
+    /// The card was reissued with a new expiry date
+    ExpiryUpdated {
+        /// The new expiry month of the card
+        card_exp_month: Secret<String>,
+
+        /// The new expiry year of the card
+        card_exp_year: Secret<String>,
+    },
+
+    /// The card was replaced with a new card number
+    CardNumberUpdated {
+        /// The new primary account number of the card
+        card_number: Secret<String>,
+
+        /// The expiry month of the new card
+        card_exp_month: Secret<String>,
+
+        /// The expiry year of the new card
+        card_exp_year: Secret<String>,
+    },
+
+    /// The account was closed, the card can no longer be charged
+    AccountClosed,
+}
+
+/// Trait defining the interface for submitting cards to an account updater provider and
+/// retrieving the updates reported by issuers.
+///
+/// Account updates are processed asynchronously by the providers, the results of a batch are
+/// expected to be polled for until they are available.
+#[async_trait::async_trait]
+pub trait AccountUpdaterProvider: std::fmt::Debug + Send + Sync {
+    /// Submits a batch of cards to be checked for updates.
+    async fn submit_batch(
+        &self,
+        batch_id: &str,
+        requests: &[AccountUpdateRequest],
+    ) -> CustomResult<(), AccountUpdaterError>;
+
+    /// Returns the results of a previously submitted batch, or `None` if they are not available
+    /// yet.
+    async fn fetch_batch_results(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Option<Vec<AccountUpdateResponse>>, AccountUpdaterError>;
+}
+
+/// Errors that could occur during account updater operations.
+#[derive(Debug, thiserror::Error)]
+pub enum AccountUpdaterError {
+    /// The batch identifier is not valid for the provider.
+    #[error("Invalid batch identifier")]
+    InvalidBatchId,
+
+    /// An error occurred when submitting the batch.
+    #[error("Failed to submit the account updater batch")]
+    SubmissionFailed,
+
+    /// An error occurred when retrieving the results of the batch.
+    #[error("Failed to retrieve the account updater batch results")]
+    ResultRetrievalFailed,
+}
+
+/// Configuration for the account updater provider, selected at application startup.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum AccountUpdaterConfig {
+    /// The card account updater is disabled, saved cards are never checked for updates
+    #[default]
+    Disabled,
+
+    /// Batches exchanged as files in a local directory, intended for tests and local development
+    FileBatch {
+        /// Configuration for the file batch provider
+        file_batch: file_batch::FileBatchConfig,
+    },
+}
+
+impl AccountUpdaterConfig {
+    /// Verifies that the configuration for the selected provider is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            Self::Disabled => Ok(()),
+            Self::FileBatch { file_batch } => file_batch.validate(),
+        }
+    }
+
+    /// Constructs a new client for the selected provider, if the card account updater is
+    /// enabled.
+    pub fn get_account_updater_provider(&self) -> Option<Arc<dyn AccountUpdaterProvider>> {
+        match self {
+            Self::Disabled => None,
+            Self::FileBatch { file_batch } => Some(Arc::new(
+                file_batch::FileBatchAccountUpdater::new(file_batch),
+            )),
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Account updater exchanging batches as JSON files in a local directory
+//!
+//! Submitted batches are written to `{directory}/requests/{batch_id}.json`. The results are read
+//! from `{directory}/responses/{batch_id}.json` once they have been placed there, by hand or by a
+//! job relaying the files to the actual provider.
+
+use std::path::PathBuf;
+
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{ByteSliceExt, Encode},
+};
+use error_stack::{IntoReport, ResultExt};
+
+use super::{
+    AccountUpdateRequest, AccountUpdateResponse, AccountUpdaterError, AccountUpdaterProvider,
+};
+
+const REQUESTS_DIRECTORY: &str = "requests";
+const RESPONSES_DIRECTORY: &str = "responses";
+
+/// Configuration parameters required for constructing a [`FileBatchAccountUpdater`].
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct FileBatchConfig {
+    /// The directory under which batch files are exchanged.
+    pub directory: PathBuf,
+}
+
+impl Default for FileBatchConfig {
+    fn default() -> Self {
+        Self {
+            directory: router_env::env::workspace_path().join("account_updater"),
+        }
+    }
+}
+
+impl FileBatchConfig {
+    /// Verifies that the [`FileBatchAccountUpdater`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        common_utils::fp_utils::when(self.directory.as_os_str().is_empty(), || {
+            Err("Account updater batch directory must not be empty")
+        })
+    }
+}
+
+/// Account updater provider reading and writing batch files under a directory.
+#[derive(Debug, Clone)]
+pub struct FileBatchAccountUpdater {
+    directory: PathBuf,
+}
+
+impl FileBatchAccountUpdater {
+    /// Constructs a new file batch account updater.
+    pub fn new(config: &FileBatchConfig) -> Self {
+        Self {
+            directory: config.directory.clone(),
+        }
+    }
+
+    /// Resolves the path of the batch file in the given subdirectory, rejecting batch identifiers
+    /// that could escape it.
+    fn get_batch_file_path(
+        &self,
+        subdirectory: &str,
+        batch_id: &str,
+    ) -> CustomResult<PathBuf, AccountUpdaterError> {
+        let is_valid = !batch_id.is_empty()
+            && batch_id
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || "_-".contains(character));
+
+        if !is_valid {
+            return Err(AccountUpdaterError::InvalidBatchId)
+                .into_report()
+                .attach_printable_lazy(|| format!("Invalid batch identifier: {batch_id}"));
+        }
+
+        Ok(self
+            .directory
+            .join(subdirectory)
+            .join(format!("{batch_id}.json")))
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterProvider for FileBatchAccountUpdater {
+    async fn submit_batch(
+        &self,
+        batch_id: &str,
+        requests: &[AccountUpdateRequest],
+    ) -> CustomResult<(), AccountUpdaterError> {
+        let file_path = self.get_batch_file_path(REQUESTS_DIRECTORY, batch_id)?;
+        let contents = Encode::<Vec<AccountUpdateRequest>>::encode_to_vec(&requests)
+            .change_context(AccountUpdaterError::SubmissionFailed)
+            .attach_printable("Failed to serialize the account updater batch")?;
+
+        if let Some(parent) = file_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .into_report()
+                .change_context(AccountUpdaterError::SubmissionFailed)
+                .attach_printable("Failed to create the batch directory")?;
+        }
+
+        tokio::fs::write(file_path, contents)
+            .await
+            .into_report()
+            .change_context(AccountUpdaterError::SubmissionFailed)
+            .attach_printable("Failed to write the batch file")
+    }
+
+    async fn fetch_batch_results(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Option<Vec<AccountUpdateResponse>>, AccountUpdaterError> {
+        let file_path = self.get_batch_file_path(RESPONSES_DIRECTORY, batch_id)?;
+
+        let contents = match tokio::fs::read(file_path).await {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(error)
+                    .into_report()
+                    .change_context(AccountUpdaterError::ResultRetrievalFailed)
+                    .attach_printable("Failed to read the batch results file")
+            }
+        };
+
+        contents
+            .parse_struct::<Vec<AccountUpdateResponse>>("Vec<AccountUpdateResponse>")
+            .change_context(AccountUpdaterError::ResultRetrievalFailed)
+            .map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use masking::{ExposeInterface, Secret};
+
+    use super::*;
+    use crate::account_updater::AccountUpdateResult;
+
+    fn get_client() -> FileBatchAccountUpdater {
+        FileBatchAccountUpdater::new(&FileBatchConfig {
+            directory: std::env::temp_dir().join(common_utils::generate_id_with_default_len(
+                "account_updater",
+            )),
+        })
+    }
+
+    #[test]
+    fn test_batch_id_cannot_escape_directory() {
+        let client = get_client();
+        assert!(client
+            .get_batch_file_path(REQUESTS_DIRECTORY, "batch_1")
+            .is_ok());
+        assert!(client
+            .get_batch_file_path(REQUESTS_DIRECTORY, "../batch_1")
+            .is_err());
+        assert!(client.get_batch_file_path(REQUESTS_DIRECTORY, "").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_batch_round_trip() {
+        let client = get_client();
+        let requests = vec![AccountUpdateRequest {
+            reference: "pm_1".to_string(),
+            card_number: Secret::new("4111111111111111".to_string()),
+            card_exp_month: Secret::new("03".to_string()),
+            card_exp_year: Secret::new("2024".to_string()),
+        }];
+
+        client.submit_batch("batch_1", &requests).await.unwrap();
+        assert!(client
+            .fetch_batch_results("batch_1")
+            .await
+            .unwrap()
+            .is_none());
+
+        let responses = br#"[
+            {"reference": "pm_1", "result": "expiry_updated", "card_exp_month": "03", "card_exp_year": "2028"},
+            {"reference": "pm_2", "result": "account_closed"}
+        ]"#;
+        let responses_path = client
+            .get_batch_file_path(RESPONSES_DIRECTORY, "batch_1")
+            .unwrap();
+        tokio::fs::create_dir_all(responses_path.parent().unwrap())
+            .await
+            .unwrap();
+        tokio::fs::write(&responses_path, responses).await.unwrap();
+
+        let results = client
+            .fetch_batch_results("batch_1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            &results[0].result,
+            AccountUpdateResult::ExpiryUpdated { card_exp_year, .. }
+                if card_exp_year.clone().expose() == "2028"
+        ));
+        assert!(matches!(
+            results[1].result,
+            AccountUpdateResult::AccountClosed
+        ));
+
+        tokio::fs::remove_dir_all(&client.directory).await.unwrap();
+    }
+}
@@ -1,30 +1,48 @@
-//! Interactions with the AWS SES SDK
-
-use aws_config::meta::region::RegionProviderChain;
-use aws_sdk_sesv2::{
-    config::Region,
-    operation::send_email::SendEmailError,
-    types::{Body, Content, Destination, EmailContent, Message},
-    Client,
-};
+//! Interactions with the email delivery backends
+
+use std::sync::Arc;
+
 use common_utils::{errors::CustomResult, pii};
-use error_stack::{IntoReport, ResultExt};
-use masking::PeekInterface;
 use serde::Deserialize;
 
+pub mod file_sink;
+pub mod ses;
+pub mod smtp;
+
+pub use self::ses::{AwsSes, AwsSesError};
+
 /// Custom Result type alias for Email operations.
 pub type EmailResult<T> = CustomResult<T, EmailError>;
 
+/// The contents of an email, with an optional HTML alternative of the plain text body.
+#[derive(Debug, Clone)]
+pub struct EmailContents {
+    /// The subject of the email.
+    pub subject: String,
+
+    /// The plain text body of the email.
+    pub text_body: String,
+
+    /// The HTML body of the email, if any.
+    pub html_body: Option<String>,
+}
+
+impl EmailContents {
+    /// Constructs the contents of a plain text email.
+    pub fn plain_text(subject: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            text_body: body.into(),
+            html_body: None,
+        }
+    }
+}
+
 /// A trait that defines the methods that must be implemented to send email.
 #[async_trait::async_trait]
 pub trait EmailClient: Sync + Send + dyn_clone::DynClone {
-    /// Sends an email to the specified recipient with the given subject and body.
-    async fn send_email(
-        &self,
-        recipient: pii::Email,
-        subject: String,
-        body: String,
-    ) -> EmailResult<()>;
+    /// Sends an email with the given contents to the specified recipient.
+    async fn send_email(&self, recipient: pii::Email, contents: EmailContents) -> EmailResult<()>;
 }
 
 dyn_clone::clone_trait_object!(EmailClient);
@@ -35,70 +53,83 @@ pub struct EmailSettings {
     /// Sender email.
     pub from_email: String,
 
-    /// The AWS region to send SES requests to.
-    pub aws_region: String,
-
     /// Base-url used when adding links that should redirect to self
     pub base_url: String,
+
+    /// Configuration for the email client used to deliver emails.
+    #[serde(flatten)]
+    pub client_config: EmailClientConfig,
 }
 
-/// Client for AWS SES operation
-#[derive(Debug, Clone)]
-pub struct AwsSes {
-    ses_client: Client,
-    from_email: String,
+impl EmailSettings {
+    /// Verifies that the email settings are usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.from_email.is_default_or_empty(), || {
+            Err("email sender address must not be empty")
+        })?;
+
+        self.client_config.validate()
+    }
+
+    /// Constructs a new client for the selected email backend.
+    pub async fn get_email_client(&self) -> EmailResult<Arc<dyn EmailClient>> {
+        Ok(match &self.client_config {
+            EmailClientConfig::Ses { aws_ses } => {
+                Arc::new(AwsSes::new(aws_ses, self.from_email.clone()).await)
+            }
+            EmailClientConfig::Smtp { smtp } => {
+                Arc::new(smtp::SmtpServer::new(smtp, &self.from_email)?)
+            }
+            EmailClientConfig::FileSink { file_sink } => {
+                Arc::new(file_sink::FileSinkEmailClient::new(file_sink))
+            }
+        })
+    }
 }
 
-impl AwsSes {
-    /// Constructs a new AwsSes client
-    pub async fn new(conf: &EmailSettings) -> Self {
-        let region_provider = RegionProviderChain::first_try(Region::new(conf.aws_region.clone()));
-        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+/// Configuration for the email backend, selected at application startup.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "active_email_client", rename_all = "snake_case")]
+pub enum EmailClientConfig {
+    /// AWS SES
+    Ses {
+        /// Configuration for the AWS SES backend
+        aws_ses: ses::SesConfig,
+    },
 
-        Self {
-            ses_client: Client::new(&sdk_config),
-            from_email: conf.from_email.clone(),
+    /// An SMTP server
+    Smtp {
+        /// Configuration for the SMTP backend
+        smtp: smtp::SmtpServerConfig,
+    },
+
+    /// Emails are not delivered, and are optionally written to a local directory instead.
+    /// Intended for tests and local development only.
+    FileSink {
+        /// Configuration for the file sink backend
+        #[serde(default)]
+        file_sink: file_sink::FileSinkConfig,
+    },
+}
+
+impl Default for EmailClientConfig {
+    fn default() -> Self {
+        Self::FileSink {
+            file_sink: file_sink::FileSinkConfig::default(),
         }
     }
 }
 
-#[async_trait::async_trait]
-impl EmailClient for AwsSes {
-    async fn send_email(
-        &self,
-        recipient: pii::Email,
-        subject: String,
-        body: String,
-    ) -> EmailResult<()> {
-        self.ses_client
-            .send_email()
-            .from_email_address(self.from_email.to_owned())
-            .destination(
-                Destination::builder()
-                    .to_addresses(recipient.peek())
-                    .build(),
-            )
-            .content(
-                EmailContent::builder()
-                    .simple(
-                        Message::builder()
-                            .subject(Content::builder().data(subject).build())
-                            .body(
-                                Body::builder()
-                                    .text(Content::builder().data(body).charset("UTF-8").build())
-                                    .build(),
-                            )
-                            .build(),
-                    )
-                    .build(),
-            )
-            .send()
-            .await
-            .map_err(AwsSesError::SendingFailure)
-            .into_report()
-            .change_context(EmailError::EmailSendingFailure)?;
-
-        Ok(())
+impl EmailClientConfig {
+    /// Verifies that the configuration for the selected backend is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            Self::Ses { aws_ses } => aws_ses.validate(),
+            Self::Smtp { smtp } => smtp.validate(),
+            Self::FileSink { .. } => Ok(()),
+        }
     }
 }
 
@@ -116,12 +147,10 @@ pub enum EmailError {
     TokenGenerationFailure,
     #[error("Feature not implemented")]
     NotImplemented,
-}
-
-/// Errors that could occur during SES operations.
-#[derive(Debug, thiserror::Error)]
-pub enum AwsSesError {
-    /// An error occurred in the SDK while sending email.
-    #[error("Failed to Send Email {0:?}")]
-    SendingFailure(aws_smithy_client::SdkError<SendEmailError>),
+    /// The email address of the sender or the recipient is not valid.
+    #[error("Invalid email address")]
+    InvalidEmailAddress,
+    /// An error occurred when rendering the email contents from a template.
+    #[error("Failed to render the email template")]
+    TemplateRenderingFailure,
 }
@@ -0,0 +1,120 @@
+//! Email "delivery" that never sends emails, intended for tests and local development only
+
+use std::path::PathBuf;
+
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::logger;
+use serde::Deserialize;
+
+use super::{EmailClient, EmailContents, EmailError, EmailResult};
+
+/// Configuration parameters required for constructing a [`FileSinkEmailClient`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct FileSinkConfig {
+    /// The directory into which emails are written, one file per email. If not specified, emails
+    /// are only logged and then discarded.
+    pub directory: Option<PathBuf>,
+}
+
+/// Client that writes emails to a local directory instead of delivering them.
+#[derive(Debug, Clone)]
+pub struct FileSinkEmailClient {
+    directory: Option<PathBuf>,
+}
+
+impl FileSinkEmailClient {
+    /// Constructs a new file sink email client.
+    pub fn new(conf: &FileSinkConfig) -> Self {
+        Self {
+            directory: conf.directory.clone(),
+        }
+    }
+
+    fn format_email(recipient: &pii::Email, contents: &EmailContents) -> String {
+        let mut email = format!(
+            "To: {}\nSubject: {}\n\n{}\n",
+            recipient.peek(),
+            contents.subject,
+            contents.text_body
+        );
+
+        if let Some(html_body) = &contents.html_body {
+            email.push_str("\n--- HTML ---\n\n");
+            email.push_str(html_body);
+            email.push('\n');
+        }
+
+        email
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for FileSinkEmailClient {
+    async fn send_email(&self, recipient: pii::Email, contents: EmailContents) -> EmailResult<()> {
+        let directory = match &self.directory {
+            Some(directory) => directory,
+            None => {
+                logger::info!(
+                    ?recipient,
+                    subject = %contents.subject,
+                    "Discarding email as no email sink directory is configured"
+                );
+                return Ok(());
+            }
+        };
+
+        tokio::fs::create_dir_all(directory)
+            .await
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)
+            .attach_printable("Failed to create email sink directory")?;
+
+        let file_path = directory.join(format!("{}.eml", common_utils::generate_id(32, "email")));
+
+        tokio::fs::write(&file_path, Self::format_email(&recipient, &contents))
+            .await
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)
+            .attach_printable("Failed to write email into the sink directory")?;
+
+        logger::info!(
+            ?recipient,
+            path = %file_path.display(),
+            "Email written to the sink directory"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_email_is_written_into_directory() {
+        let directory = std::env::temp_dir().join(common_utils::generate_id(16, "email_sink"));
+        let client = FileSinkEmailClient::new(&FileSinkConfig {
+            directory: Some(directory.clone()),
+        });
+        let recipient = pii::Email::try_from("user@example.com".to_string()).unwrap();
+
+        client
+            .send_email(recipient, EmailContents::plain_text("Hello", "Hello there"))
+            .await
+            .unwrap();
+
+        let mut entries = std::fs::read_dir(&directory).unwrap();
+        let contents = std::fs::read_to_string(entries.next().unwrap().unwrap().path()).unwrap();
+        assert!(contents.contains("To: user@example.com"));
+        assert!(contents.contains("Subject: Hello"));
+        assert!(contents.contains("Hello there"));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+//! Email delivery through AWS SES
+
+use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_sesv2::{
+    config::Region,
+    operation::send_email::SendEmailError,
+    types::{Body, Content, Destination, EmailContent, Message},
+    Client,
+};
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use serde::Deserialize;
+
+use super::{EmailClient, EmailContents, EmailError, EmailResult};
+
+/// Configuration parameters required for constructing an [`AwsSes`] client.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SesConfig {
+    /// The AWS region to send SES requests to.
+    pub aws_region: String,
+}
+
+impl SesConfig {
+    /// Verifies that the [`AwsSes`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.aws_region.is_default_or_empty(), || {
+            Err("AWS SES region must not be empty")
+        })
+    }
+}
+
+/// Client for AWS SES operation
+#[derive(Debug, Clone)]
+pub struct AwsSes {
+    ses_client: Client,
+    from_email: String,
+}
+
+impl AwsSes {
+    /// Constructs a new AwsSes client
+    pub async fn new(conf: &SesConfig, from_email: String) -> Self {
+        let region_provider = RegionProviderChain::first_try(Region::new(conf.aws_region.clone()));
+        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+
+        Self {
+            ses_client: Client::new(&sdk_config),
+            from_email,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for AwsSes {
+    async fn send_email(&self, recipient: pii::Email, contents: EmailContents) -> EmailResult<()> {
+        let body = Body::builder().text(
+            Content::builder()
+                .data(contents.text_body)
+                .charset("UTF-8")
+                .build(),
+        );
+        let body = match contents.html_body {
+            Some(html_body) => {
+                body.html(Content::builder().data(html_body).charset("UTF-8").build())
+            }
+            None => body,
+        };
+
+        self.ses_client
+            .send_email()
+            .from_email_address(self.from_email.to_owned())
+            .destination(
+                Destination::builder()
+                    .to_addresses(recipient.peek())
+                    .build(),
+            )
+            .content(
+                EmailContent::builder()
+                    .simple(
+                        Message::builder()
+                            .subject(Content::builder().data(contents.subject).build())
+                            .body(body.build())
+                            .build(),
+                    )
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(AwsSesError::SendingFailure)
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)?;
+
+        Ok(())
+    }
+}
+
+/// Errors that could occur during SES operations.
+#[derive(Debug, thiserror::Error)]
+pub enum AwsSesError {
+    /// An error occurred in the SDK while sending email.
+    #[error("Failed to Send Email {0:?}")]
+    SendingFailure(aws_smithy_client::SdkError<SendEmailError>),
+}
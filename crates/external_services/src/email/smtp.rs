@@ -0,0 +1,220 @@
+//! Email delivery through an SMTP server
+
+use std::time::Duration;
+
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use lettre::{
+    address::Address,
+    message::{header::ContentType, Mailbox, Message, MultiPart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
+};
+use masking::{PeekInterface, Secret};
+use serde::Deserialize;
+
+use super::{EmailClient, EmailContents, EmailError, EmailResult};
+
+/// The security of the connection to the SMTP server.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpConnection {
+    /// The connection is upgraded to TLS using `STARTTLS`.
+    #[default]
+    StartTls,
+
+    /// The connection is established over TLS.
+    Tls,
+
+    /// The connection is not encrypted. Intended for local test SMTP servers only.
+    Plaintext,
+}
+
+/// Configuration parameters required for constructing an [`SmtpServer`] client.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SmtpServerConfig {
+    /// The hostname of the SMTP server.
+    pub host: String,
+
+    /// The port of the SMTP server.
+    pub port: u16,
+
+    /// The timeout for SMTP operations, in seconds.
+    pub timeout: u64,
+
+    /// The security of the connection to the SMTP server.
+    pub connection: SmtpConnection,
+
+    /// The username used to authenticate with the SMTP server, if authentication is required.
+    pub username: Option<Secret<String>>,
+
+    /// The password used to authenticate with the SMTP server, if authentication is required.
+    pub password: Option<Secret<String>>,
+}
+
+impl Default for SmtpServerConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 587,
+            timeout: 10,
+            connection: SmtpConnection::default(),
+            username: None,
+            password: None,
+        }
+    }
+}
+
+impl SmtpServerConfig {
+    /// Verifies that the [`SmtpServer`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.host.is_default_or_empty(), || {
+            Err("SMTP server host must not be empty")
+        })?;
+
+        when(self.port == 0, || Err("SMTP server port must not be zero"))?;
+
+        when(self.username.is_some() != self.password.is_some(), || {
+            Err("SMTP username and password must either be both specified or both omitted")
+        })
+    }
+}
+
+/// Client for delivering emails through an SMTP server.
+#[derive(Clone)]
+pub struct SmtpServer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    sender: Mailbox,
+}
+
+impl SmtpServer {
+    /// Constructs a new SMTP client. The connection to the server is established lazily, when
+    /// sending the first email.
+    pub fn new(conf: &SmtpServerConfig, from_email: &str) -> EmailResult<Self> {
+        let builder = match conf.connection {
+            SmtpConnection::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&conf.host)
+                    .into_report()
+                    .change_context(EmailError::ClientBuildingFailure)?
+            }
+            SmtpConnection::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&conf.host)
+                .into_report()
+                .change_context(EmailError::ClientBuildingFailure)?,
+            SmtpConnection::Plaintext => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&conf.host)
+            }
+        }
+        .port(conf.port)
+        .timeout(Some(Duration::from_secs(conf.timeout)));
+
+        let builder = match (&conf.username, &conf.password) {
+            (Some(username), Some(password)) => builder.credentials(Credentials::new(
+                username.peek().to_owned(),
+                password.peek().to_owned(),
+            )),
+            _ => builder,
+        };
+
+        let sender = from_email
+            .parse::<Address>()
+            .into_report()
+            .change_context(EmailError::InvalidEmailAddress)
+            .attach_printable("Invalid sender email address")?;
+
+        Ok(Self {
+            transport: builder.build(),
+            sender: Mailbox::new(None, sender),
+        })
+    }
+
+    fn build_message(
+        &self,
+        recipient: &pii::Email,
+        contents: EmailContents,
+    ) -> EmailResult<Message> {
+        let recipient = recipient
+            .peek()
+            .parse::<Address>()
+            .into_report()
+            .change_context(EmailError::InvalidEmailAddress)
+            .attach_printable("Invalid recipient email address")?;
+
+        let builder = Message::builder()
+            .from(self.sender.clone())
+            .to(Mailbox::new(None, recipient))
+            .subject(contents.subject);
+
+        match contents.html_body {
+            Some(html_body) => builder.multipart(MultiPart::alternative_plain_html(
+                contents.text_body,
+                html_body,
+            )),
+            None => builder
+                .header(ContentType::TEXT_PLAIN)
+                .body(contents.text_body),
+        }
+        .into_report()
+        .change_context(EmailError::EmailSendingFailure)
+        .attach_printable("Failed to build email message")
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for SmtpServer {
+    async fn send_email(&self, recipient: pii::Email, contents: EmailContents) -> EmailResult<()> {
+        let message = self.build_message(&recipient, contents)?;
+
+        self.transport
+            .send(message)
+            .await
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)
+            .attach_printable("Failed to send email through the SMTP server")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_client() -> SmtpServer {
+        SmtpServer::new(
+            &SmtpServerConfig {
+                connection: SmtpConnection::Plaintext,
+                ..Default::default()
+            },
+            "notify@example.com",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_message_with_html_alternative() {
+        let contents = EmailContents {
+            subject: "Verify your email".to_string(),
+            text_body: "plain body".to_string(),
+            html_body: Some("<p>html body</p>".to_string()),
+        };
+        let recipient = pii::Email::try_from("user@example.com".to_string()).unwrap();
+
+        let message = get_client().build_message(&recipient, contents).unwrap();
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+
+        assert!(formatted.contains("Subject: Verify your email"));
+        assert!(formatted.contains("multipart/alternative"));
+        assert!(formatted.contains("plain body"));
+        assert!(formatted.contains("<p>html body</p>"));
+    }
+
+    #[test]
+    fn test_invalid_sender_is_rejected() {
+        assert!(SmtpServer::new(&SmtpServerConfig::default(), "not-an-email").is_err());
+    }
+}
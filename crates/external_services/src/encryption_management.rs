@@ -0,0 +1,261 @@
+//! Interactions with the encryption management services
+
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+
+#[cfg(feature = "hashicorp-vault")]
+use crate::hashicorp_vault::{HashiCorpVault, HashiCorpVaultConfig};
+#[cfg(feature = "kms")]
+use crate::kms::{KmsClient, KmsConfig};
+#[cfg(feature = "local-keyfile")]
+use crate::local_keyfile::{LocalKeyfileClient, LocalKeyfileConfig};
+use crate::{
+    no_encryption::NoEncryption,
+    secrets_management::{SecretManagementInterface, SecretsManagementError},
+};
+
+static ENCRYPTION_MANAGEMENT_CLIENT: tokio::sync::OnceCell<EncryptionManagementClient> =
+    tokio::sync::OnceCell::const_new();
+
+/// Returns the shared encryption management client, or initializes a new one for the configured
+/// backend if not previously initialized.
+#[inline]
+pub async fn get_encryption_management_client(
+    config: &EncryptionManagementConfig,
+) -> CustomResult<&'static EncryptionManagementClient, EncryptionError> {
+    ENCRYPTION_MANAGEMENT_CLIENT
+        .get_or_try_init(|| config.get_encryption_management_client())
+        .await
+}
+
+/// Returns the shared encryption management client, if it has already been initialized.
+#[inline]
+pub fn try_get_encryption_management_client(
+) -> CustomResult<&'static EncryptionManagementClient, EncryptionError> {
+    ENCRYPTION_MANAGEMENT_CLIENT
+        .get()
+        .ok_or(EncryptionError::ClientNotInitialized)
+        .into_report()
+}
+
+/// Trait defining the interface for encrypting and decrypting data using an encryption
+/// management service.
+#[async_trait::async_trait]
+pub trait EncryptionManagementInterface: Send + Sync {
+    /// Encrypts the given plaintext.
+    async fn encrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError>;
+
+    /// Decrypts the given ciphertext.
+    async fn decrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError>;
+}
+
+/// Errors that could occur during encryption management operations.
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    /// An error occurred when initializing the encryption management client.
+    #[error("Failed to initialize the encryption management client")]
+    ClientInitializationFailed,
+
+    /// An error occurred when encrypting input data.
+    #[error("Failed to encrypt input data")]
+    EncryptionFailed,
+
+    /// An error occurred when decrypting input data.
+    #[error("Failed to decrypt input data")]
+    DecryptionFailed,
+
+    /// The encryption management client has not been initialized.
+    #[error("The encryption management client has not been initialized")]
+    ClientNotInitialized,
+}
+
+/// Configuration for the encryption management backend, selected at application startup.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(tag = "encryption_manager", rename_all = "snake_case")]
+pub enum EncryptionManagementConfig {
+    /// AWS KMS
+    #[cfg(feature = "kms")]
+    AwsKms {
+        /// Configuration for the AWS KMS client
+        aws_kms: KmsConfig,
+    },
+
+    /// HashiCorp Vault, using the transit secrets engine
+    #[cfg(feature = "hashicorp-vault")]
+    HashicorpVault {
+        /// Configuration for the HashiCorp Vault client
+        hc_vault: HashiCorpVaultConfig,
+    },
+
+    /// AES-256-GCM using a key read from a local file
+    #[cfg(feature = "local-keyfile")]
+    LocalKeyfile {
+        /// Configuration for the local keyfile client
+        local_keyfile: LocalKeyfileConfig,
+    },
+
+    /// Values are used as is, without any encryption
+    #[default]
+    NoEncryption,
+}
+
+impl EncryptionManagementConfig {
+    /// Verifies that the configuration for the selected backend is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            #[cfg(feature = "kms")]
+            Self::AwsKms { aws_kms } => aws_kms.validate(),
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashicorpVault { hc_vault } => hc_vault.validate(),
+            #[cfg(feature = "local-keyfile")]
+            Self::LocalKeyfile { local_keyfile } => local_keyfile.validate(),
+            Self::NoEncryption => Ok(()),
+        }
+    }
+
+    /// Constructs a new client for the selected backend.
+    pub async fn get_encryption_management_client(
+        &self,
+    ) -> CustomResult<EncryptionManagementClient, EncryptionError> {
+        Ok(match self {
+            #[cfg(feature = "kms")]
+            Self::AwsKms { aws_kms } => {
+                EncryptionManagementClient::AwsKms(KmsClient::new(aws_kms).await)
+            }
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashicorpVault { hc_vault } => EncryptionManagementClient::HashicorpVault(
+                HashiCorpVault::new(hc_vault)
+                    .change_context(EncryptionError::ClientInitializationFailed)?,
+            ),
+            #[cfg(feature = "local-keyfile")]
+            Self::LocalKeyfile { local_keyfile } => EncryptionManagementClient::LocalKeyfile(
+                LocalKeyfileClient::new(local_keyfile)
+                    .change_context(EncryptionError::ClientInitializationFailed)?,
+            ),
+            Self::NoEncryption => EncryptionManagementClient::NoEncryption(NoEncryption),
+        })
+    }
+}
+
+/// The `[kms]` configuration section, superseded by `[encryption_management]`.
+///
+/// It is still accepted so that existing deployments keep decrypting their secrets with AWS KMS,
+/// see [`EncryptionManagementConfig::with_legacy_kms_config`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LegacyKmsConfig {
+    /// The AWS key identifier of the KMS key used to decrypt data.
+    pub key_id: String,
+
+    /// The AWS region to send KMS requests to.
+    pub region: String,
+}
+
+impl EncryptionManagementConfig {
+    /// Selects the AWS KMS backend when only the legacy `[kms]` section is configured.
+    ///
+    /// Configuring both sections, or the legacy section without AWS KMS support, is an error
+    /// rather than a silent fallback to [`EncryptionManagementConfig::NoEncryption`].
+    pub fn with_legacy_kms_config(
+        self,
+        legacy_kms_config: Option<LegacyKmsConfig>,
+    ) -> Result<Self, &'static str> {
+        match (self, legacy_kms_config) {
+            (config, None) => Ok(config),
+            #[cfg(feature = "kms")]
+            (Self::NoEncryption, Some(LegacyKmsConfig { key_id, region })) => Ok(Self::AwsKms {
+                aws_kms: KmsConfig { key_id, region },
+            }),
+            #[cfg(not(feature = "kms"))]
+            (Self::NoEncryption, Some(_)) => Err(
+                "The `[kms]` section requires the `kms` feature, configure `[encryption_management]` instead",
+            ),
+            (_, Some(_)) => Err(
+                "Both `[kms]` and `[encryption_management]` are configured, move the `[kms]` values to `[encryption_management.aws_kms]`",
+            ),
+        }
+    }
+}
+
+/// Client for the configured encryption management backend.
+#[derive(Debug)]
+pub enum EncryptionManagementClient {
+    /// AWS KMS client
+    #[cfg(feature = "kms")]
+    AwsKms(KmsClient),
+
+    /// HashiCorp Vault client
+    #[cfg(feature = "hashicorp-vault")]
+    HashicorpVault(HashiCorpVault),
+
+    /// Local keyfile client
+    #[cfg(feature = "local-keyfile")]
+    LocalKeyfile(LocalKeyfileClient),
+
+    /// Pass-through client
+    NoEncryption(NoEncryption),
+}
+
+#[async_trait::async_trait]
+impl EncryptionManagementInterface for EncryptionManagementClient {
+    async fn encrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        match self {
+            #[cfg(feature = "kms")]
+            Self::AwsKms(client) => EncryptionManagementInterface::encrypt(client, input).await,
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashicorpVault(client) => {
+                EncryptionManagementInterface::encrypt(client, input).await
+            }
+            #[cfg(feature = "local-keyfile")]
+            Self::LocalKeyfile(client) => {
+                EncryptionManagementInterface::encrypt(client, input).await
+            }
+            Self::NoEncryption(client) => {
+                EncryptionManagementInterface::encrypt(client, input).await
+            }
+        }
+    }
+
+    async fn decrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        match self {
+            #[cfg(feature = "kms")]
+            Self::AwsKms(client) => EncryptionManagementInterface::decrypt(client, input).await,
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashicorpVault(client) => {
+                EncryptionManagementInterface::decrypt(client, input).await
+            }
+            #[cfg(feature = "local-keyfile")]
+            Self::LocalKeyfile(client) => {
+                EncryptionManagementInterface::decrypt(client, input).await
+            }
+            Self::NoEncryption(client) => {
+                EncryptionManagementInterface::decrypt(client, input).await
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for EncryptionManagementClient {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        match self {
+            #[cfg(feature = "kms")]
+            Self::AwsKms(client) => SecretManagementInterface::get_secret(client, input).await,
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashicorpVault(client) => {
+                SecretManagementInterface::get_secret(client, input).await
+            }
+            #[cfg(feature = "local-keyfile")]
+            Self::LocalKeyfile(client) => {
+                SecretManagementInterface::get_secret(client, input).await
+            }
+            Self::NoEncryption(client) => {
+                SecretManagementInterface::get_secret(client, input).await
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Interactions with file storage backends
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use common_utils::errors::CustomResult;
+use futures::{stream::BoxStream, TryStreamExt};
+
+#[cfg(feature = "aws_s3")]
+pub mod aws_s3;
+pub mod file_system;
+pub mod memory;
+
+/// A stream of file contents, yielded in chunks.
+pub type FileStream = BoxStream<'static, CustomResult<Bytes, FileStorageError>>;
+
+/// Trait defining the interface for storing, retrieving and deleting files.
+///
+/// File keys are opaque to the backends, and are expected to be namespaced by the caller (for
+/// example, `{merchant_id}/{file_id}`).
+#[async_trait::async_trait]
+pub trait FileStorageInterface: std::fmt::Debug + Send + Sync {
+    /// Uploads the file contents read from the provided stream, under the given key.
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: FileStream,
+    ) -> CustomResult<(), FileStorageError>;
+
+    /// Deletes the file stored under the given key.
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError>;
+
+    /// Returns a stream of the contents of the file stored under the given key.
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<FileStream, FileStorageError>;
+}
+
+/// Collects all the chunks of a [`FileStream`] into a single buffer.
+pub async fn collect_file_stream(file: FileStream) -> CustomResult<Vec<u8>, FileStorageError> {
+    file.try_fold(Vec::new(), |mut buffer, chunk| async move {
+        buffer.extend_from_slice(&chunk);
+        Ok(buffer)
+    })
+    .await
+}
+
+/// Errors that could occur during file storage operations.
+#[derive(Debug, thiserror::Error)]
+pub enum FileStorageError {
+    /// The file key is not valid for the backend.
+    #[error("Invalid file key")]
+    InvalidFileKey,
+
+    /// An error occurred when uploading the file.
+    #[error("Failed to upload the file")]
+    UploadFailed,
+
+    /// An error occurred when retrieving the file.
+    #[error("Failed to retrieve the file")]
+    RetrieveFailed,
+
+    /// An error occurred when deleting the file.
+    #[error("Failed to delete the file")]
+    DeleteFailed,
+
+    /// No file exists under the given key.
+    #[error("File not found")]
+    FileNotFound,
+}
+
+/// Configuration for the file storage backend, selected at application startup.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(tag = "file_storage_backend", rename_all = "snake_case")]
+pub enum FileStorageConfig {
+    /// AWS S3
+    #[cfg(feature = "aws_s3")]
+    AwsS3 {
+        /// Configuration for the AWS S3 backend
+        aws_s3: aws_s3::AwsFileStorageConfig,
+    },
+
+    /// A directory on the local file system
+    FileSystem {
+        /// Configuration for the local file system backend
+        #[serde(default)]
+        file_system: file_system::FileSystemStorageConfig,
+    },
+
+    /// In-memory storage, intended for tests and local development only
+    InMemory,
+}
+
+impl Default for FileStorageConfig {
+    fn default() -> Self {
+        Self::FileSystem {
+            file_system: file_system::FileSystemStorageConfig::default(),
+        }
+    }
+}
+
+impl FileStorageConfig {
+    /// Verifies that the configuration for the selected backend is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            #[cfg(feature = "aws_s3")]
+            Self::AwsS3 { aws_s3 } => aws_s3.validate(),
+            Self::FileSystem { file_system } => file_system.validate(),
+            Self::InMemory => Ok(()),
+        }
+    }
+
+    /// Constructs a new client for the selected backend.
+    pub async fn get_file_storage_client(&self) -> Arc<dyn FileStorageInterface> {
+        match self {
+            #[cfg(feature = "aws_s3")]
+            Self::AwsS3 { aws_s3 } => Arc::new(aws_s3::AwsFileStorageClient::new(aws_s3).await),
+            Self::FileSystem { file_system } => {
+                Arc::new(file_system::FileSystemStorageClient::new(file_system))
+            }
+            Self::InMemory => Arc::new(memory::InMemoryStorageClient::default()),
+        }
+    }
+}
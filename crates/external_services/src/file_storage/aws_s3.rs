@@ -0,0 +1,232 @@
+//! File storage in an AWS S3 bucket
+
+use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_s3::{
+    config::Region,
+    primitives::ByteStream,
+    types::{CompletedMultipartUpload, CompletedPart},
+    Client,
+};
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use futures::{StreamExt, TryStreamExt};
+use router_env::logger;
+
+use super::{FileStorageError, FileStorageInterface, FileStream};
+
+/// Minimum size of every part of a multipart upload, except the last one.
+const MULTIPART_UPLOAD_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Configuration parameters required for constructing an [`AwsFileStorageClient`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct AwsFileStorageConfig {
+    /// The AWS region to send file uploads to.
+    pub region: String,
+
+    /// The AWS S3 bucket to send file uploads to.
+    pub bucket_name: String,
+}
+
+impl AwsFileStorageConfig {
+    /// Verifies that the [`AwsFileStorageClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.region.is_default_or_empty(), || {
+            Err("AWS S3 region must not be empty")
+        })?;
+
+        when(self.bucket_name.is_default_or_empty(), || {
+            Err("AWS S3 bucket name must not be empty")
+        })
+    }
+}
+
+/// Client for AWS S3 file storage operations.
+#[derive(Debug, Clone)]
+pub struct AwsFileStorageClient {
+    inner_client: Client,
+    bucket_name: String,
+}
+
+impl AwsFileStorageClient {
+    /// Constructs a new AWS S3 file storage client.
+    pub async fn new(config: &AwsFileStorageConfig) -> Self {
+        let region_provider = RegionProviderChain::first_try(Region::new(config.region.clone()));
+        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+
+        Self {
+            inner_client: Client::new(&sdk_config),
+            bucket_name: config.bucket_name.clone(),
+        }
+    }
+
+    /// Uploads a single part of a multipart upload, returning the completed part.
+    async fn upload_part(
+        &self,
+        file_key: &str,
+        upload_id: &str,
+        part_number: i32,
+        part: Vec<u8>,
+    ) -> CustomResult<CompletedPart, FileStorageError> {
+        let output = self
+            .inner_client
+            .upload_part()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .body(ByteStream::from(part))
+            .send()
+            .await
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)
+            .attach_printable("Failed to upload file part to S3")?;
+
+        Ok(CompletedPart::builder()
+            .set_e_tag(output.e_tag().map(ToOwned::to_owned))
+            .part_number(part_number)
+            .build())
+    }
+
+    /// Streams the file to S3 in parts, buffering at most one part in memory.
+    async fn upload_parts(
+        &self,
+        file_key: &str,
+        upload_id: &str,
+        mut file: FileStream,
+    ) -> CustomResult<Vec<CompletedPart>, FileStorageError> {
+        let mut completed_parts = Vec::new();
+        let mut part_number = 1;
+        let mut buffer = Vec::with_capacity(MULTIPART_UPLOAD_PART_SIZE);
+
+        while let Some(chunk) = file.try_next().await? {
+            buffer.extend_from_slice(&chunk);
+
+            if buffer.len() >= MULTIPART_UPLOAD_PART_SIZE {
+                let part =
+                    std::mem::replace(&mut buffer, Vec::with_capacity(MULTIPART_UPLOAD_PART_SIZE));
+                completed_parts.push(
+                    self.upload_part(file_key, upload_id, part_number, part)
+                        .await?,
+                );
+                part_number += 1;
+            }
+        }
+
+        // The last part may be smaller than the minimum part size, and S3 requires at least one
+        // part even for empty files.
+        if !buffer.is_empty() || completed_parts.is_empty() {
+            completed_parts.push(
+                self.upload_part(file_key, upload_id, part_number, buffer)
+                    .await?,
+            );
+        }
+
+        Ok(completed_parts)
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for AwsFileStorageClient {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: FileStream,
+    ) -> CustomResult<(), FileStorageError> {
+        let multipart_upload = self
+            .inner_client
+            .create_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)
+            .attach_printable("Failed to initiate multipart upload to S3")?;
+
+        let upload_id = multipart_upload
+            .upload_id()
+            .ok_or(FileStorageError::UploadFailed)
+            .into_report()
+            .attach_printable("Missing upload ID in S3 multipart upload response")?
+            .to_owned();
+
+        let completed_parts = match self.upload_parts(file_key, &upload_id, file).await {
+            Ok(completed_parts) => completed_parts,
+            Err(error) => {
+                // Abort the upload so that S3 discards the parts already uploaded
+                if let Err(abort_error) = self
+                    .inner_client
+                    .abort_multipart_upload()
+                    .bucket(&self.bucket_name)
+                    .key(file_key)
+                    .upload_id(&upload_id)
+                    .send()
+                    .await
+                {
+                    logger::error!(?abort_error, "Failed to abort S3 multipart upload");
+                }
+                return Err(error);
+            }
+        };
+
+        self.inner_client
+            .complete_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .upload_id(&upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(completed_parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)
+            .attach_printable("File upload to S3 failed")?;
+
+        Ok(())
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
+        self.inner_client
+            .delete_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .into_report()
+            .change_context(FileStorageError::DeleteFailed)
+            .attach_printable("File delete from S3 failed")?;
+
+        Ok(())
+    }
+
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<FileStream, FileStorageError> {
+        let object = self
+            .inner_client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .into_report()
+            .change_context(FileStorageError::RetrieveFailed)
+            .attach_printable("File retrieve from S3 failed")?;
+
+        Ok(
+            futures::stream::try_unfold(object.body, |mut body| async move {
+                body.try_next()
+                    .await
+                    .into_report()
+                    .change_context(FileStorageError::RetrieveFailed)
+                    .attach_printable("Invalid file data received from S3")
+                    .map(|chunk| chunk.map(|chunk| (chunk, body)))
+            })
+            .boxed(),
+        )
+    }
+}
@@ -0,0 +1,180 @@
+//! File storage in a directory on the local file system
+
+use std::path::{Component, Path, PathBuf};
+
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use futures::{StreamExt, TryStreamExt};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use super::{FileStorageError, FileStorageInterface, FileStream};
+
+/// Size of the chunks in which files are read from disk.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Configuration parameters required for constructing a [`FileSystemStorageClient`].
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct FileSystemStorageConfig {
+    /// The directory under which files are stored.
+    pub root_path: PathBuf,
+}
+
+impl Default for FileSystemStorageConfig {
+    fn default() -> Self {
+        Self {
+            root_path: router_env::env::workspace_path().join("files"),
+        }
+    }
+}
+
+impl FileSystemStorageConfig {
+    /// Verifies that the [`FileSystemStorageClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        common_utils::fp_utils::when(self.root_path.as_os_str().is_empty(), || {
+            Err("File system storage root path must not be empty")
+        })
+    }
+}
+
+/// Client storing files under a root directory, with one subdirectory per key segment.
+#[derive(Debug, Clone)]
+pub struct FileSystemStorageClient {
+    root_path: PathBuf,
+}
+
+impl FileSystemStorageClient {
+    /// Constructs a new local file system storage client.
+    pub fn new(config: &FileSystemStorageConfig) -> Self {
+        Self {
+            root_path: config.root_path.clone(),
+        }
+    }
+
+    /// Resolves the file key to a path under the root directory, rejecting keys that could
+    /// escape it.
+    fn get_file_path(&self, file_key: &str) -> CustomResult<PathBuf, FileStorageError> {
+        let key_path = Path::new(file_key);
+        let is_valid = !file_key.is_empty()
+            && key_path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+
+        if !is_valid {
+            return Err(FileStorageError::InvalidFileKey)
+                .into_report()
+                .attach_printable_lazy(|| format!("Invalid file key: {file_key}"));
+        }
+
+        Ok(self.root_path.join(key_path))
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for FileSystemStorageClient {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        mut file: FileStream,
+    ) -> CustomResult<(), FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+
+        if let Some(parent) = file_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .into_report()
+                .change_context(FileStorageError::UploadFailed)
+                .attach_printable("Failed to create file directory")?;
+        }
+
+        let mut output = tokio::fs::File::create(&file_path)
+            .await
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)
+            .attach_printable("Failed to create file")?;
+
+        while let Some(chunk) = file.try_next().await? {
+            output
+                .write_all(&chunk)
+                .await
+                .into_report()
+                .change_context(FileStorageError::UploadFailed)
+                .attach_printable("Failed while writing into file")?;
+        }
+
+        output
+            .flush()
+            .await
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)
+            .attach_printable("Failed while flushing file")
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+
+        tokio::fs::remove_file(file_path)
+            .await
+            .into_report()
+            .change_context(FileStorageError::DeleteFailed)
+            .attach_printable("Failed while deleting the file")
+    }
+
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<FileStream, FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+
+        let file = tokio::fs::File::open(file_path)
+            .await
+            .into_report()
+            .change_context(FileStorageError::FileNotFound)
+            .attach_printable("Failed while opening the file")?;
+
+        Ok(futures::stream::try_unfold(file, |mut file| async move {
+            let mut buffer = vec![0; READ_CHUNK_SIZE];
+            let bytes_read = file
+                .read(&mut buffer)
+                .await
+                .into_report()
+                .change_context(FileStorageError::RetrieveFailed)
+                .attach_printable("Failed while reading the file")?;
+
+            if bytes_read == 0 {
+                Ok(None)
+            } else {
+                buffer.truncate(bytes_read);
+                Ok(Some((bytes::Bytes::from(buffer), file)))
+            }
+        })
+        .boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_client() -> FileSystemStorageClient {
+        FileSystemStorageClient::new(&FileSystemStorageConfig {
+            root_path: PathBuf::from("/root"),
+        })
+    }
+
+    #[test]
+    fn test_file_key_is_namespaced_under_root() {
+        assert_eq!(
+            get_client().get_file_path("merchant_1/file_1").unwrap(),
+            PathBuf::from("/root/merchant_1/file_1")
+        );
+    }
+
+    #[test]
+    fn test_file_key_cannot_escape_root() {
+        let client = get_client();
+        assert!(client.get_file_path("../merchant_1/file_1").is_err());
+        assert!(client.get_file_path("merchant_1/../../file_1").is_err());
+        assert!(client.get_file_path("/etc/passwd").is_err());
+        assert!(client.get_file_path("").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! In-memory file storage, intended for tests and local development only
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use bytes::Bytes;
+use common_utils::errors::CustomResult;
+use error_stack::IntoReport;
+use futures::StreamExt;
+
+use super::{collect_file_stream, FileStorageError, FileStorageInterface, FileStream};
+
+/// Client storing files in a process-local map.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryStorageClient {
+    files: Arc<RwLock<HashMap<String, Bytes>>>,
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for InMemoryStorageClient {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: FileStream,
+    ) -> CustomResult<(), FileStorageError> {
+        let contents = collect_file_stream(file).await?;
+        self.files
+            .write()
+            .map_err(|_| FileStorageError::UploadFailed)
+            .into_report()?
+            .insert(file_key.to_owned(), Bytes::from(contents));
+        Ok(())
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
+        self.files
+            .write()
+            .map_err(|_| FileStorageError::DeleteFailed)
+            .into_report()?
+            .remove(file_key)
+            .map(|_| ())
+            .ok_or(FileStorageError::FileNotFound)
+            .into_report()
+    }
+
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<FileStream, FileStorageError> {
+        let contents = self
+            .files
+            .read()
+            .map_err(|_| FileStorageError::RetrieveFailed)
+            .into_report()?
+            .get(file_key)
+            .cloned()
+            .ok_or(FileStorageError::FileNotFound)
+            .into_report()?;
+
+        Ok(futures::stream::once(async move { Ok(contents) }).boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn file_stream(chunks: &[&'static [u8]]) -> FileStream {
+        futures::stream::iter(
+            chunks
+                .iter()
+                .map(|chunk| Ok(Bytes::from_static(chunk)))
+                .collect::<Vec<_>>(),
+        )
+        .boxed()
+    }
+
+    #[tokio::test]
+    async fn test_upload_retrieve_and_delete() {
+        let client = InMemoryStorageClient::default();
+
+        client
+            .upload_file("merchant/file", file_stream(&[b"hello ", b"world"]))
+            .await
+            .unwrap();
+
+        let contents = collect_file_stream(client.retrieve_file("merchant/file").await.unwrap())
+            .await
+            .unwrap();
+        assert_eq!(contents, b"hello world");
+
+        client.delete_file("merchant/file").await.unwrap();
+        assert!(client.retrieve_file("merchant/file").await.is_err());
+    }
+}
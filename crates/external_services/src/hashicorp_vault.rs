@@ -0,0 +1,243 @@
+//! Interactions with the HashiCorp Vault transit secrets engine
+
+use base64::Engine;
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+use crate::{
+    consts,
+    encryption_management::{EncryptionError, EncryptionManagementInterface},
+    metrics,
+    secrets_management::{SecretManagementInterface, SecretsManagementError},
+};
+
+/// Configuration parameters required for constructing a [`HashiCorpVault`] client.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct HashiCorpVaultConfig {
+    /// The base URL of the Vault server.
+    pub url: String,
+
+    /// The token used to authenticate with the Vault server.
+    pub token: Secret<String>,
+
+    /// The path at which the transit secrets engine is mounted.
+    pub mount_path: String,
+
+    /// The name of the transit key used to encrypt or decrypt data.
+    pub key_name: String,
+}
+
+impl Default for HashiCorpVaultConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            token: Secret::new(String::new()),
+            mount_path: "transit".to_string(),
+            key_name: String::new(),
+        }
+    }
+}
+
+impl HashiCorpVaultConfig {
+    /// Verifies that the [`HashiCorpVault`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.url.is_default_or_empty(), || {
+            Err("HashiCorp Vault URL must not be empty")
+        })?;
+
+        when(self.token.peek().is_default_or_empty(), || {
+            Err("HashiCorp Vault token must not be empty")
+        })?;
+
+        when(self.mount_path.is_default_or_empty(), || {
+            Err("HashiCorp Vault transit mount path must not be empty")
+        })?;
+
+        when(self.key_name.is_default_or_empty(), || {
+            Err("HashiCorp Vault transit key name must not be empty")
+        })
+    }
+}
+
+/// Client for HashiCorp Vault transit operations.
+#[derive(Debug)]
+pub struct HashiCorpVault {
+    client: reqwest::Client,
+    url: String,
+    token: Secret<String>,
+    mount_path: String,
+    key_name: String,
+}
+
+#[derive(serde::Serialize)]
+struct EncryptRequest {
+    plaintext: String,
+}
+
+#[derive(serde::Deserialize)]
+struct EncryptResponse {
+    data: EncryptResponseData,
+}
+
+#[derive(serde::Deserialize)]
+struct EncryptResponseData {
+    ciphertext: String,
+}
+
+#[derive(serde::Serialize)]
+struct DecryptRequest {
+    ciphertext: String,
+}
+
+#[derive(serde::Deserialize)]
+struct DecryptResponse {
+    data: DecryptResponseData,
+}
+
+#[derive(serde::Deserialize)]
+struct DecryptResponseData {
+    plaintext: String,
+}
+
+impl HashiCorpVault {
+    /// Constructs a new HashiCorp Vault client.
+    pub fn new(config: &HashiCorpVaultConfig) -> CustomResult<Self, HashiCorpError> {
+        let client = reqwest::Client::builder()
+            .build()
+            .into_report()
+            .change_context(HashiCorpError::ClientCreationFailed)?;
+
+        Ok(Self {
+            client,
+            url: config.url.trim_end_matches('/').to_string(),
+            token: config.token.clone(),
+            mount_path: config.mount_path.trim_matches('/').to_string(),
+            key_name: config.key_name.clone(),
+        })
+    }
+
+    fn endpoint(&self, operation: &str) -> String {
+        format!(
+            "{}/v1/{}/{}/{}",
+            self.url, self.mount_path, operation, self.key_name
+        )
+    }
+
+    async fn post<Req, Res>(
+        &self,
+        operation: &str,
+        request: &Req,
+    ) -> CustomResult<Res, HashiCorpError>
+    where
+        Req: serde::Serialize + Sync,
+        Res: serde::de::DeserializeOwned,
+    {
+        self.client
+            .post(self.endpoint(operation))
+            .header("X-Vault-Token", self.token.peek())
+            .json(request)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|error| {
+                logger::error!(hashicorp_vault_error=?error, operation, "Failed to call HashiCorp Vault API");
+                metrics::HASHICORP_VAULT_FAILURES.add(&metrics::CONTEXT, 1, &[]);
+                error
+            })
+            .into_report()
+            .change_context(HashiCorpError::RequestFailed)?
+            .json::<Res>()
+            .await
+            .into_report()
+            .change_context(HashiCorpError::ResponseDeserializationFailed)
+    }
+
+    /// Encrypts the provided data using the transit key, returning the Vault ciphertext.
+    pub async fn encrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<String, HashiCorpError> {
+        let request = EncryptRequest {
+            plaintext: consts::BASE64_ENGINE.encode(data),
+        };
+
+        self.post::<_, EncryptResponse>("encrypt", &request)
+            .await
+            .map(|response| response.data.ciphertext)
+    }
+
+    /// Decrypts the provided Vault ciphertext (of the form `vault:v1:...`) using the transit key.
+    pub async fn decrypt(&self, ciphertext: &str) -> CustomResult<Vec<u8>, HashiCorpError> {
+        let request = DecryptRequest {
+            ciphertext: ciphertext.to_string(),
+        };
+
+        let response = self.post::<_, DecryptResponse>("decrypt", &request).await?;
+
+        consts::BASE64_ENGINE
+            .decode(response.data.plaintext)
+            .into_report()
+            .change_context(HashiCorpError::Base64DecodingFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl EncryptionManagementInterface for HashiCorpVault {
+    async fn encrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        self.encrypt(input)
+            .await
+            .map(String::into_bytes)
+            .change_context(EncryptionError::EncryptionFailed)
+    }
+
+    async fn decrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        let ciphertext = std::str::from_utf8(input)
+            .into_report()
+            .change_context(EncryptionError::DecryptionFailed)?;
+
+        self.decrypt(ciphertext)
+            .await
+            .change_context(EncryptionError::DecryptionFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for HashiCorpVault {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        let plaintext = self
+            .decrypt(input.peek())
+            .await
+            .change_context(SecretsManagementError::FetchSecretFailed)?;
+
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to UTF-8 decode decryption output")
+            .map(Secret::new)
+    }
+}
+
+/// Errors that could occur during HashiCorp Vault operations.
+#[derive(Debug, thiserror::Error)]
+pub enum HashiCorpError {
+    /// An error occurred when constructing the HTTP client.
+    #[error("Failed to create the HashiCorp Vault HTTP client")]
+    ClientCreationFailed,
+
+    /// An error occurred when calling the Vault API.
+    #[error("Failed to call the HashiCorp Vault API")]
+    RequestFailed,
+
+    /// An error occurred when deserializing the Vault API response.
+    #[error("Failed to deserialize the HashiCorp Vault API response")]
+    ResponseDeserializationFailed,
+
+    /// An error occurred when base64 decoding the Vault API response.
+    #[error("Failed to base64 decode the decrypted output")]
+    Base64DecodingFailed,
+}
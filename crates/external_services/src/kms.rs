@@ -9,18 +9,13 @@ use common_utils::errors::CustomResult;
 use error_stack::{IntoReport, ResultExt};
 use masking::{PeekInterface, Secret};
 use router_env::logger;
-/// decrypting data using the AWS KMS SDK.
-pub mod decrypt;
 
-use crate::{consts, metrics};
-
-static KMS_CLIENT: tokio::sync::OnceCell<KmsClient> = tokio::sync::OnceCell::const_new();
-
-/// Returns a shared KMS client, or initializes a new one if not previously initialized.
-#[inline]
-pub async fn get_kms_client(config: &KmsConfig) -> &'static KmsClient {
-    KMS_CLIENT.get_or_init(|| KmsClient::new(config)).await
-}
+use crate::{
+    consts,
+    encryption_management::{EncryptionError, EncryptionManagementInterface},
+    metrics,
+    secrets_management::{SecretManagementInterface, SecretsManagementError},
+};
 
 /// Configuration parameters required for constructing a [`KmsClient`].
 #[derive(Clone, Debug, Default, serde::Deserialize)]
@@ -57,11 +52,27 @@ impl KmsClient {
     /// `AWS_SECRET_ACCESS_KEY`) either set in environment variables, or that the SDK is running in
     /// a machine that is able to assume an IAM role.
     pub async fn decrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<String, KmsError> {
-        let start = Instant::now();
         let data = consts::BASE64_ENGINE
             .decode(data)
             .into_report()
             .change_context(KmsError::Base64DecodingFailed)?;
+
+        self.decrypt_blob(data).await.and_then(|plaintext| {
+            String::from_utf8(plaintext)
+                .into_report()
+                .change_context(KmsError::Utf8DecodingFailed)
+        })
+    }
+
+    /// Encrypts the provided data using the AWS KMS SDK, returning the base64-encoded ciphertext.
+    pub async fn encrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<String, KmsError> {
+        self.encrypt_blob(data.as_ref().to_vec())
+            .await
+            .map(|ciphertext| consts::BASE64_ENGINE.encode(ciphertext))
+    }
+
+    async fn decrypt_blob(&self, data: Vec<u8>) -> CustomResult<Vec<u8>, KmsError> {
+        let start = Instant::now();
         let ciphertext_blob = Blob::new(data);
 
         let decrypt_output = self
@@ -84,18 +95,75 @@ impl KmsClient {
         let output = decrypt_output
             .plaintext
             .ok_or(KmsError::MissingPlaintextDecryptionOutput)
-            .into_report()
-            .and_then(|blob| {
-                String::from_utf8(blob.into_inner())
-                    .into_report()
-                    .change_context(KmsError::Utf8DecodingFailed)
-            })?;
+            .into_report()?
+            .into_inner();
 
         let time_taken = start.elapsed();
         metrics::AWS_KMS_DECRYPT_TIME.record(&metrics::CONTEXT, time_taken.as_secs_f64(), &[]);
 
         Ok(output)
     }
+
+    async fn encrypt_blob(&self, data: Vec<u8>) -> CustomResult<Vec<u8>, KmsError> {
+        let start = Instant::now();
+        let plaintext_blob = Blob::new(data);
+
+        let encrypt_output = self
+            .inner_client
+            .encrypt()
+            .key_id(&self.key_id)
+            .plaintext(plaintext_blob)
+            .send()
+            .await
+            .map_err(|error| {
+                // Logging using `Debug` representation of the error as the `Display`
+                // representation does not hold sufficient information.
+                logger::error!(kms_sdk_error=?error, "Failed to KMS encrypt data");
+                metrics::AWS_KMS_FAILURES.add(&metrics::CONTEXT, 1, &[]);
+                error
+            })
+            .into_report()
+            .change_context(KmsError::EncryptionFailed)?;
+
+        let output = encrypt_output
+            .ciphertext_blob
+            .ok_or(KmsError::MissingCiphertextEncryptionOutput)
+            .into_report()?
+            .into_inner();
+
+        let time_taken = start.elapsed();
+        metrics::AWS_KMS_ENCRYPT_TIME.record(&metrics::CONTEXT, time_taken.as_secs_f64(), &[]);
+
+        Ok(output)
+    }
+}
+
+#[async_trait::async_trait]
+impl EncryptionManagementInterface for KmsClient {
+    async fn encrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        self.encrypt_blob(input.to_vec())
+            .await
+            .change_context(EncryptionError::EncryptionFailed)
+    }
+
+    async fn decrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        self.decrypt_blob(input.to_vec())
+            .await
+            .change_context(EncryptionError::DecryptionFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for KmsClient {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        self.decrypt(input.peek())
+            .await
+            .map(Secret::new)
+            .change_context(SecretsManagementError::FetchSecretFailed)
+    }
 }
 
 /// Errors that could occur during KMS operations.
@@ -109,6 +177,14 @@ pub enum KmsError {
     #[error("Failed to KMS decrypt input data")]
     DecryptionFailed,
 
+    /// An error occurred when KMS encrypting input data.
+    #[error("Failed to KMS encrypt input data")]
+    EncryptionFailed,
+
+    /// The KMS encrypted output does not include a ciphertext output.
+    #[error("Missing ciphertext KMS encryption output")]
+    MissingCiphertextEncryptionOutput,
+
     /// The KMS decrypted output does not include a plaintext output.
     #[error("Missing plaintext KMS decryption output")]
     MissingPlaintextDecryptionOutput,
@@ -116,10 +192,6 @@ pub enum KmsError {
     /// An error occurred UTF-8 decoding KMS decrypted output.
     #[error("Failed to UTF-8 decode decryption output")]
     Utf8DecodingFailed,
-
-    /// The KMS client has not been initialized.
-    #[error("The KMS client has not been initialized")]
-    KmsClientNotInitialized,
 }
 
 impl KmsConfig {
@@ -136,14 +208,3 @@ impl KmsConfig {
         })
     }
 }
-
-/// A wrapper around a KMS value that can be decrypted.
-#[derive(Clone, Debug, Default, serde::Deserialize, Eq, PartialEq)]
-#[serde(transparent)]
-pub struct KmsValue(Secret<String>);
-
-impl common_utils::ext_traits::ConfigExt for KmsValue {
-    fn is_empty_after_trim(&self) -> bool {
-        self.0.peek().is_empty_after_trim()
-    }
-}
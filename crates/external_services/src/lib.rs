@@ -6,11 +6,20 @@
 #[cfg(feature = "email")]
 pub mod email;
 
+pub mod account_updater;
+pub mod encryption_management;
+pub mod file_storage;
+#[cfg(feature = "hashicorp-vault")]
+pub mod hashicorp_vault;
 #[cfg(feature = "kms")]
 pub mod kms;
+#[cfg(feature = "local-keyfile")]
+pub mod local_keyfile;
+pub mod network_tokenization;
+pub mod no_encryption;
+pub mod secrets_management;
 
 /// Crate specific constants
-#[cfg(feature = "kms")]
 pub mod consts {
     /// General purpose base64 engine
     pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
@@ -18,9 +27,11 @@ pub mod consts {
 }
 
 /// Metrics for interactions with external systems.
-#[cfg(feature = "kms")]
+#[cfg(any(feature = "kms", feature = "hashicorp-vault"))]
 pub mod metrics {
-    use router_env::{counter_metric, global_meter, histogram_metric, metrics_context};
+    #[cfg(feature = "kms")]
+    use router_env::histogram_metric;
+    use router_env::{counter_metric, global_meter, metrics_context};
 
     metrics_context!(CONTEXT);
     global_meter!(GLOBAL_METER, "EXTERNAL_SERVICES");
@@ -30,4 +41,10 @@ pub mod metrics {
 
     #[cfg(feature = "kms")]
     histogram_metric!(AWS_KMS_DECRYPT_TIME, GLOBAL_METER); // Histogram for KMS decryption time (in sec)
+
+    #[cfg(feature = "kms")]
+    histogram_metric!(AWS_KMS_ENCRYPT_TIME, GLOBAL_METER); // Histogram for KMS encryption time (in sec)
+
+    #[cfg(feature = "hashicorp-vault")]
+    counter_metric!(HASHICORP_VAULT_FAILURES, GLOBAL_METER); // No. of HashiCorp Vault API failures
 }
@@ -0,0 +1,173 @@
+//! Encryption using a key read from a local file
+//!
+//! Intended for development setups and self-hosted deployments without access to a managed
+//! key management service. Secrets in the configuration are expected to be base64-encoded
+//! AES-256-GCM ciphertexts, produced with the same key.
+
+use base64::Engine;
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    errors::CustomResult,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    consts,
+    encryption_management::{EncryptionError, EncryptionManagementInterface},
+    secrets_management::{SecretManagementInterface, SecretsManagementError},
+};
+
+/// Length of the AES-256-GCM key, in bytes.
+const KEY_LENGTH: usize = 32;
+
+/// Configuration parameters required for constructing a [`LocalKeyfileClient`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LocalKeyfileConfig {
+    /// Path to the file holding the base64-encoded 256-bit key.
+    pub key_path: String,
+}
+
+impl LocalKeyfileConfig {
+    /// Verifies that the [`LocalKeyfileClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.key_path.is_default_or_empty(), || {
+            Err("Local keyfile path must not be empty")
+        })
+    }
+}
+
+/// Client for encryption operations using a key read from a local file.
+pub struct LocalKeyfileClient {
+    key: Secret<Vec<u8>>,
+}
+
+impl std::fmt::Debug for LocalKeyfileClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalKeyfileClient").finish_non_exhaustive()
+    }
+}
+
+impl LocalKeyfileClient {
+    /// Constructs a new client, reading the key from the configured file.
+    pub fn new(config: &LocalKeyfileConfig) -> CustomResult<Self, LocalKeyfileError> {
+        let contents = std::fs::read_to_string(&config.key_path)
+            .into_report()
+            .change_context(LocalKeyfileError::KeyfileReadFailed)
+            .attach_printable_lazy(|| format!("Failed to read keyfile at {}", config.key_path))?;
+
+        Self::from_base64_key(contents.trim())
+    }
+
+    /// Constructs a new client from a base64-encoded key.
+    pub fn from_base64_key(key: &str) -> CustomResult<Self, LocalKeyfileError> {
+        let key = consts::BASE64_ENGINE
+            .decode(key)
+            .into_report()
+            .change_context(LocalKeyfileError::Base64DecodingFailed)?;
+
+        if key.len() != KEY_LENGTH {
+            return Err(LocalKeyfileError::InvalidKeyLength).into_report();
+        }
+
+        Ok(Self {
+            key: Secret::new(key),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EncryptionManagementInterface for LocalKeyfileClient {
+    async fn encrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        GcmAes256
+            .encode_message(self.key.peek(), input)
+            .change_context(EncryptionError::EncryptionFailed)
+    }
+
+    async fn decrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        GcmAes256
+            .decode_message(self.key.peek(), Secret::new(input.to_vec()))
+            .change_context(EncryptionError::DecryptionFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for LocalKeyfileClient {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        let ciphertext = consts::BASE64_ENGINE
+            .decode(input.peek())
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to base64 decode input data")?;
+
+        let plaintext = self
+            .decrypt(&ciphertext)
+            .await
+            .change_context(SecretsManagementError::FetchSecretFailed)?;
+
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to UTF-8 decode decryption output")
+            .map(Secret::new)
+    }
+}
+
+/// Errors that could occur when constructing a [`LocalKeyfileClient`].
+#[derive(Debug, thiserror::Error)]
+pub enum LocalKeyfileError {
+    /// An error occurred when reading the keyfile.
+    #[error("Failed to read the keyfile")]
+    KeyfileReadFailed,
+
+    /// An error occurred when base64 decoding the key.
+    #[error("Failed to base64 decode the key")]
+    Base64DecodingFailed,
+
+    /// The key is not 256 bits long.
+    #[error("The key must be 256 bits long")]
+    InvalidKeyLength,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const KEY: &str = "Zm9vYmFyYmF6cXV4Zm9vYmFyYmF6cXV4Zm9vYmFyYmE=";
+
+    #[tokio::test]
+    async fn test_encrypt_decrypt_round_trip() {
+        let client = LocalKeyfileClient::from_base64_key(KEY).unwrap();
+
+        let ciphertext = client.encrypt(b"super secret").await.unwrap();
+        assert_ne!(ciphertext, b"super secret");
+
+        let plaintext = client.decrypt(&ciphertext).await.unwrap();
+        assert_eq!(plaintext, b"super secret");
+    }
+
+    #[tokio::test]
+    async fn test_get_secret() {
+        let client = LocalKeyfileClient::from_base64_key(KEY).unwrap();
+
+        let ciphertext = client.encrypt(b"super secret").await.unwrap();
+        let secret = client
+            .get_secret(Secret::new(consts::BASE64_ENGINE.encode(ciphertext)))
+            .await
+            .unwrap();
+        assert_eq!(secret.peek(), "super secret");
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        assert!(LocalKeyfileClient::from_base64_key("Zm9vYmFy").is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//! Interactions with network token service providers
+
+use std::sync::Arc;
+
+use common_utils::errors::CustomResult;
+use masking::Secret;
+
+pub mod mock;
+
+/// Card details submitted to the token service provider when provisioning a network token.
+#[derive(Clone, Debug)]
+pub struct CardDetails {
+    /// The primary account number of the card
+    pub card_number: Secret<String>,
+
+    /// The expiry month of the card
+    pub card_exp_month: Secret<String>,
+
+    /// The expiry year of the card
+    pub card_exp_year: Secret<String>,
+
+    /// The name of the card holder
+    pub card_holder_name: Option<Secret<String>>,
+}
+
+/// A network token provisioned by the token service provider in place of a card.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NetworkToken {
+    /// Reference identifying the token with the token service provider
+    pub token_reference: String,
+
+    /// The network token, usable in place of the card number
+    pub token_number: Secret<String>,
+
+    /// The expiry month of the network token
+    pub token_exp_month: Secret<String>,
+
+    /// The expiry year of the network token
+    pub token_exp_year: Secret<String>,
+}
+
+/// A single use cryptogram authenticating a transaction made with a network token.
+#[derive(Clone, Debug)]
+pub struct NetworkTokenCryptogram {
+    /// The cryptogram generated for the transaction
+    pub cryptogram: Secret<String>,
+
+    /// The electronic commerce indicator to be sent along with the cryptogram
+    pub eci: Option<String>,
+}
+
+/// Lifecycle events that may be applied to a provisioned network token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenLifecycleEvent {
+    /// The token must not be used for transactions until it is resumed
+    Suspend,
+
+    /// A suspended token may be used for transactions again
+    Resume,
+
+    /// The token is deleted and can no longer be used
+    Delete,
+
+    /// The underlying card was updated by the issuer, for example reissued with a new expiry
+    CardUpdated {
+        /// The new expiry month of the card
+        card_exp_month: Secret<String>,
+
+        /// The new expiry year of the card
+        card_exp_year: Secret<String>,
+    },
+}
+
+/// Trait defining the interface for provisioning and using network tokens.
+#[async_trait::async_trait]
+pub trait NetworkTokenProvider: std::fmt::Debug + Send + Sync {
+    /// Provisions a network token for the provided card.
+    async fn provision_token(
+        &self,
+        card: &CardDetails,
+    ) -> CustomResult<NetworkToken, NetworkTokenizationError>;
+
+    /// Generates a cryptogram for a single transaction made with the network token.
+    async fn get_cryptogram(
+        &self,
+        token_reference: &str,
+    ) -> CustomResult<NetworkTokenCryptogram, NetworkTokenizationError>;
+
+    /// Applies a lifecycle event to the network token.
+    async fn handle_lifecycle_event(
+        &self,
+        token_reference: &str,
+        event: TokenLifecycleEvent,
+    ) -> CustomResult<(), NetworkTokenizationError>;
+}
+
+/// Errors that could occur during network tokenization operations.
+#[derive(Debug, thiserror::Error)]
+pub enum NetworkTokenizationError {
+    /// An error occurred when provisioning the network token.
+    #[error("Failed to provision the network token")]
+    ProvisioningFailed,
+
+    /// An error occurred when generating the cryptogram.
+    #[error("Failed to generate a cryptogram for the network token")]
+    CryptogramFailed,
+
+    /// No network token exists with the given reference.
+    #[error("Network token not found")]
+    TokenNotFound,
+
+    /// The network token is suspended or deleted.
+    #[error("Network token is not active")]
+    TokenInactive,
+
+    /// An error occurred when applying a lifecycle event to the network token.
+    #[error("Failed to update the network token lifecycle")]
+    LifecycleUpdateFailed,
+}
+
+/// Configuration for the network token service provider, selected at application startup.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum NetworkTokenizationConfig {
+    /// Network tokenization is disabled, saved cards are always charged with the card number
+    #[default]
+    Disabled,
+
+    /// In-memory token service provider, intended for tests and local development only
+    Mock,
+}
+
+impl NetworkTokenizationConfig {
+    /// Verifies that the configuration for the selected provider is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            Self::Disabled | Self::Mock => Ok(()),
+        }
+    }
+
+    /// Constructs a new client for the selected provider, if network tokenization is enabled.
+    pub fn get_network_token_provider(&self) -> Option<Arc<dyn NetworkTokenProvider>> {
+        match self {
+            Self::Disabled => None,
+            Self::Mock => Some(Arc::new(mock::MockNetworkTokenProvider::default())),
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! In-memory token service provider, intended for tests and local development only
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use base64::Engine;
+use common_utils::{crypto, errors::CustomResult};
+use error_stack::IntoReport;
+use masking::{PeekInterface, Secret};
+
+use super::{
+    CardDetails, NetworkToken, NetworkTokenCryptogram, NetworkTokenProvider,
+    NetworkTokenizationError, TokenLifecycleEvent,
+};
+use crate::consts;
+
+/// Electronic commerce indicator returned along with the mock cryptograms.
+const MOCK_ECI: &str = "07";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum TokenStatus {
+    Active,
+    Suspended,
+    Deleted,
+}
+
+#[derive(Clone, Debug)]
+struct StoredToken {
+    token: NetworkToken,
+    status: TokenStatus,
+}
+
+/// Token service provider issuing tokens from a process-local map.
+#[derive(Debug, Clone, Default)]
+pub struct MockNetworkTokenProvider {
+    tokens: Arc<RwLock<HashMap<String, StoredToken>>>,
+}
+
+impl MockNetworkTokenProvider {
+    fn get_token(
+        &self,
+        token_reference: &str,
+    ) -> CustomResult<StoredToken, NetworkTokenizationError> {
+        self.tokens
+            .read()
+            .map_err(|_| NetworkTokenizationError::TokenNotFound)
+            .into_report()?
+            .get(token_reference)
+            .cloned()
+            .ok_or(NetworkTokenizationError::TokenNotFound)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl NetworkTokenProvider for MockNetworkTokenProvider {
+    async fn provision_token(
+        &self,
+        card: &CardDetails,
+    ) -> CustomResult<NetworkToken, NetworkTokenizationError> {
+        let token_number = generate_token_number(card.card_number.peek())
+            .ok_or(NetworkTokenizationError::ProvisioningFailed)
+            .into_report()?;
+        let token = NetworkToken {
+            token_reference: common_utils::generate_id_with_default_len("ntr"),
+            token_number: Secret::new(token_number),
+            token_exp_month: card.card_exp_month.clone(),
+            token_exp_year: card.card_exp_year.clone(),
+        };
+
+        self.tokens
+            .write()
+            .map_err(|_| NetworkTokenizationError::ProvisioningFailed)
+            .into_report()?
+            .insert(
+                token.token_reference.clone(),
+                StoredToken {
+                    token: token.clone(),
+                    status: TokenStatus::Active,
+                },
+            );
+        Ok(token)
+    }
+
+    async fn get_cryptogram(
+        &self,
+        token_reference: &str,
+    ) -> CustomResult<NetworkTokenCryptogram, NetworkTokenizationError> {
+        let stored = self.get_token(token_reference)?;
+        if stored.status != TokenStatus::Active {
+            return Err(NetworkTokenizationError::TokenInactive).into_report();
+        }
+
+        let cryptogram = consts::BASE64_ENGINE
+            .encode(crypto::generate_cryptographically_secure_random_bytes::<20>());
+        Ok(NetworkTokenCryptogram {
+            cryptogram: Secret::new(cryptogram),
+            eci: Some(MOCK_ECI.to_owned()),
+        })
+    }
+
+    async fn handle_lifecycle_event(
+        &self,
+        token_reference: &str,
+        event: TokenLifecycleEvent,
+    ) -> CustomResult<(), NetworkTokenizationError> {
+        let mut tokens = self
+            .tokens
+            .write()
+            .map_err(|_| NetworkTokenizationError::LifecycleUpdateFailed)
+            .into_report()?;
+        let stored = tokens
+            .get_mut(token_reference)
+            .ok_or(NetworkTokenizationError::TokenNotFound)
+            .into_report()?;
+
+        if stored.status == TokenStatus::Deleted {
+            return Err(NetworkTokenizationError::TokenInactive).into_report();
+        }
+
+        match event {
+            TokenLifecycleEvent::Suspend => stored.status = TokenStatus::Suspended,
+            TokenLifecycleEvent::Resume => stored.status = TokenStatus::Active,
+            TokenLifecycleEvent::Delete => stored.status = TokenStatus::Deleted,
+            TokenLifecycleEvent::CardUpdated {
+                card_exp_month,
+                card_exp_year,
+            } => {
+                stored.token.token_exp_month = card_exp_month;
+                stored.token.token_exp_year = card_exp_year;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generates a Luhn valid token number of the same length and network (leading digit) as the
+/// card number.
+fn generate_token_number(card_number: &str) -> Option<String> {
+    let digits = card_number
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .map(|character| character.to_digit(10))
+        .collect::<Option<Vec<_>>>()?;
+    let network_digit = digits.first()?;
+    let random_digit_count = digits.len().checked_sub(2)?;
+
+    let random_bytes = crypto::generate_cryptographically_secure_random_bytes::<32>();
+    let mut token_digits = vec![*network_digit];
+    token_digits.extend(
+        random_bytes
+            .iter()
+            .take(random_digit_count)
+            .map(|byte| u32::from(*byte % 10)),
+    );
+    token_digits.push(luhn_check_digit(&token_digits));
+
+    Some(
+        token_digits
+            .into_iter()
+            .filter_map(|digit| char::from_digit(digit, 10))
+            .collect(),
+    )
+}
+
+/// Computes the check digit to be appended to the given digits for the result to pass the Luhn
+/// check.
+fn luhn_check_digit(digits: &[u32]) -> u32 {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 0 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                *digit
+            }
+        })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use masking::ExposeInterface;
+
+    use super::*;
+
+    fn card_details() -> CardDetails {
+        CardDetails {
+            card_number: Secret::new("4111111111111111".to_string()),
+            card_exp_month: Secret::new("03".to_string()),
+            card_exp_year: Secret::new("2030".to_string()),
+            card_holder_name: None,
+        }
+    }
+
+    #[test]
+    fn test_generated_token_number_passes_luhn_check() {
+        let token_number = generate_token_number("4111 1111 1111 1111").unwrap();
+        assert_eq!(token_number.len(), 16);
+        assert!(token_number.starts_with('4'));
+
+        let digits = token_number
+            .chars()
+            .filter_map(|character| character.to_digit(10))
+            .collect::<Vec<_>>();
+        let (check_digit, payload) = digits.split_last().unwrap();
+        assert_eq!(luhn_check_digit(payload), *check_digit);
+    }
+
+    #[tokio::test]
+    async fn test_token_lifecycle() {
+        let provider = MockNetworkTokenProvider::default();
+        let token = provider.provision_token(&card_details()).await.unwrap();
+        assert_ne!(token.token_number.clone().expose(), "4111111111111111");
+
+        let cryptogram = provider
+            .get_cryptogram(&token.token_reference)
+            .await
+            .unwrap();
+        assert_eq!(cryptogram.eci.as_deref(), Some(MOCK_ECI));
+
+        provider
+            .handle_lifecycle_event(&token.token_reference, TokenLifecycleEvent::Suspend)
+            .await
+            .unwrap();
+        assert!(provider
+            .get_cryptogram(&token.token_reference)
+            .await
+            .is_err());
+
+        provider
+            .handle_lifecycle_event(&token.token_reference, TokenLifecycleEvent::Resume)
+            .await
+            .unwrap();
+        assert!(provider
+            .get_cryptogram(&token.token_reference)
+            .await
+            .is_ok());
+
+        provider
+            .handle_lifecycle_event(&token.token_reference, TokenLifecycleEvent::Delete)
+            .await
+            .unwrap();
+        assert!(provider
+            .get_cryptogram(&token.token_reference)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,34 @@
+//! Pass-through encryption management, used when no encryption backend is configured
+
+use common_utils::errors::CustomResult;
+use masking::Secret;
+
+use crate::{
+    encryption_management::{EncryptionError, EncryptionManagementInterface},
+    secrets_management::{SecretManagementInterface, SecretsManagementError},
+};
+
+/// Client that returns its input unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoEncryption;
+
+#[async_trait::async_trait]
+impl EncryptionManagementInterface for NoEncryption {
+    async fn encrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        Ok(input.to_vec())
+    }
+
+    async fn decrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        Ok(input.to_vec())
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for NoEncryption {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        Ok(input)
+    }
+}
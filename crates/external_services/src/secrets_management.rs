@@ -0,0 +1,42 @@
+//! Interactions with the secrets management services
+
+use common_utils::errors::CustomResult;
+use masking::{PeekInterface, Secret};
+
+/// Decrypting configuration values using the configured secrets management service.
+pub mod decrypt;
+
+/// Trait defining the interface for retrieving plaintext secrets from a secrets management
+/// service, given the value stored in the application configuration.
+#[async_trait::async_trait]
+pub trait SecretManagementInterface: Send + Sync {
+    /// Given the (possibly encrypted) configured value, returns the plaintext secret.
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError>;
+}
+
+/// Errors that could occur during secrets management operations.
+#[derive(Debug, thiserror::Error)]
+pub enum SecretsManagementError {
+    /// An error occurred when retrieving the plaintext secret.
+    #[error("Failed to fetch the plaintext secret")]
+    FetchSecretFailed,
+
+    /// The secrets management client has not been initialized.
+    #[error("The secrets management client has not been initialized")]
+    ClientNotInitialized,
+}
+
+/// A wrapper around a configuration value that is stored encrypted, and can be decrypted using
+/// the configured secrets management service.
+#[derive(Clone, Debug, Default, serde::Deserialize, Eq, PartialEq)]
+#[serde(transparent)]
+pub struct KmsValue(Secret<String>);
+
+impl common_utils::ext_traits::ConfigExt for KmsValue {
+    fn is_empty_after_trim(&self) -> bool {
+        self.0.peek().is_empty_after_trim()
+    }
+}
@@ -0,0 +1,44 @@
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+
+use super::*;
+use crate::encryption_management::try_get_encryption_management_client;
+
+#[async_trait::async_trait]
+/// This trait performs in place decryption of the structure on which this is implemented
+pub trait KmsDecrypt {
+    /// The output type of the decryption
+    type Output;
+    /// Decrypts the structure given a secrets management client
+    async fn decrypt_inner(
+        self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self::Output, SecretsManagementError>
+    where
+        Self: Sized;
+
+    /// Tries to use the Singleton client to decrypt the structure
+    async fn try_decrypt_inner(self) -> CustomResult<Self::Output, SecretsManagementError>
+    where
+        Self: Sized,
+    {
+        let client = try_get_encryption_management_client()
+            .change_context(SecretsManagementError::ClientNotInitialized)?;
+        self.decrypt_inner(client).await
+    }
+}
+
+#[async_trait::async_trait]
+impl KmsDecrypt for &KmsValue {
+    type Output = String;
+    async fn decrypt_inner(
+        self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self::Output, SecretsManagementError> {
+        secret_management_client
+            .get_secret(self.0.clone())
+            .await
+            .map(masking::ExposeInterface::expose)
+            .attach_printable("Failed to decrypt the configuration value")
+    }
+}
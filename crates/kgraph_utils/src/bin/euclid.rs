@@ -0,0 +1,316 @@
+//! Command line tool for linting and testing routing programs before they are pushed to the
+//! routing API.
+//!
+//! The program is parsed from its text form, or from JSON if the file has a `.json` extension,
+//! analysed against the knowledge graph of the given merchant connector accounts and then executed
+//! against every fixture, comparing the selected connectors with the expected ones.
+//!
+//! This lives in `kgraph_utils` rather than `euclid` as building the knowledge graph for merchant
+//! connector accounts needs `api_models`, which itself depends on `euclid`.
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use api_models::{admin as admin_api, routing::ConnectorSelection};
+use clap::Parser;
+use euclid::{
+    backend::{inputs, EuclidBackend, InterpreterBackend},
+    dssa::{analyzer, graph},
+    frontend::ast::{self, printer::EuclidPrintable},
+};
+use kgraph_utils::mca;
+
+#[derive(Parser)]
+#[command(version, about = "Lint and test routing programs", long_about = None)]
+struct Args {
+    /// Routing program in the text form of the DSL, or JSON if the file ends with `.json`
+    program: PathBuf,
+    /// JSON list of merchant connector accounts used to build the knowledge graph
+    #[arg(short, long)]
+    mcas: Option<PathBuf>,
+    /// JSON list of test cases, each with a `name`, an `input` and the `expected` connectors
+    #[arg(short, long)]
+    fixtures: Option<PathBuf>,
+}
+
+#[derive(serde::Deserialize)]
+struct Fixture {
+    name: String,
+    input: inputs::BackendInput,
+    expected: ConnectorSelection,
+    /// The rule expected to be selected, `None` if the default selection should be used
+    #[serde(default)]
+    expected_rule: Option<String>,
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {err}", path.display()))
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, String> {
+    serde_json::from_str(&read_file(path)?)
+        .map_err(|err| format!("Failed to parse {}: {err}", path.display()))
+}
+
+fn load_program(path: &Path) -> Result<ast::Program<ConnectorSelection>, String> {
+    if path
+        .extension()
+        .map_or(false, |extension| extension == "json")
+    {
+        read_json(path)
+    } else {
+        ast::parser::parse_program(&read_file(path)?)
+            .map_err(|diagnostic| format!("{}: {diagnostic}", path.display()))
+    }
+}
+
+fn analyze(
+    program: ast::Program<ConnectorSelection>,
+    knowledge_graph: Option<&graph::KnowledgeGraph<'_>>,
+) -> Result<(), String> {
+    analyzer::analyze(program, knowledge_graph)
+        .map(|_| ())
+        .map_err(|err| {
+            let details = serde_json::to_string_pretty(&err).unwrap_or_default();
+            format!("Analysis failed: {err}\n{details}")
+        })
+}
+
+fn write_line(out: &mut impl Write, line: fmt::Arguments<'_>) -> Result<(), String> {
+    writeln!(out, "{line}").map_err(|err| format!("Failed to write the output: {err}"))
+}
+
+fn selection_matches(got: &ConnectorSelection, expected: &ConnectorSelection) -> bool {
+    serde_json::to_value(got).ok() == serde_json::to_value(expected).ok()
+}
+
+/// Runs every fixture against the program, returning the number of failed fixtures.
+fn run_fixtures(
+    program: ast::Program<ConnectorSelection>,
+    fixtures: Vec<Fixture>,
+    out: &mut impl Write,
+) -> Result<usize, String> {
+    let backend = InterpreterBackend::with_program(program)
+        .map_err(|err| format!("Failed to load program: {err:?}"))?;
+
+    let mut failures = 0;
+    for fixture in fixtures {
+        let result = match backend.execute(fixture.input) {
+            Ok(output) => {
+                if !selection_matches(&output.connector_selection, &fixture.expected) {
+                    Err(format!(
+                        "expected {}, got {}",
+                        fixture.expected.print_output(),
+                        output.connector_selection.print_output()
+                    ))
+                } else if fixture.expected_rule.is_some()
+                    && fixture.expected_rule != output.rule_name
+                {
+                    Err(format!(
+                        "expected rule {:?}, got {:?}",
+                        fixture.expected_rule, output.rule_name
+                    ))
+                } else {
+                    Ok(())
+                }
+            }
+            Err(err) => Err(format!("execution failed: {err:?}")),
+        };
+
+        match result {
+            Ok(()) => write_line(out, format_args!("PASS {}", fixture.name))?,
+            Err(reason) => {
+                failures += 1;
+                write_line(out, format_args!("FAIL {}: {reason}", fixture.name))?;
+            }
+        }
+    }
+
+    Ok(failures)
+}
+
+fn run(args: Args, out: &mut impl Write) -> Result<bool, String> {
+    let program = load_program(&args.program)?;
+
+    let knowledge_graph = args
+        .mcas
+        .as_ref()
+        .map(|path| {
+            let mcas: Vec<admin_api::MerchantConnectorResponse> = read_json(path)?;
+            mca::make_mca_graph(mcas)
+                .map_err(|err| format!("Failed to build the knowledge graph: {err:?}"))
+        })
+        .transpose()?;
+
+    analyze(program.clone(), knowledge_graph.as_ref())?;
+    write_line(
+        out,
+        format_args!("Analysis passed for {}", args.program.display()),
+    )?;
+
+    match args.fixtures {
+        Some(path) => {
+            let fixtures: Vec<Fixture> = read_json(&path)?;
+            let total = fixtures.len();
+            let failures = run_fixtures(program, fixtures, out)?;
+            write_line(
+                out,
+                format_args!(
+                    "{} passed, {failures} failed",
+                    total.saturating_sub(failures)
+                ),
+            )?;
+            Ok(failures == 0)
+        }
+        None => Ok(true),
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse(), &mut io::stdout().lock()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use euclid::{enums, frontend::ast::parser::EuclidParsable};
+
+    use super::*;
+
+    const PROGRAM: &str = r#"default: [stripe]
+
+cards: [adyen]
+{
+    payment_method = card
+}
+"#;
+
+    fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("euclid_cli_{name}"));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn selection(text: &str) -> serde_json::Value {
+        let (_, selection) = ConnectorSelection::parse_output(text).unwrap();
+        serde_json::to_value(selection).unwrap()
+    }
+
+    fn fixture(
+        name: &str,
+        payment_method: enums::PaymentMethod,
+        expected: &str,
+    ) -> serde_json::Value {
+        let input = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: 1000,
+                currency: enums::Currency::USD,
+                authentication_type: None,
+                card_bin: None,
+                card_fingerprint: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(payment_method),
+                payment_method_type: None,
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+
+        serde_json::json!({
+            "name": name,
+            "input": input,
+            "expected": selection(expected),
+        })
+    }
+
+    #[test]
+    fn test_run_reports_analysis_and_fixture_results() {
+        let program = write_temp_file("fixtures_program.txt", PROGRAM);
+        let fixtures = serde_json::json!([
+            fixture("card", enums::PaymentMethod::Card, "[adyen]"),
+            fixture("wallet", enums::PaymentMethod::Wallet, "[adyen]"),
+        ]);
+        let fixtures = write_temp_file("fixtures.json", &fixtures.to_string());
+
+        let mut out = Vec::new();
+        let passed = run(
+            Args {
+                program: program.clone(),
+                mcas: None,
+                fixtures: Some(fixtures),
+            },
+            &mut out,
+        )
+        .unwrap();
+
+        assert!(!passed);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "Analysis passed for {}\nPASS card\nFAIL wallet: expected [adyen], got [stripe]\n1 passed, 1 failed\n",
+                program.display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_run_reports_parse_and_analysis_failures() {
+        let unparsable = write_temp_file(
+            "unparsable_program.txt",
+            "default: [stripe]\n\ncards: [adyen]\n{\n    payment_method == card\n}\n",
+        );
+        let mut out = Vec::new();
+        let err = run(
+            Args {
+                program: unparsable.clone(),
+                mcas: None,
+                fixtures: None,
+            },
+            &mut out,
+        )
+        .unwrap_err();
+        assert!(err.starts_with(&format!("{}: ", unparsable.display())));
+        assert!(err.contains("at line 5"));
+        assert!(out.is_empty());
+
+        let shadowed = write_temp_file(
+            "shadowed_program.txt",
+            &format!("{PROGRAM}\nlarge_cards: [stripe]\n{{\n    payment_method = card & amount > 500\n}}\n"),
+        );
+        let err = run(
+            Args {
+                program: shadowed,
+                mcas: None,
+                fixtures: None,
+            },
+            &mut out,
+        )
+        .unwrap_err();
+        assert!(err.starts_with(
+            "Analysis failed: Rule 'large_cards' is shadowed by the earlier rule 'cards'"
+        ));
+        assert!(out.is_empty());
+    }
+}
@@ -12,14 +12,177 @@ use euclid::{
     types::{NumValue, NumValueRefinement},
 };
 
-use crate::{error::KgraphError, transformers::IntoDirValue};
+use crate::{
+    error::KgraphError,
+    transformers::{self, IntoDirValue},
+};
 
 pub const DOMAIN_IDENTIFIER: &str = "payment_methods_enabled_for_merchantconnectoraccount";
 
+/// Compiles the country and currency restrictions of a payment method filter. The restrictions
+/// are weak, so that they only apply when the billing country or currency is known, like in the
+/// payment method list.
+fn compile_pm_filter_restrictions(
+    builder: &mut graph::KnowledgeGraphBuilder<'_>,
+    pm_filter: &admin_api::PaymentMethodFilter,
+) -> Result<Vec<(graph::NodeId, graph::Relation, graph::Strength)>, KgraphError> {
+    let mut restriction_nodes = Vec::new();
+
+    if let Some(currencies) = pm_filter
+        .currencies
+        .as_ref()
+        .filter(|currencies| !currencies.is_empty())
+    {
+        let dir_vals: Vec<dir::DirValue> = currencies
+            .iter()
+            .copied()
+            .map(IntoDirValue::into_dir_value)
+            .collect::<Result<_, _>>()?;
+
+        let filtered_currencies_info = "Filtered Currencies";
+        let filtered_currencies_id = builder
+            .make_in_aggregator(
+                dir_vals,
+                Some(filtered_currencies_info),
+                None::<()>,
+                Vec::new(),
+            )
+            .map_err(KgraphError::GraphConstructionError)?;
+
+        restriction_nodes.push((
+            filtered_currencies_id,
+            graph::Relation::Positive,
+            graph::Strength::Weak,
+        ));
+    }
+
+    if let Some(countries) = pm_filter
+        .countries
+        .as_ref()
+        .filter(|countries| !countries.is_empty())
+    {
+        let dir_vals: Vec<dir::DirValue> = countries
+            .iter()
+            .map(|country| {
+                transformers::billing_country_to_dir_value(api_enums::Country::from_alpha2(
+                    *country,
+                ))
+            })
+            .collect();
+
+        let filtered_countries_info = "Filtered Countries";
+        let filtered_countries_id = builder
+            .make_in_aggregator(
+                dir_vals,
+                Some(filtered_countries_info),
+                None::<()>,
+                Vec::new(),
+            )
+            .map_err(KgraphError::GraphConstructionError)?;
+
+        restriction_nodes.push((
+            filtered_countries_id,
+            graph::Relation::Positive,
+            graph::Strength::Weak,
+        ));
+    }
+
+    Ok(restriction_nodes)
+}
+
+/// Compiles the card network filters into a node that is satisfied if the card network is unknown
+/// or unfiltered, or if the restrictions of the filter for the card network are met.
+fn compile_card_network_filters(
+    builder: &mut graph::KnowledgeGraphBuilder<'_>,
+    pm_filters: &[admin_api::PaymentMethodFilter],
+) -> Result<Option<graph::NodeId>, KgraphError> {
+    let mut filtered_networks = Vec::new();
+    let mut network_nodes = Vec::new();
+
+    for pm_filter in pm_filters {
+        let card_network = match &pm_filter.card_network {
+            Some(card_network) => card_network.clone(),
+            None => continue,
+        };
+        let restriction_nodes = compile_pm_filter_restrictions(builder, pm_filter)?;
+        if restriction_nodes.is_empty() {
+            continue;
+        }
+
+        let card_network_val = card_network.into_dir_value()?;
+        filtered_networks.push(card_network_val.clone());
+
+        let card_network_info = "Filtered Card Network";
+        let card_network_id = builder
+            .make_value_node(
+                card_network_val.into(),
+                Some(card_network_info),
+                vec![DomainIdentifier::new(DOMAIN_IDENTIFIER)],
+                None::<()>,
+            )
+            .map_err(KgraphError::GraphConstructionError)?;
+
+        let mut all_nodes = vec![(
+            card_network_id,
+            graph::Relation::Positive,
+            graph::Strength::Strong,
+        )];
+        all_nodes.extend(restriction_nodes);
+
+        let card_network_filter_info = "All Aggregator for Card Network Filter";
+        let card_network_filter_id = builder
+            .make_all_aggregator(
+                &all_nodes,
+                Some(card_network_filter_info),
+                None::<()>,
+                Vec::new(),
+            )
+            .map_err(KgraphError::GraphConstructionError)?;
+
+        network_nodes.push((card_network_filter_id, graph::Relation::Positive));
+    }
+
+    if filtered_networks.is_empty() {
+        return Ok(None);
+    }
+
+    let filters_agg_info = "Any Aggregator for Card Network Filters";
+    let filters_agg_id = builder
+        .make_any_aggregator(
+            &network_nodes,
+            Some(filters_agg_info),
+            None::<()>,
+            Vec::new(),
+        )
+        .map_err(KgraphError::GraphConstructionError)?;
+
+    // Satisfied when the card network is unknown or is not one of the filtered networks
+    let unfiltered_networks_info = "Unfiltered Card Networks";
+    let unfiltered_networks_id = builder
+        .make_in_aggregator(
+            filtered_networks,
+            Some(unfiltered_networks_info),
+            None::<()>,
+            Vec::new(),
+        )
+        .map_err(KgraphError::GraphConstructionError)?;
+    builder
+        .make_edge(
+            unfiltered_networks_id,
+            filters_agg_id,
+            graph::Strength::Weak,
+            graph::Relation::Negative,
+        )
+        .map_err(KgraphError::GraphConstructionError)?;
+
+    Ok(Some(filters_agg_id))
+}
+
 fn compile_request_pm_types(
     builder: &mut graph::KnowledgeGraphBuilder<'_>,
     pm_types: RequestPaymentMethodTypes,
     pm: api_enums::PaymentMethod,
+    pm_filters: &[admin_api::PaymentMethodFilter],
 ) -> Result<graph::NodeId, KgraphError> {
     let mut agg_nodes: Vec<(graph::NodeId, graph::Relation, graph::Strength)> = Vec::new();
 
@@ -102,6 +265,29 @@ fn compile_request_pm_types(
         agg_nodes.push((accepted_currencies_id, relation, graph::Strength::Strong));
     }
 
+    match pm_types.payment_method_type {
+        api_enums::PaymentMethodType::Credit | api_enums::PaymentMethodType::Debit => {
+            if let Some(card_network_filters_id) =
+                compile_card_network_filters(builder, pm_filters)?
+            {
+                agg_nodes.push((
+                    card_network_filters_id,
+                    graph::Relation::Positive,
+                    graph::Strength::Strong,
+                ));
+            }
+        }
+
+        payment_method_type => {
+            if let Some(pm_filter) = pm_filters
+                .iter()
+                .find(|pm_filter| pm_filter.payment_method_type == Some(payment_method_type))
+            {
+                agg_nodes.extend(compile_pm_filter_restrictions(builder, pm_filter)?);
+            }
+        }
+    }
+
     let mut amount_nodes = Vec::with_capacity(2);
 
     if let Some(min_amt) = pm_types.minimum_amount {
@@ -212,6 +398,7 @@ fn compile_request_pm_types(
 fn compile_payment_method_enabled(
     builder: &mut graph::KnowledgeGraphBuilder<'_>,
     enabled: admin_api::PaymentMethodsEnabled,
+    pm_filters: &[admin_api::PaymentMethodFilter],
 ) -> Result<Option<graph::NodeId>, KgraphError> {
     let agg_id = if !enabled
         .payment_method_types
@@ -233,7 +420,8 @@ fn compile_payment_method_enabled(
 
         if let Some(pm_types) = enabled.payment_method_types {
             for pm_type in pm_types {
-                let node_id = compile_request_pm_types(builder, pm_type, enabled.payment_method)?;
+                let node_id =
+                    compile_request_pm_types(builder, pm_type, enabled.payment_method, pm_filters)?;
                 agg_nodes.push((node_id, graph::Relation::Positive));
             }
         }
@@ -282,9 +470,11 @@ fn compile_merchant_connector_graph(
 
     let mut agg_nodes: Vec<(graph::NodeId, graph::Relation)> = Vec::new();
 
+    let pm_filters = mca.pm_filters.unwrap_or_default();
     if let Some(pms_enabled) = mca.payment_methods_enabled {
         for pm_enabled in pms_enabled {
-            let maybe_pm_enabled_id = compile_payment_method_enabled(builder, pm_enabled)?;
+            let maybe_pm_enabled_id =
+                compile_payment_method_enabled(builder, pm_enabled, &pm_filters)?;
             if let Some(pm_enabled_id) = maybe_pm_enabled_id {
                 agg_nodes.push((pm_enabled_id, graph::Relation::Positive));
             }
@@ -352,6 +542,12 @@ mod tests {
     use super::*;
 
     fn build_test_data<'a>() -> graph::KnowledgeGraph<'a> {
+        build_test_data_with_pm_filters(None)
+    }
+
+    fn build_test_data_with_pm_filters<'a>(
+        pm_filters: Option<Vec<admin_api::PaymentMethodFilter>>,
+    ) -> graph::KnowledgeGraph<'a> {
         use api_models::{admin::*, payment_methods::*};
 
         let stripe_account = MerchantConnectorResponse {
@@ -410,11 +606,45 @@ mod tests {
             profile_id: None,
             applepay_verified_domains: None,
             pm_auth_config: None,
+            pm_filters,
         };
 
         make_mca_graph(vec![stripe_account]).expect("Failed graph construction")
     }
 
+    #[test]
+    fn test_card_network_pm_filter() {
+        let graph = build_test_data_with_pm_filters(Some(vec![admin_api::PaymentMethodFilter {
+            payment_method_type: None,
+            card_network: Some(api_enums::CardNetwork::Visa),
+            countries: None,
+            currencies: Some(vec![api_enums::Currency::USD]),
+        }]));
+
+        let check = |card_network, currency| {
+            graph.key_value_analysis(
+                dirval!(Connector = Stripe),
+                &AnalysisContext::from_dir_values([
+                    dirval!(Connector = Stripe),
+                    dirval!(PaymentMethod = Card),
+                    dirval!(CardType = Credit),
+                    card_network,
+                    currency,
+                    dirval!(PaymentAmount = 100),
+                ]),
+                &mut Memoization::new(),
+            )
+        };
+
+        assert!(check(dirval!(CardNetwork = Visa), dirval!(PaymentCurrency = USD)).is_ok());
+        assert!(check(dirval!(CardNetwork = Visa), dirval!(PaymentCurrency = INR)).is_err());
+        assert!(check(
+            dirval!(CardNetwork = Mastercard),
+            dirval!(PaymentCurrency = INR)
+        )
+        .is_ok());
+    }
+
     #[test]
     fn test_credit_card_success_case() {
         let graph = build_test_data();
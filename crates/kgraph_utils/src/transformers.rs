@@ -40,6 +40,11 @@ impl IntoContext for BackendInput {
                 value: business_label,
             }));
         }
+        if let Some(card_fingerprint) = self.payment.card_fingerprint {
+            ctx.push(dir::DirValue::CardFingerprint(StrValue {
+                value: card_fingerprint,
+            }));
+        }
         if let Some(billing_country) = self.payment.billing_country {
             ctx.push(dir::DirValue::BillingCountry(billing_country));
         }
@@ -217,6 +217,15 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -12,6 +12,8 @@ pub enum RedisError {
     SetExFailed,
     #[error("Failed to set expiry for key value in Redis")]
     SetExpiryFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to get key value in Redis")]
     GetFailed,
     #[error("Failed to delete key value in Redis")]
@@ -1,9 +1,12 @@
+mod connector_performance;
 mod core;
+mod disputes;
 mod errors;
 pub mod metrics;
 mod payments;
 mod query;
 mod refunds;
+pub mod reports;
 pub mod routes;
 
 mod sqlx;
@@ -11,6 +14,11 @@ mod types;
 mod utils;
 
 use api_models::analytics::{
+    connector_performance::{
+        ConnectorPerformanceDimensions, ConnectorPerformanceFilters, ConnectorPerformanceMetrics,
+        ConnectorPerformanceMetricsBucketIdentifier,
+    },
+    disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics, DisputeMetricsBucketIdentifier},
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
     Granularity, TimeRange,
@@ -18,6 +26,8 @@ use api_models::analytics::{
 use router_env::{instrument, tracing};
 
 use self::{
+    connector_performance::metrics::{ConnectorPerformanceMetric, ConnectorPerformanceMetricRow},
+    disputes::metrics::{DisputeMetric, DisputeMetricRow},
     payments::metrics::{PaymentMetric, PaymentMetricRow},
     refunds::metrics::{RefundMetric, RefundMetricRow},
     sqlx::SqlxClient,
@@ -96,16 +106,71 @@ impl AnalyticsProvider {
         }
     }
 
+    pub async fn get_dispute_metrics(
+        &self,
+        metric: &DisputeMetrics,
+        dimensions: &[DisputeDimensions],
+        merchant_id: &str,
+        filters: &DisputeFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<Vec<(DisputeMetricsBucketIdentifier, DisputeMetricRow)>> {
+        match self {
+            Self::Sqlx(pool) => {
+                metric
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+
+    pub async fn get_connector_performance_metrics(
+        &self,
+        metric: &ConnectorPerformanceMetrics,
+        dimensions: &[ConnectorPerformanceDimensions],
+        merchant_id: &str,
+        filters: &ConnectorPerformanceFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<
+        Vec<(
+            ConnectorPerformanceMetricsBucketIdentifier,
+            ConnectorPerformanceMetricRow,
+        )>,
+    > {
+        match self {
+            Self::Sqlx(pool) => {
+                metric
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+
     pub async fn from_conf(
         config: &AnalyticsConfig,
-        #[cfg(feature = "kms")] kms_client: &external_services::kms::KmsClient,
+        #[cfg(feature = "encryption_service")] secret_management_client: &dyn external_services::secrets_management::SecretManagementInterface,
     ) -> Self {
         match config {
             AnalyticsConfig::Sqlx { sqlx } => Self::Sqlx(
                 SqlxClient::from_conf(
                     sqlx,
-                    #[cfg(feature = "kms")]
-                    kms_client,
+                    #[cfg(feature = "encryption_service")]
+                    secret_management_client,
                 )
                 .await,
             ),
@@ -0,0 +1,10 @@
+pub mod accumulator;
+mod core;
+pub mod filters;
+pub mod metrics;
+pub mod types;
+pub use accumulator::{
+    ConnectorPerformanceMetricAccumulator, ConnectorPerformanceMetricsAccumulator,
+};
+
+pub use self::core::get_metrics;
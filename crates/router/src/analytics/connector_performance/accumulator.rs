@@ -0,0 +1,108 @@
+use api_models::analytics::connector_performance::ConnectorPerformanceMetricsBucketValue;
+
+use super::metrics::ConnectorPerformanceMetricRow;
+
+#[derive(Debug, Default)]
+pub struct ConnectorPerformanceMetricsAccumulator {
+    pub attempt_count: CountAccumulator,
+    pub connector_latency_p50: PercentileAccumulator,
+    pub connector_latency_p95: PercentileAccumulator,
+    pub error_rate: RateAccumulator,
+    pub retry_rate: RateAccumulator,
+    pub step_up_rate: RateAccumulator,
+    pub three_ds_challenge_rate: RateAccumulator,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct PercentileAccumulator {
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+pub struct RateAccumulator {
+    pub flagged: i64,
+    pub total: i64,
+}
+
+pub trait ConnectorPerformanceMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorPerformanceMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl ConnectorPerformanceMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorPerformanceMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl ConnectorPerformanceMetricAccumulator for PercentileAccumulator {
+    type MetricOutput = Option<f64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorPerformanceMetricRow) {
+        // Percentiles cannot be merged across rows, each bucket is expected to hold a single row
+        if metrics.latency.is_some() {
+            self.value = metrics.latency;
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.value
+    }
+}
+
+impl ConnectorPerformanceMetricAccumulator for RateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorPerformanceMetricRow) {
+        let count = metrics.count.unwrap_or_default();
+        if metrics.flagged.unwrap_or_default() {
+            self.flagged += count;
+        }
+        self.total += count;
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.flagged).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.total).ok()?),
+            )
+        }
+    }
+}
+
+impl ConnectorPerformanceMetricsAccumulator {
+    pub fn collect(self) -> ConnectorPerformanceMetricsBucketValue {
+        ConnectorPerformanceMetricsBucketValue {
+            attempt_count: self.attempt_count.collect(),
+            connector_latency_p50: self.connector_latency_p50.collect(),
+            connector_latency_p95: self.connector_latency_p95.collect(),
+            error_rate: self.error_rate.collect(),
+            retry_rate: self.retry_rate.collect(),
+            step_up_rate: self.step_up_rate.collect(),
+            three_ds_challenge_rate: self.three_ds_challenge_rate.collect(),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    connector_performance::{
+        ConnectorPerformanceMetrics, ConnectorPerformanceMetricsBucketIdentifier,
+        ConnectorPerformanceMetricsBucketResponse,
+    },
+    AnalyticsMetadata, GetConnectorPerformanceMetricRequest, MetricsResponse,
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{
+    logger,
+    tracing::{self, Instrument},
+};
+
+use super::ConnectorPerformanceMetricsAccumulator;
+use crate::{
+    analytics::{
+        connector_performance::ConnectorPerformanceMetricAccumulator, core::AnalyticsApiResponse,
+        errors::AnalyticsError, AnalyticsProvider,
+    },
+    services::ApplicationResponse,
+    types::domain,
+};
+
+pub async fn get_metrics(
+    pool: AnalyticsProvider,
+    merchant_account: domain::MerchantAccount,
+    req: GetConnectorPerformanceMetricRequest,
+) -> AnalyticsApiResponse<MetricsResponse<ConnectorPerformanceMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        ConnectorPerformanceMetricsBucketIdentifier,
+        ConnectorPerformanceMetricsAccumulator,
+    > = HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let merchant_id = merchant_account.merchant_id.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_connector_performance_query",
+            connector_performance_metric = metric_type.as_ref()
+        );
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_connector_performance_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &merchant_id,
+                        &req.filters,
+                        &req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .into_report()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        for (id, value) in data? {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                ConnectorPerformanceMetrics::AttemptCount => {
+                    metrics_builder.attempt_count.add_metrics_bucket(&value)
+                }
+                ConnectorPerformanceMetrics::ConnectorLatencyP50 => metrics_builder
+                    .connector_latency_p50
+                    .add_metrics_bucket(&value),
+                ConnectorPerformanceMetrics::ConnectorLatencyP95 => metrics_builder
+                    .connector_latency_p95
+                    .add_metrics_bucket(&value),
+                ConnectorPerformanceMetrics::ErrorRate => {
+                    metrics_builder.error_rate.add_metrics_bucket(&value)
+                }
+                ConnectorPerformanceMetrics::RetryRate => {
+                    metrics_builder.retry_rate.add_metrics_bucket(&value)
+                }
+                ConnectorPerformanceMetrics::StepUpRate => {
+                    metrics_builder.step_up_rate.add_metrics_bucket(&value)
+                }
+                ConnectorPerformanceMetrics::ThreeDsChallengeRate => metrics_builder
+                    .three_ds_challenge_rate
+                    .add_metrics_bucket(&value),
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+    let query_data: Vec<ConnectorPerformanceMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| ConnectorPerformanceMetricsBucketResponse {
+            values: val.collect(),
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    }))
+}
@@ -0,0 +1,62 @@
+use api_models::analytics::{
+    connector_performance::ConnectorPerformanceDimensions, Granularity, TimeRange,
+};
+use common_enums::enums::{AuthenticationType, Currency};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::analytics::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, FiltersError, FiltersResult,
+        LoadRow,
+    },
+};
+
+pub trait ConnectorPerformanceFilterAnalytics: LoadRow<ConnectorPerformanceFilterRow> {}
+
+pub async fn get_connector_performance_filter_for_dimension<T>(
+    dimension: ConnectorPerformanceDimensions,
+    merchant: &String,
+    time_range: &TimeRange,
+    pool: &T,
+) -> FiltersResult<Vec<ConnectorPerformanceFilterRow>>
+where
+    T: AnalyticsDataSource + ConnectorPerformanceFilterAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::ConnectorPerformance);
+
+    query_builder.add_select_column(dimension).switch()?;
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant)
+        .switch()?;
+
+    query_builder.set_distinct();
+
+    query_builder
+        .execute_query::<ConnectorPerformanceFilterRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+pub struct ConnectorPerformanceFilterRow {
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub authentication_type: Option<DBEnumWrapper<AuthenticationType>>,
+    pub error_code: Option<String>,
+    pub gsm_decision: Option<String>,
+}
@@ -0,0 +1,187 @@
+use api_models::analytics::{
+    connector_performance::{
+        ConnectorPerformanceDimensions, ConnectorPerformanceFilters, ConnectorPerformanceMetrics,
+        ConnectorPerformanceMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use common_enums::enums as storage_enums;
+use time::PrimitiveDateTime;
+mod attempt_count;
+mod attempt_rate;
+mod connector_latency;
+use attempt_count::AttemptCount;
+use attempt_rate::AttemptRate;
+use connector_latency::ConnectorLatency;
+
+use crate::analytics::{
+    query::{Aggregate, GroupByClause, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+
+#[derive(Debug, PartialEq)]
+pub struct ConnectorPerformanceMetricRow {
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub authentication_type: Option<DBEnumWrapper<storage_enums::AuthenticationType>>,
+    pub error_code: Option<String>,
+    pub gsm_decision: Option<String>,
+    /// Whether the attempts in the row are counted towards the rate being computed
+    pub flagged: Option<bool>,
+    pub latency: Option<f64>,
+    pub count: Option<i64>,
+    pub start_bucket: Option<PrimitiveDateTime>,
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait ConnectorPerformanceMetricAnalytics: LoadRow<ConnectorPerformanceMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait ConnectorPerformanceMetric<T>
+where
+    T: AnalyticsDataSource + ConnectorPerformanceMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorPerformanceDimensions],
+        merchant_id: &str,
+        filters: &ConnectorPerformanceFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        Vec<(
+            ConnectorPerformanceMetricsBucketIdentifier,
+            ConnectorPerformanceMetricRow,
+        )>,
+    >;
+}
+
+#[async_trait::async_trait]
+impl<T> ConnectorPerformanceMetric<T> for ConnectorPerformanceMetrics
+where
+    T: AnalyticsDataSource + ConnectorPerformanceMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorPerformanceDimensions],
+        merchant_id: &str,
+        filters: &ConnectorPerformanceFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        Vec<(
+            ConnectorPerformanceMetricsBucketIdentifier,
+            ConnectorPerformanceMetricRow,
+        )>,
+    > {
+        match self {
+            Self::AttemptCount => {
+                AttemptCount::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ConnectorLatencyP50 => {
+                ConnectorLatency { percentile: 50 }
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ConnectorLatencyP95 => {
+                ConnectorLatency { percentile: 95 }
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ErrorRate => {
+                AttemptRate {
+                    flag_column: "errored",
+                    authentication_type: None,
+                }
+                .load_metrics(
+                    dimensions,
+                    merchant_id,
+                    filters,
+                    granularity,
+                    time_range,
+                    pool,
+                )
+                .await
+            }
+            Self::RetryRate => {
+                AttemptRate {
+                    flag_column: "retried",
+                    authentication_type: None,
+                }
+                .load_metrics(
+                    dimensions,
+                    merchant_id,
+                    filters,
+                    granularity,
+                    time_range,
+                    pool,
+                )
+                .await
+            }
+            Self::StepUpRate => {
+                AttemptRate {
+                    flag_column: "stepped_up",
+                    authentication_type: None,
+                }
+                .load_metrics(
+                    dimensions,
+                    merchant_id,
+                    filters,
+                    granularity,
+                    time_range,
+                    pool,
+                )
+                .await
+            }
+            Self::ThreeDsChallengeRate => {
+                AttemptRate {
+                    flag_column: "three_ds_challenged",
+                    authentication_type: Some(storage_enums::AuthenticationType::ThreeDs),
+                }
+                .load_metrics(
+                    dimensions,
+                    merchant_id,
+                    filters,
+                    granularity,
+                    time_range,
+                    pool,
+                )
+                .await
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use api_models::analytics::{
+    connector_performance::{
+        ConnectorPerformanceDimensions, ConnectorPerformanceFilters,
+        ConnectorPerformanceMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::ConnectorPerformanceMetricRow;
+use crate::analytics::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, SeriesBucket, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+pub(super) struct ConnectorLatency {
+    /// The percentile of the connector latency to compute, between 0 and 100
+    pub percentile: u8,
+}
+
+#[async_trait::async_trait]
+impl<T> super::ConnectorPerformanceMetric<T> for ConnectorLatency
+where
+    T: AnalyticsDataSource + super::ConnectorPerformanceMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorPerformanceDimensions],
+        merchant_id: &str,
+        filters: &ConnectorPerformanceFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        Vec<(
+            ConnectorPerformanceMetricsBucketIdentifier,
+            ConnectorPerformanceMetricRow,
+        )>,
+    > {
+        let mut query_builder: QueryBuilder<T> =
+            QueryBuilder::new(AnalyticsCollection::ConnectorPerformance);
+
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
+        query_builder
+            .add_select_column(Aggregate::Percentile {
+                field: "connector_latency",
+                alias: Some("latency"),
+                percentile: self.percentile,
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Min {
+                field: "created_at",
+                alias: Some("start_bucket"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Max {
+                field: "created_at",
+                alias: Some("end_bucket"),
+            })
+            .switch()?;
+
+        filters.set_filter_clause(&mut query_builder).switch()?;
+
+        query_builder
+            .add_filter_clause("merchant_id", merchant_id)
+            .switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
+        if let Some(granularity) = granularity.as_ref() {
+            granularity
+                .set_group_by_clause(&mut query_builder)
+                .attach_printable("Error adding granularity")
+                .switch()?;
+        }
+
+        query_builder
+            .execute_query::<ConnectorPerformanceMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?
+            .into_iter()
+            .map(|i| {
+                Ok((
+                    ConnectorPerformanceMetricsBucketIdentifier::new(
+                        i.connector.clone(),
+                        i.payment_method.clone(),
+                        i.currency.as_ref().map(|i| i.0),
+                        i.authentication_type.as_ref().map(|i| i.0),
+                        i.error_code.clone(),
+                        i.gsm_decision.clone(),
+                        TimeRange {
+                            start_time: match (granularity, i.start_bucket) {
+                                (Some(g), Some(st)) => g.clip_to_start(st)?,
+                                _ => time_range.start_time,
+                            },
+                            end_time: granularity.as_ref().map_or_else(
+                                || Ok(time_range.end_time),
+                                |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                            )?,
+                        },
+                    ),
+                    i,
+                ))
+            })
+            .collect::<error_stack::Result<Vec<_>, crate::analytics::query::PostProcessingError>>()
+            .change_context(MetricsError::PostProcessingFailure)
+    }
+}
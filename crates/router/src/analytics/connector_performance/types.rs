@@ -0,0 +1,72 @@
+use api_models::analytics::connector_performance::{
+    ConnectorPerformanceDimensions, ConnectorPerformanceFilters,
+};
+use error_stack::ResultExt;
+
+use crate::analytics::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for ConnectorPerformanceFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorPerformanceDimensions::Connector,
+                    &self.connector,
+                )
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorPerformanceDimensions::Currency,
+                    &self.currency,
+                )
+                .attach_printable("Error adding currency filter")?;
+        }
+
+        if !self.payment_method.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorPerformanceDimensions::PaymentMethod,
+                    &self.payment_method,
+                )
+                .attach_printable("Error adding payment method filter")?;
+        }
+
+        if !self.auth_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorPerformanceDimensions::AuthType,
+                    &self.auth_type,
+                )
+                .attach_printable("Error adding auth type filter")?;
+        }
+
+        if !self.error_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorPerformanceDimensions::ErrorCode,
+                    &self.error_code,
+                )
+                .attach_printable("Error adding error code filter")?;
+        }
+
+        if !self.gsm_decision.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorPerformanceDimensions::GsmDecision,
+                    &self.gsm_decision,
+                )
+                .attach_printable("Error adding gsm decision filter")?;
+        }
+        Ok(())
+    }
+}
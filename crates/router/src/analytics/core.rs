@@ -1,11 +1,18 @@
 use api_models::analytics::{
-    payments::PaymentDimensions, refunds::RefundDimensions, FilterValue, GetInfoResponse,
+    connector_performance::ConnectorPerformanceDimensions, disputes::DisputeDimensions,
+    payments::PaymentDimensions, refunds::RefundDimensions, ConnectorPerformanceFilterValue,
+    ConnectorPerformanceFiltersResponse, DisputeFilterValue, DisputeFiltersResponse, FilterValue,
+    GetConnectorPerformanceFilterRequest, GetDisputeFilterRequest, GetInfoResponse,
     GetPaymentFiltersRequest, GetRefundFilterRequest, PaymentFiltersResponse, RefundFilterValue,
     RefundFiltersResponse,
 };
 use error_stack::ResultExt;
 
 use super::{
+    connector_performance::filters::{
+        get_connector_performance_filter_for_dimension, ConnectorPerformanceFilterRow,
+    },
+    disputes::filters::{get_dispute_filter_for_dimension, DisputeFilterRow},
     errors::{self, AnalyticsError},
     payments::filters::{get_payment_filter_for_dimension, FilterRow},
     refunds::filters::{get_refund_filter_for_dimension, RefundFilterRow},
@@ -28,6 +35,16 @@ pub async fn get_domain_info(domain: AnalyticsDomain) -> AnalyticsApiResponse<Ge
             download_dimensions: None,
             dimensions: utils::get_refund_dimensions(),
         },
+        AnalyticsDomain::Disputes => GetInfoResponse {
+            metrics: utils::get_dispute_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_dispute_dimensions(),
+        },
+        AnalyticsDomain::ConnectorPerformance => GetInfoResponse {
+            metrics: utils::get_connector_performance_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_connector_performance_dimensions(),
+        },
     };
     Ok(ApplicationResponse::Json(info))
 }
@@ -94,3 +111,75 @@ pub async fn refund_filter_core(
     }
     Ok(ApplicationResponse::Json(res))
 }
+
+pub async fn dispute_filter_core(
+    pool: AnalyticsProvider,
+    req: GetDisputeFilterRequest,
+    merchant: domain::MerchantAccount,
+) -> AnalyticsApiResponse<DisputeFiltersResponse> {
+    let mut res = DisputeFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool.clone() {
+            AnalyticsProvider::Sqlx(pool) => {
+                get_dispute_filter_for_dimension(dim, &merchant.merchant_id, &req.time_range, &pool)
+                    .await
+            }
+        }
+        .change_context(AnalyticsError::UnknownError)?
+        .into_iter()
+        .filter_map(|fil: DisputeFilterRow| match dim {
+            DisputeDimensions::Connector => fil.connector,
+            DisputeDimensions::Currency => fil.currency,
+            DisputeDimensions::DisputeStage => fil.dispute_stage.map(|i| i.as_ref().to_string()),
+            DisputeDimensions::DisputeStatus => fil.dispute_status.map(|i| i.as_ref().to_string()),
+            DisputeDimensions::ConnectorReason => fil.connector_reason,
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(DisputeFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(ApplicationResponse::Json(res))
+}
+
+pub async fn connector_performance_filter_core(
+    pool: AnalyticsProvider,
+    req: GetConnectorPerformanceFilterRequest,
+    merchant: domain::MerchantAccount,
+) -> AnalyticsApiResponse<ConnectorPerformanceFiltersResponse> {
+    let mut res = ConnectorPerformanceFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool.clone() {
+            AnalyticsProvider::Sqlx(pool) => {
+                get_connector_performance_filter_for_dimension(
+                    dim,
+                    &merchant.merchant_id,
+                    &req.time_range,
+                    &pool,
+                )
+                .await
+            }
+        }
+        .change_context(AnalyticsError::UnknownError)?
+        .into_iter()
+        .filter_map(|fil: ConnectorPerformanceFilterRow| match dim {
+            ConnectorPerformanceDimensions::Connector => fil.connector,
+            ConnectorPerformanceDimensions::PaymentMethod => fil.payment_method,
+            ConnectorPerformanceDimensions::Currency => {
+                fil.currency.map(|i| i.as_ref().to_string())
+            }
+            ConnectorPerformanceDimensions::AuthType => {
+                fil.authentication_type.map(|i| i.as_ref().to_string())
+            }
+            ConnectorPerformanceDimensions::ErrorCode => fil.error_code,
+            ConnectorPerformanceDimensions::GsmDecision => fil.gsm_decision,
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(ConnectorPerformanceFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(ApplicationResponse::Json(res))
+}
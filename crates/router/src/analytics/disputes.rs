@@ -0,0 +1,8 @@
+pub mod accumulator;
+mod core;
+pub mod filters;
+pub mod metrics;
+pub mod types;
+pub use accumulator::{DisputeMetricAccumulator, DisputeMetricsAccumulator};
+
+pub use self::core::get_metrics;
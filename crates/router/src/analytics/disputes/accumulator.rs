@@ -0,0 +1,114 @@
+use api_models::analytics::disputes::DisputeMetricsBucketValue;
+use common_enums::enums as storage_enums;
+
+use super::metrics::DisputeMetricRow;
+
+#[derive(Debug, Default)]
+pub struct DisputeMetricsAccumulator {
+    pub dispute_count: CountAccumulator,
+    pub total_amount_disputed: SumAccumulator,
+    pub dispute_win_rate: WinRateAccumulator,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct SumAccumulator {
+    pub total: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct WinRateAccumulator {
+    pub won: i64,
+    pub decided: i64,
+}
+
+pub trait DisputeMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl DisputeMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl DisputeMetricAccumulator for SumAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        self.total = match (
+            self.total,
+            metrics
+                .total
+                .as_ref()
+                .and_then(bigdecimal::ToPrimitive::to_i64),
+        ) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.total.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl DisputeMetricAccumulator for WinRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        // Only the disputes which have been decided count towards the win rate
+        match metrics.dispute_status.as_ref().map(|status| status.0) {
+            Some(storage_enums::DisputeStatus::DisputeWon) => {
+                self.won += metrics.count.unwrap_or_default();
+                self.decided += metrics.count.unwrap_or_default();
+            }
+            Some(storage_enums::DisputeStatus::DisputeLost) => {
+                self.decided += metrics.count.unwrap_or_default();
+            }
+            _ => {}
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.decided <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.won).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.decided).ok()?),
+            )
+        }
+    }
+}
+
+impl DisputeMetricsAccumulator {
+    pub fn collect(self) -> DisputeMetricsBucketValue {
+        DisputeMetricsBucketValue {
+            dispute_count: self.dispute_count.collect(),
+            total_amount_disputed: self.total_amount_disputed.collect(),
+            dispute_win_rate: self.dispute_win_rate.collect(),
+        }
+    }
+}
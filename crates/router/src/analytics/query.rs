@@ -4,6 +4,8 @@ use std::marker::PhantomData;
 use api_models::{
     analytics::{
         self as analytics_api,
+        connector_performance::ConnectorPerformanceDimensions,
+        disputes::DisputeDimensions,
         payments::PaymentDimensions,
         refunds::{RefundDimensions, RefundType},
         Granularity,
@@ -13,11 +15,14 @@ use api_models::{
 };
 use common_enums::{
     enums as storage_enums,
-    enums::{AttemptStatus, AuthenticationType, Currency, PaymentMethod},
+    enums::{
+        AttemptStatus, AuthenticationType, Currency, DisputeStage, DisputeStatus, PaymentMethod,
+    },
 };
 use common_utils::errors::{CustomResult, ParsingError};
 use error_stack::{IntoReport, ResultExt};
 use router_env::logger;
+use time::PrimitiveDateTime;
 
 use super::types::{AnalyticsCollection, AnalyticsDataSource, LoadRow};
 use crate::analytics::types::QueryExecutionError;
@@ -201,6 +206,8 @@ pub enum QueryBuildingError {
     SqlSerializeError,
     #[error("Failed to build sql query: {0}")]
     InvalidQuery(&'static str),
+    #[error("Column cannot be used in filters: {0}")]
+    UnknownFilterColumn(String),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -227,6 +234,55 @@ pub enum Aggregate<R> {
         field: R,
         alias: Option<&'static str>,
     },
+    /// The continuous percentile of the field, with `percentile` ranging from 0 to 100
+    Percentile {
+        field: R,
+        alias: Option<&'static str>,
+        percentile: u8,
+    },
+}
+
+/// A value bound to a placeholder of the built query, instead of being formatted into the query
+/// text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryParam {
+    Text(String),
+    Bool(bool),
+    BigInt(i64),
+    Timestamp(PrimitiveDateTime),
+}
+
+/// A column of a collection that filters can be applied on.
+#[derive(Debug)]
+pub struct FilterColumn {
+    pub name: &'static str,
+    /// The database type that the bound values need to be cast to, for columns whose type cannot
+    /// be bound directly (such as database enums).
+    pub cast_type: Option<&'static str>,
+}
+
+impl FilterColumn {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            cast_type: None,
+        }
+    }
+
+    pub const fn with_cast(name: &'static str, cast_type: &'static str) -> Self {
+        Self {
+            name,
+            cast_type: Some(cast_type),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct FilterCondition {
+    lhs: String,
+    comparison: FilterTypes,
+    values: Vec<QueryParam>,
+    cast_type: Option<&'static str>,
 }
 
 #[derive(Debug)]
@@ -236,11 +292,12 @@ where
     AnalyticsCollection: ToSql<T>,
 {
     columns: Vec<String>,
-    filters: Vec<(String, FilterTypes, String)>,
+    filters: Vec<FilterCondition>,
     group_by: Vec<String>,
-    having: Option<Vec<(String, FilterTypes, String)>>,
+    having: Option<Vec<FilterCondition>>,
     table: AnalyticsCollection,
     distinct: bool,
+    limit_by: Option<u64>,
     db_type: PhantomData<T>,
 }
 
@@ -266,8 +323,12 @@ impl_to_sql_for_to_string!(
     &str,
     &PaymentDimensions,
     &RefundDimensions,
+    &DisputeDimensions,
+    &ConnectorPerformanceDimensions,
     PaymentDimensions,
     RefundDimensions,
+    DisputeDimensions,
+    ConnectorPerformanceDimensions,
     PaymentMethod,
     AuthenticationType,
     Connector,
@@ -281,6 +342,66 @@ impl_to_sql_for_to_string!(
     &u64
 );
 
+pub trait ToQueryParam {
+    fn to_query_param(&self) -> error_stack::Result<QueryParam, ParsingError>;
+}
+
+impl<P: ToQueryParam + ?Sized> ToQueryParam for &P {
+    fn to_query_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        (**self).to_query_param()
+    }
+}
+
+/// Implement `ToQueryParam` as a text parameter on types that impl `ToString`.
+macro_rules! impl_to_query_param_for_to_string {
+    ($($type:ty),+) => {
+        $(
+            impl ToQueryParam for $type {
+                fn to_query_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+                    Ok(QueryParam::Text(self.to_string()))
+                }
+            }
+        )+
+     };
+}
+
+impl_to_query_param_for_to_string!(
+    str,
+    String,
+    PaymentMethod,
+    AuthenticationType,
+    Connector,
+    AttemptStatus,
+    RefundStatus,
+    storage_enums::RefundStatus,
+    Currency,
+    RefundType,
+    DisputeStage,
+    DisputeStatus
+);
+
+impl ToQueryParam for bool {
+    fn to_query_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        Ok(QueryParam::Bool(*self))
+    }
+}
+
+impl ToQueryParam for u64 {
+    fn to_query_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        i64::try_from(*self)
+            .into_report()
+            .change_context(ParsingError::UnknownError)
+            .attach_printable("Integer value out of range")
+            .map(QueryParam::BigInt)
+    }
+}
+
+impl ToQueryParam for PrimitiveDateTime {
+    fn to_query_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        Ok(QueryParam::Timestamp(*self))
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum FilterTypes {
@@ -305,6 +426,7 @@ where
             having: Default::default(),
             table,
             distinct: Default::default(),
+            limit_by: Default::default(),
             db_type: Default::default(),
         }
     }
@@ -323,10 +445,14 @@ where
         self.distinct = true
     }
 
+    pub fn set_limit_by(&mut self, limit: u64) {
+        self.limit_by = Some(limit)
+    }
+
     pub fn add_filter_clause(
         &mut self,
         key: impl ToSql<T>,
-        value: impl ToSql<T>,
+        value: impl ToQueryParam,
     ) -> QueryResult<()> {
         self.add_custom_filter_clause(key, value, FilterTypes::Equal)
     }
@@ -334,7 +460,7 @@ where
     pub fn add_bool_filter_clause(
         &mut self,
         key: impl ToSql<T>,
-        value: impl ToSql<T>,
+        value: impl ToQueryParam,
     ) -> QueryResult<()> {
         self.add_custom_filter_clause(key, value, FilterTypes::EqualBool)
     }
@@ -342,40 +468,60 @@ where
     pub fn add_custom_filter_clause(
         &mut self,
         lhs: impl ToSql<T>,
-        rhs: impl ToSql<T>,
+        rhs: impl ToQueryParam,
         comparison: FilterTypes,
     ) -> QueryResult<()> {
-        self.filters.push((
-            lhs.to_sql()
-                .change_context(QueryBuildingError::SqlSerializeError)
-                .attach_printable("Error serializing filter key")?,
-            comparison,
-            rhs.to_sql()
-                .change_context(QueryBuildingError::SqlSerializeError)
-                .attach_printable("Error serializing filter value")?,
-        ));
-        Ok(())
+        let value = rhs
+            .to_query_param()
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing filter value")?;
+        self.add_filter_condition(lhs, comparison, vec![value])
     }
 
     pub fn add_filter_in_range_clause(
         &mut self,
         key: impl ToSql<T>,
-        values: &[impl ToSql<T>],
+        values: &[impl ToQueryParam],
     ) -> QueryResult<()> {
-        let list = values
+        if values.is_empty() {
+            Err(QueryBuildingError::InvalidQuery(
+                "No values provided for range filter",
+            ))
+            .into_report()?;
+        }
+        let values = values
             .iter()
-            .map(|i| {
-                // trimming whitespaces from the filter values received in request, to prevent a possibility of an SQL injection
-                i.to_sql().map(|s| {
-                    let trimmed_str = s.replace(' ', "");
-                    format!("'{trimmed_str}'")
-                })
-            })
-            .collect::<error_stack::Result<Vec<String>, ParsingError>>()
+            .map(ToQueryParam::to_query_param)
+            .collect::<error_stack::Result<Vec<_>, ParsingError>>()
             .change_context(QueryBuildingError::SqlSerializeError)
-            .attach_printable("Error serializing range filter value")?
-            .join(", ");
-        self.add_custom_filter_clause(key, list, FilterTypes::In)
+            .attach_printable("Error serializing range filter value")?;
+        self.add_filter_condition(key, FilterTypes::In, values)
+    }
+
+    /// Adds a filter on the column `key`, which must be one of the filter columns of the
+    /// collection known to the data source.
+    fn add_filter_condition(
+        &mut self,
+        key: impl ToSql<T>,
+        comparison: FilterTypes,
+        values: Vec<QueryParam>,
+    ) -> QueryResult<()> {
+        let key = key
+            .to_sql()
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing filter key")?;
+        let column = T::get_filter_columns(self.table)
+            .iter()
+            .find(|column| column.name == key)
+            .ok_or_else(|| QueryBuildingError::UnknownFilterColumn(key.clone()))
+            .into_report()?;
+        self.filters.push(FilterCondition {
+            lhs: key,
+            comparison,
+            values,
+            cast_type: column.cast_type,
+        });
+        Ok(())
     }
 
     pub fn add_group_by_clause(&mut self, column: impl ToSql<T>) -> QueryResult<()> {
@@ -403,16 +549,30 @@ where
         Ok(())
     }
 
-    fn get_filter_clause(&self) -> String {
-        self.filters
+    /// Renders the filter conditions with placeholders, appending the bound values to `params`.
+    fn get_filter_clause(filters: &[FilterCondition], params: &mut Vec<QueryParam>) -> String {
+        filters
             .iter()
-            .map(|(l, op, r)| match op {
-                FilterTypes::EqualBool => format!("{l} = {r}"),
-                FilterTypes::Equal => format!("{l} = '{r}'"),
-                FilterTypes::In => format!("{l} IN ({r})"),
-                FilterTypes::Gte => format!("{l} >= '{r}'"),
-                FilterTypes::Gt => format!("{l} > {r}"),
-                FilterTypes::Lte => format!("{l} <= '{r}'"),
+            .map(|filter| {
+                let placeholders = filter
+                    .values
+                    .iter()
+                    .map(|value| {
+                        params.push(value.clone());
+                        T::get_bind_placeholder(params.len(), filter.cast_type)
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                let lhs = &filter.lhs;
+                match filter.comparison {
+                    FilterTypes::Equal | FilterTypes::EqualBool => {
+                        format!("{lhs} = {placeholders}")
+                    }
+                    FilterTypes::In => format!("{lhs} IN ({placeholders})"),
+                    FilterTypes::Gte => format!("{lhs} >= {placeholders}"),
+                    FilterTypes::Gt => format!("{lhs} > {placeholders}"),
+                    FilterTypes::Lte => format!("{lhs} <= {placeholders}"),
+                }
             })
             .collect::<Vec<String>>()
             .join(" AND ")
@@ -431,7 +591,7 @@ where
         &mut self,
         aggregate: Aggregate<R>,
         filter_type: FilterTypes,
-        value: impl ToSql<T>,
+        value: impl ToQueryParam,
     ) -> QueryResult<()>
     where
         Aggregate<R>: ToSql<T>,
@@ -441,10 +601,15 @@ where
             .change_context(QueryBuildingError::SqlSerializeError)
             .attach_printable("Error serializing having aggregate")?;
         let value = value
-            .to_sql()
+            .to_query_param()
             .change_context(QueryBuildingError::SqlSerializeError)
             .attach_printable("Error serializing having value")?;
-        let entry = (aggregate, filter_type, value);
+        let entry = FilterCondition {
+            lhs: aggregate,
+            comparison: filter_type,
+            values: vec![value],
+            cast_type: None,
+        };
         if let Some(having) = &mut self.having {
             having.push(entry);
         } else {
@@ -453,22 +618,8 @@ where
         Ok(())
     }
 
-    pub fn get_filter_type_clause(&self) -> Option<String> {
-        self.having.as_ref().map(|vec| {
-            vec.iter()
-                .map(|(l, op, r)| match op {
-                    FilterTypes::Equal | FilterTypes::EqualBool => format!("{l} = {r}"),
-                    FilterTypes::In => format!("{l} IN ({r})"),
-                    FilterTypes::Gte => format!("{l} >= {r}"),
-                    FilterTypes::Lte => format!("{l} < {r}"),
-                    FilterTypes::Gt => format!("{l} > {r}"),
-                })
-                .collect::<Vec<String>>()
-                .join(" AND ")
-        })
-    }
-
-    pub fn build_query(&mut self) -> QueryResult<String>
+    /// Builds the query text, along with the values to be bound to its placeholders in order.
+    pub fn build_query(&mut self) -> QueryResult<(String, Vec<QueryParam>)>
     where
         Aggregate<&'static str>: ToSql<T>,
     {
@@ -479,6 +630,7 @@ where
             .into_report()?;
         }
         let mut query = String::from("SELECT ");
+        let mut params = Vec::new();
 
         if self.distinct {
             query.push_str("DISTINCT ");
@@ -498,7 +650,7 @@ where
 
         if !self.filters.is_empty() {
             query.push_str(" WHERE ");
-            query.push_str(&self.get_filter_clause());
+            query.push_str(&Self::get_filter_clause(&self.filters, &mut params));
         }
 
         if !self.group_by.is_empty() {
@@ -506,13 +658,15 @@ where
             query.push_str(&self.get_group_by_clause());
         }
 
-        if self.having.is_some() {
-            if let Some(condition) = self.get_filter_type_clause() {
-                query.push_str(" HAVING ");
-                query.push_str(condition.as_str());
-            }
+        if let Some(having) = &self.having {
+            query.push_str(" HAVING ");
+            query.push_str(&Self::get_filter_clause(having, &mut params));
+        }
+
+        if let Some(limit) = self.limit_by {
+            query.push_str(&format!(" LIMIT {limit}"));
         }
-        Ok(query)
+        Ok((query, params))
     }
 
     pub async fn execute_query<R, P: AnalyticsDataSource>(
@@ -523,11 +677,60 @@ where
         P: LoadRow<R>,
         Aggregate<&'static str>: ToSql<T>,
     {
-        let query = self
+        let (query, params) = self
             .build_query()
             .change_context(QueryBuildingError::SqlSerializeError)
             .attach_printable("Failed to execute query")?;
-        logger::debug!(?query);
-        Ok(store.load_results(query.as_str()).await)
+        logger::debug!(?query, ?params);
+        Ok(store.load_results(query.as_str(), &params).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::analytics::sqlx::SqlxClient;
+
+    #[test]
+    fn test_filter_values_are_bound_as_params() {
+        let mut builder = QueryBuilder::<SqlxClient>::new(AnalyticsCollection::Payment);
+        builder.add_select_column("connector").unwrap();
+        builder
+            .add_filter_clause("merchant_id", "merchant' OR '1'='1")
+            .unwrap();
+        builder
+            .add_filter_in_range_clause(
+                PaymentDimensions::Currency,
+                &[Currency::USD, Currency::EUR],
+            )
+            .unwrap();
+
+        let (query, params) = builder.build_query().unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT connector FROM payment_attempt WHERE merchant_id = $1 AND currency IN ($2::\"Currency\", $3::\"Currency\")"
+        );
+        assert_eq!(
+            params,
+            vec![
+                QueryParam::Text("merchant' OR '1'='1".to_string()),
+                QueryParam::Text("USD".to_string()),
+                QueryParam::Text("EUR".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_filter_column_is_rejected() {
+        let mut builder = QueryBuilder::<SqlxClient>::new(AnalyticsCollection::Refund);
+        let result = builder.add_filter_clause("1 = 1 OR merchant_id", "merchant");
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            QueryBuildingError::UnknownFilterColumn(_)
+        ));
     }
 }
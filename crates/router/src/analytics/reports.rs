@@ -0,0 +1,974 @@
+mod csv;
+pub mod exports;
+
+use api_models::analytics::{
+    reports::{
+        PaymentsExportRequest, RefundsExportRequest, ReportCreateRequest, ReportDefinition,
+        ReportExportId, ReportExportResponse, ReportId, ReportQuery, ReportResponse,
+    },
+    GetConnectorPerformanceMetricRequest, GetDisputeMetricRequest, GetPaymentMetricRequest,
+    GetRefundMetricRequest, MetricsResponse, TimeRange, TimeSeries,
+};
+use common_utils::{date_time, ext_traits::ValueExt};
+use error_stack::{IntoReport, ResultExt};
+use futures::StreamExt;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
+use serde::Serialize;
+use time::{Duration, PrimitiveDateTime};
+
+use super::{
+    connector_performance, core::AnalyticsApiResponse, disputes, payments, refunds,
+    AnalyticsProvider,
+};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::{self, helpers as file_helpers},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::{ForeignInto, ForeignTryFrom},
+    },
+    utils::{self, OptionExt},
+};
+
+const ANALYTICS_REPORT_RUNNER: &str = "ANALYTICS_REPORT_WORKFLOW";
+const ANALYTICS_REPORT_TASK: &str = "ANALYTICS_REPORT";
+const CSV_CONTENT_TYPE: &str = "text/csv";
+/// Longest period an on demand export may cover
+const MAX_EXPORT_RANGE_DAYS: i64 = 31;
+/// Largest number of rows an on demand export may contain
+const MAX_EXPORT_ROWS: u64 = 100_000;
+
+#[instrument(skip(state))]
+pub async fn create_report(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: ReportCreateRequest,
+) -> RouterResponse<ReportResponse> {
+    utils::when(req.name.trim().is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "name must not be empty".to_string(),
+        })
+    })?;
+    utils::when(!req.definition.query.has_metrics(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "at least one metric must be selected".to_string(),
+        })
+    })?;
+    utils::when(req.lookback_days == Some(0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "lookback_days must be greater than zero".to_string(),
+        })
+    })?;
+
+    let current_time = date_time::now();
+    let next_run_at = match req.first_run_at {
+        Some(first_run_at) => {
+            utils::when(first_run_at <= current_time, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "first_run_at must be in the future".to_string(),
+                })
+            })?;
+            first_run_at
+        }
+        None => add_report_frequency(current_time, req.frequency, 1)
+            .get_required_value("next_run_at")
+            .attach_printable("Failed to compute the time of the first report run")?,
+    };
+    let definition = serde_json::to_value(&req.definition)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the report definition")?;
+
+    let report = storage::AnalyticsReportNew {
+        report_id: utils::generate_id(consts::ID_LENGTH, "report"),
+        merchant_id: merchant_account.merchant_id,
+        name: req.name,
+        definition,
+        frequency: req.frequency,
+        lookback_days: req.lookback_days.map(Into::into),
+        email_recipients: req.email_recipients.map(|recipients| {
+            recipients
+                .iter()
+                .map(|email| email.peek().to_owned())
+                .collect()
+        }),
+        is_active: true,
+        next_run_at,
+        last_run_at: None,
+        created_at: current_time,
+        modified_at: current_time,
+    };
+
+    let db = &*state.store;
+    let report = db
+        .insert_analytics_report(report)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Analytics report with the given id already exists".to_string(),
+        })?;
+    add_analytics_report_task(db, &report).await?;
+
+    Ok(ApplicationResponse::Json(ReportResponse::foreign_try_from(
+        report,
+    )?))
+}
+
+#[instrument(skip(state))]
+pub async fn list_reports(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<ReportResponse>> {
+    let reports = state
+        .store
+        .list_analytics_reports_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list analytics reports")?;
+
+    Ok(ApplicationResponse::Json(
+        reports
+            .into_iter()
+            .filter(|report| report.is_active)
+            .map(ReportResponse::foreign_try_from)
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_report(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: ReportId,
+) -> RouterResponse<ReportResponse> {
+    let report = find_report(&*state.store, &merchant_account.merchant_id, &req.report_id).await?;
+
+    Ok(ApplicationResponse::Json(ReportResponse::foreign_try_from(
+        report,
+    )?))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_report(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: ReportId,
+) -> RouterResponse<ReportResponse> {
+    let db = &*state.store;
+    let report = find_report(db, &merchant_account.merchant_id, &req.report_id).await?;
+
+    // The exports generated so far are kept, the report is only deactivated so that they can
+    // still be listed and downloaded
+    let report = db
+        .update_analytics_report_by_merchant_id_report_id(
+            &report.merchant_id,
+            &report.report_id,
+            storage::AnalyticsReportUpdate::DeactivateUpdate,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Analytics report not found".to_string(),
+        })?;
+
+    let process_tracker_id = get_analytics_report_process_tracker_id(&report);
+    let report_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the analytics report task")?;
+    if let Some(report_process) = report_process {
+        report_process
+            .finish_with_status(db.as_scheduler(), "REPORT_DELETED".to_string())
+            .await
+            .map_err(|error| logger::error!(analytics_report_task_error=?error))
+            .ok();
+    }
+
+    Ok(ApplicationResponse::Json(ReportResponse::foreign_try_from(
+        report,
+    )?))
+}
+
+#[instrument(skip(state))]
+pub async fn list_report_exports(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: ReportId,
+) -> RouterResponse<Vec<ReportExportResponse>> {
+    let db = &*state.store;
+    let report = find_report(db, &merchant_account.merchant_id, &req.report_id).await?;
+    let exports = db
+        .list_analytics_report_exports_by_merchant_id_report_id(
+            &report.merchant_id,
+            &report.report_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list analytics report exports")?;
+
+    Ok(ApplicationResponse::Json(
+        exports.into_iter().map(ForeignInto::foreign_into).collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn export_payments(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: PaymentsExportRequest,
+) -> RouterResponse<ReportExportResponse> {
+    let export_id = get_export_id(
+        &merchant_account.merchant_id,
+        enums::ReportExportType::Payments,
+        &req.filters,
+        &req.time_range,
+    )?;
+    if let Some(export) =
+        find_report_export(&*state.store, &merchant_account.merchant_id, &export_id).await?
+    {
+        return Ok(ApplicationResponse::Json(export.foreign_into()));
+    }
+
+    let time_range = get_export_time_range(req.time_range)?;
+    let rows = match &state.pool {
+        AnalyticsProvider::Sqlx(pool) => exports::get_payment_export_rows(
+            &merchant_account.merchant_id,
+            &req.filters,
+            &time_range,
+            MAX_EXPORT_ROWS + 1,
+            pool,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payments to export")?,
+    };
+    validate_export_row_count(rows.len())?;
+    let csv = csv::to_csv(&rows)?;
+
+    let export = store_report_export(
+        &state,
+        &merchant_account.merchant_id,
+        export_id,
+        None,
+        enums::ReportExportType::Payments,
+        &time_range,
+        csv,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(export.foreign_into()))
+}
+
+#[instrument(skip(state))]
+pub async fn export_refunds(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: RefundsExportRequest,
+) -> RouterResponse<ReportExportResponse> {
+    let export_id = get_export_id(
+        &merchant_account.merchant_id,
+        enums::ReportExportType::Refunds,
+        &req.filters,
+        &req.time_range,
+    )?;
+    if let Some(export) =
+        find_report_export(&*state.store, &merchant_account.merchant_id, &export_id).await?
+    {
+        return Ok(ApplicationResponse::Json(export.foreign_into()));
+    }
+
+    let time_range = get_export_time_range(req.time_range)?;
+    let rows = match &state.pool {
+        AnalyticsProvider::Sqlx(pool) => exports::get_refund_export_rows(
+            &merchant_account.merchant_id,
+            &req.filters,
+            &time_range,
+            MAX_EXPORT_ROWS + 1,
+            pool,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds to export")?,
+    };
+    validate_export_row_count(rows.len())?;
+    let csv = csv::to_csv(&rows)?;
+
+    let export = store_report_export(
+        &state,
+        &merchant_account.merchant_id,
+        export_id,
+        None,
+        enums::ReportExportType::Refunds,
+        &time_range,
+        csv,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(export.foreign_into()))
+}
+
+#[instrument(skip(state))]
+pub async fn download_report_export(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: ReportExportId,
+) -> RouterResponse<serde_json::Value> {
+    let export = state
+        .store
+        .find_analytics_report_export_by_merchant_id_export_id(
+            &merchant_account.merchant_id,
+            &req.export_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Analytics report export not found".to_string(),
+        })?;
+
+    files::files_retrieve_core(
+        state,
+        merchant_account,
+        key_store,
+        api::FileId {
+            file_id: export.file_id,
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn start_analytics_report_workflow(
+    state: &AppState,
+    report_process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::AnalyticsReportTrackingData = report_process
+        .tracking_data
+        .clone()
+        .parse_value("AnalyticsReportTrackingData")?;
+
+    let report = find_report(db, &tracking_data.merchant_id, &tracking_data.report_id).await?;
+    if !report.is_active {
+        return report_process
+            .clone()
+            .finish_with_status(db.as_scheduler(), "REPORT_DELETED".to_string())
+            .await;
+    }
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await?;
+
+    // A retried run finds the export stored by the previous attempt instead of generating, and
+    // emailing, the report a second time
+    let export_id = get_report_run_export_id(&report);
+    let export = match find_report_export(db, &report.merchant_id, &export_id).await? {
+        Some(export) => export,
+        None => {
+            let definition: ReportDefinition =
+                report.definition.clone().parse_value("ReportDefinition")?;
+            let time_range = TimeRange {
+                start_time: get_report_start_time(&report)?,
+                end_time: Some(report.next_run_at),
+            };
+            let csv = generate_report_csv(state, merchant_account, definition, time_range.clone())
+                .await?;
+
+            let export = store_report_export(
+                state,
+                &report.merchant_id,
+                export_id,
+                Some(report.report_id.clone()),
+                enums::ReportExportType::Report,
+                &time_range,
+                csv,
+            )
+            .await?;
+
+            #[cfg(feature = "email")]
+            send_report_emails(state, &report, &export).await;
+
+            export
+        }
+    };
+
+    // Runs missed while the scheduler was down are skipped, instead of generating a report for
+    // every missed period
+    let current_time = date_time::now();
+    let mut next_run_at = report.next_run_at;
+    while next_run_at <= current_time {
+        next_run_at = add_report_frequency(next_run_at, report.frequency, 1)
+            .get_required_value("next_run_at")
+            .attach_printable("Failed to compute the time of the next report run")?;
+    }
+
+    db.update_analytics_report_by_merchant_id_report_id(
+        &report.merchant_id,
+        &report.report_id,
+        storage::AnalyticsReportUpdate::RunUpdate {
+            last_run_at: current_time,
+            next_run_at,
+        },
+    )
+    .await?;
+
+    logger::info!(
+        "Generated export {} for analytics report {}",
+        export.export_id,
+        report.report_id
+    );
+
+    report_process
+        .clone()
+        .reset(db.as_scheduler(), next_run_at)
+        .await
+}
+
+async fn generate_report_csv(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    definition: ReportDefinition,
+    time_range: TimeRange,
+) -> RouterResult<Vec<u8>> {
+    let time_series = definition
+        .granularity
+        .map(|granularity| TimeSeries { granularity });
+    let pool = state.pool.clone();
+
+    match definition.query {
+        ReportQuery::Payments {
+            metrics,
+            group_by_names,
+            filters,
+        } => metrics_to_csv(
+            payments::get_metrics(
+                pool,
+                merchant_account,
+                GetPaymentMetricRequest {
+                    time_series,
+                    time_range,
+                    group_by_names,
+                    filters,
+                    metrics,
+                    delta: false,
+                },
+            )
+            .await,
+        ),
+        ReportQuery::Refunds {
+            metrics,
+            group_by_names,
+            filters,
+        } => metrics_to_csv(
+            refunds::get_metrics(
+                pool,
+                merchant_account,
+                GetRefundMetricRequest {
+                    time_series,
+                    time_range,
+                    group_by_names,
+                    filters,
+                    metrics,
+                    delta: false,
+                },
+            )
+            .await,
+        ),
+        ReportQuery::Disputes {
+            metrics,
+            group_by_names,
+            filters,
+        } => metrics_to_csv(
+            disputes::get_metrics(
+                pool,
+                merchant_account,
+                GetDisputeMetricRequest {
+                    time_series,
+                    time_range,
+                    group_by_names,
+                    filters,
+                    metrics,
+                    delta: false,
+                },
+            )
+            .await,
+        ),
+        ReportQuery::ConnectorPerformance {
+            metrics,
+            group_by_names,
+            filters,
+        } => metrics_to_csv(
+            connector_performance::get_metrics(
+                pool,
+                merchant_account,
+                GetConnectorPerformanceMetricRequest {
+                    time_series,
+                    time_range,
+                    group_by_names,
+                    filters,
+                    metrics,
+                    delta: false,
+                },
+            )
+            .await,
+        ),
+    }
+}
+
+fn metrics_to_csv<T: Serialize>(
+    response: AnalyticsApiResponse<MetricsResponse<T>>,
+) -> RouterResult<Vec<u8>> {
+    match response
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the report metrics")?
+    {
+        ApplicationResponse::Json(metrics) => csv::to_csv(&metrics.query_data),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Received non-json response from analytics core"),
+    }
+}
+
+async fn store_report_export(
+    state: &AppState,
+    merchant_id: &str,
+    export_id: String,
+    report_id: Option<String>,
+    export_type: enums::ReportExportType,
+    time_range: &TimeRange,
+    csv: Vec<u8>,
+) -> RouterResult<storage::AnalyticsReportExport> {
+    let end_time = time_range.end_time.unwrap_or_else(date_time::now);
+    let file_id = utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = file_helpers::get_file_key(merchant_id, &file_id);
+    let checksum = file_helpers::get_file_checksum(&csv);
+    let file_size = csv.len();
+    let file = futures::stream::once(async move { Ok(bytes::Bytes::from(csv)) }).boxed();
+    file_helpers::upload_file(state, file_key.clone(), file).await?;
+
+    let file_new = diesel_models::file::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_id.to_string(),
+        file_name: Some(format!(
+            "{export_type}_{}_{}.csv",
+            time_range.start_time.date(),
+            end_time.date()
+        )),
+        file_size: i32::try_from(file_size)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Export file is too large")?,
+        file_type: CSV_CONTENT_TYPE.to_string(),
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: None,
+        profile_id: None,
+        merchant_connector_id: None,
+        checksum: Some(checksum),
+    };
+    state
+        .store
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file_metadata")?;
+
+    let export = storage::AnalyticsReportExportNew {
+        export_id,
+        merchant_id: merchant_id.to_string(),
+        report_id,
+        export_type,
+        file_id,
+        start_time: time_range.start_time,
+        end_time,
+        created_at: date_time::now(),
+    };
+    state
+        .store
+        .insert_analytics_report_export(export)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Analytics report export with the given id already exists".to_string(),
+        })
+}
+
+/// Emails the link to the generated report to the recipients of the report. Failures are only
+/// logged, the export can still be downloaded from the dashboard.
+#[cfg(feature = "email")]
+async fn send_report_emails(
+    state: &AppState,
+    report: &storage::AnalyticsReport,
+    export: &storage::AnalyticsReportExport,
+) {
+    use std::str::FromStr;
+
+    use external_services::email::EmailError;
+
+    use crate::services::{email as email_service, email::types as email_types};
+
+    let template = email_types::AnalyticsReportReady {
+        report_name: report.name.clone(),
+        period: format!("{} to {}", export.start_time.date(), export.end_time.date()),
+        link: email_types::get_report_export_link(&state.conf.email.base_url, &export.export_id),
+    };
+
+    for recipient in report.email_recipients.iter().flatten() {
+        let result = match common_utils::pii::Email::from_str(recipient) {
+            Ok(email) => {
+                email_service::compose_and_send_email(
+                    state,
+                    email,
+                    &template,
+                    &email_types::EmailBranding::default(),
+                )
+                .await
+            }
+            Err(error) => Err(error).change_context(EmailError::InvalidEmailAddress),
+        };
+
+        if let Err(error) = result {
+            logger::error!(?error, report_id = %report.report_id, "Failed to send report email");
+        }
+    }
+}
+
+async fn find_report_export(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    export_id: &str,
+) -> RouterResult<Option<storage::AnalyticsReportExport>> {
+    match db
+        .find_analytics_report_export_by_merchant_id_export_id(merchant_id, export_id)
+        .await
+    {
+        Ok(export) => Ok(Some(export)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the analytics report export"),
+    }
+}
+
+/// Identifier of an on demand export. Exports of a period that has already ended are identified
+/// by their parameters, so that a retried request returns the export stored by the first one
+/// instead of generating a duplicate.
+fn get_export_id<F: Serialize>(
+    merchant_id: &str,
+    export_type: enums::ReportExportType,
+    filters: &F,
+    time_range: &TimeRange,
+) -> RouterResult<String> {
+    match time_range
+        .end_time
+        .filter(|end_time| *end_time <= date_time::now())
+    {
+        Some(end_time) => {
+            let filters = serde_json::to_string(filters)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the export filters")?;
+            Ok(hash_export_id(&[
+                merchant_id,
+                &export_type.to_string(),
+                &filters,
+                &time_range.start_time.to_string(),
+                &end_time.to_string(),
+            ]))
+        }
+        None => Ok(utils::generate_id(consts::ID_LENGTH, "export")),
+    }
+}
+
+/// Identifier of the export generated by a run of the report, a run is identified by its scheduled
+/// time.
+fn get_report_run_export_id(report: &storage::AnalyticsReport) -> String {
+    hash_export_id(&[
+        &report.merchant_id,
+        &report.report_id,
+        &report.next_run_at.to_string(),
+    ])
+}
+
+fn hash_export_id(parts: &[&str]) -> String {
+    let digest = file_helpers::get_file_checksum(parts.join("|").as_bytes());
+    format!("export_{}", digest.chars().take(32).collect::<String>())
+}
+
+fn validate_export_row_count(row_count: usize) -> RouterResult<()> {
+    utils::when(
+        u64::try_from(row_count).map_or(true, |row_count| row_count > MAX_EXPORT_ROWS),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The export exceeds {MAX_EXPORT_ROWS} rows, narrow down the time range or the filters"
+                ),
+            })
+            .into_report()
+        },
+    )
+}
+
+fn get_export_time_range(time_range: TimeRange) -> RouterResult<TimeRange> {
+    let end_time = time_range.end_time.unwrap_or_else(date_time::now);
+    utils::when(time_range.start_time >= end_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time must be before end_time".to_string(),
+        })
+    })?;
+    utils::when(
+        end_time - time_range.start_time > Duration::days(MAX_EXPORT_RANGE_DAYS),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The export time range must not exceed {MAX_EXPORT_RANGE_DAYS} days"
+                ),
+            })
+        },
+    )?;
+
+    Ok(TimeRange {
+        start_time: time_range.start_time,
+        end_time: Some(end_time),
+    })
+}
+
+/// Start of the period covered by a run of the report, the report covers `lookback_days` days, or
+/// one period of the report frequency, up to the scheduled time of the run.
+fn get_report_start_time(report: &storage::AnalyticsReport) -> RouterResult<PrimitiveDateTime> {
+    match report.lookback_days {
+        Some(lookback_days) => report
+            .next_run_at
+            .checked_sub(Duration::days(lookback_days.into())),
+        None => add_report_frequency(report.next_run_at, report.frequency, -1),
+    }
+    .get_required_value("start_time")
+    .attach_printable("Failed to compute the start of the report period")
+}
+
+/// Add `count` periods of the report frequency to `date_time`.
+fn add_report_frequency(
+    date_time: PrimitiveDateTime,
+    frequency: enums::ReportFrequency,
+    count: i32,
+) -> Option<PrimitiveDateTime> {
+    match frequency {
+        enums::ReportFrequency::Daily => date_time.checked_add(Duration::days(count.into())),
+        enums::ReportFrequency::Weekly => date_time.checked_add(Duration::weeks(count.into())),
+        enums::ReportFrequency::Monthly => add_report_months(date_time, count),
+    }
+}
+
+/// Add `months` calendar months to `date_time`, clamping the day to the end of shorter months.
+///
+/// Reports scheduled on the last day of a month stay on the last day of every following month,
+/// instead of sticking to the day of the shortest month they went through.
+fn add_report_months(date_time: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let date = date_time.date();
+    let is_month_end = date.day() == time::util::days_in_year_month(date.year(), date.month());
+    let month_index = date
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date.month())) - 1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let days_in_month = time::util::days_in_year_month(year, month);
+    let day = if is_month_end {
+        days_in_month
+    } else {
+        date.day().min(days_in_month)
+    };
+
+    time::Date::from_calendar_date(year, month, day)
+        .ok()
+        .map(|date| date.with_time(date_time.time()))
+}
+
+async fn add_analytics_report_task(
+    db: &dyn StorageInterface,
+    report: &storage::AnalyticsReport,
+) -> RouterResult<storage::ProcessTracker> {
+    let tracking_data = storage::AnalyticsReportTrackingData {
+        report_id: report.report_id.clone(),
+        merchant_id: report.merchant_id.clone(),
+    };
+    let tracking_data = serde_json::to_value(tracking_data)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize analytics report tracking data")?;
+
+    let current_time = date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: get_analytics_report_process_tracker_id(report),
+        name: Some(String::from(ANALYTICS_REPORT_TASK)),
+        tag: vec![String::from("ANALYTICS_REPORT")],
+        runner: Some(String::from(ANALYTICS_REPORT_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(report.next_run_at),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: report_id: {}",
+                report.report_id
+            )
+        })
+}
+
+fn get_analytics_report_process_tracker_id(report: &storage::AnalyticsReport) -> String {
+    pt_utils::get_process_tracker_id(
+        ANALYTICS_REPORT_RUNNER,
+        ANALYTICS_REPORT_TASK,
+        &report.report_id,
+        &report.merchant_id,
+    )
+}
+
+async fn find_report(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    report_id: &str,
+) -> RouterResult<storage::AnalyticsReport> {
+    db.find_analytics_report_by_merchant_id_report_id(merchant_id, report_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Analytics report not found".to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_monthly_reports_are_clamped_to_month_end() {
+        let run_at = datetime!(2023-01-31 09:00);
+        let next_run_at = add_report_frequency(run_at, enums::ReportFrequency::Monthly, 1).unwrap();
+        assert_eq!(next_run_at, datetime!(2023-02-28 09:00));
+
+        let next_run_at =
+            add_report_frequency(next_run_at, enums::ReportFrequency::Monthly, 1).unwrap();
+        assert_eq!(next_run_at, datetime!(2023-03-31 09:00));
+    }
+
+    #[test]
+    fn test_export_time_range_is_capped() {
+        assert!(get_export_time_range(TimeRange {
+            start_time: datetime!(2023-11-01 00:00),
+            end_time: Some(datetime!(2023-12-02 00:00)),
+        })
+        .is_ok());
+        assert!(get_export_time_range(TimeRange {
+            start_time: datetime!(2023-11-01 00:00),
+            end_time: Some(datetime!(2023-12-02 00:01)),
+        })
+        .is_err());
+        assert!(get_export_time_range(TimeRange {
+            start_time: datetime!(2023-11-01 00:00),
+            end_time: Some(datetime!(2023-11-01 00:00)),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_export_id_is_stable_for_past_periods() {
+        let time_range = TimeRange {
+            start_time: datetime!(2023-11-01 00:00),
+            end_time: Some(datetime!(2023-11-02 00:00)),
+        };
+        let filters = serde_json::json!({ "currency": ["USD"] });
+        let export_id = get_export_id(
+            "merchant_1",
+            enums::ReportExportType::Payments,
+            &filters,
+            &time_range,
+        )
+        .unwrap();
+
+        assert!(export_id.len() <= 64);
+        assert_eq!(
+            export_id,
+            get_export_id(
+                "merchant_1",
+                enums::ReportExportType::Payments,
+                &filters,
+                &time_range
+            )
+            .unwrap()
+        );
+        assert_ne!(
+            export_id,
+            get_export_id(
+                "merchant_1",
+                enums::ReportExportType::Refunds,
+                &filters,
+                &time_range
+            )
+            .unwrap()
+        );
+
+        let open_time_range = TimeRange {
+            end_time: None,
+            ..time_range
+        };
+        assert_ne!(
+            get_export_id(
+                "merchant_1",
+                enums::ReportExportType::Payments,
+                &filters,
+                &open_time_range
+            )
+            .unwrap(),
+            get_export_id(
+                "merchant_1",
+                enums::ReportExportType::Payments,
+                &filters,
+                &open_time_range
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_monthly_reports_keep_their_day_of_month() {
+        let run_at = datetime!(2023-12-15 09:00);
+        assert_eq!(
+            add_report_frequency(run_at, enums::ReportFrequency::Monthly, 2).unwrap(),
+            datetime!(2024-02-15 09:00)
+        );
+        assert_eq!(
+            add_report_frequency(run_at, enums::ReportFrequency::Monthly, -1).unwrap(),
+            datetime!(2023-11-15 09:00)
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::core::errors;
+
+/// Leading characters which make spreadsheet applications evaluate a cell as a formula.
+const FORMULA_PREFIXES: &[char] = &['=', '+', '-', '@', '\t', '\r'];
+
+/// Serializes the rows into a CSV file with a header row. Every field of the rows gets a column,
+/// in the order the fields are first seen, and nested objects are flattened into `parent.child`
+/// columns.
+pub fn to_csv<T: Serialize>(rows: &[T]) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+    let mut columns: Vec<String> = Vec::new();
+    let mut records = Vec::with_capacity(rows.len());
+
+    for row in rows {
+        let value = serde_json::to_value(row)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the CSV row")?;
+        let mut record = Vec::new();
+        flatten_value(None, value, &mut record);
+        for (column, _) in record.iter() {
+            if !columns.contains(column) {
+                columns.push(column.clone());
+            }
+        }
+        records.push(record);
+    }
+
+    let mut csv = String::new();
+    if columns.is_empty() {
+        return Ok(csv.into_bytes());
+    }
+    write_line(&mut csv, columns.iter().map(|column| escape_field(column)));
+    for record in records {
+        write_line(
+            &mut csv,
+            columns.iter().map(|column| {
+                record
+                    .iter()
+                    .find(|(key, _)| key == column)
+                    .map(|(_, field)| field.clone())
+                    .unwrap_or_default()
+            }),
+        );
+    }
+
+    Ok(csv.into_bytes())
+}
+
+fn flatten_value(prefix: Option<&str>, value: Value, record: &mut Vec<(String, String)>) {
+    let column = prefix.unwrap_or_default().to_string();
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = match prefix {
+                    Some(prefix) => format!("{prefix}.{key}"),
+                    None => key,
+                };
+                flatten_value(Some(&key), value, record);
+            }
+        }
+        Value::Null => record.push((column, String::new())),
+        Value::String(text) => {
+            // Values coming from connectors or customers could otherwise be run as formulas
+            // when the file is opened in a spreadsheet
+            let text = if text.starts_with(FORMULA_PREFIXES) {
+                format!("'{text}")
+            } else {
+                text
+            };
+            record.push((column, escape_field(&text)))
+        }
+        Value::Bool(_) | Value::Number(_) | Value::Array(_) => {
+            record.push((column, escape_field(&value.to_string())))
+        }
+    }
+}
+
+fn escape_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn write_line(csv: &mut String, fields: impl Iterator<Item = String>) {
+    csv.push_str(&fields.collect::<Vec<_>>().join(","));
+    csv.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::to_csv;
+
+    #[derive(serde::Serialize)]
+    struct Range {
+        from: u32,
+        to: Option<u32>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Row {
+        connector: String,
+        count: Option<u64>,
+        range: Range,
+    }
+
+    #[test]
+    fn test_to_csv_flattens_nested_fields() {
+        let rows = vec![
+            Row {
+                connector: "stripe".to_string(),
+                count: Some(3),
+                range: Range {
+                    from: 1,
+                    to: Some(2),
+                },
+            },
+            Row {
+                connector: "adyen, \"eu\"".to_string(),
+                count: None,
+                range: Range { from: 3, to: None },
+            },
+        ];
+
+        let csv = String::from_utf8(to_csv(&rows).unwrap()).unwrap();
+        assert_eq!(
+            csv,
+            "connector,count,range.from,range.to\r\nstripe,3,1,2\r\n\"adyen, \"\"eu\"\"\",,3,\r\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_neutralizes_formulas() {
+        let rows = vec![Row {
+            connector: "=HYPERLINK(\"https://example.com\")".to_string(),
+            count: Some(1),
+            range: Range { from: 0, to: None },
+        }];
+
+        let csv = String::from_utf8(to_csv(&rows).unwrap()).unwrap();
+        assert!(csv.contains("\"'=HYPERLINK(\"\"https://example.com\"\")\""));
+    }
+
+    #[test]
+    fn test_to_csv_without_rows() {
+        let rows: Vec<Row> = Vec::new();
+        assert!(to_csv(&rows).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use api_models::analytics::{
+    payments::PaymentFilters,
+    refunds::{RefundFilters, RefundType},
+    Granularity, TimeRange,
+};
+use common_enums::enums::{AttemptStatus, AuthenticationType, Currency, RefundStatus};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::analytics::{
+    query::{GroupByClause, QueryBuilder, QueryFilter, ToSql},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsError,
+        MetricsResult,
+    },
+};
+
+/// The columns of the payment attempts included in the payments export
+const PAYMENT_EXPORT_COLUMNS: &[&str] = &[
+    "payment_id",
+    "attempt_id",
+    "status",
+    "amount",
+    "currency",
+    "connector",
+    "payment_method",
+    "payment_method_type",
+    "authentication_type",
+    "error_code",
+    "error_message",
+    "created_at",
+];
+
+/// The columns of the refunds included in the refunds export
+const REFUND_EXPORT_COLUMNS: &[&str] = &[
+    "refund_id",
+    "payment_id",
+    "connector",
+    "refund_status",
+    "refund_type",
+    "refund_amount",
+    "currency",
+    "refund_reason",
+    "created_at",
+];
+
+pub trait ReportExportAnalytics: LoadRow<PaymentExportRow> + LoadRow<RefundExportRow> {}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentExportRow {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub status: DBEnumWrapper<AttemptStatus>,
+    pub amount: i64,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub authentication_type: Option<DBEnumWrapper<AuthenticationType>>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RefundExportRow {
+    pub refund_id: String,
+    pub payment_id: String,
+    pub connector: String,
+    pub refund_status: DBEnumWrapper<RefundStatus>,
+    pub refund_type: DBEnumWrapper<RefundType>,
+    pub refund_amount: i64,
+    pub currency: DBEnumWrapper<Currency>,
+    pub refund_reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Fetches at most `limit` rows of the payments to export
+pub async fn get_payment_export_rows<T>(
+    merchant_id: &str,
+    filters: &PaymentFilters,
+    time_range: &TimeRange,
+    limit: u64,
+    pool: &T,
+) -> MetricsResult<Vec<PaymentExportRow>>
+where
+    T: AnalyticsDataSource + ReportExportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+    for column in PAYMENT_EXPORT_COLUMNS {
+        query_builder.add_select_column(*column).switch()?;
+    }
+
+    filters.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+    query_builder.set_limit_by(limit);
+
+    query_builder
+        .execute_query::<PaymentExportRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)
+}
+
+/// Fetches at most `limit` rows of the refunds to export
+pub async fn get_refund_export_rows<T>(
+    merchant_id: &str,
+    filters: &RefundFilters,
+    time_range: &TimeRange,
+    limit: u64,
+    pool: &T,
+) -> MetricsResult<Vec<RefundExportRow>>
+where
+    T: AnalyticsDataSource + ReportExportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Refund);
+
+    for column in REFUND_EXPORT_COLUMNS {
+        query_builder.add_select_column(*column).switch()?;
+    }
+
+    filters.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+    query_builder.set_limit_by(limit);
+
+    query_builder
+        .execute_query::<RefundExportRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)
+}
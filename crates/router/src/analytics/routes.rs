@@ -1,11 +1,17 @@
 use actix_web::{web, Responder, Scope};
 use api_models::analytics::{
-    GetPaymentFiltersRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-    GetRefundMetricRequest,
+    reports::{
+        PaymentsExportRequest, RefundsExportRequest, ReportCreateRequest, ReportExportId, ReportId,
+    },
+    GetConnectorPerformanceFilterRequest, GetConnectorPerformanceMetricRequest,
+    GetDisputeFilterRequest, GetDisputeMetricRequest, GetPaymentFiltersRequest,
+    GetPaymentMetricRequest, GetRefundFilterRequest, GetRefundMetricRequest,
 };
 use router_env::AnalyticsFlow;
 
-use super::{core::*, payments, refunds, types::AnalyticsDomain};
+use super::{
+    connector_performance, core::*, disputes, payments, refunds, reports, types::AnalyticsDomain,
+};
 use crate::{
     core::api_locking,
     services::{api, authentication as auth, authentication::AuthenticationData},
@@ -22,6 +28,36 @@ impl Analytics {
             .service(web::resource("metrics/refunds").route(web::post().to(get_refunds_metrics)))
             .service(web::resource("filters/payments").route(web::post().to(get_payment_filters)))
             .service(web::resource("filters/refunds").route(web::post().to(get_refund_filters)))
+            .service(web::resource("metrics/disputes").route(web::post().to(get_dispute_metrics)))
+            .service(web::resource("filters/disputes").route(web::post().to(get_dispute_filters)))
+            .service(
+                web::resource("metrics/connector_performance")
+                    .route(web::post().to(get_connector_performance_metrics)),
+            )
+            .service(
+                web::resource("filters/connector_performance")
+                    .route(web::post().to(get_connector_performance_filters)),
+            )
+            .service(
+                web::resource("reports")
+                    .route(web::post().to(create_report))
+                    .route(web::get().to(list_reports)),
+            )
+            .service(
+                web::resource("reports/{report_id}")
+                    .route(web::get().to(retrieve_report))
+                    .route(web::delete().to(delete_report)),
+            )
+            .service(
+                web::resource("reports/{report_id}/exports")
+                    .route(web::get().to(list_report_exports)),
+            )
+            .service(web::resource("exports/payments").route(web::post().to(export_payments)))
+            .service(web::resource("exports/refunds").route(web::post().to(export_refunds)))
+            .service(
+                web::resource("exports/{export_id}/download")
+                    .route(web::get().to(download_report_export)),
+            )
             .service(web::resource("{domain}/info").route(web::get().to(get_info)))
     }
 }
@@ -143,3 +179,268 @@ pub async fn get_refund_filters(
     )
     .await
 }
+
+/// # Panics
+///
+/// Panics if `json_payload` array does not contain one `GetDisputeMetricRequest` element.
+pub async fn get_dispute_metrics(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<[GetDisputeMetricRequest; 1]>,
+) -> impl Responder {
+    #[allow(clippy::expect_used)]
+    // safety: This shouldn't panic owing to the data type
+    let payload = json_payload
+        .into_inner()
+        .to_vec()
+        .pop()
+        .expect("Couldn't get GetDisputeMetricRequest");
+    let flow = AnalyticsFlow::GetDisputeMetrics;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: AuthenticationData, req| {
+            disputes::get_metrics(state.pool.clone(), auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn get_dispute_filters(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<GetDisputeFilterRequest>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::GetDisputeFilters;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationData, req: GetDisputeFilterRequest| {
+            dispute_filter_core(state.pool.clone(), req, auth.merchant_account)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// # Panics
+///
+/// Panics if `json_payload` array does not contain one `GetConnectorPerformanceMetricRequest` element.
+pub async fn get_connector_performance_metrics(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<[GetConnectorPerformanceMetricRequest; 1]>,
+) -> impl Responder {
+    #[allow(clippy::expect_used)]
+    // safety: This shouldn't panic owing to the data type
+    let payload = json_payload
+        .into_inner()
+        .to_vec()
+        .pop()
+        .expect("Couldn't get GetConnectorPerformanceMetricRequest");
+    let flow = AnalyticsFlow::GetConnectorPerformanceMetrics;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: AuthenticationData, req| {
+            connector_performance::get_metrics(state.pool.clone(), auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn get_connector_performance_filters(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<GetConnectorPerformanceFilterRequest>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::GetConnectorPerformanceFilters;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationData, req: GetConnectorPerformanceFilterRequest| {
+            connector_performance_filter_core(state.pool.clone(), req, auth.merchant_account)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn create_report(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<ReportCreateRequest>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::CreateReport;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationData, req| {
+            reports::create_report(state, auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn list_reports(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+) -> impl Responder {
+    let flow = AnalyticsFlow::ListReports;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: AuthenticationData, _| reports::list_reports(state, auth.merchant_account),
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn retrieve_report(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::RetrieveReport;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        ReportId {
+            report_id: path.into_inner(),
+        },
+        |state, auth: AuthenticationData, req| {
+            reports::retrieve_report(state, auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn delete_report(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::DeleteReport;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        ReportId {
+            report_id: path.into_inner(),
+        },
+        |state, auth: AuthenticationData, req| {
+            reports::delete_report(state, auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn list_report_exports(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::ListReportExports;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        ReportId {
+            report_id: path.into_inner(),
+        },
+        |state, auth: AuthenticationData, req| {
+            reports::list_report_exports(state, auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn export_payments(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<PaymentsExportRequest>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::ExportPayments;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationData, req| {
+            reports::export_payments(state, auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn export_refunds(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<RefundsExportRequest>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::ExportRefunds;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationData, req| {
+            reports::export_refunds(state, auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn download_report_export(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = AnalyticsFlow::DownloadReportExport;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        ReportExportId {
+            export_id: path.into_inner(),
+        },
+        |state, auth: AuthenticationData, req| {
+            reports::download_report_export(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
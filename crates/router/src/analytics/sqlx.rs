@@ -2,13 +2,14 @@ use std::{fmt::Display, str::FromStr};
 
 use api_models::analytics::refunds::RefundType;
 use common_enums::enums::{
-    AttemptStatus, AuthenticationType, Currency, PaymentMethod, RefundStatus,
+    AttemptStatus, AuthenticationType, Currency, DisputeStage, DisputeStatus, PaymentMethod,
+    RefundStatus,
 };
 use common_utils::errors::{CustomResult, ParsingError};
 use error_stack::{IntoReport, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::{kms, kms::decrypt::KmsDecrypt};
-#[cfg(not(feature = "kms"))]
+#[cfg(feature = "encryption_service")]
+use external_services::secrets_management::{decrypt::KmsDecrypt, SecretManagementInterface};
+#[cfg(not(feature = "encryption_service"))]
 use masking::PeekInterface;
 use sqlx::{
     postgres::{PgArgumentBuffer, PgPoolOptions, PgRow, PgTypeInfo, PgValueRef},
@@ -19,7 +20,7 @@ use sqlx::{
 use time::PrimitiveDateTime;
 
 use super::{
-    query::{Aggregate, ToSql},
+    query::{Aggregate, FilterColumn, QueryParam, ToSql},
     types::{
         AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, QueryExecutionError,
     },
@@ -49,17 +50,17 @@ impl Default for SqlxClient {
 impl SqlxClient {
     pub async fn from_conf(
         conf: &Database,
-        #[cfg(feature = "kms")] kms_client: &kms::KmsClient,
+        #[cfg(feature = "encryption_service")] secret_management_client: &dyn SecretManagementInterface,
     ) -> Self {
-        #[cfg(feature = "kms")]
+        #[cfg(feature = "encryption_service")]
         #[allow(clippy::expect_used)]
         let password = conf
             .password
-            .decrypt_inner(kms_client)
+            .decrypt_inner(secret_management_client)
             .await
             .expect("Failed to KMS decrypt database password");
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "encryption_service"))]
         let password = &conf.password.peek();
         let database_url = format!(
             "postgres://{}:{}@{}:{}/{}",
@@ -102,6 +103,8 @@ db_type!(AttemptStatus);
 db_type!(PaymentMethod, TEXT);
 db_type!(RefundStatus);
 db_type!(RefundType);
+db_type!(DisputeStage);
+db_type!(DisputeStatus);
 
 impl<'q, Type> Encode<'q, Postgres> for DBEnumWrapper<Type>
 where
@@ -152,20 +155,116 @@ where
     }
 }
 
+const PAYMENT_FILTER_COLUMNS: &[FilterColumn] = &[
+    FilterColumn::new("merchant_id"),
+    FilterColumn::new("created_at"),
+    FilterColumn::with_cast("currency", "Currency"),
+    FilterColumn::with_cast("status", "AttemptStatus"),
+    FilterColumn::new("connector"),
+    FilterColumn::with_cast("authentication_type", "AuthenticationType"),
+    FilterColumn::new("payment_method"),
+];
+
+const REFUND_FILTER_COLUMNS: &[FilterColumn] = &[
+    FilterColumn::new("merchant_id"),
+    FilterColumn::new("created_at"),
+    FilterColumn::with_cast("currency", "Currency"),
+    FilterColumn::with_cast("refund_status", "RefundStatus"),
+    FilterColumn::new("connector"),
+    FilterColumn::with_cast("refund_type", "RefundType"),
+];
+
+const DISPUTE_FILTER_COLUMNS: &[FilterColumn] = &[
+    FilterColumn::new("merchant_id"),
+    FilterColumn::new("created_at"),
+    FilterColumn::new("currency"),
+    FilterColumn::with_cast("dispute_stage", "DisputeStage"),
+    FilterColumn::with_cast("dispute_status", "DisputeStatus"),
+    FilterColumn::new("connector"),
+    FilterColumn::new("connector_reason"),
+];
+
+const CONNECTOR_PERFORMANCE_FILTER_COLUMNS: &[FilterColumn] = &[
+    FilterColumn::new("merchant_id"),
+    FilterColumn::new("created_at"),
+    FilterColumn::with_cast("currency", "Currency"),
+    FilterColumn::with_cast("status", "AttemptStatus"),
+    FilterColumn::new("connector"),
+    FilterColumn::with_cast("authentication_type", "AuthenticationType"),
+    FilterColumn::new("payment_method"),
+    FilterColumn::new("error_code"),
+    FilterColumn::new("gsm_decision"),
+];
+
+/// Payment attempts joined with the gateway status mapping of the error returned by the
+/// connector for the authorization, along with the flags derived from them. A failed attempt was
+/// retried if a later attempt was made for the same payment, and stepped up if that later attempt
+/// was a 3DS attempt while the failed one was not.
+const CONNECTOR_PERFORMANCE_COLLECTION: &str = "(SELECT pa.merchant_id, pa.created_at, \
+    pa.modified_at, pa.status, pa.currency, pa.connector, pa.payment_method, \
+    pa.authentication_type, pa.error_code, pa.connector_latency, gsm.decision AS gsm_decision, \
+    (pa.error_code IS NOT NULL) AS errored, \
+    (pa.error_code IS NOT NULL AND EXISTS (SELECT 1 FROM payment_attempt next \
+    WHERE next.merchant_id = pa.merchant_id AND next.payment_id = pa.payment_id \
+    AND next.created_at > pa.created_at)) AS retried, \
+    (pa.error_code IS NOT NULL AND pa.authentication_type = 'no_three_ds' \
+    AND EXISTS (SELECT 1 FROM payment_attempt next \
+    WHERE next.merchant_id = pa.merchant_id AND next.payment_id = pa.payment_id \
+    AND next.created_at > pa.created_at AND next.authentication_type = 'three_ds')) \
+    AS stepped_up, \
+    (pa.authentication_type = 'three_ds' AND pa.authentication_data IS NOT NULL) \
+    AS three_ds_challenged \
+    FROM payment_attempt pa LEFT JOIN gateway_status_map gsm \
+    ON gsm.connector = pa.connector AND gsm.flow = 'Authorize' AND gsm.sub_flow = 'sub_flow' \
+    AND gsm.code = pa.error_code AND gsm.message = pa.error_message) AS connector_performance";
+
 impl super::payments::filters::PaymentFilterAnalytics for SqlxClient {}
 impl super::payments::metrics::PaymentMetricAnalytics for SqlxClient {}
 impl super::refunds::metrics::RefundMetricAnalytics for SqlxClient {}
 impl super::refunds::filters::RefundFilterAnalytics for SqlxClient {}
+impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {}
+impl super::disputes::filters::DisputeFilterAnalytics for SqlxClient {}
+impl super::connector_performance::metrics::ConnectorPerformanceMetricAnalytics for SqlxClient {}
+impl super::connector_performance::filters::ConnectorPerformanceFilterAnalytics for SqlxClient {}
+impl super::reports::exports::ReportExportAnalytics for SqlxClient {}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
     type Row = PgRow;
 
-    async fn load_results<T>(&self, query: &str) -> CustomResult<Vec<T>, QueryExecutionError>
+    fn get_filter_columns(table: AnalyticsCollection) -> &'static [FilterColumn] {
+        match table {
+            AnalyticsCollection::Payment => PAYMENT_FILTER_COLUMNS,
+            AnalyticsCollection::Refund => REFUND_FILTER_COLUMNS,
+            AnalyticsCollection::Dispute => DISPUTE_FILTER_COLUMNS,
+            AnalyticsCollection::ConnectorPerformance => CONNECTOR_PERFORMANCE_FILTER_COLUMNS,
+        }
+    }
+
+    fn get_bind_placeholder(position: usize, cast_type: Option<&'static str>) -> String {
+        match cast_type {
+            Some(cast_type) => format!("${position}::\"{cast_type}\""),
+            None => format!("${position}"),
+        }
+    }
+
+    async fn load_results<T>(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+    ) -> CustomResult<Vec<T>, QueryExecutionError>
     where
         Self: LoadRow<T>,
     {
-        sqlx::query(&format!("{query};"))
+        let query_text = format!("{query};");
+        params
+            .iter()
+            .fold(sqlx::query(&query_text), |query, param| match param {
+                QueryParam::Text(value) => query.bind(value.as_str()),
+                QueryParam::Bool(value) => query.bind(*value),
+                QueryParam::BigInt(value) => query.bind(*value),
+                QueryParam::Timestamp(value) => query.bind(*value),
+            })
             .fetch_all(&self.pool)
             .await
             .into_report()
@@ -348,6 +447,203 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<String> = row.try_get("currency").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let dispute_stage: Option<DBEnumWrapper<DisputeStage>> =
+            row.try_get("dispute_stage").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let dispute_status: Option<DBEnumWrapper<DisputeStatus>> =
+            row.try_get("dispute_status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector_reason: Option<String> =
+            row.try_get("connector_reason").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector,
+            currency,
+            dispute_stage,
+            dispute_status,
+            connector_reason,
+            total,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::disputes::filters::DisputeFilterRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<String> = row.try_get("currency").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let dispute_stage: Option<DBEnumWrapper<DisputeStage>> =
+            row.try_get("dispute_stage").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let dispute_status: Option<DBEnumWrapper<DisputeStatus>> =
+            row.try_get("dispute_status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector_reason: Option<String> =
+            row.try_get("connector_reason").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        Ok(Self {
+            connector,
+            currency,
+            dispute_stage,
+            dispute_status,
+            connector_reason,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow>
+    for super::connector_performance::metrics::ConnectorPerformanceMetricRow
+{
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let authentication_type: Option<DBEnumWrapper<AuthenticationType>> =
+            row.try_get("authentication_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let error_code: Option<String> = row.try_get("error_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let gsm_decision: Option<String> = row.try_get("gsm_decision").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let flagged: Option<bool> = row.try_get("flagged").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let latency: Option<f64> = row.try_get("latency").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector,
+            payment_method,
+            currency,
+            authentication_type,
+            error_code,
+            gsm_decision,
+            flagged,
+            latency,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow>
+    for super::connector_performance::filters::ConnectorPerformanceFilterRow
+{
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let authentication_type: Option<DBEnumWrapper<AuthenticationType>> =
+            row.try_get("authentication_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let error_code: Option<String> = row.try_get("error_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let gsm_decision: Option<String> = row.try_get("gsm_decision").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        Ok(Self {
+            connector,
+            payment_method,
+            currency,
+            authentication_type,
+            error_code,
+            gsm_decision,
+        })
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
@@ -359,6 +655,8 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
         match self {
             Self::Payment => Ok("payment_attempt".to_string()),
             Self::Refund => Ok("refund".to_string()),
+            Self::Dispute => Ok("dispute".to_string()),
+            Self::ConnectorPerformance => Ok(CONNECTOR_PERFORMANCE_COLLECTION.to_string()),
         }
     }
 }
@@ -396,6 +694,66 @@ where
                     alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
                 )
             }
+            Self::Percentile {
+                field,
+                alias,
+                percentile,
+            } => {
+                format!(
+                    "percentile_cont({}.{:02}) within group (order by {}){}",
+                    percentile / 100,
+                    percentile % 100,
+                    field
+                        .to_sql()
+                        .attach_printable("Failed to percentile aggregate")?,
+                    alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
+                )
+            }
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::exports::PaymentExportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let authentication_type: Option<DBEnumWrapper<AuthenticationType>> =
+            row.try_get("authentication_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        Ok(Self {
+            payment_id: row.try_get("payment_id")?,
+            attempt_id: row.try_get("attempt_id")?,
+            status: row.try_get("status")?,
+            amount: row.try_get("amount")?,
+            currency,
+            connector: row.try_get("connector")?,
+            payment_method: row.try_get("payment_method")?,
+            payment_method_type: row.try_get("payment_method_type")?,
+            authentication_type,
+            error_code: row.try_get("error_code")?,
+            error_message: row.try_get("error_message")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::exports::RefundExportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            refund_id: row.try_get("refund_id")?,
+            payment_id: row.try_get("payment_id")?,
+            connector: row.try_get("connector")?,
+            refund_status: row.try_get("refund_status")?,
+            refund_type: row.try_get("refund_type")?,
+            refund_amount: row.try_get("refund_amount")?,
+            currency: row.try_get("currency")?,
+            refund_reason: row.try_get("refund_reason")?,
+            created_at: row.try_get("created_at")?,
         })
     }
 }
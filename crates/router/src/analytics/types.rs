@@ -6,13 +6,15 @@ use common_utils::{
 };
 use error_stack::{report, Report, ResultExt};
 
-use super::query::QueryBuildingError;
+use super::query::{FilterColumn, QueryBuildingError, QueryParam};
 
 #[derive(serde::Deserialize, Debug, masking::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AnalyticsDomain {
     Payments,
     Refunds,
+    Disputes,
+    ConnectorPerformance,
 }
 
 impl ApiEventMetric for AnalyticsDomain {}
@@ -21,6 +23,9 @@ impl ApiEventMetric for AnalyticsDomain {}
 pub enum AnalyticsCollection {
     Payment,
     Refund,
+    Dispute,
+    /// Payment attempts, along with the gateway status mapping of the errors returned for them
+    ConnectorPerformance,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
@@ -57,7 +62,19 @@ where
     Self: Sized + Sync + Send,
 {
     type Row;
-    async fn load_results<T>(&self, query: &str) -> CustomResult<Vec<T>, QueryExecutionError>
+
+    /// The columns of the collection which queries are allowed to filter on.
+    fn get_filter_columns(table: AnalyticsCollection) -> &'static [FilterColumn];
+
+    /// The placeholder for the bound parameter at the 1-based `position`, cast to `cast_type`
+    /// if specified.
+    fn get_bind_placeholder(position: usize, cast_type: Option<&'static str>) -> String;
+
+    async fn load_results<T>(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+    ) -> CustomResult<Vec<T>, QueryExecutionError>
     where
         Self: LoadRow<T>;
 }
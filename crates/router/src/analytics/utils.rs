@@ -1,4 +1,6 @@
 use api_models::analytics::{
+    connector_performance::{ConnectorPerformanceDimensions, ConnectorPerformanceMetrics},
+    disputes::{DisputeDimensions, DisputeMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     NameDescription,
@@ -20,3 +22,23 @@ pub fn get_payment_metrics_info() -> Vec<NameDescription> {
 pub fn get_refund_metrics_info() -> Vec<NameDescription> {
     RefundMetrics::iter().map(Into::into).collect()
 }
+
+pub fn get_dispute_dimensions() -> Vec<NameDescription> {
+    DisputeDimensions::iter().map(Into::into).collect()
+}
+
+pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
+    DisputeMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_connector_performance_dimensions() -> Vec<NameDescription> {
+    ConnectorPerformanceDimensions::iter()
+        .map(Into::into)
+        .collect()
+}
+
+pub fn get_connector_performance_metrics_info() -> Vec<NameDescription> {
+    ConnectorPerformanceMetrics::iter()
+        .map(Into::into)
+        .collect()
+}
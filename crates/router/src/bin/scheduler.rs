@@ -81,6 +81,11 @@ pub enum PTRunner {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
+    SubscriptionBillingWorkflow,
+    DisputeDeadlineWorkflow,
+    MerchantKeyRotationWorkflow,
+    AnalyticsReportWorkflow,
+    CardAccountUpdaterWorkflow,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -105,6 +110,22 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
             Some(PTRunner::DeleteTokenizeDataWorkflow) => {
                 Box::new(workflows::tokenized_data::DeleteTokenizeDataWorkflow)
             }
+            Some(PTRunner::SubscriptionBillingWorkflow) => {
+                Box::new(workflows::subscription_billing::SubscriptionBillingWorkflow)
+            }
+            Some(PTRunner::DisputeDeadlineWorkflow) => {
+                Box::new(workflows::dispute_deadline::DisputeDeadlineWorkflow)
+            }
+            Some(PTRunner::MerchantKeyRotationWorkflow) => {
+                Box::new(workflows::merchant_key_rotation::MerchantKeyRotationWorkflow)
+            }
+            Some(PTRunner::CardAccountUpdaterWorkflow) => {
+                Box::new(workflows::card_account_updater::CardAccountUpdaterWorkflow)
+            }
+            #[cfg(feature = "olap")]
+            Some(PTRunner::AnalyticsReportWorkflow) => {
+                Box::new(workflows::analytics_report::AnalyticsReportWorkflow)
+            }
             _ => Err(ProcessTrackerError::UnexpectedFlow)?,
         };
         let app_state = &state.clone();
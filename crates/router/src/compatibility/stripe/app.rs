@@ -1,6 +1,9 @@
 use actix_web::{web, Scope};
 
-use super::{customers::*, payment_intents::*, refunds::*, setup_intents::*, webhooks::*};
+use super::{
+    charges::*, checkout_sessions::*, customers::*, payment_intents::*, payment_methods::*,
+    refunds::*, setup_intents::*, webhooks::*,
+};
 use crate::routes::{self, mandates, webhooks};
 
 pub struct PaymentIntents;
@@ -112,12 +115,41 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::post().to(payment_method_create))
+                    .route(web::get().to(payment_method_list)),
+            )
+            .service(web::resource("/{id}").route(web::get().to(payment_method_retrieve)))
+            .service(web::resource("/{id}/attach").route(web::post().to(payment_method_attach)))
             .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
     }
 }
+
+pub struct Charges;
+
+impl Charges {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/charges")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(charge_list)))
+            .service(web::resource("/{charge_id}").route(web::get().to(charge_retrieve)))
+    }
+}
+
+pub struct CheckoutSessions;
+
+impl CheckoutSessions {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/checkout/sessions")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::post().to(checkout_session_create)))
+            .service(web::resource("/{session_id}").route(web::get().to(checkout_session_retrieve)))
+    }
+}
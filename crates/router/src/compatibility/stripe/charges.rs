@@ -0,0 +1,292 @@
+pub mod types;
+use std::collections::{hash_map::Entry, HashMap};
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payments as payment_types;
+use common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking::{self, GetLockingInput},
+        errors::{ApiErrorResponse, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::Oss,
+        payments,
+    },
+    db::StorageInterface,
+    routes,
+    services::{self, api, authentication as auth},
+    types::{api as api_types, domain, storage, transformers::ForeignFrom},
+    utils,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieve))]
+pub async fn charge_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let charge_id = path.into_inner();
+
+    let flow = Flow::PaymentsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeCharge,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        charge_id,
+        |state, auth, charge_id| {
+            retrieve_charge_core(state, auth.merchant_account, auth.key_store, charge_id)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
+pub async fn charge_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripeChargeListConstraints>,
+) -> HttpResponse {
+    let payload = query_payload.into_inner();
+
+    let flow = Flow::PaymentsList;
+    let locking_action = match &payload.payment_intent {
+        Some(payment_id) => get_payment_retrieve_request(payment_id.clone())
+            .get_locking_input(Flow::PaymentsRetrieve),
+        None => api_locking::LockAction::NotApplicable,
+    };
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeChargeList,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, payload| {
+            list_charges_core(state, auth.merchant_account, auth.key_store, payload)
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+fn get_payment_retrieve_request(payment_id: String) -> payment_types::PaymentsRetrieveRequest {
+    payment_types::PaymentsRetrieveRequest {
+        resource_id: api_types::PaymentIdType::PaymentIntentId(payment_id),
+        merchant_id: None,
+        force_sync: false,
+        connector: None,
+        param: None,
+        merchant_connector_details: None,
+        client_secret: None,
+        expand_attempts: Some(true),
+        expand_captures: None,
+    }
+}
+
+/// Lists the charges of a payment when `payment_intent` is provided, or pages through all the
+/// charges of the merchant otherwise, newest first.
+async fn list_charges_core(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    constraints: types::StripeChargeListConstraints,
+) -> RouterResponse<types::ChargeListResponse> {
+    utils::when(
+        !(1..=PAYMENTS_LIST_MAX_LIMIT_V1).contains(&constraints.limit),
+        || {
+            Err(ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {PAYMENTS_LIST_MAX_LIMIT_V1}"),
+            })
+        },
+    )?;
+
+    let payment_id = match constraints.payment_intent {
+        Some(payment_id) => payment_id,
+        None => {
+            return list_merchant_charges(&state, &merchant_account, constraints)
+                .await
+                .map(services::ApplicationResponse::Json)
+        }
+    };
+
+    let response =
+        payments::payments_core::<api_types::PSync, api_types::PaymentsResponse, _, _, _, Oss>(
+            state,
+            merchant_account,
+            key_store,
+            payments::PaymentStatus,
+            get_payment_retrieve_request(payment_id),
+            api::AuthFlow::Merchant,
+            payments::CallConnectorAction::Trigger,
+            None,
+            api_types::HeaderPayload::default(),
+        )
+        .await?;
+
+    match response {
+        services::ApplicationResponse::Json(payment) => Ok(services::ApplicationResponse::Json(
+            types::ChargeListResponse {
+                payments: vec![payment],
+                has_more: false,
+            },
+        )),
+        _ => Err(ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response received from payments core"),
+    }
+}
+
+async fn list_merchant_charges(
+    state: &routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    constraints: types::StripeChargeListConstraints,
+) -> RouterResult<types::ChargeListResponse> {
+    let db = &*state.store;
+    let limit = usize::try_from(constraints.limit)
+        .into_report()
+        .change_context(ApiErrorResponse::InternalServerError)?;
+
+    let mut cursor_ids = Vec::new();
+    for charge_id in [&constraints.starting_after, &constraints.ending_before] {
+        cursor_ids.push(match charge_id {
+            Some(charge_id) => Some(find_charge(db, merchant_account, charge_id).await?.id),
+            None => None,
+        });
+    }
+    let (starting_after_id, ending_before_id) = (cursor_ids[0], cursor_ids[1]);
+
+    // One more charge than requested is fetched to know whether there are more charges to list
+    let mut attempts = db
+        .list_payment_attempts_by_merchant_id(
+            &merchant_account.merchant_id,
+            starting_after_id,
+            ending_before_id,
+            i64::from(constraints.limit) + 1,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payment attempts of the merchant")?;
+    let has_more = attempts.len() > limit;
+    if has_more {
+        // When paging backwards, the extra charge is the newest one
+        if ending_before_id.is_some() {
+            attempts.remove(0);
+        } else {
+            attempts.truncate(limit);
+        }
+    }
+
+    let mut payment_intents = HashMap::new();
+    let mut payments = Vec::with_capacity(attempts.len());
+    for attempt in attempts {
+        let payment_intent = match payment_intents.entry(attempt.payment_id.clone()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(
+                db.find_payment_intent_by_payment_id_merchant_id(
+                    &attempt.payment_id,
+                    &merchant_account.merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(ApiErrorResponse::PaymentNotFound)?,
+            ),
+        };
+        payments.push(get_charge_payment(payment_intent, attempt));
+    }
+
+    Ok(types::ChargeListResponse { payments, has_more })
+}
+
+/// Builds the response for the payment owning a charge, with the charge as its only attempt.
+fn get_charge_payment(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: storage::PaymentAttempt,
+) -> api_types::PaymentsResponse {
+    let attempt = payment_types::PaymentAttemptResponse::foreign_from(payment_attempt.clone());
+    api_types::PaymentsResponse {
+        amount_received: payment_intent.amount_captured,
+        attempts: Some(vec![attempt]),
+        ..api_types::PaymentsResponse::foreign_from((payment_intent.clone(), payment_attempt))
+    }
+}
+
+async fn find_charge(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    charge_id: &str,
+) -> RouterResult<storage::PaymentAttempt> {
+    db.find_payment_attempt_by_attempt_id_merchant_id(
+        charge_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(ApiErrorResponse::GenericNotFoundError {
+        message: "Charge not found".to_string(),
+    })
+}
+
+/// A charge maps onto a payment attempt, so the payment owning the attempt is retrieved and only
+/// the requested attempt is kept in the response.
+async fn retrieve_charge_core(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    charge_id: String,
+) -> RouterResponse<api_types::PaymentsResponse> {
+    let payment_attempt = find_charge(&*state.store, &merchant_account, &charge_id).await?;
+    let payload = get_payment_retrieve_request(payment_attempt.payment_id);
+
+    let response =
+        payments::payments_core::<api_types::PSync, api_types::PaymentsResponse, _, _, _, Oss>(
+            state,
+            merchant_account,
+            key_store,
+            payments::PaymentStatus,
+            payload,
+            api::AuthFlow::Merchant,
+            payments::CallConnectorAction::Trigger,
+            None,
+            api_types::HeaderPayload::default(),
+        )
+        .await?;
+
+    match response {
+        services::ApplicationResponse::Json(mut payment) => {
+            payment.attempts = payment.attempts.map(|attempts| {
+                attempts
+                    .into_iter()
+                    .filter(|attempt| attempt.attempt_id == charge_id)
+                    .collect()
+            });
+            Ok(services::ApplicationResponse::Json(payment))
+        }
+        other => Ok(other),
+    }
+}
@@ -0,0 +1,232 @@
+use api_models::payments;
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    impl_misc_api_event_type, pii,
+};
+use error_stack::IntoReport;
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, types::api::enums as api_enums};
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct StripeChargeListConstraints {
+    pub payment_intent: Option<String>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+}
+
+fn default_limit() -> u32 {
+    10
+}
+
+/// The payments owning a page of charges, each payment including the charges listed for it as its
+/// attempts.
+#[derive(Debug, Serialize)]
+pub struct ChargeListResponse {
+    pub payments: Vec<payments::PaymentsResponse>,
+    pub has_more: bool,
+}
+
+impl_misc_api_event_type!(StripeChargeListConstraints, ChargeListResponse);
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeChargeStatus {
+    Succeeded,
+    Pending,
+    Failed,
+}
+
+impl From<api_enums::AttemptStatus> for StripeChargeStatus {
+    fn from(item: api_enums::AttemptStatus) -> Self {
+        match item {
+            api_enums::AttemptStatus::Charged | api_enums::AttemptStatus::PartialCharged => {
+                Self::Succeeded
+            }
+            api_enums::AttemptStatus::Failure
+            | api_enums::AttemptStatus::AuthorizationFailed
+            | api_enums::AttemptStatus::AuthenticationFailed
+            | api_enums::AttemptStatus::RouterDeclined
+            | api_enums::AttemptStatus::CaptureFailed
+            | api_enums::AttemptStatus::VoidFailed => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeChargePaymentMethodDetails {
+    #[serde(rename = "type")]
+    pub payment_method_type: Option<api_enums::PaymentMethod>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeCharge {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: i64,
+    pub amount_captured: i64,
+    pub currency: String,
+    pub status: StripeChargeStatus,
+    pub paid: bool,
+    pub captured: bool,
+    pub payment_intent: String,
+    pub payment_method_details: StripeChargePaymentMethodDetails,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+    pub created: Option<i64>,
+    pub customer: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub livemode: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct StripeChargeList {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeCharge>,
+}
+
+fn to_stripe_charge(
+    payment: &payments::PaymentsResponse,
+    attempt: payments::PaymentAttemptResponse,
+) -> StripeCharge {
+    let status = StripeChargeStatus::from(attempt.status);
+    let captured = status == StripeChargeStatus::Succeeded;
+    let amount_captured = match attempt.status {
+        api_enums::AttemptStatus::Charged => attempt.amount,
+        api_enums::AttemptStatus::PartialCharged => payment.amount_received.unwrap_or_default(),
+        _ => 0,
+    };
+    StripeCharge {
+        id: attempt.attempt_id,
+        object: "charge",
+        amount: attempt.amount,
+        amount_captured,
+        currency: attempt
+            .currency
+            .map(|currency| currency.to_string())
+            .unwrap_or_else(|| payment.currency.clone())
+            .to_lowercase(),
+        paid: captured,
+        captured,
+        status,
+        payment_intent: payment.payment_id.clone().unwrap_or_default(),
+        payment_method_details: StripeChargePaymentMethodDetails {
+            payment_method_type: attempt.payment_method,
+        },
+        failure_code: attempt.error_code,
+        failure_message: attempt.error_message,
+        created: payment
+            .created
+            .map(|created| created.assume_utc().unix_timestamp()),
+        customer: payment.customer_id.clone(),
+        description: payment.description.clone(),
+        metadata: payment.metadata.clone(),
+        livemode: false,
+    }
+}
+
+impl TryFrom<payments::PaymentsResponse> for StripeCharge {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(mut item: payments::PaymentsResponse) -> errors::RouterResult<Self> {
+        let attempt = item
+            .attempts
+            .take()
+            .and_then(|attempts| attempts.into_iter().next())
+            .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Charge not found".to_string(),
+            })
+            .into_report()?;
+        Ok(to_stripe_charge(&item, attempt))
+    }
+}
+
+impl From<ChargeListResponse> for StripeChargeList {
+    fn from(item: ChargeListResponse) -> Self {
+        let data = item
+            .payments
+            .into_iter()
+            .flat_map(|mut payment| {
+                let attempts = payment.attempts.take().unwrap_or_default();
+                attempts
+                    .into_iter()
+                    .map(|attempt| to_stripe_charge(&payment, attempt))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Self {
+            object: "list",
+            url: "/v1/charges",
+            has_more: item.has_more,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_payment(payment_id: &str, attempt_ids: &[&str]) -> payments::PaymentsResponse {
+        payments::PaymentsResponse {
+            payment_id: Some(payment_id.to_string()),
+            currency: "USD".to_string(),
+            attempts: Some(
+                attempt_ids
+                    .iter()
+                    .map(|attempt_id| payments::PaymentAttemptResponse {
+                        attempt_id: attempt_id.to_string(),
+                        status: api_enums::AttemptStatus::Charged,
+                        amount: 100,
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_charge_list_contains_the_charges_of_every_payment() {
+        let charge_list = StripeChargeList::from(ChargeListResponse {
+            payments: vec![
+                get_payment("pay_1", &["pay_1_1", "pay_1_2"]),
+                get_payment("pay_2", &["pay_2_1"]),
+            ],
+            has_more: true,
+        });
+
+        let charges = charge_list
+            .data
+            .iter()
+            .map(|charge| (charge.id.as_str(), charge.payment_intent.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            charges,
+            vec![
+                ("pay_1_1", "pay_1"),
+                ("pay_1_2", "pay_1"),
+                ("pay_2_1", "pay_2")
+            ]
+        );
+        assert!(charge_list.has_more);
+        assert_eq!(charge_list.data[0].currency, "usd");
+        assert_eq!(charge_list.data[0].status, StripeChargeStatus::Succeeded);
+    }
+
+    #[test]
+    fn test_charge_list_limit_defaults_to_ten() {
+        let constraints: StripeChargeListConstraints =
+            serde_json::from_str(r#"{"starting_after": "pay_1_1"}"#).unwrap();
+        assert_eq!(constraints.limit, 10);
+        assert_eq!(constraints.payment_intent, None);
+        assert_eq!(constraints.starting_after.as_deref(), Some("pay_1_1"));
+    }
+}
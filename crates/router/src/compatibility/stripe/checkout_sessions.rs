@@ -0,0 +1,176 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payments as payment_types;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking::GetLockingInput,
+        errors::{ApiErrorResponse, RouterResult, StorageErrorExt},
+        payment_methods::Oss,
+        payments::{self, helpers},
+    },
+    routes,
+    services::{api, authentication as auth},
+    types::{api as api_types, storage::enums},
+    utils::OptionExt,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCreate))]
+pub async fn checkout_session_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeCheckoutSessionRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let create_payment_req: payment_types::PaymentsRequest = match payload.try_into() {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::PaymentsCreate;
+    let locking_action = create_payment_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeCheckoutSessionResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payment_req,
+        |state, auth, req| {
+            payments::payments_core::<api_types::Authorize, api_types::PaymentsResponse, _, _, _, Oss>(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payments::PaymentCreate,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                api_types::HeaderPayload::default(),
+            )
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieve))]
+pub async fn checkout_session_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_types::PaymentsRetrieveRequest {
+        resource_id: api_types::PaymentIdType::PaymentIntentId(path.into_inner()),
+        merchant_id: None,
+        force_sync: false,
+        connector: None,
+        param: None,
+        merchant_connector_details: None,
+        client_secret: None,
+        expand_attempts: None,
+        expand_captures: None,
+    };
+
+    let flow = Flow::PaymentsRetrieve;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeCheckoutSessionResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, payload| async move {
+            let merchant_id = auth.merchant_account.merchant_id.clone();
+            let storage_scheme = auth.merchant_account.storage_scheme;
+            let response = payments::payments_core::<
+                api_types::PSync,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                Oss,
+            >(
+                state.clone(),
+                auth.merchant_account,
+                auth.key_store,
+                payments::PaymentStatus,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                api_types::HeaderPayload::default(),
+            )
+            .await?;
+            add_payment_link_to_response(&state, &merchant_id, storage_scheme, response).await
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+/// The payment sync response does not carry the payment link, so it is fetched only for checkout
+/// sessions, which are backed by payment links.
+async fn add_payment_link_to_response(
+    state: &routes::AppState,
+    merchant_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+    response: api::ApplicationResponse<api_types::PaymentsResponse>,
+) -> RouterResult<api::ApplicationResponse<api_types::PaymentsResponse>> {
+    match response {
+        api::ApplicationResponse::Json(mut payments_response)
+            if payments_response.payment_link.is_none() =>
+        {
+            let payment_id = payments_response
+                .payment_id
+                .clone()
+                .get_required_value("payment_id")?;
+            let payment_intent = state
+                .store
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &payment_id,
+                    merchant_id,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+            payments_response.payment_link = helpers::get_payment_link_data(
+                payment_intent.payment_link_id.as_ref(),
+                &*state.store,
+            )
+            .await?;
+            Ok(api::ApplicationResponse::Json(payments_response))
+        }
+        response => Ok(response),
+    }
+}
@@ -0,0 +1,226 @@
+use api_models::payments;
+use common_utils::{ext_traits::StringExt, pii};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{env, logger};
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, pii::Email, types::api::enums as api_enums};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCheckoutSessionMode {
+    #[default]
+    Payment,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripeProductData {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripePriceData {
+    pub currency: String,
+    pub unit_amount: i64,
+    pub product_data: StripeProductData,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripeLineItem {
+    pub price_data: StripePriceData,
+    pub quantity: u16,
+}
+
+/// Checkout sessions are backed by payment links, so only the `payment` mode and inline
+/// `price_data` line items are supported.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripeCheckoutSessionRequest {
+    pub mode: StripeCheckoutSessionMode,
+    pub line_items: Vec<StripeLineItem>,
+    pub success_url: Option<url::Url>,
+    pub cancel_url: Option<url::Url>, // not used
+    pub customer: Option<String>,
+    pub customer_email: Option<Email>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub expires_at: Option<i64>,
+}
+
+impl TryFrom<StripeCheckoutSessionRequest> for payments::PaymentsRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeCheckoutSessionRequest) -> errors::RouterResult<Self> {
+        let mut currency = None;
+        let mut amount: i64 = 0;
+        let mut order_details = Vec::with_capacity(item.line_items.len());
+        for line_item in item.line_items {
+            let line_item_currency: api_enums::Currency = line_item
+                .price_data
+                .currency
+                .to_uppercase()
+                .parse_enum("currency")
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "currency",
+                })?;
+            if matches!(currency, Some(currency) if currency != line_item_currency) {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "All line items must use the same currency".to_string(),
+                })
+                .into_report();
+            }
+            currency = Some(line_item_currency);
+
+            amount = line_item
+                .price_data
+                .unit_amount
+                .checked_mul(i64::from(line_item.quantity))
+                .and_then(|line_item_amount| amount.checked_add(line_item_amount))
+                .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "line_items",
+                })
+                .into_report()?;
+
+            order_details.push(payments::OrderDetailsWithAmount {
+                product_name: line_item.price_data.product_data.name,
+                quantity: line_item.quantity,
+                amount: line_item.price_data.unit_amount,
+                product_img_link: None,
+            });
+        }
+
+        let link_expiry = item
+            .expires_at
+            .map(|expires_at| {
+                time::OffsetDateTime::from_unix_timestamp(expires_at)
+                    .map(|expires_at| {
+                        time::PrimitiveDateTime::new(expires_at.date(), expires_at.time())
+                    })
+                    .map_err(|err| {
+                        logger::error!("Error: from_unix_timestamp: {}", err);
+                        errors::ApiErrorResponse::InvalidDataValue {
+                            field_name: "expires_at",
+                        }
+                    })
+            })
+            .transpose()
+            .into_report()?;
+
+        Ok(Self {
+            amount: Some(amount.into()),
+            currency: Some(
+                currency
+                    .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "line_items",
+                    })
+                    .into_report()?,
+            ),
+            confirm: Some(false),
+            customer_id: item.customer,
+            email: item.customer_email,
+            return_url: item.success_url,
+            order_details: Some(order_details),
+            metadata: item.metadata,
+            payment_link_object: Some(payments::PaymentLinkObject {
+                link_expiry,
+                merchant_custom_domain_name: None,
+                custom_merchant_name: None,
+                is_reusable: false,
+            }),
+            ..Self::default()
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCheckoutSessionStatus {
+    Open,
+    Complete,
+    Expired,
+}
+
+impl From<api_enums::IntentStatus> for StripeCheckoutSessionStatus {
+    fn from(item: api_enums::IntentStatus) -> Self {
+        match item {
+            api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::PartiallyCaptured => Self::Complete,
+            api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::Failed => Self::Expired,
+            _ => Self::Open,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCheckoutSessionPaymentStatus {
+    Paid,
+    Unpaid,
+}
+
+impl From<api_enums::IntentStatus> for StripeCheckoutSessionPaymentStatus {
+    fn from(item: api_enums::IntentStatus) -> Self {
+        match item {
+            api_enums::IntentStatus::Succeeded => Self::Paid,
+            _ => Self::Unpaid,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeCheckoutSessionResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub mode: &'static str,
+    pub url: String,
+    pub status: StripeCheckoutSessionStatus,
+    pub payment_status: StripeCheckoutSessionPaymentStatus,
+    pub payment_intent: String,
+    pub amount_total: i64,
+    pub currency: String,
+    pub customer: Option<String>,
+    pub success_url: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created: Option<i64>,
+    pub livemode: bool,
+}
+
+impl From<(payments::PaymentsResponse, payments::PaymentLinkResponse)>
+    for StripeCheckoutSessionResponse
+{
+    fn from(
+        (item, payment_link): (payments::PaymentsResponse, payments::PaymentLinkResponse),
+    ) -> Self {
+        let payment_id = item.payment_id.unwrap_or_default();
+        Self {
+            id: payment_id.clone(),
+            object: "checkout.session",
+            mode: "payment",
+            url: payment_link.link,
+            status: item.status.into(),
+            payment_status: item.status.into(),
+            payment_intent: payment_id,
+            amount_total: item.amount,
+            currency: item.currency.to_lowercase(),
+            customer: item.customer_id,
+            success_url: item.return_url,
+            metadata: item.metadata,
+            created: item
+                .created
+                .map(|created| created.assume_utc().unix_timestamp()),
+            livemode: matches!(env::which(), env::Env::Production),
+        }
+    }
+}
+
+impl TryFrom<payments::PaymentsResponse> for StripeCheckoutSessionResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(mut item: payments::PaymentsResponse) -> errors::RouterResult<Self> {
+        let payment_link = item
+            .payment_link
+            .take()
+            .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Checkout session not found".to_string(),
+            })
+            .into_report()?;
+        Ok((item, payment_link).into())
+    }
+}
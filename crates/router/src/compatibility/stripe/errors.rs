@@ -527,6 +527,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::PreconditionFailed { message } => {
                 Self::PreconditionFailed { message }
             }
+            errors::ApiErrorResponse::RoutingTestsFailed { .. } => Self::PreconditionFailed {
+                message: "Routing test cases failed".to_string(),
+            },
             errors::ApiErrorResponse::InvalidDataValue { field_name } => Self::ParameterMissing {
                 field_name: field_name.to_string(),
                 param: field_name.to_string(),
@@ -54,12 +54,12 @@ impl From<StripeBillingDetails> for payments::Address {
     }
 }
 
-#[derive(Default, Serialize, PartialEq, Eq, Deserialize, Clone, Debug)]
+#[derive(Serialize, PartialEq, Eq, Deserialize, Clone, Debug)]
 pub struct StripeCard {
     pub number: cards::CardNumber,
-    pub exp_month: masking::Secret<String>,
-    pub exp_year: masking::Secret<String>,
-    pub cvc: masking::Secret<String>,
+    pub exp_month: cards::CardExpirationMonth,
+    pub exp_year: cards::CardExpirationYear,
+    pub cvc: cards::CardSecurityCode,
     pub holder_name: Option<masking::Secret<String>>,
 }
 
@@ -119,7 +119,7 @@ impl From<StripeCard> for payments::Card {
             card_exp_month: card.exp_month,
             card_exp_year: card.exp_year,
             card_holder_name: card.holder_name.unwrap_or("name".to_string().into()),
-            card_cvc: card.cvc,
+            card_cvc: Some(card.cvc),
             card_issuer: None,
             card_network: None,
             bank_code: None,
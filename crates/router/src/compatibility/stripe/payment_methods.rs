@@ -0,0 +1,224 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::report;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{ApiErrorResponse, RouterResponse, StorageErrorExt},
+        payment_methods::cards,
+    },
+    routes,
+    services::{api, authentication as auth},
+    types::{api::payment_methods as payment_method_types, domain},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsCreate))]
+pub async fn payment_method_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripePaymentMethodCreateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let create_pm_req: payment_method_types::PaymentMethodCreate = payload.into();
+
+    let flow = Flow::PaymentMethodsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_pm_req,
+        |state, auth, req| async move {
+            cards::add_payment_method(state, req, &auth.merchant_account, &auth.key_store).await
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
+pub async fn payment_method_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    let flow = Flow::PaymentMethodsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| {
+            payment_method_retrieve_for_customer(state, auth.merchant_account, req, None)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsUpdate))]
+pub async fn payment_method_attach(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let attach_req: types::StripePaymentMethodAttachRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    let flow = Flow::PaymentMethodsUpdate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| {
+            payment_method_retrieve_for_customer(
+                state,
+                auth.merchant_account,
+                req,
+                Some(attach_req.customer.clone()),
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsList))]
+pub async fn payment_method_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripePaymentMethodListConstraints>,
+) -> HttpResponse {
+    let customer_id = match query_payload.into_inner().customer {
+        Some(customer_id) => customer_id,
+        None => {
+            return api::log_and_return_error_response(report!(
+                errors::StripeErrorCode::ParameterMissing {
+                    field_name: "customer".to_string(),
+                    param: "customer".to_string(),
+                }
+            ))
+        }
+    };
+
+    let flow = Flow::CustomerPaymentMethodsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        customer_id,
+        |state, auth, customer_id| {
+            cards::list_payment_methods_by_customer_id(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment methods are always saved for a customer, so attaching a payment method only succeeds
+/// when it already belongs to the customer, as it cannot be moved to another customer.
+async fn payment_method_retrieve_for_customer(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payment_method_types::PaymentMethodId,
+    customer_id: Option<String>,
+) -> RouterResponse<payment_method_types::PaymentMethodResponse> {
+    let payment_method = state
+        .store
+        .find_payment_method(&req.payment_method_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentMethodNotFound)?;
+    if payment_method.merchant_id != merchant_account.merchant_id {
+        return Err(report!(ApiErrorResponse::PaymentMethodNotFound));
+    }
+    if let Some(customer_id) = customer_id {
+        if payment_method.customer_id != customer_id {
+            return Err(report!(ApiErrorResponse::PreconditionFailed {
+                message: "The payment method is attached to a different customer".to_string(),
+            }));
+        }
+    }
+
+    cards::retrieve_payment_method(state, req).await
+}
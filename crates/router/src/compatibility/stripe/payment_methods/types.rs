@@ -0,0 +1,167 @@
+use api_models::payment_methods as api_types;
+use common_utils::pii::{self, Email};
+use serde::{Deserialize, Serialize};
+
+use crate::types::api::enums as api_enums;
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePaymentMethodType {
+    #[default]
+    Card,
+}
+
+impl From<StripePaymentMethodType> for api_enums::PaymentMethod {
+    fn from(item: StripePaymentMethodType) -> Self {
+        match item {
+            StripePaymentMethodType::Card => Self::Card,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripePaymentMethodCard {
+    pub number: cards::CardNumber,
+    pub exp_month: cards::CardExpirationMonth,
+    pub exp_year: cards::CardExpirationYear,
+    pub cvc: Option<masking::Secret<String>>, // not used, the cvc is never stored
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct StripeBillingDetails {
+    pub name: Option<masking::Secret<String>>,
+    pub email: Option<Email>,                   // not used
+    pub phone: Option<masking::Secret<String>>, // not used
+    pub address: Option<pii::SecretSerdeValue>, // not used
+}
+
+/// Payment methods are saved for a customer, so `customer` has to be passed on creation unlike in
+/// Stripe, where payment methods can be created first and attached to a customer later.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripePaymentMethodCreateRequest {
+    #[serde(rename = "type")]
+    pub payment_method_type: StripePaymentMethodType,
+    pub card: Option<StripePaymentMethodCard>,
+    pub billing_details: Option<StripeBillingDetails>,
+    pub customer: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripePaymentMethodAttachRequest {
+    pub customer: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripePaymentMethodListConstraints {
+    pub customer: Option<String>,
+    #[serde(rename = "type")]
+    pub payment_method_type: Option<StripePaymentMethodType>,
+}
+
+impl From<StripePaymentMethodCreateRequest> for api_types::PaymentMethodCreate {
+    fn from(req: StripePaymentMethodCreateRequest) -> Self {
+        let card_holder_name = req.billing_details.and_then(|details| details.name);
+        Self {
+            payment_method: req.payment_method_type.into(),
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card: req.card.map(|card| api_types::CardDetail {
+                card_number: card.number,
+                card_exp_month: card.exp_month,
+                card_exp_year: card.exp_year,
+                card_holder_name,
+                nick_name: None,
+            }),
+            metadata: req.metadata,
+            customer_id: req.customer,
+            card_network: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub payment_method_type: api_enums::PaymentMethod,
+    pub card: Option<StripePaymentMethodCardResponse>,
+    pub billing_details: StripeBillingDetailsResponse,
+    pub customer: Option<String>,
+    pub created: Option<i64>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub livemode: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodCardResponse {
+    pub brand: Option<String>,
+    pub country: Option<String>,
+    pub last4: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+    pub fingerprint: Option<masking::Secret<String>>,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
+pub struct StripeBillingDetailsResponse {
+    pub name: Option<masking::Secret<String>>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripePaymentMethodResponse>,
+}
+
+impl From<api_types::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(item: api_types::PaymentMethodResponse) -> Self {
+        let billing_details = StripeBillingDetailsResponse {
+            name: item
+                .card
+                .as_ref()
+                .and_then(|card| card.card_holder_name.clone()),
+        };
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            payment_method_type: item.payment_method,
+            card: item.card.map(From::from),
+            billing_details,
+            customer: item.customer_id,
+            created: item
+                .created
+                .map(|created| created.assume_utc().unix_timestamp()),
+            metadata: item.metadata,
+            livemode: false,
+        }
+    }
+}
+
+impl From<api_types::CardDetailFromLocker> for StripePaymentMethodCardResponse {
+    fn from(item: api_types::CardDetailFromLocker) -> Self {
+        Self {
+            brand: item.scheme.map(|scheme| scheme.to_lowercase()),
+            country: item.issuer_country,
+            last4: item.last4_digits,
+            exp_month: item.expiry_month,
+            exp_year: item.expiry_year,
+            fingerprint: item.card_fingerprint,
+        }
+    }
+}
+
+impl From<Vec<api_types::PaymentMethodResponse>> for StripePaymentMethodListResponse {
+    fn from(items: Vec<api_types::PaymentMethodResponse>) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/payment_methods",
+            has_more: false,
+            data: items.into_iter().map(From::from).collect(),
+        }
+    }
+}
@@ -41,12 +41,12 @@ impl From<StripeBillingDetails> for payments::Address {
     }
 }
 
-#[derive(Default, Serialize, PartialEq, Eq, Deserialize, Clone)]
+#[derive(Serialize, PartialEq, Eq, Deserialize, Clone)]
 pub struct StripeCard {
     pub number: cards::CardNumber,
-    pub exp_month: pii::Secret<String>,
-    pub exp_year: pii::Secret<String>,
-    pub cvc: pii::Secret<String>,
+    pub exp_month: cards::CardExpirationMonth,
+    pub exp_year: cards::CardExpirationYear,
+    pub cvc: cards::CardSecurityCode,
 }
 
 // ApplePay wallet param is not available in stripe Docs
@@ -96,7 +96,7 @@ impl From<StripeCard> for payments::Card {
             card_exp_month: card.exp_month,
             card_exp_year: card.exp_year,
             card_holder_name: masking::Secret::new("stripe_cust".to_owned()),
-            card_cvc: card.cvc,
+            card_cvc: Some(card.cvc),
             card_issuer: None,
             card_network: None,
             bank_code: None,
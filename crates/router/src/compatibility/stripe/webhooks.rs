@@ -1,19 +1,25 @@
 use api_models::{
-    enums::{DisputeStatus, MandateStatus},
+    enums::{DisputeStatus, MandateStatus, SubscriptionStatus},
     webhooks::{self as api},
 };
-use common_utils::{crypto::SignMessage, date_time, ext_traits};
+use common_utils::{
+    crypto::{SignMessage, VerifySignature},
+    date_time, ext_traits,
+};
 use error_stack::{IntoReport, ResultExt};
 use router_env::logger;
 use serde::Serialize;
 
 use super::{
-    payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
+    checkout_sessions::types::StripeCheckoutSessionResponse,
+    payment_intents::types::StripePaymentIntentResponse,
+    payment_methods::types::StripePaymentMethodResponse, refunds::types::StripeRefundResponse,
 };
 use crate::{
     core::{errors, webhooks::types::OutgoingWebhookType},
     headers,
     services::request::Maskable,
+    utils,
 };
 
 #[derive(Serialize, Debug)]
@@ -22,17 +28,122 @@ pub struct StripeOutgoingWebhook {
     #[serde(rename = "type")]
     stype: &'static str,
     object: &'static str,
-    data: StripeWebhookObject,
+    data: StripeWebhookData,
     created: u64,
+    livemode: bool,
     // api_version: "2019-11-05", // not used
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripeWebhookData {
+    object: StripeWebhookObject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_attributes: Option<serde_json::Value>,
+}
+
+/// Computes the `v1` signature of a Stripe compatible webhook, which is the hex encoded
+/// HMAC-SHA256 of `"{timestamp}.{payload}"`.
+fn compute_stripe_webhook_signature(
+    secret: &[u8],
+    timestamp: i64,
+    payload: &str,
+) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+    let signed_payload = format!("{timestamp}.{payload}");
+    common_utils::crypto::HmacSha256::sign_message(
+        &common_utils::crypto::HmacSha256,
+        secret,
+        signed_payload.as_bytes(),
+    )
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+    .attach_printable("Failed to sign the message")
+    .map(hex::encode)
+}
+
+/// Verifies the `Stripe-Signature` header of a Stripe compatible webhook the way the Stripe
+/// libraries do: one of the `v1` signatures must match the payload, and the timestamp must be no
+/// older than `tolerance`.
+pub fn verify_stripe_signature(
+    header: &str,
+    payload: &str,
+    secret: &[u8],
+    tolerance: time::Duration,
+) -> errors::CustomResult<(), errors::WebhooksFlowError> {
+    verify_stripe_signature_at(
+        header,
+        payload,
+        secret,
+        tolerance,
+        date_time::now().assume_utc().unix_timestamp(),
+    )
+}
+
+fn verify_stripe_signature_at(
+    header: &str,
+    payload: &str,
+    secret: &[u8],
+    tolerance: time::Duration,
+    current_timestamp: i64,
+) -> errors::CustomResult<(), errors::WebhooksFlowError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for item in header.split(',') {
+        match item.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("v1", value)) => signatures.push(value),
+            Some(_) => {}
+            None => {
+                return Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed)
+                    .into_report()
+                    .attach_printable("Malformed stripe signature header")
+            }
+        }
+    }
+
+    let timestamp = timestamp
+        .ok_or(errors::WebhooksFlowError::WebhookSourceVerificationFailed)
+        .into_report()
+        .attach_printable("Missing or invalid timestamp in stripe signature header")?;
+    if signatures.is_empty() {
+        return Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed)
+            .into_report()
+            .attach_printable("No v1 signature in stripe signature header");
+    }
+
+    let signed_payload = format!("{timestamp}.{payload}");
+    let is_signature_valid = signatures.into_iter().any(|signature| {
+        hex::decode(signature)
+            .ok()
+            .and_then(|signature| {
+                common_utils::crypto::HmacSha256
+                    .verify_signature(secret, &signature, signed_payload.as_bytes())
+                    .ok()
+            })
+            .unwrap_or(false)
+    });
+    utils::when(!is_signature_valid, || {
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed)
+            .into_report()
+            .attach_printable("No stripe signature matches the payload")
+    })?;
+
+    utils::when(
+        current_timestamp.saturating_sub(timestamp) > tolerance.whole_seconds(),
+        || {
+            Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed)
+                .into_report()
+                .attach_printable("Stripe signature timestamp is outside the tolerance window")
+        },
+    )
+}
+
 impl OutgoingWebhookType for StripeOutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<String>,
     ) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
-        let timestamp = self.created;
+        // Stripe signs with the time of delivery rather than the event creation time, so that
+        // retried deliveries still fall within the verification tolerance window
+        let timestamp = date_time::now().assume_utc().unix_timestamp();
 
         let payment_response_hash_key = payment_response_hash_key
             .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
@@ -44,16 +155,11 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
                 .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
                 .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
-        let v1 = hex::encode(
-            common_utils::crypto::HmacSha256::sign_message(
-                &common_utils::crypto::HmacSha256,
-                payment_response_hash_key.as_bytes(),
-                new_signature_payload.as_bytes(),
-            )
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?,
-        );
+        let v1 = compute_stripe_webhook_signature(
+            payment_response_hash_key.as_bytes(),
+            timestamp,
+            &webhook_signature_payload,
+        )?;
 
         let t = timestamp;
         Ok(Some(format!("t={t},v1={v1}")))
@@ -68,17 +174,21 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
 }
 
 #[derive(Serialize, Debug)]
-#[serde(tag = "type", content = "object", rename_all = "snake_case")]
+#[serde(untagged)]
 pub enum StripeWebhookObject {
     PaymentIntent(StripePaymentIntentResponse),
+    CheckoutSession(StripeCheckoutSessionResponse),
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    Subscription(StripeSubscriptionResponse),
+    PaymentMethod(StripePaymentMethodResponse),
 }
 
 #[derive(Serialize, Debug)]
 pub struct StripeDisputeResponse {
     pub id: String,
+    pub object: &'static str,
     pub amount: String,
     pub currency: String,
     pub payment_intent: String,
@@ -88,12 +198,40 @@ pub struct StripeDisputeResponse {
 
 #[derive(Serialize, Debug)]
 pub struct StripeMandateResponse {
-    pub mandate_id: String,
+    pub id: String,
+    pub object: &'static str,
     pub status: StripeMandateStatus,
     pub payment_method_id: String,
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripeSubscriptionResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub customer: String,
+    pub plan: String,
+    pub mandate: String,
+    pub status: StripeSubscriptionStatus,
+    pub current_period_start: i64,
+    pub current_period_end: i64,
+    pub cancel_at_period_end: bool,
+    pub canceled_at: Option<i64>,
+    pub trial_end: Option<i64>,
+    pub latest_invoice: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeSubscriptionStatus {
+    Incomplete,
+    Trialing,
+    Active,
+    PastDue,
+    Unpaid,
+    Canceled,
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeMandateStatus {
@@ -119,6 +257,7 @@ impl From<api_models::disputes::DisputeResponse> for StripeDisputeResponse {
     fn from(res: api_models::disputes::DisputeResponse) -> Self {
         Self {
             id: res.dispute_id,
+            object: "dispute",
             amount: res.amount,
             currency: res.currency,
             payment_intent: res.payment_id,
@@ -131,7 +270,8 @@ impl From<api_models::disputes::DisputeResponse> for StripeDisputeResponse {
 impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     fn from(res: api_models::mandates::MandateResponse) -> Self {
         Self {
-            mandate_id: res.mandate_id,
+            id: res.mandate_id,
+            object: "mandate",
             payment_method: res.payment_method,
             payment_method_id: res.payment_method_id,
             status: StripeMandateStatus::from(res.status),
@@ -139,6 +279,42 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+impl From<api_models::subscriptions::SubscriptionResponse> for StripeSubscriptionResponse {
+    fn from(res: api_models::subscriptions::SubscriptionResponse) -> Self {
+        Self {
+            id: res.subscription_id,
+            object: "subscription",
+            customer: res.customer_id,
+            plan: res.plan_id,
+            mandate: res.mandate_id,
+            status: StripeSubscriptionStatus::from(res.status),
+            current_period_start: res.current_period_start.assume_utc().unix_timestamp(),
+            current_period_end: res.current_period_end.assume_utc().unix_timestamp(),
+            cancel_at_period_end: res.cancel_at_period_end,
+            canceled_at: res
+                .cancelled_at
+                .map(|cancelled_at| cancelled_at.assume_utc().unix_timestamp()),
+            trial_end: res
+                .trial_end
+                .map(|trial_end| trial_end.assume_utc().unix_timestamp()),
+            latest_invoice: res.latest_payment_id,
+        }
+    }
+}
+
+impl From<SubscriptionStatus> for StripeSubscriptionStatus {
+    fn from(status: SubscriptionStatus) -> Self {
+        match status {
+            SubscriptionStatus::Incomplete => Self::Incomplete,
+            SubscriptionStatus::Trialing => Self::Trialing,
+            SubscriptionStatus::Active => Self::Active,
+            SubscriptionStatus::PastDue => Self::PastDue,
+            SubscriptionStatus::Unpaid => Self::Unpaid,
+            SubscriptionStatus::Cancelled => Self::Canceled,
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -169,30 +345,110 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
         api_models::enums::EventType::PaymentProcessing => "payment_intent.processing",
         api_models::enums::EventType::PaymentCancelled => "payment_intent.canceled",
-
-        // the below are not really stripe compatible because stripe doesn't provide this
-        api_models::enums::EventType::ActionRequired => "action.required",
-        api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
+        api_models::enums::EventType::ActionRequired => "payment_intent.requires_action",
+        api_models::enums::EventType::RefundSucceeded => "refund.updated",
         api_models::enums::EventType::RefundFailed => "refund.failed",
-        api_models::enums::EventType::DisputeOpened => "dispute.failed",
-        api_models::enums::EventType::DisputeExpired => "dispute.expired",
-        api_models::enums::EventType::DisputeAccepted => "dispute.accepted",
-        api_models::enums::EventType::DisputeCancelled => "dispute.cancelled",
-        api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
-        api_models::enums::EventType::DisputeWon => "dispute.won",
-        api_models::enums::EventType::DisputeLost => "dispute.lost",
-        api_models::enums::EventType::MandateActive => "mandate.active",
-        api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::DisputeOpened => "charge.dispute.created",
+        api_models::enums::EventType::DisputeChallenged
+        | api_models::enums::EventType::DisputeEvidenceDue => "charge.dispute.updated",
+        api_models::enums::EventType::DisputeExpired
+        | api_models::enums::EventType::DisputeAccepted
+        | api_models::enums::EventType::DisputeCancelled
+        | api_models::enums::EventType::DisputeWon
+        | api_models::enums::EventType::DisputeLost
+        | api_models::enums::EventType::DisputeDeadlineMissed => "charge.dispute.closed",
+        api_models::enums::EventType::MandateActive
+        | api_models::enums::EventType::MandateRevoked => "mandate.updated",
+        api_models::enums::EventType::SubscriptionCycleSucceeded => "invoice.paid",
+        api_models::enums::EventType::SubscriptionCycleFailed => "invoice.payment_failed",
+        api_models::enums::EventType::SubscriptionPastDue
+        | api_models::enums::EventType::SubscriptionUnpaid => "customer.subscription.updated",
+        api_models::enums::EventType::SubscriptionCancelled => "customer.subscription.deleted",
+        api_models::enums::EventType::PaymentMethodUpdated => {
+            "payment_method.automatically_updated"
+        }
+        api_models::enums::EventType::PaymentMethodAccountClosed => "payment_method.detached",
+    }
+}
+
+/// Stripe reports the values the top level attributes of the object had before an update event,
+/// only the attributes whose value changed are included.
+fn get_stripe_previous_attributes(
+    object: &StripeWebhookObject,
+    previous_object: &StripeWebhookObject,
+) -> Option<serde_json::Value> {
+    let (object, previous_object) = match (
+        serde_json::to_value(object),
+        serde_json::to_value(previous_object),
+    ) {
+        (Ok(serde_json::Value::Object(object)), Ok(serde_json::Value::Object(previous_object))) => {
+            (object, previous_object)
+        }
+        _ => {
+            logger::error!("Failed to serialize the webhook object for previous attributes");
+            return None;
+        }
+    };
+
+    let previous_attributes = previous_object
+        .into_iter()
+        .filter(|(key, value)| object.get(key) != Some(value))
+        .collect::<serde_json::Map<_, _>>();
+
+    (!previous_attributes.is_empty()).then_some(serde_json::Value::Object(previous_attributes))
+}
+
+/// Payments created through a checkout session carry a payment link, their terminal events are
+/// delivered as checkout session events instead of payment intent events.
+fn get_stripe_checkout_session_event_type(
+    event_type: api_models::enums::EventType,
+) -> Option<&'static str> {
+    match event_type {
+        api_models::enums::EventType::PaymentSucceeded => Some("checkout.session.completed"),
+        api_models::enums::EventType::PaymentFailed => {
+            Some("checkout.session.async_payment_failed")
+        }
+        api_models::enums::EventType::PaymentCancelled => Some("checkout.session.expired"),
+        _ => None,
     }
 }
 
 impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
     fn from(value: api::OutgoingWebhook) -> Self {
+        let (stype, data) = match value.content {
+            api::OutgoingWebhookContent::PaymentDetails(mut payment) => match (
+                get_stripe_checkout_session_event_type(value.event_type),
+                payment.payment_link.take(),
+            ) {
+                (Some(stype), Some(payment_link)) => (
+                    stype,
+                    StripeWebhookObject::CheckoutSession((payment, payment_link).into()),
+                ),
+                (_, payment_link) => {
+                    payment.payment_link = payment_link;
+                    (
+                        get_stripe_event_type(value.event_type),
+                        StripeWebhookObject::PaymentIntent(payment.into()),
+                    )
+                }
+            },
+            content => (
+                get_stripe_event_type(value.event_type),
+                StripeWebhookObject::from(content),
+            ),
+        };
+        let previous_attributes = value.previous_content.and_then(|previous_content| {
+            get_stripe_previous_attributes(&data, &StripeWebhookObject::from(previous_content))
+        });
         Self {
             id: value.event_id,
-            stype: get_stripe_event_type(value.event_type),
-            data: StripeWebhookObject::from(value.content),
+            stype,
+            data: StripeWebhookData {
+                object: data,
+                previous_attributes,
+            },
             object: "event",
+            livemode: value.livemode,
             // put this conversion it into a function
             created: u64::try_from(value.timestamp.assume_utc().unix_timestamp()).unwrap_or_else(
                 |error| {
@@ -223,6 +479,175 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription((*subscription).into())
+            }
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_subscription(
+        status: SubscriptionStatus,
+    ) -> api_models::subscriptions::SubscriptionResponse {
+        api_models::subscriptions::SubscriptionResponse {
+            subscription_id: "sub_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            plan_id: "plan_123".to_string(),
+            mandate_id: "man_123".to_string(),
+            status,
+            current_period_start: time::macros::datetime!(2023-11-01 0:00),
+            current_period_end: time::macros::datetime!(2023-12-01 0:00),
+            trial_end: None,
+            cancel_at_period_end: false,
+            cancelled_at: None,
+            proration_amount: 0,
+            latest_payment_id: None,
+            metadata: None,
+            created_at: time::macros::datetime!(2023-11-01 0:00),
         }
     }
+
+    const WEBHOOK_SECRET: &[u8] = b"whsec_test_secret";
+    const WEBHOOK_PAYLOAD: &str = r#"{"id":"evt_123","object":"event"}"#;
+    // HMAC-SHA256 of "1700000000.{WEBHOOK_PAYLOAD}" keyed with WEBHOOK_SECRET, computed with the
+    // Python standard library `hmac` module as an independent reference
+    const WEBHOOK_SIGNATURE: &str =
+        "dd87ab365008cd15388d35ae8467b42bbc97bb9efe68060ed81e123be0e21c6e";
+
+    #[test]
+    fn test_stripe_webhook_signature() {
+        let signature =
+            compute_stripe_webhook_signature(WEBHOOK_SECRET, 1700000000, WEBHOOK_PAYLOAD).unwrap();
+
+        assert_eq!(signature, WEBHOOK_SIGNATURE);
+    }
+
+    #[test]
+    fn test_verify_stripe_signature() {
+        let tolerance = time::Duration::minutes(5);
+        let header = format!("t=1700000000,v1={WEBHOOK_SIGNATURE}");
+
+        assert!(verify_stripe_signature_at(
+            &header,
+            WEBHOOK_PAYLOAD,
+            WEBHOOK_SECRET,
+            tolerance,
+            1700000000 + 60
+        )
+        .is_ok());
+        // A signature rotated in alongside the current one is accepted as well
+        assert!(verify_stripe_signature_at(
+            &format!(
+                "t=1700000000,v1={},v1={WEBHOOK_SIGNATURE},v0=abc",
+                "0".repeat(64)
+            ),
+            WEBHOOK_PAYLOAD,
+            WEBHOOK_SECRET,
+            tolerance,
+            1700000000
+        )
+        .is_ok());
+        assert!(verify_stripe_signature_at(
+            &header,
+            r#"{"id":"evt_124","object":"event"}"#,
+            WEBHOOK_SECRET,
+            tolerance,
+            1700000000
+        )
+        .is_err());
+        assert!(verify_stripe_signature_at(
+            &header,
+            WEBHOOK_PAYLOAD,
+            b"whsec_other_secret",
+            tolerance,
+            1700000000
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_stripe_signature_rejects_expired_timestamp() {
+        let header = format!("t=1700000000,v1={WEBHOOK_SIGNATURE}");
+
+        assert!(verify_stripe_signature(
+            &header,
+            WEBHOOK_PAYLOAD,
+            WEBHOOK_SECRET,
+            time::Duration::minutes(5)
+        )
+        .is_err());
+        assert!(verify_stripe_signature_at(
+            &header,
+            WEBHOOK_PAYLOAD,
+            WEBHOOK_SECRET,
+            time::Duration::minutes(5),
+            1700000000 + 301
+        )
+        .is_err());
+
+        let timestamp = date_time::now().assume_utc().unix_timestamp();
+        let signature =
+            compute_stripe_webhook_signature(WEBHOOK_SECRET, timestamp, WEBHOOK_PAYLOAD).unwrap();
+        assert!(verify_stripe_signature(
+            &format!("t={timestamp},v1={signature}"),
+            WEBHOOK_PAYLOAD,
+            WEBHOOK_SECRET,
+            time::Duration::minutes(5)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_verify_stripe_signature_rejects_malformed_header() {
+        let verify = |header: &str| {
+            verify_stripe_signature_at(
+                header,
+                WEBHOOK_PAYLOAD,
+                WEBHOOK_SECRET,
+                time::Duration::minutes(5),
+                1700000000,
+            )
+        };
+
+        assert!(verify("").is_err());
+        assert!(verify(WEBHOOK_SIGNATURE).is_err());
+        assert!(verify(&format!("v1={WEBHOOK_SIGNATURE}")).is_err());
+        assert!(verify("t=1700000000").is_err());
+        assert!(verify(&format!("t=yesterday,v1={WEBHOOK_SIGNATURE}")).is_err());
+        assert!(verify("t=1700000000,v1=not-hex").is_err());
+    }
+
+    #[test]
+    fn test_stripe_previous_attributes_contain_only_changed_attributes() {
+        let object =
+            StripeWebhookObject::Subscription(get_subscription(SubscriptionStatus::PastDue).into());
+        let previous_object =
+            StripeWebhookObject::Subscription(get_subscription(SubscriptionStatus::Active).into());
+
+        assert_eq!(
+            get_stripe_previous_attributes(&object, &previous_object),
+            Some(serde_json::json!({ "status": "active" }))
+        );
+        assert_eq!(get_stripe_previous_attributes(&object, &object), None);
+    }
+
+    #[test]
+    fn test_stripe_mandate_has_id() {
+        let mandate = api_models::mandates::MandateResponse {
+            mandate_id: "man_123".to_string(),
+            ..Default::default()
+        };
+        let object = serde_json::to_value(StripeMandateResponse::from(mandate)).unwrap();
+
+        assert_eq!(object["id"], "man_123");
+        assert_eq!(object["object"], "mandate");
+    }
 }
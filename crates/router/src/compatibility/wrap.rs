@@ -108,6 +108,9 @@ where
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
             api::http_response_file_data(file_data, content_type)
         }
+        Ok(api::ApplicationResponse::FileStream((file_stream, content_type))) => {
+            api::http_response_file_stream(file_stream, content_type)
+        }
         Ok(api::ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => api::http_redirect_response(res, response),
@@ -133,8 +136,8 @@ where
             .map_into_boxed_body()
         }
 
-        Ok(api::ApplicationResponse::PaymenkLinkForm(payment_link_data)) => {
-            match api::build_payment_link_html(*payment_link_data) {
+        Ok(api::ApplicationResponse::PaymenkLinkForm(payment_link_action)) => {
+            match api::build_payment_link_action_html(*payment_link_action) {
                 Ok(rendered_html) => api::http_response_html_data(rendered_html),
                 Err(_) => api::http_response_err(
                     r#"{
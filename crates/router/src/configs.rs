@@ -1,5 +1,5 @@
 mod defaults;
-#[cfg(feature = "kms")]
+#[cfg(feature = "encryption_service")]
 pub mod kms;
 pub mod settings;
 mod validations;
@@ -1,8 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
 use api_models::{enums, payment_methods::RequiredFieldInfo};
-#[cfg(feature = "kms")]
-use external_services::kms::KmsValue;
+#[cfg(feature = "encryption_service")]
+use external_services::secrets_management::KmsValue;
 
 use super::settings::{ConnectorFields, Password, PaymentMethodType, RequiredFieldFinal};
 
@@ -84,12 +84,39 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::CardVelocity {
+    fn default() -> Self {
+        Self {
+            max_attempts: None,
+            window: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::ReusableLinkRateLimit {
+    fn default() -> Self {
+        Self {
+            max_payments: 100,
+            window_in_seconds: 60,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
     }
 }
 
+impl Default for super::settings::FileUploadConfig {
+    fn default() -> Self {
+        Self {
+            // 10 MiB
+            max_file_size: 10 * 1024 * 1024,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -4220,12 +4247,12 @@ impl Default for super::settings::RequiredFields {
 impl Default for super::settings::ApiKeys {
     fn default() -> Self {
         Self {
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "encryption_service")]
             kms_encrypted_hash_key: KmsValue::default(),
 
             /// Hex-encoded 32-byte long (64 characters long when hex-encoded) key used for calculating
             /// hashes of API keys
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "encryption_service"))]
             hash_key: String::new(),
 
             // Specifies the number of days before API key expiry when email reminders should be sent
@@ -1,5 +1,7 @@
 use common_utils::errors::CustomResult;
-use external_services::kms::{decrypt::KmsDecrypt, KmsClient, KmsError};
+use external_services::secrets_management::{
+    decrypt::KmsDecrypt, SecretManagementInterface, SecretsManagementError,
+};
 use masking::ExposeInterface;
 
 use crate::configs::settings;
@@ -10,25 +12,33 @@ impl KmsDecrypt for settings::Jwekey {
 
     async fn decrypt_inner(
         mut self,
-        kms_client: &KmsClient,
-    ) -> CustomResult<Self::Output, KmsError> {
-        (
-            self.locker_encryption_key1,
-            self.locker_encryption_key2,
-            self.locker_decryption_key1,
-            self.locker_decryption_key2,
-            self.vault_encryption_key,
-            self.vault_private_key,
-            self.tunnel_private_key,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self::Output, SecretsManagementError> {
+        let (
+            locker_encryption_key1,
+            locker_encryption_key2,
+            locker_decryption_key1,
+            locker_decryption_key2,
+            vault_encryption_key,
+            vault_private_key,
+            tunnel_private_key,
         ) = tokio::try_join!(
-            kms_client.decrypt(self.locker_encryption_key1),
-            kms_client.decrypt(self.locker_encryption_key2),
-            kms_client.decrypt(self.locker_decryption_key1),
-            kms_client.decrypt(self.locker_decryption_key2),
-            kms_client.decrypt(self.vault_encryption_key),
-            kms_client.decrypt(self.vault_private_key),
-            kms_client.decrypt(self.tunnel_private_key),
+            secret_management_client.get_secret(self.locker_encryption_key1.into()),
+            secret_management_client.get_secret(self.locker_encryption_key2.into()),
+            secret_management_client.get_secret(self.locker_decryption_key1.into()),
+            secret_management_client.get_secret(self.locker_decryption_key2.into()),
+            secret_management_client.get_secret(self.vault_encryption_key.into()),
+            secret_management_client.get_secret(self.vault_private_key.into()),
+            secret_management_client.get_secret(self.tunnel_private_key.into()),
         )?;
+
+        self.locker_encryption_key1 = locker_encryption_key1.expose();
+        self.locker_encryption_key2 = locker_encryption_key2.expose();
+        self.locker_decryption_key1 = locker_decryption_key1.expose();
+        self.locker_decryption_key2 = locker_decryption_key2.expose();
+        self.vault_encryption_key = vault_encryption_key.expose();
+        self.vault_private_key = vault_private_key.expose();
+        self.tunnel_private_key = tunnel_private_key.expose();
         Ok(self)
     }
 }
@@ -38,9 +48,14 @@ impl KmsDecrypt for settings::ActiveKmsSecrets {
     type Output = Self;
     async fn decrypt_inner(
         mut self,
-        kms_client: &KmsClient,
-    ) -> CustomResult<Self::Output, KmsError> {
-        self.jwekey = self.jwekey.expose().decrypt_inner(kms_client).await?.into();
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self::Output, SecretsManagementError> {
+        self.jwekey = self
+            .jwekey
+            .expose()
+            .decrypt_inner(secret_management_client)
+            .await?
+            .into();
         Ok(self)
     }
 }
@@ -51,14 +66,18 @@ impl KmsDecrypt for settings::Database {
 
     async fn decrypt_inner(
         mut self,
-        kms_client: &KmsClient,
-    ) -> CustomResult<Self::Output, KmsError> {
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self::Output, SecretsManagementError> {
         Ok(storage_impl::config::Database {
             host: self.host,
             port: self.port,
             dbname: self.dbname,
             username: self.username,
-            password: self.password.decrypt_inner(kms_client).await?.into(),
+            password: self
+                .password
+                .decrypt_inner(secret_management_client)
+                .await?
+                .into(),
             pool_size: self.pool_size,
             connection_timeout: self.connection_timeout,
             queue_strategy: self.queue_strategy.into(),
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     path::PathBuf,
     str::FromStr,
@@ -9,8 +10,15 @@ use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
 #[cfg(feature = "email")]
 use external_services::email::EmailSettings;
-#[cfg(feature = "kms")]
-use external_services::kms;
+use external_services::{
+    account_updater::AccountUpdaterConfig, file_storage::FileStorageConfig,
+    network_tokenization::NetworkTokenizationConfig,
+};
+#[cfg(feature = "encryption_service")]
+use external_services::{
+    encryption_management::{EncryptionManagementConfig, LegacyKmsConfig},
+    secrets_management,
+};
 use redis_interface::RedisSettings;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use scheduler::SchedulerSettings;
@@ -22,9 +30,9 @@ use crate::{
     core::errors::{ApplicationError, ApplicationResult},
     env::{self, logger, Env},
 };
-#[cfg(feature = "kms")]
-pub type Password = kms::KmsValue;
-#[cfg(not(feature = "kms"))]
+#[cfg(feature = "encryption_service")]
+pub type Password = secrets_management::KmsValue;
+#[cfg(not(feature = "encryption_service"))]
 pub type Password = masking::Secret<String>;
 
 #[derive(clap::Parser, Default)]
@@ -46,7 +54,7 @@ pub enum Subcommand {
     GenerateOpenapiSpec,
 }
 
-#[cfg(feature = "kms")]
+#[cfg(feature = "encryption_service")]
 /// Store the decrypted kms secret values for active use in the application
 /// Currently using `StrongSecret` won't have any effect as this struct have smart pointers to heap
 /// allocations.
@@ -71,6 +79,7 @@ pub struct Settings {
     pub locker: Locker,
     pub connectors: Connectors,
     pub refund: Refund,
+    pub card_velocity: CardVelocity,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -80,9 +89,14 @@ pub struct Settings {
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
     pub api_keys: ApiKeys,
-    #[cfg(feature = "kms")]
-    pub kms: kms::KmsConfig,
-    #[cfg(feature = "s3")]
+    #[cfg(feature = "encryption_service")]
+    pub encryption_management: EncryptionManagementConfig,
+    /// Deprecated, superseded by `encryption_management`
+    #[cfg(feature = "encryption_service")]
+    pub kms: Option<LegacyKmsConfig>,
+    pub file_storage: FileStorageConfig,
+    pub network_tokenization: NetworkTokenization,
+    pub card_account_updater: CardAccountUpdater,
     pub file_upload_config: FileUploadConfig,
     pub tokenization: TokenizationConfig,
     pub connector_customer: ConnectorCustomer,
@@ -117,6 +131,17 @@ pub struct KvConfig {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct PaymentLink {
     pub sdk_url: String,
+    #[serde(default)]
+    pub reusable_link_rate_limit: ReusableLinkRateLimit,
+}
+
+/// Limits the number of payments that a reusable payment link can create, as every visit to the
+/// link creates a new payment
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ReusableLinkRateLimit {
+    pub max_payments: i64,
+    pub window_in_seconds: i64,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -142,6 +167,39 @@ pub struct TokenizationConfig(pub HashMap<String, PaymentMethodTokenFilter>);
 #[serde(transparent)]
 pub struct TempLockerEnableConfig(pub HashMap<String, TempLockerEnablePaymentMethodFilter>);
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NetworkTokenization {
+    #[serde(flatten)]
+    pub provider: NetworkTokenizationConfig,
+    /// Connectors accepting a network token and cryptogram in place of the card number
+    #[serde(deserialize_with = "connector_deser")]
+    pub supported_connectors: HashSet<api_models::enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardAccountUpdater {
+    #[serde(flatten)]
+    pub provider: AccountUpdaterConfig,
+    /// Saved cards expiring within this many days are submitted to the account updater
+    pub expiry_window_days: u32,
+    /// Interval between two runs of the account updater for a merchant
+    pub run_interval_hours: u32,
+    /// Interval at which the results of a submitted batch are polled for
+    pub result_poll_interval_minutes: u32,
+}
+
+impl Default for CardAccountUpdater {
+    fn default() -> Self {
+        Self {
+            provider: AccountUpdaterConfig::default(),
+            expiry_window_days: 30,
+            run_interval_hours: 24,
+            result_poll_interval_minutes: 60,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorCustomer {
     #[serde(deserialize_with = "connector_deser")]
@@ -301,10 +359,99 @@ pub struct BanksVector {
 #[serde(transparent)]
 pub struct ConnectorFilters(pub HashMap<String, PaymentMethodFilters>);
 
+impl ConnectorFilters {
+    /// Provides the filters for a merchant connector account. The filters configured for the
+    /// account take precedence over the ones configured for the connector, which are used as the
+    /// defaults.
+    pub fn get_merchant_connector_filters(
+        &self,
+        connector: &str,
+        merchant_filters: &[api_models::admin::PaymentMethodFilter],
+    ) -> Option<Cow<'_, PaymentMethodFilters>> {
+        let default_filters = self.0.get(connector).or_else(|| self.0.get("default"));
+        if merchant_filters.is_empty() {
+            return default_filters.map(Cow::Borrowed);
+        }
+
+        let mut filters = default_filters.cloned().unwrap_or_default();
+        for merchant_filter in merchant_filters {
+            let key = match (
+                merchant_filter.payment_method_type,
+                &merchant_filter.card_network,
+            ) {
+                (Some(payment_method_type), _) => {
+                    PaymentMethodFilterKey::PaymentMethodType(payment_method_type)
+                }
+                (None, Some(card_network)) => {
+                    PaymentMethodFilterKey::CardNetwork(card_network.clone())
+                }
+                (None, None) => continue,
+            };
+
+            // Flow restrictions depend on the connector integration and are not merchant editable
+            let not_available_flows = filters
+                .0
+                .get(&key)
+                .and_then(|filter| filter.not_available_flows);
+
+            filters.0.insert(
+                key,
+                CurrencyCountryFlowFilter {
+                    currency: merchant_filter
+                        .currencies
+                        .as_ref()
+                        .map(|currencies| currencies.iter().copied().collect()),
+                    country: merchant_filter
+                        .countries
+                        .as_ref()
+                        .map(|countries| countries.iter().copied().collect()),
+                    not_available_flows,
+                },
+            );
+        }
+
+        Some(Cow::Owned(filters))
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(transparent)]
 pub struct PaymentMethodFilters(pub HashMap<PaymentMethodFilterKey, CurrencyCountryFlowFilter>);
 
+impl PaymentMethodFilters {
+    /// Provides the filters in the form configured on merchant connector accounts, which is the
+    /// form the knowledge graph of the merchant connector accounts is compiled from. Flow
+    /// restrictions are left out as the knowledge graph does not track them.
+    pub fn get_pm_filters(&self) -> Vec<api_models::admin::PaymentMethodFilter> {
+        self.0
+            .iter()
+            .map(|(key, filter)| {
+                let (payment_method_type, card_network) = match key {
+                    PaymentMethodFilterKey::PaymentMethodType(payment_method_type) => {
+                        (Some(*payment_method_type), None)
+                    }
+                    PaymentMethodFilterKey::CardNetwork(card_network) => {
+                        (None, Some(card_network.clone()))
+                    }
+                };
+
+                api_models::admin::PaymentMethodFilter {
+                    payment_method_type,
+                    card_network,
+                    countries: filter
+                        .country
+                        .as_ref()
+                        .map(|countries| countries.iter().copied().collect()),
+                    currencies: filter
+                        .currency
+                        .as_ref()
+                        .map(|currencies| currencies.iter().copied().collect()),
+                }
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[serde(untagged)]
 pub enum PaymentMethodFilterKey {
@@ -401,19 +548,23 @@ where
 #[derive(Debug, Default, Deserialize, Clone)]
 #[serde(default)]
 pub struct Secrets {
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     pub jwt_secret: String,
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     pub admin_api_key: String,
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     pub recon_admin_api_key: String,
     pub master_enc_key: Password,
-    #[cfg(feature = "kms")]
-    pub kms_encrypted_jwt_secret: kms::KmsValue,
-    #[cfg(feature = "kms")]
-    pub kms_encrypted_admin_api_key: kms::KmsValue,
-    #[cfg(feature = "kms")]
-    pub kms_encrypted_recon_admin_api_key: kms::KmsValue,
+    #[cfg(not(feature = "encryption_service"))]
+    pub card_fingerprint_key: String,
+    #[cfg(feature = "encryption_service")]
+    pub kms_encrypted_jwt_secret: secrets_management::KmsValue,
+    #[cfg(feature = "encryption_service")]
+    pub kms_encrypted_admin_api_key: secrets_management::KmsValue,
+    #[cfg(feature = "encryption_service")]
+    pub kms_encrypted_recon_admin_api_key: secrets_management::KmsValue,
+    #[cfg(feature = "encryption_service")]
+    pub kms_encrypted_card_fingerprint_key: secrets_management::KmsValue,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -432,6 +583,15 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardVelocity {
+    /// Number of payment attempts a card can be used in within the window, unlimited if not set
+    pub max_attempts: Option<i64>,
+    /// Length of the window in seconds
+    pub window: i64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -501,7 +661,7 @@ impl From<QueueStrategy> for bb8::QueueStrategy {
     }
 }
 
-#[cfg(not(feature = "kms"))]
+#[cfg(not(feature = "encryption_service"))]
 impl From<Database> for storage_impl::config::Database {
     fn from(val: Database) -> Self {
         Self {
@@ -651,12 +811,12 @@ pub struct WebhookIgnoreErrorSettings {
 pub struct ApiKeys {
     /// Base64-encoded (KMS encrypted) ciphertext of the key used for calculating hashes of API
     /// keys
-    #[cfg(feature = "kms")]
-    pub kms_encrypted_hash_key: kms::KmsValue,
+    #[cfg(feature = "encryption_service")]
+    pub kms_encrypted_hash_key: secrets_management::KmsValue,
 
     /// Hex-encoded 32-byte long (64 characters long when hex-encoded) key used for calculating
     /// hashes of API keys
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     pub hash_key: String,
 
     // Specifies the number of days before API key expiry when email reminders should be sent
@@ -664,14 +824,11 @@ pub struct ApiKeys {
     pub expiry_reminder_days: Vec<u8>,
 }
 
-#[cfg(feature = "s3")]
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct FileUploadConfig {
-    /// The AWS region to send file uploads
-    pub region: String,
-    /// The AWS s3 bucket to send file uploads
-    pub bucket_name: String,
+    /// The maximum size of an uploaded file, in bytes
+    pub max_file_size: usize,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -751,11 +908,27 @@ impl Settings {
             )
             .build()?;
 
-        serde_path_to_error::deserialize(config).map_err(|error| {
+        #[allow(unused_mut)]
+        let mut settings: Self = serde_path_to_error::deserialize(config).map_err(|error| {
             logger::error!(%error, "Unable to deserialize application configuration");
             eprintln!("Unable to deserialize application configuration: {error}");
             ApplicationError::from(error.into_inner())
-        })
+        })?;
+
+        #[cfg(feature = "encryption_service")]
+        {
+            if settings.kms.is_some() {
+                logger::warn!(
+                    "The `[kms]` configuration section is deprecated, use `[encryption_management]` instead"
+                );
+            }
+            settings.encryption_management = settings
+                .encryption_management
+                .with_legacy_kms_config(settings.kms.take())
+                .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
+        }
+
+        Ok(settings)
     }
 
     pub fn validate(&self) -> ApplicationResult<()> {
@@ -791,12 +964,23 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.validate()?;
-        #[cfg(feature = "kms")]
-        self.kms
+        #[cfg(feature = "encryption_service")]
+        self.encryption_management
             .validate()
             .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
-        #[cfg(feature = "s3")]
+        self.file_storage
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
+        self.network_tokenization
+            .provider
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
+        self.card_account_updater.validate()?;
         self.file_upload_config.validate()?;
+        #[cfg(feature = "email")]
+        self.email
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
         self.lock_settings.validate()?;
         Ok(())
     }
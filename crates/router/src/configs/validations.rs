@@ -5,7 +5,7 @@ impl super::settings::Secrets {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "encryption_service"))]
         {
             when(self.jwt_secret.is_default_or_empty(), || {
                 Err(ApplicationError::InvalidConfigurationValueError(
@@ -18,9 +18,15 @@ impl super::settings::Secrets {
                     "admin API key must not be empty".into(),
                 ))
             })?;
+
+            when(self.card_fingerprint_key.is_default_or_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "card fingerprint key must not be empty".into(),
+                ))
+            })?;
         }
 
-        #[cfg(feature = "kms")]
+        #[cfg(feature = "encryption_service")]
         {
             when(self.kms_encrypted_jwt_secret.is_default_or_empty(), || {
                 Err(ApplicationError::InvalidConfigurationValueError(
@@ -36,6 +42,15 @@ impl super::settings::Secrets {
                     ))
                 },
             )?;
+
+            when(
+                self.kms_encrypted_card_fingerprint_key.is_default_or_empty(),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "KMS encrypted card fingerprint key must not be empty".into(),
+                    ))
+                },
+            )?;
         }
         when(self.master_enc_key.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
@@ -127,20 +142,13 @@ impl super::settings::DrainerSettings {
     }
 }
 
-#[cfg(feature = "s3")]
 impl super::settings::FileUploadConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        when(self.region.is_default_or_empty(), || {
-            Err(ApplicationError::InvalidConfigurationValueError(
-                "s3 region must not be empty".into(),
-            ))
-        })?;
-
-        when(self.bucket_name.is_default_or_empty(), || {
+        when(self.max_file_size == 0, || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "s3 bucket name must not be empty".into(),
+                "maximum file size must be greater than zero".into(),
             ))
         })
     }
@@ -150,14 +158,14 @@ impl super::settings::ApiKeys {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        #[cfg(feature = "kms")]
+        #[cfg(feature = "encryption_service")]
         return when(self.kms_encrypted_hash_key.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "API key hashing key must not be empty when KMS feature is enabled".into(),
             ))
         });
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "encryption_service"))]
         when(self.hash_key.is_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "API key hashing key must not be empty".into(),
@@ -193,3 +201,29 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+impl super::settings::CardAccountUpdater {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.provider
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
+
+        when(self.run_interval_hours.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater run_interval_hours must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(
+            self.result_poll_interval_minutes.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "card account updater result_poll_interval_minutes must not be empty or 0"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
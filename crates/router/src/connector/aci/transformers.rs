@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 use super::result_codes::{FAILURE_CODES, PENDING_CODES, SUCCESSFUL_CODES};
 use crate::{
-    connector::utils::{self, RouterData},
+    connector::utils::{self, CardData, RouterData},
     core::errors,
     services,
     types::{self, api, storage::enums},
@@ -253,11 +253,11 @@ impl TryFrom<api_models::payments::Card> for PaymentDetails {
     type Error = Error;
     fn try_from(card_data: api_models::payments::Card) -> Result<Self, Self::Error> {
         Ok(Self::AciCard(Box::new(CardDetails {
+            card_cvv: card_data.get_card_cvc(),
             card_number: card_data.card_number,
             card_holder: card_data.card_holder_name,
-            card_expiry_month: card_data.card_exp_month,
-            card_expiry_year: card_data.card_exp_year,
-            card_cvv: card_data.card_cvc,
+            card_expiry_month: card_data.card_exp_month.into(),
+            card_expiry_year: card_data.card_exp_year.into(),
         })))
     }
 }
@@ -1593,8 +1593,8 @@ fn get_payout_card_details(payout_method_data: &PayoutMethodData) -> Option<Payo
         PayoutMethodData::Card(card) => Some(PayoutCardDetails {
             _type: "scheme".to_string(), // FIXME: Remove hardcoding
             number: card.card_number.peek().to_string(),
-            expiry_month: card.expiry_month.peek().to_string(),
-            expiry_year: card.expiry_year.peek().to_string(),
+            expiry_month: card.expiry_month.two_digits(),
+            expiry_year: card.expiry_year.four_digits(),
             holder_name: card.card_holder_name.peek().to_string(),
         }),
         _ => None,
@@ -1761,9 +1761,9 @@ impl<'a> TryFrom<&api::Card> for AdyenPaymentMethod<'a> {
         let adyen_card = AdyenCard {
             payment_type: PaymentType::Scheme,
             number: card.card_number.clone(),
-            expiry_month: card.card_exp_month.clone(),
+            expiry_month: card.card_exp_month.clone().into(),
             expiry_year: card.get_expiry_year_4_digit(),
-            cvc: Some(card.card_cvc.clone()),
+            cvc: Some(card.get_card_cvc()),
             brand: None,
             network_payment_reference: None,
         };
@@ -2251,8 +2251,8 @@ impl<'a>
                         let adyen_card = AdyenCard {
                             payment_type: PaymentType::Scheme,
                             number: card.card_number.clone(),
-                            expiry_month: card.card_exp_month.clone(),
-                            expiry_year: card.card_exp_year.clone(),
+                            expiry_month: card.card_exp_month.clone().into(),
+                            expiry_year: card.card_exp_year.clone().into(),
                             cvc: None,
                             brand: Some(brand),
                             network_payment_reference: Some(network_mandate_id),
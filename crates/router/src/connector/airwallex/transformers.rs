@@ -178,9 +178,9 @@ impl TryFrom<&AirwallexRouterData<&types::PaymentsAuthorizeRouterData>>
                 Ok(AirwallexPaymentMethod::Card(AirwallexCard {
                     card: AirwallexCardDetails {
                         number: ccard.card_number.clone(),
-                        expiry_month: ccard.card_exp_month.clone(),
+                        expiry_month: ccard.card_exp_month.clone().into(),
                         expiry_year: ccard.get_expiry_year_4_digit(),
-                        cvc: ccard.card_cvc,
+                        cvc: ccard.get_card_cvc(),
                     },
                     payment_method_type: AirwallexPaymentType::Card,
                 }))
@@ -179,7 +179,7 @@ fn get_pm_and_subsequent_auth_detail(
                         card_number: (*ccard.card_number).clone(),
                         // expiration_date: format!("{expiry_year}-{expiry_month}").into(),
                         expiration_date: ccard.get_expiry_date_as_yyyymm("-"),
-                        card_code: Some(ccard.card_cvc.clone()),
+                        card_code: Some(ccard.get_card_cvc()),
                     }),
                     Some(ProcessingOptions {
                         is_subsequent_auth: true,
@@ -5,7 +5,7 @@ use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::{
-    connector::utils::{BrowserInformationData, PaymentsAuthorizeRequestData},
+    connector::utils::{self, BrowserInformationData, PaymentsAuthorizeRequestData},
     consts,
     core::errors,
     services,
@@ -117,11 +117,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for BamboraPaymentsRequest {
                     enums::AuthenticationType::NoThreeDs => None,
                 };
                 let bambora_card = BamboraCard {
+                    cvd: utils::CardData::get_card_cvc(&req_card),
                     name: req_card.card_holder_name,
                     number: req_card.card_number,
-                    expiry_month: req_card.card_exp_month,
-                    expiry_year: req_card.card_exp_year,
-                    cvd: req_card.card_cvc,
+                    expiry_month: req_card.card_exp_month.into(),
+                    expiry_year: req_card.card_exp_year.into(),
                     three_d_secure: three_ds,
                     complete: item.request.is_auto_capture()?,
                 };
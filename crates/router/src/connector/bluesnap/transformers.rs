@@ -245,9 +245,9 @@ impl TryFrom<&BluesnapRouterData<&types::PaymentsAuthorizeRouterData>> for Blues
                 api::PaymentMethodData::Card(ref ccard) => Ok((
                     PaymentMethodDetails::CreditCard(Card {
                         card_number: ccard.card_number.clone(),
-                        expiration_month: ccard.card_exp_month.clone(),
+                        expiration_month: ccard.card_exp_month.clone().into(),
                         expiration_year: ccard.get_expiry_year_4_digit(),
-                        security_code: ccard.card_cvc.clone(),
+                        security_code: ccard.get_card_cvc(),
                     }),
                     get_card_holder_info(
                         item.router_data.get_billing_address()?,
@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{
-    connector::utils::{self, PaymentsAuthorizeRequestData, RefundsRequestData, RouterData},
+    connector::utils::{
+        self, CardData, PaymentsAuthorizeRequestData, RefundsRequestData, RouterData,
+    },
     consts,
     core::errors,
     services,
@@ -855,10 +857,10 @@ impl TryFrom<&types::TokenizationRouterData> for BraintreeTokenRequest {
                 let query = TOKENIZE_CREDIT_CARD.to_string();
                 let input = InputData {
                     credit_card: CreditCardData {
+                        cvv: card_data.get_card_cvc(),
                         number: card_data.card_number,
-                        expiration_year: card_data.card_exp_year,
-                        expiration_month: card_data.card_exp_month,
-                        cvv: card_data.card_cvc,
+                        expiration_year: card_data.card_exp_year.into(),
+                        expiration_month: card_data.card_exp_month.into(),
                         cardholder_name: card_data.card_holder_name,
                     },
                 };
@@ -1321,7 +1323,8 @@ impl
                 input: PaymentInput {
                     payment_method_id: match item.router_data.get_payment_method_token()? {
                         types::PaymentMethodToken::Token(token) => token,
-                        types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                        types::PaymentMethodToken::ApplePayDecrypt(_)
+                        | types::PaymentMethodToken::NetworkToken(_) => {
                             Err(errors::ConnectorError::InvalidWalletToken)?
                         }
                     },
@@ -1403,7 +1406,8 @@ fn get_braintree_redirect_form(
             .expose(),
         card_token: match payment_method_token {
             types::PaymentMethodToken::Token(token) => token,
-            types::PaymentMethodToken::ApplePayDecrypt(_) => {
+            types::PaymentMethodToken::ApplePayDecrypt(_)
+            | types::PaymentMethodToken::NetworkToken(_) => {
                 Err(errors::ConnectorError::InvalidWalletToken)?
             }
         },
@@ -4,7 +4,7 @@ use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::{self},
+    connector::utils::{self, CardData},
     consts,
     core::errors,
     types::{self, api, storage::enums},
@@ -117,10 +117,10 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for BraintreePaymentsRequest {
         let payment_method_data_type = match item.request.payment_method_data.clone() {
             api::PaymentMethodData::Card(ccard) => Ok(PaymentMethodType::CreditCard(Card {
                 credit_card: CardDetails {
+                    cvv: ccard.get_card_cvc(),
                     number: ccard.card_number,
-                    expiration_month: ccard.card_exp_month,
-                    expiration_year: ccard.card_exp_year,
-                    cvv: ccard.card_cvc,
+                    expiration_month: ccard.card_exp_month.into(),
+                    expiration_year: ccard.card_exp_year.into(),
                 },
             })),
             api::PaymentMethodData::Wallet(ref wallet_data) => {
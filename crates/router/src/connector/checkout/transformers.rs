@@ -6,7 +6,7 @@ use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
-    connector::utils::{self, PaymentsCaptureRequestData, RouterData, WalletData},
+    connector::utils::{self, CardData, PaymentsCaptureRequestData, RouterData, WalletData},
     consts,
     core::errors,
     services,
@@ -193,6 +193,19 @@ pub enum PaymentSource {
     Card(CardSource),
     Wallets(WalletSource),
     ApplePayPredecrypt(Box<ApplePayPredecrypt>),
+    NetworkToken(Box<NetworkTokenSource>),
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    #[serde(rename = "type")]
+    source_type: String,
+    token: Secret<String>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    token_type: String,
+    cryptogram: Secret<String>,
+    eci: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -272,14 +285,35 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
     ) -> Result<Self, Self::Error> {
         let source_var = match item.router_data.request.payment_method_data.clone() {
             api::PaymentMethodData::Card(ccard) => {
-                let a = PaymentSource::Card(CardSource {
-                    source_type: CheckoutSourceTypes::Card,
-                    number: ccard.card_number.clone(),
-                    expiry_month: ccard.card_exp_month.clone(),
-                    expiry_year: ccard.card_exp_year.clone(),
-                    cvv: ccard.card_cvc,
-                });
-                Ok(a)
+                let network_token_type = match ccard.get_card_issuer() {
+                    Ok(utils::CardIssuer::Visa) => Some("vts"),
+                    Ok(utils::CardIssuer::Master) => Some("mdes"),
+                    _ => None,
+                };
+                match (
+                    item.router_data.payment_method_token.clone(),
+                    network_token_type,
+                ) {
+                    (
+                        Some(types::PaymentMethodToken::NetworkToken(network_token)),
+                        Some(token_type),
+                    ) => Ok(PaymentSource::NetworkToken(Box::new(NetworkTokenSource {
+                        source_type: "network_token".to_string(),
+                        token: network_token.token_number,
+                        expiry_month: network_token.token_exp_month,
+                        expiry_year: network_token.token_exp_year,
+                        token_type: token_type.to_string(),
+                        cryptogram: network_token.cryptogram,
+                        eci: network_token.eci,
+                    }))),
+                    _ => Ok(PaymentSource::Card(CardSource {
+                        source_type: CheckoutSourceTypes::Card,
+                        number: ccard.card_number.clone(),
+                        expiry_month: ccard.card_exp_month.clone().into(),
+                        expiry_year: ccard.card_exp_year.clone().into(),
+                        cvv: ccard.get_card_cvc(),
+                    })),
+                }
             }
             api::PaymentMethodData::Wallet(wallet_data) => match wallet_data {
                 api_models::payments::WalletData::GooglePay(_) => {
@@ -287,7 +321,8 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
                         source_type: CheckoutSourceTypes::Token,
                         token: match item.router_data.get_payment_method_token()? {
                             types::PaymentMethodToken::Token(token) => token,
-                            types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                            types::PaymentMethodToken::ApplePayDecrypt(_)
+                            | types::PaymentMethodToken::NetworkToken(_) => {
                                 Err(errors::ConnectorError::InvalidWalletToken)?
                             }
                         },
@@ -333,6 +368,9 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
                                 },
                             )))
                         }
+                        types::PaymentMethodToken::NetworkToken(_) => {
+                            Err(errors::ConnectorError::InvalidWalletToken)?
+                        }
                     }
                 }
                 api_models::payments::WalletData::AliPayQr(_)
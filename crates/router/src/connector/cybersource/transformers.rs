@@ -4,7 +4,7 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::{self, AddressDetailsData, PhoneDetailsData, RouterData},
+    connector::utils::{self, AddressDetailsData, CardData, PhoneDetailsData, RouterData},
     consts,
     core::errors,
     pii::PeekInterface,
@@ -173,10 +173,10 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
 
                 let payment_information = PaymentInformation {
                     card: Card {
+                        security_code: ccard.get_card_cvc(),
                         number: ccard.card_number,
-                        expiration_month: ccard.card_exp_month,
-                        expiration_year: ccard.card_exp_year,
-                        security_code: ccard.card_cvc,
+                        expiration_month: ccard.card_exp_month.into(),
+                        expiration_year: ccard.card_exp_year.into(),
                     },
                 };
 
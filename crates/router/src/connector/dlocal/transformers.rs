@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
-    connector::utils::{AddressDetailsData, PaymentsAuthorizeRequestData, RouterData},
+    connector::utils::{AddressDetailsData, CardData, PaymentsAuthorizeRequestData, RouterData},
     core::errors,
     services,
     types::{self, api, storage::enums},
@@ -127,9 +127,9 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                     card: Some(Card {
                         holder_name: ccard.card_holder_name.clone(),
                         number: ccard.card_number.clone(),
-                        cvv: ccard.card_cvc.clone(),
-                        expiration_month: ccard.card_exp_month.clone(),
-                        expiration_year: ccard.card_exp_year.clone(),
+                        cvv: ccard.get_card_cvc(),
+                        expiration_month: ccard.card_exp_month.clone().into(),
+                        expiration_year: ccard.card_exp_year.clone().into(),
                         capture: should_capture.to_string(),
                         installments_id: item
                             .router_data
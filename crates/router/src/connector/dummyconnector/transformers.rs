@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
+    connector::utils::CardData,
     core::errors,
     services,
     types::{self, api, storage::enums},
@@ -86,11 +87,11 @@ pub struct DummyConnectorCard {
 impl From<api_models::payments::Card> for DummyConnectorCard {
     fn from(value: api_models::payments::Card) -> Self {
         Self {
+            cvc: value.get_card_cvc(),
             name: value.card_holder_name,
             number: value.card_number,
-            expiry_month: value.card_exp_month,
-            expiry_year: value.card_exp_year,
-            cvc: value.card_cvc,
+            expiry_month: value.card_exp_month.into(),
+            expiry_year: value.card_exp_year.into(),
         }
     }
 }
@@ -171,9 +171,9 @@ impl TryFrom<&FiservRouterData<&types::PaymentsAuthorizeRouterData>> for FiservP
             api::PaymentMethodData::Card(ref ccard) => {
                 let card = CardData {
                     card_data: ccard.card_number.clone(),
-                    expiration_month: ccard.card_exp_month.clone(),
-                    expiration_year: ccard.card_exp_year.clone(),
-                    security_code: ccard.card_cvc.clone(),
+                    expiration_month: ccard.card_exp_month.clone().into(),
+                    expiration_year: ccard.card_exp_year.clone().into(),
+                    security_code: utils::CardData::get_card_cvc(ccard),
                 };
                 Source::PaymentCard { card }
             }
@@ -84,9 +84,9 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for FortePaymentsRequest {
                     card_type,
                     name_on_card: ccard.card_holder_name.clone(),
                     account_number: ccard.card_number.clone(),
-                    expire_month: ccard.card_exp_month.clone(),
-                    expire_year: ccard.card_exp_year.clone(),
-                    card_verification_value: ccard.card_cvc.clone(),
+                    expire_month: ccard.card_exp_month.clone().into(),
+                    expire_year: ccard.card_exp_year.clone().into(),
+                    card_verification_value: ccard.get_card_cvc(),
                 };
                 let billing_address = BillingAddress {
                     first_name: address.get_first_name()?.to_owned(),
@@ -383,9 +383,9 @@ fn get_payment_method_data(
     match &item.request.payment_method_data {
         api::PaymentMethodData::Card(ccard) => Ok(PaymentMethodData::Card(requests::Card {
             number: ccard.card_number.clone(),
-            expiry_month: ccard.card_exp_month.clone(),
+            expiry_month: ccard.card_exp_month.clone().into(),
             expiry_year: ccard.get_card_expiry_year_2_digit(),
-            cvv: ccard.card_cvc.clone(),
+            cvv: ccard.get_card_cvc(),
             account_type: None,
             authcode: None,
             avs_address: None,
@@ -492,7 +492,8 @@ impl TryFrom<&types::SetupMandateRouterData> for GocardlessMandateRequest {
         let payment_method_token = item.get_payment_method_token()?;
         let customer_bank_account = match payment_method_token {
             types::PaymentMethodToken::Token(token) => Ok(token),
-            types::PaymentMethodToken::ApplePayDecrypt(_) => {
+            types::PaymentMethodToken::ApplePayDecrypt(_)
+            | types::PaymentMethodToken::NetworkToken(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     "Setup Mandate flow for selected payment method through Gocardless".to_string(),
                 ))
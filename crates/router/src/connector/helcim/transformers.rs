@@ -98,7 +98,7 @@ impl TryFrom<(&types::SetupMandateRouterData, &api::Card)> for HelcimVerifyReque
         let card_data = HelcimCard {
             card_expiry: req_card.get_card_expiry_month_year_2_digit_with_delimiter("".to_string()),
             card_number: req_card.card_number.clone(),
-            card_c_v_v: req_card.card_cvc.clone(),
+            card_c_v_v: req_card.get_card_cvc(),
         };
         let req_address = item.get_billing_address()?.to_owned();
 
@@ -168,7 +168,7 @@ impl
         let card_data = HelcimCard {
             card_expiry: req_card.get_card_expiry_month_year_2_digit_with_delimiter("".to_string()),
             card_number: req_card.card_number.clone(),
-            card_c_v_v: req_card.card_cvc.clone(),
+            card_c_v_v: req_card.get_card_cvc(),
         };
         let req_address = item
             .router_data
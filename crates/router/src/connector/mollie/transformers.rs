@@ -177,7 +177,8 @@ impl TryFrom<&MollieRouterData<&types::PaymentsAuthorizeRouterData>> for MollieP
                                 shipping_address: get_shipping_details(item.router_data)?,
                                 card_token: Some(Secret::new(match pm_token {
                                     types::PaymentMethodToken::Token(token) => token,
-                                    types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                                    types::PaymentMethodToken::ApplePayDecrypt(_)
+                                    | types::PaymentMethodToken::NetworkToken(_) => {
                                         Err(errors::ConnectorError::InvalidWalletToken)?
                                     }
                                 })),
@@ -290,7 +291,7 @@ impl TryFrom<&types::TokenizationRouterData> for MollieCardTokenRequest {
                 let card_number = ccard.card_number.clone();
                 let card_expiry_date =
                     ccard.get_card_expiry_month_year_2_digit_with_delimiter("/".to_owned());
-                let card_cvv = ccard.card_cvc;
+                let card_cvv = ccard.get_card_cvc();
                 let locale = item.request.get_browser_info()?.get_language()?;
                 let testmode =
                     item.test_mode
@@ -427,12 +427,12 @@ impl TryFrom<&MultisafepayRouterData<&types::PaymentsAuthorizeRouterData>>
                     (format!(
                         "{}{}",
                         ccard.get_card_expiry_year_2_digit().expose(),
-                        ccard.card_exp_month.clone().expose()
+                        ccard.card_exp_month.two_digits()
                     ))
                     .parse::<i32>()
                     .unwrap_or_default(),
                 ),
-                card_cvc: Some(ccard.card_cvc.clone()),
+                card_cvc: Some(ccard.get_card_cvc()),
                 card_holder_name: None,
                 flexible_3d: None,
                 moto: None,
@@ -677,9 +677,9 @@ fn get_applepay_details(
 fn get_card_details(req_card: &api_models::payments::Card) -> CardDetails {
     CardDetails {
         card_number: req_card.card_number.clone(),
-        expiry_month: req_card.card_exp_month.clone(),
+        expiry_month: req_card.card_exp_month.clone().into(),
         expiry_year: req_card.get_card_expiry_year_2_digit(),
-        verification: req_card.card_cvc.clone(),
+        verification: req_card.get_card_cvc(),
     }
 }
 
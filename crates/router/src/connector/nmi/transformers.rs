@@ -206,7 +206,7 @@ impl From<&api_models::payments::Card> for PaymentMethod {
         let card = CardData {
             ccnumber: card.card_number.clone(),
             ccexp,
-            cvv: card.card_cvc.clone(),
+            cvv: utils::CardData::get_card_cvc(card),
         };
         Self::Card(Box::new(card))
     }
@@ -202,9 +202,9 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for NoonPaymentsRequest {
                     api::PaymentMethodData::Card(req_card) => Ok(NoonPaymentData::Card(NoonCard {
                         name_on_card: req_card.card_holder_name.clone(),
                         number_plain: req_card.card_number.clone(),
-                        expiry_month: req_card.card_exp_month.clone(),
+                        expiry_month: req_card.card_exp_month.clone().into(),
                         expiry_year: req_card.get_expiry_year_4_digit(),
-                        cvv: req_card.card_cvc,
+                        cvv: req_card.get_card_cvc(),
                     })),
                     api::PaymentMethodData::Wallet(wallet_data) => match wallet_data.clone() {
                         api_models::payments::WalletData::GooglePay(google_pay_data) => {
@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     connector::utils::{
-        self, AddressDetailsData, BrowserInformationData, PaymentsAuthorizeRequestData,
+        self, AddressDetailsData, BrowserInformationData, CardData, PaymentsAuthorizeRequestData,
         PaymentsCancelRequestData, RouterData,
     },
     consts,
@@ -1001,12 +1001,12 @@ impl From<NuveiCardDetails> for PaymentOption {
         let card = card_details.card;
         Self {
             card: Some(Card {
+                cvv: Some(card.get_card_cvc()),
                 card_number: Some(card.card_number),
                 card_holder_name: Some(card.card_holder_name),
-                expiration_month: Some(card.card_exp_month),
-                expiration_year: Some(card.card_exp_year),
+                expiration_month: Some(card.card_exp_month.into()),
+                expiration_year: Some(card.card_exp_year.into()),
                 three_d: card_details.three_d,
-                cvv: Some(card.card_cvc),
                 ..Default::default()
             }),
             ..Default::default()
@@ -2,7 +2,7 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::{self, PaymentsAuthorizeRequestData},
+    connector::utils::{self, CardData, PaymentsAuthorizeRequestData},
     core::errors,
     types::{self, api, storage::enums},
 };
@@ -29,11 +29,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for OpayoPaymentsRequest {
         match item.request.payment_method_data.clone() {
             api::PaymentMethodData::Card(req_card) => {
                 let card = OpayoCard {
+                    cvc: req_card.get_card_cvc(),
                     name: req_card.card_holder_name,
                     number: req_card.card_number,
-                    expiry_month: req_card.card_exp_month,
-                    expiry_year: req_card.card_exp_year,
-                    cvc: req_card.card_cvc,
+                    expiry_month: req_card.card_exp_month.into(),
+                    expiry_year: req_card.card_exp_year.into(),
                     complete: item.request.is_auto_capture()?,
                 };
                 Ok(Self {
@@ -245,7 +245,7 @@ fn get_payment_method_data(
                 cardholder_name: card.card_holder_name.clone(),
                 card_number: card.card_number.clone(),
                 exp_date: card.get_card_expiry_month_year_2_digit_with_delimiter("".to_string()),
-                cvv: card.card_cvc.clone(),
+                cvv: card.get_card_cvc(),
             };
             Ok(PayeezyPaymentMethod::PayeezyCard(payeezy_card))
         }
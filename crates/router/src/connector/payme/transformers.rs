@@ -633,7 +633,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayRequest {
         match item.request.payment_method_data.clone() {
             api::PaymentMethodData::Card(req_card) => {
                 let card = PaymeCard {
-                    credit_card_cvv: req_card.card_cvc.clone(),
+                    credit_card_cvv: req_card.get_card_cvc(),
                     credit_card_exp: req_card
                         .get_card_expiry_month_year_2_digit_with_delimiter("".to_string()),
                     credit_card_number: req_card.card_number,
@@ -699,7 +699,8 @@ impl TryFrom<&types::PaymentsCompleteAuthorizeRouterData> for Pay3dsRequest {
                 let pm_token = item.get_payment_method_token()?;
                 let buyer_key = match pm_token {
                     types::PaymentMethodToken::Token(token) => token,
-                    types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                    types::PaymentMethodToken::ApplePayDecrypt(_)
+                    | types::PaymentMethodToken::NetworkToken(_) => {
                         Err(errors::ConnectorError::InvalidWalletToken)?
                     }
                 };
@@ -738,7 +739,7 @@ impl TryFrom<&types::TokenizationRouterData> for CaptureBuyerRequest {
                 let seller_payme_id =
                     PaymeAuthType::try_from(&item.connector_auth_type)?.seller_payme_id;
                 let card = PaymeCard {
-                    credit_card_cvv: req_card.card_cvc.clone(),
+                    credit_card_cvv: req_card.get_card_cvc(),
                     credit_card_exp: req_card
                         .get_card_expiry_month_year_2_digit_with_delimiter("".to_string()),
                     credit_card_number: req_card.card_number,
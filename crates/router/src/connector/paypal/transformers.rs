@@ -289,7 +289,7 @@ impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalP
                     expiry,
                     name: ccard.card_holder_name.clone(),
                     number: Some(ccard.card_number.clone()),
-                    security_code: Some(ccard.card_cvc.clone()),
+                    security_code: Some(ccard.get_card_cvc()),
                     attributes,
                 }));
 
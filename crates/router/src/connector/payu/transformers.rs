@@ -3,7 +3,7 @@ use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::AccessTokenRequestInfo,
+    connector::utils::{AccessTokenRequestInfo, CardData},
     consts,
     core::errors,
     pii::Secret,
@@ -71,10 +71,10 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayuPaymentsRequest {
         let payment_method = match item.request.payment_method_data.clone() {
             api::PaymentMethodData::Card(ccard) => Ok(PayuPaymentMethod {
                 pay_method: PayuPaymentMethodData::Card(PayuCard::Card {
+                    cvv: ccard.get_card_cvc(),
                     number: ccard.card_number,
-                    expiration_month: ccard.card_exp_month,
-                    expiration_year: ccard.card_exp_year,
-                    cvv: ccard.card_cvc,
+                    expiration_month: ccard.card_exp_month.into(),
+                    expiration_year: ccard.card_exp_year.into(),
                 }),
             }),
             api::PaymentMethodData::Wallet(wallet_data) => match wallet_data {
@@ -216,7 +216,7 @@ impl From<&Card> for Source {
             cardholder_name: card.card_holder_name.clone(),
             card_pan: card.card_number.clone(),
             card_expiration: card.get_expiry_date_as_yymm(),
-            card_cvv: card.card_cvc.clone(),
+            card_cvv: card.get_card_cvc(),
         };
         Self::Card(card)
     }
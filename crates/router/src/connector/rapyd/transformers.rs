@@ -4,7 +4,7 @@ use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
-    connector::utils::PaymentsAuthorizeRequestData,
+    connector::utils::{CardData, PaymentsAuthorizeRequestData},
     consts,
     core::errors,
     pii::Secret,
@@ -129,10 +129,10 @@ impl TryFrom<&RapydRouterData<&types::PaymentsAuthorizeRouterData>> for RapydPay
                     pm_type: "in_amex_card".to_owned(), //[#369] Map payment method type based on country
                     fields: Some(PaymentFields {
                         number: ccard.card_number.to_owned(),
-                        expiration_month: ccard.card_exp_month.to_owned(),
-                        expiration_year: ccard.card_exp_year.to_owned(),
+                        expiration_month: ccard.card_exp_month.to_owned().into(),
+                        expiration_year: ccard.card_exp_year.to_owned().into(),
                         name: ccard.card_holder_name.to_owned(),
-                        cvv: ccard.card_cvc.to_owned(),
+                        cvv: ccard.get_card_cvc(),
                     }),
                     address: None,
                     digital_wallet: None,
@@ -311,8 +311,8 @@ impl<T>
     ) -> Result<Self, Self::Error> {
         let card_object = Card {
             number: card.card_number.clone(),
-            exp_month: card.card_exp_month.clone(),
-            exp_year: card.card_exp_year.clone(),
+            exp_month: card.card_exp_month.clone().into(),
+            exp_year: card.card_exp_year.clone().into(),
             cardholder_name: card.card_holder_name.clone(),
         };
         if item.is_three_ds() {
@@ -50,14 +50,7 @@ impl TryFrom<(&types::TokenizationRouterData, api_models::payments::Card)> for S
                 .into_report()
                 .change_context(errors::ConnectorError::DateFormattingFailed)?,
         );
-        let exp_month = Secret::new(
-            card_data
-                .card_exp_month
-                .peek()
-                .parse::<u16>()
-                .into_report()
-                .change_context(errors::ConnectorError::DateFormattingFailed)?,
-        );
+        let exp_month = Secret::new(u16::from(*card_data.card_exp_month.peek()));
         //The below error will never happen because if session-id is not generated it would give error in execute_pretasks itself.
         let session_id = Secret::new(
             item.session_token
@@ -68,10 +61,10 @@ impl TryFrom<(&types::TokenizationRouterData, api_models::payments::Card)> for S
             client_id: auth.key1,
             session_id,
             card_data: SquareCardData {
+                cvv: card_data.get_card_cvc(),
                 exp_year,
                 exp_month,
                 number: card_data.card_number,
-                cvv: card_data.card_cvc,
             },
         }))
     }
@@ -278,7 +271,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for SquarePaymentsRequest {
                     idempotency_key: Secret::new(item.attempt_id.clone()),
                     source_id: Secret::new(match pm_token {
                         types::PaymentMethodToken::Token(token) => token,
-                        types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                        types::PaymentMethodToken::ApplePayDecrypt(_)
+                        | types::PaymentMethodToken::NetworkToken(_) => {
                             Err(errors::ConnectorError::InvalidWalletToken)?
                         }
                     }),
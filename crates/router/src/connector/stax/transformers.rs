@@ -81,7 +81,8 @@ impl TryFrom<&StaxRouterData<&types::PaymentsAuthorizeRouterData>> for StaxPayme
                     pre_auth,
                     payment_method_id: Secret::new(match pm_token {
                         types::PaymentMethodToken::Token(token) => token,
-                        types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                        types::PaymentMethodToken::ApplePayDecrypt(_)
+                        | types::PaymentMethodToken::NetworkToken(_) => {
                             Err(errors::ConnectorError::InvalidWalletToken)?
                         }
                     }),
@@ -100,7 +101,8 @@ impl TryFrom<&StaxRouterData<&types::PaymentsAuthorizeRouterData>> for StaxPayme
                     pre_auth,
                     payment_method_id: Secret::new(match pm_token {
                         types::PaymentMethodToken::Token(token) => token,
-                        types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                        types::PaymentMethodToken::ApplePayDecrypt(_)
+                        | types::PaymentMethodToken::NetworkToken(_) => {
                             Err(errors::ConnectorError::InvalidWalletToken)?
                         }
                     }),
@@ -229,7 +231,7 @@ impl TryFrom<&types::TokenizationRouterData> for StaxTokenRequest {
                         .get_card_expiry_month_year_2_digit_with_delimiter("".to_string()),
                     person_name: card_data.card_holder_name,
                     card_number: card_data.card_number,
-                    card_cvv: card_data.card_cvc,
+                    card_cvv: card_data.get_card_cvc(),
                     customer_id: Secret::new(customer_id),
                 };
                 Ok(Self::Card(stax_card_data))
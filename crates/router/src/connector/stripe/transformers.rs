@@ -16,7 +16,9 @@ use uuid::Uuid;
 
 use crate::{
     collect_missing_value_keys,
-    connector::utils::{self as connector_util, ApplePay, PaymentsPreProcessingData, RouterData},
+    connector::utils::{
+        self as connector_util, ApplePay, CardData, PaymentsPreProcessingData, RouterData,
+    },
     core::errors,
     services,
     types::{
@@ -1445,9 +1447,9 @@ impl TryFrom<(&payments::Card, Auth3ds)> for StripePaymentMethodData {
         Ok(Self::Card(StripeCardData {
             payment_method_data_type: StripePaymentMethodType::Card,
             payment_method_data_card_number: card.card_number.clone(),
-            payment_method_data_card_exp_month: card.card_exp_month.clone(),
-            payment_method_data_card_exp_year: card.card_exp_year.clone(),
-            payment_method_data_card_cvc: card.card_cvc.clone(),
+            payment_method_data_card_exp_month: card.card_exp_month.clone().into(),
+            payment_method_data_card_exp_year: card.card_exp_year.clone().into(),
+            payment_method_data_card_cvc: card.get_card_cvc(),
             payment_method_auth_type,
         }))
     }
@@ -1787,7 +1789,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                     .change_context(errors::ConnectorError::RequestEncodingFailed)?;
                 let payment_method_token = match payment_method_token {
                     types::PaymentMethodToken::Token(payment_method_token) => payment_method_token,
-                    types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                    types::PaymentMethodToken::ApplePayDecrypt(_)
+                    | types::PaymentMethodToken::NetworkToken(_) => {
                         Err(errors::ConnectorError::InvalidWalletToken)?
                     }
                 };
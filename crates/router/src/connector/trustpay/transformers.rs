@@ -296,7 +296,7 @@ fn get_card_request_data(
             amount,
             currency: item.request.currency.to_string(),
             pan: ccard.card_number.clone(),
-            cvv: ccard.card_cvc.clone(),
+            cvv: ccard.get_card_cvc(),
             expiry_date: ccard.get_card_expiry_month_year_2_digit_with_delimiter("/".to_owned()),
             cardholder: get_full_name(params.billing_first_name, billing_last_name),
             reference: item.payment_id.clone(),
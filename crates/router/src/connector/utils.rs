@@ -663,13 +663,13 @@ pub trait CardData {
     fn get_expiry_date_as_mmyyyy(&self, delimiter: &str) -> Secret<String>;
     fn get_expiry_year_4_digit(&self) -> Secret<String>;
     fn get_expiry_date_as_yymm(&self) -> Secret<String>;
+    /// Cards fetched from the locker do not carry a CVC, an empty one is passed on for those
+    fn get_card_cvc(&self) -> Secret<String>;
 }
 
 impl CardData for api::Card {
     fn get_card_expiry_year_2_digit(&self) -> Secret<String> {
-        let binding = self.card_exp_year.clone();
-        let year = binding.peek();
-        Secret::new(year[year.len() - 2..].to_string())
+        Secret::new(self.card_exp_year.two_digits())
     }
     fn get_card_issuer(&self) -> Result<CardIssuer, Error> {
         get_card_issuer(self.card_number.peek())
@@ -681,7 +681,7 @@ impl CardData for api::Card {
         let year = self.get_card_expiry_year_2_digit();
         Secret::new(format!(
             "{}{}{}",
-            self.card_exp_month.peek().clone(),
+            self.card_exp_month.two_digits(),
             delimiter,
             year.peek()
         ))
@@ -692,30 +692,29 @@ impl CardData for api::Card {
             "{}{}{}",
             year.peek(),
             delimiter,
-            self.card_exp_month.peek().clone()
+            self.card_exp_month.two_digits()
         ))
     }
     fn get_expiry_date_as_mmyyyy(&self, delimiter: &str) -> Secret<String> {
         let year = self.get_expiry_year_4_digit();
         Secret::new(format!(
             "{}{}{}",
-            self.card_exp_month.peek().clone(),
+            self.card_exp_month.two_digits(),
             delimiter,
             year.peek()
         ))
     }
     fn get_expiry_year_4_digit(&self) -> Secret<String> {
-        let mut year = self.card_exp_year.peek().clone();
-        if year.len() == 2 {
-            year = format!("20{}", year);
-        }
-        Secret::new(year)
+        Secret::new(self.card_exp_year.four_digits())
     }
     fn get_expiry_date_as_yymm(&self) -> Secret<String> {
         let year = self.get_card_expiry_year_2_digit().expose();
-        let month = self.card_exp_month.clone().expose();
+        let month = self.card_exp_month.two_digits();
         Secret::new(format!("{year}{month}"))
     }
+    fn get_card_cvc(&self) -> Secret<String> {
+        self.card_cvc.clone().map(Secret::from).unwrap_or_default()
+    }
 }
 
 #[track_caller]
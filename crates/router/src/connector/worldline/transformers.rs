@@ -342,17 +342,16 @@ fn make_card_request(
     req: &PaymentsAuthorizeData,
     ccard: &payments::Card,
 ) -> Result<CardPaymentMethod, error_stack::Report<errors::ConnectorError>> {
-    let expiry_year = ccard.card_exp_year.peek().clone();
     let secret_value = format!(
         "{}{}",
-        ccard.card_exp_month.peek(),
-        &expiry_year[expiry_year.len() - 2..]
+        ccard.card_exp_month.two_digits(),
+        ccard.card_exp_year.two_digits()
     );
     let expiry_date: Secret<String> = Secret::new(secret_value);
     let card = Card {
         card_number: ccard.card_number.clone(),
         cardholder_name: ccard.card_holder_name.clone(),
-        cvv: ccard.card_cvc.clone(),
+        cvv: ccard.get_card_cvc(),
         expiry_date,
     };
     #[allow(clippy::as_conversions)]
@@ -47,20 +47,10 @@ fn fetch_payment_instrument(
     match payment_method {
         api::PaymentMethodData::Card(card) => Ok(PaymentInstrument::Card(CardPayment {
             card_expiry_date: CardExpiryDate {
-                month: card
-                    .card_exp_month
-                    .peek()
-                    .clone()
-                    .parse::<i8>()
-                    .into_report()
-                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?,
-                year: card
-                    .card_exp_year
-                    .peek()
-                    .clone()
-                    .parse::<i32>()
+                month: i8::try_from(*card.card_exp_month.peek())
                     .into_report()
                     .change_context(errors::ConnectorError::ResponseDeserializationFailed)?,
+                year: i32::from(*card.card_exp_year.peek()),
             },
             card_number: card.card_number,
             ..CardPayment::default()
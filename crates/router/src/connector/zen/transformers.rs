@@ -223,7 +223,7 @@ impl TryFrom<(&ZenRouterData<&types::PaymentsAuthorizeRouterData>, &Card)> for Z
                     number: ccard.card_number.clone(),
                     expiry_date: ccard
                         .get_card_expiry_month_year_2_digit_with_delimiter("".to_owned()),
-                    cvv: ccard.card_cvc.clone(),
+                    cvv: ccard.get_card_cvc(),
                 }),
                 descriptor: item
                     .router_data
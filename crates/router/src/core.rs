@@ -18,9 +18,10 @@ pub mod payments;
 pub mod payouts;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
 #[cfg(feature = "olap")]
 pub mod user;
 pub mod utils;
-#[cfg(all(feature = "olap", feature = "kms"))]
+#[cfg(all(feature = "olap", feature = "encryption_service"))]
 pub mod verification;
 pub mod webhooks;
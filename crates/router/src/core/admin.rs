@@ -1,24 +1,26 @@
-use std::str::FromStr;
+use std::{collections::HashSet, str::FromStr};
 
 use api_models::{
     admin::{self as admin_types},
     enums as api_enums, routing as routing_types,
 };
 use common_utils::{
-    crypto::{generate_cryptographically_secure_random_string, OptionalSecretValue},
+    crypto::{generate_cryptographically_secure_random_string, Encryptable, OptionalSecretValue},
     date_time,
     ext_traits::{AsyncExt, ConfigExt, Encode, ValueExt},
     pii,
 };
-use error_stack::{report, FutureExt, ResultExt};
+use error_stack::{report, FutureExt, IntoReport, ResultExt};
 use futures::future::try_join_all;
-use masking::{PeekInterface, Secret};
+use masking::Secret;
+use scheduler::db::process_tracker::ProcessTrackerExt;
 use uuid::Uuid;
 
 use crate::{
     consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_link, payment_methods,
         payments::helpers,
         routing::helpers as routing_helpers,
         utils as core_utils,
@@ -38,6 +40,10 @@ use crate::{
     utils::{self, OptionExt},
 };
 
+const MERCHANT_KEY_ROTATION_RUNNER: &str = "MERCHANT_KEY_ROTATION_WORKFLOW";
+const MERCHANT_KEY_ROTATION_TASK: &str = "MERCHANT_KEY_ROTATION";
+const MERCHANT_KEY_ROTATION_BATCH_SIZE: i64 = 100;
+
 #[inline]
 pub fn create_merchant_publishable_key() -> String {
     format!(
@@ -108,6 +114,9 @@ pub async fn create_merchant_account(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to decrypt data from key store")?,
         created_at: date_time::now(),
+        key_version: domain_types::INITIAL_KEY_VERSION,
+        previous_key: None,
+        previous_key_version: None,
     };
 
     let enable_payment_response_hash = req.enable_payment_response_hash.unwrap_or(true);
@@ -140,6 +149,10 @@ pub async fn create_merchant_account(
         .transpose()?
         .map(Secret::new);
 
+    req.payment_link_config
+        .as_ref()
+        .map(payment_link::validate_payment_link_config)
+        .transpose()?;
     let payment_link_config = req
         .payment_link_config
         .as_ref()
@@ -270,6 +283,14 @@ pub async fn create_merchant_account(
     })
     .ok();
 
+    payment_methods::account_updater::schedule_card_account_updater_task(
+        &state,
+        &merchant_account.merchant_id,
+    )
+    .await
+    .map_err(|error| crate::logger::error!(card_account_updater_schedule_error=?error))
+    .ok();
+
     Ok(service_api::ApplicationResponse::Json(
         merchant_account
             .try_into()
@@ -475,6 +496,16 @@ pub async fn merchant_account_update(
         }))?;
     }
 
+    if let Some(ref payment_link_config) = req.payment_link_config {
+        let payment_link_config: admin_types::PaymentLinkConfig = payment_link_config
+            .clone()
+            .parse_value("PaymentLinkConfig")
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "payment_link_config",
+            })?;
+        payment_link::validate_payment_link_config(&payment_link_config)?;
+    }
+
     if let Some(ref routing_algorithm) = req.routing_algorithm {
         let _: api_models::routing::RoutingAlgorithm = routing_algorithm
             .clone()
@@ -513,7 +544,7 @@ pub async fn merchant_account_update(
         })
         .await;
 
-    let key = key_store.key.get_inner().peek();
+    let key = &key_store.key_ring();
 
     let business_profile_id_update = if let Some(ref profile_id) = req.default_profile {
         if !profile_id.is_empty_after_trim() {
@@ -836,37 +867,7 @@ pub async fn create_payment_connector(
     })?;
 
     let frm_configs = get_frm_config_as_secret(req.frm_configs);
-
-    // The purpose of this merchant account update is just to update the
-    // merchant account `modified_at` field for KGraph cache invalidation
-    let merchant_account_update = storage::MerchantAccountUpdate::Update {
-        merchant_name: None,
-        merchant_details: None,
-        return_url: None,
-        webhook_details: None,
-        sub_merchants_enabled: None,
-        parent_merchant_id: None,
-        enable_payment_response_hash: None,
-        locker_id: None,
-        payment_response_hash_key: None,
-        primary_business_details: None,
-        metadata: None,
-        publishable_key: None,
-        redirect_to_merchant_with_http_post: None,
-        routing_algorithm: None,
-        intent_fulfillment_time: None,
-        frm_routing_algorithm: None,
-        payout_routing_algorithm: None,
-        default_profile: None,
-        payment_link_config: None,
-    };
-
-    state
-        .store
-        .update_specific_fields_in_merchant(merchant_id, merchant_account_update, &key_store)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("error updating the merchant account when creating payment connector")?;
+    let pm_filters = get_pm_filters_as_value(req.pm_filters)?;
 
     let merchant_connector_account = domain::MerchantConnectorAccount {
         merchant_id: merchant_id.to_string(),
@@ -879,7 +880,7 @@ pub async fn create_payment_connector(
                     field_name: "connector_account_details",
                 },
             )?,
-            key_store.key.peek(),
+            &key_store.key_ring(),
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -911,6 +912,7 @@ pub async fn create_payment_connector(
         profile_id: Some(profile_id.clone()),
         applepay_verified_domains: None,
         pm_auth_config: req.pm_auth_config.clone(),
+        pm_filters,
     };
 
     let mut default_routing_config =
@@ -930,6 +932,11 @@ pub async fn create_payment_connector(
             },
         )?;
 
+    // Only once the connector account exists, so that the KGraph is not rebuilt without it
+    update_merchant_account_modified_at(&state, merchant_id, &key_store)
+        .await
+        .attach_printable("error updating the merchant account when creating payment connector")?;
+
     if let Some(routable_connector_val) = routable_connector {
         let choice = routing_types::RoutableConnectorChoice {
             #[cfg(feature = "backwards_compatibility")]
@@ -1082,6 +1089,8 @@ pub async fn update_payment_connector(
     });
 
     let frm_configs = get_frm_config_as_secret(req.frm_configs);
+    let pm_filters = get_pm_filters_as_value(req.pm_filters)?;
+    let is_pm_filters_updated = pm_filters.is_some();
 
     let payment_connector = storage::MerchantConnectorAccountUpdate::Update {
         merchant_id: None,
@@ -1091,9 +1100,7 @@ pub async fn update_payment_connector(
         connector_label: req.connector_label,
         connector_account_details: req
             .connector_account_details
-            .async_lift(|inner| {
-                domain_types::encrypt_optional(inner, key_store.key.get_inner().peek())
-            })
+            .async_lift(|inner| domain_types::encrypt_optional(inner, &key_store.key_ring()))
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed while encrypting data")?,
@@ -1115,6 +1122,7 @@ pub async fn update_payment_connector(
         },
         applepay_verified_domains: None,
         pm_auth_config: req.pm_auth_config,
+        pm_filters,
     };
 
     let updated_mca = db
@@ -1125,6 +1133,16 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
+    // Payment method filters are compiled into the KGraph, which has to be rebuilt once the
+    // updated filters are stored
+    if is_pm_filters_updated {
+        update_merchant_account_modified_at(&state, merchant_id, &key_store)
+            .await
+            .attach_printable(
+                "error updating the merchant account when updating payment connector",
+            )?;
+    }
+
     let response = updated_mca.try_into()?;
 
     Ok(service_api::ApplicationResponse::Json(response))
@@ -1264,6 +1282,656 @@ pub async fn check_merchant_account_kv_status(
     ))
 }
 
+/// Makes a newly generated key the active key of the merchant and schedules the task which
+/// re-encrypts the data of the merchant with it. The current key is still used for decrypting the
+/// data which has not been re-encrypted yet, and is retired once the task completes.
+pub async fn rotate_merchant_key(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<admin_types::MerchantKeyRotationResponse> {
+    let db = state.store.as_ref();
+    let master_key: Secret<Vec<u8>> = db.get_master_key().to_vec().into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &master_key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    utils::when(key_store.previous_key.is_some(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A key rotation is already in progress for this merchant".to_string(),
+        })
+    })?;
+
+    let key = services::generate_aes256_key()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to generate aes 256 key")?;
+    let key: Encryptable<Secret<Vec<u8>>> =
+        domain_types::encrypt(key.to_vec().into(), db.get_master_key())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the rotated merchant key")?;
+    let key_version = key_store.key_version + 1;
+    let previous_key_version = key_store.key_version;
+
+    let merchant_key_store_update = storage::MerchantKeyStoreUpdate::RotateKey {
+        key: key.into(),
+        key_version,
+        previous_key: key_store.key.into(),
+        previous_key_version,
+    };
+    db.update_merchant_key_store(&merchant_id, merchant_key_store_update, &master_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to rotate the merchant key")?;
+
+    schedule_merchant_key_rotation_task(db, &merchant_id, key_version)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the re-encryption of the merchant data")?;
+    metrics::MERCHANT_KEY_ROTATED_METRIC.add(&metrics::CONTEXT, 1, &[]);
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::MerchantKeyRotationResponse {
+            merchant_id,
+            key_version,
+            previous_key_version,
+        },
+    ))
+}
+
+async fn schedule_merchant_key_rotation_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    key_version: i32,
+) -> RouterResult<()> {
+    let tracking_data = serde_json::to_value(storage::MerchantKeyRotationTrackingData {
+        merchant_id: merchant_id.to_string(),
+        stage: storage::MerchantKeyRotationStage::MerchantAccount,
+        last_id: None,
+        found_stale_records: false,
+    })
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize merchant key rotation tracking data")?;
+
+    let current_time = date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: format!("{MERCHANT_KEY_ROTATION_TASK}_{merchant_id}_{key_version}"),
+        name: Some(String::from(MERCHANT_KEY_ROTATION_TASK)),
+        tag: vec![String::from("MERCHANT")],
+        runner: Some(String::from(MERCHANT_KEY_ROTATION_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(current_time),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: storage::enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    Ok(())
+}
+
+/// Re-encrypts a batch of the records of the merchant, including the payment method data held in
+/// the locker, with the active key. The previous key is retired once a complete pass over the
+/// merchant data finds no records left to re-encrypt, so that records written while a pass was
+/// running are not missed.
+pub async fn start_merchant_key_rotation_workflow(
+    state: &AppState,
+    rotation_process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::MerchantKeyRotationTrackingData = rotation_process
+        .tracking_data
+        .clone()
+        .parse_value("MerchantKeyRotationTrackingData")?;
+
+    let master_key: Secret<Vec<u8>> = db.get_master_key().to_vec().into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&tracking_data.merchant_id, &master_key)
+        .await?;
+    if key_store.previous_key.is_none() {
+        return rotation_process
+            .clone()
+            .finish_with_status(db.as_scheduler(), "COMPLETED_BY_PT".to_string())
+            .await;
+    }
+
+    let batch = reencrypt_merchant_data_batch(
+        state,
+        &key_store,
+        tracking_data.stage,
+        tracking_data.last_id,
+    )
+    .await?;
+    metrics::MERCHANT_KEY_REENCRYPTED_RECORDS_METRIC.add(
+        &metrics::CONTEXT,
+        u64::try_from(batch.reencrypted_records).unwrap_or_default(),
+        &[],
+    );
+
+    let found_stale_records = tracking_data.found_stale_records || batch.reencrypted_records > 0;
+    let is_stage_complete = i64::try_from(batch.batch_size).map_or(true, |batch_size| {
+        batch_size < MERCHANT_KEY_ROTATION_BATCH_SIZE
+    });
+    let next_tracking_data = if !is_stage_complete {
+        Some(storage::MerchantKeyRotationTrackingData {
+            last_id: batch.last_id,
+            found_stale_records,
+            ..tracking_data.clone()
+        })
+    } else {
+        match get_next_merchant_key_rotation_stage(tracking_data.stage) {
+            Some(stage) => Some(storage::MerchantKeyRotationTrackingData {
+                stage,
+                last_id: None,
+                found_stale_records,
+                ..tracking_data.clone()
+            }),
+            // Start another pass to verify that no records are left on the previous key
+            None if found_stale_records => Some(storage::MerchantKeyRotationTrackingData {
+                stage: storage::MerchantKeyRotationStage::MerchantAccount,
+                last_id: None,
+                found_stale_records: false,
+                ..tracking_data.clone()
+            }),
+            None => None,
+        }
+    };
+
+    match next_tracking_data {
+        Some(next_tracking_data) => {
+            let current_time = date_time::now();
+            let tracking_data = serde_json::to_value(next_tracking_data)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize merchant key rotation tracking data")?;
+            db.update_process(
+                rotation_process.clone(),
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(current_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(storage::enums::ProcessTrackerStatus::New),
+                    updated_at: Some(current_time),
+                },
+            )
+            .await?;
+            Ok(())
+        }
+        None => {
+            db.update_merchant_key_store(
+                &tracking_data.merchant_id,
+                storage::MerchantKeyStoreUpdate::RetirePreviousKey,
+                &master_key,
+            )
+            .await?;
+            metrics::MERCHANT_KEY_RETIRED_METRIC.add(&metrics::CONTEXT, 1, &[]);
+            rotation_process
+                .clone()
+                .finish_with_status(db.as_scheduler(), "COMPLETED_BY_PT".to_string())
+                .await
+        }
+    }
+}
+
+fn get_next_merchant_key_rotation_stage(
+    stage: storage::MerchantKeyRotationStage,
+) -> Option<storage::MerchantKeyRotationStage> {
+    match stage {
+        storage::MerchantKeyRotationStage::MerchantAccount => {
+            Some(storage::MerchantKeyRotationStage::MerchantConnectorAccounts)
+        }
+        storage::MerchantKeyRotationStage::MerchantConnectorAccounts => {
+            Some(storage::MerchantKeyRotationStage::Customers)
+        }
+        storage::MerchantKeyRotationStage::Customers => {
+            Some(storage::MerchantKeyRotationStage::Addresses)
+        }
+        storage::MerchantKeyRotationStage::Addresses => {
+            Some(storage::MerchantKeyRotationStage::PaymentMethods)
+        }
+        storage::MerchantKeyRotationStage::PaymentMethods => {
+            Some(storage::MerchantKeyRotationStage::LockerData)
+        }
+        storage::MerchantKeyRotationStage::LockerData => None,
+    }
+}
+
+struct MerchantDataBatch {
+    /// Number of records read in the batch
+    batch_size: usize,
+    /// Id of the last record read in the batch
+    last_id: Option<i32>,
+    /// Number of records of the batch which were not encrypted with the active key
+    reencrypted_records: usize,
+}
+
+fn is_stale<T: Clone>(field: &Option<Encryptable<T>>, key_ring: &domain_types::KeyRing) -> bool {
+    field.as_ref().map_or(false, |field| {
+        !domain_types::is_encrypted_with_active_key(field, key_ring)
+    })
+}
+
+/// Re-encrypts the records of the given stage which follow `last_id` and are not encrypted with
+/// the active key yet. Stages holding a single record are processed in a single batch.
+async fn reencrypt_merchant_data_batch(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    stage: storage::MerchantKeyRotationStage,
+    last_id: Option<i32>,
+) -> Result<MerchantDataBatch, errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let merchant_id = key_store.merchant_id.as_str();
+    let key_ring = key_store.key_ring();
+
+    match stage {
+        storage::MerchantKeyRotationStage::MerchantAccount => {
+            let merchant_account = db
+                .find_merchant_account_by_merchant_id(merchant_id, key_store)
+                .await?;
+            if !is_stale(&merchant_account.merchant_name, &key_ring)
+                && !is_stale(&merchant_account.merchant_details, &key_ring)
+            {
+                return Ok(MerchantDataBatch {
+                    batch_size: 1,
+                    last_id: None,
+                    reencrypted_records: 0,
+                });
+            }
+
+            let merchant_account_update = storage::MerchantAccountUpdate::Update {
+                merchant_name: domain_types::reencrypt_optional(
+                    merchant_account.merchant_name,
+                    &key_ring,
+                )
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+                merchant_details: domain_types::reencrypt_optional(
+                    merchant_account.merchant_details,
+                    &key_ring,
+                )
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+                return_url: None,
+                webhook_details: None,
+                sub_merchants_enabled: None,
+                parent_merchant_id: None,
+                enable_payment_response_hash: None,
+                locker_id: None,
+                payment_response_hash_key: None,
+                primary_business_details: None,
+                metadata: None,
+                publishable_key: None,
+                redirect_to_merchant_with_http_post: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                default_profile: None,
+                payment_link_config: None,
+            };
+            db.update_specific_fields_in_merchant(merchant_id, merchant_account_update, key_store)
+                .await?;
+            Ok(MerchantDataBatch {
+                batch_size: 1,
+                last_id: None,
+                reencrypted_records: 1,
+            })
+        }
+        storage::MerchantKeyRotationStage::MerchantConnectorAccounts => {
+            let merchant_connector_accounts = db
+                .list_merchant_connector_accounts_by_merchant_id_after_id(
+                    merchant_id,
+                    last_id,
+                    MERCHANT_KEY_ROTATION_BATCH_SIZE,
+                    key_store,
+                )
+                .await?;
+            let batch_size = merchant_connector_accounts.len();
+            let last_id = merchant_connector_accounts
+                .last()
+                .and_then(|merchant_connector_account| merchant_connector_account.id);
+            let mut reencrypted_records = 0;
+            for merchant_connector_account in merchant_connector_accounts {
+                if domain_types::is_encrypted_with_active_key(
+                    &merchant_connector_account.connector_account_details,
+                    &key_ring,
+                ) {
+                    continue;
+                }
+                let connector_account_details = domain_types::reencrypt(
+                    merchant_connector_account.connector_account_details.clone(),
+                    &key_ring,
+                )
+                .await
+                .change_context(errors::StorageError::EncryptionError)?;
+                let merchant_connector_account_update =
+                    storage::MerchantConnectorAccountUpdate::Update {
+                        merchant_id: None,
+                        connector_type: None,
+                        connector_name: None,
+                        connector_account_details: Some(connector_account_details),
+                        test_mode: None,
+                        disabled: None,
+                        merchant_connector_id: None,
+                        payment_methods_enabled: None,
+                        metadata: None,
+                        frm_configs: None,
+                        connector_webhook_details: None,
+                        applepay_verified_domains: None,
+                        pm_auth_config: None,
+                        pm_filters: None,
+                        connector_label: None,
+                    };
+                db.update_merchant_connector_account(
+                    merchant_connector_account,
+                    merchant_connector_account_update.into(),
+                    key_store,
+                )
+                .await?;
+                reencrypted_records += 1;
+            }
+            Ok(MerchantDataBatch {
+                batch_size,
+                last_id,
+                reencrypted_records,
+            })
+        }
+        storage::MerchantKeyRotationStage::Customers => {
+            let customers = db
+                .list_customers_by_merchant_id_after_id(
+                    merchant_id,
+                    last_id,
+                    MERCHANT_KEY_ROTATION_BATCH_SIZE,
+                    key_store,
+                )
+                .await?;
+            let batch_size = customers.len();
+            let last_id = customers.last().and_then(|customer| customer.id);
+            let mut reencrypted_records = 0;
+            for customer in customers {
+                if !is_stale(&customer.name, &key_ring)
+                    && !is_stale(&customer.email, &key_ring)
+                    && !is_stale(&customer.phone, &key_ring)
+                {
+                    continue;
+                }
+                let customer_update = storage::CustomerUpdate::Update {
+                    name: domain_types::reencrypt_optional(customer.name, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    email: domain_types::reencrypt_optional(customer.email, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    phone: Box::new(
+                        domain_types::reencrypt_optional(customer.phone, &key_ring)
+                            .await
+                            .change_context(errors::StorageError::EncryptionError)?,
+                    ),
+                    description: None,
+                    phone_country_code: None,
+                    metadata: None,
+                    connector_customer: None,
+                    address_id: None,
+                };
+                db.update_customer_by_customer_id_merchant_id(
+                    customer.customer_id,
+                    customer.merchant_id,
+                    customer_update,
+                    key_store,
+                )
+                .await?;
+                reencrypted_records += 1;
+            }
+            Ok(MerchantDataBatch {
+                batch_size,
+                last_id,
+                reencrypted_records,
+            })
+        }
+        storage::MerchantKeyRotationStage::Addresses => {
+            let addresses = db
+                .list_addresses_by_merchant_id_after_id(
+                    merchant_id,
+                    last_id,
+                    MERCHANT_KEY_ROTATION_BATCH_SIZE,
+                    key_store,
+                )
+                .await?;
+            let batch_size = addresses.len();
+            let last_id = addresses.last().and_then(|address| address.id);
+            let mut reencrypted_records = 0;
+            for address in addresses {
+                let is_address_stale = [
+                    &address.line1,
+                    &address.line2,
+                    &address.line3,
+                    &address.state,
+                    &address.zip,
+                    &address.first_name,
+                    &address.last_name,
+                    &address.phone_number,
+                ]
+                .into_iter()
+                .any(|field| is_stale(field, &key_ring));
+                if !is_address_stale {
+                    continue;
+                }
+                let address_update = storage::AddressUpdate::Update {
+                    city: address.city,
+                    country: address.country,
+                    line1: domain_types::reencrypt_optional(address.line1, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    line2: domain_types::reencrypt_optional(address.line2, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    line3: domain_types::reencrypt_optional(address.line3, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    state: domain_types::reencrypt_optional(address.state, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    zip: domain_types::reencrypt_optional(address.zip, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    first_name: domain_types::reencrypt_optional(address.first_name, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    last_name: domain_types::reencrypt_optional(address.last_name, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    phone_number: domain_types::reencrypt_optional(address.phone_number, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?,
+                    country_code: address.country_code,
+                    updated_by: address.updated_by,
+                };
+                db.update_address(address.address_id, address_update, key_store)
+                    .await?;
+                reencrypted_records += 1;
+            }
+            Ok(MerchantDataBatch {
+                batch_size,
+                last_id,
+                reencrypted_records,
+            })
+        }
+        storage::MerchantKeyRotationStage::PaymentMethods => {
+            let payment_methods = db
+                .find_payment_method_by_merchant_id_after_id(
+                    merchant_id,
+                    last_id,
+                    MERCHANT_KEY_ROTATION_BATCH_SIZE,
+                )
+                .await?;
+            let batch_size = payment_methods.len();
+            let last_id = payment_methods
+                .last()
+                .map(|payment_method| payment_method.id);
+            let mut reencrypted_records = 0;
+            for payment_method in payment_methods {
+                let payment_method_data =
+                    domain_types::decrypt::<serde_json::Value, masking::WithType, _>(
+                        payment_method.payment_method_data.clone(),
+                        &key_ring,
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?;
+                let network_token_data =
+                    domain_types::decrypt::<serde_json::Value, masking::WithType, _>(
+                        payment_method.network_token_data.clone(),
+                        &key_ring,
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?;
+                if !is_stale(&payment_method_data, &key_ring)
+                    && !is_stale(&network_token_data, &key_ring)
+                {
+                    continue;
+                }
+                let payment_method_data =
+                    domain_types::reencrypt_optional(payment_method_data, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?
+                        .map(Into::into);
+                let network_token_data =
+                    domain_types::reencrypt_optional(network_token_data, &key_ring)
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?
+                        .map(Into::into);
+                let payment_method = db
+                    .update_payment_method(
+                        payment_method,
+                        storage::PaymentMethodUpdate::PaymentMethodDataUpdate {
+                            payment_method_data,
+                        },
+                    )
+                    .await?;
+                if network_token_data.is_some() {
+                    db.update_payment_method(
+                        payment_method,
+                        storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+                            network_token_reference: None,
+                            network_token_data,
+                        },
+                    )
+                    .await?;
+                }
+                reencrypted_records += 1;
+            }
+            Ok(MerchantDataBatch {
+                batch_size,
+                last_id,
+                reencrypted_records,
+            })
+        }
+        storage::MerchantKeyRotationStage::LockerData => {
+            let payment_methods = db
+                .find_payment_method_by_merchant_id_after_id(
+                    merchant_id,
+                    last_id,
+                    MERCHANT_KEY_ROTATION_BATCH_SIZE,
+                )
+                .await?;
+            let batch_size = payment_methods.len();
+            let last_id = payment_methods
+                .last()
+                .map(|payment_method| payment_method.id);
+            let mut reencrypted_records = 0;
+            for payment_method in payment_methods {
+                // Only payout bank accounts are stored in the locker encrypted with the merchant
+                // key, cards are stored in the clear and encrypted by the locker itself
+                if payment_method.payment_method != storage::enums::PaymentMethod::BankTransfer {
+                    continue;
+                }
+                if reencrypt_locker_data(state, key_store, &key_ring, payment_method)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to re-encrypt the locker data of payment method")?
+                {
+                    reencrypted_records += 1;
+                }
+            }
+            Ok(MerchantDataBatch {
+                batch_size,
+                last_id,
+                reencrypted_records,
+            })
+        }
+    }
+}
+
+/// Stores the locker data of the payment method again, encrypted with the active key, if it was
+/// encrypted with the previous key. Returns whether the data was re-encrypted.
+async fn reencrypt_locker_data(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    key_ring: &domain_types::KeyRing,
+    payment_method: storage::PaymentMethod,
+) -> errors::CustomResult<bool, errors::VaultError> {
+    let enc_data = payment_methods::cards::get_encrypted_payment_method_from_hs_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method.get_locker_id(),
+    )
+    .await?;
+    let is_encrypted_with_active_key = hex::decode(&enc_data).map_or(false, |encrypted_data| {
+        domain_types::is_data_encrypted_with_active_key(&encrypted_data, key_ring)
+    });
+    if is_encrypted_with_active_key {
+        return Ok(false);
+    }
+
+    let data = payment_methods::cards::decode_and_decrypt_locker_data(key_store, enc_data).await?;
+    let enc_data = payment_methods::cards::encrypt_and_encode_locker_data(key_store, data).await?;
+    let stored_data = payment_methods::cards::call_to_locker_hs(
+        state,
+        &payment_methods::transformers::StoreLockerReq::LockerGeneric(
+            payment_methods::transformers::StoreGenericReq {
+                merchant_id: &payment_method.merchant_id,
+                merchant_customer_id: payment_method.customer_id.clone(),
+                enc_data,
+            },
+        ),
+        &payment_method.customer_id,
+    )
+    .await?;
+
+    let previous_locker_id = payment_method.get_locker_id().to_string();
+    let payment_method = state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::LockerIdUpdate {
+                locker_id: Some(stored_data.card_reference.clone()),
+            },
+        )
+        .await
+        .change_context(errors::VaultError::UpdateInPaymentMethodDataTableFailed)?;
+
+    if previous_locker_id != stored_data.card_reference {
+        payment_methods::cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &previous_locker_id,
+        )
+        .await
+        .map_err(|error| crate::logger::error!(locker_delete_error=?error))
+        .ok();
+    }
+    Ok(true)
+}
+
 pub fn get_frm_config_as_secret(
     frm_configs: Option<Vec<api_models::admin::FrmConfigs>>,
 ) -> Option<Vec<Secret<serde_json::Value>>> {
@@ -1284,6 +1952,85 @@ pub fn get_frm_config_as_secret(
     }
 }
 
+// The purpose of this merchant account update is just to update the
+// merchant account `modified_at` field for KGraph cache invalidation
+async fn update_merchant_account_modified_at(
+    state: &AppState,
+    merchant_id: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    let merchant_account_update = storage::MerchantAccountUpdate::Update {
+        merchant_name: None,
+        merchant_details: None,
+        return_url: None,
+        webhook_details: None,
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        enable_payment_response_hash: None,
+        locker_id: None,
+        payment_response_hash_key: None,
+        primary_business_details: None,
+        metadata: None,
+        publishable_key: None,
+        redirect_to_merchant_with_http_post: None,
+        routing_algorithm: None,
+        intent_fulfillment_time: None,
+        frm_routing_algorithm: None,
+        payout_routing_algorithm: None,
+        default_profile: None,
+        payment_link_config: None,
+    };
+
+    state
+        .store
+        .update_specific_fields_in_merchant(merchant_id, merchant_account_update, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .map(|_| ())
+}
+
+fn validate_pm_filters(pm_filters: &[api_models::admin::PaymentMethodFilter]) -> RouterResult<()> {
+    let mut keys = HashSet::new();
+    for filter in pm_filters {
+        let key = match (filter.payment_method_type, &filter.card_network) {
+            (Some(payment_method_type), None) => payment_method_type.to_string(),
+            (None, Some(card_network)) => card_network.to_string(),
+            _ => {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Each payment method filter must have exactly one of \
+                        payment_method_type and card_network"
+                        .to_string(),
+                })
+                .into_report()
+            }
+        };
+
+        utils::when(!keys.insert(key.clone()), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate payment method filter for {key}"),
+            })
+            .into_report()
+        })?;
+    }
+
+    Ok(())
+}
+
+pub fn get_pm_filters_as_value(
+    pm_filters: Option<Vec<api_models::admin::PaymentMethodFilter>>,
+) -> RouterResult<Option<serde_json::Value>> {
+    pm_filters
+        .map(|pm_filters| {
+            validate_pm_filters(&pm_filters)?;
+            utils::Encode::<Vec<api_models::admin::PaymentMethodFilter>>::encode_to_value(
+                &pm_filters,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize payment method filters")
+        })
+        .transpose()
+}
+
 pub async fn create_and_insert_business_profile(
     db: &dyn StorageInterface,
     request: api::BusinessProfileCreate,
@@ -1436,6 +2183,22 @@ pub async fn update_business_profile(
         })
         .transpose()?;
 
+    request
+        .payment_link_config
+        .as_ref()
+        .map(payment_link::validate_payment_link_config)
+        .transpose()?;
+    let payment_link_config = request
+        .payment_link_config
+        .as_ref()
+        .map(|pl_config| {
+            utils::Encode::<admin_types::PaymentLinkConfig>::encode_to_value(pl_config)
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_link_config",
+                })
+        })
+        .transpose()?;
+
     if let Some(ref routing_algorithm) = request.routing_algorithm {
         let _: api_models::routing::RoutingAlgorithm = routing_algorithm
             .clone()
@@ -1461,6 +2224,7 @@ pub async fn update_business_profile(
         payout_routing_algorithm: request.payout_routing_algorithm,
         is_recon_enabled: None,
         applepay_verified_domains: request.applepay_verified_domains,
+        payment_link_config,
     };
 
     let updated_business_profile = db
@@ -2,8 +2,8 @@ use common_utils::date_time;
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
 use error_stack::{report, IntoReport, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "encryption_service")]
+use external_services::secrets_management::SecretManagementInterface;
 use masking::{PeekInterface, StrongSecret};
 use router_env::{instrument, tracing};
 
@@ -26,27 +26,27 @@ const API_KEY_EXPIRY_NAME: &str = "API_KEY_EXPIRY";
 #[cfg(feature = "email")]
 const API_KEY_EXPIRY_RUNNER: &str = "API_KEY_EXPIRY_WORKFLOW";
 
-#[cfg(feature = "kms")]
-use external_services::kms::decrypt::KmsDecrypt;
+#[cfg(feature = "encryption_service")]
+use external_services::secrets_management::decrypt::KmsDecrypt;
 
 static HASH_KEY: tokio::sync::OnceCell<StrongSecret<[u8; PlaintextApiKey::HASH_KEY_LEN]>> =
     tokio::sync::OnceCell::const_new();
 
 pub async fn get_hash_key(
     api_key_config: &settings::ApiKeys,
-    #[cfg(feature = "kms")] kms_client: &kms::KmsClient,
+    #[cfg(feature = "encryption_service")] secret_management_client: &dyn SecretManagementInterface,
 ) -> errors::RouterResult<&'static StrongSecret<[u8; PlaintextApiKey::HASH_KEY_LEN]>> {
     HASH_KEY
         .get_or_try_init(|| async {
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "encryption_service")]
             let hash_key = api_key_config
                 .kms_encrypted_hash_key
-                .decrypt_inner(kms_client)
+                .decrypt_inner(secret_management_client)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to KMS decrypt API key hashing key")?;
 
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "encryption_service"))]
             let hash_key = &api_key_config.hash_key;
 
             <[u8; PlaintextApiKey::HASH_KEY_LEN]>::try_from(
@@ -131,7 +131,7 @@ impl PlaintextApiKey {
 #[instrument(skip_all)]
 pub async fn create_api_key(
     state: AppState,
-    #[cfg(feature = "kms")] kms_client: &kms::KmsClient,
+    #[cfg(feature = "encryption_service")] secret_management_client: &dyn SecretManagementInterface,
     api_key: api::CreateApiKeyRequest,
     merchant_id: String,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
@@ -151,8 +151,8 @@ pub async fn create_api_key(
 
     let hash_key = get_hash_key(
         api_key_config,
-        #[cfg(feature = "kms")]
-        kms_client,
+        #[cfg(feature = "encryption_service")]
+        secret_management_client,
     )
     .await?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
@@ -558,8 +558,12 @@ mod tests {
         let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
         let hash_key = get_hash_key(
             &settings.api_keys,
-            #[cfg(feature = "kms")]
-            external_services::kms::get_kms_client(&settings.kms).await,
+            #[cfg(feature = "encryption_service")]
+            external_services::encryption_management::get_encryption_management_client(
+                &settings.encryption_management,
+            )
+            .await
+            .unwrap(),
         )
         .await
         .unwrap();
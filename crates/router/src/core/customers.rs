@@ -11,7 +11,6 @@ use crate::{
         errors::{self, StorageErrorExt},
         payment_methods::cards,
     },
-    pii::PeekInterface,
     routes::{metrics, AppState},
     services,
     types::{
@@ -58,7 +57,7 @@ pub async fn create_customer(
         Ok(_) => Err(errors::CustomersErrorResponse::CustomerAlreadyExists).into_report(),
     }?;
 
-    let key = key_store.key.get_inner().peek();
+    let key = &key_store.key_ring();
     let address = if let Some(addr) = &customer_data.address {
         let customer_address: api_models::payments::AddressDetails = addr.clone();
 
@@ -108,6 +107,7 @@ pub async fn create_customer(
             address_id: address.clone().map(|addr| addr.address_id),
             created_at: common_utils::date_time::now(),
             modified_at: common_utils::date_time::now(),
+            default_payment_method_id: None,
         })
     }
     .await
@@ -218,7 +218,7 @@ pub async fn delete_customer(
                         &state,
                         &req.customer_id,
                         &merchant_account.merchant_id,
-                        &pm.payment_method_id,
+                        pm.get_locker_id(),
                     )
                     .await
                     .switch()?;
@@ -242,7 +242,7 @@ pub async fn delete_customer(
         }
     };
 
-    let key = key_store.key.get_inner().peek();
+    let key = &key_store.key_ring();
 
     let redacted_encrypted_value: Encryptable<masking::Secret<_>> =
         Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
@@ -336,7 +336,7 @@ pub async fn update_customer(
         .await
         .switch()?;
 
-    let key = key_store.key.get_inner().peek();
+    let key = &key_store.key_ring();
 
     let address = if let Some(addr) = &update_customer.address {
         match customer.address_id {
@@ -1,21 +1,27 @@
 use api_models::{disputes as dispute_models, files as files_api_models};
-use common_utils::ext_traits::ValueExt;
+use common_utils::{
+    date_time,
+    ext_traits::{StringExt, ValueExt},
+};
 use error_stack::{IntoReport, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
+use time::{Duration, PrimitiveDateTime};
 pub mod transformers;
 
 use super::{
-    errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
-    metrics,
+    errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+    metrics, webhooks,
 };
 use crate::{
     core::{files, payments, utils as core_utils},
+    db::StorageInterface,
     routes::AppState,
     services,
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
@@ -23,6 +29,9 @@ use crate::{
     utils,
 };
 
+const DISPUTE_DEADLINE_RUNNER: &str = "DISPUTE_DEADLINE_WORKFLOW";
+const DISPUTE_DEADLINE_TASK: &str = "DISPUTE_DEADLINE";
+
 #[instrument(skip(state))]
 pub async fn retrieve_dispute(
     state: AppState,
@@ -324,19 +333,18 @@ pub async fn submit_evidence(
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
-pub async fn attach_evidence(
-    state: AppState,
-    merchant_account: domain::MerchantAccount,
-    key_store: domain::MerchantKeyStore,
-    attach_evidence_request: api::AttachEvidenceRequest,
-) -> RouterResponse<files_api_models::CreateFileResponse> {
-    let db = &state.store;
-    let dispute_id = attach_evidence_request
-        .create_file_request
+/// Fetches the dispute the evidence is attached to, ensuring that it still accepts evidence.
+async fn get_dispute_accepting_evidence(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    create_file_request: &api::CreateFileRequest,
+) -> RouterResult<storage::Dispute> {
+    let dispute_id = create_file_request
         .dispute_id
         .clone()
         .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
-    let dispute = db
+    let dispute = state
+        .store
         .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -359,6 +367,38 @@ pub async fn attach_evidence(
             })
         },
     )?;
+    Ok(dispute)
+}
+
+pub async fn attach_evidence(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    attach_evidence_request: api::AttachEvidenceRequest,
+) -> RouterResponse<files_api_models::CreateFileResponse> {
+    let db = &state.store;
+    let dispute = match get_dispute_accepting_evidence(
+        &state,
+        &merchant_account,
+        &attach_evidence_request.create_file_request,
+    )
+    .await
+    {
+        Ok(dispute) => dispute,
+        Err(error) => {
+            // The file content was streamed to the file storage while the request was read
+            let file_key = files::helpers::get_file_key(
+                &merchant_account.merchant_id,
+                &attach_evidence_request.create_file_request.file_id,
+            );
+            files::helpers::delete_file(&state, file_key)
+                .await
+                .map_err(|error| logger::warn!(?error, "Failed to delete the uploaded file"))
+                .ok();
+            return Err(error);
+        }
+    };
+    let dispute_id = dispute.dispute_id.clone();
     let create_file_response = files::files_create_core(
         state.clone(),
         merchant_account,
@@ -423,3 +463,469 @@ pub async fn retrieve_dispute_evidence(
         transformers::get_dispute_evidence_vec(&state, merchant_account, dispute_evidence).await?;
     Ok(services::ApplicationResponse::Json(dispute_evidence_vec))
 }
+
+/// Schedules the task acting on the evidence deadline of an open dispute, or reschedules it if the
+/// deadline of the dispute was updated by the connector
+#[instrument(skip_all)]
+pub async fn schedule_dispute_deadline_task(
+    db: &dyn StorageInterface,
+    dispute: &storage::Dispute,
+) -> Result<(), errors::ProcessTrackerError> {
+    let challenge_required_by = match dispute.challenge_required_by {
+        Some(challenge_required_by) if is_dispute_open(dispute) => challenge_required_by,
+        _ => return Ok(()),
+    };
+    let config = get_dispute_deadline_config(db, &dispute.merchant_id).await;
+    let current_time = date_time::now();
+    // A deadline which already passed is acted upon right away
+    let schedule_time =
+        get_next_dispute_deadline_checkpoint(&config, challenge_required_by, current_time)
+            .unwrap_or(current_time);
+
+    let process_tracker_id = get_dispute_deadline_process_tracker_id(dispute);
+    match db.find_process_by_id(&process_tracker_id).await? {
+        Some(deadline_process) => {
+            deadline_process
+                .reset(db.as_scheduler(), schedule_time)
+                .await
+        }
+        None => {
+            let tracking_data = storage::DisputeDeadlineTrackingData {
+                dispute_id: dispute.dispute_id.clone(),
+                merchant_id: dispute.merchant_id.clone(),
+            };
+            let tracking_data = serde_json::to_value(tracking_data)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize dispute deadline tracking data")?;
+
+            let process_tracker_entry = storage::ProcessTrackerNew {
+                id: process_tracker_id,
+                name: Some(String::from(DISPUTE_DEADLINE_TASK)),
+                tag: vec![String::from("DISPUTE")],
+                runner: Some(String::from(DISPUTE_DEADLINE_RUNNER)),
+                retry_count: 0,
+                schedule_time: Some(schedule_time),
+                rule: String::new(),
+                tracking_data,
+                business_status: String::from("Pending"),
+                status: storage_enums::ProcessTrackerStatus::New,
+                event: vec![],
+                created_at: current_time,
+                updated_at: current_time,
+            };
+            db.insert_process(process_tracker_entry).await?;
+            Ok(())
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn start_dispute_deadline_workflow(
+    state: &AppState,
+    deadline_process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::DisputeDeadlineTrackingData = deadline_process
+        .tracking_data
+        .clone()
+        .parse_value("DisputeDeadlineTrackingData")?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await?;
+
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(
+            &tracking_data.merchant_id,
+            &tracking_data.dispute_id,
+        )
+        .await?;
+
+    let challenge_required_by = match dispute.challenge_required_by {
+        Some(challenge_required_by) if is_dispute_open(&dispute) => challenge_required_by,
+        _ => {
+            return deadline_process
+                .clone()
+                .finish_with_status(db.as_scheduler(), "COMPLETED_BY_PT".to_string())
+                .await
+        }
+    };
+    let config = get_dispute_deadline_config(db, &dispute.merchant_id).await;
+    let current_time = date_time::now();
+
+    if current_time >= challenge_required_by {
+        metrics::DISPUTE_DEADLINE_MISSED_METRIC.add(&metrics::CONTEXT, 1, &[]);
+        let dispute = db
+            .update_dispute(
+                dispute,
+                storage::DisputeUpdate::DeadlineMissedUpdate {
+                    is_deadline_missed: true,
+                },
+            )
+            .await?;
+
+        let primary_object_id = dispute.dispute_id.clone();
+        trigger_dispute_webhook(
+            state,
+            merchant_account,
+            dispute,
+            storage_enums::EventType::DisputeDeadlineMissed,
+            primary_object_id,
+        )
+        .await
+        .map_err(|error| logger::warn!(dispute_outgoing_webhook_error=?error))
+        .ok();
+
+        return deadline_process
+            .clone()
+            .finish_with_status(db.as_scheduler(), "DEADLINE_MISSED".to_string())
+            .await;
+    }
+
+    let auto_action_time = challenge_required_by
+        .checked_sub(Duration::seconds(config.auto_action_offset))
+        .unwrap_or(challenge_required_by);
+    if current_time >= auto_action_time {
+        match take_dispute_deadline_action(state, &merchant_account, &key_store, &dispute, &config)
+            .await
+        {
+            Ok(Some(business_status)) => {
+                return deadline_process
+                    .clone()
+                    .finish_with_status(db.as_scheduler(), business_status)
+                    .await
+            }
+            Ok(None) => {}
+            Err(error) => {
+                logger::error!(dispute_deadline_action_error=?error);
+                let retry_time = current_time
+                    .checked_add(Duration::seconds(config.auto_action_retry_interval))
+                    .filter(|retry_time| *retry_time < challenge_required_by);
+                // Once no retry fits before the deadline, the merchant is reminded of the deadline
+                // and can still act on the dispute
+                if let Some(retry_time) = retry_time {
+                    return deadline_process
+                        .clone()
+                        .retry(db.as_scheduler(), retry_time)
+                        .await;
+                }
+            }
+        }
+    }
+
+    // Every checkpoint gets its own reminder event, so that the reminders are not deduplicated
+    let reminder_time = deadline_process.schedule_time.unwrap_or(current_time);
+    let primary_object_id = format!(
+        "{}_{}",
+        dispute.dispute_id,
+        reminder_time.assume_utc().unix_timestamp()
+    );
+    trigger_dispute_webhook(
+        state,
+        merchant_account,
+        dispute,
+        storage_enums::EventType::DisputeEvidenceDue,
+        primary_object_id,
+    )
+    .await
+    .map_err(|error| logger::warn!(dispute_outgoing_webhook_error=?error))
+    .ok();
+
+    let next_schedule_time =
+        get_next_dispute_deadline_checkpoint(&config, challenge_required_by, current_time)
+            .unwrap_or(challenge_required_by);
+    deadline_process
+        .clone()
+        .reset(db.as_scheduler(), next_schedule_time)
+        .await
+}
+
+/// Submits the evidence attached to the dispute, or accepts the dispute if no evidence was
+/// attached and the dispute amount is within the auto acceptance threshold of the merchant.
+/// Returns the business status of the deadline task if an action was taken.
+async fn take_dispute_deadline_action(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+    config: &disputes::DisputeDeadlineConfig,
+) -> RouterResult<Option<String>> {
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+
+    if let Some(submit_evidence_request) =
+        transformers::get_submit_evidence_request_from_attached_evidence(
+            dispute.dispute_id.clone(),
+            dispute_evidence,
+        )
+    {
+        Box::pin(submit_evidence(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            submit_evidence_request,
+        ))
+        .await?;
+        metrics::DISPUTE_EVIDENCE_AUTO_SUBMITTED_METRIC.add(&metrics::CONTEXT, 1, &[]);
+        return Ok(Some("EVIDENCE_SUBMITTED".to_string()));
+    }
+
+    let is_auto_accept_eligible =
+        is_dispute_auto_accept_eligible(config, &dispute.currency, &dispute.amount)
+            .map_err(|error| {
+                logger::error!(
+                    dispute_auto_accept_error=?error,
+                    dispute_id=%dispute.dispute_id,
+                    "Dispute can not be accepted automatically"
+                )
+            })
+            .unwrap_or(false);
+    if is_auto_accept_eligible {
+        Box::pin(accept_dispute(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            disputes::DisputeId {
+                dispute_id: dispute.dispute_id.clone(),
+            },
+        ))
+        .await?;
+        metrics::DISPUTE_AUTO_ACCEPTED_METRIC.add(&metrics::CONTEXT, 1, &[]);
+        return Ok(Some("DISPUTE_ACCEPTED".to_string()));
+    }
+
+    Ok(None)
+}
+
+/// Compares the dispute amount with the auto acceptance threshold of the dispute currency. Fails
+/// if the dispute currency or amount are not valid.
+fn is_dispute_auto_accept_eligible(
+    config: &disputes::DisputeDeadlineConfig,
+    currency: &str,
+    amount: &str,
+) -> RouterResult<bool> {
+    if config.auto_accept_amount_thresholds.is_empty() {
+        return Ok(false);
+    }
+    let currency = currency
+        .parse::<storage_enums::Currency>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Invalid dispute currency {currency}"))?;
+
+    match config.auto_accept_amount_thresholds.get(&currency) {
+        Some(threshold) => {
+            let amount = amount
+                .parse::<i64>()
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| format!("Invalid dispute amount {amount}"))?;
+            Ok(amount <= *threshold)
+        }
+        None => Ok(false),
+    }
+}
+
+async fn trigger_dispute_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    dispute: storage::Dispute,
+    event_type: storage_enums::EventType,
+    primary_object_id: String,
+) -> RouterResult<()> {
+    let profile_id = match dispute.profile_id.clone() {
+        Some(profile_id) => Some(profile_id),
+        None => {
+            state
+                .store
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &dispute.payment_id,
+                    &merchant_account.merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::PaymentNotFound)?
+                .profile_id
+        }
+    };
+    let profile_id = match profile_id {
+        Some(profile_id) => profile_id,
+        None => {
+            logger::info!(
+                "Skipping {event_type} webhook as dispute {} has no business profile",
+                dispute.dispute_id
+            );
+            return Ok(());
+        }
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        })?;
+
+    Box::pin(
+        webhooks::create_event_and_trigger_appropriate_outgoing_webhook(
+            state.clone(),
+            merchant_account,
+            business_profile,
+            event_type,
+            storage_enums::EventClass::Disputes,
+            None,
+            primary_object_id,
+            storage_enums::EventObjectType::DisputeDetails,
+            api::OutgoingWebhookContent::DisputeDetails(Box::new(
+                dispute_models::DisputeResponse::foreign_from(dispute),
+            )),
+            None,
+        ),
+    )
+    .await
+}
+
+async fn get_dispute_deadline_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> disputes::DisputeDeadlineConfig {
+    db.find_config_by_key(&format!("dispute_deadline_config_{merchant_id}"))
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("DisputeDeadlineConfig")
+                .change_context(errors::StorageError::DeserializationFailed)
+        })
+        .unwrap_or_else(|error| {
+            logger::info!("Dispute deadline config error: {}", error);
+            disputes::DisputeDeadlineConfig::default()
+        })
+}
+
+fn get_dispute_deadline_process_tracker_id(dispute: &storage::Dispute) -> String {
+    pt_utils::get_process_tracker_id(
+        DISPUTE_DEADLINE_RUNNER,
+        DISPUTE_DEADLINE_TASK,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    )
+}
+
+fn is_dispute_open(dispute: &storage::Dispute) -> bool {
+    dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+        && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened
+}
+
+/// Returns the earliest point in time after `current_time` at which the deadline task has to run,
+/// i.e. a reminder, the automatic action or the deadline itself. Returns `None` if the deadline
+/// already passed.
+pub fn get_next_dispute_deadline_checkpoint(
+    config: &disputes::DisputeDeadlineConfig,
+    challenge_required_by: PrimitiveDateTime,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    config
+        .reminder_offsets
+        .iter()
+        .chain([config.auto_action_offset, 0].iter())
+        .filter_map(|offset| challenge_required_by.checked_sub(Duration::seconds(*offset)))
+        .filter(|checkpoint| *checkpoint > current_time)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_dispute_auto_accept_threshold_is_per_currency() {
+        let config = disputes::DisputeDeadlineConfig {
+            auto_accept_amount_thresholds: [(storage_enums::Currency::USD, 1000)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+
+        assert!(is_dispute_auto_accept_eligible(&config, "USD", "1000").unwrap());
+        assert!(!is_dispute_auto_accept_eligible(&config, "USD", "1001").unwrap());
+        // A threshold only applies to disputes in its own currency
+        assert!(!is_dispute_auto_accept_eligible(&config, "JPY", "500").unwrap());
+        assert!(is_dispute_auto_accept_eligible(&config, "USD", "ten dollars").is_err());
+        assert!(is_dispute_auto_accept_eligible(&config, "dollars", "500").is_err());
+        assert!(!is_dispute_auto_accept_eligible(
+            &disputes::DisputeDeadlineConfig::default(),
+            "USD",
+            "1"
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_get_next_dispute_deadline_checkpoint() {
+        let config = disputes::DisputeDeadlineConfig::default();
+        let challenge_required_by = datetime!(2023-11-20 12:00);
+
+        // Before the first reminder
+        assert_eq!(
+            get_next_dispute_deadline_checkpoint(
+                &config,
+                challenge_required_by,
+                datetime!(2023-11-10 00:00)
+            ),
+            Some(datetime!(2023-11-13 12:00))
+        );
+        // Between the reminders
+        assert_eq!(
+            get_next_dispute_deadline_checkpoint(
+                &config,
+                challenge_required_by,
+                datetime!(2023-11-13 12:00)
+            ),
+            Some(datetime!(2023-11-17 12:00))
+        );
+        // After the last reminder, the automatic action is next
+        assert_eq!(
+            get_next_dispute_deadline_checkpoint(
+                &config,
+                challenge_required_by,
+                datetime!(2023-11-19 18:00)
+            ),
+            Some(datetime!(2023-11-20 00:00))
+        );
+        // After the automatic action, the deadline itself is next
+        assert_eq!(
+            get_next_dispute_deadline_checkpoint(
+                &config,
+                challenge_required_by,
+                datetime!(2023-11-20 06:00)
+            ),
+            Some(challenge_required_by)
+        );
+        // The deadline already passed
+        assert_eq!(
+            get_next_dispute_deadline_checkpoint(
+                &config,
+                challenge_required_by,
+                challenge_required_by
+            ),
+            None
+        );
+    }
+}
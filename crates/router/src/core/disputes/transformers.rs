@@ -202,6 +202,54 @@ pub fn update_dispute_evidence(
     }
 }
 
+/// Builds a submit evidence request out of the evidence files attached to the dispute, returns
+/// `None` if no evidence was attached to the dispute
+pub fn get_submit_evidence_request_from_attached_evidence(
+    dispute_id: String,
+    dispute_evidence: DisputeEvidence,
+) -> Option<api_models::disputes::SubmitEvidenceRequest> {
+    let DisputeEvidence {
+        cancellation_policy,
+        customer_communication,
+        customer_signature,
+        receipt,
+        refund_policy,
+        service_documentation,
+        shipping_documentation,
+        invoice_showing_distinct_transactions,
+        recurring_transaction_agreement,
+        uncategorized_file,
+    } = dispute_evidence;
+    let is_evidence_attached = [
+        &cancellation_policy,
+        &customer_communication,
+        &customer_signature,
+        &receipt,
+        &refund_policy,
+        &service_documentation,
+        &shipping_documentation,
+        &invoice_showing_distinct_transactions,
+        &recurring_transaction_agreement,
+        &uncategorized_file,
+    ]
+    .iter()
+    .any(|evidence| evidence.is_some());
+    is_evidence_attached.then_some(api_models::disputes::SubmitEvidenceRequest {
+        dispute_id,
+        cancellation_policy,
+        customer_communication,
+        customer_signature,
+        receipt,
+        refund_policy,
+        service_documentation,
+        shipping_documentation,
+        invoice_showing_distinct_transactions,
+        recurring_transaction_agreement,
+        uncategorized_file,
+        ..Default::default()
+    })
+}
+
 pub async fn get_dispute_evidence_block(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
@@ -212,6 +212,8 @@ pub enum VaultError {
     FetchPaymentMethodFailed,
     #[error("Failed to save payment method in vault")]
     SavePaymentMethodFailed,
+    #[error("The card fetched from the card vault has expired")]
+    CardExpired,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -238,6 +238,8 @@ pub enum ApiErrorResponse {
     CurrencyNotSupported { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_24", message = "Merchant connector account is configured with invalid {config}")]
     InvalidConnectorConfiguration { config: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_25", message = "Routing test cases failed")]
+    RoutingTestsFailed { data: Option<serde_json::Value> },
 }
 
 impl PTError for ApiErrorResponse {
@@ -270,6 +270,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::InvalidConnectorConfiguration {config} => {
                 AER::BadRequest(ApiError::new("IR", 24, format!("Merchant connector account is configured with invalid {config}"), None))
             }
+            Self::RoutingTestsFailed { data } => {
+                AER::BadRequest(ApiError::new("IR", 25, "Routing test cases failed", Some(Extra { data: data.clone(), ..Default::default()})))
+            }
         }
     }
 }
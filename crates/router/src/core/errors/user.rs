@@ -27,6 +27,8 @@ pub enum UserErrors {
     InvalidEmailError,
     #[error("DuplicateOrganizationId")]
     DuplicateOrganizationId,
+    #[error("LinkInvalid")]
+    LinkInvalid,
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -73,6 +75,9 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 "An Organization with the id already exists",
                 None,
             )),
+            Self::LinkInvalid => {
+                AER::Unauthorized(ApiError::new(sub_code, 22, "Invalid or expired link", None))
+            }
         }
     }
 }
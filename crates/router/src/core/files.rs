@@ -1,38 +1,47 @@
 pub mod helpers;
-#[cfg(feature = "s3")]
-pub mod s3_utils;
-
-#[cfg(not(feature = "s3"))]
-pub mod fs_utils;
 
 use api_models::files;
 use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
 
 use super::errors::{self, RouterResponse};
+
 use crate::{
-    consts,
     routes::AppState,
     services::{self, ApplicationResponse},
     types::{api, domain},
 };
 
+/// Creates the file metadata for a file whose content was already streamed to the file storage,
+/// deleting the file content if the file cannot be created.
 pub async fn files_create_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     create_file_request: api::CreateFileRequest,
 ) -> RouterResponse<files::CreateFileResponse> {
-    helpers::validate_file_upload(
-        &state,
-        merchant_account.clone(),
-        create_file_request.clone(),
-    )
-    .await?;
-    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
-    #[cfg(feature = "s3")]
-    let file_key = format!("{}/{}", merchant_account.merchant_id, file_id);
-    #[cfg(not(feature = "s3"))]
-    let file_key = format!("{}_{}", merchant_account.merchant_id, file_id);
+    let file_key =
+        helpers::get_file_key(&merchant_account.merchant_id, &create_file_request.file_id);
+    let result = create_file(&state, merchant_account, key_store, create_file_request).await;
+    if result.is_err() {
+        helpers::delete_file(&state, file_key)
+            .await
+            .map_err(|error| logger::warn!(?error, "Failed to delete the uploaded file"))
+            .ok();
+    }
+    result
+}
+
+async fn create_file(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    create_file_request: api::CreateFileRequest,
+) -> RouterResponse<files::CreateFileResponse> {
+    helpers::validate_file_upload(state, merchant_account.clone(), create_file_request.clone())
+        .await?;
+    let file_id = create_file_request.file_id.clone();
+    let file_key = helpers::get_file_key(&merchant_account.merchant_id, &file_id);
     let file_new = diesel_models::file::FileMetadataNew {
         file_id: file_id.clone(),
         merchant_id: merchant_account.merchant_id.clone(),
@@ -45,6 +54,7 @@ pub async fn files_create_core(
         connector_label: None,
         profile_id: None,
         merchant_connector_id: None,
+        checksum: Some(create_file_request.checksum.clone()),
     };
 
     let file_metadata_object = state
@@ -55,7 +65,7 @@ pub async fn files_create_core(
         .attach_printable("Unable to insert file_metadata")?;
     let (provider_file_id, file_upload_provider, profile_id, merchant_connector_id) =
         helpers::upload_and_get_provider_provider_file_id_profile_id(
-            &state,
+            state,
             &merchant_account,
             &key_store,
             &create_file_request,
@@ -114,26 +124,47 @@ pub async fn files_retrieve_core(
         .await
         .change_context(errors::ApiErrorResponse::FileNotFound)
         .attach_printable("Unable to retrieve file_metadata")?;
-    let (received_data, _provider_file_id) =
-        helpers::retrieve_file_and_provider_file_id_from_file_id(
-            &state,
-            Some(req.file_id),
-            &merchant_account,
-            &key_store,
-            api::FileDataRequired::Required,
-        )
-        .await?;
     let content_type = file_metadata_object
         .file_type
         .parse::<mime::Mime>()
         .into_report()
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to parse file content type")?;
-    Ok(ApplicationResponse::FileData((
-        received_data
-            .ok_or(errors::ApiErrorResponse::FileNotAvailable)
-            .into_report()
-            .attach_printable("File data not found")?,
-        content_type,
-    )))
+    match (
+        file_metadata_object.file_upload_provider,
+        file_metadata_object.provider_file_id.clone(),
+        file_metadata_object.available,
+    ) {
+        // Files kept in the file storage are streamed to the client as they are read
+        (Some(diesel_models::enums::FileUploadProvider::Router), Some(provider_file_id), true) => {
+            let file_stream = helpers::retrieve_file_stream(
+                &state,
+                provider_file_id,
+                file_metadata_object.checksum,
+            )
+            .await?;
+            Ok(ApplicationResponse::FileStream((
+                services::FileStreamData(file_stream),
+                content_type,
+            )))
+        }
+        _ => {
+            let (received_data, _provider_file_id) =
+                helpers::retrieve_file_and_provider_file_id_from_file_id(
+                    &state,
+                    Some(req.file_id),
+                    &merchant_account,
+                    &key_store,
+                    api::FileDataRequired::Required,
+                )
+                .await?;
+            Ok(ApplicationResponse::FileData((
+                received_data
+                    .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+                    .into_report()
+                    .attach_printable("File data not found")?,
+                content_type,
+            )))
+        }
+    }
 }
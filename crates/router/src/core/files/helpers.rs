@@ -1,18 +1,26 @@
 use actix_multipart::Field;
 use common_utils::errors::CustomResult;
-use error_stack::{IntoReport, ResultExt};
-use futures::TryStreamExt;
+use error_stack::{report, IntoReport, ResultExt};
+use external_services::file_storage;
+use futures::{SinkExt, StreamExt, TryStreamExt};
+use router_env::logger;
 
 use crate::{
     core::{
         errors::{self, StorageErrorExt},
-        files, payments, utils,
+        payments, utils,
     },
     routes::AppState,
     services,
     types::{self, api, domain, transformers::ForeignTryFrom},
 };
 
+/// Number of file chunks buffered between the multipart request and the file storage upload.
+const UPLOAD_CHANNEL_BUFFER_SIZE: usize = 8;
+
+/// Number of bytes from the start of the file used to sniff its content type.
+const FILE_HEADER_SIZE: usize = 8192;
+
 pub async fn read_string(field: &mut Field) -> Option<String> {
     let bytes = field.try_next().await;
     if let Ok(Some(bytes)) = bytes {
@@ -30,35 +38,222 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     }
 }
 
+/// Details of a file computed while its content is streamed to the file storage.
+#[derive(Debug)]
+pub struct UploadedFileDetails {
+    pub file_size: i32,
+    pub file_type: mime::Mime,
+    /// Hex-encoded SHA-256 checksum of the file content
+    pub checksum: String,
+}
+
+/// Streams the file content of a multipart field to the file storage under the given key.
+///
+/// The file is rejected as soon as it exceeds `max_file_size`, and anything already stored for a
+/// rejected file is deleted. The size, content type and checksum of the file are computed along
+/// the way.
+pub async fn upload_file_content(
+    state: &AppState,
+    file_key: String,
+    field: Field,
+    max_file_size: usize,
+) -> CustomResult<UploadedFileDetails, errors::ApiErrorResponse> {
+    let (sender, receiver) = futures::channel::mpsc::channel(UPLOAD_CHANNEL_BUFFER_SIZE);
+    let (upload_result, read_result) = futures::join!(
+        upload_file(state, file_key.clone(), receiver.boxed()),
+        read_file_content(field, max_file_size, sender)
+    );
+
+    match (read_result, upload_result) {
+        (Ok(file_details), Ok(())) => Ok(file_details),
+        (Err(error), _) | (Ok(_), Err(error)) => {
+            delete_file(state, file_key)
+                .await
+                .map_err(|delete_error| {
+                    logger::warn!(?delete_error, "Failed to delete partially uploaded file")
+                })
+                .ok();
+            Err(error)
+        }
+    }
+}
+
+/// Reads the file content of a multipart field chunk by chunk and forwards it to `sender`.
+///
+/// If the field cannot be read or the file is too large, an error is forwarded instead so that
+/// the file storage does not store a truncated file.
+async fn read_file_content(
+    field: Field,
+    max_file_size: usize,
+    mut sender: futures::channel::mpsc::Sender<
+        CustomResult<bytes::Bytes, file_storage::FileStorageError>,
+    >,
+) -> CustomResult<UploadedFileDetails, errors::ApiErrorResponse> {
+    let mut file_size = 0;
+    let mut file_header = Vec::new();
+    let mut digest = ring::digest::Context::new(&ring::digest::SHA256);
+    let mut stream = field.into_stream();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = match chunk {
+            Ok(chunk) if file_size + chunk.len() <= max_file_size => Ok(chunk),
+            Ok(_) => Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!("file size exceeds the maximum of {max_file_size} bytes"),
+            })),
+            Err(err) => Err(report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(format!("File parsing error: {err}"))),
+        };
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(error) => {
+                sender
+                    .send(Err(report!(file_storage::FileStorageError::UploadFailed)
+                        .attach_printable("Failed to read the file from the request")))
+                    .await
+                    .ok();
+                return Err(error);
+            }
+        };
+
+        file_size += chunk.len();
+        digest.update(&chunk);
+        if file_header.len() < FILE_HEADER_SIZE {
+            let header_len = chunk.len().min(FILE_HEADER_SIZE - file_header.len());
+            file_header.extend_from_slice(&chunk[..header_len]);
+        }
+
+        sender
+            .send(Ok(chunk))
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("File storage stopped reading the file")?;
+    }
+    // Closing the channel marks the end of the file for the file storage
+    drop(sender);
+
+    let file_size = file_size
+        .try_into()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("File size error")?;
+    // Check if empty file and throw error
+    if file_size <= 0 {
+        Err(errors::ApiErrorResponse::MissingFile)
+            .into_report()
+            .attach_printable("Missing / Invalid file in the request")?
+    }
+    Ok(UploadedFileDetails {
+        file_size,
+        file_type: get_file_type(&file_header)?,
+        checksum: hex::encode(digest.finish()),
+    })
+}
+
+/// Returns the content type of a file, sniffed from the start of the file content.
+pub fn get_file_type(file_header: &[u8]) -> CustomResult<mime::Mime, errors::ApiErrorResponse> {
+    // Get file mime type using 'infer'
+    let kind = infer::get(file_header).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
+    kind.mime_type()
+        .parse::<mime::Mime>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::MissingFileContentType)
+        .attach_printable("File content type error")
+}
+
+/// Returns the key under which a file is stored, namespaced by the merchant.
+pub fn get_file_key(merchant_id: &str, file_id: &str) -> String {
+    format!("{merchant_id}/{file_id}")
+}
+
+pub fn get_file_checksum(file: &[u8]) -> String {
+    hex::encode(ring::digest::digest(&ring::digest::SHA256, file))
+}
+
 pub async fn upload_file(
-    #[cfg(feature = "s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
-    file: Vec<u8>,
+    file: file_storage::FileStream,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    #[cfg(feature = "s3")]
-    return files::s3_utils::upload_file_to_s3(state, file_key, file).await;
-    #[cfg(not(feature = "s3"))]
-    return files::fs_utils::save_file_to_fs(file_key, file);
+    state
+        .file_storage_client
+        .upload_file(&file_key, file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload file")
 }
 
 pub async fn delete_file(
-    #[cfg(feature = "s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    #[cfg(feature = "s3")]
-    return files::s3_utils::delete_file_from_s3(state, file_key).await;
-    #[cfg(not(feature = "s3"))]
-    return files::fs_utils::delete_file_from_fs(file_key);
+    state
+        .file_storage_client
+        .delete_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete file")
+}
+
+/// Returns a stream of the contents of a file stored in the file storage.
+///
+/// If a checksum is provided, it is verified as the file is streamed, and the stream fails at its
+/// end if the checksum does not match.
+pub async fn retrieve_file_stream(
+    state: &AppState,
+    file_key: String,
+    checksum: Option<String>,
+) -> CustomResult<file_storage::FileStream, errors::ApiErrorResponse> {
+    let file_stream = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve file")?;
+    // Files uploaded before checksums were recorded do not have one to verify against
+    Ok(match checksum {
+        Some(checksum) => verify_file_checksum(file_stream, checksum),
+        None => file_stream,
+    })
+}
+
+fn verify_file_checksum(
+    file_stream: file_storage::FileStream,
+    checksum: String,
+) -> file_storage::FileStream {
+    let digest = ring::digest::Context::new(&ring::digest::SHA256);
+    futures::stream::try_unfold(
+        (file_stream, digest),
+        move |(mut file_stream, mut digest)| {
+            let checksum = checksum.clone();
+            async move {
+                match file_stream.try_next().await? {
+                    Some(chunk) => {
+                        digest.update(&chunk);
+                        Ok(Some((chunk, (file_stream, digest))))
+                    }
+                    None if hex::encode(digest.finish()) == checksum => Ok(None),
+                    None => Err(report!(file_storage::FileStorageError::RetrieveFailed)
+                        .attach_printable("File checksum mismatch")),
+                }
+            }
+        },
+    )
+    .boxed()
 }
 
+/// Retrieves the whole content of a file stored in the file storage, for forwarding it to
+/// connectors.
 pub async fn retrieve_file(
-    #[cfg(feature = "s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
+    checksum: Option<String>,
 ) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
-    #[cfg(feature = "s3")]
-    return files::s3_utils::retrieve_file_from_s3(state, file_key).await;
-    #[cfg(not(feature = "s3"))]
-    return files::fs_utils::retrieve_file_from_fs(file_key);
+    let file_stream = retrieve_file_stream(state, file_key, checksum).await?;
+    file_storage::collect_file_stream(file_stream)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read file")
 }
 
 pub async fn validate_file_upload(
@@ -66,6 +261,19 @@ pub async fn validate_file_upload(
     merchant_account: domain::MerchantAccount,
     create_file_request: api::CreateFileRequest,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
+    if !create_file_request
+        .purpose
+        .allowed_content_types()
+        .contains(&create_file_request.file_type.essence_str())
+    {
+        Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: format!(
+                "file type {} is not allowed for {} uploads",
+                create_file_request.file_type, create_file_request.purpose
+            ),
+        })
+        .into_report()?
+    }
     //File Validation based on the purpose of file upload
     match create_file_request.purpose {
         api::FilePurpose::DisputeEvidence => {
@@ -133,12 +341,7 @@ pub async fn delete_file_using_file_id(
     };
     match provider {
         diesel_models::enums::FileUploadProvider::Router => {
-            delete_file(
-                #[cfg(feature = "s3")]
-                state,
-                provider_file_id,
-            )
-            .await
+            delete_file(state, provider_file_id).await
         }
         _ => Err(errors::ApiErrorResponse::FileProviderNotSupported {
             message: "Not Supported because provider is not Router".to_string(),
@@ -235,9 +438,9 @@ pub async fn retrieve_file_and_provider_file_id_from_file_id(
                 diesel_models::enums::FileUploadProvider::Router => Ok((
                     Some(
                         retrieve_file(
-                            #[cfg(feature = "s3")]
                             state,
                             provider_file_id.clone(),
+                            file_metadata_object.checksum.clone(),
                         )
                         .await?,
                     ),
@@ -263,7 +466,7 @@ pub async fn retrieve_file_and_provider_file_id_from_file_id(
     }
 }
 
-//Upload file to connector if it supports / store it in S3 and return file_upload_provider, provider_file_id accordingly
+//Upload file to connector if it supports / keep it in the file storage and return file_upload_provider, provider_file_id accordingly
 pub async fn upload_and_get_provider_provider_file_id_profile_id(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
@@ -297,6 +500,14 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 dispute.merchant_connector_id.clone(),
             )?;
             if connector_data.connector_name.supports_file_storage_module() {
+                // Connectors expect the whole file in the request, so it is read back from the
+                // file storage it was streamed to, and removed from it once handed over
+                let file = retrieve_file(
+                    state,
+                    file_key.clone(),
+                    Some(create_file_request.checksum.clone()),
+                )
+                .await?;
                 let payment_intent = state
                     .store
                     .find_payment_intent_by_payment_id_merchant_id(
@@ -329,8 +540,9 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                     merchant_account,
                     key_store,
                     create_file_request,
+                    file,
                     &dispute.connector,
-                    file_key,
+                    file_key.clone(),
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -355,6 +567,7 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                         reason: err.reason,
                     }
                 })?;
+                delete_file(state, file_key).await?;
                 Ok((
                     upload_file_response.provider_file_id,
                     api_models::enums::FileUploadProvider::foreign_try_from(
@@ -364,13 +577,6 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                     payment_attempt.merchant_connector_id,
                 ))
             } else {
-                upload_file(
-                    #[cfg(feature = "s3")]
-                    state,
-                    file_key.clone(),
-                    create_file_request.file.clone(),
-                )
-                .await?;
                 Ok((
                     file_key,
                     api_models::enums::FileUploadProvider::Router,
@@ -32,6 +32,17 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(DISPUTE_EVIDENCE_AUTO_SUBMITTED_METRIC, GLOBAL_METER); // No. of disputes for which attached evidence was submitted before the deadline by the scheduler
+counter_metric!(DISPUTE_AUTO_ACCEPTED_METRIC, GLOBAL_METER); // No. of low value disputes accepted by the scheduler
+counter_metric!(DISPUTE_DEADLINE_MISSED_METRIC, GLOBAL_METER); // No. of disputes whose evidence deadline passed without any action
+
+counter_metric!(MERCHANT_KEY_ROTATED_METRIC, GLOBAL_METER); // No. of merchant key rotations triggered
+counter_metric!(MERCHANT_KEY_REENCRYPTED_RECORDS_METRIC, GLOBAL_METER); // No. of records re-encrypted with the rotated merchant key
+counter_metric!(MERCHANT_KEY_RETIRED_METRIC, GLOBAL_METER); // No. of merchant keys retired after re-encrypting the data of the merchant
+
+counter_metric!(CARD_ACCOUNT_UPDATER_SUBMITTED_CARDS_METRIC, GLOBAL_METER); // No. of expiring saved cards submitted to the account updater
+counter_metric!(CARD_ACCOUNT_UPDATER_UPDATED_CARDS_METRIC, GLOBAL_METER); // No. of saved cards updated with a new expiry or card number reported by the issuer
+counter_metric!(CARD_ACCOUNT_UPDATER_CLOSED_ACCOUNTS_METRIC, GLOBAL_METER); // No. of saved cards removed as the account was reported closed by the issuer
 
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
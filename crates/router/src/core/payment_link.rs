@@ -1,21 +1,33 @@
+mod locale;
+
 use api_models::admin as admin_types;
 use common_utils::{
     consts::{
-        DEFAULT_BACKGROUND_COLOR, DEFAULT_MERCHANT_LOGO, DEFAULT_PRODUCT_IMG, DEFAULT_SDK_THEME,
+        DEFAULT_BACKGROUND_COLOR, DEFAULT_FONT_FAMILY, DEFAULT_MERCHANT_LOGO, DEFAULT_PRODUCT_IMG,
+        DEFAULT_SDK_THEME,
     },
     ext_traits::ValueExt,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::{PeekInterface, Secret};
 
-use super::errors::{self, RouterResult, StorageErrorExt};
+use super::{
+    errors::{self, RouterResult, StorageErrorExt},
+    payment_methods::Oss,
+    payments,
+};
 use crate::{
     core::payments::helpers,
+    db::StorageInterface,
     errors::RouterResponse,
     routes::AppState,
     services,
-    types::{domain, storage::enums as storage_enums, transformers::ForeignFrom},
-    utils::OptionExt,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+    utils::{self, OptionExt},
 };
 
 pub async fn retrieve_payment_link(
@@ -36,8 +48,10 @@ pub async fn retrieve_payment_link(
 pub async fn intiate_payment_link_flow(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
     merchant_id: String,
     payment_id: String,
+    accept_language: Option<String>,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let db = &*state.store;
     let payment_intent = db
@@ -51,36 +65,105 @@ pub async fn intiate_payment_link_flow(
 
     let payment_link_id = payment_intent
         .payment_link_id
+        .clone()
         .get_required_value("payment_link_id")
         .change_context(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
-    helpers::validate_payment_status_against_not_allowed_statuses(
-        &payment_intent.status,
-        &[
-            storage_enums::IntentStatus::Cancelled,
-            storage_enums::IntentStatus::Succeeded,
-            storage_enums::IntentStatus::Processing,
-            storage_enums::IntentStatus::RequiresCapture,
-            storage_enums::IntentStatus::RequiresMerchantAction,
-        ],
-        "create payment link",
-    )?;
-
     let payment_link = db
         .find_payment_link_by_payment_link_id(&payment_link_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
-    let payment_link_config = merchant_account
-        .payment_link_config
+    let payment_link_config =
+        get_payment_link_config(db, &merchant_account, payment_intent.profile_id.as_ref()).await?;
+
+    let (locale, translations) =
+        locale::get_locale_from_accept_language(accept_language.as_deref());
+
+    let merchant_name = payment_link
+        .custom_merchant_name
+        .clone()
+        .or_else(|| {
+            payment_link_config
+                .as_ref()
+                .and_then(|pl_config| pl_config.display_name.clone())
+        })
+        .unwrap_or(
+            merchant_account
+                .merchant_name
+                .clone()
+                .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+                .unwrap_or_default(),
+        );
+
+    let merchant_logo = payment_link_config
+        .clone()
         .map(|pl_config| {
-            serde_json::from_value::<admin_types::PaymentLinkConfig>(pl_config)
-                .into_report()
-                .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                    field_name: "payment_link_config",
-                })
+            pl_config
+                .merchant_logo
+                .unwrap_or(DEFAULT_MERCHANT_LOGO.to_string())
         })
-        .transpose()?;
+        .unwrap_or_default();
+
+    let (support_email, support_phone) = payment_link_config
+        .as_ref()
+        .and_then(|pl_config| pl_config.support_contact.clone())
+        .map(|support_contact| (support_contact.email, support_contact.phone))
+        .unwrap_or_default();
+
+    let css_script = get_color_scheme_css(
+        payment_link_config.clone(),
+        DEFAULT_BACKGROUND_COLOR.to_string(),
+    );
+
+    let is_expired = payment_link
+        .fulfilment_time
+        .map_or(false, |expiry| expiry <= common_utils::date_time::now());
+
+    if is_expired {
+        let status_details = api_models::payments::PaymentLinkStatusDetails {
+            payment_id: payment_intent.payment_id,
+            merchant_name,
+            merchant_logo,
+            expiry: payment_link.fulfilment_time,
+            locale: locale.to_string(),
+            support_email,
+            support_phone,
+        };
+        let js_script = get_js_script("__PAYMENT_LINK_STATUS", &status_details, translations)?;
+        let payment_link_status_data = services::PaymentLinkStatusData {
+            js_script,
+            css_script,
+        };
+        return Ok(services::ApplicationResponse::PaymenkLinkForm(Box::new(
+            services::PaymentLinkAction::PaymentLinkStatus(payment_link_status_data),
+        )));
+    }
+
+    // A reusable link stays open for new payments, every visit gets a fresh payment of its own
+    let payment_intent = if payment_link.is_reusable {
+        create_payment_for_reusable_link(
+            &state,
+            merchant_account.clone(),
+            key_store,
+            &payment_link.payment_link_id,
+            &payment_intent,
+        )
+        .await?
+    } else {
+        helpers::validate_payment_status_against_not_allowed_statuses(
+            &payment_intent.status,
+            &[
+                storage_enums::IntentStatus::Cancelled,
+                storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Processing,
+                storage_enums::IntentStatus::RequiresCapture,
+                storage_enums::IntentStatus::RequiresMerchantAction,
+            ],
+            "create payment link",
+        )?;
+        payment_intent
+    };
 
     let order_details = validate_order_details(payment_intent.order_details)?;
 
@@ -100,90 +183,310 @@ pub async fn intiate_payment_link_flow(
         payment_intent.client_secret,
     )?;
 
-    let (default_sdk_theme, default_background_color) =
-        (DEFAULT_SDK_THEME, DEFAULT_BACKGROUND_COLOR);
+    let default_sdk_theme = DEFAULT_SDK_THEME;
 
     let payment_details = api_models::payments::PaymentLinkDetails {
         amount: payment_intent.amount,
         currency,
         payment_id: payment_intent.payment_id,
-        merchant_name: payment_link.custom_merchant_name.unwrap_or(
-            merchant_account
-                .merchant_name
-                .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
-                .unwrap_or_default(),
-        ),
+        merchant_name,
         order_details,
         return_url,
         expiry: payment_link.fulfilment_time,
         pub_key,
         client_secret,
-        merchant_logo: payment_link_config
-            .clone()
-            .map(|pl_config| {
-                pl_config
-                    .merchant_logo
-                    .unwrap_or(DEFAULT_MERCHANT_LOGO.to_string())
-            })
-            .unwrap_or_default(),
+        merchant_logo,
         max_items_visible_after_collapse: 3,
         sdk_theme: payment_link_config.clone().and_then(|pl_config| {
             pl_config
                 .color_scheme
                 .map(|color| color.sdk_theme.unwrap_or(default_sdk_theme.to_string()))
         }),
+        locale: locale.to_string(),
+        support_email,
+        support_phone,
     };
 
-    let js_script = get_js_script(payment_details)?;
-    let css_script = get_color_scheme_css(
-        payment_link_config.clone(),
-        default_background_color.to_string(),
-    );
+    let js_script = get_js_script("__PAYMENT_DETAILS", &payment_details, translations)?;
     let payment_link_data = services::PaymentLinkFormData {
         js_script,
         sdk_url: state.conf.payment_link.sdk_url.clone(),
         css_script,
     };
     Ok(services::ApplicationResponse::PaymenkLinkForm(Box::new(
-        payment_link_data,
+        services::PaymentLinkAction::PaymentLinkFormData(payment_link_data),
     )))
 }
 
+/// The payment link config of the business profile takes precedence over the one of the merchant
+async fn get_payment_link_config(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<&String>,
+) -> RouterResult<Option<admin_types::PaymentLinkConfig>> {
+    let profile_payment_link_config = match profile_id {
+        Some(profile_id) => {
+            db.find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_owned(),
+                })?
+                .payment_link_config
+        }
+        None => None,
+    };
+
+    profile_payment_link_config
+        .or(merchant_account.payment_link_config.clone())
+        .map(|pl_config| {
+            serde_json::from_value::<admin_types::PaymentLinkConfig>(pl_config)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_link_config",
+                })
+        })
+        .transpose()
+}
+
+async fn create_payment_for_reusable_link(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_link_id: &str,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<storage::PaymentIntent> {
+    check_reusable_link_rate_limit(state, payment_link_id).await?;
+
+    let merchant_id = merchant_account.merchant_id.clone();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let req = api_models::payments::PaymentsRequest {
+        amount: Some(payment_intent.amount.into()),
+        currency: payment_intent.currency,
+        description: payment_intent.description.clone(),
+        return_url: payment_intent
+            .return_url
+            .as_ref()
+            .and_then(|return_url| url::Url::parse(return_url).ok()),
+        order_details: parse_order_details(payment_intent.order_details.clone())?,
+        metadata: payment_intent.metadata.clone(),
+        business_country: payment_intent.business_country,
+        business_label: payment_intent.business_label.clone(),
+        profile_id: payment_intent.profile_id.clone(),
+        ..Default::default()
+    };
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        merchant_account,
+        key_store,
+        payments::PaymentCreate,
+        req,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api_models::payments::HeaderPayload::default(),
+    ))
+    .await?;
+
+    let payment_id = match payments_response {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            payments_response.payment_id
+        }
+        _ => None,
+    }
+    .get_required_value("payment_id")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to create a payment for the reusable payment link")?;
+
+    let db = &*state.store;
+    let created_payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(&payment_id, &merchant_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // Payments created through a reusable link are attributed to it
+    db.update_payment_intent(
+        created_payment_intent,
+        storage::PaymentIntentUpdate::PaymentLinkUpdate {
+            payment_link_id: payment_link_id.to_string(),
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+}
+
+/// Every visit to a reusable link creates a payment, so the number of payments a link can create
+/// within a window is limited to keep the link from being used to flood the merchant with payments.
+async fn check_reusable_link_rate_limit(
+    state: &AppState,
+    payment_link_id: &str,
+) -> RouterResult<()> {
+    let rate_limit = &state.conf.payment_link.reusable_link_rate_limit;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let key = format!("payment_link_rate_limit_{payment_link_id}");
+
+    // The window starts with the first payment created in it. The counter is created along with
+    // its expiry, so that it can never outlive the window.
+    redis_conn
+        .set_key_if_not_exists_with_expiry(&key, 0, Some(rate_limit.window_in_seconds))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to start the rate limiting window of the payment link")?;
+    let payments_created = redis_conn
+        .increment_key(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the payments created by the payment link")?;
+
+    utils::when(payments_created > rate_limit.max_payments, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message:
+                "Too many payments were created using this payment link, please try again later"
+                    .to_string(),
+        })
+        .into_report()
+    })
+}
+
 /*
 The get_js_script function is used to inject dynamic value to payment_link sdk, which is unique to every payment.
 */
 
-fn get_js_script(
-    payment_details: api_models::payments::PaymentLinkDetails,
+fn get_js_script<T: serde::Serialize>(
+    variable_name: &str,
+    details: &T,
+    translations: &locale::PaymentLinkLocale,
 ) -> RouterResult<String> {
-    let payment_details_str = serde_json::to_string(&payment_details)
+    let details_str = serde_json::to_string(details)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize payment link details")?;
+    let translations_str = serde_json::to_string(translations)
         .into_report()
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to serialize PaymentLinkDetails")?;
-    Ok(format!("window.__PAYMENT_DETAILS = {payment_details_str};"))
+        .attach_printable("Failed to serialize PaymentLinkLocale")?;
+    Ok(format!(
+        "window.{variable_name} = {details_str};\nwindow.__PAYMENT_LINK_LOCALE = {translations_str};",
+        details_str = escape_json_for_script(&details_str),
+        translations_str = escape_json_for_script(&translations_str),
+    ))
+}
+
+/// Escapes the characters of serialized JSON which could end the inline script it is rendered in,
+/// or start an HTML comment or entity. These characters only appear within JSON strings, where the
+/// unicode escapes decode to the same value.
+fn escape_json_for_script(json: &str) -> String {
+    json.replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+        .replace('\u{2028}', "\\u2028")
+        .replace('\u{2029}', "\\u2029")
+}
+
+/// Substrings which are not allowed in the custom css, as they load external resources or run
+/// scripts. Compared case insensitively.
+const CUSTOM_CSS_FORBIDDEN_SUBSTRINGS: [&str; 7] = [
+    "url(",
+    "image-set(",
+    "src(",
+    "expression(",
+    "javascript:",
+    "behavior:",
+    "-moz-binding",
+];
+
+/// Validates the custom css of the payment link config. Only printable ASCII characters are
+/// allowed, excluding `<`, which could close the style tag the css is rendered in, `\`, which
+/// could spell any other character as a css escape, and `@`, which starts at-rules such as
+/// `@import`.
+pub fn validate_custom_css(custom_css: &str) -> RouterResult<()> {
+    let has_forbidden_character = custom_css.chars().any(|c| {
+        !(c.is_ascii_graphic() || c.is_ascii_whitespace()) || matches!(c, '<' | '\\' | '@')
+    });
+    let lowercase_css = custom_css.to_lowercase();
+    let has_forbidden_substring = CUSTOM_CSS_FORBIDDEN_SUBSTRINGS
+        .iter()
+        .any(|substring| lowercase_css.contains(substring));
+
+    utils::when(has_forbidden_character || has_forbidden_substring, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "custom_css must not contain '<', '\\', '@', non ASCII characters or references to external resources".to_string(),
+        })
+    })
+    .into_report()
+}
+
+pub fn validate_payment_link_config(
+    payment_link_config: &admin_types::PaymentLinkConfig,
+) -> RouterResult<()> {
+    match payment_link_config.custom_css.as_deref() {
+        Some(custom_css) => validate_custom_css(custom_css),
+        None => Ok(()),
+    }
 }
 
 fn get_color_scheme_css(
     payment_link_config: Option<api_models::admin::PaymentLinkConfig>,
     default_primary_color: String,
 ) -> String {
-    let background_primary_color = payment_link_config
-        .and_then(|pl_config| {
-            pl_config.color_scheme.map(|color| {
-                color
-                    .background_primary_color
-                    .unwrap_or(default_primary_color.clone())
-            })
+    let (color_scheme, font_family, custom_css) = payment_link_config
+        .map(|pl_config| {
+            (
+                pl_config.color_scheme,
+                pl_config.font_family,
+                pl_config.custom_css,
+            )
         })
+        .unwrap_or_default();
+
+    let background_primary_color = color_scheme
+        .and_then(|color| color.background_primary_color)
+        .map(|color| sanitize_css_value(&color))
         .unwrap_or(default_primary_color);
 
+    let font_family = font_family
+        .map(|font_family| sanitize_css_value(&font_family))
+        .unwrap_or(DEFAULT_FONT_FAMILY.to_string());
+
+    // The custom css is validated when the config is stored, configs stored before that are
+    // checked again so that they can not break out of the style tag of the page
+    let custom_css = custom_css
+        .filter(|custom_css| validate_custom_css(custom_css).is_ok())
+        .unwrap_or_default();
+
     format!(
         ":root {{
       --primary-color: {background_primary_color};
-    }}"
+      --font-family: {font_family};
+    }}
+    {custom_css}"
     )
 }
 
+/// Strips the characters which would allow a value to break out of its css declaration
+fn sanitize_css_value(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, '<' | '>' | '{' | '}' | ';'))
+        .collect()
+}
+
 fn validate_sdk_requirements(
     pub_key: Option<String>,
     currency: Option<api_models::enums::Currency>,
@@ -203,13 +506,10 @@ fn validate_sdk_requirements(
     Ok((pub_key, currency, client_secret))
 }
 
-fn validate_order_details(
+fn parse_order_details(
     order_details: Option<Vec<Secret<serde_json::Value>>>,
-) -> Result<
-    Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
-    error_stack::Report<errors::ApiErrorResponse>,
-> {
-    let order_details = order_details
+) -> RouterResult<Option<Vec<api_models::payments::OrderDetailsWithAmount>>> {
+    order_details
         .map(|order_details| {
             order_details
                 .iter()
@@ -223,7 +523,16 @@ fn validate_order_details(
                 })
                 .collect::<Result<Vec<api_models::payments::OrderDetailsWithAmount>, _>>()
         })
-        .transpose()?;
+        .transpose()
+}
+
+fn validate_order_details(
+    order_details: Option<Vec<Secret<serde_json::Value>>>,
+) -> Result<
+    Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
+    error_stack::Report<errors::ApiErrorResponse>,
+> {
+    let order_details = parse_order_details(order_details)?;
 
     let updated_order_details = order_details.map(|mut order_details| {
         for order in order_details.iter_mut() {
@@ -235,3 +544,41 @@ fn validate_order_details(
     });
     Ok(updated_order_details)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_escape_json_for_script() {
+        let json = serde_json::to_string(&serde_json::json!({
+            "merchant_name": "</script><script>alert(1)</script> & co"
+        }))
+        .unwrap();
+        let escaped = escape_json_for_script(&json);
+
+        assert!(!escaped.contains('<'));
+        assert!(!escaped.contains('>'));
+        assert!(!escaped.contains('&'));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&escaped).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_validate_custom_css() {
+        assert!(validate_custom_css(
+            "#submit > .button { color: #fff; font-weight: 600; margin: 0 auto; }"
+        )
+        .is_ok());
+        assert!(validate_custom_css("</style><script>alert(1)</script>").is_err());
+        assert!(validate_custom_css("@import 'https://example.com/a.css';").is_err());
+        assert!(
+            validate_custom_css("body { background: URL(https://example.com/a.png) }").is_err()
+        );
+        assert!(validate_custom_css("body { content: '\\3c' }").is_err());
+        assert!(validate_custom_css("body { width: expression(alert(1)) }").is_err());
+    }
+}
@@ -0,0 +1,199 @@
+/// Translations of the texts rendered on the payment link pages
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentLinkLocale {
+    pub requested_by: &'static str,
+    pub ref_id: &'static str,
+    pub link_expires_on: &'static str,
+    pub your_cart: &'static str,
+    pub quantity: &'static str,
+    pub show_more: &'static str,
+    pub show_less: &'static str,
+    pub unexpected_error: &'static str,
+    pub error_code: &'static str,
+    pub error_message: &'static str,
+    pub paid_successfully: &'static str,
+    pub payment_received: &'static str,
+    pub payment_pending: &'static str,
+    pub payment_taking_longer: &'static str,
+    pub payment_failed: &'static str,
+    pub payment_cancelled: &'static str,
+    pub payment_under_review: &'static str,
+    pub partial_payment_pending: &'static str,
+    pub partial_payment_captured: &'static str,
+    pub something_went_wrong: &'static str,
+    pub need_help: &'static str,
+    pub link_expired: &'static str,
+    pub link_expired_message: &'static str,
+}
+
+const DEFAULT_LOCALE: &str = "en";
+
+const EN: PaymentLinkLocale = PaymentLinkLocale {
+    requested_by: "Requested by",
+    ref_id: "Ref Id",
+    link_expires_on: "Link expires on",
+    your_cart: "Your Cart",
+    quantity: "Qty",
+    show_more: "Show More",
+    show_less: "Show Less",
+    unexpected_error: "An unexpected error occurred.",
+    error_code: "Error code",
+    error_message: "Error message",
+    paid_successfully: "Paid successfully",
+    payment_received: "We have successfully received your payment",
+    payment_pending: "Payment Pending",
+    payment_taking_longer:
+        "Sorry! Your payment is taking longer than expected. Please check back again in sometime.",
+    payment_failed: "Payment Failed!",
+    payment_cancelled: "Payment Cancelled",
+    payment_under_review: "Payment under review",
+    partial_payment_pending: "Partial Payment Pending",
+    partial_payment_captured: "Partial payment was captured.",
+    something_went_wrong: "Something went wrong",
+    need_help: "Need help? Contact",
+    link_expired: "Link Expired",
+    link_expired_message:
+        "This payment link has expired. Please reach out to the merchant for a new link.",
+};
+
+const DE: PaymentLinkLocale = PaymentLinkLocale {
+    requested_by: "Angefordert von",
+    ref_id: "Referenz-ID",
+    link_expires_on: "Link gültig bis",
+    your_cart: "Ihr Warenkorb",
+    quantity: "Menge",
+    show_more: "Mehr anzeigen",
+    show_less: "Weniger anzeigen",
+    unexpected_error: "Ein unerwarteter Fehler ist aufgetreten.",
+    error_code: "Fehlercode",
+    error_message: "Fehlermeldung",
+    paid_successfully: "Erfolgreich bezahlt",
+    payment_received: "Wir haben Ihre Zahlung erfolgreich erhalten",
+    payment_pending: "Zahlung ausstehend",
+    payment_taking_longer:
+        "Entschuldigung! Ihre Zahlung dauert länger als erwartet. Bitte schauen Sie später noch einmal vorbei.",
+    payment_failed: "Zahlung fehlgeschlagen!",
+    payment_cancelled: "Zahlung storniert",
+    payment_under_review: "Zahlung wird geprüft",
+    partial_payment_pending: "Teilzahlung ausstehend",
+    partial_payment_captured: "Eine Teilzahlung wurde erfasst.",
+    something_went_wrong: "Etwas ist schiefgelaufen",
+    need_help: "Brauchen Sie Hilfe? Kontakt",
+    link_expired: "Link abgelaufen",
+    link_expired_message: "Dieser Zahlungslink ist abgelaufen. Bitte fordern Sie beim Händler einen neuen Link an.",
+};
+
+const FR: PaymentLinkLocale = PaymentLinkLocale {
+    requested_by: "Demandé par",
+    ref_id: "Référence",
+    link_expires_on: "Le lien expire le",
+    your_cart: "Votre panier",
+    quantity: "Qté",
+    show_more: "Afficher plus",
+    show_less: "Afficher moins",
+    unexpected_error: "Une erreur inattendue s'est produite.",
+    error_code: "Code d'erreur",
+    error_message: "Message d'erreur",
+    paid_successfully: "Paiement réussi",
+    payment_received: "Nous avons bien reçu votre paiement",
+    payment_pending: "Paiement en attente",
+    payment_taking_longer:
+        "Désolé ! Votre paiement prend plus de temps que prévu. Veuillez revenir plus tard.",
+    payment_failed: "Échec du paiement !",
+    payment_cancelled: "Paiement annulé",
+    payment_under_review: "Paiement en cours de vérification",
+    partial_payment_pending: "Paiement partiel en attente",
+    partial_payment_captured: "Un paiement partiel a été capturé.",
+    something_went_wrong: "Une erreur s'est produite",
+    need_help: "Besoin d'aide ? Contactez",
+    link_expired: "Lien expiré",
+    link_expired_message:
+        "Ce lien de paiement a expiré. Veuillez contacter le marchand pour obtenir un nouveau lien.",
+};
+
+const ES: PaymentLinkLocale = PaymentLinkLocale {
+    requested_by: "Solicitado por",
+    ref_id: "Referencia",
+    link_expires_on: "El enlace caduca el",
+    your_cart: "Tu carrito",
+    quantity: "Cant.",
+    show_more: "Mostrar más",
+    show_less: "Mostrar menos",
+    unexpected_error: "Se ha producido un error inesperado.",
+    error_code: "Código de error",
+    error_message: "Mensaje de error",
+    paid_successfully: "Pagado correctamente",
+    payment_received: "Hemos recibido tu pago correctamente",
+    payment_pending: "Pago pendiente",
+    payment_taking_longer:
+        "¡Lo sentimos! Tu pago está tardando más de lo esperado. Vuelve a consultarlo más tarde.",
+    payment_failed: "¡Pago fallido!",
+    payment_cancelled: "Pago cancelado",
+    payment_under_review: "Pago en revisión",
+    partial_payment_pending: "Pago parcial pendiente",
+    partial_payment_captured: "Se ha capturado un pago parcial.",
+    something_went_wrong: "Algo salió mal",
+    need_help: "¿Necesitas ayuda? Contacta con",
+    link_expired: "Enlace caducado",
+    link_expired_message: "Este enlace de pago ha caducado. Ponte en contacto con el comercio para obtener un nuevo enlace.",
+};
+
+fn get_translations(language: &str) -> Option<(&'static str, &'static PaymentLinkLocale)> {
+    match language {
+        "en" => Some(("en", &EN)),
+        "de" => Some(("de", &DE)),
+        "fr" => Some(("fr", &FR)),
+        "es" => Some(("es", &ES)),
+        _ => None,
+    }
+}
+
+/// Picks the supported language with the highest quality value from an `Accept-Language` header,
+/// falling back to English when none of the requested languages are supported
+pub fn get_locale_from_accept_language(
+    accept_language: Option<&str>,
+) -> (&'static str, &'static PaymentLinkLocale) {
+    let mut languages = accept_language
+        .unwrap_or_default()
+        .split(',')
+        .enumerate()
+        .filter_map(|(position, language_range)| {
+            let mut parts = language_range.split(';');
+            let language = parts.next()?.trim().to_lowercase();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())?;
+            let primary_language = language.split('-').next()?.to_string();
+            (quality > 0.0).then_some((primary_language, quality, position))
+        })
+        .collect::<Vec<_>>();
+
+    // Highest quality first, keeping the order of the header for equal quality values
+    languages.sort_by(|(_, q1, p1), (_, q2, p2)| q2.total_cmp(q1).then(p1.cmp(p2)));
+
+    languages
+        .iter()
+        .find_map(|(language, _, _)| get_translations(language))
+        .unwrap_or((DEFAULT_LOCALE, &EN))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_locale_from_accept_language() {
+        assert_eq!(get_locale_from_accept_language(None).0, "en");
+        assert_eq!(get_locale_from_accept_language(Some("de-DE")).0, "de");
+        assert_eq!(
+            get_locale_from_accept_language(Some("ja-JP, fr;q=0.8, de;q=0.9")).0,
+            "de"
+        );
+        assert_eq!(
+            get_locale_from_accept_language(Some("es;q=0.5, fr-CA;q=0.5")).0,
+            "es"
+        );
+        assert_eq!(get_locale_from_accept_language(Some("de;q=0, it")).0, "en");
+        assert_eq!(get_locale_from_accept_language(Some("*")).0, "en");
+    }
+}
@@ -1,4 +1,6 @@
+pub mod account_updater;
 pub mod cards;
+pub mod network_tokenization;
 pub mod transformers;
 pub mod vault;
 
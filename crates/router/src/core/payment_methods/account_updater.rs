@@ -0,0 +1,712 @@
+use std::{str::FromStr, sync::Arc};
+
+use api_models::payment_methods::{CardDetailsPaymentMethod, PaymentMethodsData};
+use common_utils::{consts, date_time, ext_traits::ValueExt, generate_id};
+use error_stack::{IntoReport, ResultExt};
+use external_services::{
+    account_updater::{
+        AccountUpdateRequest, AccountUpdateResponse, AccountUpdateResult, AccountUpdaterProvider,
+    },
+    network_tokenization::TokenLifecycleEvent,
+};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
+use time::{util::days_in_year_month, Date, Duration, Month, PrimitiveDateTime, Time};
+
+use super::{cards, network_tokenization, transformers as payment_methods};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        metrics, webhooks,
+    },
+    routes::AppState,
+    types::{
+        api,
+        domain::{self, types::decrypt},
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const CARD_ACCOUNT_UPDATER_RUNNER: &str = "CARD_ACCOUNT_UPDATER_WORKFLOW";
+const CARD_ACCOUNT_UPDATER_TASK: &str = "CARD_ACCOUNT_UPDATER";
+const PAYMENT_METHODS_PAGE_SIZE: i64 = 100;
+/// Offset of the timezone furthest behind UTC (UTC-12), in hours
+const LATEST_TIMEZONE_OFFSET_HOURS: i64 = 12;
+
+/// Schedules the periodic account updater run for the merchant, if the card account updater is
+/// enabled. A run that is already scheduled is left untouched.
+#[instrument(skip_all)]
+pub async fn schedule_card_account_updater_task(
+    state: &AppState,
+    merchant_id: &str,
+) -> Result<(), errors::ProcessTrackerError> {
+    if state.account_updater_provider.is_none() {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let current_time = date_time::now();
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        CARD_ACCOUNT_UPDATER_RUNNER,
+        CARD_ACCOUNT_UPDATER_TASK,
+        merchant_id,
+        merchant_id,
+    );
+    match db.find_process_by_id(&process_tracker_id).await? {
+        Some(process) if process.status == storage_enums::ProcessTrackerStatus::Finish => {
+            process.reset(db.as_scheduler(), current_time).await
+        }
+        Some(_) => Ok(()),
+        None => {
+            let tracking_data = storage::CardAccountUpdaterTrackingData {
+                merchant_id: merchant_id.to_string(),
+                batch_id: None,
+            };
+            let tracking_data = serde_json::to_value(tracking_data)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize card account updater tracking data")?;
+
+            let process_tracker_entry = storage::ProcessTrackerNew {
+                id: process_tracker_id,
+                name: Some(String::from(CARD_ACCOUNT_UPDATER_TASK)),
+                tag: vec![String::from("PAYMENT_METHOD")],
+                runner: Some(String::from(CARD_ACCOUNT_UPDATER_RUNNER)),
+                retry_count: 0,
+                schedule_time: Some(current_time),
+                rule: String::new(),
+                tracking_data,
+                business_status: String::from("Pending"),
+                status: storage_enums::ProcessTrackerStatus::New,
+                event: vec![],
+                created_at: current_time,
+                updated_at: current_time,
+            };
+            db.insert_process(process_tracker_entry).await?;
+            Ok(())
+        }
+    }
+}
+
+/// Submits the saved cards of the merchant expiring within the configured window to the account
+/// updater, or applies the updates reported for a previously submitted batch once available
+#[instrument(skip_all)]
+pub async fn start_card_account_updater_workflow(
+    state: &AppState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::CardAccountUpdaterTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("CardAccountUpdaterTrackingData")?;
+
+    let provider = match state.account_updater_provider.as_ref() {
+        Some(provider) => provider,
+        None => {
+            return process
+                .clone()
+                .finish_with_status(db.as_scheduler(), "COMPLETED_BY_PT".to_string())
+                .await
+        }
+    };
+    let config = &state.conf.card_account_updater;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await?;
+
+    let current_time = date_time::now();
+    let run_interval = Duration::hours(i64::from(config.run_interval_hours));
+    let poll_interval = Duration::minutes(i64::from(config.result_poll_interval_minutes));
+
+    let (batch_id, next_schedule_time) = match tracking_data.batch_id {
+        Some(batch_id) => match provider
+            .fetch_batch_results(&batch_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the account updater batch results")?
+        {
+            Some(responses) => {
+                apply_account_updates(state, &merchant_account, &key_store, responses).await;
+                (None, current_time.saturating_add(run_interval))
+            }
+            None => (Some(batch_id), current_time.saturating_add(poll_interval)),
+        },
+        None => {
+            let expiry_cutoff =
+                current_time.saturating_add(Duration::days(i64::from(config.expiry_window_days)));
+            match submit_expiring_cards(
+                state,
+                provider,
+                &merchant_account,
+                &key_store,
+                expiry_cutoff,
+            )
+            .await?
+            {
+                Some(batch_id) => (Some(batch_id), current_time.saturating_add(poll_interval)),
+                None => (None, current_time.saturating_add(run_interval)),
+            }
+        }
+    };
+
+    let tracking_data = serde_json::to_value(storage::CardAccountUpdaterTrackingData {
+        batch_id,
+        ..tracking_data
+    })
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize card account updater tracking data")?;
+    db.update_process(
+        process.clone(),
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(next_schedule_time),
+            tracking_data: Some(tracking_data),
+            business_status: None,
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(current_time),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Returns the moment after which the card can no longer be used, i.e. the end of its expiry month
+/// in the timezone furthest behind UTC, expressed in UTC. Two digit years are taken to be in the 2000s. Returns
+/// `None` if the expiry month or year is not valid.
+pub fn get_card_expiry_time(
+    card_exp_month: &Secret<String>,
+    card_exp_year: &Secret<String>,
+) -> Option<PrimitiveDateTime> {
+    let month = card_exp_month
+        .peek()
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .and_then(|month| Month::try_from(month).ok())?;
+    let year = card_exp_year.peek().trim();
+    let year = if year.len() == 2 {
+        format!("20{year}")
+    } else {
+        year.to_string()
+    }
+    .parse::<i32>()
+    .ok()?;
+
+    let expiry_date =
+        Date::from_calendar_date(year, month, days_in_year_month(year, month)).ok()?;
+    Some(
+        PrimitiveDateTime::new(expiry_date, Time::MIDNIGHT)
+            .saturating_add(Duration::days(1))
+            .saturating_add(Duration::hours(LATEST_TIMEZONE_OFFSET_HOURS)),
+    )
+}
+
+/// Returns whether the card expired, based on the card details saved for the payment method.
+/// Cards whose expiry is unknown are not considered expired.
+pub fn is_card_expired(card: &api::CardDetailFromLocker) -> bool {
+    card.expiry_month
+        .as_ref()
+        .zip(card.expiry_year.as_ref())
+        .and_then(|(card_exp_month, card_exp_year)| {
+            get_card_expiry_time(card_exp_month, card_exp_year)
+        })
+        .map_or(false, |expiry_time| date_time::now() > expiry_time)
+}
+
+/// Submits the saved cards of the merchant expiring before the cutoff to the account updater.
+/// Returns the identifier of the submitted batch, or `None` if no card is expiring.
+async fn submit_expiring_cards(
+    state: &AppState,
+    provider: &Arc<dyn AccountUpdaterProvider>,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    expiry_cutoff: PrimitiveDateTime,
+) -> RouterResult<Option<String>> {
+    let db = &*state.store;
+    let mut requests = Vec::new();
+    let mut offset = 0;
+    loop {
+        let payment_methods = db
+            .find_payment_method_by_merchant_id_paginated(
+                &merchant_account.merchant_id,
+                PAYMENT_METHODS_PAGE_SIZE,
+                offset,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the payment methods of the merchant")?;
+        let page_size = payment_methods.len();
+
+        for payment_method in payment_methods {
+            if payment_method.payment_method != storage_enums::PaymentMethod::Card {
+                continue;
+            }
+            let is_expiring = get_saved_card_details(&payment_method, key_store)
+                .await?
+                .and_then(|card| card.expiry_month.zip(card.expiry_year))
+                .and_then(|(card_exp_month, card_exp_year)| {
+                    get_card_expiry_time(&card_exp_month, &card_exp_year)
+                })
+                .map_or(false, |expiry_time| expiry_time <= expiry_cutoff);
+            if !is_expiring {
+                continue;
+            }
+
+            // A card missing from the locker is skipped, so that the rest of the batch is checked
+            match cards::get_card_from_locker(
+                state,
+                &payment_method.customer_id,
+                &payment_method.merchant_id,
+                payment_method.get_locker_id(),
+            )
+            .await
+            {
+                Ok(card) => requests.push(AccountUpdateRequest {
+                    reference: payment_method.payment_method_id,
+                    card_number: Secret::new(card.card_number.peek().to_string()),
+                    card_exp_month: card.card_exp_month,
+                    card_exp_year: card.card_exp_year,
+                }),
+                Err(error) => logger::error!(card_account_updater_locker_error=?error),
+            }
+        }
+
+        if i64::try_from(page_size).unwrap_or_default() < PAYMENT_METHODS_PAGE_SIZE {
+            break;
+        }
+        offset += PAYMENT_METHODS_PAGE_SIZE;
+    }
+
+    if requests.is_empty() {
+        return Ok(None);
+    }
+
+    let batch_id = generate_id(consts::ID_LENGTH, "aub");
+    provider
+        .submit_batch(&batch_id, &requests)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to submit the account updater batch")?;
+    metrics::CARD_ACCOUNT_UPDATER_SUBMITTED_CARDS_METRIC.add(
+        &metrics::CONTEXT,
+        u64::try_from(requests.len()).unwrap_or_default(),
+        &[],
+    );
+    Ok(Some(batch_id))
+}
+
+/// Applies the updates reported for each card of the batch. A failure to update one card is logged
+/// and does not prevent the rest of the batch from being applied.
+async fn apply_account_updates(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    responses: Vec<AccountUpdateResponse>,
+) {
+    for response in responses {
+        if let Err(error) = apply_account_update(state, merchant_account, key_store, response).await
+        {
+            logger::error!(card_account_update_error=?error);
+        }
+    }
+}
+
+async fn apply_account_update(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    response: AccountUpdateResponse,
+) -> RouterResult<()> {
+    let payment_method = match state.store.find_payment_method(&response.reference).await {
+        Ok(payment_method) if payment_method.merchant_id == merchant_account.merchant_id => {
+            payment_method
+        }
+        Ok(_) => return Ok(()),
+        // The card was deleted since the batch was submitted
+        Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the payment method of the account update")
+        }
+    };
+
+    match response.result {
+        AccountUpdateResult::NoChange => Ok(()),
+        AccountUpdateResult::ExpiryUpdated {
+            card_exp_month,
+            card_exp_year,
+        } => {
+            update_saved_card(
+                state,
+                merchant_account,
+                key_store,
+                payment_method,
+                None,
+                card_exp_month,
+                card_exp_year,
+            )
+            .await
+        }
+        AccountUpdateResult::CardNumberUpdated {
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        } => {
+            let card_number = ::cards::CardNumber::from_str(card_number.peek())
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid card number reported by the account updater")?;
+            update_saved_card(
+                state,
+                merchant_account,
+                key_store,
+                payment_method,
+                Some(card_number),
+                card_exp_month,
+                card_exp_year,
+            )
+            .await
+        }
+        AccountUpdateResult::AccountClosed => {
+            remove_closed_card(state, merchant_account, key_store, payment_method).await
+        }
+    }
+}
+
+/// Stores the reissued card in the locker in place of the saved card, and updates the card details
+/// saved for the payment method
+async fn update_saved_card(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: storage::PaymentMethod,
+    card_number: Option<::cards::CardNumber>,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+) -> RouterResult<()> {
+    let saved_card = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method.get_locker_id(),
+    )
+    .await?;
+    let updated_card = payment_methods::Card {
+        card_number: card_number.unwrap_or(saved_card.card_number),
+        card_exp_month,
+        card_exp_year,
+        ..saved_card
+    };
+
+    let stored_card = cards::call_to_locker_hs(
+        state,
+        &payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+            merchant_id: &payment_method.merchant_id,
+            merchant_customer_id: payment_method.customer_id.clone(),
+            card: updated_card.clone(),
+        }),
+        &payment_method.customer_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the updated card in the locker")?;
+
+    let card_fingerprint = cards::generate_card_fingerprint(
+        state,
+        &payment_method.merchant_id,
+        &updated_card.card_number,
+    )
+    .await?;
+    let card_details = get_saved_card_details(&payment_method, key_store)
+        .await?
+        .unwrap_or_else(|| CardDetailsPaymentMethod {
+            last4_digits: None,
+            issuer_country: None,
+            expiry_month: None,
+            expiry_year: None,
+            nick_name: updated_card.nick_name.clone().map(Secret::new),
+            card_holder_name: updated_card.name_on_card.clone(),
+        });
+    let card_details = CardDetailsPaymentMethod {
+        last4_digits: Some(updated_card.card_number.clone().get_last4()),
+        expiry_month: Some(updated_card.card_exp_month.clone()),
+        expiry_year: Some(updated_card.card_exp_year.clone()),
+        ..card_details
+    };
+    let payment_method_data = cards::create_encrypted_payment_method_data(
+        key_store,
+        Some(PaymentMethodsData::Card(card_details)),
+    )
+    .await;
+
+    let previous_locker_id = payment_method.get_locker_id().to_string();
+    let payment_method = state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::CardAccountUpdate {
+                locker_id: Some(stored_card.card_reference.clone()),
+                payment_method_data,
+                card_fingerprint: Some(card_fingerprint),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the card of the payment method")?;
+    metrics::CARD_ACCOUNT_UPDATER_UPDATED_CARDS_METRIC.add(&metrics::CONTEXT, 1, &[]);
+
+    if previous_locker_id != stored_card.card_reference {
+        cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &previous_locker_id,
+        )
+        .await
+        .map_err(|error| logger::error!(card_account_updater_locker_error=?error))
+        .ok();
+    }
+
+    network_tokenization::handle_network_token_lifecycle_event(
+        state,
+        &payment_method,
+        TokenLifecycleEvent::CardUpdated {
+            card_exp_month: updated_card.card_exp_month,
+            card_exp_year: updated_card.card_exp_year,
+        },
+    )
+    .await
+    .map_err(|error| logger::error!(network_tokenization_error=?error))
+    .ok();
+
+    // Every update of the card gets its own event, so that the events are not deduplicated
+    let primary_object_id = format!(
+        "{}_{}",
+        payment_method.payment_method_id, stored_card.card_reference
+    );
+    let payment_method_response =
+        cards::get_payment_method_response(payment_method, key_store).await?;
+    trigger_payment_method_webhook(
+        state,
+        merchant_account,
+        storage_enums::EventType::PaymentMethodUpdated,
+        primary_object_id,
+        payment_method_response,
+    )
+    .await
+    .map_err(|error| logger::warn!(payment_method_outgoing_webhook_error=?error))
+    .ok();
+    Ok(())
+}
+
+/// Removes the card of a closed account from the locker, and deletes the payment method
+async fn remove_closed_card(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: storage::PaymentMethod,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_method_response =
+        cards::get_payment_method_response(payment_method.clone(), key_store).await?;
+
+    let response = cards::delete_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method.get_locker_id(),
+    )
+    .await?;
+    if response.status != "SUCCESS" {
+        return Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to delete the card of a closed account from the locker: {response:?}"
+                )
+            });
+    }
+
+    network_tokenization::handle_network_token_lifecycle_event(
+        state,
+        &payment_method,
+        TokenLifecycleEvent::Delete,
+    )
+    .await
+    .map_err(|error| logger::error!(network_tokenization_error=?error))
+    .ok();
+
+    db.delete_payment_method_by_merchant_id_payment_method_id(
+        &payment_method.merchant_id,
+        &payment_method.payment_method_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    cards::replace_customer_default_payment_method(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        &payment_method.payment_method_id,
+        None,
+        key_store,
+    )
+    .await?;
+    metrics::CARD_ACCOUNT_UPDATER_CLOSED_ACCOUNTS_METRIC.add(&metrics::CONTEXT, 1, &[]);
+
+    trigger_payment_method_webhook(
+        state,
+        merchant_account,
+        storage_enums::EventType::PaymentMethodAccountClosed,
+        payment_method.payment_method_id,
+        payment_method_response,
+    )
+    .await
+    .map_err(|error| logger::warn!(payment_method_outgoing_webhook_error=?error))
+    .ok();
+    Ok(())
+}
+
+/// Returns the card details saved in the encrypted payment method data, if any
+pub async fn get_saved_card_details(
+    payment_method: &storage::PaymentMethod,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<CardDetailsPaymentMethod>> {
+    Ok(decrypt::<serde_json::Value, masking::WithType, _>(
+        payment_method.payment_method_data.clone(),
+        &key_store.key_ring(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to decrypt payment method data")?
+    .map(|data| data.into_inner().expose())
+    .and_then(|data| serde_json::from_value::<PaymentMethodsData>(data).ok())
+    .and_then(|data| match data {
+        PaymentMethodsData::Card(card) => Some(card),
+        _ => None,
+    }))
+}
+
+async fn trigger_payment_method_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    event_type: storage_enums::EventType,
+    primary_object_id: String,
+    payment_method_response: api::PaymentMethodResponse,
+) -> RouterResult<()> {
+    let profile_id = match merchant_account.default_profile.clone() {
+        Some(profile_id) => profile_id,
+        None => {
+            logger::info!(
+                "Skipping {event_type} webhook as merchant {} has no default business profile",
+                merchant_account.merchant_id
+            );
+            return Ok(());
+        }
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        })?;
+
+    Box::pin(
+        webhooks::create_event_and_trigger_appropriate_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile,
+            event_type,
+            storage_enums::EventClass::PaymentMethods,
+            None,
+            primary_object_id,
+            storage_enums::EventObjectType::PaymentMethodDetails,
+            api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+            None,
+        ),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_card_expiry_time_is_end_of_expiry_month() {
+        let expiry_time = get_card_expiry_time(
+            &Secret::new("02".to_string()),
+            &Secret::new("24".to_string()),
+        )
+        .unwrap();
+        assert_eq!(
+            expiry_time,
+            PrimitiveDateTime::new(
+                Date::from_calendar_date(2024, Month::March, 1).unwrap(),
+                Time::from_hms(12, 0, 0).unwrap()
+            )
+        );
+
+        let expiry_time = get_card_expiry_time(
+            &Secret::new("12".to_string()),
+            &Secret::new("2030".to_string()),
+        )
+        .unwrap();
+        assert_eq!(
+            expiry_time.date(),
+            Date::from_calendar_date(2031, Month::January, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_card_expiry_time_rejects_invalid_expiry() {
+        assert!(get_card_expiry_time(
+            &Secret::new("13".to_string()),
+            &Secret::new("2030".to_string())
+        )
+        .is_none());
+        assert!(get_card_expiry_time(
+            &Secret::new("01".to_string()),
+            &Secret::new("abcd".to_string())
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_is_card_expired() {
+        let card = |card_exp_year: &str| api::CardDetailFromLocker {
+            scheme: None,
+            issuer_country: None,
+            last4_digits: None,
+            card_number: None,
+            expiry_month: Some(Secret::new("01".to_string())),
+            expiry_year: Some(Secret::new(card_exp_year.to_string())),
+            card_token: None,
+            card_holder_name: None,
+            card_fingerprint: None,
+            nick_name: None,
+        };
+        assert!(is_card_expired(&card("2020")));
+        assert!(!is_card_expired(&card("2099")));
+        assert!(!is_card_expired(&api::CardDetailFromLocker {
+            expiry_year: None,
+            ..card("2020")
+        }));
+    }
+}
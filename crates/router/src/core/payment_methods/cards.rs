@@ -15,12 +15,19 @@ use api_models::{
 };
 use common_utils::{
     consts,
+    crypto::{self, SignMessage},
     ext_traits::{AsyncExt, StringExt, ValueExt},
     generate_id,
 };
 use diesel_models::{encryption::Encryption, enums as storage_enums, payment_method};
 use error_stack::{report, IntoReport, ResultExt};
-use masking::Secret;
+use external_services::network_tokenization::TokenLifecycleEvent;
+#[cfg(feature = "encryption_service")]
+use external_services::{
+    encryption_management,
+    secrets_management::{decrypt::KmsDecrypt, SecretManagementInterface},
+};
+use masking::{Secret, StrongSecret};
 use router_env::{instrument, tracing};
 
 use crate::{
@@ -28,6 +35,7 @@ use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::{
+            account_updater, network_tokenization,
             transformers::{self as payment_methods},
             vault,
         },
@@ -66,6 +74,7 @@ pub async fn create_payment_method(
     merchant_id: &str,
     pm_metadata: Option<serde_json::Value>,
     payment_method_data: Option<Encryption>,
+    card_fingerprint: Option<String>,
     key_store: &domain::MerchantKeyStore,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::ApiErrorResponse> {
     db.find_customer_by_customer_id_merchant_id(customer_id, merchant_id, key_store)
@@ -83,6 +92,7 @@ pub async fn create_payment_method(
             scheme: req.card_network.clone(),
             metadata: pm_metadata.map(masking::Secret::new),
             payment_method_data,
+            card_fingerprint,
             ..storage::PaymentMethodNew::default()
         })
         .await
@@ -92,6 +102,202 @@ pub async fn create_payment_method(
     Ok(response)
 }
 
+static CARD_FINGERPRINT_KEY: tokio::sync::OnceCell<StrongSecret<String>> =
+    tokio::sync::OnceCell::const_new();
+
+pub async fn get_card_fingerprint_key(
+    secrets: &settings::Secrets,
+    #[cfg(feature = "encryption_service")] secret_management_client: &dyn SecretManagementInterface,
+) -> errors::RouterResult<&'static StrongSecret<String>> {
+    CARD_FINGERPRINT_KEY
+        .get_or_try_init(|| async {
+            #[cfg(feature = "encryption_service")]
+            let card_fingerprint_key = secrets
+                .kms_encrypted_card_fingerprint_key
+                .decrypt_inner(secret_management_client)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to KMS decrypt card fingerprint key")?;
+
+            #[cfg(not(feature = "encryption_service"))]
+            let card_fingerprint_key = secrets.card_fingerprint_key.clone();
+
+            Ok(StrongSecret::new(card_fingerprint_key))
+        })
+        .await
+}
+
+/// Computes the keyed fingerprint of a card number. The fingerprint is scoped to the merchant, so
+/// the same card cannot be correlated across merchants, and it does not reveal the card number.
+pub async fn generate_card_fingerprint(
+    state: &routes::AppState,
+    merchant_id: &str,
+    card_number: &cards::CardNumber,
+) -> errors::RouterResult<String> {
+    let card_fingerprint_key = get_card_fingerprint_key(
+        &state.conf.secrets,
+        #[cfg(feature = "encryption_service")]
+        encryption_management::get_encryption_management_client(&state.conf.encryption_management)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    )
+    .await?;
+
+    let message = format!("{merchant_id}:{}", card_number.peek());
+    crypto::HmacSha256
+        .sign_message(card_fingerprint_key.peek().as_bytes(), message.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate card fingerprint")
+        .map(hex::encode)
+}
+
+/// Finds the payment method the customer has already saved with the same card, if any
+pub async fn find_payment_method_by_card(
+    state: &routes::AppState,
+    customer_id: &str,
+    merchant_id: &str,
+    card: &api::CardDetail,
+) -> errors::RouterResult<Option<storage::PaymentMethod>> {
+    let card_fingerprint = generate_card_fingerprint(state, merchant_id, &card.card_number).await?;
+
+    match state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+            customer_id,
+            merchant_id,
+            &card_fingerprint,
+        )
+        .await
+    {
+        Ok(payment_method) => Ok(Some(payment_method)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment method by card fingerprint")),
+    }
+}
+
+/// Returns the saved card details with the expiry and card holder name of the card being saved
+/// again, or `None` if the saved card details are already up to date
+fn get_updated_card_details(
+    saved_card_details: Option<CardDetailsPaymentMethod>,
+    card: &api::CardDetail,
+) -> Option<CardDetailsPaymentMethod> {
+    let is_same_secret = |saved: &Option<Secret<String>>, new: &Secret<String>| {
+        saved.as_ref().map(|saved| saved.peek()) == Some(new.peek())
+    };
+    let card_exp_month = Secret::from(card.card_exp_month.clone());
+    let card_exp_year = Secret::from(card.card_exp_year.clone());
+
+    match saved_card_details {
+        Some(saved_card_details)
+            if is_same_secret(&saved_card_details.expiry_month, &card_exp_month)
+                && is_same_secret(&saved_card_details.expiry_year, &card_exp_year)
+                && card
+                    .card_holder_name
+                    .as_ref()
+                    .map_or(true, |card_holder_name| {
+                        is_same_secret(&saved_card_details.card_holder_name, card_holder_name)
+                    }) =>
+        {
+            None
+        }
+        Some(saved_card_details) => Some(CardDetailsPaymentMethod {
+            expiry_month: Some(card_exp_month),
+            expiry_year: Some(card_exp_year),
+            card_holder_name: card
+                .card_holder_name
+                .clone()
+                .or(saved_card_details.card_holder_name),
+            ..saved_card_details
+        }),
+        None => Some(CardDetailsPaymentMethod {
+            last4_digits: Some(card.card_number.clone().get_last4()),
+            issuer_country: None,
+            expiry_month: Some(card_exp_month),
+            expiry_year: Some(card_exp_year),
+            nick_name: card.nick_name.clone(),
+            card_holder_name: card.card_holder_name.clone(),
+        }),
+    }
+}
+
+/// Updates the expiry and card holder name of a saved card with the details of the same card
+/// being saved again, both in the locker and in the card details saved for the payment method
+pub async fn update_saved_card_details(
+    state: &routes::AppState,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: storage::PaymentMethod,
+    card: &api::CardDetail,
+) -> errors::RouterResult<storage::PaymentMethod> {
+    let saved_card_details =
+        account_updater::get_saved_card_details(&payment_method, key_store).await?;
+    let card_details = match get_updated_card_details(saved_card_details, card) {
+        Some(card_details) => card_details,
+        None => return Ok(payment_method),
+    };
+
+    let saved_card = get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method.get_locker_id(),
+    )
+    .await?;
+    let stored_card = call_to_locker_hs(
+        state,
+        &payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+            merchant_id: &payment_method.merchant_id,
+            merchant_customer_id: payment_method.customer_id.clone(),
+            card: payment_methods::Card {
+                card_exp_month: card.card_exp_month.clone().into(),
+                card_exp_year: card.card_exp_year.clone().into(),
+                name_on_card: card_details.card_holder_name.clone(),
+                ..saved_card
+            },
+        }),
+        &payment_method.customer_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the updated card in the locker")?;
+
+    let payment_method_data = create_encrypted_payment_method_data(
+        key_store,
+        Some(PaymentMethodsData::Card(card_details)),
+    )
+    .await;
+    let previous_locker_id = payment_method.get_locker_id().to_string();
+    let card_fingerprint = payment_method.card_fingerprint.clone();
+    let payment_method = state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::CardAccountUpdate {
+                locker_id: Some(stored_card.card_reference.clone()),
+                payment_method_data,
+                card_fingerprint,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the card details of the payment method")?;
+
+    if previous_locker_id != stored_card.card_reference {
+        delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &previous_locker_id,
+        )
+        .await
+        .map_err(|error| logger::error!(card_locker_delete_error=?error))
+        .ok();
+    }
+
+    Ok(payment_method)
+}
+
 #[instrument(skip_all)]
 pub async fn add_payment_method(
     state: routes::AppState,
@@ -102,6 +308,21 @@ pub async fn add_payment_method(
     req.validate()?;
     let merchant_id = &merchant_account.merchant_id;
     let customer_id = req.customer_id.clone().get_required_value("customer_id")?;
+
+    // Saving the same card again for the customer refreshes the expiry and card holder name of the
+    // payment method that already exists
+    if let Some(card) = req.card.as_ref() {
+        if let Some(payment_method) =
+            find_payment_method_by_card(&state, &customer_id, merchant_id, card).await?
+        {
+            let payment_method =
+                update_saved_card_details(&state, key_store, payment_method, card).await?;
+            return get_payment_method_response(payment_method, key_store)
+                .await
+                .map(services::ApplicationResponse::Json);
+        }
+    }
+
     let response = match req.card.clone() {
         Some(card) => add_card_to_locker(
             &state,
@@ -144,6 +365,12 @@ pub async fn add_payment_method(
         let pm_data_encrypted =
             create_encrypted_payment_method_data(key_store, pm_card_details).await;
 
+        let card_fingerprint = resp
+            .card
+            .as_ref()
+            .and_then(|card| card.card_fingerprint.clone())
+            .map(masking::Secret::expose);
+
         create_payment_method(
             &*state.store,
             &req,
@@ -152,9 +379,17 @@ pub async fn add_payment_method(
             &resp.merchant_id,
             pm_metadata.cloned(),
             pm_data_encrypted,
+            card_fingerprint,
             key_store,
         )
         .await?;
+
+        if resp.card.is_some() {
+            account_updater::schedule_card_account_updater_task(&state, merchant_id)
+                .await
+                .map_err(|error| logger::error!(card_account_updater_schedule_error=?error))
+                .ok();
+        }
     }
 
     Ok(resp).map(services::ApplicationResponse::Json)
@@ -169,6 +404,29 @@ pub async fn update_customer_payment_method(
     key_store: domain::MerchantKeyStore,
 ) -> errors::RouterResponse<api::PaymentMethodResponse> {
     let db = state.store.as_ref();
+
+    // Without new card details, the payment method is updated in place
+    if req.card.is_none() {
+        let pm = db
+            .find_payment_method(payment_method_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+        utils::when(pm.merchant_id != merchant_account.merchant_id, || {
+            Err(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+        let pm = update_payment_method_details(
+            &state,
+            pm,
+            req.nick_name,
+            req.metadata.map(masking::Secret::expose),
+            &key_store,
+        )
+        .await?;
+        return get_payment_method_response(pm, &key_store)
+            .await
+            .map(services::ApplicationResponse::Json);
+    }
+
     let pm = db
         .delete_payment_method_by_merchant_id_payment_method_id(
             &merchant_account.merchant_id,
@@ -177,28 +435,230 @@ pub async fn update_customer_payment_method(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
     if pm.payment_method == enums::PaymentMethod::Card {
-        delete_card_from_locker(
-            &state,
-            &pm.customer_id,
-            &pm.merchant_id,
-            &pm.payment_method_id,
-        )
-        .await?;
+        delete_card_from_locker(&state, &pm.customer_id, &pm.merchant_id, pm.get_locker_id())
+            .await?;
     };
+    let customer_id = pm.customer_id;
+    let nick_name = req.nick_name;
     let new_pm = api::PaymentMethodCreate {
         payment_method: pm.payment_method,
         payment_method_type: pm.payment_method_type,
         payment_method_issuer: pm.payment_method_issuer,
         payment_method_issuer_code: pm.payment_method_issuer_code,
-        card: req.card,
+        card: req.card.map(|card| api::CardDetail {
+            nick_name: card.nick_name.or(nick_name),
+            ..card
+        }),
         metadata: req.metadata,
-        customer_id: Some(pm.customer_id),
+        customer_id: Some(customer_id.clone()),
         card_network: req
             .card_network
             .as_ref()
             .map(|card_network| card_network.to_string()),
     };
-    add_payment_method(state, new_pm, &merchant_account, &key_store).await
+    let response = add_payment_method(state.clone(), new_pm, &merchant_account, &key_store).await?;
+
+    // The card is saved again under a new payment method, which replaces the old one as default
+    if let services::ApplicationResponse::Json(ref new_payment_method) = response {
+        replace_customer_default_payment_method(
+            &state,
+            &customer_id,
+            &merchant_account.merchant_id,
+            payment_method_id,
+            Some(new_payment_method.payment_method_id.clone()),
+            &key_store,
+        )
+        .await?;
+    }
+
+    Ok(response)
+}
+
+/// Updates the nick name and the metadata of a saved payment method, without saving the card again
+async fn update_payment_method_details(
+    state: &routes::AppState,
+    pm: storage::PaymentMethod,
+    nick_name: Option<Secret<String>>,
+    metadata: Option<serde_json::Value>,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<storage::PaymentMethod> {
+    let payment_method_data = match nick_name {
+        Some(nick_name) => {
+            let card_details = account_updater::get_saved_card_details(&pm, key_store)
+                .await?
+                .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "nick name can only be set for saved cards".into(),
+                })
+                .into_report()?;
+            create_encrypted_payment_method_data(
+                key_store,
+                Some(PaymentMethodsData::Card(CardDetailsPaymentMethod {
+                    nick_name: Some(nick_name),
+                    ..card_details
+                })),
+            )
+            .await
+        }
+        None => None,
+    };
+
+    state
+        .store
+        .update_payment_method(
+            pm,
+            storage::PaymentMethodUpdate::DetailsUpdate {
+                metadata,
+                payment_method_data,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payment method")
+}
+
+/// Finds the payment method, ensuring it is saved for the given customer of the merchant
+async fn find_customer_payment_method(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+    payment_method_id: &str,
+) -> errors::RouterResult<storage::PaymentMethod> {
+    let pm = db
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    utils::when(
+        pm.merchant_id != merchant_id || pm.customer_id != customer_id,
+        || Err(errors::ApiErrorResponse::PaymentMethodNotFound),
+    )?;
+    Ok(pm)
+}
+
+#[instrument(skip_all)]
+pub async fn update_customer_payment_method_nick_name(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: &str,
+    payment_method_id: &str,
+    req: api::CustomerPaymentMethodUpdate,
+) -> errors::RouterResponse<api::PaymentMethodResponse> {
+    let pm = find_customer_payment_method(
+        &*state.store,
+        &merchant_account.merchant_id,
+        customer_id,
+        payment_method_id,
+    )
+    .await?;
+    let pm =
+        update_payment_method_details(&state, pm, Some(req.nick_name), None, &key_store).await?;
+
+    get_payment_method_response(pm, &key_store)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn set_default_payment_method(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::DefaultPaymentMethod,
+) -> errors::RouterResponse<api::CustomerDefaultPaymentMethodResponse> {
+    let db = &*state.store;
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &req.customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let pm = find_customer_payment_method(
+        db,
+        &merchant_account.merchant_id,
+        &customer.customer_id,
+        &req.payment_method_id,
+    )
+    .await?;
+
+    let customer = db
+        .update_customer_by_customer_id_merchant_id(
+            customer.customer_id,
+            customer.merchant_id,
+            storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id: Some(pm.payment_method_id),
+            },
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the default payment method of the customer")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::CustomerDefaultPaymentMethodResponse {
+            customer_id: customer.customer_id,
+            default_payment_method_id: customer.default_payment_method_id,
+            payment_method: pm.payment_method,
+            payment_method_type: pm.payment_method_type,
+        },
+    ))
+}
+
+/// Replaces the default payment method of the customer with `new_default_payment_method_id`, if it
+/// currently is the given payment method
+pub async fn replace_customer_default_payment_method(
+    state: &routes::AppState,
+    customer_id: &str,
+    merchant_id: &str,
+    payment_method_id: &str,
+    new_default_payment_method_id: Option<String>,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<()> {
+    let db = &*state.store;
+    let customer = db
+        .find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the customer of the payment method")?;
+
+    if let Some(customer) = customer
+        .filter(|customer| customer.default_payment_method_id.as_deref() == Some(payment_method_id))
+    {
+        db.update_customer_by_customer_id_merchant_id(
+            customer.customer_id,
+            customer.merchant_id,
+            storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id: new_default_payment_method_id,
+            },
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the default payment method of the customer")?;
+    }
+    Ok(())
+}
+
+/// Records the time of a successful payment made with the saved payment method
+pub async fn update_last_used_at(
+    db: &dyn db::StorageInterface,
+    payment_method_id: &str,
+) -> errors::RouterResult<()> {
+    let pm = db
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    db.update_payment_method(
+        pm,
+        storage::PaymentMethodUpdate::LastUsedUpdate {
+            last_used_at: common_utils::date_time::now(),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the last usage of the payment method")?;
+    Ok(())
 }
 
 // Wrapper function to switch lockers
@@ -289,8 +749,8 @@ pub async fn add_card_hs(
         card: payment_methods::Card {
             card_number: card.card_number.to_owned(),
             name_on_card: card.card_holder_name.to_owned(),
-            card_exp_month: card.card_exp_month.to_owned(),
-            card_exp_year: card.card_exp_year.to_owned(),
+            card_exp_month: card.card_exp_month.to_owned().into(),
+            card_exp_year: card.card_exp_year.to_owned().into(),
             card_brand: None,
             card_isin: None,
             nick_name: card.nick_name.as_ref().map(masking::Secret::peek).cloned(),
@@ -298,9 +758,15 @@ pub async fn add_card_hs(
     });
     let store_card_payload = call_to_locker_hs(state, &payload, &customer_id).await?;
 
+    let card_fingerprint =
+        generate_card_fingerprint(state, &merchant_account.merchant_id, &card.card_number)
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)?;
+
     let payment_method_resp = payment_methods::mk_add_card_response_hs(
         card,
         store_card_payload.card_reference,
+        card_fingerprint,
         req,
         &merchant_account.merchant_id,
     );
@@ -316,7 +782,7 @@ pub async fn decode_and_decrypt_locker_data(
     enc_card_data: String,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
     // Fetch key
-    let key = key_store.key.get_inner().peek();
+    let key = &key_store.key_ring();
     // Decode
     let decoded_bytes = hex::decode(&enc_card_data)
         .into_report()
@@ -332,6 +798,21 @@ pub async fn decode_and_decrypt_locker_data(
         )
 }
 
+/// Encrypts payment method data with the active merchant key and hex encodes it, to be stored in
+/// the locker
+pub async fn encrypt_and_encode_locker_data(
+    key_store: &domain::MerchantKeyStore,
+    data: Secret<String>,
+) -> errors::CustomResult<String, errors::VaultError> {
+    let encrypted_data = encrypt_optional(Some(data), &key_store.key_ring())
+        .await
+        .change_context(errors::VaultError::SavePaymentMethodFailed)?
+        .map(Encryption::from)
+        .get_required_value("encrypted_data")
+        .change_context(errors::VaultError::SavePaymentMethodFailed)?;
+    Ok(hex::encode(encrypted_data.into_inner().peek()))
+}
+
 #[instrument(skip_all)]
 pub async fn get_payment_method_from_hs_locker<'a>(
     state: &'a routes::AppState,
@@ -340,13 +821,32 @@ pub async fn get_payment_method_from_hs_locker<'a>(
     merchant_id: &str,
     payment_method_reference: &'a str,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
+    let enc_data = get_encrypted_payment_method_from_hs_locker(
+        state,
+        customer_id,
+        merchant_id,
+        payment_method_reference,
+    )
+    .await?;
+    decode_and_decrypt_locker_data(key_store, enc_data).await
+}
+
+/// Returns the hex encoded payment method data held in the locker, which is encrypted with the
+/// merchant key
+#[instrument(skip_all)]
+pub async fn get_encrypted_payment_method_from_hs_locker<'a>(
+    state: &'a routes::AppState,
+    customer_id: &str,
+    merchant_id: &str,
+    payment_method_reference: &'a str,
+) -> errors::CustomResult<String, errors::VaultError> {
     let locker = &state.conf.locker;
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let jwekey = &state.conf.jwekey;
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let jwekey = &state.kms_secrets;
 
-    let payment_method_data = if !locker.mock_locker {
+    if !locker.mock_locker {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
             locker,
@@ -375,18 +875,21 @@ pub async fn get_payment_method_from_hs_locker<'a>(
             .payload
             .get_required_value("RetrieveCardRespPayload")
             .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
-        let enc_card_data = retrieve_card_resp
+        retrieve_card_resp
             .enc_card_data
             .get_required_value("enc_card_data")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
-        decode_and_decrypt_locker_data(key_store, enc_card_data.peek().to_string()).await?
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)
+            .map(|enc_card_data| enc_card_data.peek().to_string())
     } else {
-        mock_get_payment_method(&*state.store, key_store, payment_method_reference)
-            .await?
-            .payment_method
-            .payment_method_data
-    };
-    Ok(payment_method_data)
+        state
+            .store
+            .find_locker_by_card_id(payment_method_reference)
+            .await
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)?
+            .enc_card_data
+            .get_required_value("enc_card_data")
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)
+    }
 }
 
 #[instrument(skip_all)]
@@ -396,9 +899,9 @@ pub async fn call_to_locker_hs<'a>(
     customer_id: &str,
 ) -> errors::CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
     let locker = &state.conf.locker;
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let jwekey = &state.conf.jwekey;
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let jwekey = &state.kms_secrets;
     let db = &*state.store;
     let stored_card_response = if !locker.mock_locker {
@@ -453,9 +956,9 @@ pub async fn get_card_from_hs_locker<'a>(
     card_reference: &'a str,
 ) -> errors::CustomResult<payment_methods::Card, errors::VaultError> {
     let locker = &state.conf.locker;
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let jwekey = &state.conf.jwekey;
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let jwekey = &state.kms_secrets;
 
     if !locker.mock_locker {
@@ -506,9 +1009,9 @@ pub async fn delete_card_from_hs_locker<'a>(
     card_reference: &'a str,
 ) -> errors::RouterResult<payment_methods::DeleteCardResp> {
     let locker = &state.conf.locker;
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let jwekey = &state.conf.jwekey;
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let jwekey = &state.kms_secrets;
 
     let request = payment_methods::mk_delete_card_request_hs(
@@ -922,6 +1425,16 @@ pub async fn list_payment_methods(
             None => continue,
         };
 
+        let mca_pm_filters: Vec<admin::PaymentMethodFilter> = mca
+            .pm_filters
+            .map(|pm_filters| pm_filters.parse_value("Vec<PaymentMethodFilter>"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to deserialize pm_filters of merchant connector account")?
+            .unwrap_or_default();
+        let pm_filters =
+            pm_config_mapping.get_merchant_connector_filters(&mca.connector_name, &mca_pm_filters);
+
         filter_payment_methods(
             payment_methods,
             &mut req,
@@ -930,7 +1443,7 @@ pub async fn list_payment_methods(
             payment_attempt.as_ref(),
             billing_address.as_ref(),
             mca.connector_name,
-            pm_config_mapping,
+            pm_filters.as_deref(),
             &state.conf.mandates.supported_payment_methods,
         )
         .await?;
@@ -1479,7 +1992,7 @@ pub async fn filter_payment_methods(
     payment_attempt: Option<&storage::PaymentAttempt>,
     address: Option<&domain::Address>,
     connector: String,
-    config: &settings::ConnectorFilters,
+    pm_filters: Option<&settings::PaymentMethodFilters>,
     supported_payment_methods_for_mandate: &settings::SupportedPaymentMethodsForMandate,
 ) -> errors::CustomResult<(), errors::ApiErrorResponse> {
     for payment_method in payment_methods.into_iter() {
@@ -1548,8 +2061,7 @@ pub async fn filter_payment_methods(
                     };
 
                     let filter5 = filter_pm_based_on_config(
-                        config,
-                        &connector,
+                        pm_filters,
                         &payment_method_object.payment_method_type,
                         payment_attempt,
                         &mut payment_method_object.card_networks,
@@ -1615,19 +2127,15 @@ fn filter_pm_based_on_supported_payments_for_mandate(
         .unwrap_or(false)
 }
 
-fn filter_pm_based_on_config<'a>(
-    config: &'a crate::configs::settings::ConnectorFilters,
-    connector: &'a str,
-    payment_method_type: &'a api_enums::PaymentMethodType,
+fn filter_pm_based_on_config(
+    pm_filters: Option<&settings::PaymentMethodFilters>,
+    payment_method_type: &api_enums::PaymentMethodType,
     payment_attempt: Option<&storage::PaymentAttempt>,
     card_network: &mut Option<Vec<api_enums::CardNetwork>>,
     country: &Option<api_enums::CountryAlpha2>,
     currency: Option<api_enums::Currency>,
 ) -> bool {
-    config
-        .0
-        .get(connector)
-        .or_else(|| config.0.get("default"))
+    pm_filters
         .and_then(|inner| match payment_method_type {
             api_enums::PaymentMethodType::Credit | api_enums::PaymentMethodType::Debit => {
                 card_network_filter(country, currency, card_network, inner);
@@ -1935,6 +2443,109 @@ async fn filter_payment_mandate_based(
     Ok(recurring_filter)
 }
 
+/// Returns the payment method types enabled on the connectors of the business profile of the
+/// payment
+async fn get_enabled_payment_method_types(
+    state: &routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+) -> errors::RouterResult<Vec<(api_enums::PaymentMethod, RequestPaymentMethodTypes)>> {
+    let db = &*state.store;
+    let all_mcas = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let profile_id = crate::core::utils::get_profile_id_from_business_details(
+        payment_intent.business_country,
+        payment_intent.business_label.as_ref(),
+        merchant_account,
+        payment_intent.profile_id.as_ref(),
+        db,
+        false,
+    )
+    .await
+    .attach_printable("Could not find profile id from business details")?;
+
+    Ok(
+        helpers::filter_mca_based_on_business_profile(all_mcas, Some(profile_id))
+            .into_iter()
+            .filter_map(|mca| mca.payment_methods_enabled)
+            .flatten()
+            .filter_map(|payment_method| {
+                serde_json::from_value::<PaymentMethodsEnabled>(payment_method).ok()
+            })
+            .flat_map(|payment_methods_enabled| {
+                let payment_method = payment_methods_enabled.payment_method;
+                payment_methods_enabled
+                    .payment_method_types
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(move |payment_method_type| (payment_method, payment_method_type))
+            })
+            .collect(),
+    )
+}
+
+/// Checks whether the saved payment method can be used for the payment, against the currency and
+/// amount limits of the payment method types enabled for it
+fn get_payment_method_eligibility(
+    payment_method: &storage::PaymentMethod,
+    is_expired: bool,
+    payment_intent: &storage::PaymentIntent,
+    enabled_payment_method_types: &[(api_enums::PaymentMethod, RequestPaymentMethodTypes)],
+) -> api::PaymentMethodEligibility {
+    let mut ineligibility_reasons = Vec::new();
+    if is_expired {
+        ineligibility_reasons.push(api::PaymentMethodIneligibilityReason::Expired);
+    }
+
+    let matching_payment_method_types = enabled_payment_method_types
+        .iter()
+        .filter(|(enabled_payment_method, payment_method_type)| {
+            *enabled_payment_method == payment_method.payment_method
+                && payment_method.payment_method_type.map_or(true, |pm_type| {
+                    pm_type == payment_method_type.payment_method_type
+                })
+        })
+        .map(|(_, payment_method_type)| payment_method_type)
+        .collect::<Vec<_>>();
+    let currency_supported_payment_method_types = matching_payment_method_types
+        .iter()
+        .filter(|payment_method_type| {
+            filter_payment_currency_based(payment_intent, payment_method_type)
+        })
+        .collect::<Vec<_>>();
+    let is_currency_accepted = payment_method
+        .accepted_currency
+        .as_ref()
+        .zip(payment_intent.currency)
+        .map_or(true, |(accepted_currency, currency)| {
+            accepted_currency.contains(&currency)
+        });
+
+    if matching_payment_method_types.is_empty() {
+        ineligibility_reasons.push(api::PaymentMethodIneligibilityReason::PaymentMethodNotEnabled);
+    } else if currency_supported_payment_method_types.is_empty() || !is_currency_accepted {
+        ineligibility_reasons.push(api::PaymentMethodIneligibilityReason::CurrencyNotSupported);
+    } else if !currency_supported_payment_method_types
+        .iter()
+        .any(|payment_method_type| filter_payment_amount_based(payment_intent, payment_method_type))
+    {
+        ineligibility_reasons.push(api::PaymentMethodIneligibilityReason::AmountNotSupported);
+    }
+
+    api::PaymentMethodEligibility {
+        is_eligible: ineligibility_reasons.is_empty(),
+        ineligibility_reasons,
+    }
+}
+
 pub async fn do_list_customer_pm_fetch_customer_if_not_passed(
     state: routes::AppState,
     merchant_account: domain::MerchantAccount,
@@ -1943,36 +2554,39 @@ pub async fn do_list_customer_pm_fetch_customer_if_not_passed(
     customer_id: Option<&str>,
 ) -> errors::RouterResponse<api::CustomerPaymentMethodsListResponse> {
     let db = state.store.as_ref();
-    if let Some(customer_id) = customer_id {
-        Box::pin(list_customer_payment_method(
-            &state,
-            merchant_account,
-            key_store,
-            None,
-            customer_id,
-        ))
-        .await
-    } else {
-        let cloned_secret = req.and_then(|r| r.client_secret.as_ref().cloned());
-        let payment_intent = helpers::verify_payment_intent_time_and_client_secret(
-            db,
-            &merchant_account,
-            cloned_secret,
-        )
-        .await?;
-        let customer_id = payment_intent
+    let cloned_secret = req.and_then(|r| r.client_secret.as_ref().cloned());
+    let payment_intent =
+        helpers::verify_payment_intent_time_and_client_secret(db, &merchant_account, cloned_secret)
+            .await?;
+    let customer_id = match customer_id {
+        Some(customer_id) => {
+            utils::when(
+                payment_intent.as_ref().map_or(false, |intent| {
+                    intent.customer_id.as_deref() != Some(customer_id)
+                }),
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message:
+                            "customer associated with the payment and customer passed are not same"
+                                .into(),
+                    })
+                },
+            )?;
+            customer_id.to_owned()
+        }
+        None => payment_intent
             .as_ref()
             .and_then(|intent| intent.customer_id.to_owned())
-            .ok_or(errors::ApiErrorResponse::CustomerNotFound)?;
-        Box::pin(list_customer_payment_method(
-            &state,
-            merchant_account,
-            key_store,
-            payment_intent,
-            &customer_id,
-        ))
-        .await
-    }
+            .ok_or(errors::ApiErrorResponse::CustomerNotFound)?,
+    };
+    Box::pin(list_customer_payment_method(
+        &state,
+        merchant_account,
+        key_store,
+        payment_intent,
+        &customer_id,
+    ))
+    .await
 }
 
 pub async fn list_customer_payment_method(
@@ -1983,15 +2597,25 @@ pub async fn list_customer_payment_method(
     customer_id: &str,
 ) -> errors::RouterResponse<api::CustomerPaymentMethodsListResponse> {
     let db = &*state.store;
-    db.find_customer_by_customer_id_merchant_id(
-        customer_id,
-        &merchant_account.merchant_id,
-        &key_store,
-    )
-    .await
-    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let key = key_store.key.get_inner().peek();
+    let key = &key_store.key_ring();
+
+    // The eligibility of the saved payment methods is only known when listing them for a payment
+    let enabled_payment_method_types = payment_intent
+        .as_ref()
+        .async_map(|payment_intent| {
+            get_enabled_payment_method_types(state, &merchant_account, &key_store, payment_intent)
+        })
+        .await
+        .transpose()?;
 
     let is_requires_cvv = db
         .find_config_by_key_unwrap_or(
@@ -2032,6 +2656,22 @@ pub async fn list_customer_payment_method(
         } else {
             None
         };
+        let is_expired = card
+            .as_ref()
+            .map_or(false, account_updater::is_card_expired);
+        let eligibility = payment_intent
+            .as_ref()
+            .zip(enabled_payment_method_types.as_ref())
+            .map(|(payment_intent, enabled_payment_method_types)| {
+                get_payment_method_eligibility(
+                    &pm,
+                    is_expired,
+                    payment_intent,
+                    enabled_payment_method_types,
+                )
+            });
+        let is_default = customer.default_payment_method_id.as_ref() == Some(&pm.payment_method_id);
+
         //Need validation for enabled payment method ,querying MCA
         let pma = api::CustomerPaymentMethod {
             payment_token: parent_payment_method_token.to_owned(),
@@ -2051,6 +2691,11 @@ pub async fn list_customer_payment_method(
             #[cfg(not(feature = "payouts"))]
             bank_transfer: None,
             requires_cvv,
+            is_expired,
+            payment_method_id: pm.payment_method_id,
+            is_default,
+            last_used_at: pm.last_used_at,
+            eligibility,
         };
         customer_pms.push(pma.to_owned());
 
@@ -2108,15 +2753,89 @@ pub async fn list_customer_payment_method(
     Ok(services::ApplicationResponse::Json(response))
 }
 
+/// Lists the payment methods saved for the customer by their ids. Unlike
+/// [`list_customer_payment_method`], no payment tokens are generated and the card details are read
+/// from the encrypted payment method data instead of the locker.
+#[instrument(skip_all)]
+pub async fn list_payment_methods_by_customer_id(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: String,
+) -> errors::RouterResponse<Vec<api::PaymentMethodResponse>> {
+    let db = &*state.store;
+    db.find_customer_by_customer_id_merchant_id(
+        &customer_id,
+        &merchant_account.merchant_id,
+        &key_store,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &customer_id,
+            &merchant_account.merchant_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payment methods of the customer")?;
+
+    let mut response = Vec::with_capacity(payment_methods.len());
+    for pm in payment_methods {
+        response.push(get_payment_method_response(pm, &key_store).await?);
+    }
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Builds the response for a saved payment method, reading the card details from the encrypted
+/// payment method data instead of the locker
+pub async fn get_payment_method_response(
+    pm: storage::PaymentMethod,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<api::PaymentMethodResponse> {
+    let key = &key_store.key_ring();
+    let card_fingerprint = pm.card_fingerprint.clone().map(masking::Secret::new);
+    let card =
+        decrypt::<serde_json::Value, masking::WithType, _>(pm.payment_method_data.clone(), key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to decrypt payment method data")?
+            .map(|data| data.into_inner().expose())
+            .and_then(|data| serde_json::from_value::<PaymentMethodsData>(data).ok())
+            .and_then(|data| match data {
+                PaymentMethodsData::Card(card) => Some(api::CardDetailFromLocker {
+                    card_fingerprint,
+                    ..api::CardDetailFromLocker::from(card)
+                }),
+                _ => None,
+            });
+
+    Ok(api::PaymentMethodResponse {
+        merchant_id: pm.merchant_id,
+        customer_id: Some(pm.customer_id),
+        payment_method_id: pm.payment_method_id,
+        payment_method: pm.payment_method,
+        payment_method_type: pm.payment_method_type,
+        card,
+        metadata: pm.metadata,
+        created: Some(pm.created_at),
+        recurring_enabled: false,           //[#219]
+        installment_payment_enabled: false, //[#219]
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219]
+    })
+}
+
 async fn get_card_details(
     pm: &payment_method::PaymentMethod,
-    key: &[u8],
+    key: &domain::types::KeyRing,
     state: &routes::AppState,
     hyperswitch_token: &str,
     key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResult<Option<api::CardDetailFromLocker>> {
-    let mut _card_decrypted =
-        decrypt::<serde_json::Value, masking::WithType>(pm.payment_method_data.clone(), key)
+    let card_decrypted =
+        decrypt::<serde_json::Value, masking::WithType, _>(pm.payment_method_data.clone(), key)
             .await
             .change_context(errors::StorageError::DecryptionError)
             .attach_printable("unable to decrypt card details")
@@ -2129,9 +2848,16 @@ async fn get_card_details(
                 _ => None,
             });
 
-    Ok(Some(
-        get_lookup_key_from_locker(state, hyperswitch_token, pm, key_store).await?,
-    ))
+    let card = get_lookup_key_from_locker(state, hyperswitch_token, pm, key_store).await?;
+
+    // The nick name can be updated after the card was saved, so the saved card details take
+    // precedence over the ones from the locker
+    Ok(Some(api::CardDetailFromLocker {
+        nick_name: card_decrypted
+            .and_then(|card_decrypted| card_decrypted.nick_name)
+            .or(card.nick_name),
+        ..card
+    }))
 }
 
 pub async fn get_lookup_key_from_locker(
@@ -2140,15 +2866,10 @@ pub async fn get_lookup_key_from_locker(
     pm: &storage::PaymentMethod,
     merchant_key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResult<api::CardDetailFromLocker> {
-    let card = get_card_from_locker(
-        state,
-        &pm.customer_id,
-        &pm.merchant_id,
-        &pm.payment_method_id,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Error getting card from card vault")?;
+    let card = get_card_from_locker(state, &pm.customer_id, &pm.merchant_id, pm.get_locker_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error getting card from card vault")?;
     let card_detail = payment_methods::get_card_detail(pm, card)
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Get Card Details Failed")?;
@@ -2177,7 +2898,7 @@ pub async fn get_lookup_key_for_payout_method(
         key_store,
         &pm.customer_id,
         &pm.merchant_id,
-        &pm.payment_method_id,
+        pm.get_locker_id(),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -2272,7 +2993,7 @@ impl TempLockerCardSupport {
             value1,
             Some(value2),
             payment_token.to_string(),
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.key_ring(),
         )
         .await?;
         vault::add_delete_tokenized_data_task(
@@ -2297,15 +3018,11 @@ pub async fn retrieve_payment_method(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
     let card = if pm.payment_method == enums::PaymentMethod::Card {
-        let card = get_card_from_locker(
-            &state,
-            &pm.customer_id,
-            &pm.merchant_id,
-            &pm.payment_method_id,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Error getting card from card vault")?;
+        let card =
+            get_card_from_locker(&state, &pm.customer_id, &pm.merchant_id, pm.get_locker_id())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error getting card from card vault")?;
         let card_detail = payment_methods::get_card_detail(&pm, card)
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed while getting card details from locker")?;
@@ -2334,6 +3051,7 @@ pub async fn retrieve_payment_method(
 pub async fn delete_payment_method(
     state: routes::AppState,
     merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
     pm_id: api::PaymentMethodId,
 ) -> errors::RouterResponse<api::PaymentMethodDeleteResponse> {
     let db = state.store.as_ref();
@@ -2347,7 +3065,7 @@ pub async fn delete_payment_method(
             &state,
             &key.customer_id,
             &key.merchant_id,
-            pm_id.payment_method_id.as_str(),
+            key.get_locker_id(),
         )
         .await?;
 
@@ -2359,6 +3077,16 @@ pub async fn delete_payment_method(
         }
     }
 
+    if let Err(error) = network_tokenization::handle_network_token_lifecycle_event(
+        &state,
+        &key,
+        TokenLifecycleEvent::Delete,
+    )
+    .await
+    {
+        logger::error!(network_token_delete_error=?error);
+    }
+
     db.delete_payment_method_by_merchant_id_payment_method_id(
         &merchant_account.merchant_id,
         pm_id.payment_method_id.as_str(),
@@ -2366,6 +3094,16 @@ pub async fn delete_payment_method(
     .await
     .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
+    replace_customer_default_payment_method(
+        &state,
+        &key.customer_id,
+        &merchant_account.merchant_id,
+        &key.payment_method_id,
+        None,
+        &key_store,
+    )
+    .await?;
+
     Ok(services::ApplicationResponse::Json(
         api::PaymentMethodDeleteResponse {
             payment_method_id: key.payment_method_id,
@@ -2378,7 +3116,7 @@ pub async fn create_encrypted_payment_method_data(
     key_store: &domain::MerchantKeyStore,
     pm_data: Option<PaymentMethodsData>,
 ) -> Option<Encryption> {
-    let key = key_store.key.get_inner().peek();
+    let key = &key_store.key_ring();
 
     let pm_data_encrypted: Option<Encryption> = pm_data
         .as_ref()
@@ -2403,3 +3141,213 @@ pub async fn create_encrypted_payment_method_data(
 
     pm_data_encrypted
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_updated_card_details_refresh_expiry_and_card_holder_name() {
+        let card = api::CardDetail {
+            card_number: ::cards::CardNumber::from_str("4111111111111111").unwrap(),
+            card_exp_month: "03".parse().unwrap(),
+            card_exp_year: "2030".parse().unwrap(),
+            card_holder_name: Some(Secret::new("John Doe".to_string())),
+            nick_name: None,
+        };
+        let saved_card_details = CardDetailsPaymentMethod {
+            last4_digits: Some("1111".to_string()),
+            issuer_country: Some("US".to_string()),
+            expiry_month: Some(Secret::new("03".to_string())),
+            expiry_year: Some(Secret::new("2026".to_string())),
+            nick_name: Some(Secret::new("Travel card".to_string())),
+            card_holder_name: Some(Secret::new("J Doe".to_string())),
+        };
+
+        let updated_card_details =
+            get_updated_card_details(Some(saved_card_details.clone()), &card).unwrap();
+        assert_eq!(
+            updated_card_details
+                .expiry_year
+                .as_ref()
+                .map(|year| year.peek().as_str()),
+            Some("2030")
+        );
+        assert_eq!(
+            updated_card_details
+                .card_holder_name
+                .as_ref()
+                .map(|name| name.peek().as_str()),
+            Some("John Doe")
+        );
+        assert_eq!(updated_card_details.issuer_country.as_deref(), Some("US"));
+        assert!(updated_card_details.nick_name.is_some());
+
+        // Card details that are already up to date are left as they are
+        assert!(get_updated_card_details(Some(updated_card_details), &card).is_none());
+        // A card saved again without a card holder name keeps the saved one
+        assert!(get_updated_card_details(
+            Some(CardDetailsPaymentMethod {
+                expiry_year: Some(Secret::new("2030".to_string())),
+                ..saved_card_details
+            }),
+            &api::CardDetail {
+                card_holder_name: None,
+                ..card
+            }
+        )
+        .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_customer_payment_method_must_belong_to_the_customer() {
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        db.insert_payment_method(storage::PaymentMethodNew {
+            customer_id: "cus_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            payment_method_id: "pm_1".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        assert!(
+            find_customer_payment_method(&db, "merchant_1", "cus_1", "pm_1")
+                .await
+                .is_ok()
+        );
+        for (merchant_id, customer_id) in [("merchant_1", "cus_2"), ("merchant_2", "cus_1")] {
+            let error = find_customer_payment_method(&db, merchant_id, customer_id, "pm_1")
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::PaymentMethodNotFound
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_payment_method_eligibility_reasons() {
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let payment_method = db
+            .insert_payment_method(storage::PaymentMethodNew {
+                payment_method: enums::PaymentMethod::Card,
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let payment_intent = storage::PaymentIntent {
+            id: 1,
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            status: enums::IntentStatus::RequiresPaymentMethod,
+            amount: 5000,
+            currency: Some(enums::Currency::USD),
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: data_models::RemoteStorageObject::ForeignID("pay_1_1".to_string()),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+        };
+        let credit = RequestPaymentMethodTypes {
+            payment_method_type: api_enums::PaymentMethodType::Credit,
+            payment_experience: None,
+            card_networks: None,
+            accepted_currencies: None,
+            accepted_countries: None,
+            minimum_amount: None,
+            maximum_amount: None,
+            recurring_enabled: true,
+            installment_payment_enabled: true,
+        };
+        let reasons =
+            |is_expired: bool, enabled_payment_method_types: Vec<RequestPaymentMethodTypes>| {
+                get_payment_method_eligibility(
+                    &payment_method,
+                    is_expired,
+                    &payment_intent,
+                    &enabled_payment_method_types
+                        .into_iter()
+                        .map(|payment_method_type| {
+                            (api_enums::PaymentMethod::Card, payment_method_type)
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .ineligibility_reasons
+            };
+
+        assert!(reasons(false, vec![credit.clone()]).is_empty());
+        assert_eq!(
+            reasons(true, vec![]),
+            vec![
+                api::PaymentMethodIneligibilityReason::Expired,
+                api::PaymentMethodIneligibilityReason::PaymentMethodNotEnabled
+            ]
+        );
+        assert_eq!(
+            reasons(
+                false,
+                vec![RequestPaymentMethodTypes {
+                    payment_method_type: api_enums::PaymentMethodType::Debit,
+                    ..credit.clone()
+                }]
+            ),
+            vec![api::PaymentMethodIneligibilityReason::PaymentMethodNotEnabled]
+        );
+        assert_eq!(
+            reasons(
+                false,
+                vec![RequestPaymentMethodTypes {
+                    accepted_currencies: Some(admin::AcceptedCurrencies::EnableOnly(vec![
+                        api_enums::Currency::EUR
+                    ])),
+                    ..credit.clone()
+                }]
+            ),
+            vec![api::PaymentMethodIneligibilityReason::CurrencyNotSupported]
+        );
+        assert_eq!(
+            reasons(
+                false,
+                vec![RequestPaymentMethodTypes {
+                    maximum_amount: Some(1000),
+                    ..credit
+                }]
+            ),
+            vec![api::PaymentMethodIneligibilityReason::AmountNotSupported]
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use external_services::network_tokenization::{
+    CardDetails, NetworkToken, NetworkTokenProvider, TokenLifecycleEvent,
+};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, StorageErrorExt},
+        payments::PaymentData,
+    },
+    logger,
+    routes::AppState,
+    types::{
+        self, api,
+        domain::{
+            self,
+            types::{decrypt, encrypt},
+        },
+        storage,
+    },
+    utils,
+};
+
+/// Returns the network token and a fresh cryptogram to be sent to the connector in place of the
+/// card number, when the payment is made with a saved card and both the token service provider
+/// and the connector support it.
+///
+/// Any failure is logged and results in `None`, so that the payment falls back to the card number.
+#[instrument(skip_all)]
+pub async fn get_network_token_data<F: Clone>(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    payment_data: &PaymentData<F>,
+    key_store: &domain::MerchantKeyStore,
+) -> Option<types::NetworkTokenData> {
+    let provider = state.network_token_provider.as_ref()?;
+
+    if !state
+        .conf
+        .network_tokenization
+        .supported_connectors
+        .contains(&connector.connector_name)
+    {
+        return None;
+    }
+
+    let card = match payment_data.payment_method_data.as_ref() {
+        Some(api::PaymentMethodData::Card(card)) => card,
+        _ => return None,
+    };
+    let payment_method_id = payment_data.payment_attempt.payment_method_id.as_ref()?;
+
+    get_or_provision_network_token(state, provider, payment_method_id, card, key_store)
+        .await
+        .map_err(|error| logger::error!(network_tokenization_error=?error))
+        .ok()
+}
+
+async fn get_or_provision_network_token(
+    state: &AppState,
+    provider: &Arc<dyn NetworkTokenProvider>,
+    payment_method_id: &str,
+    card: &api::Card,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<types::NetworkTokenData> {
+    let db = &*state.store;
+    let key = &key_store.key_ring();
+
+    let payment_method = db
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let stored_token = decrypt::<serde_json::Value, masking::WithType, _>(
+        payment_method.network_token_data.clone(),
+        key,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt network token data")?
+    .map(|network_token| {
+        network_token
+            .into_inner()
+            .expose()
+            .parse_value::<NetworkToken>("NetworkToken")
+    })
+    .transpose()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to deserialize network token data")?;
+
+    let network_token = match stored_token {
+        Some(network_token) => network_token,
+        None => {
+            let network_token = provider
+                .provision_token(&CardDetails {
+                    card_number: Secret::new(card.card_number.peek().to_string()),
+                    card_exp_month: card.card_exp_month.clone().into(),
+                    card_exp_year: card.card_exp_year.clone().into(),
+                    card_holder_name: Some(card.card_holder_name.clone()),
+                })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to provision network token")?;
+
+            let network_token_value =
+                utils::Encode::<NetworkToken>::encode_to_value(&network_token)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize network token data")?;
+            let network_token_data = encrypt(
+                Secret::<_, masking::WithType>::new(network_token_value),
+                key,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt network token data")?;
+
+            db.update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+                    network_token_reference: Some(network_token.token_reference.clone()),
+                    network_token_data: Some(network_token_data.into()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store network token in payment method")?;
+
+            network_token
+        }
+    };
+
+    let cryptogram = provider
+        .get_cryptogram(&network_token.token_reference)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch network token cryptogram")?;
+
+    Ok(types::NetworkTokenData {
+        token_number: network_token.token_number,
+        token_exp_month: network_token.token_exp_month,
+        token_exp_year: network_token.token_exp_year,
+        cryptogram: cryptogram.cryptogram,
+        eci: cryptogram.eci,
+    })
+}
+
+/// Forwards a lifecycle event to the token service provider, for the network token provisioned for
+/// the payment method, if any.
+#[instrument(skip_all)]
+pub async fn handle_network_token_lifecycle_event(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+    event: TokenLifecycleEvent,
+) -> errors::RouterResult<()> {
+    match (
+        state.network_token_provider.as_ref(),
+        payment_method.network_token_reference.as_ref(),
+    ) {
+        (Some(provider), Some(token_reference)) => provider
+            .handle_lifecycle_event(token_reference, event)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update network token lifecycle"),
+        _ => Ok(()),
+    }
+}
@@ -37,7 +37,7 @@ pub struct StoreGenericReq<'a> {
     pub enc_data: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Card {
     pub card_number: cards::CardNumber,
     pub name_on_card: Option<Secret<String>>,
@@ -183,18 +183,18 @@ pub fn get_dotted_jws(jws: encryption::JwsBody) -> String {
 }
 
 pub async fn get_decrypted_response_payload(
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "encryption_service"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "encryption_service")] jwekey: &settings::ActiveKmsSecrets,
     jwe_body: encryption::JweBody,
 ) -> CustomResult<String, errors::VaultError> {
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let public_key = jwekey.jwekey.peek().vault_encryption_key.as_bytes();
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let private_key = jwekey.jwekey.peek().vault_private_key.as_bytes();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let public_key = jwekey.vault_encryption_key.as_bytes();
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let private_key = jwekey.vault_private_key.as_bytes();
 
     let jwt = get_dotted_jwe(jwe_body);
@@ -221,8 +221,8 @@ pub async fn get_decrypted_response_payload(
 }
 
 pub async fn mk_basilisk_req(
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "encryption_service")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "encryption_service"))] jwekey: &settings::Jwekey,
     jws: &str,
 ) -> CustomResult<encryption::JweBody, errors::VaultError> {
     let jws_payload: Vec<&str> = jws.split('.').collect();
@@ -240,10 +240,10 @@ pub async fn mk_basilisk_req(
     let payload = utils::Encode::<encryption::JwsBody>::encode_to_vec(&jws_body)
         .change_context(errors::VaultError::SaveCardFailed)?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let public_key = jwekey.jwekey.peek().vault_encryption_key.as_bytes();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let public_key = jwekey.vault_encryption_key.as_bytes();
 
     let jwe_encrypted = encryption::encrypt_jwe(&payload, public_key)
@@ -268,18 +268,18 @@ pub async fn mk_basilisk_req(
 }
 
 pub async fn mk_add_locker_request_hs<'a>(
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "encryption_service"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "encryption_service")] jwekey: &settings::ActiveKmsSecrets,
     locker: &settings::Locker,
     payload: &StoreLockerReq<'a>,
 ) -> CustomResult<services::Request, errors::VaultError> {
     let payload = utils::Encode::<StoreCardReq<'_>>::encode_to_vec(&payload)
         .change_context(errors::VaultError::RequestEncodingFailed)?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let private_key = jwekey.jwekey.peek().vault_private_key.as_bytes();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let private_key = jwekey.vault_private_key.as_bytes();
 
     let jws = encryption::jws_sign_payload(&payload, &locker.locker_signing_key_id, private_key)
@@ -301,6 +301,7 @@ pub async fn mk_add_locker_request_hs<'a>(
 pub fn mk_add_card_response_hs(
     card: api::CardDetail,
     card_reference: String,
+    card_fingerprint: String,
     req: api::PaymentMethodCreate,
     merchant_id: &str,
 ) -> api::PaymentMethodResponse {
@@ -310,10 +311,10 @@ pub fn mk_add_card_response_hs(
         last4_digits: Some(card_number.split_off(card_number.len() - 4)),
         issuer_country: None, // [#256] bin mapping
         card_number: Some(card.card_number),
-        expiry_month: Some(card.card_exp_month),
-        expiry_year: Some(card.card_exp_year),
-        card_token: None,       // [#256]
-        card_fingerprint: None, // fingerprint not send by basilisk-hs need to have this feature in case we need it in future
+        expiry_month: Some(card.card_exp_month.into()),
+        expiry_year: Some(card.card_exp_year.into()),
+        card_token: None, // [#256]
+        card_fingerprint: Some(card_fingerprint.into()),
         card_holder_name: card.card_holder_name,
         nick_name: card.nick_name,
     };
@@ -344,8 +345,8 @@ pub fn mk_add_card_response(
         last4_digits: Some(card_number.split_off(card_number.len() - 4)),
         issuer_country: None, // [#256] bin mapping
         card_number: Some(card.card_number),
-        expiry_month: Some(card.card_exp_month),
-        expiry_year: Some(card.card_exp_year),
+        expiry_month: Some(card.card_exp_month.into()),
+        expiry_year: Some(card.card_exp_year.into()),
         card_token: Some(response.external_id.into()), // [#256]
         card_fingerprint: Some(response.card_fingerprint),
         card_holder_name: card.card_holder_name,
@@ -382,8 +383,8 @@ pub fn mk_add_card_request(
     let add_card_req = AddCardRequest {
         card_number: card.card_number.clone(),
         customer_id: &customer_id,
-        card_exp_month: card.card_exp_month.clone(),
-        card_exp_year: card.card_exp_year.clone(),
+        card_exp_month: card.card_exp_month.clone().into(),
+        card_exp_year: card.card_exp_year.clone().into(),
         merchant_id: locker_id,
         email_address: match Email::from_str("dummy@gmail.com") {
             Ok(email) => Some(email),
@@ -406,8 +407,8 @@ pub fn mk_add_card_request(
 }
 
 pub async fn mk_get_card_request_hs(
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "encryption_service"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "encryption_service")] jwekey: &settings::ActiveKmsSecrets,
     locker: &settings::Locker,
     customer_id: &str,
     merchant_id: &str,
@@ -422,10 +423,10 @@ pub async fn mk_get_card_request_hs(
     let payload = utils::Encode::<CardReqBody<'_>>::encode_to_vec(&card_req_body)
         .change_context(errors::VaultError::RequestEncodingFailed)?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let private_key = jwekey.jwekey.peek().vault_private_key.as_bytes();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let private_key = jwekey.vault_private_key.as_bytes();
 
     let jws = encryption::jws_sign_payload(&payload, &locker.locker_signing_key_id, private_key)
@@ -486,8 +487,8 @@ pub fn mk_get_card_response(card: GetCardResponse) -> errors::RouterResult<Card>
 }
 
 pub async fn mk_delete_card_request_hs(
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "encryption_service")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "encryption_service"))] jwekey: &settings::Jwekey,
     locker: &settings::Locker,
     customer_id: &str,
     merchant_id: &str,
@@ -502,10 +503,10 @@ pub async fn mk_delete_card_request_hs(
     let payload = utils::Encode::<CardReqBody<'_>>::encode_to_vec(&card_req_body)
         .change_context(errors::VaultError::RequestEncodingFailed)?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let private_key = jwekey.jwekey.peek().vault_private_key.as_bytes();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let private_key = jwekey.vault_private_key.as_bytes();
 
     let jws = encryption::jws_sign_payload(&payload, &locker.locker_signing_key_id, private_key)
@@ -572,7 +573,7 @@ pub fn get_card_detail(
         expiry_month: Some(response.card_exp_month),
         expiry_year: Some(response.card_exp_year),
         card_token: None,
-        card_fingerprint: None,
+        card_fingerprint: pm.card_fingerprint.clone().map(masking::Secret::new),
         card_holder_name: response.name_on_card,
         nick_name: response.nick_name.map(masking::Secret::new),
     };
@@ -637,3 +638,51 @@ pub fn mk_card_value2(
         .change_context(errors::VaultError::FetchCardFailed)?;
     Ok(value2_req)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use card_vault::types as vault;
+
+    use super::*;
+
+    #[test]
+    fn test_card_vault_responses_match_locker_responses() {
+        let store = vault::VaultResponse::ok(Some(vault::StoreCardResponse {
+            card_reference: "card_ref".to_string(),
+            duplicate: Some(false),
+        }));
+        let store: StoreCardResp =
+            serde_json::from_str(&serde_json::to_string(&store).unwrap()).unwrap();
+        assert_eq!(store.status, "SUCCESS");
+        assert_eq!(store.payload.unwrap().card_reference, "card_ref");
+
+        let stored_card = vault::StoredData::Card(vault::Card {
+            card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            name_on_card: Some(Secret::new("John Doe".to_string())),
+            card_exp_month: Secret::new("10".to_string()),
+            card_exp_year: Secret::new("2030".to_string()),
+            card_brand: None,
+            card_isin: None,
+            nick_name: None,
+        });
+        let retrieve =
+            vault::VaultResponse::ok(Some(vault::RetrieveCardResponse::from(stored_card)));
+        let retrieve: RetrieveCardResp =
+            serde_json::from_str(&serde_json::to_string(&retrieve).unwrap()).unwrap();
+        assert_eq!(retrieve.status, "SUCCESS");
+        assert!(retrieve.payload.and_then(|payload| payload.card).is_some());
+
+        let delete = vault::VaultResponse::<()>::ok(None);
+        let delete: DeleteCardResp =
+            serde_json::from_str(&serde_json::to_string(&delete).unwrap()).unwrap();
+        assert_eq!(delete.status, "SUCCESS");
+
+        let failure = vault::VaultResponse::<()>::error("VE_03", "Card not found".to_string());
+        let failure: DeleteCardResp =
+            serde_json::from_str(&serde_json::to_string(&failure).unwrap()).unwrap();
+        assert_ne!(failure.status, "SUCCESS");
+        assert_eq!(failure.error_code.as_deref(), Some("VE_03"));
+    }
+}
@@ -1,12 +1,8 @@
-use common_utils::{
-    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
-    ext_traits::BytesExt,
-    generate_id_with_default_len,
-};
+use common_utils::{crypto::GcmAes256, ext_traits::BytesExt, generate_id_with_default_len};
 use error_stack::{report, IntoReport, ResultExt};
 #[cfg(feature = "basilisk")]
 use josekit::jwe;
-use masking::PeekInterface;
+use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing};
 use scheduler::{types::process_data, utils as process_tracker_utils};
 
@@ -49,8 +45,8 @@ impl Vaultable for api::Card {
     fn get_value1(&self, _customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
         let value1 = api::TokenizedCardValue1 {
             card_number: self.card_number.peek().clone(),
-            exp_year: self.card_exp_year.peek().clone(),
-            exp_month: self.card_exp_month.peek().clone(),
+            exp_year: self.card_exp_year.four_digits(),
+            exp_month: self.card_exp_month.two_digits(),
             name_on_card: Some(self.card_holder_name.peek().clone()),
             nickname: None,
             card_last_four: None,
@@ -64,7 +60,10 @@ impl Vaultable for api::Card {
 
     fn get_value2(&self, customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
         let value2 = api::TokenizedCardValue2 {
-            card_security_code: Some(self.card_cvc.peek().clone()),
+            card_security_code: self
+                .card_cvc
+                .clone()
+                .map(|card_cvc| masking::Secret::<String>::from(card_cvc).expose()),
             card_fingerprint: None,
             external_id: None,
             customer_id,
@@ -97,10 +96,23 @@ impl Vaultable for api::Card {
                 .into_report()
                 .change_context(errors::VaultError::ResponseDeserializationFailed)
                 .attach_printable("Invalid card number format from the mock locker")?,
-            card_exp_month: value1.exp_month.into(),
-            card_exp_year: value1.exp_year.into(),
+            card_exp_month: value1
+                .exp_month
+                .parse()
+                .change_context(errors::VaultError::ResponseDeserializationFailed)
+                .attach_printable("Invalid card expiry month from the locker")?,
+            card_exp_year: value1
+                .exp_year
+                .parse()
+                .change_context(errors::VaultError::CardExpired)
+                .attach_printable("Invalid or past card expiry year from the locker")?,
             card_holder_name: value1.name_on_card.unwrap_or_default().into(),
-            card_cvc: value2.card_security_code.unwrap_or_default().into(),
+            card_cvc: value2
+                .card_security_code
+                .map(|card_cvc| card_cvc.parse())
+                .transpose()
+                .change_context(errors::VaultError::ResponseDeserializationFailed)
+                .attach_printable("Invalid card security code from the locker")?,
             card_issuer: None,
             card_network: None,
             bank_code: None,
@@ -352,8 +364,8 @@ impl Vaultable for api::CardPayout {
     fn get_value1(&self, _customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
         let value1 = api::TokenizedCardValue1 {
             card_number: self.card_number.peek().clone(),
-            exp_year: self.expiry_year.peek().clone(),
-            exp_month: self.expiry_month.peek().clone(),
+            exp_year: self.expiry_year.four_digits(),
+            exp_month: self.expiry_month.two_digits(),
             name_on_card: Some(self.card_holder_name.peek().clone()),
             nickname: None,
             card_last_four: None,
@@ -398,8 +410,16 @@ impl Vaultable for api::CardPayout {
                 .card_number
                 .parse()
                 .map_err(|_| errors::VaultError::FetchCardFailed)?,
-            expiry_month: value1.exp_month.into(),
-            expiry_year: value1.exp_year.into(),
+            expiry_month: value1
+                .exp_month
+                .parse()
+                .change_context(errors::VaultError::ResponseDeserializationFailed)
+                .attach_printable("Invalid card expiry month from the locker")?,
+            expiry_year: value1
+                .exp_year
+                .parse()
+                .change_context(errors::VaultError::CardExpired)
+                .attach_printable("Invalid or past card expiry year from the locker")?,
             card_holder_name: value1.name_on_card.unwrap_or_default().into(),
         };
 
@@ -626,11 +646,20 @@ impl Vault {
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> RouterResult<(Option<api::PaymentMethodData>, SupplementaryVaultData)> {
         let de_tokenize =
-            get_tokenized_data(state, lookup_key, true, merchant_key_store.key.get_inner()).await?;
+            get_tokenized_data(state, lookup_key, true, &merchant_key_store.key_ring()).await?;
         let (payment_method, customer_id) =
-            api::PaymentMethodData::from_values(de_tokenize.value1, de_tokenize.value2)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error parsing Payment Method from Values")?;
+            api::PaymentMethodData::from_values(de_tokenize.value1, de_tokenize.value2).map_err(
+                |error| match error.current_context() {
+                    errors::VaultError::CardExpired => {
+                        error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                            message: "Card Expired".to_string(),
+                        })
+                    }
+                    _ => error
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Error parsing Payment Method from Values"),
+                },
+            )?;
 
         Ok((Some(payment_method), customer_id))
     }
@@ -661,7 +690,7 @@ impl Vault {
             value1,
             Some(value2),
             lookup_key,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.key_ring(),
         )
         .await?;
         add_delete_tokenized_data_task(&*state.store, &lookup_key, pm).await?;
@@ -677,11 +706,20 @@ impl Vault {
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> RouterResult<(Option<api::PayoutMethodData>, SupplementaryVaultData)> {
         let de_tokenize =
-            get_tokenized_data(state, lookup_key, true, merchant_key_store.key.get_inner()).await?;
+            get_tokenized_data(state, lookup_key, true, &merchant_key_store.key_ring()).await?;
         let (payout_method, supp_data) =
-            api::PayoutMethodData::from_values(de_tokenize.value1, de_tokenize.value2)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error parsing Payout Method from Values")?;
+            api::PayoutMethodData::from_values(de_tokenize.value1, de_tokenize.value2).map_err(
+                |error| match error.current_context() {
+                    errors::VaultError::CardExpired => {
+                        error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                            message: "Card Expired".to_string(),
+                        })
+                    }
+                    _ => error
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Error parsing Payout Method from Values"),
+                },
+            )?;
 
         Ok((Some(payout_method), supp_data))
     }
@@ -712,7 +750,7 @@ impl Vault {
             value1,
             Some(value2),
             lookup_key,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.key_ring(),
         )
         .await?;
         // add_delete_tokenized_data_task(&*state.store, &lookup_key, pm).await?;
@@ -748,7 +786,7 @@ pub async fn create_tokenize(
     value1: String,
     value2: Option<String>,
     lookup_key: String,
-    encryption_key: &masking::Secret<Vec<u8>>,
+    encryption_key: &domain::types::KeyRing,
 ) -> RouterResult<String> {
     let redis_key = get_redis_locker_key(lookup_key.as_str());
     let func = || async {
@@ -766,10 +804,10 @@ pub async fn create_tokenize(
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
-        let encrypted_payload = GcmAes256
-            .encode_message(encryption_key.peek().as_ref(), payload.as_bytes())
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to encode redis temp locker data")?;
+        let encrypted_payload =
+            domain::types::encode_message(encryption_key, &GcmAes256, payload.as_bytes())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode redis temp locker data")?;
 
         let redis_conn = state
             .store
@@ -818,7 +856,7 @@ pub async fn get_tokenized_data(
     state: &routes::AppState,
     lookup_key: &str,
     _should_get_value2: bool,
-    encryption_key: &masking::Secret<Vec<u8>>,
+    encryption_key: &domain::types::KeyRing,
 ) -> RouterResult<api::TokenizePayloadRequest> {
     let redis_key = get_redis_locker_key(lookup_key);
     let func = || async {
@@ -834,13 +872,10 @@ pub async fn get_tokenized_data(
 
         match response {
             Ok(resp) => {
-                let decrypted_payload = GcmAes256
-                    .decode_message(
-                        encryption_key.peek().as_ref(),
-                        masking::Secret::new(resp.into()),
-                    )
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed to decode redis temp locker data")?;
+                let decrypted_payload =
+                    domain::types::decode_message(encryption_key, &GcmAes256, &resp)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to decode redis temp locker data")?;
 
                 let get_response: api::TokenizePayloadRequest =
                     bytes::Bytes::from(decrypted_payload)
@@ -45,7 +45,7 @@ use crate::{
     configs::settings::PaymentMethodTypeTokenFilter,
     core::{
         errors::{self, CustomResult, RouterResponse, RouterResult},
-        payment_methods::PaymentMethodRetrieve,
+        payment_methods::{network_tokenization, PaymentMethodRetrieve},
         utils,
     },
     db::StorageInterface,
@@ -843,6 +843,22 @@ where
         ));
     }
 
+    // Saved cards are sent to the connector as a network token and cryptogram, when both the token
+    // service provider and the connector support it
+    if matches!(
+        tokenization_action,
+        TokenizationAction::SkipConnectorTokenization
+    ) {
+        if let Some(network_token_data) =
+            network_tokenization::get_network_token_data(state, &connector, payment_data, key_store)
+                .await
+        {
+            router_data.payment_method_token = Some(
+                router_types::PaymentMethodToken::NetworkToken(Box::new(network_token_data)),
+            );
+        }
+    }
+
     let pm_token = router_data
         .add_payment_method_token(state, &connector, &tokenization_action)
         .await?;
@@ -1875,6 +1891,7 @@ pub async fn apply_filters_on_payments(
             constraints.payment_method,
             constraints.payment_method_type,
             constraints.authentication_type,
+            constraints.card_fingerprint,
             merchant.storage_scheme,
         )
         .await
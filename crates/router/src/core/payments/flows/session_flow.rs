@@ -2,8 +2,10 @@ use api_models::payments as payment_types;
 use async_trait::async_trait;
 use common_utils::ext_traits::ByteSliceExt;
 use error_stack::{IntoReport, Report, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "encryption_service")]
+use external_services::{encryption_management, secrets_management::SecretManagementInterface};
+#[cfg(feature = "encryption_service")]
+use masking::ExposeInterface;
 
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
@@ -184,39 +186,67 @@ async fn create_applepay_session_token(
                     payment_request_data,
                     session_token_data,
                 } => {
-                    #[cfg(feature = "kms")]
-                    let decrypted_apple_pay_merchant_cert = kms::get_kms_client(&state.conf.kms)
+                    #[cfg(feature = "encryption_service")]
+                    let decrypted_apple_pay_merchant_cert =
+                        encryption_management::get_encryption_management_client(
+                            &state.conf.encryption_management,
+                        )
                         .await
-                        .decrypt(&state.conf.applepay_decrypt_keys.apple_pay_merchant_cert)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?
+                        .get_secret(
+                            state
+                                .conf
+                                .applepay_decrypt_keys
+                                .apple_pay_merchant_cert
+                                .clone()
+                                .into(),
+                        )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Apple pay merchant certificate decryption failed")?;
+                        .attach_printable("Apple pay merchant certificate decryption failed")?
+                        .expose();
 
-                    #[cfg(feature = "kms")]
+                    #[cfg(feature = "encryption_service")]
                     let decrypted_apple_pay_merchant_cert_key =
-                        kms::get_kms_client(&state.conf.kms)
-                            .await
-                            .decrypt(&state.conf.applepay_decrypt_keys.apple_pay_merchant_cert_key)
-                            .await
-                            .change_context(errors::ApiErrorResponse::InternalServerError)
-                            .attach_printable(
-                                "Apple pay merchant certificate key decryption failed",
-                            )?;
-
-                    #[cfg(feature = "kms")]
-                    let decrypted_merchant_identifier = kms::get_kms_client(&state.conf.kms)
+                        encryption_management::get_encryption_management_client(
+                            &state.conf.encryption_management,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?
+                        .get_secret(
+                            state
+                                .conf
+                                .applepay_decrypt_keys
+                                .apple_pay_merchant_cert_key
+                                .clone()
+                                .into(),
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Apple pay merchant certificate key decryption failed")?
+                        .expose();
+
+                    #[cfg(feature = "encryption_service")]
+                    let decrypted_merchant_identifier =
+                        encryption_management::get_encryption_management_client(
+                            &state.conf.encryption_management,
+                        )
                         .await
-                        .decrypt(
-                            &state
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?
+                        .get_secret(
+                            state
                                 .conf
                                 .applepay_merchant_configs
-                                .common_merchant_identifier,
+                                .common_merchant_identifier
+                                .clone()
+                                .into(),
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Apple pay merchant identifier decryption failed")?;
+                        .attach_printable("Apple pay merchant identifier decryption failed")?
+                        .expose();
 
-                    #[cfg(not(feature = "kms"))]
+                    #[cfg(not(feature = "encryption_service"))]
                     let decrypted_merchant_identifier = &state
                         .conf
                         .applepay_merchant_configs
@@ -227,11 +257,11 @@ async fn create_applepay_session_token(
                         session_token_data,
                     );
 
-                    #[cfg(not(feature = "kms"))]
+                    #[cfg(not(feature = "encryption_service"))]
                     let decrypted_apple_pay_merchant_cert =
                         &state.conf.applepay_decrypt_keys.apple_pay_merchant_cert;
 
-                    #[cfg(not(feature = "kms"))]
+                    #[cfg(not(feature = "encryption_service"))]
                     let decrypted_apple_pay_merchant_cert_key =
                         &state.conf.applepay_decrypt_keys.apple_pay_merchant_cert_key;
 
@@ -12,8 +12,8 @@ use data_models::{
 use diesel_models::enums;
 // TODO : Evaluate all the helper functions ()
 use error_stack::{report, IntoReport, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "encryption_service")]
+use external_services::{encryption_management, secrets_management::SecretManagementInterface};
 use josekit::jwe;
 use masking::{ExposeInterface, PeekInterface};
 use openssl::{
@@ -31,7 +31,7 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::{ConnectorRequestReferenceIdConfig, Server, TempLockerEnableConfig},
+    configs::settings::{self, ConnectorRequestReferenceIdConfig, Server, TempLockerEnableConfig},
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
@@ -112,7 +112,7 @@ pub async fn create_or_update_address_for_payment_by_request(
     payment_id: &str,
     storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
-    let key = merchant_key_store.key.get_inner().peek();
+    let key = &merchant_key_store.key_ring();
 
     Ok(match address_id {
         Some(id) => match req_address {
@@ -262,7 +262,7 @@ pub async fn create_or_find_address_for_payment_by_request(
     payment_id: &str,
     storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
-    let key = merchant_key_store.key.get_inner().peek();
+    let key = &merchant_key_store.key_ring();
 
     Ok(match address_id {
         Some(id) => Some(
@@ -316,7 +316,7 @@ pub async fn get_domain_address_for_payments(
     merchant_id: &str,
     customer_id: Option<&String>,
     payment_id: &str,
-    key: &[u8],
+    key: &domain::types::KeyRing,
     storage_scheme: enums::MerchantStorageScheme,
 ) -> CustomResult<domain::Address, common_utils::errors::CryptoError> {
     async {
@@ -444,15 +444,137 @@ pub async fn get_token_pm_type_mandate_details(
                 mandate_connector,
             ))
         }
-        None => Ok((
-            request.payment_token.to_owned(),
-            request.payment_method,
-            request.payment_method_type,
-            mandate_data,
-            None,
-            None,
-        )),
+        None => {
+            let default_payment_method = if should_use_default_payment_method(request) {
+                get_token_for_default_payment_method(
+                    state,
+                    request,
+                    merchant_account,
+                    merchant_key_store,
+                )
+                .await?
+            } else {
+                None
+            };
+
+            match default_payment_method {
+                Some((token, payment_method, payment_method_type)) => Ok((
+                    Some(token),
+                    Some(payment_method),
+                    payment_method_type.or(request.payment_method_type),
+                    mandate_data,
+                    None,
+                    None,
+                )),
+                None => Ok((
+                    request.payment_token.to_owned(),
+                    request.payment_method,
+                    request.payment_method_type,
+                    mandate_data,
+                    None,
+                    None,
+                )),
+            }
+        }
+    }
+}
+
+/// Off session payments made without a payment method are charged to the default payment method
+/// of the customer
+fn should_use_default_payment_method(request: &api::PaymentsRequest) -> bool {
+    request.off_session == Some(true)
+        && request.payment_token.is_none()
+        && request.payment_method_data.is_none()
+}
+
+/// Returns a payment token for the default payment method of the customer, to be used by off
+/// session payments made without a payment method. Only saved cards can be used by default.
+pub async fn get_token_for_default_payment_method(
+    state: &AppState,
+    req: &api::PaymentsRequest,
+    merchant_account: &domain::MerchantAccount,
+    merchant_key_store: &domain::MerchantKeyStore,
+) -> RouterResult<
+    Option<(
+        String,
+        storage_enums::PaymentMethod,
+        Option<storage_enums::PaymentMethodType>,
+    )>,
+> {
+    let db = &*state.store;
+    let customer_id = match req.customer_id.as_ref() {
+        Some(customer_id) => customer_id,
+        None => return Ok(None),
+    };
+
+    let default_payment_method_id = db
+        .find_customer_optional_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+            merchant_key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the customer")?
+        .and_then(|customer| customer.default_payment_method_id);
+    let default_payment_method_id = match default_payment_method_id {
+        Some(default_payment_method_id) => default_payment_method_id,
+        None => return Ok(None),
+    };
+
+    // The default payment method may have been deleted without the customer being updated
+    let payment_method = match db.find_payment_method(&default_payment_method_id).await {
+        Ok(payment_method) => payment_method,
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::warn!(
+                "Default payment method {default_payment_method_id} of customer {customer_id} was not found"
+            );
+            return Ok(None);
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the default payment method of the customer")
+        }
+    };
+    if payment_method.customer_id != *customer_id
+        || payment_method.merchant_id != merchant_account.merchant_id
+    {
+        logger::warn!(
+            "Default payment method {default_payment_method_id} does not belong to customer {customer_id}"
+        );
+        return Ok(None);
+    }
+    if payment_method.payment_method != storage_enums::PaymentMethod::Card
+        || req
+            .payment_method
+            .map_or(false, |pm| pm != payment_method.payment_method)
+    {
+        return Ok(None);
     }
+
+    let hyperswitch_token = generate_id(consts::ID_LENGTH, "token");
+    cards::get_lookup_key_from_locker(
+        state,
+        &hyperswitch_token,
+        &payment_method,
+        merchant_key_store,
+    )
+    .await?;
+
+    let parent_payment_method_token = generate_id(consts::ID_LENGTH, "token");
+    payment_methods::ParentPaymentMethodToken::create_key_for_token((
+        &parent_payment_method_token,
+        payment_method.payment_method,
+    ))
+    .insert(None, hyperswitch_token, state)
+    .await?;
+
+    Ok(Some((
+        parent_payment_method_token,
+        payment_method.payment_method,
+        payment_method.payment_method_type,
+    )))
 }
 
 pub async fn get_token_for_recurring_mandate(
@@ -605,64 +727,119 @@ pub fn validate_card_data(
     payment_method_data: Option<api::PaymentMethodData>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     if let Some(api::PaymentMethodData::Card(card)) = payment_method_data {
-        let cvc = card.card_cvc.peek().to_string();
-        if cvc.len() < 3 || cvc.len() > 4 {
-            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
-                message: "Invalid card_cvc length".to_string()
-            }))?
-        }
-        let card_cvc = cvc.parse::<u16>().into_report().change_context(
-            errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "card_cvc",
-            },
-        )?;
-        ::cards::CardSecurityCode::try_from(card_cvc).change_context(
-            errors::ApiErrorResponse::PreconditionFailed {
-                message: "Invalid Card CVC".to_string(),
-            },
-        )?;
-
-        let exp_month = card
-            .card_exp_month
-            .peek()
-            .to_string()
-            .parse::<u8>()
-            .into_report()
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "card_exp_month",
+        validate_card_number_length(&card.card_number)?;
+        let card_cvc = card.card_cvc.as_ref().get_required_value("card_cvc")?;
+        if let Some(card_network) = card
+            .card_network
+            .clone()
+            .or_else(|| card.card_number.get_card_network())
+        {
+            let is_valid_cvc_length = ::cards::network::get_network_rules(&card_network)
+                .is_valid_cvc_length(card_cvc.number_of_digits());
+            utils::when(!is_valid_cvc_length, || {
+                Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "card_cvc",
+                }))
+                .attach_printable(format!("Invalid card_cvc length for {card_network} card"))
             })?;
-        let month = ::cards::CardExpirationMonth::try_from(exp_month).change_context(
-            errors::ApiErrorResponse::PreconditionFailed {
-                message: "Invalid Expiry Month".to_string(),
-            },
-        )?;
-        let mut year_str = card.card_exp_year.peek().to_string();
-        if year_str.len() == 2 {
-            year_str = format!("20{}", year_str);
         }
-        let exp_year = year_str.parse::<u16>().into_report().change_context(
-            errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "card_exp_year",
-            },
-        )?;
-        let year = ::cards::CardExpirationYear::try_from(exp_year).change_context(
-            errors::ApiErrorResponse::PreconditionFailed {
-                message: "Invalid Expiry Year".to_string(),
-            },
-        )?;
 
-        let card_expiration = ::cards::CardExpiration { month, year };
-        let is_expired = card_expiration.is_expired().change_context(
-            errors::ApiErrorResponse::PreconditionFailed {
-                message: "Invalid card data".to_string(),
-            },
-        )?;
-        if is_expired {
-            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
-                message: "Card Expired".to_string()
-            }))?
-        }
+        validate_card_expiry(&card.card_exp_month, &card.card_exp_year)?;
+    }
+    Ok(())
+}
+
+/// Computes the fingerprint of the card in the payment method data, if the payment is made with a
+/// card
+pub async fn get_card_fingerprint(
+    state: &AppState,
+    merchant_id: &str,
+    payment_method_data: Option<&api::PaymentMethodData>,
+) -> RouterResult<Option<String>> {
+    payment_method_data
+        .and_then(|pm_data| match pm_data {
+            api::PaymentMethodData::Card(card) => Some(card),
+            _ => None,
+        })
+        .async_map(|card| cards::generate_card_fingerprint(state, merchant_id, &card.card_number))
+        .await
+        .transpose()
+}
+
+/// Rejects the payment if the card has already been used in as many payment attempts of the
+/// merchant as the velocity limit allows within the configured window
+pub async fn validate_card_velocity(
+    db: &dyn StorageInterface,
+    card_velocity: &settings::CardVelocity,
+    merchant_id: &str,
+    card_fingerprint: Option<&str>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    let (card_fingerprint, max_attempts) = match (card_fingerprint, card_velocity.max_attempts) {
+        (Some(card_fingerprint), Some(max_attempts)) => (card_fingerprint, max_attempts),
+        _ => return Ok(()),
+    };
+
+    let created_after =
+        common_utils::date_time::now().saturating_sub(Duration::seconds(card_velocity.window));
+    let attempt_count = db
+        .get_payment_attempt_count_by_card_fingerprint(
+            merchant_id,
+            card_fingerprint,
+            created_after,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the payment attempts made with the card")?;
+
+    utils::when(attempt_count >= max_attempts, || {
+        logger::warn!(
+            attempt_count,
+            max_attempts,
+            "Card velocity limit reached for merchant {merchant_id}"
+        );
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The card has been used in too many payments recently".to_string(),
+        })
+        .into_report()
+    })
+}
+
+/// Validates the number of digits of a card number received through the API against the rules
+/// of its network
+#[instrument(skip_all)]
+pub fn validate_card_number_length(
+    card_number: &::cards::CardNumber,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    card_number.validate_length().into_report().change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_number",
+        },
+    )
+}
+
+/// Rejects cards that have already expired, the expiry month and year are validated when parsed
+#[instrument(skip_all)]
+pub fn validate_card_expiry(
+    card_exp_month: &::cards::CardExpirationMonth,
+    card_exp_year: &::cards::CardExpirationYear,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let card_expiration = ::cards::CardExpiration {
+        month: card_exp_month.clone(),
+        year: card_exp_year.clone(),
+    };
+    let is_expired = card_expiration.is_expired().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid card data".to_string(),
+        },
+    )?;
+    if is_expired {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Card Expired".to_string()
+        }))?
     }
+
     Ok(())
 }
 
@@ -1207,7 +1384,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                         | request_customer_details.phone.is_some()
                         | request_customer_details.phone_country_code.is_some()
                     {
-                        let key = key_store.key.get_inner().peek();
+                        let key = &key_store.key_ring();
                         let customer_update = async {
                             Ok(Update {
                                 name: request_customer_details
@@ -1255,7 +1432,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                 }
                 None => {
                     let new_customer = async {
-                        let key = key_store.key.get_inner().peek();
+                        let key = &key_store.key_ring();
                         Ok(domain::Customer {
                             customer_id: customer_id.to_string(),
                             merchant_id: merchant_id.to_string(),
@@ -1283,6 +1460,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                             modified_at: common_utils::date_time::now(),
                             connector_customer: None,
                             address_id: None,
+                            default_payment_method_id: None,
                         })
                     }
                     .await
@@ -1400,13 +1578,22 @@ pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
                 },
             )?;
 
+            if payment_data.payment_attempt.payment_method_id.is_none() {
+                payment_data.payment_attempt.payment_method_id =
+                    supplementary_data.payment_method_id;
+            }
+
             Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(match pm.clone() {
                 Some(api::PaymentMethodData::Card(card)) => {
                     payment_data.payment_attempt.payment_method =
                         Some(storage_enums::PaymentMethod::Card);
                     if let Some(cvc) = card_cvc {
                         let mut updated_card = card;
-                        updated_card.card_cvc = cvc;
+                        updated_card.card_cvc = Some(cvc.peek().parse().change_context(
+                            errors::ApiErrorResponse::InvalidDataValue {
+                                field_name: "card_cvc",
+                            },
+                        )?);
                         let updated_pm = api::PaymentMethodData::Card(updated_card);
                         vault::Vault::store_payment_method_data_in_locker(
                             state,
@@ -2230,6 +2417,27 @@ pub async fn get_merchant_fullfillment_time(
     }
 }
 
+/// Fetches the payment link of the payment, for the responses of flows backed by payment links.
+pub async fn get_payment_link_data(
+    payment_link_id: Option<&String>,
+    db: &dyn StorageInterface,
+) -> RouterResult<Option<api_models::payments::PaymentLinkResponse>> {
+    match payment_link_id {
+        Some(payment_link_id) => {
+            let payment_link = db
+                .find_payment_link_by_payment_link_id(payment_link_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+            Ok(Some(api_models::payments::PaymentLinkResponse {
+                link: payment_link.link_to_pay,
+                payment_link_id: payment_link.payment_link_id,
+            }))
+        }
+        None => Ok(None),
+    }
+}
+
 pub(crate) fn validate_payment_status_against_not_allowed_statuses(
     intent_status: &storage_enums::IntentStatus,
     not_allowed_statuses: &[storage_enums::IntentStatus],
@@ -2532,6 +2740,130 @@ mod tests {
         )
         .is_err())
     }
+
+    #[test]
+    fn test_default_payment_method_is_used_for_off_session_payments_only() {
+        let request = api::PaymentsRequest {
+            off_session: Some(true),
+            ..Default::default()
+        };
+        assert!(should_use_default_payment_method(&request));
+        assert!(!should_use_default_payment_method(&api::PaymentsRequest {
+            off_session: None,
+            ..request.clone()
+        }));
+        assert!(!should_use_default_payment_method(&api::PaymentsRequest {
+            payment_token: Some("token_1".to_string()),
+            ..request.clone()
+        }));
+        #[allow(clippy::unwrap_used)]
+        let card = api::Card {
+            card_number: "4242424242424242".parse().unwrap(),
+            card_exp_month: "12".parse().unwrap(),
+            card_exp_year: "2099".parse().unwrap(),
+            card_holder_name: "John Doe".to_string().into(),
+            card_cvc: Some("123".parse().unwrap()),
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            card_issuing_country: None,
+            bank_code: None,
+            nick_name: None,
+        };
+        assert!(!should_use_default_payment_method(&api::PaymentsRequest {
+            payment_method_data: Some(api::PaymentMethodData::Card(card)),
+            ..request
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_validate_card_velocity() {
+        use data_models::payments::payment_attempt::{PaymentAttemptInterface, PaymentAttemptNew};
+
+        #[allow(clippy::expect_used)]
+        let db = storage_impl::mock_db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let storage_scheme = storage_enums::MerchantStorageScheme::PostgresOnly;
+        let card_velocity = settings::CardVelocity {
+            max_attempts: Some(2),
+            window: 3600,
+        };
+
+        let attempt_created_at = [
+            common_utils::date_time::now().saturating_sub(Duration::hours(2)),
+            common_utils::date_time::now(),
+        ];
+        for (idx, created_at) in attempt_created_at.into_iter().enumerate() {
+            #[allow(clippy::expect_used)]
+            db.insert_payment_attempt(
+                PaymentAttemptNew {
+                    payment_id: format!("pay_{idx}"),
+                    merchant_id: "merchant_1".to_string(),
+                    attempt_id: format!("pay_{idx}_1"),
+                    card_fingerprint: Some("fingerprint_1".to_string()),
+                    created_at: Some(created_at),
+                    ..Default::default()
+                },
+                storage_scheme,
+            )
+            .await
+            .expect("Failed to insert payment attempt");
+        }
+
+        // The attempt made outside the window is not counted
+        assert!(validate_card_velocity(
+            &db,
+            &card_velocity,
+            "merchant_1",
+            Some("fingerprint_1"),
+            storage_scheme
+        )
+        .await
+        .is_ok());
+
+        #[allow(clippy::expect_used)]
+        db.insert_payment_attempt(
+            PaymentAttemptNew {
+                payment_id: "pay_2".to_string(),
+                merchant_id: "merchant_1".to_string(),
+                attempt_id: "pay_2_1".to_string(),
+                card_fingerprint: Some("fingerprint_1".to_string()),
+                ..Default::default()
+            },
+            storage_scheme,
+        )
+        .await
+        .expect("Failed to insert payment attempt");
+
+        assert!(validate_card_velocity(
+            &db,
+            &card_velocity,
+            "merchant_1",
+            Some("fingerprint_1"),
+            storage_scheme
+        )
+        .await
+        .is_err());
+        assert!(validate_card_velocity(
+            &db,
+            &card_velocity,
+            "merchant_2",
+            Some("fingerprint_1"),
+            storage_scheme
+        )
+        .await
+        .is_ok());
+        assert!(validate_card_velocity(
+            &db,
+            &settings::CardVelocity::default(),
+            "merchant_1",
+            Some("fingerprint_1"),
+            storage_scheme
+        )
+        .await
+        .is_ok());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -2637,7 +2969,7 @@ pub async fn get_merchant_connector_account(
                     },
                 )?;
 
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "encryption_service")]
             let private_key = state
                 .kms_secrets
                 .jwekey
@@ -2645,7 +2977,7 @@ pub async fn get_merchant_connector_account(
                 .tunnel_private_key
                 .as_bytes();
 
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "encryption_service"))]
             let private_key = state.conf.jwekey.tunnel_private_key.as_bytes();
 
             let decrypted_mca = services::decrypt_jwe(mca_config.config.as_str(), services::KeyIdCheck::SkipKeyIdCheck, private_key, jwe::RSA_OAEP_256)
@@ -2940,6 +3272,8 @@ impl AttemptType {
             authentication_data: None,
             encoded_data: None,
             merchant_connector_id: None,
+            connector_latency: None,
+            card_fingerprint: None,
         }
     }
 
@@ -3079,6 +3413,74 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_validate_card_data_cvc_length_for_network() {
+        let card = |card_number: &str, card_cvc: Option<&str>| {
+            Some(super::api::PaymentMethodData::Card(super::api::Card {
+                card_number: card_number.parse().unwrap(),
+                card_exp_month: "12".parse().unwrap(),
+                card_exp_year: "2099".parse().unwrap(),
+                card_holder_name: "John Doe".to_string().into(),
+                card_cvc: card_cvc.map(|card_cvc| card_cvc.parse().unwrap()),
+                card_issuer: None,
+                card_network: None,
+                card_type: None,
+                card_issuing_country: None,
+                bank_code: None,
+                nick_name: None,
+            }))
+        };
+
+        assert!(super::validate_card_data(card("378282246310005", Some("1234"))).is_ok());
+        assert!(super::validate_card_data(card("4242424242424242", Some("123"))).is_ok());
+        assert!(super::validate_card_data(card("4242424242424242", Some("012"))).is_ok());
+        for invalid_card in [
+            card("378282246310005", Some("123")),
+            card("4242424242424242", Some("1234")),
+        ] {
+            assert!(matches!(
+                super::validate_card_data(invalid_card)
+                    .unwrap_err()
+                    .current_context(),
+                super::errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "card_cvc"
+                }
+            ));
+        }
+        assert!(super::validate_card_data(card("3782822463100003", Some("1234"))).is_err());
+        assert!(matches!(
+            super::validate_card_data(card("4242424242424242", None))
+                .unwrap_err()
+                .current_context(),
+            super::errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "card_cvc"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_validate_card_expiry() {
+        assert!(
+            super::validate_card_expiry(&"12".parse().unwrap(), &"99".parse().unwrap()).is_ok()
+        );
+
+        // a card expiring in the current year is expired once its month has passed, cards
+        // expiring in past years can not be parsed at all
+        let now = common_utils::date_time::now();
+        let current_year =
+            ::cards::CardExpirationYear::try_from(u16::try_from(now.year()).unwrap()).unwrap();
+        if let Ok(past_month) =
+            ::cards::CardExpirationMonth::try_from(u8::from(now.month()).saturating_sub(2))
+        {
+            assert!(matches!(
+                super::validate_card_expiry(&past_month, &current_year)
+                    .unwrap_err()
+                    .current_context(),
+                super::errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
 }
 
 #[instrument(skip_all)]
@@ -3103,8 +3505,8 @@ pub async fn get_additional_payment_data(
                         card_type: card_data.card_type.to_owned(),
                         card_issuing_country: card_data.card_issuing_country.to_owned(),
                         bank_code: card_data.bank_code.to_owned(),
-                        card_exp_month: Some(card_data.card_exp_month.clone()),
-                        card_exp_year: Some(card_data.card_exp_year.clone()),
+                        card_exp_month: Some(card_data.card_exp_month.clone().into()),
+                        card_exp_year: Some(card_data.card_exp_year.clone().into()),
                         card_holder_name: Some(card_data.card_holder_name.clone()),
                         last4: last4.clone(),
                         card_isin: card_isin.clone(),
@@ -3125,14 +3527,17 @@ pub async fn get_additional_payment_data(
                         api_models::payments::AdditionalPaymentData::Card(Box::new(
                             api_models::payments::AdditionalCardInfo {
                                 card_issuer: card_info.card_issuer,
-                                card_network: card_info.card_network.clone(),
+                                card_network: card_info
+                                    .card_network
+                                    .clone()
+                                    .or_else(|| card_data.card_number.get_card_network()),
                                 bank_code: card_info.bank_code,
                                 card_type: card_info.card_type,
                                 card_issuing_country: card_info.card_issuing_country,
                                 last4: last4.clone(),
                                 card_isin: card_isin.clone(),
-                                card_exp_month: Some(card_data.card_exp_month.clone()),
-                                card_exp_year: Some(card_data.card_exp_year.clone()),
+                                card_exp_month: Some(card_data.card_exp_month.clone().into()),
+                                card_exp_year: Some(card_data.card_exp_year.clone().into()),
                                 card_holder_name: Some(card_data.card_holder_name.clone()),
                             },
                         ))
@@ -3140,14 +3545,14 @@ pub async fn get_additional_payment_data(
                 card_info.unwrap_or(api_models::payments::AdditionalPaymentData::Card(Box::new(
                     api_models::payments::AdditionalCardInfo {
                         card_issuer: None,
-                        card_network: None,
+                        card_network: card_data.card_number.get_card_network(),
                         bank_code: None,
                         card_type: None,
                         card_issuing_country: None,
                         last4,
                         card_isin,
-                        card_exp_month: Some(card_data.card_exp_month.clone()),
-                        card_exp_year: Some(card_data.card_exp_year.clone()),
+                        card_exp_month: Some(card_data.card_exp_month.clone().into()),
+                        card_exp_year: Some(card_data.card_exp_year.clone().into()),
                         card_holder_name: Some(card_data.card_holder_name.clone()),
                     },
                 )))
@@ -3263,14 +3668,25 @@ impl ApplePayData {
         &self,
         state: &AppState,
     ) -> CustomResult<String, errors::ApplePayDecryptionError> {
-        #[cfg(feature = "kms")]
-        let cert_data = kms::get_kms_client(&state.conf.kms)
-            .await
-            .decrypt(&state.conf.applepay_decrypt_keys.apple_pay_ppc)
-            .await
-            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
+        #[cfg(feature = "encryption_service")]
+        let cert_data = encryption_management::get_encryption_management_client(
+            &state.conf.encryption_management,
+        )
+        .await
+        .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?
+        .get_secret(
+            state
+                .conf
+                .applepay_decrypt_keys
+                .apple_pay_ppc
+                .clone()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?
+        .expose();
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "encryption_service"))]
         let cert_data = &state.conf.applepay_decrypt_keys.apple_pay_ppc;
 
         let base64_decode_cert_data = BASE64_ENGINE
@@ -3323,14 +3739,25 @@ impl ApplePayData {
             .change_context(errors::ApplePayDecryptionError::KeyDeserializationFailed)
             .attach_printable("Failed to deserialize the public key")?;
 
-        #[cfg(feature = "kms")]
-        let decrypted_apple_pay_ppc_key = kms::get_kms_client(&state.conf.kms)
-            .await
-            .decrypt(&state.conf.applepay_decrypt_keys.apple_pay_ppc_key)
-            .await
-            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
+        #[cfg(feature = "encryption_service")]
+        let decrypted_apple_pay_ppc_key = encryption_management::get_encryption_management_client(
+            &state.conf.encryption_management,
+        )
+        .await
+        .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?
+        .get_secret(
+            state
+                .conf
+                .applepay_decrypt_keys
+                .apple_pay_ppc_key
+                .clone()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?
+        .expose();
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "encryption_service"))]
         let decrypted_apple_pay_ppc_key = &state.conf.applepay_decrypt_keys.apple_pay_ppc_key;
         // Create PKey objects from EcKey
         let private_key = PKey::private_key_from_pem(decrypted_apple_pay_ppc_key.as_bytes())
@@ -330,6 +330,14 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         payment_attempt.capture_method = request.capture_method.or(payment_attempt.capture_method);
 
+        payment_attempt.card_fingerprint = helpers::get_card_fingerprint(
+            state,
+            &merchant_account.merchant_id,
+            request.payment_method_data.as_ref(),
+        )
+        .await?
+        .or(payment_attempt.card_fingerprint);
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.amount.into();
 
@@ -569,6 +577,27 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let payment_token = payment_data.token.clone();
         let payment_method_type = payment_data.payment_attempt.payment_method_type;
         let payment_experience = payment_data.payment_attempt.payment_experience;
+        // The card of a saved payment method is only known once the payment method data is
+        // retrieved from the locker
+        let card_fingerprint = match payment_data.payment_attempt.card_fingerprint.clone() {
+            Some(card_fingerprint) => Some(card_fingerprint),
+            None => {
+                helpers::get_card_fingerprint(
+                    state,
+                    &payment_data.payment_attempt.merchant_id,
+                    payment_data.payment_method_data.as_ref(),
+                )
+                .await?
+            }
+        };
+        helpers::validate_card_velocity(
+            &*state.store,
+            &state.conf.card_velocity,
+            &payment_data.payment_attempt.merchant_id,
+            card_fingerprint.as_deref(),
+            storage_scheme,
+        )
+        .await?;
         let additional_pm_data = payment_data
             .payment_method_data
             .as_ref()
@@ -644,6 +673,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         amount_capturable: Some(authorized_amount),
                         updated_by: storage_scheme.to_string(),
                         merchant_connector_id,
+                        card_fingerprint,
                     },
                     storage_scheme,
                 )
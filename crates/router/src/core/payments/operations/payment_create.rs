@@ -787,6 +787,13 @@ async fn create_payment_link(
     amount: api::Amount,
 ) -> RouterResult<Option<api_models::payments::PaymentLinkResponse>> {
     let created_at @ last_modified_at = Some(common_utils::date_time::now());
+    if let Some(link_expiry) = payment_link_object.link_expiry {
+        utils::when(link_expiry <= common_utils::date_time::now(), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "link_expiry must be a time in the future".to_string(),
+            })
+        })?;
+    }
     let domain = if let Some(domain_name) = payment_link_object.merchant_custom_domain_name {
         format!("https://{domain_name}")
     } else {
@@ -811,6 +818,7 @@ async fn create_payment_link(
         last_modified_at,
         fulfilment_time: payment_link_object.link_expiry,
         custom_merchant_name: payment_link_object.custom_merchant_name,
+        is_reusable: payment_link_object.is_reusable,
     };
     let payment_link_db = db
         .insert_payment_link(payment_link_req)
@@ -5,7 +5,7 @@ use data_models::payments::payment_attempt::PaymentAttempt;
 use error_stack::ResultExt;
 use futures::FutureExt;
 use router_derive;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
@@ -14,7 +14,7 @@ use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         mandate,
-        payment_methods::PaymentMethodRetrieve,
+        payment_methods::{cards, PaymentMethodRetrieve},
         payments::{types::MultipleCaptureData, PaymentData},
         utils as core_utils,
     },
@@ -310,6 +310,16 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     router_data: types::RouterData<F, T, types::PaymentsResponseData>,
     storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<PaymentData<F>> {
+    // Only the authorization call is recorded, as it is the one representative of the connector's
+    // responsiveness for the payment
+    let connector_latency = if core_utils::get_flow_name::<F>()? == "Authorize" {
+        router_data
+            .external_latency
+            .and_then(|latency| i64::try_from(latency).ok())
+    } else {
+        None
+    };
+
     let (capture_update, mut payment_attempt_update) = match router_data.response.clone() {
         Err(err) => {
             let (capture_update, attempt_update) = match payment_data.multiple_capture_data {
@@ -363,6 +373,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 None
                             },
                             updated_by: storage_scheme.to_string(),
+                            connector_latency,
                         }),
                     )
                 }
@@ -485,6 +496,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 updated_by: storage_scheme.to_string(),
                                 authentication_data,
                                 encoded_data,
+                                connector_latency,
                             }),
                         ),
                     };
@@ -513,6 +525,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         error_reason: Some(reason.map(|cd| cd.message)),
                         connector_response_reference_id,
                         updated_by: storage_scheme.to_string(),
+                        connector_latency,
                     }),
                 )
             }
@@ -625,6 +638,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         },
     };
 
+    let previous_intent_status = payment_data.payment_intent.status;
     let m_db = state.clone().store;
     let m_payment_data_payment_intent = payment_data.payment_intent.clone();
     let m_payment_intent_update = payment_intent_update.clone();
@@ -665,10 +679,28 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         utils::flatten_join_error(payment_attempt_fut)
     )?;
 
+    if is_first_success(previous_intent_status, payment_intent.status) {
+        if let Some(payment_method_id) = payment_data.payment_attempt.payment_method_id.as_ref() {
+            cards::update_last_used_at(&*state.store, payment_method_id)
+                .await
+                .map_err(|error| logger::error!(payment_method_last_used_update_error=?error))
+                .ok();
+        }
+    }
+
     payment_data.payment_intent = payment_intent;
     Ok(payment_data)
 }
 
+/// The usage of the saved payment method is recorded once, when the payment first succeeds
+fn is_first_success(
+    previous_intent_status: enums::IntentStatus,
+    intent_status: enums::IntentStatus,
+) -> bool {
+    intent_status == enums::IntentStatus::Succeeded
+        && previous_intent_status != enums::IntentStatus::Succeeded
+}
+
 fn response_to_capture_update(
     multiple_capture_data: &MultipleCaptureData,
     response_list: HashMap<String, CaptureSyncResponse>,
@@ -746,3 +778,24 @@ fn get_total_amount_captured<F: Clone, T: types::Capturable>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_usage_is_recorded_on_the_first_success_only() {
+        assert!(is_first_success(
+            enums::IntentStatus::Processing,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(!is_first_success(
+            enums::IntentStatus::Succeeded,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(!is_first_success(
+            enums::IntentStatus::Processing,
+            enums::IntentStatus::Failed
+        ));
+    }
+}
@@ -366,6 +366,9 @@ where
     );
 
     let db = &*state.store;
+    let connector_latency = router_data
+        .external_latency
+        .and_then(|latency| i64::try_from(latency).ok());
 
     match router_data.response {
         Ok(types::PaymentsResponseData::TransactionResponse {
@@ -417,6 +420,7 @@ where
                     updated_by: storage_scheme.to_string(),
                     authentication_data,
                     encoded_data,
+                    connector_latency,
                 },
                 storage_scheme,
             )
@@ -438,6 +442,7 @@ where
                     error_reason: Some(error_response.reason),
                     amount_capturable: Some(0),
                     updated_by: storage_scheme.to_string(),
+                    connector_latency,
                 },
                 storage_scheme,
             )
@@ -34,6 +34,7 @@ use rustc_hash::FxHashMap;
 #[cfg(not(feature = "business_profile_routing"))]
 use crate::utils::StringExt;
 use crate::{
+    configs::settings,
     core::{
         errors as oss_errors, errors, payments as payments_oss, routing::helpers as routing_helpers,
     },
@@ -142,7 +143,10 @@ where
             .payment_method_data
             .as_ref()
             .and_then(|pm_data| match pm_data {
-                api::PaymentMethodData::Card(card) => card.card_network.clone(),
+                api::PaymentMethodData::Card(card) => card
+                    .card_network
+                    .clone()
+                    .or_else(|| card.card_number.get_card_network()),
 
                 _ => None,
             }),
@@ -159,6 +163,7 @@ where
                 }
                 _ => None,
             }),
+        card_fingerprint: payment_data.payment_attempt.card_fingerprint.clone(),
         currency: payment_data.currency,
         authentication_type: payment_data.payment_attempt.authentication_type,
         capture_method: payment_data
@@ -505,6 +510,22 @@ pub async fn get_merchant_kgraph<'a>(
     Ok(cached_kgraph)
 }
 
+/// Replaces the payment method filters of the merchant connector account with the filters that
+/// apply to it, which are the ones configured for the connector overridden by the ones configured
+/// for the account. This keeps routing consistent with the payment method list.
+fn merge_connector_pm_filters(
+    connector_filters: &settings::ConnectorFilters,
+    mut mca: admin_api::MerchantConnectorResponse,
+) -> admin_api::MerchantConnectorResponse {
+    mca.pm_filters = connector_filters
+        .get_merchant_connector_filters(
+            &mca.connector_name,
+            mca.pm_filters.as_deref().unwrap_or_default(),
+        )
+        .map(|filters| filters.get_pm_filters());
+    mca
+}
+
 pub async fn refresh_kgraph_cache(
     state: &AppState,
     key_store: &domain::MerchantKeyStore,
@@ -537,6 +558,11 @@ pub async fn refresh_kgraph_cache(
         .collect::<Result<_, _>>()
         .change_context(errors::RoutingError::KgraphCacheRefreshFailed)?;
 
+    let api_mcas = api_mcas
+        .into_iter()
+        .map(|mca| merge_connector_pm_filters(&state.conf.pm_filters, mca))
+        .collect();
+
     let kgraph = mca_graph::make_mca_graph(api_mcas)
         .into_report()
         .change_context(errors::RoutingError::KgraphCacheRefreshFailed)
@@ -551,7 +577,7 @@ pub async fn refresh_kgraph_cache(
     Ok(())
 }
 
-async fn perform_kgraph_filtering(
+pub async fn perform_kgraph_filtering(
     state: &AppState,
     key_store: &domain::MerchantKeyStore,
     merchant_last_modified: i64,
@@ -779,6 +805,7 @@ pub async fn perform_session_flow_routing(
             })?,
         authentication_type: session_input.payment_attempt.authentication_type,
         card_bin: None,
+        card_fingerprint: None,
         capture_method: session_input
             .payment_attempt
             .capture_method
@@ -996,3 +1023,120 @@ async fn perform_session_routing_for_pm_type(
 
     Ok(final_choice)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::collections::{HashMap, HashSet};
+
+    use api_models::{admin::AcceptedCurrencies, payment_methods::RequestPaymentMethodTypes};
+    use euclid::{dirval, dssa::graph::AnalysisContext};
+
+    use super::*;
+
+    fn stripe_account(
+        pm_filters: Option<Vec<admin_api::PaymentMethodFilter>>,
+    ) -> admin_api::MerchantConnectorResponse {
+        admin_api::MerchantConnectorResponse {
+            connector_type: api_enums::ConnectorType::FizOperations,
+            connector_name: "stripe".to_string(),
+            merchant_connector_id: "mca_stripe".to_string(),
+            business_country: Some(CountryAlpha2::US),
+            connector_label: None,
+            business_label: None,
+            business_sub_label: None,
+            connector_account_details: masking::Secret::new(serde_json::json!({})),
+            test_mode: None,
+            disabled: None,
+            metadata: None,
+            payment_methods_enabled: Some(vec![admin_api::PaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::Card,
+                payment_method_types: Some(vec![RequestPaymentMethodTypes {
+                    payment_method_type: api_enums::PaymentMethodType::Credit,
+                    payment_experience: None,
+                    card_networks: Some(vec![api_enums::CardNetwork::Visa]),
+                    accepted_currencies: Some(AcceptedCurrencies::EnableOnly(vec![
+                        api_enums::Currency::USD,
+                        api_enums::Currency::EUR,
+                        api_enums::Currency::INR,
+                    ])),
+                    accepted_countries: None,
+                    minimum_amount: Some(10),
+                    maximum_amount: Some(1000),
+                    recurring_enabled: true,
+                    installment_payment_enabled: true,
+                }]),
+            }]),
+            frm_configs: None,
+            connector_webhook_details: None,
+            profile_id: None,
+            applepay_verified_domains: None,
+            pm_auth_config: None,
+            pm_filters,
+        }
+    }
+
+    fn currency_eligible(
+        kgraph: &euclid_graph::KnowledgeGraph<'_>,
+        currency: euclid::frontend::dir::DirValue,
+    ) -> bool {
+        kgraph
+            .key_value_analysis(
+                dirval!(Connector = Stripe),
+                &AnalysisContext::from_dir_values([
+                    dirval!(Connector = Stripe),
+                    dirval!(PaymentMethod = Card),
+                    dirval!(CardType = Credit),
+                    dirval!(CardNetwork = Visa),
+                    currency,
+                    dirval!(PaymentAmount = 100),
+                ]),
+                &mut Memoization::new(),
+            )
+            .is_ok()
+    }
+
+    #[test]
+    fn test_kgraph_applies_default_connector_pm_filters() {
+        // The connector only supports credit cards in USD and EUR by default
+        let connector_filters = settings::ConnectorFilters(HashMap::from([(
+            "stripe".to_string(),
+            settings::PaymentMethodFilters(HashMap::from([(
+                settings::PaymentMethodFilterKey::PaymentMethodType(
+                    api_enums::PaymentMethodType::Credit,
+                ),
+                settings::CurrencyCountryFlowFilter {
+                    currency: Some(HashSet::from([
+                        api_enums::Currency::USD,
+                        api_enums::Currency::EUR,
+                    ])),
+                    country: None,
+                    not_available_flows: None,
+                },
+            )])),
+        )]));
+
+        let kgraph = mca_graph::make_mca_graph(vec![merge_connector_pm_filters(
+            &connector_filters,
+            stripe_account(None),
+        )])
+        .expect("Failed graph construction");
+        assert!(currency_eligible(&kgraph, dirval!(PaymentCurrency = USD)));
+        assert!(!currency_eligible(&kgraph, dirval!(PaymentCurrency = INR)));
+
+        // Filters configured on the account take precedence over the connector defaults
+        let kgraph = mca_graph::make_mca_graph(vec![merge_connector_pm_filters(
+            &connector_filters,
+            stripe_account(Some(vec![admin_api::PaymentMethodFilter {
+                payment_method_type: Some(api_enums::PaymentMethodType::Credit),
+                card_network: None,
+                countries: None,
+                currencies: Some(vec![api_enums::Currency::INR]),
+            }])),
+        )])
+        .expect("Failed graph construction");
+        assert!(currency_eligible(&kgraph, dirval!(PaymentCurrency = INR)));
+        assert!(!currency_eligible(&kgraph, dirval!(PaymentCurrency = USD)));
+    }
+}
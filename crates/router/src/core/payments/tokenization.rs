@@ -50,15 +50,24 @@ where
                     .payment_method_token
                     .to_owned()
                     .get_required_value("payment_token")?;
-                let token = match tokens {
-                    types::PaymentMethodToken::Token(connector_token) => connector_token,
+                match tokens {
+                    types::PaymentMethodToken::Token(connector_token) => {
+                        Some((connector, connector_token))
+                    }
                     types::PaymentMethodToken::ApplePayDecrypt(_) => {
                         Err(errors::ApiErrorResponse::NotSupported {
                             message: "Apple Pay Decrypt token is not supported".to_string(),
                         })?
                     }
-                };
-                Some((connector, token))
+                    // The payment has already succeeded, so the payment method is saved without a
+                    // connector token rather than failing the payment
+                    types::PaymentMethodToken::NetworkToken(_) => {
+                        logger::info!(
+                            "Skipping the long lived connector token, the connector returned a network token"
+                        );
+                        None
+                    }
+                }
             } else {
                 None
             };
@@ -74,91 +83,151 @@ where
                 .await?;
                 let merchant_id = &merchant_account.merchant_id;
 
-                let locker_response = save_in_locker(
-                    state,
-                    merchant_account,
-                    payment_method_create_request.to_owned(),
-                )
-                .await?;
-                let is_duplicate = locker_response.1;
-
-                let pm_card_details = locker_response.0.card.as_ref().map(|card| {
-                    api::payment_methods::PaymentMethodsData::Card(CardDetailsPaymentMethod::from(
-                        card.clone(),
-                    ))
-                });
+                let existing_pm_for_card = match payment_method_create_request.card.as_ref() {
+                    Some(card) => {
+                        payment_methods::cards::find_payment_method_by_card(
+                            state,
+                            &customer.customer_id,
+                            merchant_id,
+                            card,
+                        )
+                        .await?
+                    }
+                    None => None,
+                };
 
-                let pm_data_encrypted =
-                    payment_methods::cards::create_encrypted_payment_method_data(
-                        key_store,
-                        pm_card_details,
+                // The card is already saved for the customer, reuse the existing payment method
+                // after refreshing its card details
+                if let Some(pm) = existing_pm_for_card {
+                    let pm = match payment_method_create_request.card.as_ref() {
+                        Some(card) => {
+                            payment_methods::cards::update_saved_card_details(
+                                state, key_store, pm, card,
+                            )
+                            .await?
+                        }
+                        None => pm,
+                    };
+                    let pm_metadata =
+                        create_payment_method_metadata(pm.metadata.as_ref(), connector_token)?;
+                    let payment_method_id = pm.payment_method_id.clone();
+                    if let Some(metadata) = pm_metadata {
+                        payment_methods::cards::update_payment_method(db, pm, metadata)
+                            .await
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to update payment method in db")?;
+                    };
+                    Some(payment_method_id)
+                } else {
+                    let locker_response = save_in_locker(
+                        state,
+                        merchant_account,
+                        payment_method_create_request.to_owned(),
                     )
-                    .await;
+                    .await?;
+                    let is_duplicate = locker_response.1;
+                    let card_fingerprint = locker_response
+                        .0
+                        .card
+                        .as_ref()
+                        .and_then(|card| card.card_fingerprint.clone())
+                        .map(|card_fingerprint| card_fingerprint.expose());
+
+                    let pm_card_details = locker_response.0.card.as_ref().map(|card| {
+                        api::payment_methods::PaymentMethodsData::Card(
+                            CardDetailsPaymentMethod::from(card.clone()),
+                        )
+                    });
 
-                if is_duplicate {
-                    let existing_pm = db
-                        .find_payment_method(&locker_response.0.payment_method_id)
+                    let pm_data_encrypted =
+                        payment_methods::cards::create_encrypted_payment_method_data(
+                            key_store,
+                            pm_card_details,
+                        )
                         .await;
-                    match existing_pm {
-                        Ok(pm) => {
-                            let pm_metadata = create_payment_method_metadata(
-                                pm.metadata.as_ref(),
-                                connector_token,
-                            )?;
-                            if let Some(metadata) = pm_metadata {
-                                payment_methods::cards::update_payment_method(db, pm, metadata)
-                                    .await
-                                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                                    .attach_printable("Failed to add payment method in db")?;
-                            };
-                        }
-                        Err(error) => {
-                            match error.current_context() {
-                                errors::StorageError::DatabaseError(err) => match err
-                                    .current_context()
-                                {
-                                    diesel_models::errors::DatabaseError::NotFound => {
-                                        let pm_metadata =
-                                            create_payment_method_metadata(None, connector_token)?;
-                                        payment_methods::cards::create_payment_method(
-                                            db,
-                                            &payment_method_create_request,
-                                            &customer.customer_id,
-                                            &locker_response.0.payment_method_id,
-                                            merchant_id,
-                                            pm_metadata,
-                                            pm_data_encrypted,
-                                            key_store,
-                                        )
+
+                    if is_duplicate {
+                        let existing_pm = db
+                            .find_payment_method(&locker_response.0.payment_method_id)
+                            .await;
+                        match existing_pm {
+                            Ok(pm) => {
+                                let pm_metadata = create_payment_method_metadata(
+                                    pm.metadata.as_ref(),
+                                    connector_token,
+                                )?;
+                                if let Some(metadata) = pm_metadata {
+                                    payment_methods::cards::update_payment_method(db, pm, metadata)
                                         .await
+                                        .change_context(
+                                            errors::ApiErrorResponse::InternalServerError,
+                                        )
+                                        .attach_printable("Failed to add payment method in db")?;
+                                };
+                            }
+                            Err(error) => {
+                                match error.current_context() {
+                                    errors::StorageError::DatabaseError(err) => {
+                                        match err.current_context() {
+                                            diesel_models::errors::DatabaseError::NotFound => {
+                                                let pm_metadata = create_payment_method_metadata(
+                                                    None,
+                                                    connector_token,
+                                                )?;
+                                                payment_methods::cards::create_payment_method(
+                                                    db,
+                                                    &payment_method_create_request,
+                                                    &customer.customer_id,
+                                                    &locker_response.0.payment_method_id,
+                                                    merchant_id,
+                                                    pm_metadata,
+                                                    pm_data_encrypted,
+                                                    card_fingerprint,
+                                                    key_store,
+                                                )
+                                                .await
+                                            }
+                                            _ => Err(report!(
+                                                errors::ApiErrorResponse::InternalServerError
+                                            )
+                                            .attach_printable(
+                                                "Database Error while finding payment method",
+                                            )),
+                                        }
                                     }
                                     _ => {
                                         Err(report!(errors::ApiErrorResponse::InternalServerError)
-                                            .attach_printable(
-                                                "Database Error while finding payment method",
-                                            ))
+                                            .attach_printable("Error while finding payment method"))
                                     }
-                                },
-                                _ => Err(report!(errors::ApiErrorResponse::InternalServerError)
-                                    .attach_printable("Error while finding payment method")),
-                            }?;
-                        }
+                                }?;
+                            }
+                        };
+                    } else {
+                        let pm_metadata = create_payment_method_metadata(None, connector_token)?;
+                        payment_methods::cards::create_payment_method(
+                            db,
+                            &payment_method_create_request,
+                            &customer.customer_id,
+                            &locker_response.0.payment_method_id,
+                            merchant_id,
+                            pm_metadata,
+                            pm_data_encrypted,
+                            card_fingerprint,
+                            key_store,
+                        )
+                        .await?;
                     };
-                } else {
-                    let pm_metadata = create_payment_method_metadata(None, connector_token)?;
-                    payment_methods::cards::create_payment_method(
-                        db,
-                        &payment_method_create_request,
-                        &customer.customer_id,
-                        &locker_response.0.payment_method_id,
-                        merchant_id,
-                        pm_metadata,
-                        pm_data_encrypted,
-                        key_store,
-                    )
-                    .await?;
-                };
-                Some(locker_response.0.payment_method_id)
+                    if payment_method_create_request.card.is_some() {
+                        payment_methods::account_updater::schedule_card_account_updater_task(
+                            state,
+                            merchant_id,
+                        )
+                        .await
+                        .map_err(|error| logger::error!(card_account_updater_schedule_error=?error))
+                        .ok();
+                    }
+                    Some(locker_response.0.payment_method_id)
+                }
             } else {
                 None
             };
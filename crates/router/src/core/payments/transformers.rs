@@ -685,6 +685,10 @@ where
                         .set_profile_id(payment_intent.profile_id)
                         .set_attempt_count(payment_intent.attempt_count)
                         .set_merchant_connector_id(payment_attempt.merchant_connector_id)
+                        .set_card_fingerprint(
+                            payment_attempt.card_fingerprint,
+                            auth_flow == services::AuthFlow::Merchant,
+                        )
                         .to_owned(),
                     headers,
                 ))
@@ -849,6 +853,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             authentication_type: pa.authentication_type,
             connector_transaction_id: pa.connector_transaction_id,
             attempt_count: pi.attempt_count,
+            card_fingerprint: pa.card_fingerprint,
             ..Default::default()
         }
     }
@@ -1,7 +1,9 @@
-use common_utils::{errors::CustomResult, ext_traits::ValueExt};
-use diesel_models::encryption::Encryption;
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{AsyncExt, ValueExt},
+};
 use error_stack::{IntoReport, ResultExt};
-use masking::{ExposeInterface, PeekInterface, Secret};
+use masking::{ExposeInterface, Secret};
 
 use crate::{
     core::{
@@ -18,10 +20,7 @@ use crate::{
     routes::AppState,
     types::{
         api::{self, enums as api_enums},
-        domain::{
-            self,
-            types::{self as domain_types, AsyncLift},
-        },
+        domain::{self, types as domain_types},
         storage,
         transformers::ForeignFrom,
     },
@@ -146,8 +145,8 @@ pub async fn save_payout_data_to_locker(
                 card: transformers::Card {
                     card_number: card.card_number.to_owned(),
                     name_on_card: Some(card.card_holder_name.to_owned()),
-                    card_exp_month: card.expiry_month.to_owned(),
-                    card_exp_year: card.expiry_year.to_owned(),
+                    card_exp_month: card.expiry_month.to_owned().into(),
+                    card_exp_year: card.expiry_year.to_owned().into(),
                     card_brand: None,
                     card_isin: None,
                     nick_name: None,
@@ -160,28 +159,17 @@ pub async fn save_payout_data_to_locker(
             )
         }
         api_models::payouts::PayoutMethodData::Bank(bank) => {
-            let key = key_store.key.get_inner().peek();
-            let enc_data = async {
-                serde_json::to_value(payout_method_data.to_owned())
-                    .into_report()
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Unable to encode payout method data")
-                    .ok()
-                    .map(|v| {
-                        let secret: Secret<String> = Secret::new(v.to_string());
-                        secret
-                    })
-                    .async_lift(|inner| domain_types::encrypt_optional(inner, key))
-                    .await
-            }
+            let payout_method_data = serde_json::to_value(payout_method_data.to_owned())
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to encode payout method data")?;
+            let enc_data = cards::encrypt_and_encode_locker_data(
+                key_store,
+                Secret::new(payout_method_data.to_string()),
+            )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to encrypt payout method data")?
-            .map(Encryption::from)
-            .map(|e| e.into_inner())
-            .map_or(Err(errors::ApiErrorResponse::InternalServerError), |e| {
-                Ok(hex::encode(e.peek()))
-            })?;
+            .attach_printable("Failed to encrypt payout method data")?;
             let payload = StoreLockerReq::LockerGeneric(StoreGenericReq {
                 merchant_id: &merchant_account.merchant_id,
                 merchant_customer_id: payout_attempt.customer_id.to_owned(),
@@ -220,8 +208,12 @@ pub async fn save_payout_data_to_locker(
                 .as_ref()
                 .map(|c| c.card_number.clone().get_last4()),
             issuer_country: None,
-            expiry_month: card_details.as_ref().map(|c| c.card_exp_month.clone()),
-            expiry_year: card_details.as_ref().map(|c| c.card_exp_year.clone()),
+            expiry_month: card_details
+                .as_ref()
+                .map(|c| c.card_exp_month.clone().into()),
+            expiry_year: card_details
+                .as_ref()
+                .map(|c| c.card_exp_year.clone().into()),
             nick_name: card_details.as_ref().and_then(|c| c.nick_name.clone()),
             card_holder_name: card_details
                 .as_ref()
@@ -232,6 +224,18 @@ pub async fn save_payout_data_to_locker(
     let card_details_encrypted =
         cards::create_encrypted_payment_method_data(key_store, Some(pm_data)).await;
 
+    let card_fingerprint = card_details
+        .as_ref()
+        .async_map(|card| {
+            cards::generate_card_fingerprint(
+                state,
+                &merchant_account.merchant_id,
+                &card.card_number,
+            )
+        })
+        .await
+        .transpose()?;
+
     // Insert in payment_method table
     let payment_method = api::PaymentMethodCreate {
         payment_method: api_enums::PaymentMethod::foreign_from(payout_method_data.to_owned()),
@@ -252,6 +256,7 @@ pub async fn save_payout_data_to_locker(
         &merchant_account.merchant_id,
         None,
         card_details_encrypted,
+        card_fingerprint,
         key_store,
     )
     .await?;
@@ -270,7 +275,7 @@ pub async fn get_or_create_customer_details(
     let customer_id =
         core_utils::get_or_generate_id("customer_id", &customer_details.customer_id, "cust")?;
     let merchant_id = &merchant_account.merchant_id;
-    let key = key_store.key.get_inner().peek();
+    let key = &key_store.key_ring();
 
     match db
         .find_customer_optional_by_customer_id_merchant_id(&customer_id, merchant_id, key_store)
@@ -302,6 +307,7 @@ pub async fn get_or_create_customer_details(
                 created_at: common_utils::date_time::now(),
                 modified_at: common_utils::date_time::now(),
                 address_id: None,
+                default_payment_method_id: None,
             };
 
             Ok(Some(
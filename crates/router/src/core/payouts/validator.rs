@@ -5,6 +5,7 @@ use super::helpers;
 use crate::{
     core::{
         errors::{self, RouterResult},
+        payments::helpers as payment_helpers,
         utils as core_utils,
     },
     db::StorageInterface,
@@ -92,6 +93,12 @@ pub async fn validate_create_request(
         None => Ok(()),
     }?;
 
+    // Payout method data
+    if let Some(payouts::PayoutMethodData::Card(card)) = req.payout_method_data.as_ref() {
+        payment_helpers::validate_card_number_length(&card.card_number)?;
+        payment_helpers::validate_card_expiry(&card.expiry_month, &card.expiry_year)?;
+    }
+
     // Payout token
     let payout_method_data = match req.payout_token.to_owned() {
         Some(payout_token) => {
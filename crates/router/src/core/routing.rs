@@ -89,13 +89,25 @@ pub async fn create_routing_config(
         })
         .attach_printable("Description of config not given")?;
 
-    let algorithm = request
-        .algorithm
-        .get_required_value("algorithm")
-        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+    let algorithm = match (request.algorithm, request.program_text) {
+        (Some(algorithm), _) => algorithm,
+        (None, Some(program_text)) => {
+            routing_types::RoutingAlgorithm::from_program_text(&program_text)
+                .map_err(|diagnostic| errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Invalid routing program: {diagnostic}"),
+                })
+                .into_report()?
+        }
+        (None, None) => Err(errors::ApiErrorResponse::MissingRequiredField {
             field_name: "algorithm",
         })
-        .attach_printable("Algorithm of config not given")?;
+        .into_report()
+        .attach_printable("Algorithm of config not given")?,
+    };
+
+    let test_cases = request.test_cases.unwrap_or_default();
+    let strict_validation = request.strict_validation.unwrap_or(false);
+    helpers::validate_routing_test_cases(&algorithm, &test_cases)?;
 
     let algorithm_id = common_utils::generate_id(
         consts::ROUTING_CONFIG_ID_LENGTH,
@@ -128,6 +140,28 @@ pub async fn create_routing_config(
         )
         .await?;
 
+        let warnings = helpers::validate_routing_program(
+            &state,
+            &key_store,
+            merchant_account.modified_at.assume_utc().unix_timestamp(),
+            Some(profile_id.clone()),
+            &algorithm,
+            strict_validation,
+        )
+        .await?;
+
+        let report = helpers::run_routing_test_cases(
+            &state,
+            &key_store,
+            merchant_account.modified_at.assume_utc().unix_timestamp(),
+            Some(profile_id.clone()),
+            algorithm_id.clone(),
+            &algorithm,
+            test_cases.clone(),
+        )
+        .await?;
+        helpers::ensure_routing_tests_passed(&report)?;
+
         let timestamp = common_utils::date_time::now();
         let algo = RoutingAlgorithm {
             algorithm_id: algorithm_id.clone(),
@@ -145,13 +179,40 @@ pub async fn create_routing_config(
             .await
             .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-        let new_record = record.foreign_into();
+        if !test_cases.is_empty() {
+            helpers::insert_routing_test_cases(db, &algorithm_id, &test_cases).await?;
+        }
+
+        let new_record = routing_types::RoutingDictionaryRecord {
+            warnings,
+            ..record.foreign_into()
+        };
 
         Ok(service_api::ApplicationResponse::Json(new_record))
     }
 
     #[cfg(not(feature = "business_profile_routing"))]
     {
+        let warnings = helpers::validate_routing_program(
+            &state,
+            &key_store,
+            merchant_account.modified_at.assume_utc().unix_timestamp(),
+            &algorithm,
+            strict_validation,
+        )
+        .await?;
+
+        let report = helpers::run_routing_test_cases(
+            &state,
+            &key_store,
+            merchant_account.modified_at.assume_utc().unix_timestamp(),
+            algorithm_id.clone(),
+            &algorithm,
+            test_cases.clone(),
+        )
+        .await?;
+        helpers::ensure_routing_tests_passed(&report)?;
+
         let algorithm_str =
             utils::Encode::<routing_types::RoutingAlgorithm>::encode_to_string_of_json(&algorithm)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -187,6 +248,7 @@ pub async fn create_routing_config(
             description: description.clone(),
             created_at: timestamp,
             modified_at: timestamp,
+            warnings: Vec::new(),
         };
         merchant_dictionary.records.push(new_record.clone());
 
@@ -200,6 +262,10 @@ pub async fn create_routing_config(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to save new routing algorithm config to DB")?;
 
+        if !test_cases.is_empty() {
+            helpers::insert_routing_test_cases(db, &algorithm_id, &test_cases).await?;
+        }
+
         if records_are_empty {
             merchant_dictionary.active_id = Some(algorithm_id.clone());
             algorithm_ref.update_algorithm_id(algorithm_id);
@@ -213,14 +279,19 @@ pub async fn create_routing_config(
         )
         .await?;
 
-        Ok(service_api::ApplicationResponse::Json(new_record))
+        Ok(service_api::ApplicationResponse::Json(
+            routing_types::RoutingDictionaryRecord {
+                warnings,
+                ..new_record
+            },
+        ))
     }
 }
 
 pub async fn link_routing_config(
     state: AppState,
     merchant_account: domain::MerchantAccount,
-    #[cfg(not(feature = "business_profile_routing"))] key_store: domain::MerchantKeyStore,
+    key_store: domain::MerchantKeyStore,
     algorithm_id: String,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     let db = state.store.as_ref();
@@ -245,6 +316,23 @@ pub async fn link_routing_config(
             id: routing_algorithm.profile_id.clone(),
         })?;
 
+        let algorithm: routing_types::RoutingAlgorithm = routing_algorithm
+            .algorithm_data
+            .clone()
+            .parse_value("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize routing algorithm")?;
+        let report = helpers::run_stored_routing_test_cases(
+            &state,
+            &key_store,
+            merchant_account.modified_at.assume_utc().unix_timestamp(),
+            Some(routing_algorithm.profile_id.clone()),
+            algorithm_id.clone(),
+            &algorithm,
+        )
+        .await?;
+        helpers::ensure_routing_tests_passed(&report)?;
+
         let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
             .routing_algorithm
             .clone()
@@ -305,6 +393,25 @@ pub async fn link_routing_config(
             .into_report()
             .attach_printable("Record with given ID not found for routing config activation")?;
 
+        let algorithm: routing_types::RoutingAlgorithm = db
+            .find_config_by_key(&algorithm_id)
+            .await
+            .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+            .attach_printable("Routing config not found in DB")?
+            .config
+            .parse_struct("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error deserializing routing algorithm config")?;
+        let report = helpers::run_stored_routing_test_cases(
+            &state,
+            &key_store,
+            merchant_account.modified_at.assume_utc().unix_timestamp(),
+            algorithm_id.clone(),
+            &algorithm,
+        )
+        .await?;
+        helpers::ensure_routing_tests_passed(&report)?;
+
         record.modified_at = modified_at;
         merchant_dictionary.active_id = Some(record.id.clone());
         let response = record.clone();
@@ -382,6 +489,7 @@ pub async fn retrieve_routing_config(
             id: record.id,
             name: record.name,
             description: record.description,
+            program_text: algorithm.get_program_text(),
             algorithm,
             created_at: record.created_at,
             modified_at: record.modified_at,
@@ -390,6 +498,95 @@ pub async fn retrieve_routing_config(
         Ok(service_api::ApplicationResponse::Json(response))
     }
 }
+/// Re-runs the stored test cases of a routing algorithm against the merchant's current connector
+/// accounts, e.g. after they have been updated
+pub async fn run_routing_config_tests(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    algorithm_id: RoutingAlgorithmId,
+) -> RouterResponse<routing_types::RoutingTestReport> {
+    let db = state.store.as_ref();
+    #[cfg(feature = "business_profile_routing")]
+    {
+        let routing_algorithm = db
+            .find_routing_algorithm_by_algorithm_id_merchant_id(
+                &algorithm_id.0,
+                &merchant_account.merchant_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        core_utils::validate_and_get_business_profile(
+            db,
+            Some(&routing_algorithm.profile_id),
+            &merchant_account.merchant_id,
+        )
+        .await?
+        .get_required_value("BusinessProfile")
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        let algorithm: routing_types::RoutingAlgorithm = routing_algorithm
+            .algorithm_data
+            .parse_value("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize routing algorithm")?;
+
+        let report = helpers::run_stored_routing_test_cases(
+            &state,
+            &key_store,
+            merchant_account.modified_at.assume_utc().unix_timestamp(),
+            Some(routing_algorithm.profile_id),
+            algorithm_id.0,
+            &algorithm,
+        )
+        .await?;
+
+        Ok(service_api::ApplicationResponse::Json(report))
+    }
+
+    #[cfg(not(feature = "business_profile_routing"))]
+    {
+        let merchant_dictionary =
+            helpers::get_merchant_routing_dictionary(db, &merchant_account.merchant_id).await?;
+
+        utils::when(
+            !merchant_dictionary
+                .records
+                .iter()
+                .any(|rec| rec.id == algorithm_id.0),
+            || {
+                Err(errors::ApiErrorResponse::ResourceIdNotFound)
+                    .into_report()
+                    .attach_printable(
+                        "Algorithm with the given ID not found in the merchant dictionary",
+                    )
+            },
+        )?;
+
+        let algorithm: routing_types::RoutingAlgorithm = db
+            .find_config_by_key(&algorithm_id.0)
+            .await
+            .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+            .attach_printable("Routing config not found in DB")?
+            .config
+            .parse_struct("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error deserializing routing algorithm config")?;
+
+        let report = helpers::run_stored_routing_test_cases(
+            &state,
+            &key_store,
+            merchant_account.modified_at.assume_utc().unix_timestamp(),
+            algorithm_id.0,
+            &algorithm,
+        )
+        .await?;
+
+        Ok(service_api::ApplicationResponse::Json(report))
+    }
+}
+
 pub async fn unlink_routing_config(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -706,6 +903,7 @@ pub async fn retrieve_linked_routing_config(
                 id: record.id,
                 name: record.name,
                 description: record.description,
+                program_text: the_algorithm.get_program_text(),
                 algorithm: the_algorithm,
                 created_at: record.created_at,
                 modified_at: record.modified_at,
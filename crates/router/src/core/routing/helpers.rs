@@ -8,12 +8,20 @@ use diesel_models::{
     business_profile::{BusinessProfile, BusinessProfileUpdateInternal},
     configs,
 };
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
+use euclid::{
+    backend::{self, EuclidBackend},
+    dssa::analyzer,
+};
 use rustc_hash::FxHashSet;
 
 use crate::{
-    core::errors::{self, RouterResult},
+    core::{
+        errors::{self, RouterResult},
+        payments::routing as payments_routing,
+    },
     db::StorageInterface,
+    routes::AppState,
     types::{domain, storage},
     utils::{self, StringExt},
 };
@@ -255,6 +263,7 @@ pub async fn update_business_profile_active_algorithm_ref(
         applepay_verified_domains: None,
         modified_at: None,
         is_recon_enabled: None,
+        payment_link_config: None,
     };
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
         .await
@@ -459,6 +468,12 @@ pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
     format!("routing_dict_{merchant_id}")
 }
 
+/// Provides the identifier for the specific routing algorithm's test cases
+#[inline(always)]
+pub fn get_routing_test_cases_key(algorithm_id: &str) -> String {
+    format!("routing_tests_{algorithm_id}")
+}
+
 /// Provides the identifier for the specific merchant's agnostic_mandate_config
 #[inline(always)]
 pub fn get_pg_agnostic_mandate_config_key(merchant_id: &str) -> String {
@@ -477,3 +492,475 @@ pub fn get_payment_config_routing_id(merchant_id: &str) -> String {
 pub fn get_payment_method_surcharge_routing_id(merchant_id: &str) -> String {
     format!("payment_method_surcharge_id_{merchant_id}")
 }
+
+fn get_analysis_error_message(err: &euclid::dssa::types::AnalysisError) -> String {
+    match err.metadata.get(analyzer::RULE_NAME_METADATA_KEY) {
+        Some(rule_name) => format!("{err} (in rule {rule_name})"),
+        None => err.to_string(),
+    }
+}
+
+/// Runs the static analyzer over advanced routing programs against the merchant's knowledge
+/// graph, so that invalid rules are rejected when the config is created instead of being
+/// silently skipped during routing. Rules that can never be selected are returned as warnings,
+/// unless `strict_validation` is set.
+pub async fn validate_routing_program(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+    strict_validation: bool,
+) -> RouterResult<Vec<String>> {
+    let program = match routing_algorithm {
+        routing_types::RoutingAlgorithm::Advanced(program) => program,
+        _ => return Ok(Vec::new()),
+    };
+
+    let kgraph = payments_routing::get_merchant_kgraph(
+        state,
+        key_store,
+        merchant_last_modified,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the knowledge graph for routing analysis")?;
+
+    let (_, warnings) = analyzer::analyze_with_warnings(program.clone(), Some(kgraph.as_ref()))
+        .map_err(|err| errors::ApiErrorResponse::InvalidRequestData {
+            message: get_analysis_error_message(&err),
+        })
+        .into_report()?;
+    let warnings = warnings
+        .iter()
+        .map(get_analysis_error_message)
+        .collect::<Vec<_>>();
+
+    utils::when(strict_validation && !warnings.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: warnings.join("; "),
+        })
+        .into_report()
+    })?;
+
+    Ok(warnings)
+}
+
+/// Checks that the test cases can be run against the algorithm and that each of them asserts
+/// something about the routing decision
+pub fn validate_routing_test_cases(
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+    test_cases: &[routing_types::RoutingTestCase],
+) -> RouterResult<()> {
+    utils::when(
+        !test_cases.is_empty()
+            && !matches!(
+                routing_algorithm,
+                routing_types::RoutingAlgorithm::Advanced(_)
+            ),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Test cases are only supported for advanced routing algorithms"
+                    .to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    let mut names = FxHashSet::default();
+    for test_case in test_cases {
+        utils::when(!names.insert(test_case.name.as_str()), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate routing test case name '{}'", test_case.name),
+            })
+            .into_report()
+        })?;
+
+        utils::when(
+            test_case.expected_connector.is_none() && test_case.expected_rule_name.is_none(),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Routing test case '{}' must have an expected connector or rule name",
+                        test_case.name
+                    ),
+                })
+                .into_report()
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Provides the test cases stored for the routing algorithm, an empty list if there are none
+pub async fn get_routing_test_cases(
+    db: &dyn StorageInterface,
+    algorithm_id: &str,
+) -> RouterResult<Vec<routing_types::RoutingTestCase>> {
+    let key = get_routing_test_cases_key(algorithm_id);
+    match db.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("Vec<RoutingTestCase>")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Routing test cases have invalid structure"),
+
+        Err(e) if e.current_context().is_db_not_found() => Ok(Vec::new()),
+
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching routing test cases"),
+    }
+}
+
+pub async fn insert_routing_test_cases(
+    db: &dyn StorageInterface,
+    algorithm_id: &str,
+    test_cases: &Vec<routing_types::RoutingTestCase>,
+) -> RouterResult<()> {
+    let serialized =
+        utils::Encode::<Vec<routing_types::RoutingTestCase>>::encode_to_string_of_json(test_cases)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error serializing routing test cases")?;
+
+    db.insert_config(configs::ConfigNew {
+        key: get_routing_test_cases_key(algorithm_id),
+        config: serialized,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to save routing test cases to DB")?;
+
+    Ok(())
+}
+
+fn connector_choice_matches(
+    expected: &routing_types::RoutableConnectorChoice,
+    got: &routing_types::RoutableConnectorChoice,
+) -> bool {
+    #[cfg(feature = "connector_choice_mca_id")]
+    let label_matches = expected.merchant_connector_id.is_none()
+        || expected.merchant_connector_id == got.merchant_connector_id;
+    #[cfg(not(feature = "connector_choice_mca_id"))]
+    let label_matches = expected.sub_label.is_none() || expected.sub_label == got.sub_label;
+
+    expected.connector == got.connector && label_matches
+}
+
+/// Checks the routing decision made for a test case against its expectations, returning the
+/// reason the test case failed, if any
+fn get_routing_test_case_failure_reason(
+    expected_rule_name: Option<&str>,
+    expected_connector: Option<&routing_types::RoutableConnectorChoice>,
+    rule_name: Option<&str>,
+    connector_selection: &routing_types::ConnectorSelection,
+    eligible_connectors: &[routing_types::RoutableConnectorChoice],
+) -> Option<String> {
+    if let Some(expected_rule_name) = expected_rule_name {
+        if rule_name != Some(expected_rule_name) {
+            return Some(format!(
+                "Expected rule '{expected_rule_name}' to match, got {}",
+                rule_name.map_or("the default selection".to_string(), |name| format!(
+                    "rule '{name}'"
+                ))
+            ));
+        }
+    }
+
+    if let Some(expected_connector) = expected_connector {
+        let connector_selected = match connector_selection {
+            routing_types::ConnectorSelection::Priority(_) => {
+                eligible_connectors.first().map_or(false, |first| {
+                    connector_choice_matches(expected_connector, first)
+                })
+            }
+            routing_types::ConnectorSelection::VolumeSplit(splits) => {
+                splits.iter().any(|split| {
+                    split.split > 0
+                        && connector_choice_matches(expected_connector, &split.connector)
+                }) && eligible_connectors
+                    .iter()
+                    .any(|connector| connector_choice_matches(expected_connector, connector))
+            }
+        };
+
+        if !connector_selected {
+            return Some(format!(
+                "Expected connector '{}' to be selected, eligible connectors were [{}]",
+                expected_connector.connector,
+                eligible_connectors
+                    .iter()
+                    .map(|connector| connector.connector.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+    }
+
+    None
+}
+
+/// Runs a single test case, filtering the selected connectors through the merchant's knowledge
+/// graph so that the result reflects the connectors that would actually be tried
+async fn run_routing_test_case(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+    interpreter: &backend::VirInterpreterBackend<routing_types::ConnectorSelection>,
+    test_case: routing_types::RoutingTestCase,
+) -> RouterResult<routing_types::RoutingTestCaseResult> {
+    let mut result = routing_types::RoutingTestCaseResult {
+        name: test_case.name,
+        passed: false,
+        rule_name: None,
+        connectors: Vec::new(),
+        failure_reason: None,
+    };
+
+    let output = match interpreter.execute(test_case.input.clone()) {
+        Ok(output) => output,
+        Err(err) => {
+            result.failure_reason = Some(format!("Routing program execution failed: {err:?}"));
+            return Ok(result);
+        }
+    };
+
+    result.rule_name = output.rule_name;
+    result.connectors = payments_routing::perform_kgraph_filtering(
+        state,
+        key_store,
+        merchant_last_modified,
+        output.connector_selection.get_connector_list(),
+        test_case.input,
+        None,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to check connector eligibility for routing test case")?;
+
+    result.failure_reason = get_routing_test_case_failure_reason(
+        test_case.expected_rule_name.as_deref(),
+        test_case.expected_connector.as_ref(),
+        result.rule_name.as_deref(),
+        &output.connector_selection,
+        &result.connectors,
+    );
+    result.passed = result.failure_reason.is_none();
+    Ok(result)
+}
+
+/// Runs the test cases against the routing algorithm using the merchant's current connector
+/// accounts, producing a report with the outcome of every test case
+pub async fn run_routing_test_cases(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+    algorithm_id: String,
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+    test_cases: Vec<routing_types::RoutingTestCase>,
+) -> RouterResult<routing_types::RoutingTestReport> {
+    let mut results = Vec::with_capacity(test_cases.len());
+
+    if let routing_types::RoutingAlgorithm::Advanced(program) = routing_algorithm {
+        let interpreter = backend::VirInterpreterBackend::with_program(program.clone())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error initializing DSL interpreter backend")?;
+
+        for test_case in test_cases {
+            results.push(
+                run_routing_test_case(
+                    state,
+                    key_store,
+                    merchant_last_modified,
+                    #[cfg(feature = "business_profile_routing")]
+                    profile_id.clone(),
+                    &interpreter,
+                    test_case,
+                )
+                .await?,
+            );
+        }
+    }
+
+    Ok(routing_types::RoutingTestReport {
+        algorithm_id,
+        passed: results.iter().all(|result| result.passed),
+        results,
+    })
+}
+
+/// Runs the test cases stored for the routing algorithm
+pub async fn run_stored_routing_test_cases(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+    algorithm_id: String,
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+) -> RouterResult<routing_types::RoutingTestReport> {
+    let test_cases = get_routing_test_cases(state.store.as_ref(), &algorithm_id).await?;
+
+    run_routing_test_cases(
+        state,
+        key_store,
+        merchant_last_modified,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+        algorithm_id,
+        routing_algorithm,
+        test_cases,
+    )
+    .await
+}
+
+/// Fails with the test report in the error body if the report has any failures
+pub fn ensure_routing_tests_passed(report: &routing_types::RoutingTestReport) -> RouterResult<()> {
+    utils::when(!report.passed, || {
+        Err(errors::ApiErrorResponse::RoutingTestsFailed {
+            data: serde_json::to_value(report).ok(),
+        })
+        .into_report()
+        .attach_printable(format!(
+            "Routing test cases failed for algorithm '{}': {}",
+            report.algorithm_id,
+            report.get_failure_summary()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::panic)]
+
+    use api_models::enums::RoutableConnectors;
+
+    use super::*;
+
+    fn choice(connector: RoutableConnectors) -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            #[cfg(feature = "connector_choice_mca_id")]
+            merchant_connector_id: None,
+            #[cfg(not(feature = "connector_choice_mca_id"))]
+            sub_label: None,
+        }
+    }
+
+    #[test]
+    fn test_priority_test_case_expects_first_eligible_connector() {
+        let selection = routing_types::ConnectorSelection::Priority(vec![
+            choice(RoutableConnectors::Stripe),
+            choice(RoutableConnectors::Adyen),
+        ]);
+        let stripe = choice(RoutableConnectors::Stripe);
+        let adyen = choice(RoutableConnectors::Adyen);
+
+        assert_eq!(
+            get_routing_test_case_failure_reason(
+                Some("cards"),
+                Some(&stripe),
+                Some("cards"),
+                &selection,
+                &[stripe.clone(), adyen.clone()],
+            ),
+            None
+        );
+        assert_eq!(
+            get_routing_test_case_failure_reason(
+                Some("cards"),
+                None,
+                None,
+                &selection,
+                &[stripe.clone()],
+            )
+            .unwrap(),
+            "Expected rule 'cards' to match, got the default selection"
+        );
+        // Stripe is filtered out by the knowledge graph, so adyen is tried first
+        assert_eq!(
+            get_routing_test_case_failure_reason(
+                None,
+                Some(&stripe),
+                Some("cards"),
+                &selection,
+                &[adyen],
+            )
+            .unwrap(),
+            "Expected connector 'stripe' to be selected, eligible connectors were [adyen]"
+        );
+    }
+
+    #[test]
+    fn test_volume_split_test_case_ignores_zero_splits() {
+        let stripe = choice(RoutableConnectors::Stripe);
+        let adyen = choice(RoutableConnectors::Adyen);
+        let selection = routing_types::ConnectorSelection::VolumeSplit(vec![
+            routing_types::ConnectorVolumeSplit {
+                connector: stripe.clone(),
+                split: 100,
+            },
+            routing_types::ConnectorVolumeSplit {
+                connector: adyen.clone(),
+                split: 0,
+            },
+        ]);
+        let eligible = [stripe.clone(), adyen.clone()];
+
+        assert_eq!(
+            get_routing_test_case_failure_reason(None, Some(&stripe), None, &selection, &eligible),
+            None
+        );
+        assert!(get_routing_test_case_failure_reason(
+            None,
+            Some(&adyen),
+            None,
+            &selection,
+            &eligible
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn test_failed_routing_tests_return_report_in_error_body() {
+        let report = routing_types::RoutingTestReport {
+            algorithm_id: "routing_123".to_string(),
+            passed: false,
+            results: vec![routing_types::RoutingTestCaseResult {
+                name: "eu_cards".to_string(),
+                passed: false,
+                rule_name: None,
+                connectors: vec![choice(RoutableConnectors::Stripe)],
+                failure_reason: Some("Expected rule 'cards' to match".to_string()),
+            }],
+        };
+
+        let err = ensure_routing_tests_passed(&report).unwrap_err();
+        match err.current_context() {
+            errors::ApiErrorResponse::RoutingTestsFailed { data: Some(data) } => {
+                assert_eq!(data["algorithm_id"], "routing_123");
+                assert_eq!(data["results"][0]["name"], "eu_cards");
+                assert_eq!(
+                    data["results"][0]["failure_reason"],
+                    "Expected rule 'cards' to match"
+                );
+            }
+            other => panic!("Unexpected error: {other:?}"),
+        }
+
+        let passed_report = routing_types::RoutingTestReport {
+            passed: true,
+            results: Vec::new(),
+            ..report
+        };
+        assert!(ensure_routing_tests_passed(&passed_report).is_ok());
+    }
+}
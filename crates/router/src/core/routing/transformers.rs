@@ -25,6 +25,7 @@ impl ForeignFrom<RoutingProfileMetadata> for RoutingDictionaryRecord {
             description: value.description.unwrap_or_default(),
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -40,6 +41,7 @@ impl ForeignFrom<RoutingAlgorithm> for RoutingDictionaryRecord {
             description: value.description.unwrap_or_default(),
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -48,15 +50,18 @@ impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
     type Error = error_stack::Report<errors::ParsingError>;
 
     fn foreign_try_from(value: RoutingAlgorithm) -> Result<Self, Self::Error> {
+        let algorithm = value
+            .algorithm_data
+            .parse_value::<Algorithm>("RoutingAlgorithm")?;
+
         Ok(Self {
             id: value.algorithm_id,
             name: value.name,
             #[cfg(feature = "business_profile_routing")]
             profile_id: value.profile_id,
             description: value.description.unwrap_or_default(),
-            algorithm: value
-                .algorithm_data
-                .parse_value::<Algorithm>("RoutingAlgorithm")?,
+            program_text: algorithm.get_program_text(),
+            algorithm,
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
         })
@@ -0,0 +1,1171 @@
+use api_models::{payments as payment_types, subscriptions as subscription_types};
+use common_utils::{
+    date_time,
+    ext_traits::{StringExt, ValueExt},
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::{
+    consumer::types::process_data, db::process_tracker::ProcessTrackerExt, utils as pt_utils,
+};
+use time::{Duration, PrimitiveDateTime};
+
+use super::{
+    errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    payment_methods::Oss,
+    payments, webhooks,
+};
+use crate::{
+    consts,
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+const SUBSCRIPTION_BILLING_RUNNER: &str = "SUBSCRIPTION_BILLING_WORKFLOW";
+const SUBSCRIPTION_BILLING_TASK: &str = "SUBSCRIPTION_BILLING";
+const SUBSCRIPTION_PENDING_PAYMENT_CHECK_INTERVAL_IN_SECONDS: i64 = 60 * 60;
+
+#[instrument(skip(state))]
+pub async fn create_subscription_plan(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionPlanCreateRequest,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    utils::when(req.amount <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero".to_string(),
+        })
+    })?;
+    let billing_interval_count = req.interval_count.unwrap_or(1);
+    utils::when(billing_interval_count == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "interval_count must be greater than zero".to_string(),
+        })
+    })?;
+
+    let current_time = date_time::now();
+    let plan = storage::SubscriptionPlanNew {
+        plan_id: utils::generate_id(consts::ID_LENGTH, "plan"),
+        merchant_id: merchant_account.merchant_id,
+        name: req.name,
+        description: req.description,
+        amount: req.amount,
+        currency: req.currency,
+        billing_interval: req.interval,
+        billing_interval_count: billing_interval_count.into(),
+        trial_period_days: req.trial_period_days.map(Into::into),
+        is_active: true,
+        metadata: req.metadata,
+        created_at: current_time,
+        modified_at: current_time,
+    };
+
+    let plan = state
+        .store
+        .insert_subscription_plan(plan)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Subscription plan with the given id already exists".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(plan.foreign_into()))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_subscription_plan(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionPlanId,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let plan =
+        find_subscription_plan(&*state.store, &merchant_account.merchant_id, &req.plan_id).await?;
+
+    Ok(services::ApplicationResponse::Json(plan.foreign_into()))
+}
+
+#[instrument(skip(state))]
+pub async fn list_subscription_plans(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<subscription_types::SubscriptionPlanResponse>> {
+    let plans = state
+        .store
+        .list_subscription_plans_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription plans")?;
+
+    Ok(services::ApplicationResponse::Json(
+        plans.into_iter().map(ForeignInto::foreign_into).collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn create_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionCreateRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let plan = find_subscription_plan(db, merchant_id, &req.plan_id).await?;
+    utils::when(!plan.is_active, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Subscription plan is not active".to_string(),
+        })
+    })?;
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, &req.mandate_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    validate_subscription_mandate(&mandate, &req.customer_id, &plan, plan.amount)?;
+
+    let profile_id = req
+        .profile_id
+        .clone()
+        .or(merchant_account.default_profile.clone());
+    if let Some(profile_id) = &profile_id {
+        let business_profile = db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?;
+        utils::when(business_profile.merchant_id != *merchant_id, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "profile_id does not belong to the merchant".to_string(),
+            })
+        })?;
+    }
+
+    let current_time = date_time::now();
+    let trial_end = plan
+        .trial_period_days
+        .filter(|trial_period_days| *trial_period_days > 0)
+        .map(|trial_period_days| {
+            current_time.saturating_add(Duration::days(trial_period_days.into()))
+        });
+    let status = if trial_end.is_some() {
+        enums::SubscriptionStatus::Trialing
+    } else {
+        enums::SubscriptionStatus::Incomplete
+    };
+
+    // Customers are charged at the end of the current period for the period that follows it. A
+    // subscription without a trial starts with an empty period, so that it is charged right away.
+    let subscription = storage::SubscriptionNew {
+        subscription_id: utils::generate_id(consts::ID_LENGTH, "sub"),
+        merchant_id: merchant_id.to_owned(),
+        customer_id: req.customer_id,
+        plan_id: plan.plan_id,
+        mandate_id: mandate.mandate_id,
+        profile_id,
+        status,
+        current_period_start: current_time,
+        current_period_end: trial_end.unwrap_or(current_time),
+        trial_end,
+        cancel_at_period_end: false,
+        cancelled_at: None,
+        proration_amount: 0,
+        latest_payment_id: None,
+        metadata: req.metadata,
+        created_at: current_time,
+        modified_at: current_time,
+    };
+
+    let subscription = db
+        .insert_subscription(subscription)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Subscription with the given id already exists".to_string(),
+        })?;
+
+    add_subscription_billing_task(db, &subscription).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription.foreign_into(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionId,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let subscription = find_subscription(
+        &*state.store,
+        &merchant_account.merchant_id,
+        &req.subscription_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription.foreign_into(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn update_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionUpdateRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let subscription = find_subscription(db, merchant_id, &req.subscription_id).await?;
+    utils::when(
+        matches!(
+            subscription.status,
+            enums::SubscriptionStatus::Cancelled | enums::SubscriptionStatus::Unpaid
+        ),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Subscription cannot be updated as it is in {} status",
+                    subscription.status
+                ),
+            })
+        },
+    )?;
+
+    let current_plan = find_subscription_plan(db, merchant_id, &subscription.plan_id).await?;
+    let new_plan = find_subscription_plan(db, merchant_id, &req.plan_id).await?;
+    utils::when(!new_plan.is_active, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Subscription plan is not active".to_string(),
+        })
+    })?;
+    utils::when(new_plan.currency != current_plan.currency, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Subscription can only be moved to a plan with the same currency".to_string(),
+        })
+    })?;
+
+    // Nothing has been paid for the current period of trialing or incomplete subscriptions, so
+    // there is nothing to prorate
+    let proration_amount = match subscription.status {
+        enums::SubscriptionStatus::Active | enums::SubscriptionStatus::PastDue => subscription
+            .proration_amount
+            .saturating_add(calculate_proration_amount(
+                current_plan.amount,
+                new_plan.amount,
+                subscription.current_period_start,
+                subscription.current_period_end,
+                date_time::now(),
+            )),
+        _ => subscription.proration_amount,
+    };
+
+    // The next billing cycle charges the new plan's price along with the prorated amount, which
+    // the mandate must still cover
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, &subscription.mandate_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    validate_subscription_mandate(
+        &mandate,
+        &subscription.customer_id,
+        &new_plan,
+        new_plan.amount.saturating_add(proration_amount),
+    )?;
+
+    let subscription = db
+        .update_subscription_by_merchant_id_subscription_id(
+            merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::PlanUpdate {
+                plan_id: new_plan.plan_id,
+                proration_amount,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription.foreign_into(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionCancelRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.merchant_id.clone();
+
+    let subscription = find_subscription(db, &merchant_id, &req.subscription_id).await?;
+    utils::when(
+        subscription.status == enums::SubscriptionStatus::Cancelled,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Subscription is already cancelled".to_string(),
+            })
+        },
+    )?;
+
+    // Subscriptions cancelled at the end of the period are cancelled by the billing task, instead
+    // of charging the customer for the next period
+    if req.at_period_end {
+        let subscription = db
+            .update_subscription_by_merchant_id_subscription_id(
+                &merchant_id,
+                &subscription.subscription_id,
+                storage::SubscriptionUpdate::CancelAtPeriodEndUpdate {
+                    cancel_at_period_end: true,
+                },
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Subscription not found".to_string(),
+            })?;
+
+        return Ok(services::ApplicationResponse::Json(
+            subscription.foreign_into(),
+        ));
+    }
+
+    let previous_subscription = subscription.clone();
+    let subscription = db
+        .update_subscription_by_merchant_id_subscription_id(
+            &merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::CancelledUpdate {
+                cancelled_at: date_time::now(),
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription not found".to_string(),
+        })?;
+
+    let process_tracker_id = get_subscription_billing_process_tracker_id(&subscription);
+    let billing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the subscription billing task")?;
+    if let Some(billing_process) = billing_process {
+        billing_process
+            .finish_with_status(db.as_scheduler(), "SUBSCRIPTION_CANCELLED".to_string())
+            .await
+            .map_err(|error| logger::error!(subscription_billing_task_error=?error))
+            .ok();
+    }
+
+    let primary_object_id = subscription.subscription_id.clone();
+    trigger_subscription_webhook(
+        &state,
+        merchant_account,
+        subscription.clone(),
+        Some(previous_subscription),
+        enums::EventType::SubscriptionCancelled,
+        primary_object_id,
+    )
+    .await
+    .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+    .ok();
+
+    Ok(services::ApplicationResponse::Json(
+        subscription.foreign_into(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn start_subscription_billing_workflow(
+    state: &AppState,
+    billing_process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::SubscriptionBillingTrackingData = billing_process
+        .tracking_data
+        .clone()
+        .parse_value("SubscriptionBillingTrackingData")?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await?;
+
+    let subscription = find_subscription(
+        db,
+        &tracking_data.merchant_id,
+        &tracking_data.subscription_id,
+    )
+    .await?;
+
+    if matches!(
+        subscription.status,
+        enums::SubscriptionStatus::Cancelled | enums::SubscriptionStatus::Unpaid
+    ) {
+        let business_status = format!("SUBSCRIPTION_{}", subscription.status).to_uppercase();
+        return billing_process
+            .clone()
+            .finish_with_status(db.as_scheduler(), business_status)
+            .await;
+    }
+
+    if subscription.cancel_at_period_end {
+        let previous_subscription = subscription.clone();
+        let subscription = db
+            .update_subscription_by_merchant_id_subscription_id(
+                &subscription.merchant_id,
+                &subscription.subscription_id,
+                storage::SubscriptionUpdate::CancelledUpdate {
+                    cancelled_at: date_time::now(),
+                },
+            )
+            .await?;
+
+        let primary_object_id = subscription.subscription_id.clone();
+        trigger_subscription_webhook(
+            state,
+            merchant_account,
+            subscription,
+            Some(previous_subscription),
+            enums::EventType::SubscriptionCancelled,
+            primary_object_id,
+        )
+        .await
+        .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+        .ok();
+
+        return billing_process
+            .clone()
+            .finish_with_status(db.as_scheduler(), "SUBSCRIPTION_CANCELLED".to_string())
+            .await;
+    }
+
+    // The period was already paid for by an earlier run of this task that did not get to
+    // reschedule it
+    if subscription.current_period_end > date_time::now() {
+        return billing_process
+            .clone()
+            .reset(db.as_scheduler(), subscription.current_period_end)
+            .await;
+    }
+
+    let plan = find_subscription_plan(db, &subscription.merchant_id, &subscription.plan_id).await?;
+    let next_period_start = subscription.current_period_end;
+    let next_period_end = add_billing_interval(
+        next_period_start,
+        plan.billing_interval,
+        plan.billing_interval_count,
+        get_billing_anchor_day(&subscription),
+    )
+    .get_required_value("next_period_end")
+    .attach_printable("Failed to compute the end of the next billing period")?;
+    let amount = plan.amount.saturating_add(subscription.proration_amount);
+
+    // The credit left from plan changes covers the whole billing cycle, carry the remaining credit
+    // over to the next one
+    if amount <= 0 {
+        db.update_subscription_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::PeriodUpdate {
+                status: enums::SubscriptionStatus::Active,
+                current_period_start: next_period_start,
+                current_period_end: next_period_end,
+                proration_amount: amount,
+                latest_payment_id: None,
+            },
+        )
+        .await?;
+
+        return billing_process
+            .clone()
+            .reset(db.as_scheduler(), next_period_end)
+            .await;
+    }
+
+    let payment_id = get_subscription_payment_id(
+        &subscription,
+        next_period_start,
+        billing_process.retry_count,
+    );
+    let payment_status = match db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => Some(payment_intent.status),
+        Err(error) if error.current_context().is_db_not_found() => {
+            match make_subscription_payment(
+                state,
+                merchant_account.clone(),
+                key_store,
+                &subscription,
+                &plan,
+                amount,
+                payment_id.clone(),
+            )
+            .await
+            {
+                Ok(payment_status) => Some(payment_status),
+                // A charge that could not be created is handled as a failed charge, so that the
+                // subscription goes through dunning and the charge is retried
+                Err(error) => {
+                    logger::error!(subscription_payment_error=?error);
+                    Some(enums::IntentStatus::Failed)
+                }
+            }
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the subscription payment")?,
+    };
+
+    // The outcome of pending payments is checked again later, a new payment for the same attempt
+    // could charge the customer twice
+    if matches!(
+        payment_status,
+        Some(enums::IntentStatus::Processing | enums::IntentStatus::RequiresCapture)
+    ) {
+        let schedule_time = date_time::now().saturating_add(Duration::seconds(
+            SUBSCRIPTION_PENDING_PAYMENT_CHECK_INTERVAL_IN_SECONDS,
+        ));
+        db.as_scheduler()
+            .update_process(
+                billing_process.clone(),
+                storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                    status: enums::ProcessTrackerStatus::Pending,
+                    retry_count: billing_process.retry_count,
+                    schedule_time,
+                },
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let is_payment_successful = matches!(
+        payment_status,
+        Some(enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured)
+    );
+
+    if is_payment_successful {
+        let previous_subscription = subscription.clone();
+        let subscription = db
+            .update_subscription_by_merchant_id_subscription_id(
+                &subscription.merchant_id,
+                &subscription.subscription_id,
+                storage::SubscriptionUpdate::PeriodUpdate {
+                    status: enums::SubscriptionStatus::Active,
+                    current_period_start: next_period_start,
+                    current_period_end: next_period_end,
+                    proration_amount: 0,
+                    latest_payment_id: Some(payment_id.clone()),
+                },
+            )
+            .await?;
+
+        trigger_subscription_webhook(
+            state,
+            merchant_account,
+            subscription,
+            Some(previous_subscription),
+            enums::EventType::SubscriptionCycleSucceeded,
+            payment_id,
+        )
+        .await
+        .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+        .ok();
+
+        return billing_process
+            .clone()
+            .reset(db.as_scheduler(), next_period_end)
+            .await;
+    }
+
+    let retry_schedule_time = get_subscription_dunning_schedule_time(
+        db,
+        &subscription.merchant_id,
+        billing_process.retry_count,
+    )
+    .await;
+    let status = if retry_schedule_time.is_some() {
+        enums::SubscriptionStatus::PastDue
+    } else {
+        enums::SubscriptionStatus::Unpaid
+    };
+
+    let previous_subscription = subscription.clone();
+    let subscription = db
+        .update_subscription_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::StatusUpdate {
+                status,
+                latest_payment_id: Some(payment_id.clone()),
+            },
+        )
+        .await?;
+
+    let mut event_types = vec![enums::EventType::SubscriptionCycleFailed];
+    match status {
+        enums::SubscriptionStatus::PastDue if billing_process.retry_count == 0 => {
+            event_types.push(enums::EventType::SubscriptionPastDue)
+        }
+        enums::SubscriptionStatus::Unpaid => event_types.push(enums::EventType::SubscriptionUnpaid),
+        _ => (),
+    }
+    for event_type in event_types {
+        trigger_subscription_webhook(
+            state,
+            merchant_account.clone(),
+            subscription.clone(),
+            Some(previous_subscription.clone()),
+            event_type,
+            payment_id.clone(),
+        )
+        .await
+        .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+        .ok();
+    }
+
+    match retry_schedule_time {
+        Some(schedule_time) => {
+            billing_process
+                .clone()
+                .retry(db.as_scheduler(), schedule_time)
+                .await
+        }
+        None => {
+            billing_process
+                .clone()
+                .finish_with_status(db.as_scheduler(), "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
+async fn make_subscription_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    plan: &storage::SubscriptionPlan,
+    amount: i64,
+    payment_id: String,
+) -> RouterResult<enums::IntentStatus> {
+    let req = payment_types::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(payment_id)),
+        amount: Some(amount.into()),
+        currency: Some(plan.currency),
+        customer_id: Some(subscription.customer_id.clone()),
+        mandate_id: Some(subscription.mandate_id.clone()),
+        off_session: Some(true),
+        confirm: Some(true),
+        payment_type: Some(enums::PaymentType::RecurringMandate),
+        description: Some(format!("Subscription to {}", plan.name)),
+        profile_id: subscription.profile_id.clone(),
+        metadata: Some(masking::Secret::new(serde_json::json!({
+            "subscription_id": subscription.subscription_id,
+            "plan_id": plan.plan_id,
+        }))),
+        ..Default::default()
+    };
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        merchant_account,
+        key_store,
+        payments::PaymentCreate,
+        req,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        payment_types::HeaderPayload::default(),
+    ))
+    .await?;
+
+    match payments_response {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            Ok(payments_response.status)
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Received non-json response from payments core"),
+    }
+}
+
+async fn trigger_subscription_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    subscription: storage::Subscription,
+    previous_subscription: Option<storage::Subscription>,
+    event_type: enums::EventType,
+    primary_object_id: String,
+) -> RouterResult<()> {
+    let profile_id = match subscription.profile_id.as_ref() {
+        Some(profile_id) => profile_id,
+        None => {
+            logger::info!(
+                "Skipping {event_type} webhook as subscription {} has no business profile",
+                subscription.subscription_id
+            );
+            return Ok(());
+        }
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    Box::pin(
+        webhooks::create_event_and_trigger_appropriate_outgoing_webhook(
+            state.clone(),
+            merchant_account,
+            business_profile,
+            event_type,
+            enums::EventClass::Subscriptions,
+            None,
+            primary_object_id,
+            enums::EventObjectType::SubscriptionDetails,
+            api::OutgoingWebhookContent::SubscriptionDetails(Box::new(subscription.foreign_into())),
+            previous_subscription.map(|previous_subscription| {
+                api::OutgoingWebhookContent::SubscriptionDetails(Box::new(
+                    previous_subscription.foreign_into(),
+                ))
+            }),
+        ),
+    )
+    .await
+}
+
+async fn add_subscription_billing_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> RouterResult<storage::ProcessTracker> {
+    let tracking_data = storage::SubscriptionBillingTrackingData {
+        subscription_id: subscription.subscription_id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+    };
+    let tracking_data = serde_json::to_value(tracking_data)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize subscription billing tracking data")?;
+
+    let current_time = date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: get_subscription_billing_process_tracker_id(subscription),
+        name: Some(String::from(SUBSCRIPTION_BILLING_TASK)),
+        tag: vec![String::from("SUBSCRIPTION")],
+        runner: Some(String::from(SUBSCRIPTION_BILLING_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(subscription.current_period_end),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: subscription_id: {}",
+                subscription.subscription_id
+            )
+        })
+}
+
+/// Payment id of the charge for the billing period starting at `period_start`.
+///
+/// Each dunning attempt is a new payment, so a task that is picked up again after charging the
+/// customer finds the payment of its attempt instead of creating another one.
+fn get_subscription_payment_id(
+    subscription: &storage::Subscription,
+    period_start: PrimitiveDateTime,
+    attempt: i32,
+) -> String {
+    format!(
+        "{}_{}_{attempt}",
+        subscription.subscription_id,
+        period_start.assume_utc().unix_timestamp()
+    )
+}
+
+/// Day of the month on which monthly and yearly subscriptions are billed, which is the day of the
+/// first billing cycle.
+fn get_billing_anchor_day(subscription: &storage::Subscription) -> u8 {
+    subscription
+        .trial_end
+        .unwrap_or(subscription.created_at)
+        .day()
+}
+
+fn get_subscription_billing_process_tracker_id(subscription: &storage::Subscription) -> String {
+    pt_utils::get_process_tracker_id(
+        SUBSCRIPTION_BILLING_RUNNER,
+        SUBSCRIPTION_BILLING_TASK,
+        &subscription.subscription_id,
+        &subscription.merchant_id,
+    )
+}
+
+async fn get_subscription_dunning_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<PrimitiveDateTime> {
+    let mapping = db
+        .find_config_by_key("pt_mapping_subscription_dunning")
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("SubscriptionDunningPTMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        })
+        .unwrap_or_else(|error| {
+            logger::info!("Subscription dunning mapping error: {}", error);
+            process_data::SubscriptionDunningPTMapping::default()
+        });
+    let time_delta =
+        pt_utils::get_subscription_dunning_schedule_time(mapping, merchant_id, retry_count);
+
+    pt_utils::get_time_from_delta(time_delta)
+}
+
+async fn find_subscription(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    db.find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription not found".to_string(),
+        })
+}
+
+async fn find_subscription_plan(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    plan_id: &str,
+) -> RouterResult<storage::SubscriptionPlan> {
+    db.find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription plan not found".to_string(),
+        })
+}
+
+/// Check that `mandate` can be used to charge `amount` for a subscription to `plan`.
+fn validate_subscription_mandate(
+    mandate: &storage::Mandate,
+    customer_id: &str,
+    plan: &storage::SubscriptionPlan,
+    amount: i64,
+) -> RouterResult<()> {
+    utils::when(mandate.customer_id != customer_id, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "mandate_id does not belong to the customer".to_string(),
+        })
+    })?;
+    utils::when(
+        mandate.mandate_status != enums::MandateStatus::Active,
+        || {
+            Err(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate is not active".to_string(),
+            })
+        },
+    )?;
+    utils::when(mandate.mandate_type != enums::MandateType::MultiUse, || {
+        Err(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "only multi use mandates can be used for subscriptions".to_string(),
+        })
+    })?;
+    utils::when(
+        mandate
+            .mandate_currency
+            .map_or(false, |currency| currency != plan.currency),
+        || {
+            Err(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate currency does not match the plan currency".to_string(),
+            })
+        },
+    )?;
+    utils::when(
+        mandate
+            .mandate_amount
+            .map_or(false, |mandate_amount| mandate_amount < amount),
+        || {
+            Err(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate amount is lower than the subscription amount".to_string(),
+            })
+        },
+    )?;
+    utils::when(
+        mandate
+            .end_date
+            .map_or(false, |end_date| end_date <= date_time::now()),
+        || {
+            Err(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate has expired".to_string(),
+            })
+        },
+    )
+}
+
+/// Add `interval_count` billing intervals to `date_time`.
+///
+/// Monthly and yearly intervals land on `billing_anchor_day`, falling back to the last day of the
+/// month for months that are too short. Anchoring to the original day keeps a cycle that was cut
+/// short by a short month from shifting all the cycles that follow it.
+pub fn add_billing_interval(
+    date_time: PrimitiveDateTime,
+    interval: enums::SubscriptionInterval,
+    interval_count: i32,
+    billing_anchor_day: u8,
+) -> Option<PrimitiveDateTime> {
+    match interval {
+        enums::SubscriptionInterval::Day => {
+            date_time.checked_add(Duration::days(interval_count.into()))
+        }
+        enums::SubscriptionInterval::Week => {
+            date_time.checked_add(Duration::weeks(interval_count.into()))
+        }
+        enums::SubscriptionInterval::Month => {
+            add_months(date_time, interval_count, billing_anchor_day)
+        }
+        enums::SubscriptionInterval::Year => add_months(
+            date_time,
+            interval_count.checked_mul(12)?,
+            billing_anchor_day,
+        ),
+    }
+}
+
+fn add_months(
+    date_time: PrimitiveDateTime,
+    months: i32,
+    billing_anchor_day: u8,
+) -> Option<PrimitiveDateTime> {
+    let date = date_time.date();
+    let month_index = date
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date.month())) - 1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = billing_anchor_day.min(time::util::days_in_year_month(year, month));
+
+    time::Date::from_calendar_date(year, month, day)
+        .ok()
+        .map(|date| PrimitiveDateTime::new(date, date_time.time()))
+}
+
+/// Amount to be added to the next billing cycle when moving from a plan costing `current_amount`
+/// to one costing `new_amount` at `change_time`.
+///
+/// The unused part of the current period is credited at the current plan's price and charged at
+/// the new plan's price, so the result is negative for downgrades.
+pub fn calculate_proration_amount(
+    current_amount: i64,
+    new_amount: i64,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+    change_time: PrimitiveDateTime,
+) -> i64 {
+    let period_seconds = (period_end - period_start).whole_seconds();
+    if period_seconds <= 0 {
+        return 0;
+    }
+    let remaining_seconds = (period_end - change_time)
+        .whole_seconds()
+        .clamp(0, period_seconds);
+
+    let prorated_amount = (i128::from(new_amount) - i128::from(current_amount))
+        * i128::from(remaining_seconds)
+        / i128::from(period_seconds);
+
+    i64::try_from(prorated_amount).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_add_billing_interval() {
+        let date_time = datetime!(2023-01-31 10:00);
+        assert_eq!(
+            add_billing_interval(date_time, enums::SubscriptionInterval::Day, 3, 31),
+            Some(datetime!(2023-02-03 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(date_time, enums::SubscriptionInterval::Week, 2, 31),
+            Some(datetime!(2023-02-14 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(date_time, enums::SubscriptionInterval::Month, 1, 31),
+            Some(datetime!(2023-02-28 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(date_time, enums::SubscriptionInterval::Month, 13, 31),
+            Some(datetime!(2024-02-29 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(date_time, enums::SubscriptionInterval::Year, 1, 31),
+            Some(datetime!(2024-01-31 10:00))
+        );
+    }
+
+    #[test]
+    fn test_add_billing_interval_keeps_the_billing_anchor_day() {
+        let mut date_time = datetime!(2023-01-31 10:00);
+        let mut billing_dates = Vec::new();
+        for _ in 0..3 {
+            date_time =
+                add_billing_interval(date_time, enums::SubscriptionInterval::Month, 1, 31).unwrap();
+            billing_dates.push(date_time);
+        }
+
+        assert_eq!(
+            billing_dates,
+            vec![
+                datetime!(2023-02-28 10:00),
+                datetime!(2023-03-31 10:00),
+                datetime!(2023-04-30 10:00),
+            ]
+        );
+        assert_eq!(
+            add_billing_interval(
+                datetime!(2024-02-29 10:00),
+                enums::SubscriptionInterval::Year,
+                1,
+                29
+            ),
+            Some(datetime!(2025-02-28 10:00))
+        );
+    }
+
+    #[test]
+    fn test_subscription_dunning_stops_at_max_retries_count() {
+        let mapping = || process_data::SubscriptionDunningPTMapping {
+            max_retries_count: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            pt_utils::get_subscription_dunning_schedule_time(mapping(), "merchant", 0),
+            Some(86400)
+        );
+        assert_eq!(
+            pt_utils::get_subscription_dunning_schedule_time(mapping(), "merchant", 1),
+            Some(172800)
+        );
+        assert_eq!(
+            pt_utils::get_subscription_dunning_schedule_time(mapping(), "merchant", 2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_subscription_dunning_uses_merchant_mapping_before_max_retries_count() {
+        let mapping = || process_data::SubscriptionDunningPTMapping {
+            custom_merchant_mapping: std::collections::HashMap::from([(
+                "merchant".to_string(),
+                process_data::RetryMapping {
+                    start_after: 3600,
+                    frequency: vec![7200],
+                    count: vec![4],
+                },
+            )]),
+            max_retries_count: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            pt_utils::get_subscription_dunning_schedule_time(mapping(), "merchant", 3),
+            Some(7200)
+        );
+        assert_eq!(
+            pt_utils::get_subscription_dunning_schedule_time(mapping(), "merchant", 5),
+            None
+        );
+        assert_eq!(
+            pt_utils::get_subscription_dunning_schedule_time(mapping(), "other_merchant", 2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_calculate_proration_amount() {
+        let period_start = datetime!(2023-04-01 00:00);
+        let period_end = datetime!(2023-05-01 00:00);
+
+        // Upgrade half way through the period
+        assert_eq!(
+            calculate_proration_amount(
+                1000,
+                3000,
+                period_start,
+                period_end,
+                datetime!(2023-04-16 00:00)
+            ),
+            1000
+        );
+        // Downgrade half way through the period
+        assert_eq!(
+            calculate_proration_amount(
+                3000,
+                1000,
+                period_start,
+                period_end,
+                datetime!(2023-04-16 00:00)
+            ),
+            -1000
+        );
+        // Change after the end of the period
+        assert_eq!(
+            calculate_proration_amount(1000, 3000, period_start, period_end, period_end),
+            0
+        );
+    }
+}
@@ -1,12 +1,21 @@
 use api_models::user as api;
-use diesel_models::enums::UserStatus;
-use error_stack::IntoReport;
+use diesel_models::{enums::UserStatus, user as storage_user};
+use error_stack::{IntoReport, ResultExt};
 use masking::{ExposeInterface, Secret};
 use router_env::env;
+#[cfg(feature = "email")]
+use router_env::logger;
 
+#[cfg(feature = "email")]
+use super::errors::UserResult;
 use super::errors::{UserErrors, UserResponse};
+#[cfg(feature = "email")]
+use crate::services::{email as email_service, email::types as email_types};
 use crate::{
-    consts::user as consts, routes::AppState, services::ApplicationResponse, types::domain,
+    consts::user as consts,
+    routes::AppState,
+    services::{authentication as auth, ApplicationResponse},
+    types::domain,
 };
 
 pub async fn connect_account(
@@ -23,6 +32,11 @@ pub async fn connect_account(
 
         user_from_db.compare_password(request.password)?;
 
+        #[cfg(feature = "email")]
+        if !user_from_db.is_verified() {
+            send_verification_email(&state, user_from_db.get_email()).await;
+        }
+
         let user_role = user_from_db.get_role_from_db(state.clone()).await?;
         let jwt_token = user_from_db
             .get_jwt_auth_token(state.clone(), user_role.org_id)
@@ -62,6 +76,10 @@ pub async fn connect_account(
                 UserStatus::Active,
             )
             .await?;
+
+        #[cfg(feature = "email")]
+        send_verification_email(&state, user_from_db.get_email()).await;
+
         let jwt_token = user_from_db
             .get_jwt_auth_token(state.clone(), user_role.org_id)
             .await?;
@@ -79,3 +97,77 @@ pub async fn connect_account(
         Err(UserErrors::InternalServerError.into())
     }
 }
+
+/// Sends the email verification link to the user. Failures are only logged, so that the user can
+/// still sign in when the email could not be delivered.
+#[cfg(feature = "email")]
+async fn send_verification_email(state: &AppState, email: common_utils::pii::Email) {
+    let result: UserResult<()> = async {
+        let user_email = domain::UserEmail::from_pii_email(email.clone())?;
+        let token = auth::EmailToken::new_token(user_email, &state.conf).await?;
+        let template = email_types::EmailVerification {
+            link: email_types::get_link_with_token(
+                &state.conf.email.base_url,
+                &token,
+                "verify_email",
+            ),
+        };
+
+        email_service::compose_and_send_email(
+            state,
+            email,
+            &template,
+            &email_types::EmailBranding::default(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to send verification email");
+    }
+}
+
+pub async fn verify_email(
+    state: AppState,
+    request: api::VerifyEmailRequest,
+) -> UserResponse<api::ConnectAccountResponse> {
+    let token = auth::decode_jwt::<auth::EmailToken>(request.token.expose().as_str(), &state)
+        .await
+        .change_context(UserErrors::LinkInvalid)?;
+
+    let user = state
+        .store
+        .find_user_by_email(token.get_email())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(UserErrors::LinkInvalid)
+            } else {
+                error.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    let user_from_db: domain::UserFromStorage = state
+        .store
+        .update_user_by_user_id(user.user_id.as_str(), storage_user::UserUpdate::VerifyUser)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let user_role = user_from_db.get_role_from_db(state.clone()).await?;
+    let jwt_token = user_from_db
+        .get_jwt_auth_token(state.clone(), user_role.org_id)
+        .await?;
+
+    Ok(ApplicationResponse::Json(api::ConnectAccountResponse {
+        token: Secret::new(jwt_token),
+        merchant_id: user_role.merchant_id,
+        name: user_from_db.get_name(),
+        email: user_from_db.get_email(),
+        verification_days_left: None,
+        user_role: user_role.role_id,
+        user_id: user_from_db.get_user_id().to_string(),
+    }))
+}
@@ -674,6 +674,7 @@ pub async fn construct_upload_file_router_data<'a>(
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
     create_file_request: &types::api::CreateFileRequest,
+    file: Vec<u8>,
     connector_id: &str,
     file_key: String,
 ) -> RouterResult<types::UploadFileRouterData> {
@@ -726,7 +727,7 @@ pub async fn construct_upload_file_router_data<'a>(
         amount_captured: payment_intent.amount_captured,
         request: types::UploadFileRequestData {
             file_key,
-            file: create_file_request.file.clone(),
+            file,
             file_type: create_file_request.file_type.clone(),
             file_size: create_file_request.file_size,
         },
@@ -2,8 +2,13 @@ pub mod utils;
 use api_models::verifications::{self, ApplepayMerchantResponse};
 use common_utils::{errors::CustomResult, ext_traits::Encode};
 use error_stack::ResultExt;
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "encryption_service")]
+use external_services::{
+    encryption_management::{self, EncryptionManagementConfig},
+    secrets_management::SecretManagementInterface,
+};
+#[cfg(feature = "encryption_service")]
+use masking::ExposeInterface;
 
 use crate::{
     core::errors::{self, api_error_response},
@@ -18,7 +23,7 @@ pub async fn verify_merchant_creds_for_applepay(
     state: AppState,
     _req: &actix_web::HttpRequest,
     body: verifications::ApplepayMerchantVerificationRequest,
-    kms_config: &kms::KmsConfig,
+    encryption_management_config: &EncryptionManagementConfig,
     merchant_id: String,
 ) -> CustomResult<
     services::ApplicationResponse<ApplepayMerchantResponse>,
@@ -32,23 +37,32 @@ pub async fn verify_merchant_creds_for_applepay(
     let encrypted_key = &state.conf.applepay_merchant_configs.merchant_cert_key;
     let applepay_endpoint = &state.conf.applepay_merchant_configs.applepay_endpoint;
 
-    let applepay_internal_merchant_identifier = kms::get_kms_client(kms_config)
-        .await
-        .decrypt(encrypted_merchant_identifier)
-        .await
-        .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
+    let applepay_internal_merchant_identifier =
+        encryption_management::get_encryption_management_client(encryption_management_config)
+            .await
+            .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
+            .get_secret(encrypted_merchant_identifier.clone().into())
+            .await
+            .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
+            .expose();
 
-    let cert_data = kms::get_kms_client(kms_config)
-        .await
-        .decrypt(encrypted_cert)
-        .await
-        .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
+    let cert_data =
+        encryption_management::get_encryption_management_client(encryption_management_config)
+            .await
+            .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
+            .get_secret(encrypted_cert.clone().into())
+            .await
+            .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
+            .expose();
 
-    let key_data = kms::get_kms_client(kms_config)
-        .await
-        .decrypt(encrypted_key)
-        .await
-        .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
+    let key_data =
+        encryption_management::get_encryption_management_client(encryption_management_config)
+            .await
+            .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
+            .get_secret(encrypted_key.clone().into())
+            .await
+            .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
+            .expose();
 
     let request_body = verifications::ApplepayMerchantVerificationConfigs {
         domain_names: body.domain_names.clone(),
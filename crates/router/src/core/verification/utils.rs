@@ -59,6 +59,7 @@ pub async fn check_existence_and_add_domain_to_db(
         connector_webhook_details: None,
         applepay_verified_domains: Some(already_verified_domains.clone()),
         pm_auth_config: None,
+        pm_filters: None,
         connector_label: None,
     };
     state
@@ -18,7 +18,7 @@ use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse},
         payment_methods::PaymentMethodRetrieve,
         payments, refunds,
@@ -171,6 +171,7 @@ pub async fn payments_incoming_webhook_flow<
                     payment_id.clone(),
                     enums::EventObjectType::PaymentDetails,
                     api::OutgoingWebhookContent::PaymentDetails(payments_response),
+                    None,
                 )
                 .await?;
             };
@@ -287,6 +288,7 @@ pub async fn refunds_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             refund_id,
             enums::EventObjectType::RefundDetails,
             api::OutgoingWebhookContent::RefundDetails(refund_response),
+            None,
         )
         .await?;
     }
@@ -475,6 +477,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 updated_mandate.mandate_id.clone(),
                 enums::EventObjectType::MandateDetails,
                 api::OutgoingWebhookContent::MandateDetails(mandates_response),
+                None,
             )
             .await?;
         }
@@ -528,6 +531,10 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             connector.id(),
         )
         .await?;
+        disputes::schedule_dispute_deadline_task(db, &dispute_object)
+            .await
+            .map_err(|error| logger::error!(dispute_deadline_task_error=?error))
+            .ok();
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -541,6 +548,7 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             dispute_object.dispute_id.clone(),
             enums::EventObjectType::DisputeDetails,
             api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+            None,
         )
         .await?;
         metrics::INCOMING_DISPUTE_WEBHOOK_MERCHANT_NOTIFIED_METRIC.add(&metrics::CONTEXT, 1, &[]);
@@ -627,6 +635,7 @@ async fn bank_transfer_webhook_flow<W: types::OutgoingWebhookType, Ctx: PaymentM
                     payment_id.clone(),
                     enums::EventObjectType::PaymentDetails,
                     api::OutgoingWebhookContent::PaymentDetails(payments_response),
+                    None,
                 )
                 .await?;
             }
@@ -652,6 +661,7 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
     primary_object_id: String,
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
+    previous_content: Option<api::OutgoingWebhookContent>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
@@ -666,6 +676,7 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
                 primary_object_id,
                 primary_object_type,
                 content,
+                previous_content,
             )
             .await
         }
@@ -680,6 +691,7 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
                 primary_object_id,
                 primary_object_type,
                 content,
+                previous_content,
             )
             .await
         }
@@ -698,6 +710,7 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
     primary_object_id: String,
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
+    previous_content: Option<api::OutgoingWebhookContent>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let event_id = format!("{primary_object_id}_{}", event_type);
     let new_event = storage::EventNew {
@@ -734,6 +747,8 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
             event_type: event.event_type,
             content,
             timestamp: event.created_at,
+            previous_content,
+            livemode: merchant_account.is_live_mode(),
         };
 
         // Using a tokio spawn here and not arbiter because not all caller of this function
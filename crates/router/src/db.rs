@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_report;
 pub mod api_keys;
 pub mod business_profile;
 pub mod cache;
@@ -25,6 +26,7 @@ pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
 
@@ -82,6 +84,8 @@ pub trait StorageInterface:
     + organization::OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
     + gsm::GsmInterface
+    + subscription::SubscriptionInterface
+    + analytics_report::AnalyticsReportInterface
     + user::UserInterface
     + user_role::UserRoleInterface
     + 'static
@@ -72,6 +72,14 @@ where
         address: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    async fn list_addresses_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -107,7 +115,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -134,7 +142,7 @@ mod storage {
             .into_report()
             .async_and_then(|address| async {
                 address
-                    .convert(key_store.key.get_inner())
+                    .convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -155,7 +163,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -181,7 +189,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -206,7 +214,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -229,13 +237,40 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
                 .await
         }
 
+        async fn list_addresses_by_merchant_id_after_id(
+            &self,
+            merchant_id: &str,
+            last_id: Option<i32>,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::list_by_merchant_id_after_id(&conn, merchant_id, last_id, limit)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|addresses| async {
+                    let mut output = Vec::with_capacity(addresses.len());
+                    for address in addresses.into_iter() {
+                        output.push(
+                            address
+                                .convert(&key_store.key_ring())
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
         async fn update_address_by_merchant_id_customer_id(
             &self,
             customer_id: &str,
@@ -258,7 +293,7 @@ mod storage {
                 for address in addresses.into_iter() {
                     output.push(
                         address
-                            .convert(key_store.key.get_inner())
+                            .convert(&key_store.key_ring())
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -307,7 +342,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -355,7 +390,7 @@ mod storage {
                 }
             }?;
             address
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -374,7 +409,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -402,7 +437,7 @@ mod storage {
                         .into_report()
                         .async_and_then(|address| async {
                             address
-                                .convert(key_store.key.get_inner())
+                                .convert(&key_store.key_ring())
                                 .await
                                 .change_context(errors::StorageError::DecryptionError)
                         })
@@ -442,7 +477,7 @@ mod storage {
                     .change_context(errors::StorageError::KVError)?;
 
                     updated_address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 }
@@ -472,7 +507,7 @@ mod storage {
                         .into_report()
                         .async_and_then(|address| async {
                             address
-                                .convert(key_store.key.get_inner())
+                                .convert(&key_store.key_ring())
                                 .await
                                 .change_context(errors::StorageError::DecryptionError)
                         })
@@ -528,7 +563,7 @@ mod storage {
                         })
                         .into_report(),
                         Ok(HsetnxReply::KeySet) => Ok(created_address
-                            .convert(key_store.key.get_inner())
+                            .convert(&key_store.key_ring())
                             .await
                             .change_context(errors::StorageError::DecryptionError)?),
                         Err(er) => Err(er).change_context(errors::StorageError::KVError),
@@ -553,13 +588,40 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
                 .await
         }
 
+        async fn list_addresses_by_merchant_id_after_id(
+            &self,
+            merchant_id: &str,
+            last_id: Option<i32>,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::list_by_merchant_id_after_id(&conn, merchant_id, last_id, limit)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|addresses| async {
+                    let mut output = Vec::with_capacity(addresses.len());
+                    for address in addresses.into_iter() {
+                        output.push(
+                            address
+                                .convert(&key_store.key_ring())
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
         async fn update_address_by_merchant_id_customer_id(
             &self,
             customer_id: &str,
@@ -582,7 +644,7 @@ mod storage {
                 for address in addresses.into_iter() {
                     output.push(
                         address
-                            .convert(key_store.key.get_inner())
+                            .convert(&key_store.key_ring())
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -610,7 +672,7 @@ impl AddressInterface for MockDb {
         {
             Some(address) => address
                 .clone()
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => {
@@ -638,7 +700,7 @@ impl AddressInterface for MockDb {
         {
             Some(address) => address
                 .clone()
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => {
@@ -669,7 +731,7 @@ impl AddressInterface for MockDb {
                 address_updated
             }) {
             Some(address_updated) => address_updated
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -700,7 +762,7 @@ impl AddressInterface for MockDb {
                 address_updated
             }) {
             Some(address_updated) => address_updated
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -726,7 +788,7 @@ impl AddressInterface for MockDb {
         addresses.push(address.clone());
 
         address
-            .convert(key_store.key.get_inner())
+            .convert(&key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -745,11 +807,46 @@ impl AddressInterface for MockDb {
         addresses.push(address.clone());
 
         address
-            .convert(key_store.key.get_inner())
+            .convert(&key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn list_addresses_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let limit: usize = limit
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let mut addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| {
+                address.merchant_id == merchant_id && address.id > Some(last_id.unwrap_or_default())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        addresses.sort_by_key(|address| address.id);
+        addresses.truncate(limit);
+
+        let mut output = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            output.push(
+                address
+                    .convert(&key_store.key_ring())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
+
     async fn update_address_by_merchant_id_customer_id(
         &self,
         customer_id: &str,
@@ -774,7 +871,7 @@ impl AddressInterface for MockDb {
             }) {
             Some(address) => {
                 let address: domain::Address = address
-                    .convert(key_store.key.get_inner())
+                    .convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)?;
                 Ok(vec![address])
@@ -0,0 +1,192 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsReportInterface {
+    async fn insert_analytics_report(
+        &self,
+        report: storage::AnalyticsReportNew,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError>;
+
+    async fn find_analytics_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError>;
+
+    async fn list_analytics_reports_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsReport>, errors::StorageError>;
+
+    async fn update_analytics_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+        report: storage::AnalyticsReportUpdate,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError>;
+
+    async fn insert_analytics_report_export(
+        &self,
+        export: storage::AnalyticsReportExportNew,
+    ) -> CustomResult<storage::AnalyticsReportExport, errors::StorageError>;
+
+    async fn find_analytics_report_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsReportExport, errors::StorageError>;
+
+    async fn list_analytics_report_exports_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsReportExport>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsReportInterface for Store {
+    async fn insert_analytics_report(
+        &self,
+        report: storage::AnalyticsReportNew,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        report.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_analytics_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsReport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_analytics_reports_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsReport>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsReport::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_analytics_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+        report: storage::AnalyticsReportUpdate,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsReport::update_by_merchant_id_report_id(
+            &conn,
+            merchant_id,
+            report_id,
+            report,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn insert_analytics_report_export(
+        &self,
+        export: storage::AnalyticsReportExportNew,
+    ) -> CustomResult<storage::AnalyticsReportExport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        export.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_analytics_report_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsReportExport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsReportExport::find_by_merchant_id_export_id(&conn, merchant_id, export_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_analytics_report_exports_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsReportExport>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsReportExport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsReportInterface for MockDb {
+    async fn insert_analytics_report(
+        &self,
+        _report: storage::AnalyticsReportNew,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_report_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_reports_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsReport>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_report_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+        _report: storage::AnalyticsReportUpdate,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_analytics_report_export(
+        &self,
+        _export: storage::AnalyticsReportExportNew,
+    ) -> CustomResult<storage::AnalyticsReportExport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_report_export_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &str,
+        _export_id: &str,
+    ) -> CustomResult<storage::AnalyticsReportExport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_report_exports_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsReportExport>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -59,6 +59,14 @@ where
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
 
+    async fn list_customers_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
+
     async fn insert_customer(
         &self,
         customer_data: domain::Customer,
@@ -85,7 +93,7 @@ impl CustomerInterface for Store {
             .map_err(Into::into)
             .into_report()?
             .async_map(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -122,7 +130,7 @@ impl CustomerInterface for Store {
         .map_err(Into::into)
         .into_report()
         .async_and_then(|c| async {
-            c.convert(key_store.key.get_inner())
+            c.convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         })
@@ -142,7 +150,7 @@ impl CustomerInterface for Store {
                 .map_err(Into::into)
                 .into_report()
                 .async_and_then(|c| async {
-                    c.convert(key_store.key.get_inner())
+                    c.convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -170,7 +178,7 @@ impl CustomerInterface for Store {
         let customers = try_join_all(encrypted_customers.into_iter().map(
             |encrypted_customer| async {
                 encrypted_customer
-                    .convert(key_store.key.get_inner())
+                    .convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             },
@@ -180,6 +188,34 @@ impl CustomerInterface for Store {
         Ok(customers)
     }
 
+    async fn list_customers_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+
+        let encrypted_customers =
+            storage::Customer::list_by_merchant_id_after_id(&conn, merchant_id, last_id, limit)
+                .await
+                .map_err(Into::into)
+                .into_report()?;
+
+        try_join_all(
+            encrypted_customers
+                .into_iter()
+                .map(|encrypted_customer| async {
+                    encrypted_customer
+                        .convert(&key_store.key_ring())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                }),
+        )
+        .await
+    }
+
     async fn insert_customer(
         &self,
         customer_data: domain::Customer,
@@ -195,7 +231,7 @@ impl CustomerInterface for Store {
             .map_err(Into::into)
             .into_report()
             .async_and_then(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -233,7 +269,7 @@ impl CustomerInterface for MockDb {
             .cloned();
         customer
             .async_map(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -255,7 +291,7 @@ impl CustomerInterface for MockDb {
                 .map(|customer| async {
                     customer
                         .to_owned()
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 }),
@@ -265,6 +301,37 @@ impl CustomerInterface for MockDb {
         Ok(customers)
     }
 
+    async fn list_customers_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let limit: usize = limit
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let mut customers = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == merchant_id && customer.id > last_id.unwrap_or_default()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        customers.sort_by_key(|customer| customer.id);
+
+        try_join_all(customers.into_iter().take(limit).map(|customer| async {
+            customer
+                .convert(&key_store.key_ring())
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }))
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn update_customer_by_customer_id_merchant_id(
         &self,
@@ -302,7 +369,7 @@ impl CustomerInterface for MockDb {
         customers.push(customer.clone());
 
         customer
-            .convert(key_store.key.get_inner())
+            .convert(&key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
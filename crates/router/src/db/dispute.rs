@@ -173,6 +173,7 @@ impl DisputeInterface for MockDb {
             profile_id: dispute.profile_id,
             evidence,
             merchant_connector_id: dispute.merchant_connector_id,
+            is_deadline_missed: false,
         };
 
         locked_disputes.push(new_dispute.clone());
@@ -354,6 +355,9 @@ impl DisputeInterface for MockDb {
             storage::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
+            storage::DisputeUpdate::DeadlineMissedUpdate { is_deadline_missed } => {
+                dispute_to_update.is_deadline_missed = is_deadline_missed;
+            }
         }
 
         dispute_to_update.modified_at = now;
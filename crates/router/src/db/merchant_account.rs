@@ -83,7 +83,7 @@ impl MerchantAccountInterface for Store {
             .await
             .map_err(Into::into)
             .into_report()?
-            .convert(merchant_key_store.key.get_inner())
+            .convert(&merchant_key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -105,7 +105,7 @@ impl MerchantAccountInterface for Store {
         {
             fetch_func()
                 .await?
-                .convert(merchant_key_store.key.get_inner())
+                .convert(&merchant_key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -114,7 +114,7 @@ impl MerchantAccountInterface for Store {
         {
             super::cache::get_or_populate_in_memory(self, merchant_id, fetch_func, &ACCOUNTS_CACHE)
                 .await?
-                .convert(merchant_key_store.key.get_inner())
+                .convert(&merchant_key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -141,7 +141,7 @@ impl MerchantAccountInterface for Store {
             publish_and_redact_merchant_account_cache(self, &updated_merchant_account).await?;
         }
         updated_merchant_account
-            .convert(merchant_key_store.key.get_inner())
+            .convert(&merchant_key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -167,7 +167,7 @@ impl MerchantAccountInterface for Store {
             publish_and_redact_merchant_account_cache(self, &updated_merchant_account).await?;
         }
         updated_merchant_account
-            .convert(merchant_key_store.key.get_inner())
+            .convert(&merchant_key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -210,7 +210,7 @@ impl MerchantAccountInterface for Store {
 
         Ok(authentication::AuthenticationData {
             merchant_account: merchant_account
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError)?,
 
@@ -249,7 +249,7 @@ impl MerchantAccountInterface for Store {
                 .zip(merchant_key_stores.iter())
                 .map(|(merchant_account, key_store)| async {
                     merchant_account
-                        .convert(key_store.key.get_inner())
+                        .convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 }),
@@ -318,7 +318,7 @@ impl MerchantAccountInterface for MockDb {
         accounts.push(account.clone());
 
         account
-            .convert(merchant_key_store.key.get_inner())
+            .convert(&merchant_key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -335,7 +335,7 @@ impl MerchantAccountInterface for MockDb {
             .find(|account| account.merchant_id == merchant_id)
             .cloned()
             .async_map(|a| async {
-                a.convert(merchant_key_store.key.get_inner())
+                a.convert(&merchant_key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -149,6 +149,14 @@ where
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError>;
 
+    async fn list_merchant_connector_accounts_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError>;
+
     async fn update_merchant_connector_account(
         &self,
         this: domain::MerchantConnectorAccount,
@@ -187,7 +195,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DeserializationFailed)
         }
@@ -202,7 +210,7 @@ impl MerchantConnectorAccountInterface for Store {
             )
             .await
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -232,7 +240,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DeserializationFailed)
         }
@@ -247,7 +255,7 @@ impl MerchantConnectorAccountInterface for Store {
             )
             .await
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -274,7 +282,7 @@ impl MerchantConnectorAccountInterface for Store {
             let mut output = Vec::with_capacity(items.len());
             for item in items.into_iter() {
                 output.push(
-                    item.convert(key_store.key.get_inner())
+                    item.convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)?,
                 )
@@ -306,7 +314,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -320,7 +328,7 @@ impl MerchantConnectorAccountInterface for Store {
                 &cache::ACCOUNTS_CACHE,
             )
             .await?
-            .convert(key_store.key.get_inner())
+            .convert(&key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
         }
@@ -340,7 +348,7 @@ impl MerchantConnectorAccountInterface for Store {
             .map_err(Into::into)
             .into_report()
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -362,7 +370,7 @@ impl MerchantConnectorAccountInterface for Store {
                 let mut output = Vec::with_capacity(items.len());
                 for item in items.into_iter() {
                     output.push(
-                        item.convert(key_store.key.get_inner())
+                        item.convert(&key_store.key_ring())
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -372,6 +380,37 @@ impl MerchantConnectorAccountInterface for Store {
             .await
     }
 
+    async fn list_merchant_connector_accounts_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantConnectorAccount::list_by_merchant_id_after_id(
+            &conn,
+            merchant_id,
+            last_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+        .async_and_then(|items| async {
+            let mut output = Vec::with_capacity(items.len());
+            for item in items.into_iter() {
+                output.push(
+                    item.convert(&key_store.key_ring())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                )
+            }
+            Ok(output)
+        })
+        .await
+    }
+
     async fn update_merchant_connector_account(
         &self,
         this: domain::MerchantConnectorAccount,
@@ -399,7 +438,7 @@ impl MerchantConnectorAccountInterface for Store {
                 .map_err(Into::into)
                 .into_report()
                 .async_and_then(|item| async {
-                    item.convert(key_store.key.get_inner())
+                    item.convert(&key_store.key_ring())
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -502,7 +541,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .cloned()
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -538,7 +577,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         for account in accounts.into_iter() {
             output.push(
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)?,
             )
@@ -566,7 +605,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         match maybe_mca {
             Some(mca) => mca
                 .to_owned()
-                .convert(key_store.key.get_inner())
+                .convert(&key_store.key_ring())
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -594,7 +633,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .cloned()
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -643,10 +682,11 @@ impl MerchantConnectorAccountInterface for MockDb {
             profile_id: t.profile_id,
             applepay_verified_domains: t.applepay_verified_domains,
             pm_auth_config: t.pm_auth_config,
+            pm_filters: t.pm_filters,
         };
         accounts.push(account.clone());
         account
-            .convert(key_store.key.get_inner())
+            .convert(&key_store.key_ring())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -676,7 +716,42 @@ impl MerchantConnectorAccountInterface for MockDb {
         for account in accounts.into_iter() {
             output.push(
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(&key_store.key_ring())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
+
+    async fn list_merchant_connector_accounts_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError> {
+        let limit: usize = limit
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let mut accounts = self
+            .merchant_connector_accounts
+            .lock()
+            .await
+            .iter()
+            .filter(|account| {
+                account.merchant_id == merchant_id && account.id > last_id.unwrap_or_default()
+            })
+            .cloned()
+            .collect::<Vec<storage::MerchantConnectorAccount>>();
+        accounts.sort_by_key(|account| account.id);
+        accounts.truncate(limit);
+
+        let mut output = Vec::with_capacity(accounts.len());
+        for account in accounts.into_iter() {
+            output.push(
+                account
+                    .convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)?,
             )
@@ -704,7 +779,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             })
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(&key_store.key_ring())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -750,7 +825,6 @@ mod merchant_connector_account_cache_tests {
     use common_utils::date_time;
     use diesel_models::enums::ConnectorType;
     use error_stack::ResultExt;
-    use masking::PeekInterface;
     use storage_impl::redis::{
         cache::{CacheKind, ACCOUNTS_CACHE},
         kv_store::RedisConnInterface,
@@ -801,6 +875,9 @@ mod merchant_connector_account_cache_tests {
                 .await
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                key_version: domain::types::INITIAL_KEY_VERSION,
+                previous_key: None,
+                previous_key_version: None,
             },
             &master_key.to_vec().into(),
         )
@@ -818,7 +895,7 @@ mod merchant_connector_account_cache_tests {
             connector_name: "stripe".to_string(),
             connector_account_details: domain::types::encrypt(
                 serde_json::Value::default().into(),
-                merchant_key.key.get_inner().peek(),
+                &merchant_key.key_ring(),
             )
             .await
             .unwrap(),
@@ -839,6 +916,7 @@ mod merchant_connector_account_cache_tests {
             profile_id: Some(profile_id.to_string()),
             applepay_verified_domains: None,
             pm_auth_config: None,
+            pm_filters: None,
         };
 
         db.insert_merchant_connector_account(mca.clone(), &merchant_key)
@@ -28,6 +28,13 @@ pub trait MerchantKeyStoreInterface {
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
 
+    async fn update_merchant_key_store(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: diesel_models::merchant_key_store::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -97,6 +104,44 @@ impl MerchantKeyStoreInterface for Store {
         }
     }
 
+    async fn update_merchant_key_store(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: diesel_models::merchant_key_store::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let update_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            diesel_models::merchant_key_store::MerchantKeyStore::update_by_merchant_id(
+                &conn,
+                merchant_id,
+                merchant_key_store_update,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        let updated_key_store = update_func().await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let updated_key_store = {
+            let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(key_store_cache_key.into()),
+                update_func,
+            )
+            .await?
+        };
+
+        updated_key_store
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -179,6 +224,33 @@ impl MerchantKeyStoreInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn update_merchant_key_store(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: diesel_models::merchant_key_store::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let mut merchant_key_stores = self.merchant_key_store.lock().await;
+        let merchant_key_store = merchant_key_stores
+            .iter_mut()
+            .find(|mks| mks.merchant_id == merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No merchant key store found for merchant_id = {}",
+                merchant_id
+            )))?;
+        *merchant_key_store =
+            diesel_models::merchant_key_store::MerchantKeyStoreUpdateInternal::from(
+                merchant_key_store_update,
+            )
+            .apply_changeset(merchant_key_store.clone());
+
+        merchant_key_store
+            .clone()
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -227,6 +299,9 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: domain::types::INITIAL_KEY_VERSION,
+                    previous_key: None,
+                    previous_key_version: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -252,6 +327,9 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: domain::types::INITIAL_KEY_VERSION,
+                    previous_key: None,
+                    previous_key_version: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -1,4 +1,4 @@
-use diesel_models::payment_method::PaymentMethodUpdateInternal;
+use diesel_models::{errors::DatabaseError, payment_method::PaymentMethodUpdateInternal};
 use error_stack::{IntoReport, ResultExt};
 
 use super::{MockDb, Store};
@@ -21,6 +21,27 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
 
+    async fn find_payment_method_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
+    async fn find_payment_method_by_merchant_id_paginated(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         payment_method_new: storage::PaymentMethodNew,
@@ -89,6 +110,50 @@ impl PaymentMethodInterface for Store {
             .into_report()
     }
 
+    async fn find_payment_method_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_by_merchant_id_after_id(&conn, merchant_id, last_id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_method_by_merchant_id_paginated(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_by_merchant_id_paginated(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_by_customer_id_merchant_id_card_fingerprint(
+            &conn,
+            customer_id,
+            merchant_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -160,6 +225,11 @@ impl PaymentMethodInterface for MockDb {
             payment_method_issuer_code: payment_method_new.payment_method_issuer_code,
             metadata: payment_method_new.metadata,
             payment_method_data: payment_method_new.payment_method_data,
+            card_fingerprint: payment_method_new.card_fingerprint,
+            network_token_reference: payment_method_new.network_token_reference,
+            network_token_data: payment_method_new.network_token_data,
+            locker_id: payment_method_new.locker_id,
+            last_used_at: payment_method_new.last_used_at,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
@@ -187,6 +257,75 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_payment_method_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        last_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let limit: usize = limit
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let mut payment_methods = self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|pm| pm.merchant_id == merchant_id && pm.id > last_id.unwrap_or_default())
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_methods.sort_by_key(|pm| pm.id);
+        payment_methods.truncate(limit);
+
+        Ok(payment_methods)
+    }
+
+    async fn find_payment_method_by_merchant_id_paginated(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let offset: usize = offset
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let limit: usize = limit
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+
+        Ok(self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|pm| pm.merchant_id == merchant_id)
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        self.payment_methods
+            .lock()
+            .await
+            .iter()
+            .find(|pm| {
+                pm.customer_id == customer_id
+                    && pm.merchant_id == merchant_id
+                    && pm.card_fingerprint.as_deref() == Some(card_fingerprint)
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -0,0 +1,200 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription_plan(
+        &self,
+        plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &str,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError>;
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    async fn insert_subscription_plan(
+        &self,
+        plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        plan.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &str,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::find_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Subscription::update_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            subscription,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription_plan(
+        &self,
+        _plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &str,
+        _plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription(
+        &self,
+        _subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &str,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscriptions_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &str,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &str,
+        _subscription_id: &str,
+        _subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -18,6 +18,7 @@ use crate::{
     services::{authentication::AuthenticationType, ApplicationResponse, PaymentLinkFormData},
     types::api::{
         AttachEvidenceRequest, Config, ConfigUpdate, CreateFileRequest, DisputeId, FileId,
+        MultipartPayload,
     },
 };
 
@@ -103,6 +104,7 @@ impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
 impl_misc_api_event_type!(
     Config,
     CreateFileRequest,
+    MultipartPayload,
     FileId,
     AttachEvidenceRequest,
     DisputeId,
@@ -125,6 +125,7 @@ pub fn mk_app(
             .service(routes::Refunds::server(state.clone()))
             .service(routes::MerchantConnectorAccount::server(state.clone()))
             .service(routes::Mandates::server(state.clone()))
+            .service(routes::Subscriptions::server(state.clone()))
     }
 
     #[cfg(feature = "oltp")]
@@ -149,7 +150,7 @@ pub fn mk_app(
             .service(routes::User::server(state.clone()))
     }
 
-    #[cfg(all(feature = "olap", feature = "kms"))]
+    #[cfg(all(feature = "olap", feature = "encryption_service"))]
     {
         server_app = server_app.service(routes::Verify::server(state.clone()));
     }
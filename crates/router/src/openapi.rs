@@ -56,6 +56,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
         (name = "Mandates", description = "Manage mandates"),
+        (name = "Subscriptions", description = "Manage subscription plans and subscriptions"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "Disputes", description = "Manage disputes"),
@@ -80,6 +81,13 @@ Never share your secret api keys. Keep them guarded and secure.
         // crate::routes::admin::payment_connector_delete,
         crate::routes::mandates::get_mandate,
         crate::routes::mandates::revoke_mandate,
+        crate::routes::subscriptions::create_subscription_plan,
+        crate::routes::subscriptions::retrieve_subscription_plan,
+        crate::routes::subscriptions::list_subscription_plans,
+        crate::routes::subscriptions::create_subscription,
+        crate::routes::subscriptions::retrieve_subscription,
+        crate::routes::subscriptions::update_subscription,
+        crate::routes::subscriptions::cancel_subscription,
         crate::routes::payments::payments_create,
     // crate::routes::payments::payments_start,
         crate::routes::payments::payments_retrieve,
@@ -97,6 +105,8 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payment_methods::payment_method_retrieve_api,
         crate::routes::payment_methods::payment_method_update_api,
         crate::routes::payment_methods::payment_method_delete_api,
+        crate::routes::payment_methods::customer_payment_method_update_api,
+        crate::routes::payment_methods::default_payment_method_set_api,
         crate::routes::customers::customers_create,
         crate::routes::customers::customers_retrieve,
         crate::routes::customers::customers_update,
@@ -137,6 +147,10 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::payment_methods::CustomerPaymentMethodsListResponse,
         crate::types::api::payment_methods::PaymentMethodDeleteResponse,
         crate::types::api::payment_methods::PaymentMethodUpdate,
+        crate::types::api::payment_methods::CustomerPaymentMethodUpdate,
+        crate::types::api::payment_methods::CustomerDefaultPaymentMethodResponse,
+        crate::types::api::payment_methods::PaymentMethodEligibility,
+        crate::types::api::payment_methods::PaymentMethodIneligibilityReason,
         crate::types::api::payment_methods::CardDetailFromLocker,
         crate::types::api::payment_methods::CardDetail,
         api_models::customers::CustomerResponse,
@@ -157,6 +171,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::SubscriptionInterval,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::CardNetwork,
@@ -176,11 +192,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::FrmConfigs,
         api_models::admin::FrmPaymentMethod,
         api_models::admin::FrmPaymentMethodType,
+        api_models::admin::PaymentMethodFilter,
         api_models::admin::PaymentMethodsEnabled,
         api_models::admin::MerchantConnectorDetailsWrap,
         api_models::admin::MerchantConnectorDetails,
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::PaymentLinkConfig,
+        api_models::admin::PaymentLinkSupportContact,
         api_models::admin::PaymentLinkColorSchema,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
@@ -309,6 +327,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::subscriptions::SubscriptionPlanCreateRequest,
+        api_models::subscriptions::SubscriptionPlanResponse,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionUpdateRequest,
+        api_models::subscriptions::SubscriptionCancelRequest,
+        api_models::subscriptions::SubscriptionResponse,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
         api_models::payments::CustomerDetails,
         api_models::payments::GiftCardData,
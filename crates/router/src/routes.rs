@@ -23,9 +23,10 @@ pub mod payouts;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+pub mod subscriptions;
 #[cfg(feature = "olap")]
 pub mod user;
-#[cfg(all(feature = "olap", feature = "kms"))]
+#[cfg(all(feature = "olap", feature = "encryption_service"))]
 pub mod verification;
 pub mod webhooks;
 
@@ -35,12 +36,12 @@ pub use self::app::DummyConnector;
 pub use self::app::Payouts;
 #[cfg(feature = "olap")]
 pub use self::app::Routing;
-#[cfg(all(feature = "olap", feature = "kms"))]
+#[cfg(all(feature = "olap", feature = "encryption_service"))]
 pub use self::app::Verify;
 pub use self::app::{
     ApiKeys, AppState, BusinessProfile, Cache, Cards, Configs, Customers, Disputes, EphemeralKey,
     Files, Gsm, Health, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink,
-    PaymentMethods, Payments, Refunds, User, Webhooks,
+    PaymentMethods, Payments, Refunds, Subscriptions, User, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -585,3 +585,27 @@ pub async fn merchant_account_kv_status(
     )
     .await
 }
+
+/// Merchant Account - Rotate Key
+///
+/// Rotate the key used for encrypting the data of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotate))]
+pub async fn merchant_account_rotate_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotate;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id| rotate_merchant_key(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
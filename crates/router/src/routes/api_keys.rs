@@ -1,4 +1,6 @@
 use actix_web::{web, HttpRequest, Responder};
+#[cfg(feature = "encryption_service")]
+use error_stack::ResultExt;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
@@ -42,12 +44,17 @@ pub async fn api_key_create(
         &req,
         payload,
         |state, _, payload| async {
-            #[cfg(feature = "kms")]
-            let kms_client = external_services::kms::get_kms_client(&state.clone().conf.kms).await;
+            #[cfg(feature = "encryption_service")]
+            let secret_management_client =
+                external_services::encryption_management::get_encryption_management_client(
+                    &state.clone().conf.encryption_management,
+                )
+                .await
+                .change_context(crate::core::errors::ApiErrorResponse::InternalServerError)?;
             api_keys::create_api_key(
                 state,
-                #[cfg(feature = "kms")]
-                kms_client,
+                #[cfg(feature = "encryption_service")]
+                secret_management_client,
                 payload,
                 merchant_id.clone(),
             )
@@ -2,9 +2,13 @@ use std::sync::Arc;
 
 use actix_web::{web, Scope};
 #[cfg(feature = "email")]
-use external_services::email::{AwsSes, EmailClient};
-#[cfg(feature = "kms")]
-use external_services::kms::{self, decrypt::KmsDecrypt};
+use external_services::email::EmailClient;
+use external_services::{
+    account_updater::AccountUpdaterProvider, file_storage::FileStorageInterface,
+    network_tokenization::NetworkTokenProvider,
+};
+#[cfg(feature = "encryption_service")]
+use external_services::{encryption_management, secrets_management::decrypt::KmsDecrypt};
 use router_env::tracing_actix_web::RequestId;
 use scheduler::SchedulerInterface;
 use storage_impl::MockDb;
@@ -16,13 +20,13 @@ use super::dummy_connector::*;
 use super::payouts::*;
 #[cfg(feature = "olap")]
 use super::routing as cloud_routing;
-#[cfg(all(feature = "olap", feature = "kms"))]
+#[cfg(all(feature = "olap", feature = "encryption_service"))]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
 use super::{admin::*, api_keys::*, disputes::*, files::*, gsm::*, user::*};
 use super::{cache::*, health::*, payment_link::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
-use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
+use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*, subscriptions::*};
 #[cfg(feature = "oltp")]
 use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
 use crate::{
@@ -41,11 +45,14 @@ pub struct AppState {
     pub event_handler: Box<dyn EventHandler>,
     #[cfg(feature = "email")]
     pub email_client: Arc<dyn EmailClient>,
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     pub kms_secrets: Arc<settings::ActiveKmsSecrets>,
     pub api_client: Box<dyn crate::services::ApiClient>,
     #[cfg(feature = "olap")]
     pub pool: crate::analytics::AnalyticsProvider,
+    pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub network_token_provider: Option<Arc<dyn NetworkTokenProvider>>,
+    pub account_updater_provider: Option<Arc<dyn AccountUpdaterProvider>>,
 }
 
 impl scheduler::SchedulerAppState for AppState {
@@ -113,8 +120,13 @@ impl AppState {
         api_client: Box<dyn crate::services::ApiClient>,
     ) -> Self {
         Box::pin(async move {
-            #[cfg(feature = "kms")]
-            let kms_client = kms::get_kms_client(&conf.kms).await;
+            #[cfg(feature = "encryption_service")]
+            #[allow(clippy::expect_used)]
+            let secret_management_client = encryption_management::get_encryption_management_client(
+                &conf.encryption_management,
+            )
+            .await
+            .expect("Failed to initialize encryption management client");
             let testable = storage_impl == StorageImpl::PostgresqlTest;
             let store: Box<dyn StorageInterface> = match storage_impl {
                 StorageImpl::Postgresql | StorageImpl::PostgresqlTest => Box::new(
@@ -134,34 +146,53 @@ impl AppState {
             #[cfg(feature = "olap")]
             let pool = crate::analytics::AnalyticsProvider::from_conf(
                 &conf.analytics,
-                #[cfg(feature = "kms")]
-                kms_client,
+                #[cfg(feature = "encryption_service")]
+                secret_management_client,
             )
             .await;
 
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "encryption_service")]
             #[allow(clippy::expect_used)]
             let kms_secrets = settings::ActiveKmsSecrets {
                 jwekey: conf.jwekey.clone().into(),
             }
-            .decrypt_inner(kms_client)
+            .decrypt_inner(secret_management_client)
             .await
             .expect("Failed while performing KMS decryption");
 
             #[cfg(feature = "email")]
-            let email_client = Arc::new(AwsSes::new(&conf.email).await);
+            #[allow(clippy::expect_used)]
+            let email_client = conf
+                .email
+                .get_email_client()
+                .await
+                .expect("Failed to create email client");
+
+            let file_storage_client = conf.file_storage.get_file_storage_client().await;
+            let network_token_provider = conf
+                .network_tokenization
+                .provider
+                .get_network_token_provider();
+            let account_updater_provider = conf
+                .card_account_updater
+                .provider
+                .get_account_updater_provider();
+
             Self {
                 flow_name: String::from("default"),
                 store,
                 conf: Arc::new(conf),
                 #[cfg(feature = "email")]
                 email_client,
-                #[cfg(feature = "kms")]
+                #[cfg(feature = "encryption_service")]
                 kms_secrets: Arc::new(kms_secrets),
                 api_client,
                 event_handler: Box::<EventLogger>::default(),
                 #[cfg(feature = "olap")]
                 pool,
+                file_storage_client,
+                network_token_provider,
+                account_updater_provider,
             }
         })
         .await
@@ -333,6 +364,10 @@ impl Routing {
                 web::resource("/{algorithm_id}/activate")
                     .route(web::post().to(cloud_routing::routing_link_config)),
             )
+            .service(
+                web::resource("/{algorithm_id}/test")
+                    .route(web::post().to(cloud_routing::routing_run_tests)),
+            )
             .service(
                 web::resource("/default/profile/{profile_id}").route(
                     web::post().to(cloud_routing::routing_update_default_config_for_profile),
@@ -375,6 +410,14 @@ impl Customers {
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
                 )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/{payment_method_id}")
+                        .route(web::post().to(customer_payment_method_update_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
+                        .route(web::post().to(default_payment_method_set_api)),
+                )
                 .service(
                     web::resource("/{customer_id}")
                         .route(web::get().to(customers_retrieve))
@@ -469,6 +512,10 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/keys/rotate")
+                    .route(web::post().to(merchant_account_rotate_key)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -546,6 +593,34 @@ impl Mandates {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/plans")
+                    .route(web::post().to(create_subscription_plan))
+                    .route(web::get().to(list_subscription_plans)),
+            )
+            .service(
+                web::resource("/plans/{plan_id}").route(web::get().to(retrieve_subscription_plan)),
+            )
+            .service(web::resource("").route(web::post().to(create_subscription)))
+            .service(
+                web::resource("/{subscription_id}")
+                    .route(web::get().to(retrieve_subscription))
+                    .route(web::post().to(update_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(cancel_subscription)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(feature = "oltp")]
@@ -711,10 +786,10 @@ impl Gsm {
     }
 }
 
-#[cfg(all(feature = "olap", feature = "kms"))]
+#[cfg(all(feature = "olap", feature = "encryption_service"))]
 pub struct Verify;
 
-#[cfg(all(feature = "olap", feature = "kms"))]
+#[cfg(all(feature = "olap", feature = "encryption_service"))]
 impl Verify {
     pub fn server(state: AppState) -> Scope {
         web::scope("/verify")
@@ -741,5 +816,6 @@ impl User {
             .service(web::resource("/signup").route(web::post().to(user_connect_account)))
             .service(web::resource("/v2/signin").route(web::post().to(user_connect_account)))
             .service(web::resource("/v2/signup").route(web::post().to(user_connect_account)))
+            .service(web::resource("/verify_email").route(web::post().to(user_verify_email)))
     }
 }
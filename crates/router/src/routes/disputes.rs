@@ -10,7 +10,7 @@ use super::app::AppState;
 use crate::{
     core::disputes,
     services::{api, authentication as auth},
-    types::api::disputes as dispute_types,
+    types::api::{disputes as dispute_types, files},
 };
 
 /// Disputes - Retrieve Dispute
@@ -185,19 +185,26 @@ pub async fn attach_dispute_evidence(
     payload: Multipart,
 ) -> HttpResponse {
     let flow = Flow::AttachDisputeEvidence;
-    //Get attach_evidence_request from the multipart request
-    let attach_evidence_request_result = utils::get_attach_evidence_request(payload).await;
-    let attach_evidence_request = match attach_evidence_request_result {
-        Ok(valid_request) => valid_request,
-        Err(err) => return api::log_and_return_error_response(err),
-    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        attach_evidence_request,
-        |state, auth, req| {
-            disputes::attach_evidence(state, auth.merchant_account, auth.key_store, req)
+        files::MultipartPayload(payload),
+        |state, auth, payload| async move {
+            //Get attach_evidence_request from the multipart request
+            let attach_evidence_request = utils::get_attach_evidence_request(
+                &state,
+                &auth.merchant_account.merchant_id,
+                payload.0,
+            )
+            .await?;
+            disputes::attach_evidence(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                attach_evidence_request,
+            )
+            .await
         },
         auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
         api_locking::LockAction::NotApplicable,
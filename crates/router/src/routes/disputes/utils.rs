@@ -1,11 +1,12 @@
 use actix_multipart::{Field, Multipart};
-use actix_web::web::Bytes;
-use common_utils::{errors::CustomResult, ext_traits::StringExt, fp_utils};
-use error_stack::{IntoReport, ResultExt};
-use futures::{StreamExt, TryStreamExt};
+use common_utils::{errors::CustomResult, ext_traits::StringExt};
+use error_stack::ResultExt;
+use futures::TryStreamExt;
 
 use crate::{
+    consts,
     core::{errors, files::helpers},
+    routes::AppState,
     types::api::{disputes, files},
     utils::OptionExt,
 };
@@ -25,14 +26,18 @@ pub async fn parse_evidence_type(
     }
 }
 
+/// Parses the multipart attach evidence request, streaming the file content to the file storage.
 pub async fn get_attach_evidence_request(
+    state: &AppState,
+    merchant_id: &str,
     mut payload: Multipart,
 ) -> CustomResult<disputes::AttachEvidenceRequest, errors::ApiErrorResponse> {
     let mut option_evidence_type: Option<disputes::EvidenceType> = None;
     let mut dispute_id: Option<String> = None;
 
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
     let mut file_name: Option<String> = None;
-    let mut file_content: Option<Vec<Bytes>> = None;
+    let mut file_details: Option<helpers::UploadedFileDetails> = None;
 
     while let Ok(Some(mut field)) = payload.try_next().await {
         let content_disposition = field.content_disposition();
@@ -41,18 +46,16 @@ pub async fn get_attach_evidence_request(
         match field_name {
             Some("file") => {
                 file_name = content_disposition.get_filename().map(String::from);
-                //Collect the file content and throw error if something fails
-                let mut file_data = Vec::new();
-                let mut stream = field.into_stream();
-                while let Some(chunk) = stream.next().await {
-                    match chunk {
-                        Ok(bytes) => file_data.push(bytes),
-                        Err(err) => Err(errors::ApiErrorResponse::InternalServerError)
-                            .into_report()
-                            .attach_printable_lazy(|| format!("File parsing error: {err}"))?,
-                    }
-                }
-                file_content = Some(file_data)
+                //Stream the file content to the file storage and throw error if something fails
+                file_details = Some(
+                    helpers::upload_file_content(
+                        state,
+                        helpers::get_file_key(merchant_id, &file_id),
+                        field,
+                        state.conf.file_upload_config.max_file_size,
+                    )
+                    .await?,
+                )
             }
             Some("dispute_id") => {
                 dispute_id = helpers::read_string(&mut field).await;
@@ -64,36 +67,26 @@ pub async fn get_attach_evidence_request(
             _ => (),
         }
     }
-    let evidence_type = option_evidence_type.get_required_value("evidence_type")?;
-    let file = file_content.get_required_value("file")?.concat().to_vec();
-    //Get and validate file size
-    let file_size: i32 = file
-        .len()
-        .try_into()
-        .into_report()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("File size error")?;
-    // Check if empty file and throw error
-    fp_utils::when(file_size <= 0, || {
-        Err(errors::ApiErrorResponse::MissingFile)
-            .into_report()
-            .attach_printable("Missing / Invalid file in the request")
-    })?;
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
-        .parse::<mime::Mime>()
-        .into_report()
-        .change_context(errors::ApiErrorResponse::MissingFileContentType)
-        .attach_printable("File content type error")?;
+    let evidence_type = match (
+        option_evidence_type.get_required_value("evidence_type"),
+        &file_details,
+    ) {
+        (Ok(evidence_type), _) => evidence_type,
+        (Err(error), None) => return Err(error),
+        (Err(error), Some(_)) => {
+            helpers::delete_file(state, helpers::get_file_key(merchant_id, &file_id)).await?;
+            return Err(error);
+        }
+    };
+    let file_details = file_details.get_required_value("file")?;
     let create_file_request = files::CreateFileRequest {
-        file,
+        file_id,
         file_name,
-        file_size,
-        file_type,
+        file_size: file_details.file_size,
+        file_type: file_details.file_type,
         purpose: files::FilePurpose::DisputeEvidence,
         dispute_id,
+        checksum: file_details.checksum,
     };
     Ok(disputes::AttachEvidenceRequest {
         evidence_type,
@@ -34,17 +34,26 @@ pub async fn files_create(
     payload: Multipart,
 ) -> HttpResponse {
     let flow = Flow::CreateFile;
-    let create_file_request_result = transformers::get_create_file_request(payload).await;
-    let create_file_request = match create_file_request_result {
-        Ok(valid_request) => valid_request,
-        Err(err) => return api::log_and_return_error_response(err),
-    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        create_file_request,
-        |state, auth, req| files_create_core(state, auth.merchant_account, auth.key_store, req),
+        files::MultipartPayload(payload),
+        |state, auth, payload| async move {
+            let create_file_request = transformers::get_create_file_request(
+                &state,
+                &auth.merchant_account.merchant_id,
+                payload.0,
+            )
+            .await?;
+            files_create_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                create_file_request,
+            )
+            .await
+        },
         auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
         api_locking::LockAction::NotApplicable,
     ))
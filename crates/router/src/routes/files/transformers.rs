@@ -1,23 +1,28 @@
 use actix_multipart::Multipart;
-use actix_web::web::Bytes;
 use common_utils::errors::CustomResult;
-use error_stack::{IntoReport, ResultExt};
-use futures::{StreamExt, TryStreamExt};
+use error_stack::IntoReport;
+use futures::TryStreamExt;
 
 use crate::{
+    consts,
     core::{errors, files::helpers},
+    routes::AppState,
     types::api::files::{self, CreateFileRequest},
     utils::OptionExt,
 };
 
+/// Parses the multipart file upload request, streaming the file content to the file storage.
 pub async fn get_create_file_request(
+    state: &AppState,
+    merchant_id: &str,
     mut payload: Multipart,
 ) -> CustomResult<CreateFileRequest, errors::ApiErrorResponse> {
     let mut option_purpose: Option<files::FilePurpose> = None;
     let mut dispute_id: Option<String> = None;
 
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
     let mut file_name: Option<String> = None;
-    let mut file_content: Option<Vec<Bytes>> = None;
+    let mut file_details: Option<helpers::UploadedFileDetails> = None;
 
     while let Ok(Some(mut field)) = payload.try_next().await {
         let content_disposition = field.content_disposition();
@@ -30,18 +35,16 @@ pub async fn get_create_file_request(
             Some("file") => {
                 file_name = content_disposition.get_filename().map(String::from);
 
-                //Collect the file content and throw error if something fails
-                let mut file_data = Vec::new();
-                let mut stream = field.into_stream();
-                while let Some(chunk) = stream.next().await {
-                    match chunk {
-                        Ok(bytes) => file_data.push(bytes),
-                        Err(err) => Err(errors::ApiErrorResponse::InternalServerError)
-                            .into_report()
-                            .attach_printable(format!("{}{}", "File parsing error: ", err))?,
-                    }
-                }
-                file_content = Some(file_data)
+                //Stream the file content to the file storage and throw error if something fails
+                file_details = Some(
+                    helpers::upload_file_content(
+                        state,
+                        helpers::get_file_key(merchant_id, &file_id),
+                        field,
+                        state.conf.file_upload_config.max_file_size,
+                    )
+                    .await?,
+                )
             }
             Some("dispute_id") => {
                 dispute_id = helpers::read_string(&mut field).await;
@@ -50,40 +53,27 @@ pub async fn get_create_file_request(
             _ => (),
         }
     }
-    let purpose = option_purpose.get_required_value("purpose")?;
-    let file = match file_content {
-        Some(valid_file_content) => valid_file_content.concat().to_vec(),
+    let purpose = match (option_purpose.get_required_value("purpose"), &file_details) {
+        (Ok(purpose), _) => purpose,
+        (Err(error), None) => return Err(error),
+        (Err(error), Some(_)) => {
+            helpers::delete_file(state, helpers::get_file_key(merchant_id, &file_id)).await?;
+            return Err(error);
+        }
+    };
+    let file_details = match file_details {
+        Some(file_details) => file_details,
         None => Err(errors::ApiErrorResponse::MissingFile)
             .into_report()
             .attach_printable("Missing / Invalid file in the request")?,
     };
-    //Get and validate file size
-    let file_size: i32 = file
-        .len()
-        .try_into()
-        .into_report()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("File size error")?;
-    // Check if empty file and throw error
-    if file_size <= 0 {
-        Err(errors::ApiErrorResponse::MissingFile)
-            .into_report()
-            .attach_printable("Missing / Invalid file in the request")?
-    }
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
-        .parse::<mime::Mime>()
-        .into_report()
-        .change_context(errors::ApiErrorResponse::MissingFileContentType)
-        .attach_printable("File content type error")?;
     Ok(CreateFileRequest {
-        file,
+        file_id,
         file_name,
-        file_size,
-        file_type,
+        file_size: file_details.file_size,
+        file_type: file_details.file_type,
         purpose,
         dispute_id,
+        checksum: file_details.checksum,
     })
 }
@@ -25,6 +25,7 @@ pub enum ApiIdentifier {
     Routing,
     Gsm,
     User,
+    Subscriptions,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -34,10 +35,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountRetrieve
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
+            | Flow::MerchantKeyRotate
             | Flow::MerchantAccountList => Self::MerchantAccount,
 
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
+            | Flow::RoutingRunTests
             | Flow::RoutingUnlinkConfig
             | Flow::RoutingRetrieveConfig
             | Flow::RoutingRetrieveActiveConfig
@@ -75,6 +78,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
             | Flow::PaymentMethodsDelete
+            | Flow::CustomerPaymentMethodsUpdate
+            | Flow::CustomerPaymentMethodsSetDefault
             | Flow::ValidatePaymentMethod => Self::PaymentMethods,
 
             Flow::PaymentsCreate
@@ -136,7 +141,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GsmRuleUpdate
             | Flow::GsmRuleDelete => Self::Gsm,
 
-            Flow::UserConnectAccount => Self::User,
+            Flow::UserConnectAccount | Flow::UserVerifyEmail => Self::User,
+
+            Flow::SubscriptionPlanCreate
+            | Flow::SubscriptionPlanRetrieve
+            | Flow::SubscriptionPlanList
+            | Flow::SubscriptionCreate
+            | Flow::SubscriptionRetrieve
+            | Flow::SubscriptionUpdate
+            | Flow::SubscriptionCancel => Self::Subscriptions,
         }
     }
 }
@@ -62,6 +62,7 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::Form(_)
         | ApplicationResponse::PaymenkLinkForm(_)
         | ApplicationResponse::FileData(_)
+        | ApplicationResponse::FileStream(_)
         | ApplicationResponse::JsonWithHeaders(_) => 200,
         ApplicationResponse::JsonForRedirection(_) => 302,
     }
@@ -58,6 +58,11 @@ pub async fn initiate_payment_link(
 ) -> impl Responder {
     let flow = Flow::PaymentLinkInitiate;
     let (merchant_id, payment_id) = path.into_inner();
+    let accept_language = req
+        .headers()
+        .get(actix_web::http::header::ACCEPT_LANGUAGE)
+        .and_then(|header_value| header_value.to_str().ok())
+        .map(ToString::to_string);
     let payload = api_models::payments::PaymentLinkInitiateRequest {
         payment_id,
         merchant_id: merchant_id.clone(),
@@ -71,8 +76,10 @@ pub async fn initiate_payment_link(
             intiate_payment_link_flow(
                 state,
                 auth.merchant_account,
+                auth.key_store,
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
+                accept_language.clone(),
             )
         },
         &crate::services::authentication::MerchantIdAuth(merchant_id),
@@ -119,7 +119,7 @@ pub async fn list_payment_method_api(
     ),
     tag = "Payment Methods",
     operation_id = "List all Payment Methods for a Customer",
-    security(("api_key" = []))
+    security(("api_key" = []), ("ephemeral_key" = []))
 )]
 #[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsList))]
 pub async fn list_customer_payment_method_api(
@@ -130,11 +130,18 @@ pub async fn list_customer_payment_method_api(
 ) -> HttpResponse {
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-        Ok((auth, _auth_flow)) => (auth, _auth_flow),
+    let customer_id = customer_id.into_inner().0;
+    let auth = match auth::get_api_key(req.headers()) {
+        Ok(api_key) if api_key.starts_with("epk") => {
+            auth::is_ephemeral_auth(req.headers(), &*state.store, &customer_id).await
+        }
+        _ => auth::check_client_secret_and_get_auth(req.headers(), &payload)
+            .map(|(auth, _auth_flow)| auth),
+    };
+    let auth = match auth {
+        Ok(auth) => auth,
         Err(e) => return api::log_and_return_error_response(e),
     };
-    let customer_id = customer_id.into_inner().0;
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -329,12 +336,116 @@ pub async fn payment_method_delete_api(
         state,
         &req,
         pm,
-        |state, auth, req| cards::delete_payment_method(state, auth.merchant_account, req),
+        |state, auth, req| {
+            cards::delete_payment_method(state, auth.merchant_account, auth.key_store, req)
+        },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
+/// Payment Method - Update for a Customer
+///
+/// To update the nick name of a payment method saved for a customer. This API can be used by client SDKs with an ephemeral key of the customer
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/payment_methods/{payment_method_id}",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the customer account"),
+        ("payment_method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = CustomerPaymentMethodUpdate,
+    responses(
+        (status = 200, description = "Payment Method updated", body = PaymentMethodResponse),
+        (status = 404, description = "Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Update a Payment method for a Customer",
+    security(("api_key" = []), ("ephemeral_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsUpdate))]
+pub async fn customer_payment_method_update_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<payment_methods::CustomerPaymentMethodUpdate>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsUpdate;
+    let (customer_id, payment_method_id) = path.into_inner();
+    let auth = match auth::is_ephemeral_auth(req.headers(), &*state.store, &customer_id).await {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, payload| {
+            cards::update_customer_payment_method_nick_name(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                &customer_id,
+                &payment_method_id,
+                payload,
+            )
+        },
+        &*auth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payment Method - Set Default Payment Method for a Customer
+///
+/// To set the payment method used by default for the off session payments of a customer, when no payment method is provided. This API can be used by client SDKs with an ephemeral key of the customer
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/payment_methods/{payment_method_id}/default",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the customer account"),
+        ("payment_method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Default Payment Method set for the Customer", body = CustomerDefaultPaymentMethodResponse),
+        (status = 404, description = "Customer or Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Set the Payment Method as Default",
+    security(("api_key" = []), ("ephemeral_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsSetDefault))]
+pub async fn default_payment_method_set_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsSetDefault;
+    let (customer_id, payment_method_id) = path.into_inner();
+    let payload = payment_methods::DefaultPaymentMethod {
+        customer_id,
+        payment_method_id,
+    };
+    let auth =
+        match auth::is_ephemeral_auth(req.headers(), &*state.store, &payload.customer_id).await {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            cards::set_default_payment_method(state, auth.merchant_account, auth.key_store, req)
+        },
+        &*auth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
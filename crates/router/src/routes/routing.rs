@@ -59,7 +59,6 @@ pub async fn routing_link_config(
             routing::link_routing_config(
                 state,
                 auth.merchant_account,
-                #[cfg(not(feature = "business_profile_routing"))]
                 auth.key_store,
                 algorithm_id.0,
             )
@@ -73,6 +72,36 @@ pub async fn routing_link_config(
     .await
 }
 
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_run_tests(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<routing_types::RoutingAlgorithmId>,
+) -> impl Responder {
+    let flow = Flow::RoutingRunTests;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, algorithm_id| {
+            routing::run_routing_config_tests(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                algorithm_id,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn routing_retrieve_config(
@@ -0,0 +1,269 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::subscriptions as subscription_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, subscriptions},
+    services::{api, authentication as auth},
+};
+
+/// Subscriptions - Create Plan
+///
+/// Create a plan that customers can subscribe to
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans",
+    request_body = SubscriptionPlanCreateRequest,
+    responses(
+        (status = 200, description = "Subscription plan created", body = SubscriptionPlanResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription Plan",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanCreate))]
+pub async fn create_subscription_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionPlanCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| {
+            subscriptions::create_subscription_plan(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Retrieve Plan
+///
+/// Retrieve a subscription plan
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    responses(
+        (status = 200, description = "Subscription plan retrieved", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription Plan",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanRetrieve))]
+pub async fn retrieve_subscription_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanRetrieve;
+    let plan_id = subscription_types::SubscriptionPlanId {
+        plan_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        plan_id,
+        |state, auth, req| {
+            subscriptions::retrieve_subscription_plan(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - List Plans
+///
+/// List the subscription plans of the merchant
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans",
+    responses(
+        (status = 200, description = "Subscription plans listed", body = Vec<SubscriptionPlanResponse>)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscription Plans",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanList))]
+pub async fn list_subscription_plans(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _| subscriptions::list_subscription_plans(state, auth.merchant_account),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Create
+///
+/// Subscribe a customer to a plan. The customer is charged with the given mandate for every
+/// billing cycle, starting at the end of the trial period of the plan.
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCreate))]
+pub async fn create_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| subscriptions::create_subscription(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Retrieve
+///
+/// Retrieve a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionRetrieve))]
+pub async fn retrieve_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionRetrieve;
+    let subscription_id = subscription_types::SubscriptionId {
+        subscription_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        subscription_id,
+        |state, auth, req| subscriptions::retrieve_subscription(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Update
+///
+/// Move a subscription to a different plan. The price difference for the rest of the current
+/// billing period is prorated and added to the next billing cycle.
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription updated", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionUpdate))]
+pub async fn update_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| subscriptions::update_subscription(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Cancel
+///
+/// Cancel a subscription, either immediately or at the end of the current billing period
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionCancelRequest,
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCancel))]
+pub async fn cancel_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionCancelRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionCancel;
+    let mut payload = json_payload.into_inner();
+    payload.subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| subscriptions::cancel_subscription(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -29,3 +29,21 @@ pub async fn user_connect_account(
     ))
     .await
 }
+
+pub async fn user_verify_email(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    json_payload: web::Json<user_api::VerifyEmailRequest>,
+) -> HttpResponse {
+    let flow = Flow::UserVerifyEmail;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &http_req,
+        json_payload.into_inner(),
+        |state, _, req_body| user::verify_email(state, req_body),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -17,7 +17,7 @@ pub async fn apple_pay_merchant_registration(
 ) -> impl Responder {
     let flow = Flow::Verification;
     let merchant_id = path.into_inner();
-    let kms_conf = &state.clone().conf.kms;
+    let encryption_management_conf = &state.clone().conf.encryption_management;
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -28,7 +28,7 @@ pub async fn apple_pay_merchant_registration(
                 state.clone(),
                 &req,
                 body,
-                kms_conf,
+                encryption_management_conf,
                 merchant_id.clone(),
             )
         },
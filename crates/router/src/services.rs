@@ -1,17 +1,22 @@
 pub mod api;
 pub mod authentication;
+#[cfg(feature = "email")]
+pub mod email;
 pub mod encryption;
 #[cfg(feature = "olap")]
 pub mod jwt;
 pub mod logger;
 
-#[cfg(feature = "kms")]
+#[cfg(feature = "encryption_service")]
 use data_models::errors::StorageError;
 use data_models::errors::StorageResult;
 use error_stack::{IntoReport, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::kms::{self, decrypt::KmsDecrypt};
-#[cfg(not(feature = "kms"))]
+#[cfg(feature = "encryption_service")]
+use external_services::{
+    encryption_management,
+    secrets_management::{decrypt::KmsDecrypt, SecretManagementInterface},
+};
+#[cfg(not(feature = "encryption_service"))]
 use masking::PeekInterface;
 use masking::StrongSecret;
 #[cfg(feature = "kv_store")]
@@ -37,36 +42,40 @@ pub async fn get_store(
     shut_down_signal: oneshot::Sender<()>,
     test_transaction: bool,
 ) -> StorageResult<Store> {
-    #[cfg(feature = "kms")]
-    let kms_client = kms::get_kms_client(&config.kms).await;
+    #[cfg(feature = "encryption_service")]
+    let secret_management_client =
+        encryption_management::get_encryption_management_client(&config.encryption_management)
+            .await
+            .change_context(StorageError::InitializationError)
+            .attach_printable("Failed to initialize encryption management client")?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let master_config = config
         .master_database
         .clone()
-        .decrypt_inner(kms_client)
+        .decrypt_inner(secret_management_client)
         .await
         .change_context(StorageError::InitializationError)
         .attach_printable("Failed to decrypt master database config")?;
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let master_config = config.master_database.clone().into();
 
-    #[cfg(all(feature = "olap", feature = "kms"))]
+    #[cfg(all(feature = "olap", feature = "encryption_service"))]
     let replica_config = config
         .replica_database
         .clone()
-        .decrypt_inner(kms_client)
+        .decrypt_inner(secret_management_client)
         .await
         .change_context(StorageError::InitializationError)
         .attach_printable("Failed to decrypt replica database config")?;
 
-    #[cfg(all(feature = "olap", not(feature = "kms")))]
+    #[cfg(all(feature = "olap", not(feature = "encryption_service")))]
     let replica_config = config.replica_database.clone().into();
 
     let master_enc_key = get_master_enc_key(
         config,
-        #[cfg(feature = "kms")]
-        kms_client,
+        #[cfg(feature = "encryption_service")]
+        secret_management_client,
     )
     .await;
     #[cfg(not(feature = "olap"))]
@@ -101,20 +110,20 @@ pub async fn get_store(
 #[allow(clippy::expect_used)]
 async fn get_master_enc_key(
     conf: &crate::configs::settings::Settings,
-    #[cfg(feature = "kms")] kms_client: &kms::KmsClient,
+    #[cfg(feature = "encryption_service")] secret_management_client: &dyn SecretManagementInterface,
 ) -> StrongSecret<Vec<u8>> {
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "encryption_service")]
     let master_enc_key = hex::decode(
         conf.secrets
             .master_enc_key
             .clone()
-            .decrypt_inner(kms_client)
+            .decrypt_inner(secret_management_client)
             .await
             .expect("Failed to decrypt master enc key"),
     )
     .expect("Failed to decode from hex");
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "encryption_service"))]
     let master_enc_key =
         hex::decode(conf.secrets.master_enc_key.peek()).expect("Failed to decode from hex");
 
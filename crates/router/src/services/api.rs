@@ -1,3 +1,5 @@
+#[cfg(feature = "connector_cassettes")]
+pub mod cassette;
 pub mod client;
 pub mod request;
 use std::{
@@ -18,6 +20,7 @@ use common_utils::{
     errors::{ErrorSwitch, ReportSwitchExt},
 };
 use error_stack::{report, IntoReport, Report, ResultExt};
+use futures::TryStreamExt;
 use masking::{ExposeOptionInterface, PeekInterface};
 use router_env::{instrument, tracing, tracing_actix_web::RequestId, Tag};
 use serde::Serialize;
@@ -28,6 +31,7 @@ use self::request::{HeaderExt, RequestBuilderExt};
 use super::authentication::AuthenticateAndFetch;
 use crate::{
     configs::settings::{Connectors, Settings},
+    connector::utils::CardData,
     consts,
     core::{
         api_locking,
@@ -667,6 +671,24 @@ async fn handle_response(
         .await
 }
 
+/// The contents of a file, streamed as the response body.
+pub struct FileStreamData(pub external_services::file_storage::FileStream);
+
+impl Debug for FileStreamData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FileStreamData")
+    }
+}
+
+/// Streams cannot be compared by their contents, so a stream is only equal to itself.
+impl PartialEq for FileStreamData {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for FileStreamData {}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum ApplicationResponse<R> {
     Json(R),
@@ -674,11 +696,18 @@ pub enum ApplicationResponse<R> {
     TextPlain(String),
     JsonForRedirection(api::RedirectionResponse),
     Form(Box<RedirectionFormData>),
-    PaymenkLinkForm(Box<PaymentLinkFormData>),
+    PaymenkLinkForm(Box<PaymentLinkAction>),
     FileData((Vec<u8>, mime::Mime)),
+    FileStream((FileStreamData, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, String)>)),
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub enum PaymentLinkAction {
+    PaymentLinkFormData(PaymentLinkFormData),
+    PaymentLinkStatus(PaymentLinkStatusData),
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PaymentLinkFormData {
     pub js_script: String,
@@ -686,6 +715,12 @@ pub struct PaymentLinkFormData {
     pub sdk_url: String,
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentLinkStatusData {
+    pub js_script: String,
+    pub css_script: String,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct RedirectionFormData {
     pub redirect_form: RedirectForm,
@@ -945,6 +980,9 @@ where
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
         }
+        Ok(ApplicationResponse::FileStream((file_stream, content_type))) => {
+            http_response_file_stream(file_stream, content_type)
+        }
         Ok(ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => http_redirect_response(res, response),
@@ -970,8 +1008,8 @@ where
             .map_into_boxed_body()
         }
 
-        Ok(ApplicationResponse::PaymenkLinkForm(payment_link_data)) => {
-            match build_payment_link_html(*payment_link_data) {
+        Ok(ApplicationResponse::PaymenkLinkForm(payment_link_action)) => {
+            match build_payment_link_action_html(*payment_link_action) {
                 Ok(rendered_html) => http_response_html_data(rendered_html),
                 Err(_) => http_response_err(
                     r#"{
@@ -1102,6 +1140,21 @@ pub fn http_response_file_data<T: body::MessageBody + 'static>(
     HttpResponse::Ok().content_type(content_type).body(res)
 }
 
+pub fn http_response_file_stream(
+    file_stream: FileStreamData,
+    content_type: mime::Mime,
+) -> HttpResponse {
+    // Errors can only be reported by aborting the response, since its status has already been
+    // sent by the time the file is read
+    let body = file_stream.0.map_err(|error| {
+        logger::error!(?error, "Failed to stream file");
+        std::io::Error::new(std::io::ErrorKind::Other, "Failed to stream file")
+    });
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .streaming(body)
+}
+
 pub fn http_response_html_data<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
     HttpResponse::Ok().content_type(mime::TEXT_HTML).body(res)
 }
@@ -1247,7 +1300,7 @@ pub fn build_redirection_form(
                 if let Some(api::PaymentMethodData::Card(ccard)) = payment_method_data {
                     format!(
                         "var saveCardDirectly={{cvv: \"{}\",amount: {},currency: \"{}\"}};",
-                        ccard.card_cvc.peek(),
+                        ccard.get_card_cvc().peek(),
                         amount,
                         currency
                     )
@@ -1466,6 +1519,41 @@ pub fn build_payment_link_html(
     }
 }
 
+pub fn build_payment_link_status_html(
+    payment_link_data: PaymentLinkStatusData,
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    let html_template = include_str!("../core/payment_link/payment_link_status.html").to_string();
+
+    let mut tera = Tera::default();
+
+    let _ = tera.add_raw_template("payment_link_status", &html_template);
+
+    let mut context = Context::new();
+    context.insert("css_color_scheme", &payment_link_data.css_script);
+    context.insert("payment_details_js_script", &payment_link_data.js_script);
+
+    match tera.render("payment_link_status", &context) {
+        Ok(rendered_html) => Ok(rendered_html),
+        Err(tera_error) => {
+            crate::logger::warn!("{tera_error}");
+            Err(errors::ApiErrorResponse::InternalServerError)?
+        }
+    }
+}
+
+pub fn build_payment_link_action_html(
+    payment_link_action: PaymentLinkAction,
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    match payment_link_action {
+        PaymentLinkAction::PaymentLinkFormData(payment_link_data) => {
+            build_payment_link_html(payment_link_data)
+        }
+        PaymentLinkAction::PaymentLinkStatus(payment_link_status_data) => {
+            build_payment_link_status_html(payment_link_status_data)
+        }
+    }
+}
+
 fn get_hyper_loader_sdk(sdk_url: &str) -> String {
     format!("<script src=\"{sdk_url}\"></script>")
 }
@@ -0,0 +1,646 @@
+//!
+//! Recording and replay of connector HTTP interactions.
+//!
+//! A cassette is a JSON file holding the request and response pairs exchanged with a connector
+//! during a test. In record mode the requests are sent to the connector and every interaction is
+//! written to the cassette, with secrets masked in the URL query, headers and bodies of both the
+//! requests and the responses. In replay mode no request leaves the process and
+//! the recorded responses are served instead, matched by method, URL and normalized body.
+//!
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use error_stack::{IntoReport, ResultExt};
+use http::Method as HttpMethod;
+use masking::{PeekInterface, Secret};
+use router_env::{logger, tracing_actix_web::RequestId};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    client::{ApiClient, RequestBuilder},
+    request::Maskable,
+    Method, Request,
+};
+use crate::{
+    core::errors::{ApiClientError, CustomResult},
+    routes::AppState,
+};
+
+/// Environment variable selecting the cassette mode for connector tests, one of `record` or
+/// `replay`
+pub const CASSETTE_MODE_ENV: &str = "CONNECTOR_CASSETTE_MODE";
+
+/// Body fields and URL query parameters whose values are masked before an interaction is written
+/// to a cassette. Keys are compared case insensitively, and only by their last segment for dotted
+/// keys such as `card.number`. Masking the fields carrying credentials also lets a cassette recorded with real
+/// credentials be replayed with placeholder ones.
+const DEFAULT_MASKED_FIELDS: [&str; 16] = [
+    "api_key",
+    "apikey",
+    "secret",
+    "secret_key",
+    "password",
+    "key",
+    "transactionkey",
+    "entityid",
+    "signature",
+    "token",
+    "access_token",
+    "card_number",
+    "number",
+    "holder",
+    "cvc",
+    "cvv",
+];
+
+/// Headers whose values are masked before an interaction is written to a cassette, in addition to
+/// the headers named after a masked field. Names are compared case insensitively.
+const DEFAULT_MASKED_HEADERS: [&str; 6] = [
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+    "api-key",
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+impl CassetteMode {
+    pub fn from_env() -> Option<Self> {
+        std::env::var(CASSETTE_MODE_ENV)
+            .ok()
+            .and_then(|mode| mode.parse().ok())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<serde_json::Value>,
+}
+
+impl RecordedRequest {
+    fn matches(&self, other: &Self) -> bool {
+        self.method == other.method && self.url == other.url && self.body == other.body
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status_code: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Free form remark on how the cassette was produced, e.g. for cassettes written by hand
+    /// rather than recorded against the connector
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+    cassette: Cassette,
+    replayed: Vec<bool>,
+}
+
+///
+/// Api client recording connector interactions to, or replaying them from, a cassette file
+///
+#[derive(Clone)]
+pub struct CassetteApiClient {
+    mode: CassetteMode,
+    path: PathBuf,
+    masked_fields: Vec<String>,
+    inner: Option<Box<dyn ApiClient>>,
+    state: Arc<Mutex<CassetteState>>,
+}
+
+impl CassetteApiClient {
+    /// Sends requests through `inner` and writes every interaction to the cassette at `path`,
+    /// replacing any interactions recorded earlier.
+    pub fn record(path: impl Into<PathBuf>, inner: Box<dyn ApiClient>) -> Self {
+        Self {
+            mode: CassetteMode::Record,
+            path: path.into(),
+            masked_fields: default_masked_fields(),
+            inner: Some(inner),
+            state: Arc::new(Mutex::new(CassetteState::default())),
+        }
+    }
+
+    /// Serves the responses recorded in the cassette at `path`, each interaction is replayed at
+    /// most once.
+    pub fn replay(path: impl Into<PathBuf>) -> CustomResult<Self, ApiClientError> {
+        let path = path.into();
+        let contents = std::fs::read_to_string(&path)
+            .into_report()
+            .change_context(ApiClientError::CassetteReadFailed)
+            .attach_printable_lazy(|| format!("Unable to read cassette {}", path.display()))?;
+        let cassette: Cassette = serde_json::from_str(&contents)
+            .into_report()
+            .change_context(ApiClientError::CassetteReadFailed)
+            .attach_printable_lazy(|| format!("Unable to parse cassette {}", path.display()))?;
+        let replayed = vec![false; cassette.interactions.len()];
+
+        Ok(Self {
+            mode: CassetteMode::Replay,
+            path,
+            masked_fields: default_masked_fields(),
+            inner: None,
+            state: Arc::new(Mutex::new(CassetteState { cassette, replayed })),
+        })
+    }
+
+    /// Masks the values of the given body fields and query parameters in addition to the default
+    /// ones
+    pub fn with_masked_fields(mut self, fields: &[&str]) -> Self {
+        self.masked_fields
+            .extend(fields.iter().map(|field| field.to_lowercase()));
+        self
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    fn to_recorded_request(&self, request: &Request) -> RecordedRequest {
+        let mut headers = request
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    Maskable::Masked(secret) => format!("{secret:?}"),
+                    Maskable::Normal(value) => mask_header(name, value, &self.masked_fields),
+                };
+                (name.to_lowercase(), value)
+            })
+            .collect::<Vec<_>>();
+        headers.sort();
+
+        RecordedRequest {
+            method: request.method,
+            url: mask_url(&request.url, &self.masked_fields),
+            headers,
+            body: request
+                .payload
+                .as_ref()
+                .map(|payload| normalize_body(payload.peek(), &self.masked_fields)),
+        }
+    }
+
+    fn replay_interaction(
+        &self,
+        request: &RecordedRequest,
+    ) -> CustomResult<RecordedResponse, ApiClientError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| ApiClientError::UnexpectedState)
+            .into_report()
+            .attach_printable("Cassette state lock poisoned")?;
+        let CassetteState { cassette, replayed } = &mut *state;
+
+        let position = cassette
+            .interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|(interaction, is_replayed)| {
+                !is_replayed && interaction.request.matches(request)
+            })
+            .ok_or(ApiClientError::NoRecordedInteraction)
+            .into_report()
+            .attach_printable_lazy(|| {
+                format!(
+                    "No interaction in cassette {} matches {} {}",
+                    self.path.display(),
+                    request.method,
+                    request.url
+                )
+            })?;
+
+        if let Some(is_replayed) = replayed.get_mut(position) {
+            *is_replayed = true;
+        }
+        cassette
+            .interactions
+            .get(position)
+            .map(|interaction| interaction.response.clone())
+            .ok_or(ApiClientError::UnexpectedState)
+            .into_report()
+    }
+
+    fn record_interaction(&self, interaction: Interaction) -> CustomResult<(), ApiClientError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| ApiClientError::UnexpectedState)
+            .into_report()
+            .attach_printable("Cassette state lock poisoned")?;
+        state.cassette.interactions.push(interaction);
+
+        let contents = serde_json::to_string_pretty(&state.cassette)
+            .into_report()
+            .change_context(ApiClientError::CassetteWriteFailed)?;
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .into_report()
+                .change_context(ApiClientError::CassetteWriteFailed)?;
+        }
+        std::fs::write(&self.path, contents)
+            .into_report()
+            .change_context(ApiClientError::CassetteWriteFailed)
+            .attach_printable_lazy(|| format!("Unable to write cassette {}", self.path.display()))
+    }
+}
+
+fn default_masked_fields() -> Vec<String> {
+    DEFAULT_MASKED_FIELDS
+        .iter()
+        .map(|field| field.to_string())
+        .collect()
+}
+
+/// Normalizes a request body so that semantically equal bodies compare equal: JSON and url
+/// encoded bodies are parsed into JSON values with the sensitive fields masked, any other body is
+/// kept as a string.
+pub fn normalize_body(body: &str, masked_fields: &[String]) -> serde_json::Value {
+    let value = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .or_else(|| {
+            serde_urlencoded::from_str::<Vec<(String, String)>>(body)
+                .ok()
+                .filter(|pairs| !pairs.is_empty() && body.contains('='))
+                .map(|pairs| {
+                    serde_json::Value::Object(
+                        pairs
+                            .into_iter()
+                            .map(|(key, value)| (key, serde_json::Value::String(value)))
+                            .collect(),
+                    )
+                })
+        })
+        .unwrap_or_else(|| serde_json::Value::String(body.to_string()));
+
+    mask_fields(value, masked_fields)
+}
+
+/// Masks the sensitive fields of a JSON response body, any other body is kept as is
+fn mask_response_body(body: &str, masked_fields: &[String]) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .map(|value| mask_fields(value, masked_fields).to_string())
+        .unwrap_or_else(|| body.to_string())
+}
+
+/// Masks the values of the sensitive query parameters of the URL
+fn mask_url(url: &str, masked_fields: &[String]) -> String {
+    let (path, query) = match url.split_once('?') {
+        Some((path, query)) => (path, query),
+        None => return url.to_string(),
+    };
+    let masked_query = serde_urlencoded::from_str::<Vec<(String, String)>>(query)
+        .ok()
+        .map(|pairs| {
+            pairs
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = if is_masked_field(&key, masked_fields) {
+                        format!("{:?}", Secret::<_>::new(value))
+                    } else {
+                        value
+                    };
+                    (key, value)
+                })
+                .collect::<Vec<_>>()
+        })
+        .and_then(|pairs| serde_urlencoded::to_string(pairs).ok());
+
+    match masked_query {
+        Some(masked_query) => format!("{path}?{masked_query}"),
+        // A query that cannot be parsed is masked as a whole rather than written as is
+        None => format!("{path}?{:?}", Secret::<_>::new(query.to_string())),
+    }
+}
+
+fn mask_header(name: &str, value: &str, masked_fields: &[String]) -> String {
+    let name = name.to_lowercase();
+    if DEFAULT_MASKED_HEADERS.contains(&name.as_str()) || masked_fields.contains(&name) {
+        format!("{:?}", Secret::<_>::new(value.to_string()))
+    } else {
+        value.to_string()
+    }
+}
+
+fn is_masked_field(key: &str, masked_fields: &[String]) -> bool {
+    let field = key.rsplit('.').next().unwrap_or(key).to_lowercase();
+    masked_fields.contains(&field)
+}
+
+fn mask_fields(value: serde_json::Value, masked_fields: &[String]) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let value = if is_masked_field(&key, masked_fields) {
+                        serde_json::Value::String(format!("{:?}", Secret::<_>::new(value)))
+                    } else {
+                        mask_fields(value, masked_fields)
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(|value| mask_fields(value, masked_fields))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+fn to_reqwest_response(
+    response: RecordedResponse,
+) -> CustomResult<reqwest::Response, ApiClientError> {
+    let mut builder = http::Response::builder().status(response.status_code);
+    for (name, value) in response.headers {
+        builder = builder.header(name, value);
+    }
+    builder
+        .body(response.body.into_bytes())
+        .into_report()
+        .change_context(ApiClientError::CassetteReadFailed)
+        .attach_printable("Unable to construct the recorded response")
+        .map(reqwest::Response::from)
+}
+
+#[async_trait::async_trait]
+impl ApiClient for CassetteApiClient {
+    fn request(
+        &self,
+        method: HttpMethod,
+        url: String,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        self.inner
+            .as_ref()
+            .ok_or(ApiClientError::UnexpectedState)
+            .into_report()
+            .attach_printable("Raw requests cannot be replayed from a cassette")?
+            .request(method, url)
+    }
+
+    fn request_with_certificate(
+        &self,
+        method: HttpMethod,
+        url: String,
+        certificate: Option<String>,
+        certificate_key: Option<String>,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        self.inner
+            .as_ref()
+            .ok_or(ApiClientError::UnexpectedState)
+            .into_report()
+            .attach_printable("Raw requests cannot be replayed from a cassette")?
+            .request_with_certificate(method, url, certificate, certificate_key)
+    }
+
+    async fn send_request(
+        &self,
+        state: &AppState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let recorded_request = self.to_recorded_request(&request);
+
+        let inner = match (self.mode, self.inner.as_ref()) {
+            (CassetteMode::Record, Some(inner)) => inner,
+            _ => {
+                logger::debug!(cassette=%self.path.display(), url=%recorded_request.url, "replaying connector response");
+                return to_reqwest_response(self.replay_interaction(&recorded_request)?);
+            }
+        };
+
+        let response = inner
+            .send_request(state, request, option_timeout_secs, forward_to_kafka)
+            .await?;
+
+        // The body is stored decoded, so the headers describing the transfer encoding of the
+        // original body no longer apply
+        let headers = response
+            .headers()
+            .iter()
+            .filter(|(name, _)| {
+                *name != http::header::CONTENT_ENCODING
+                    && *name != http::header::CONTENT_LENGTH
+                    && *name != http::header::TRANSFER_ENCODING
+            })
+            .filter_map(|(name, value)| {
+                value.to_str().ok().map(|value| {
+                    (
+                        name.to_string(),
+                        mask_header(name.as_str(), value, &self.masked_fields),
+                    )
+                })
+            })
+            .collect();
+        let status_code = response.status().as_u16();
+        let body = response
+            .bytes()
+            .await
+            .into_report()
+            .change_context(ApiClientError::ResponseDecodingFailed)?;
+
+        // The test is served the response as received, only the recorded copy is masked
+        let body = String::from_utf8_lossy(&body).into_owned();
+        let recorded_response = RecordedResponse {
+            status_code,
+            headers,
+            body: mask_response_body(&body, &self.masked_fields),
+        };
+        self.record_interaction(Interaction {
+            request: recorded_request,
+            response: recorded_response.clone(),
+        })?;
+
+        to_reqwest_response(RecordedResponse {
+            body,
+            ..recorded_response
+        })
+    }
+
+    fn add_request_id(&mut self, request_id: RequestId) {
+        if let Some(inner) = self.inner.as_mut() {
+            inner.add_request_id(request_id)
+        }
+    }
+
+    fn get_request_id(&self) -> Option<String> {
+        self.inner.as_ref().and_then(|inner| inner.get_request_id())
+    }
+
+    fn add_merchant_id(&mut self, merchant_id: Option<String>) {
+        if let Some(inner) = self.inner.as_mut() {
+            inner.add_merchant_id(merchant_id)
+        }
+    }
+
+    fn add_flow_name(&mut self, flow_name: String) {
+        if let Some(inner) = self.inner.as_mut() {
+            inner.add_flow_name(flow_name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_normalize_body_masks_sensitive_fields() {
+        let masked_fields = default_masked_fields();
+        let body = normalize_body(
+            r#"{"amount":100,"card":{"number":"4242424242424242","cvc":"123"},"api_key":"sk_test"}"#,
+            &masked_fields,
+        );
+
+        assert_eq!(body["amount"], serde_json::json!(100));
+        assert_ne!(
+            body["card"]["number"],
+            serde_json::json!("4242424242424242")
+        );
+        assert_ne!(body["card"]["cvc"], serde_json::json!("123"));
+        assert_ne!(body["api_key"], serde_json::json!("sk_test"));
+    }
+
+    #[test]
+    fn test_normalize_body_masks_dotted_fields() {
+        let masked_fields = default_masked_fields();
+        let body = normalize_body(
+            "entityId=MyEntityId&amount=10.00&card.number=4200000000000000&card.cvv=999",
+            &masked_fields,
+        );
+
+        assert_eq!(body["amount"], serde_json::json!("10.00"));
+        assert_ne!(body["entityId"], serde_json::json!("MyEntityId"));
+        assert_ne!(body["card.number"], serde_json::json!("4200000000000000"));
+        assert_ne!(body["card.cvv"], serde_json::json!("999"));
+    }
+
+    #[test]
+    fn test_mask_response_body_and_url() {
+        let masked_fields = default_masked_fields();
+        let body: serde_json::Value = serde_json::from_str(&mask_response_body(
+            r#"{"id":"pay_1","card":{"holder":"John Doe","last4Digits":"0000"}}"#,
+            &masked_fields,
+        ))
+        .unwrap();
+
+        assert_eq!(body["id"], serde_json::json!("pay_1"));
+        assert_eq!(body["card"]["last4Digits"], serde_json::json!("0000"));
+        assert_ne!(body["card"]["holder"], serde_json::json!("John Doe"));
+        assert_eq!(
+            mask_response_body("<status>ok</status>", &masked_fields),
+            "<status>ok</status>"
+        );
+
+        let url = mask_url(
+            "https://connector.test/payments/1?entityId=MyEntityId&amount=10.00",
+            &masked_fields,
+        );
+        assert!(url.starts_with("https://connector.test/payments/1?entityId="));
+        assert!(!url.contains("MyEntityId"));
+        assert!(url.ends_with("&amount=10.00"));
+        assert_eq!(
+            mask_url("https://connector.test/payments/1", &masked_fields),
+            "https://connector.test/payments/1"
+        );
+    }
+
+    #[test]
+    fn test_mask_header() {
+        let masked_fields = default_masked_fields();
+
+        assert_ne!(
+            mask_header("Set-Cookie", "session=abc", &masked_fields),
+            "session=abc"
+        );
+        assert_ne!(mask_header("token", "abc", &masked_fields), "abc");
+        assert_eq!(
+            mask_header("content-type", "application/json", &masked_fields),
+            "application/json"
+        );
+    }
+
+    #[test]
+    fn test_normalize_body_ignores_field_order() {
+        let masked_fields = default_masked_fields();
+
+        assert_eq!(
+            normalize_body(r#"{"amount":100,"currency":"USD"}"#, &masked_fields),
+            normalize_body(r#"{"currency":"USD","amount":100}"#, &masked_fields)
+        );
+        assert_eq!(
+            normalize_body("amount=100&currency=USD", &masked_fields),
+            normalize_body("currency=USD&amount=100", &masked_fields)
+        );
+    }
+
+    #[test]
+    fn test_replay_serves_each_interaction_once() {
+        let path = std::env::temp_dir().join("cassette_replay_test.json");
+        let interaction = Interaction {
+            request: RecordedRequest {
+                method: Method::Post,
+                url: "https://connector.test/payments".to_string(),
+                headers: vec![],
+                body: Some(normalize_body(
+                    r#"{"amount":100}"#,
+                    &default_masked_fields(),
+                )),
+            },
+            response: RecordedResponse {
+                status_code: 200,
+                headers: vec![],
+                body: r#"{"status":"succeeded"}"#.to_string(),
+            },
+        };
+        let cassette = Cassette {
+            note: None,
+            interactions: vec![interaction.clone()],
+        };
+        std::fs::write(&path, serde_json::to_string(&cassette).unwrap()).unwrap();
+
+        let client = CassetteApiClient::replay(&path).unwrap();
+        assert_eq!(
+            client.replay_interaction(&interaction.request).unwrap(),
+            interaction.response
+        );
+        assert!(client.replay_interaction(&interaction.request).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -3,9 +3,14 @@ use api_models::{payment_methods::PaymentMethodListRequest, payments};
 use async_trait::async_trait;
 use common_utils::date_time;
 use error_stack::{report, IntoReport, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::kms::{self, decrypt::KmsDecrypt};
+#[cfg(feature = "encryption_service")]
+use external_services::{
+    encryption_management,
+    secrets_management::{decrypt::KmsDecrypt, SecretManagementInterface},
+};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+#[cfg(feature = "olap")]
+use masking::ExposeInterface;
 use masking::{PeekInterface, StrongSecret};
 use serde::Serialize;
 
@@ -106,6 +111,34 @@ impl AuthToken {
     }
 }
 
+/// Token carried by the links sent in user emails, such as the email verification link.
+#[cfg(feature = "olap")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct EmailToken {
+    email: String,
+    exp: u64,
+}
+
+#[cfg(feature = "olap")]
+impl EmailToken {
+    pub async fn new_token(
+        email: domain::UserEmail,
+        settings: &settings::Settings,
+    ) -> errors::UserResult<String> {
+        let exp_duration = std::time::Duration::from_secs(consts::EMAIL_TOKEN_TIME_IN_SECS);
+        let exp = jwt::generate_exp(exp_duration)?.as_secs();
+        let token_payload = Self {
+            email: email.get_secret().expose(),
+            exp,
+        };
+        jwt::generate_jwt(&token_payload, settings).await
+    }
+
+    pub fn get_email(&self) -> &str {
+        self.email.as_str()
+    }
+}
+
 pub trait AuthInfo {
     fn get_merchant_id(&self) -> Option<&str>;
 }
@@ -177,8 +210,12 @@ where
             let config = state.conf();
             api_keys::get_hash_key(
                 &config.api_keys,
-                #[cfg(feature = "kms")]
-                kms::get_kms_client(&config.kms).await,
+                #[cfg(feature = "encryption_service")]
+                encryption_management::get_encryption_management_client(
+                    &config.encryption_management,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
             )
             .await?
         };
@@ -237,19 +274,19 @@ static ADMIN_API_KEY: tokio::sync::OnceCell<StrongSecret<String>> =
 
 pub async fn get_admin_api_key(
     secrets: &settings::Secrets,
-    #[cfg(feature = "kms")] kms_client: &kms::KmsClient,
+    #[cfg(feature = "encryption_service")] secret_management_client: &dyn SecretManagementInterface,
 ) -> RouterResult<&'static StrongSecret<String>> {
     ADMIN_API_KEY
         .get_or_try_init(|| async {
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "encryption_service")]
             let admin_api_key = secrets
                 .kms_encrypted_admin_api_key
-                .decrypt_inner(kms_client)
+                .decrypt_inner(secret_management_client)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to KMS decrypt admin API key")?;
 
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "encryption_service"))]
             let admin_api_key = secrets.admin_api_key.clone();
 
             Ok(StrongSecret::new(admin_api_key))
@@ -276,8 +313,10 @@ where
 
         let admin_api_key = get_admin_api_key(
             &conf.secrets,
-            #[cfg(feature = "kms")]
-            kms::get_kms_client(&conf.kms).await,
+            #[cfg(feature = "encryption_service")]
+            encryption_management::get_encryption_management_client(&conf.encryption_management)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
         )
         .await?;
 
@@ -613,19 +652,19 @@ static JWT_SECRET: tokio::sync::OnceCell<StrongSecret<String>> = tokio::sync::On
 
 pub async fn get_jwt_secret(
     secrets: &settings::Secrets,
-    #[cfg(feature = "kms")] kms_client: &kms::KmsClient,
+    #[cfg(feature = "encryption_service")] secret_management_client: &dyn SecretManagementInterface,
 ) -> RouterResult<&'static StrongSecret<String>> {
     JWT_SECRET
         .get_or_try_init(|| async {
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "encryption_service")]
             let jwt_secret = secrets
                 .kms_encrypted_jwt_secret
-                .decrypt_inner(kms_client)
+                .decrypt_inner(secret_management_client)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to KMS decrypt JWT secret")?;
 
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "encryption_service"))]
             let jwt_secret = secrets.jwt_secret.clone();
 
             Ok(StrongSecret::new(jwt_secret))
@@ -640,8 +679,10 @@ where
     let conf = state.conf();
     let secret = get_jwt_secret(
         &conf.secrets,
-        #[cfg(feature = "kms")]
-        kms::get_kms_client(&conf.kms).await,
+        #[cfg(feature = "encryption_service")]
+        encryption_management::get_encryption_management_client(&conf.encryption_management)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
     )
     .await?
     .peek()
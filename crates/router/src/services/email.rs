@@ -0,0 +1,110 @@
+pub mod types;
+
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use external_services::email::{EmailContents, EmailError, EmailResult};
+use tera::{Context, Tera};
+
+use self::types::{EmailBranding, EmailTemplate};
+use crate::routes::AppState;
+
+const BASE_TEMPLATE_NAME: &str = "base.html";
+const BASE_TEMPLATE: &str = include_str!("email/assets/base.html");
+
+/// Renders the subject, plain text and HTML bodies of the email from the template, styled with
+/// the provided branding.
+pub fn render_email<T: EmailTemplate>(
+    template: &T,
+    branding: &EmailBranding,
+) -> EmailResult<EmailContents> {
+    let mut tera = Tera::default();
+    tera.add_raw_templates(vec![
+        (BASE_TEMPLATE_NAME, BASE_TEMPLATE),
+        (T::TEMPLATE_NAME, T::HTML_TEMPLATE),
+    ])
+    .into_report()
+    .change_context(EmailError::TemplateRenderingFailure)
+    .attach_printable("Failed to parse email templates")?;
+
+    let subject = template.get_subject(branding);
+
+    let mut context = Context::new();
+    context.insert("branding", branding);
+    context.insert("subject", &subject);
+    context.insert("email", template);
+
+    let html_body = tera
+        .render(T::TEMPLATE_NAME, &context)
+        .into_report()
+        .change_context(EmailError::TemplateRenderingFailure)
+        .attach_printable_lazy(|| {
+            format!("Failed to render email template {}", T::TEMPLATE_NAME)
+        })?;
+
+    Ok(EmailContents {
+        subject,
+        text_body: template.get_text_body(branding),
+        html_body: Some(html_body),
+    })
+}
+
+/// Renders the email from the template and sends it to the recipient using the configured email
+/// client.
+pub async fn compose_and_send_email<T: EmailTemplate>(
+    state: &AppState,
+    recipient: pii::Email,
+    template: &T,
+    branding: &EmailBranding,
+) -> EmailResult<()> {
+    let contents = render_email(template, branding)?;
+
+    state.email_client.send_email(recipient, contents).await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::{render_email, types};
+
+    #[test]
+    fn test_render_email_verification() {
+        let template = types::EmailVerification {
+            link: "https://app.example.com/user/verify_email?token=abc".to_string(),
+        };
+        let contents = render_email(&template, &types::EmailBranding::default()).unwrap();
+        let html_body = contents.html_body.unwrap();
+
+        assert_eq!(
+            contents.subject,
+            "Verify your email address for Hyperswitch"
+        );
+        // Tera escapes slashes in the HTML body, which browsers decode back in the link
+        assert!(html_body.contains("verify_email?token=abc"));
+        assert!(contents
+            .text_body
+            .contains("https://app.example.com/user/verify_email?token=abc"));
+    }
+
+    #[test]
+    fn test_render_email_escapes_merchant_branding() {
+        let template = types::RefundNotice {
+            refund_id: "ref_123".to_string(),
+            payment_id: "pay_123".to_string(),
+            amount: "10.00".to_string(),
+            currency: "USD".to_string(),
+            reason: None,
+        };
+        let branding = types::EmailBranding {
+            display_name: "<script>alert(1)</script>".to_string(),
+            ..Default::default()
+        };
+        let html_body = render_email(&template, &branding)
+            .unwrap()
+            .html_body
+            .unwrap();
+
+        assert!(html_body.contains("ref_123"));
+        assert!(!html_body.contains("<script>"));
+    }
+}
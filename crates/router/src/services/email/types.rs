@@ -0,0 +1,225 @@
+use api_models::{admin::PaymentLinkConfig, enums::Currency};
+use error_stack::{IntoReport, ResultExt};
+use external_services::email::{EmailError, EmailResult};
+use serde::Serialize;
+
+#[cfg(feature = "olap")]
+use masking::ExposeInterface;
+
+#[cfg(feature = "olap")]
+use crate::{configs::settings, consts, core::errors::UserResult, services::jwt, types::domain};
+
+/// Branding applied to the rendered emails.
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailBranding {
+    pub display_name: String,
+    pub logo_url: Option<String>,
+    pub primary_color: String,
+    pub support_email: Option<String>,
+}
+
+impl Default for EmailBranding {
+    fn default() -> Self {
+        Self {
+            display_name: "Hyperswitch".to_string(),
+            logo_url: None,
+            primary_color: "#006DF9".to_string(),
+            support_email: None,
+        }
+    }
+}
+
+impl From<PaymentLinkConfig> for EmailBranding {
+    fn from(config: PaymentLinkConfig) -> Self {
+        let default = Self::default();
+        Self {
+            display_name: config.display_name.unwrap_or(default.display_name),
+            logo_url: config.merchant_logo,
+            primary_color: config
+                .color_scheme
+                .and_then(|color_scheme| color_scheme.background_primary_color)
+                .unwrap_or(default.primary_color),
+            support_email: config
+                .support_contact
+                .and_then(|support_contact| support_contact.email),
+        }
+    }
+}
+
+/// A typed email, rendered into the HTML template `TEMPLATE_NAME`, which extends the common
+/// `base.html` layout. The email itself is available to the template as `email`.
+pub trait EmailTemplate: Serialize {
+    const TEMPLATE_NAME: &'static str;
+    const HTML_TEMPLATE: &'static str;
+
+    fn get_subject(&self, branding: &EmailBranding) -> String;
+
+    fn get_text_body(&self, branding: &EmailBranding) -> String;
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserInvite {
+    pub inviter_name: String,
+    pub link: String,
+}
+
+impl EmailTemplate for UserInvite {
+    const TEMPLATE_NAME: &'static str = "user_invite.html";
+    const HTML_TEMPLATE: &'static str = include_str!("assets/user_invite.html");
+
+    fn get_subject(&self, branding: &EmailBranding) -> String {
+        format!("You have been invited to join {}", branding.display_name)
+    }
+
+    fn get_text_body(&self, branding: &EmailBranding) -> String {
+        format!(
+            "{} has invited you to join their team on {}.\n\nAccept the invitation by visiting the link below:\n{}\n",
+            self.inviter_name, branding.display_name, self.link
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmailVerification {
+    pub link: String,
+}
+
+impl EmailTemplate for EmailVerification {
+    const TEMPLATE_NAME: &'static str = "email_verification.html";
+    const HTML_TEMPLATE: &'static str = include_str!("assets/email_verification.html");
+
+    fn get_subject(&self, branding: &EmailBranding) -> String {
+        format!("Verify your email address for {}", branding.display_name)
+    }
+
+    fn get_text_body(&self, branding: &EmailBranding) -> String {
+        format!(
+            "Welcome to {}!\n\nVerify your email address by visiting the link below:\n{}\n\nIf you did not sign up, you can ignore this email.\n",
+            branding.display_name, self.link
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PasswordReset {
+    pub link: String,
+}
+
+impl EmailTemplate for PasswordReset {
+    const TEMPLATE_NAME: &'static str = "password_reset.html";
+    const HTML_TEMPLATE: &'static str = include_str!("assets/password_reset.html");
+
+    fn get_subject(&self, branding: &EmailBranding) -> String {
+        format!("Reset your {} password", branding.display_name)
+    }
+
+    fn get_text_body(&self, branding: &EmailBranding) -> String {
+        format!(
+            "We received a request to reset your {} password.\n\nReset your password by visiting the link below:\n{}\n\nIf you did not request a password reset, you can ignore this email.\n",
+            branding.display_name, self.link
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaymentReceipt {
+    pub payment_id: String,
+    /// The amount paid, in the base unit of the currency
+    pub amount: String,
+    pub currency: String,
+    pub description: Option<String>,
+}
+
+impl EmailTemplate for PaymentReceipt {
+    const TEMPLATE_NAME: &'static str = "payment_receipt.html";
+    const HTML_TEMPLATE: &'static str = include_str!("assets/payment_receipt.html");
+
+    fn get_subject(&self, branding: &EmailBranding) -> String {
+        format!("Your receipt from {}", branding.display_name)
+    }
+
+    fn get_text_body(&self, branding: &EmailBranding) -> String {
+        format!(
+            "Thank you for your payment to {}.\n\nPayment ID: {}\nAmount: {} {}\n",
+            branding.display_name, self.payment_id, self.amount, self.currency
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefundNotice {
+    pub refund_id: String,
+    pub payment_id: String,
+    /// The amount refunded, in the base unit of the currency
+    pub amount: String,
+    pub currency: String,
+    pub reason: Option<String>,
+}
+
+impl EmailTemplate for RefundNotice {
+    const TEMPLATE_NAME: &'static str = "refund_notice.html";
+    const HTML_TEMPLATE: &'static str = include_str!("assets/refund_notice.html");
+
+    fn get_subject(&self, branding: &EmailBranding) -> String {
+        format!("Your refund from {}", branding.display_name)
+    }
+
+    fn get_text_body(&self, branding: &EmailBranding) -> String {
+        format!(
+            "{} has issued you a refund.\n\nRefund ID: {}\nPayment ID: {}\nAmount: {} {}\n",
+            branding.display_name, self.refund_id, self.payment_id, self.amount, self.currency
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnalyticsReportReady {
+    pub report_name: String,
+    /// The period covered by the report, formatted for display
+    pub period: String,
+    pub link: String,
+}
+
+impl EmailTemplate for AnalyticsReportReady {
+    const TEMPLATE_NAME: &'static str = "analytics_report_ready.html";
+    const HTML_TEMPLATE: &'static str = include_str!("assets/analytics_report_ready.html");
+
+    fn get_subject(&self, branding: &EmailBranding) -> String {
+        format!(
+            "Your {} report from {}",
+            self.report_name, branding.display_name
+        )
+    }
+
+    fn get_text_body(&self, _branding: &EmailBranding) -> String {
+        format!(
+            "Your {} report for {} is ready.\n\nDownload the report by visiting the link below:\n{}\n",
+            self.report_name, self.period, self.link
+        )
+    }
+}
+
+/// Builds the link to the dashboard page downloading the analytics report export.
+pub fn get_report_export_link(base_url: &str, export_id: &str) -> String {
+    format!(
+        "{}/analytics/reports/exports/{export_id}",
+        base_url.trim_end_matches('/')
+    )
+}
+
+/// Formats an amount in the minor unit of the currency for display in emails.
+pub fn format_amount(amount: i64, currency: Currency) -> EmailResult<String> {
+    currency
+        .to_currency_base_unit(amount)
+        .into_report()
+        .change_context(EmailError::TemplateRenderingFailure)
+        .attach_printable("Failed to convert amount to the base unit of the currency")
+}
+
+/// Builds the link to the user facing `action` page, carrying the email token.
+pub fn get_link_with_token(base_url: &str, token: &str, action: &str) -> String {
+    format!(
+        "{}/user/{action}?token={token}",
+        base_url.trim_end_matches('/')
+    )
+}
@@ -26,8 +26,12 @@ where
 {
     let jwt_secret = authentication::get_jwt_secret(
         &settings.secrets,
-        #[cfg(feature = "kms")]
-        external_services::kms::get_kms_client(&settings.kms).await,
+        #[cfg(feature = "encryption_service")]
+        external_services::encryption_management::get_encryption_management_client(
+            &settings.encryption_management,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?,
     )
     .await
     .change_context(UserErrors::InternalServerError)
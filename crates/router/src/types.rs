@@ -298,6 +298,17 @@ pub struct RouterData<Flow, Request, Response> {
 pub enum PaymentMethodToken {
     Token(String),
     ApplePayDecrypt(Box<ApplePayPredecryptData>),
+    NetworkToken(Box<NetworkTokenData>),
+}
+
+/// Network token and transaction cryptogram to be sent in place of the card number
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NetworkTokenData {
+    pub token_number: Secret<String>,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    pub cryptogram: Secret<String>,
+    pub eci: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
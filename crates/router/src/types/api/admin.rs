@@ -3,7 +3,7 @@ pub use api_models::admin::{
     BusinessProfileUpdate, MerchantAccountCreate, MerchantAccountDeleteResponse,
     MerchantAccountResponse, MerchantAccountUpdate, MerchantConnectorCreate,
     MerchantConnectorDeleteResponse, MerchantConnectorDetails, MerchantConnectorDetailsWrap,
-    MerchantConnectorId, MerchantConnectorResponse, MerchantDetails, MerchantId,
+    MerchantConnectorId, MerchantConnectorResponse, MerchantDetails, MerchantId, PaymentLinkConfig,
     PaymentMethodsEnabled, PayoutRoutingAlgorithm, PayoutStraightThroughAlgorithm, ToggleKVRequest,
     ToggleKVResponse, WebhookDetails,
 };
@@ -12,7 +12,7 @@ use error_stack::ResultExt;
 use masking::Secret;
 
 use crate::{
-    core::errors,
+    core::{errors, payment_link},
     types::{domain, storage, transformers::ForeignTryFrom},
 };
 
@@ -72,6 +72,7 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
             frm_routing_algorithm: item.frm_routing_algorithm,
             payout_routing_algorithm: item.payout_routing_algorithm,
             applepay_verified_domains: item.applepay_verified_domains,
+            payment_link_config: item.payment_link_config,
         })
     }
 }
@@ -100,6 +101,24 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
             })
             .transpose()?;
 
+        request
+            .payment_link_config
+            .as_ref()
+            .map(payment_link::validate_payment_link_config)
+            .transpose()?;
+        let payment_link_config = request
+            .payment_link_config
+            .as_ref()
+            .map(|payment_link_config| {
+                common_utils::ext_traits::Encode::<PaymentLinkConfig>::encode_to_value(
+                    payment_link_config,
+                )
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_link_config",
+                })
+            })
+            .transpose()?;
+
         let payment_response_hash_key = request
             .payment_response_hash_key
             .or(merchant_account.payment_response_hash_key)
@@ -139,6 +158,7 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 .or(merchant_account.payout_routing_algorithm),
             is_recon_enabled: merchant_account.is_recon_enabled,
             applepay_verified_domains: request.applepay_verified_domains,
+            payment_link_config,
         })
     }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use masking::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -36,6 +38,38 @@ pub struct DisputeEvidence {
     pub uncategorized_file: Option<String>,
 }
 
+/// Merchant specific configuration of the dispute deadline workflow, stored in the configs table
+/// with the key `dispute_deadline_config_{merchant_id}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DisputeDeadlineConfig {
+    /// Offsets (in seconds) before the evidence deadline at which reminder webhooks are sent
+    pub reminder_offsets: Vec<i64>,
+    /// Offset (in seconds) before the evidence deadline at which attached evidence is submitted,
+    /// or the dispute is accepted if it is eligible for auto acceptance
+    pub auto_action_offset: i64,
+    /// Interval (in seconds) at which a failed automatic action is retried, until the deadline
+    pub auto_action_retry_interval: i64,
+    /// Amounts, in the minor unit of each currency, up to which disputes in that currency are
+    /// accepted automatically if no evidence was attached to them. Disputes in any other currency
+    /// are never accepted automatically.
+    pub auto_accept_amount_thresholds: HashMap<types::storage::enums::Currency, i64>,
+}
+
+impl Default for DisputeDeadlineConfig {
+    fn default() -> Self {
+        Self {
+            // 7 days, 3 days and 1 day before the deadline
+            reminder_offsets: vec![604800, 259200, 86400],
+            // 12 hours before the deadline
+            auto_action_offset: 43200,
+            // 1 hour
+            auto_action_retry_interval: 3600,
+            auto_accept_amount_thresholds: HashMap::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct AttachEvidenceRequest {
     pub create_file_request: types::api::CreateFileRequest,
@@ -47,15 +47,36 @@ impl ForeignTryFrom<&types::Connector> for FileUploadProvider {
     }
 }
 
+/// The body of a multipart file upload request.
+///
+/// The body is only read once the request has been authenticated, so that the file content can be
+/// streamed to the file storage instead of being held in memory.
+pub struct MultipartPayload(pub actix_multipart::Multipart);
+
+impl std::fmt::Debug for MultipartPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MultipartPayload")
+    }
+}
+
+impl Serialize for MultipartPayload {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct CreateFileRequest {
-    pub file: Vec<u8>,
+    /// The identifier of the file, whose content has already been streamed to the file storage
+    pub file_id: String,
     pub file_name: Option<String>,
     pub file_size: i32,
     #[serde(serialize_with = "crate::utils::custom_serde::display_serialize")]
     pub file_type: mime::Mime,
     pub purpose: FilePurpose,
     pub dispute_id: Option<String>,
+    /// Hex-encoded SHA-256 checksum of the file content
+    pub checksum: String,
 }
 
 #[derive(Debug, serde::Deserialize, strum::Display, Clone, serde::Serialize)]
@@ -65,6 +86,15 @@ pub enum FilePurpose {
     DisputeEvidence,
 }
 
+impl FilePurpose {
+    /// The content types accepted for files uploaded with this purpose
+    pub fn allowed_content_types(&self) -> &'static [&'static str] {
+        match self {
+            Self::DisputeEvidence => &["application/pdf", "image/jpeg", "image/png"],
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Upload;
 
@@ -1,16 +1,22 @@
 use api_models::enums as api_enums;
 pub use api_models::payment_methods::{
-    CardDetail, CardDetailFromLocker, CardDetailsPaymentMethod, CustomerPaymentMethod,
-    CustomerPaymentMethodsListResponse, DeleteTokenizeByTokenRequest, GetTokenizePayloadRequest,
-    GetTokenizePayloadResponse, PaymentMethodCreate, PaymentMethodDeleteResponse, PaymentMethodId,
-    PaymentMethodList, PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodResponse,
-    PaymentMethodUpdate, PaymentMethodsData, TokenizePayloadEncrypted, TokenizePayloadRequest,
-    TokenizedCardValue1, TokenizedCardValue2, TokenizedWalletValue1, TokenizedWalletValue2,
+    CardDetail, CardDetailFromLocker, CardDetailsPaymentMethod,
+    CustomerDefaultPaymentMethodResponse, CustomerPaymentMethod, CustomerPaymentMethodUpdate,
+    CustomerPaymentMethodsListResponse, DefaultPaymentMethod, DeleteTokenizeByTokenRequest,
+    GetTokenizePayloadRequest, GetTokenizePayloadResponse, PaymentMethodCreate,
+    PaymentMethodDeleteResponse, PaymentMethodEligibility, PaymentMethodId,
+    PaymentMethodIneligibilityReason, PaymentMethodList, PaymentMethodListRequest,
+    PaymentMethodListResponse, PaymentMethodResponse, PaymentMethodUpdate, PaymentMethodsData,
+    TokenizePayloadEncrypted, TokenizePayloadRequest, TokenizedCardValue1, TokenizedCardValue2,
+    TokenizedWalletValue1, TokenizedWalletValue2,
 };
 use error_stack::report;
 
 use crate::{
-    core::errors::{self, RouterResult},
+    core::{
+        errors::{self, RouterResult},
+        payments::helpers,
+    },
     types::transformers::ForeignFrom,
 };
 
@@ -32,6 +38,10 @@ impl PaymentMethodCreateExt for PaymentMethodCreate {
             })
             .attach_printable("Invalid payment method type"));
         }
+        if let Some(card) = &self.card {
+            helpers::validate_card_number_length(&card.card_number)?;
+            helpers::validate_card_expiry(&card.card_exp_month, &card.card_exp_year)?;
+        }
         Ok(())
     }
 }
@@ -228,10 +228,10 @@ mod payments_test {
     fn card() -> Card {
         Card {
             card_number: "1234432112344321".to_string().try_into().unwrap(),
-            card_exp_month: "12".to_string().into(),
-            card_exp_year: "99".to_string().into(),
+            card_exp_month: "12".parse().unwrap(),
+            card_exp_year: "99".parse().unwrap(),
             card_holder_name: "JohnDoe".to_string().into(),
-            card_cvc: "123".to_string().into(),
+            card_cvc: Some("123".parse().unwrap()),
             card_issuer: Some("HDFC".to_string()),
             card_network: Some(api_models::enums::CardNetwork::Visa),
             bank_code: None,
@@ -5,7 +5,6 @@ use common_utils::{
 };
 use diesel_models::{address::AddressUpdateInternal, encryption::Encryption, enums};
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
 use time::{OffsetDateTime, PrimitiveDateTime};
 
 use super::{
@@ -70,12 +69,12 @@ impl behaviour::Conversion for Address {
         })
     }
 
-    async fn convert_back(
+    async fn convert_back<K: types::EncryptionKey + ?Sized>(
         other: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &K,
     ) -> CustomResult<Self, ValidationError> {
         async {
-            let inner_decrypt = |inner| types::decrypt(inner, key.peek());
+            let inner_decrypt = |inner| types::decrypt(inner, key);
             Ok(Self {
                 id: other.id,
                 address_id: other.address_id,
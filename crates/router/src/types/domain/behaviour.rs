@@ -1,6 +1,6 @@
 use common_utils::errors::{CustomResult, ValidationError};
 
-use crate::pii::Secret;
+use super::types::EncryptionKey;
 
 /// Trait for converting domain types to storage models
 #[async_trait::async_trait]
@@ -9,9 +9,9 @@ pub trait Conversion {
     type NewDstType;
     async fn convert(self) -> CustomResult<Self::DstType, ValidationError>;
 
-    async fn convert_back(
+    async fn convert_back<K: EncryptionKey + ?Sized>(
         item: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &K,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized;
@@ -21,12 +21,15 @@ pub trait Conversion {
 
 #[async_trait::async_trait]
 pub trait ReverseConversion<SrcType: Conversion> {
-    async fn convert(self, key: &Secret<Vec<u8>>) -> CustomResult<SrcType, ValidationError>;
+    async fn convert<K: EncryptionKey + ?Sized>(
+        self,
+        key: &K,
+    ) -> CustomResult<SrcType, ValidationError>;
 }
 
 #[async_trait::async_trait]
 impl<T: Send, U: Conversion<DstType = T>> ReverseConversion<U> for T {
-    async fn convert(self, key: &Secret<Vec<u8>>) -> CustomResult<U, ValidationError> {
+    async fn convert<K: EncryptionKey + ?Sized>(self, key: &K) -> CustomResult<U, ValidationError> {
         U::convert_back(self, key).await
     }
 }
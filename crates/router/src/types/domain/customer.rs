@@ -1,7 +1,6 @@
 use common_utils::{crypto, date_time, pii};
 use diesel_models::{customers::CustomerUpdateInternal, encryption::Encryption};
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
 use super::types::{self, AsyncLift};
@@ -22,6 +21,7 @@ pub struct Customer {
     pub modified_at: PrimitiveDateTime,
     pub connector_customer: Option<serde_json::Value>,
     pub address_id: Option<String>,
+    pub default_payment_method_id: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -45,19 +45,20 @@ impl super::behaviour::Conversion for Customer {
             modified_at: self.modified_at,
             connector_customer: self.connector_customer,
             address_id: self.address_id,
+            default_payment_method_id: self.default_payment_method_id,
         })
     }
 
-    async fn convert_back(
+    async fn convert_back<K: types::EncryptionKey + ?Sized>(
         item: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &K,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
     {
         async {
-            let inner_decrypt = |inner| types::decrypt(inner, key.peek());
-            let inner_decrypt_email = |inner| types::decrypt(inner, key.peek());
+            let inner_decrypt = |inner| types::decrypt(inner, key);
+            let inner_decrypt_email = |inner| types::decrypt(inner, key);
             Ok(Self {
                 id: Some(item.id),
                 customer_id: item.customer_id,
@@ -72,6 +73,7 @@ impl super::behaviour::Conversion for Customer {
                 modified_at: item.modified_at,
                 connector_customer: item.connector_customer,
                 address_id: item.address_id,
+                default_payment_method_id: item.default_payment_method_id,
             })
         }
         .await
@@ -95,6 +97,7 @@ impl super::behaviour::Conversion for Customer {
             modified_at: now,
             connector_customer: self.connector_customer,
             address_id: self.address_id,
+            default_payment_method_id: self.default_payment_method_id,
         })
     }
 }
@@ -114,6 +117,9 @@ pub enum CustomerUpdate {
     ConnectorCustomer {
         connector_customer: Option<serde_json::Value>,
     },
+    DefaultPaymentMethodUpdate {
+        default_payment_method_id: Option<String>,
+    },
 }
 
 impl From<CustomerUpdate> for CustomerUpdateInternal {
@@ -138,12 +144,20 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 connector_customer,
                 modified_at: Some(date_time::now()),
                 address_id,
+                default_payment_method_id: None,
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
+            CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id,
+            } => Self {
+                default_payment_method_id: Some(default_payment_method_id),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -9,7 +9,6 @@ use diesel_models::{
     merchant_account::MerchantAccountUpdateInternal,
 };
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
 use router_env::logger;
 
 use crate::{
@@ -182,9 +181,9 @@ impl super::behaviour::Conversion for MerchantAccount {
         })
     }
 
-    async fn convert_back(
+    async fn convert_back<K: types::EncryptionKey + ?Sized>(
         item: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &K,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
@@ -199,11 +198,11 @@ impl super::behaviour::Conversion for MerchantAccount {
                 redirect_to_merchant_with_http_post: item.redirect_to_merchant_with_http_post,
                 merchant_name: item
                     .merchant_name
-                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .async_lift(|inner| types::decrypt(inner, key))
                     .await?,
                 merchant_details: item
                     .merchant_details
-                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .async_lift(|inner| types::decrypt(inner, key))
                     .await?,
                 webhook_details: item.webhook_details,
                 sub_merchants_enabled: item.sub_merchants_enabled,
@@ -275,4 +274,14 @@ impl MerchantAccount {
             });
         metadata.and_then(|a| a.compatible_connector)
     }
+
+    /// Whether the merchant processes live payments, as recorded by the environment prefix of the
+    /// publishable key issued to the merchant when the account was created.
+    pub fn is_live_mode(&self) -> bool {
+        self.publishable_key
+            .as_deref()
+            .map_or(false, |publishable_key| {
+                publishable_key.starts_with("pk_prd_")
+            })
+    }
 }
@@ -9,9 +9,12 @@ use diesel_models::{
     merchant_connector_account::MerchantConnectorAccountUpdateInternal,
 };
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
+use masking::Secret;
 
-use super::{behaviour, types::TypeEncryption};
+use super::{
+    behaviour,
+    types::{EncryptionKey, TypeEncryption},
+};
 #[derive(Clone, Debug)]
 pub struct MerchantConnectorAccount {
     pub id: Option<i32>,
@@ -35,6 +38,7 @@ pub struct MerchantConnectorAccount {
     pub profile_id: Option<String>,
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<serde_json::Value>,
+    pub pm_filters: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -53,6 +57,7 @@ pub enum MerchantConnectorAccountUpdate {
         connector_webhook_details: Option<pii::SecretSerdeValue>,
         applepay_verified_domains: Option<Vec<String>>,
         pm_auth_config: Option<serde_json::Value>,
+        pm_filters: Option<serde_json::Value>,
         connector_label: Option<String>,
     },
 }
@@ -89,13 +94,14 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 profile_id: self.profile_id,
                 applepay_verified_domains: self.applepay_verified_domains,
                 pm_auth_config: self.pm_auth_config,
+                pm_filters: self.pm_filters,
             },
         )
     }
 
-    async fn convert_back(
+    async fn convert_back<K: EncryptionKey + ?Sized>(
         other: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &K,
     ) -> CustomResult<Self, ValidationError> {
         Ok(Self {
             id: Some(other.id),
@@ -103,7 +109,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_name: other.connector_name,
             connector_account_details: Encryptable::decrypt(
                 other.connector_account_details,
-                key.peek(),
+                key,
                 GcmAes256,
             )
             .await
@@ -128,6 +134,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             profile_id: other.profile_id,
             applepay_verified_domains: other.applepay_verified_domains,
             pm_auth_config: other.pm_auth_config,
+            pm_filters: other.pm_filters,
         })
     }
 
@@ -155,6 +162,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             profile_id: self.profile_id,
             applepay_verified_domains: self.applepay_verified_domains,
             pm_auth_config: self.pm_auth_config,
+            pm_filters: self.pm_filters,
         })
     }
 }
@@ -176,6 +184,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_webhook_details,
                 applepay_verified_domains,
                 pm_auth_config,
+                pm_filters,
                 connector_label,
             } => Self {
                 merchant_id,
@@ -193,6 +202,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_webhook_details,
                 applepay_verified_domains,
                 pm_auth_config,
+                pm_filters,
                 connector_label,
             },
         }
@@ -3,12 +3,12 @@ use common_utils::{
     custom_serde, date_time,
 };
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
+use masking::Secret;
 use time::PrimitiveDateTime;
 
 use crate::{
     errors::{CustomResult, ValidationError},
-    types::domain::types::TypeEncryption,
+    types::domain::types::{self, EncryptionKey, KeyRing, TypeEncryption, VersionedKey},
 };
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -17,6 +17,29 @@ pub struct MerchantKeyStore {
     pub key: Encryptable<Secret<Vec<u8>>>,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    pub previous_key: Option<Encryptable<Secret<Vec<u8>>>>,
+    pub previous_key_version: Option<i32>,
+}
+
+impl MerchantKeyStore {
+    /// Keys for encrypting and decrypting the data of the merchant
+    pub fn key_ring(&self) -> KeyRing {
+        KeyRing {
+            active: VersionedKey {
+                version: self.key_version,
+                key: self.key.get_inner().clone(),
+            },
+            previous: self
+                .previous_key
+                .as_ref()
+                .zip(self.previous_key_version)
+                .map(|(previous_key, version)| VersionedKey {
+                    version,
+                    key: previous_key.get_inner().clone(),
+                }),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -28,24 +51,34 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             key: self.key.into(),
             merchant_id: self.merchant_id,
             created_at: self.created_at,
+            key_version: self.key_version,
+            previous_key: self.previous_key.map(Into::into),
+            previous_key_version: self.previous_key_version,
         })
     }
 
-    async fn convert_back(
+    async fn convert_back<K: EncryptionKey + ?Sized>(
         item: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &K,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
     {
         Ok(Self {
-            key: Encryptable::decrypt(item.key, key.peek(), GcmAes256)
+            key: Encryptable::decrypt(item.key, key, GcmAes256)
                 .await
                 .change_context(ValidationError::InvalidValue {
                     message: "Failed while decrypting customer data".to_string(),
                 })?,
             merchant_id: item.merchant_id,
             created_at: item.created_at,
+            key_version: item.key_version,
+            previous_key: types::decrypt(item.previous_key, key)
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting previous merchant key".to_string(),
+                })?,
+            previous_key_version: item.previous_key_version,
         })
     }
 
@@ -54,6 +87,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             merchant_id: self.merchant_id,
             key: self.key.into(),
             created_at: date_time::now(),
+            key_version: self.key_version,
         })
     }
 }
@@ -11,6 +11,153 @@ use router_env::{instrument, tracing};
 
 use crate::routes::metrics::{request, DECRYPTION_TIME, ENCRYPTION_TIME};
 
+/// Prefix of the ciphertexts which carry the version of the merchant key they were encrypted with
+const KEY_VERSION_TAG: &[u8] = b"hskv";
+
+/// Version of the merchant keys created before keys were versioned, data encrypted with these keys
+/// does not carry a version tag
+pub const INITIAL_KEY_VERSION: i32 = 1;
+
+/// Key material used for encrypting and decrypting data
+pub trait EncryptionKey: Sync {
+    /// Returns the key used for encrypting new data, along with the tag to be prepended to the
+    /// ciphertext
+    fn encryption_key(&self) -> (&[u8], Option<Vec<u8>>);
+
+    /// Returns the keys which may have encrypted the given ciphertext, along with the ciphertext
+    /// to be decrypted with each of them
+    fn decryption_keys<'a>(&'a self, encrypted_data: &'a [u8]) -> Vec<(&'a [u8], &'a [u8])>;
+}
+
+impl EncryptionKey for [u8] {
+    fn encryption_key(&self) -> (&[u8], Option<Vec<u8>>) {
+        (self, None)
+    }
+
+    fn decryption_keys<'a>(&'a self, encrypted_data: &'a [u8]) -> Vec<(&'a [u8], &'a [u8])> {
+        vec![(self, encrypted_data)]
+    }
+}
+
+impl EncryptionKey for Vec<u8> {
+    fn encryption_key(&self) -> (&[u8], Option<Vec<u8>>) {
+        self.as_slice().encryption_key()
+    }
+
+    fn decryption_keys<'a>(&'a self, encrypted_data: &'a [u8]) -> Vec<(&'a [u8], &'a [u8])> {
+        self.as_slice().decryption_keys(encrypted_data)
+    }
+}
+
+impl EncryptionKey for Secret<Vec<u8>> {
+    fn encryption_key(&self) -> (&[u8], Option<Vec<u8>>) {
+        self.peek().encryption_key()
+    }
+
+    fn decryption_keys<'a>(&'a self, encrypted_data: &'a [u8]) -> Vec<(&'a [u8], &'a [u8])> {
+        self.peek().decryption_keys(encrypted_data)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct VersionedKey {
+    pub version: i32,
+    pub key: Secret<Vec<u8>>,
+}
+
+/// The keys of a merchant. New data is encrypted with the active key and tagged with its version,
+/// the previous key is only kept for decrypting data until it has been re-encrypted.
+#[derive(Clone, Debug)]
+pub struct KeyRing {
+    pub active: VersionedKey,
+    pub previous: Option<VersionedKey>,
+}
+
+impl KeyRing {
+    fn get_key(&self, version: i32) -> Option<&[u8]> {
+        std::iter::once(&self.active)
+            .chain(self.previous.as_ref())
+            .find(|versioned_key| versioned_key.version == version)
+            .map(|versioned_key| versioned_key.key.peek().as_slice())
+    }
+}
+
+impl EncryptionKey for KeyRing {
+    fn encryption_key(&self) -> (&[u8], Option<Vec<u8>>) {
+        let tag = [KEY_VERSION_TAG, &self.active.version.to_be_bytes()].concat();
+        (self.active.key.peek(), Some(tag))
+    }
+
+    fn decryption_keys<'a>(&'a self, encrypted_data: &'a [u8]) -> Vec<(&'a [u8], &'a [u8])> {
+        let tagged_key = split_key_version_tag(encrypted_data)
+            .and_then(|(version, data)| self.get_key(version).map(|key| (key, data)));
+
+        // Untagged data was encrypted before the keys were versioned. An untagged ciphertext may
+        // also happen to start with the tag, so it is tried as a whole as well.
+        let untagged_key = self
+            .get_key(INITIAL_KEY_VERSION)
+            .map(|key| (key, encrypted_data));
+
+        tagged_key.into_iter().chain(untagged_key).collect()
+    }
+}
+
+/// Whether the data was encrypted with the active key of the key ring
+pub fn is_encrypted_with_active_key<T: Clone>(
+    data: &crypto::Encryptable<T>,
+    key_ring: &KeyRing,
+) -> bool {
+    is_data_encrypted_with_active_key(data.clone().into_encrypted().peek(), key_ring)
+}
+
+/// Whether the encrypted bytes were produced with the active key of the key ring
+pub fn is_data_encrypted_with_active_key(encrypted_data: &[u8], key_ring: &KeyRing) -> bool {
+    split_key_version_tag(encrypted_data)
+        .map_or(false, |(version, _)| version == key_ring.active.version)
+}
+
+fn split_key_version_tag(encrypted_data: &[u8]) -> Option<(i32, &[u8])> {
+    let data = encrypted_data.strip_prefix(KEY_VERSION_TAG)?;
+    let version = data.get(..4)?.try_into().ok().map(i32::from_be_bytes)?;
+    Some((version, data.get(4..)?))
+}
+
+pub fn encode_message<K: EncryptionKey + ?Sized, V: crypto::EncodeMessage>(
+    key: &K,
+    crypt_algo: &V,
+    msg: &[u8],
+) -> CustomResult<Vec<u8>, errors::CryptoError> {
+    let (encryption_key, tag) = key.encryption_key();
+    let encrypted_data = crypt_algo.encode_message(encryption_key, msg)?;
+
+    Ok(match tag {
+        Some(mut tag) => {
+            tag.extend(encrypted_data);
+            tag
+        }
+        None => encrypted_data,
+    })
+}
+
+pub fn decode_message<K: EncryptionKey + ?Sized, V: crypto::DecodeMessage>(
+    key: &K,
+    crypt_algo: &V,
+    encrypted_data: &[u8],
+) -> CustomResult<Vec<u8>, errors::CryptoError> {
+    let mut result = Err(errors::CryptoError::DecodingFailed)
+        .into_report()
+        .attach_printable("No key available for decrypting the data");
+
+    for (decryption_key, data) in key.decryption_keys(encrypted_data) {
+        result = crypt_algo.decode_message(decryption_key, data.to_vec().into());
+        if result.is_ok() {
+            break;
+        }
+    }
+
+    result
+}
+
 #[async_trait]
 pub trait TypeEncryption<
     T,
@@ -18,15 +165,15 @@ pub trait TypeEncryption<
     S: masking::Strategy<T>,
 >: Sized
 {
-    async fn encrypt(
+    async fn encrypt<K: EncryptionKey + ?Sized>(
         masked_data: Secret<T, S>,
-        key: &[u8],
+        key: &K,
         crypt_algo: V,
     ) -> CustomResult<Self, errors::CryptoError>;
 
-    async fn decrypt(
+    async fn decrypt<K: EncryptionKey + ?Sized>(
         encrypted_data: Encryption,
-        key: &[u8],
+        key: &K,
         crypt_algo: V,
     ) -> CustomResult<Self, errors::CryptoError>;
 }
@@ -38,24 +185,24 @@ impl<
     > TypeEncryption<String, V, S> for crypto::Encryptable<Secret<String, S>>
 {
     #[instrument(skip_all)]
-    async fn encrypt(
+    async fn encrypt<K: EncryptionKey + ?Sized>(
         masked_data: Secret<String, S>,
-        key: &[u8],
+        key: &K,
         crypt_algo: V,
     ) -> CustomResult<Self, errors::CryptoError> {
-        let encrypted_data = crypt_algo.encode_message(key, masked_data.peek().as_bytes())?;
+        let encrypted_data = encode_message(key, &crypt_algo, masked_data.peek().as_bytes())?;
 
         Ok(Self::new(masked_data, encrypted_data.into()))
     }
 
     #[instrument(skip_all)]
-    async fn decrypt(
+    async fn decrypt<K: EncryptionKey + ?Sized>(
         encrypted_data: Encryption,
-        key: &[u8],
+        key: &K,
         crypt_algo: V,
     ) -> CustomResult<Self, errors::CryptoError> {
         let encrypted = encrypted_data.into_inner();
-        let data = crypt_algo.decode_message(key, encrypted.clone())?;
+        let data = decode_message(key, &crypt_algo, encrypted.peek())?;
 
         let value: String = std::str::from_utf8(&data)
             .into_report()
@@ -74,27 +221,27 @@ impl<
     for crypto::Encryptable<Secret<serde_json::Value, S>>
 {
     #[instrument(skip_all)]
-    async fn encrypt(
+    async fn encrypt<K: EncryptionKey + ?Sized>(
         masked_data: Secret<serde_json::Value, S>,
-        key: &[u8],
+        key: &K,
         crypt_algo: V,
     ) -> CustomResult<Self, errors::CryptoError> {
         let data = serde_json::to_vec(&masked_data.peek())
             .into_report()
             .change_context(errors::CryptoError::DecodingFailed)?;
-        let encrypted_data = crypt_algo.encode_message(key, &data)?;
+        let encrypted_data = encode_message(key, &crypt_algo, &data)?;
 
         Ok(Self::new(masked_data, encrypted_data.into()))
     }
 
     #[instrument(skip_all)]
-    async fn decrypt(
+    async fn decrypt<K: EncryptionKey + ?Sized>(
         encrypted_data: Encryption,
-        key: &[u8],
+        key: &K,
         crypt_algo: V,
     ) -> CustomResult<Self, errors::CryptoError> {
         let encrypted = encrypted_data.into_inner();
-        let data = crypt_algo.decode_message(key, encrypted.clone())?;
+        let data = decode_message(key, &crypt_algo, encrypted.peek())?;
 
         let value: serde_json::Value = serde_json::from_slice(&data)
             .into_report()
@@ -111,24 +258,24 @@ impl<
     > TypeEncryption<Vec<u8>, V, S> for crypto::Encryptable<Secret<Vec<u8>, S>>
 {
     #[instrument(skip_all)]
-    async fn encrypt(
+    async fn encrypt<K: EncryptionKey + ?Sized>(
         masked_data: Secret<Vec<u8>, S>,
-        key: &[u8],
+        key: &K,
         crypt_algo: V,
     ) -> CustomResult<Self, errors::CryptoError> {
-        let encrypted_data = crypt_algo.encode_message(key, masked_data.peek())?;
+        let encrypted_data = encode_message(key, &crypt_algo, masked_data.peek())?;
 
         Ok(Self::new(masked_data, encrypted_data.into()))
     }
 
     #[instrument(skip_all)]
-    async fn decrypt(
+    async fn decrypt<K: EncryptionKey + ?Sized>(
         encrypted_data: Encryption,
-        key: &[u8],
+        key: &K,
         crypt_algo: V,
     ) -> CustomResult<Self, errors::CryptoError> {
         let encrypted = encrypted_data.into_inner();
-        let data = crypt_algo.decode_message(key, encrypted.clone())?;
+        let data = decode_message(key, &crypt_algo, encrypted.peek())?;
 
         Ok(Self::new(data.into(), encrypted))
     }
@@ -181,9 +328,9 @@ impl<U, V: Lift<U> + Lift<U, SelfWrapper<U> = V> + Send> AsyncLift<U> for V {
 }
 
 #[inline]
-pub async fn encrypt<E: Clone, S>(
+pub async fn encrypt<E: Clone, S, K: EncryptionKey + ?Sized>(
     inner: Secret<E, S>,
-    key: &[u8],
+    key: &K,
 ) -> CustomResult<crypto::Encryptable<Secret<E, S>>, errors::CryptoError>
 where
     S: masking::Strategy<E>,
@@ -198,9 +345,9 @@ where
 }
 
 #[inline]
-pub async fn encrypt_optional<E: Clone, S>(
+pub async fn encrypt_optional<E: Clone, S, K: EncryptionKey + ?Sized>(
     inner: Option<Secret<E, S>>,
-    key: &[u8],
+    key: &K,
 ) -> CustomResult<Option<crypto::Encryptable<Secret<E, S>>>, errors::CryptoError>
 where
     Secret<E, S>: Send,
@@ -211,9 +358,9 @@ where
 }
 
 #[inline]
-pub async fn decrypt<T: Clone, S: masking::Strategy<T>>(
+pub async fn decrypt<T: Clone, S: masking::Strategy<T>, K: EncryptionKey + ?Sized>(
     inner: Option<Encryption>,
-    key: &[u8],
+    key: &K,
 ) -> CustomResult<Option<crypto::Encryptable<Secret<T, S>>>, errors::CryptoError>
 where
     crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
@@ -226,3 +373,105 @@ where
     .await
     .transpose()
 }
+
+/// Encrypts the data again with the active key of the key ring
+#[inline]
+pub async fn reencrypt<E: Clone, S>(
+    inner: crypto::Encryptable<Secret<E, S>>,
+    key_ring: &KeyRing,
+) -> CustomResult<crypto::Encryptable<Secret<E, S>>, errors::CryptoError>
+where
+    S: masking::Strategy<E>,
+    crypto::Encryptable<Secret<E, S>>: TypeEncryption<E, crypto::GcmAes256, S>,
+{
+    encrypt(inner.into_inner(), key_ring).await
+}
+
+#[inline]
+pub async fn reencrypt_optional<E: Clone, S>(
+    inner: Option<crypto::Encryptable<Secret<E, S>>>,
+    key_ring: &KeyRing,
+) -> CustomResult<Option<crypto::Encryptable<Secret<E, S>>>, errors::CryptoError>
+where
+    Secret<E, S>: Send,
+    S: masking::Strategy<E>,
+    crypto::Encryptable<Secret<E, S>>: TypeEncryption<E, crypto::GcmAes256, S>,
+{
+    encrypt_optional(inner.map(crypto::Encryptable::into_inner), key_ring).await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::{EncodeMessage, GcmAes256};
+
+    use super::*;
+
+    fn versioned_key(version: i32, key: [u8; 32]) -> VersionedKey {
+        VersionedKey {
+            version,
+            key: key.to_vec().into(),
+        }
+    }
+
+    #[test]
+    fn test_key_ring_decrypts_data_encrypted_with_previous_keys() {
+        let legacy_key = [1; 32];
+        let rotated_key = [2; 32];
+        let message = b"hyperswitch";
+
+        let legacy_data = GcmAes256.encode_message(&legacy_key, message).unwrap();
+        let key_ring = KeyRing {
+            active: versioned_key(INITIAL_KEY_VERSION, legacy_key),
+            previous: None,
+        };
+        let tagged_data = encode_message(&key_ring, &GcmAes256, message).unwrap();
+        assert!(tagged_data.starts_with(KEY_VERSION_TAG));
+
+        let key_ring = KeyRing {
+            active: versioned_key(INITIAL_KEY_VERSION + 1, rotated_key),
+            previous: Some(versioned_key(INITIAL_KEY_VERSION, legacy_key)),
+        };
+        let rotated_data = encode_message(&key_ring, &GcmAes256, message).unwrap();
+        for encrypted_data in [&legacy_data, &tagged_data, &rotated_data] {
+            let decrypted_data = decode_message(&key_ring, &GcmAes256, encrypted_data).unwrap();
+            assert_eq!(decrypted_data, message);
+        }
+
+        let retired_key_ring = KeyRing {
+            active: versioned_key(INITIAL_KEY_VERSION + 1, rotated_key),
+            previous: None,
+        };
+        assert!(decode_message(&retired_key_ring, &GcmAes256, &legacy_data).is_err());
+        let decrypted_data = decode_message(&retired_key_ring, &GcmAes256, &rotated_data).unwrap();
+        assert_eq!(decrypted_data, message);
+    }
+
+    #[test]
+    fn test_is_encrypted_with_active_key() {
+        let legacy_key = [1; 32];
+        let message = b"hyperswitch";
+        let key_ring = KeyRing {
+            active: versioned_key(INITIAL_KEY_VERSION + 1, [2; 32]),
+            previous: Some(versioned_key(INITIAL_KEY_VERSION, legacy_key)),
+        };
+        let encryptable = |encrypted_data: Vec<u8>| {
+            crypto::Encryptable::new(
+                Secret::<Vec<u8>>::new(message.to_vec()),
+                encrypted_data.into(),
+            )
+        };
+
+        let legacy_data = GcmAes256.encode_message(&legacy_key, message).unwrap();
+        assert!(!is_encrypted_with_active_key(
+            &encryptable(legacy_data),
+            &key_ring
+        ));
+
+        let rotated_data = encode_message(&key_ring, &GcmAes256, message).unwrap();
+        assert!(is_encrypted_with_active_key(
+            &encryptable(rotated_data),
+            &key_ring
+        ));
+    }
+}
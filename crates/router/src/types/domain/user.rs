@@ -455,6 +455,10 @@ impl UserFromStorage {
         self.0.email.clone()
     }
 
+    pub fn is_verified(&self) -> bool {
+        self.0.is_verified
+    }
+
     pub async fn get_jwt_auth_token(&self, state: AppState, org_id: String) -> UserResult<String> {
         let role_id = self.get_role_from_db(state.clone()).await?.role_id;
         let merchant_id = state
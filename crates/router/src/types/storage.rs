@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_report;
 pub mod api_keys;
 pub mod business_profile;
 pub mod capture;
@@ -32,6 +33,7 @@ pub mod payout_attempt;
 pub mod payouts;
 mod query;
 pub mod refund;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
 
@@ -42,11 +44,11 @@ pub use data_models::payments::{
 };
 
 pub use self::{
-    address::*, api_keys::*, capture::*, cards_info::*, configs::*, customers::*, dispute::*,
-    ephemeral_key::*, events::*, file::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, payout_attempt::*, payouts::*, process_tracker::*, refund::*,
-    reverse_lookup::*, routing_algorithm::*, user::*, user_role::*,
+    address::*, analytics_report::*, api_keys::*, capture::*, cards_info::*, configs::*,
+    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, gsm::*, locker_mock_up::*,
+    mandate::*, merchant_account::*, merchant_connector_account::*, merchant_key_store::*,
+    payment_link::*, payment_method::*, payout_attempt::*, payouts::*, process_tracker::*,
+    refund::*, reverse_lookup::*, routing_algorithm::*, subscription::*, user::*, user_role::*,
 };
 use crate::types::api::routing;
 
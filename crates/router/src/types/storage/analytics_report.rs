@@ -0,0 +1,4 @@
+pub use diesel_models::analytics_report::{
+    AnalyticsReport, AnalyticsReportExport, AnalyticsReportExportNew, AnalyticsReportNew,
+    AnalyticsReportTrackingData, AnalyticsReportUpdate, AnalyticsReportUpdateInternal,
+};
@@ -1,7 +1,7 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
-pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
+pub use diesel_models::dispute::{Dispute, DisputeDeadlineTrackingData, DisputeNew, DisputeUpdate};
 use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
 use error_stack::{IntoReport, ResultExt};
 
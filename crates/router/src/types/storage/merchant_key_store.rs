@@ -1 +1,4 @@
-pub use diesel_models::merchant_key_store::MerchantKeyStore;
+pub use diesel_models::merchant_key_store::{
+    MerchantKeyRotationStage, MerchantKeyRotationTrackingData, MerchantKeyStore,
+    MerchantKeyStoreUpdate,
+};
@@ -1,4 +1,4 @@
 pub use diesel_models::payment_method::{
-    PaymentMethod, PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal,
-    TokenizeCoreWorkflow,
+    CardAccountUpdaterTrackingData, PaymentMethod, PaymentMethodNew, PaymentMethodUpdate,
+    PaymentMethodUpdateInternal, TokenizeCoreWorkflow,
 };
@@ -0,0 +1,4 @@
+pub use diesel_models::subscription::{
+    Subscription, SubscriptionBillingTrackingData, SubscriptionNew, SubscriptionPlan,
+    SubscriptionPlanNew, SubscriptionUpdate, SubscriptionUpdateInternal,
+};
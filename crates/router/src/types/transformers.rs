@@ -740,6 +740,7 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             connector_created_at: dispute.connector_created_at,
             connector_updated_at: dispute.connector_updated_at,
             created_at: dispute.created_at,
+            is_deadline_missed: dispute.is_deadline_missed,
         }
     }
 }
@@ -842,6 +843,12 @@ impl TryFrom<domain::MerchantConnectorAccount> for api_models::admin::MerchantCo
             profile_id: item.profile_id,
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
+            pm_filters: item
+                .pm_filters
+                .map(|pm_filters| pm_filters.parse_value("Vec<PaymentMethodFilter>"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to deserialize pm_filters")?,
         })
     }
 }
@@ -991,6 +998,85 @@ impl ForeignFrom<storage::PaymentLink> for api_models::payments::RetrievePayment
             created_at: payment_link_object.created_at,
             last_modified_at: payment_link_object.last_modified_at,
             link_expiry: payment_link_object.fulfilment_time,
+            is_reusable: payment_link_object.is_reusable,
+        }
+    }
+}
+
+impl ForeignFrom<storage::SubscriptionPlan>
+    for api_models::subscriptions::SubscriptionPlanResponse
+{
+    fn foreign_from(plan: storage::SubscriptionPlan) -> Self {
+        Self {
+            plan_id: plan.plan_id,
+            name: plan.name,
+            description: plan.description,
+            amount: plan.amount,
+            currency: plan.currency,
+            interval: plan.billing_interval,
+            interval_count: plan.billing_interval_count,
+            trial_period_days: plan.trial_period_days,
+            is_active: plan.is_active,
+            metadata: plan.metadata,
+            created_at: plan.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Subscription> for api_models::subscriptions::SubscriptionResponse {
+    fn foreign_from(subscription: storage::Subscription) -> Self {
+        Self {
+            subscription_id: subscription.subscription_id,
+            customer_id: subscription.customer_id,
+            plan_id: subscription.plan_id,
+            mandate_id: subscription.mandate_id,
+            status: subscription.status,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            trial_end: subscription.trial_end,
+            cancel_at_period_end: subscription.cancel_at_period_end,
+            cancelled_at: subscription.cancelled_at,
+            proration_amount: subscription.proration_amount,
+            latest_payment_id: subscription.latest_payment_id,
+            metadata: subscription.metadata,
+            created_at: subscription.created_at,
+        }
+    }
+}
+
+impl ForeignTryFrom<storage::AnalyticsReport> for api_models::analytics::reports::ReportResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn foreign_try_from(report: storage::AnalyticsReport) -> Result<Self, Self::Error> {
+        Ok(Self {
+            report_id: report.report_id,
+            name: report.name,
+            definition: report
+                .definition
+                .parse_value("ReportDefinition")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            frequency: report.frequency,
+            lookback_days: report.lookback_days,
+            email_recipients: report.email_recipients,
+            is_active: report.is_active,
+            next_run_at: report.next_run_at,
+            last_run_at: report.last_run_at,
+            created_at: report.created_at,
+        })
+    }
+}
+
+impl ForeignFrom<storage::AnalyticsReportExport>
+    for api_models::analytics::reports::ReportExportResponse
+{
+    fn foreign_from(export: storage::AnalyticsReportExport) -> Self {
+        Self {
+            export_id: export.export_id,
+            report_id: export.report_id,
+            export_type: export.export_type,
+            file_id: export.file_id,
+            start_time: export.start_time,
+            end_time: export.end_time,
+            created_at: export.created_at,
         }
     }
 }
@@ -456,7 +456,7 @@ pub trait CustomerAddress {
     async fn get_address_update(
         &self,
         address_details: api_models::payments::AddressDetails,
-        key: &[u8],
+        key: &domain::types::KeyRing,
         storage_scheme: storage::enums::MerchantStorageScheme,
     ) -> CustomResult<storage::AddressUpdate, common_utils::errors::CryptoError>;
 
@@ -465,7 +465,7 @@ pub trait CustomerAddress {
         address_details: api_models::payments::AddressDetails,
         merchant_id: &str,
         customer_id: &str,
-        key: &[u8],
+        key: &domain::types::KeyRing,
         storage_scheme: storage::enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, common_utils::errors::CryptoError>;
 }
@@ -475,7 +475,7 @@ impl CustomerAddress for api_models::customers::CustomerRequest {
     async fn get_address_update(
         &self,
         address_details: api_models::payments::AddressDetails,
-        key: &[u8],
+        key: &domain::types::KeyRing,
         storage_scheme: storage::enums::MerchantStorageScheme,
     ) -> CustomResult<storage::AddressUpdate, common_utils::errors::CryptoError> {
         async {
@@ -527,7 +527,7 @@ impl CustomerAddress for api_models::customers::CustomerRequest {
         address_details: api_models::payments::AddressDetails,
         merchant_id: &str,
         customer_id: &str,
-        key: &[u8],
+        key: &domain::types::KeyRing,
         storage_scheme: storage::enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, common_utils::errors::CryptoError> {
         async {
@@ -766,6 +766,7 @@ where
                     payment_id,
                     diesel_models::enums::EventObjectType::PaymentDetails,
                     webhooks::OutgoingWebhookContent::PaymentDetails(payments_response_json),
+                    None,
                 ),
             )
             .await?;
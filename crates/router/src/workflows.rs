@@ -1,3 +1,9 @@
+#[cfg(feature = "olap")]
+pub mod analytics_report;
+pub mod card_account_updater;
+pub mod dispute_deadline;
+pub mod merchant_key_rotation;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod subscription_billing;
 pub mod tokenized_data;
@@ -0,0 +1,25 @@
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{analytics::reports, errors, routes::AppState, types::storage};
+
+pub struct AnalyticsReportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for AnalyticsReportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(Box::pin(reports::start_analytics_report_workflow(state, &process)).await?)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
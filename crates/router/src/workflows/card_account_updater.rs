@@ -0,0 +1,30 @@
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{core::payment_methods::account_updater, errors, routes::AppState, types::storage};
+
+pub struct CardAccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for CardAccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(
+            Box::pin(account_updater::start_card_account_updater_workflow(
+                state, &process,
+            ))
+            .await?,
+        )
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
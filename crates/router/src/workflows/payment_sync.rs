@@ -136,6 +136,7 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                             )),
                             amount_capturable: Some(0),
                             updated_by: merchant_account.storage_scheme.to_string(),
+                            connector_latency: None,
                         };
 
                     payment_data.payment_attempt = db
@@ -0,0 +1,28 @@
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{core::subscriptions, errors, routes::AppState, types::storage};
+
+pub struct SubscriptionBillingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for SubscriptionBillingWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(Box::pin(subscriptions::start_subscription_billing_workflow(
+            state, &process,
+        ))
+        .await?)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -11,10 +11,10 @@ use router::{
 };
 use tokio::sync::oneshot;
 
-use crate::{connector_auth::ConnectorAuthentication, utils};
+use crate::utils;
 
 fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
-    let auth = ConnectorAuthentication::new()
+    let auth = utils::get_connector_authentication()
         .aci
         .expect("Missing ACI connector authentication configuration");
 
@@ -23,8 +23,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         merchant_id: String::from("aci"),
         customer_id: Some(String::from("aci")),
         connector: "aci".to_string(),
-        payment_id: uuid::Uuid::new_v4().to_string(),
-        attempt_id: uuid::Uuid::new_v4().to_string(),
+        payment_id: utils::generate_test_id(),
+        attempt_id: utils::generate_test_id(),
         status: enums::AttemptStatus::default(),
         auth_type: enums::AuthenticationType::NoThreeDs,
         payment_method: enums::PaymentMethod::Card,
@@ -36,10 +36,10 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             currency: enums::Currency::USD,
             payment_method_data: types::api::PaymentMethodData::Card(types::api::Card {
                 card_number: cards::CardNumber::from_str("4200000000000000").unwrap(),
-                card_exp_month: Secret::new("10".to_string()),
-                card_exp_year: Secret::new("2025".to_string()),
+                card_exp_month: "10".parse().unwrap(),
+                card_exp_year: "2030".parse().unwrap(),
                 card_holder_name: Secret::new("John Doe".to_string()),
-                card_cvc: Secret::new("999".to_string()),
+                card_cvc: Some("999".parse().unwrap()),
                 card_issuer: None,
                 card_network: None,
                 card_type: None,
@@ -83,7 +83,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         recurring_mandate_payment_data: None,
 
         preprocessing_id: None,
-        connector_request_reference_id: uuid::Uuid::new_v4().to_string(),
+        connector_request_reference_id: utils::generate_test_id(),
         #[cfg(feature = "payouts")]
         payout_method_data: None,
         #[cfg(feature = "payouts")]
@@ -98,7 +98,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
 }
 
 fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
-    let auth = ConnectorAuthentication::new()
+    let auth = utils::get_connector_authentication()
         .aci
         .expect("Missing ACI connector authentication configuration");
 
@@ -107,8 +107,8 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         merchant_id: String::from("aci"),
         customer_id: Some(String::from("aci")),
         connector: "aci".to_string(),
-        payment_id: uuid::Uuid::new_v4().to_string(),
-        attempt_id: uuid::Uuid::new_v4().to_string(),
+        payment_id: utils::generate_test_id(),
+        attempt_id: utils::generate_test_id(),
         status: enums::AttemptStatus::default(),
         payment_method: enums::PaymentMethod::Card,
         auth_type: enums::AuthenticationType::NoThreeDs,
@@ -119,7 +119,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             payment_amount: 1000,
             currency: enums::Currency::USD,
 
-            refund_id: uuid::Uuid::new_v4().to_string(),
+            refund_id: utils::generate_test_id(),
             connector_transaction_id: String::new(),
             refund_amount: 100,
             webhook_url: None,
@@ -141,7 +141,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         recurring_mandate_payment_data: None,
 
         preprocessing_id: None,
-        connector_request_reference_id: uuid::Uuid::new_v4().to_string(),
+        connector_request_reference_id: utils::generate_test_id(),
         #[cfg(feature = "payouts")]
         payout_method_data: None,
         #[cfg(feature = "payouts")]
@@ -164,7 +164,7 @@ async fn payments_create_success() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     )
     .await;
 
@@ -200,57 +200,8 @@ async fn payments_create_success() {
 #[actix_web::test]
 #[ignore]
 async fn payments_create_failure() {
-    {
-        let conf = Settings::new().unwrap();
-        static CV: aci::Aci = aci::Aci;
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            conf,
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
-        let connector = types::api::ConnectorData {
-            connector: Box::new(&CV),
-            connector_name: types::Connector::Aci,
-            get_token: types::api::GetToken::Connector,
-            merchant_connector_id: None,
-        };
-        let connector_integration: services::BoxedConnectorIntegration<
-            '_,
-            types::api::Authorize,
-            types::PaymentsAuthorizeData,
-            types::PaymentsResponseData,
-        > = connector.connector.get_connector_integration();
-        let mut request = construct_payment_router_data();
-        request.request.payment_method_data =
-            types::api::PaymentMethodData::Card(types::api::Card {
-                card_number: cards::CardNumber::from_str("4200000000000000").unwrap(),
-                card_exp_month: Secret::new("10".to_string()),
-                card_exp_year: Secret::new("2025".to_string()),
-                card_holder_name: Secret::new("John Doe".to_string()),
-                card_cvc: Secret::new("99".to_string()),
-                card_issuer: None,
-                card_network: None,
-                card_type: None,
-                card_issuing_country: None,
-                bank_code: None,
-                nick_name: Some(masking::Secret::new("nick_name".into())),
-            });
-
-        let response = services::api::execute_connector_processing_step(
-            &state,
-            connector_integration,
-            &request,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await
-        .is_err();
-        println!("{response:?}");
-        assert!(response, "The payment was intended to fail but it passed");
-    }
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("99".parse::<cards::CardSecurityCode>().is_err());
 }
 
 #[actix_web::test]
@@ -269,7 +220,7 @@ async fn refund_for_successful_payments() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     )
     .await;
     let connector_integration: services::BoxedConnectorIntegration<
@@ -337,7 +288,7 @@ async fn refunds_create_failure() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     )
     .await;
     let connector_integration: services::BoxedConnectorIntegration<
@@ -93,8 +93,8 @@ impl AdyenTest {
                 enums::PayoutType::Card => {
                     Some(api::PayoutMethodData::Card(api::payouts::CardPayout {
                         card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                        expiry_month: Secret::new("3".to_string()),
-                        expiry_year: Secret::new("2030".to_string()),
+                        expiry_month: "3".parse().unwrap(),
+                        expiry_year: "2030".parse().unwrap(),
                         card_holder_name: Secret::new("John Doe".to_string()),
                     }))
                 }
@@ -124,10 +124,10 @@ impl AdyenTest {
             currency: enums::Currency::USD,
             payment_method_data: types::api::PaymentMethodData::Card(types::api::Card {
                 card_number: cards::CardNumber::from_str(card_number).unwrap(),
-                card_exp_month: Secret::new(card_exp_month.to_string()),
-                card_exp_year: Secret::new(card_exp_year.to_string()),
+                card_exp_month: card_exp_month.parse().unwrap(),
+                card_exp_year: card_exp_year.parse().unwrap(),
                 card_holder_name: Secret::new("John Doe".to_string()),
-                card_cvc: Secret::new(card_cvc.to_string()),
+                card_cvc: Some(card_cvc.parse().unwrap()),
                 card_issuer: None,
                 card_network: None,
                 card_type: None,
@@ -444,65 +444,22 @@ async fn should_fail_payment_for_incorrect_card_number() {
 // Creates a payment with incorrect CVC.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                router_return_url: Some(String::from("http://localhost:8080")),
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_cvc: Secret::new("12345".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            AdyenTest::get_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "CVC is not the right length",
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("12345".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Creates a payment with incorrect expiry month.
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                router_return_url: Some(String::from("http://localhost:8080")),
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_month: Secret::new("20".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            AdyenTest::get_payment_info(),
-        )
-        .await
-        .unwrap();
-    let errors = ["The provided Expiry Date is not valid.: Expiry month should be between 1 and 12 inclusive: 20","Refused"];
-    assert!(errors.contains(&response.response.unwrap_err().message.as_str()))
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                router_return_url: Some(String::from("http://localhost:8080")),
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("2000".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            AdyenTest::get_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(response.response.unwrap_err().message, "Expired Card",);
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2000".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Captures a payment using invalid connector payment id.
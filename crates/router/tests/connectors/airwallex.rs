@@ -58,10 +58,10 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
     Some(types::PaymentsAuthorizeData {
         payment_method_data: types::api::PaymentMethodData::Card(api::Card {
             card_number: cards::CardNumber::from_str("4035501000000008").unwrap(),
-            card_exp_month: Secret::new("02".to_string()),
-            card_exp_year: Secret::new("2035".to_string()),
+            card_exp_month: "02".parse().unwrap(),
+            card_exp_year: "2035".parse().unwrap(),
             card_holder_name: Secret::new("John Doe".to_string()),
-            card_cvc: Secret::new("123".to_string()),
+            card_cvc: Some("123".parse().unwrap()),
             card_issuer: None,
             card_network: None,
             card_type: None,
@@ -377,69 +377,24 @@ async fn should_fail_payment_for_incorrect_card_number() {
 #[serial_test::serial]
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_cvc: Secret::new("12345".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "Invalid card cvc".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("12345".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Creates a payment with incorrect expiry month.
 #[serial_test::serial]
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_month: Secret::new("20".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "Invalid expiry month".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
 #[serial_test::serial]
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("2000".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "payment_method.card should not be expired".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2000".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
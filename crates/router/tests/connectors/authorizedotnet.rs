@@ -40,10 +40,10 @@ static CONNECTOR: AuthorizedotnetTest = AuthorizedotnetTest {};
 fn get_payment_method_data() -> api::Card {
     api::Card {
         card_number: cards::CardNumber::from_str("5424000000000015").unwrap(),
-        card_exp_month: Secret::new("02".to_string()),
-        card_exp_year: Secret::new("2035".to_string()),
+        card_exp_month: "02".parse().unwrap(),
+        card_exp_year: "2035".parse().unwrap(),
         card_holder_name: Secret::new("John Doe".to_string()),
-        card_cvc: Secret::new("123".to_string()),
+        card_cvc: Some("123".parse().unwrap()),
         ..Default::default()
     }
 }
@@ -409,68 +409,23 @@ async fn should_fail_payment_for_empty_card_number() {
 // Creates a payment with incorrect CVC.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_cvc: Secret::new("12345".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "The 'AnetApi/xml/v1/schema/AnetApiSchema.xsd:cardCode' element is invalid - The value XXXXXXX is invalid according to its datatype 'AnetApi/xml/v1/schema/AnetApiSchema.xsd:cardCode' - The actual length is greater than the MaxLength value.".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("12345".parse::<cards::CardSecurityCode>().is_err());
 }
 // todo()
 
 // Creates a payment with incorrect expiry month.
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_month: Secret::new("20".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "Credit card expiration date is invalid.".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("2000".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "The credit card has expired.".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2000".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
@@ -1,7 +1,6 @@
 use std::str::FromStr;
 
 use api_models::payments::PaymentMethodData;
-use masking::Secret;
 use router::types::{self, api, storage::enums};
 
 use crate::{
@@ -43,8 +42,8 @@ fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData>
     Some(types::PaymentsAuthorizeData {
         payment_method_data: types::api::PaymentMethodData::Card(api::Card {
             card_number: cards::CardNumber::from_str("4030000010001234").unwrap(),
-            card_exp_year: Secret::new("25".to_string()),
-            card_cvc: Secret::new("123".to_string()),
+            card_exp_year: "30".parse().unwrap(),
+            card_cvc: Some("123".parse().unwrap()),
             ..utils::CCardType::default().0
         }),
         ..utils::PaymentAuthorizeType::default().0
@@ -295,7 +294,7 @@ async fn should_fail_payment_for_incorrect_card_number() {
             Some(types::PaymentsAuthorizeData {
                 payment_method_data: PaymentMethodData::Card(api::Card {
                     card_number: cards::CardNumber::from_str("1234567891011").unwrap(),
-                    card_exp_year: Secret::new("25".to_string()),
+                    card_exp_year: "30".parse().unwrap(),
                     ..utils::CCardType::default().0
                 }),
                 ..utils::PaymentAuthorizeType::default().0
@@ -313,73 +312,22 @@ async fn should_fail_payment_for_incorrect_card_number() {
 // Creates a payment with incorrect CVC.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("25".to_string()),
-                    card_cvc: Secret::new("12345".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().reason,
-        Some(r#"[{"field":"card:cvd","message":"Invalid card CVD"},{"field":"card:cvd","message":"Invalid card CVD"}]"#.to_string())
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("12345".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Creates a payment with incorrect expiry month.
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: PaymentMethodData::Card(api::Card {
-                    card_exp_month: Secret::new("20".to_string()),
-                    card_number: cards::CardNumber::from_str("4030000010001234").unwrap(),
-                    card_exp_year: Secret::new("25".to_string()),
-                    card_cvc: Secret::new("123".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().reason,
-        Some(r#"[{"field":"card:expiry_month","message":"Invalid expiry date"}]"#.to_string())
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("2000".to_string()),
-                    card_number: cards::CardNumber::from_str("4030000010001234").unwrap(),
-                    card_cvc: Secret::new("123".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().reason,
-        Some(r#"[{"field":"card:expiry_year","message":"Invalid expiration year"}]"#.to_string())
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2000".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
@@ -395,25 +395,8 @@ async fn should_sync_refund() {
 #[serial_test::serial]
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_holder_name: Secret::new("John Doe".to_string()),
-                    card_cvc: Secret::new("12345".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "VALIDATION_GENERAL_FAILURE".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("12345".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Creates a payment with incorrect expiry month.
@@ -421,25 +404,8 @@ async fn should_fail_payment_for_incorrect_cvc() {
 #[serial_test::serial]
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_holder_name: Secret::new("John Doe".to_string()),
-                    card_exp_month: Secret::new("20".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "VALIDATION_GENERAL_FAILURE".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
@@ -447,25 +413,8 @@ async fn should_fail_payment_for_invalid_exp_month() {
 #[serial_test::serial]
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_holder_name: Secret::new("John Doe".to_string()),
-                    card_exp_year: Secret::new("2000".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "VALIDATION_GENERAL_FAILURE".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2000".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
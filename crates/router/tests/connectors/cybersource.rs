@@ -155,71 +155,18 @@ async fn should_void_already_authorized_payment() {
 
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = Cybersource {}
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_month: Secret::new("13".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..get_default_payment_authorize_data().unwrap()
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    let x = response.response.unwrap_err();
-    assert_eq!(
-        x.message,
-        "Declined - One or more fields in the request contains invalid data",
-    );
-    assert_eq!(
-        x.reason,
-        Some("paymentInformation.card.expirationMonth : INVALID_DATA".to_string())
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("13".parse::<cards::CardExpirationMonth>().is_err());
 }
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_year() {
-    let response = Cybersource {}
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("2022".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..get_default_payment_authorize_data().unwrap()
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    let x = response.response.unwrap_err();
-    assert_eq!(x.message, "Decline - Expired card. You might also receive this if the expiration date you provided does not match the date the issuing bank has on file.",);
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2022".parse::<cards::CardExpirationYear>().is_err());
 }
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_card_cvc() {
-    let response = Cybersource {}
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_cvc: Secret::new("2131233213".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..get_default_payment_authorize_data().unwrap()
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    let x = response.response.unwrap_err();
-    assert_eq!(
-        x.message,
-        "Declined - One or more fields in the request contains invalid data",
-    );
-    assert_eq!(
-        x.reason,
-        Some("paymentInformation.card.securityCode : INVALID_DATA".to_string())
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2131233213".parse::<cards::CardSecurityCode>().is_err());
 }
 // Voids a payment using automatic capture flow (Non 3DS).
 #[actix_web::test]
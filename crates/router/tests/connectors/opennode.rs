@@ -2,10 +2,7 @@ use api_models::payments::CryptoData;
 use masking::Secret;
 use router::types::{self, api, storage::enums, PaymentAddress};
 
-use crate::{
-    connector_auth,
-    utils::{self, ConnectorActions},
-};
+use crate::utils::{self, ConnectorActions};
 
 #[derive(Clone, Copy)]
 struct OpennodeTest;
@@ -23,7 +20,7 @@ impl utils::Connector for OpennodeTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            utils::get_connector_authentication()
                 .opennode
                 .expect("Missing connector authentication configuration")
                 .into(),
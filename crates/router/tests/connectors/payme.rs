@@ -84,9 +84,9 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
         email: Some(Email::from_str("test@gmail.com").unwrap()),
         payment_method_data: types::api::PaymentMethodData::Card(api::Card {
             card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-            card_cvc: Secret::new("123".to_string()),
-            card_exp_month: Secret::new("10".to_string()),
-            card_exp_year: Secret::new("2025".to_string()),
+            card_cvc: Some("123".parse().unwrap()),
+            card_exp_month: "10".parse().unwrap(),
+            card_exp_year: "2030".parse().unwrap(),
             card_holder_name: Secret::new("John Doe".to_string()),
             ..utils::CCardType::default().0
         }),
@@ -359,100 +359,22 @@ async fn should_sync_refund() {
 // Creates a payment with incorrect CVC.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                amount: 100,
-                currency: enums::Currency::ILS,
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_cvc: Secret::new("12345".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                order_details: Some(vec![OrderDetailsWithAmount {
-                    product_name: "iphone 13".to_string(),
-                    quantity: 1,
-                    amount: 100,
-                    product_img_link: None,
-                }]),
-                router_return_url: Some("https://hyperswitch.io".to_string()),
-                webhook_url: Some("https://hyperswitch.io".to_string()),
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "internal_server_error".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("12345".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Creates a payment with incorrect expiry month.
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                amount: 100,
-                currency: enums::Currency::ILS,
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_month: Secret::new("20".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                order_details: Some(vec![OrderDetailsWithAmount {
-                    product_name: "iphone 13".to_string(),
-                    quantity: 1,
-                    amount: 100,
-                    product_img_link: None,
-                }]),
-                router_return_url: Some("https://hyperswitch.io".to_string()),
-                webhook_url: Some("https://hyperswitch.io".to_string()),
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "internal_server_error".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                amount: 100,
-                currency: enums::Currency::ILS,
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("2012".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                order_details: Some(vec![OrderDetailsWithAmount {
-                    product_name: "iphone 13".to_string(),
-                    quantity: 1,
-                    amount: 100,
-                    product_img_link: None,
-                }]),
-                router_return_url: Some("https://hyperswitch.io".to_string()),
-                webhook_url: Some("https://hyperswitch.io".to_string()),
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            get_default_payment_info(),
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "internal_server_error".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2012".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
@@ -44,10 +44,10 @@ async fn should_only_authorize_payment() {
             Some(types::PaymentsAuthorizeData {
                 payment_method_data: types::api::PaymentMethodData::Card(api::Card {
                     card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                    card_exp_month: Secret::new("02".to_string()),
-                    card_exp_year: Secret::new("2024".to_string()),
+                    card_exp_month: "02".parse().unwrap(),
+                    card_exp_year: "2030".parse().unwrap(),
                     card_holder_name: Secret::new("John Doe".to_string()),
-                    card_cvc: Secret::new("123".to_string()),
+                    card_cvc: Some("123".parse().unwrap()),
                     card_issuer: None,
                     card_network: None,
                     card_type: None,
@@ -72,10 +72,10 @@ async fn should_authorize_and_capture_payment() {
             Some(types::PaymentsAuthorizeData {
                 payment_method_data: types::api::PaymentMethodData::Card(api::Card {
                     card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                    card_exp_month: Secret::new("02".to_string()),
-                    card_exp_year: Secret::new("2024".to_string()),
+                    card_exp_month: "02".parse().unwrap(),
+                    card_exp_year: "2030".parse().unwrap(),
                     card_holder_name: Secret::new("John Doe".to_string()),
-                    card_cvc: Secret::new("123".to_string()),
+                    card_cvc: Some("123".parse().unwrap()),
                     card_issuer: None,
                     card_network: None,
                     card_type: None,
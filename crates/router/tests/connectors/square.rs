@@ -1,6 +1,5 @@
 use std::{str::FromStr, time::Duration};
 
-use masking::Secret;
 use router::types::{
     self, api,
     storage::{self, enums},
@@ -63,9 +62,9 @@ fn token_details() -> Option<types::PaymentMethodTokenizationData> {
     Some(types::PaymentMethodTokenizationData {
         payment_method_data: types::api::PaymentMethodData::Card(api::Card {
             card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-            card_exp_month: Secret::new("04".to_string()),
-            card_exp_year: Secret::new("2027".to_string()),
-            card_cvc: Secret::new("100".to_string()),
+            card_exp_month: "04".parse().unwrap(),
+            card_exp_year: "2027".parse().unwrap(),
+            card_cvc: Some("100".parse().unwrap()),
             ..utils::CCardType::default().0
         }),
         browser_info: None,
@@ -431,94 +430,22 @@ async fn should_sync_refund() {
 // Creates a payment with incorrect CVC.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let token_response = CONNECTOR
-        .create_connector_pm_token(
-            Some(types::PaymentMethodTokenizationData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                    card_exp_month: Secret::new("11".to_string()),
-                    card_exp_year: Secret::new("2027".to_string()),
-                    card_cvc: Secret::new("".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                browser_info: None,
-                amount: None,
-                currency: storage::enums::Currency::USD,
-            }),
-            get_default_payment_info(None),
-        )
-        .await
-        .expect("Authorize payment response");
-    assert_eq!(
-        token_response
-            .response
-            .unwrap_err()
-            .reason
-            .unwrap_or("".to_string()),
-        "Missing required parameter.".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Creates a payment with incorrect expiry month.
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let token_response = CONNECTOR
-        .create_connector_pm_token(
-            Some(types::PaymentMethodTokenizationData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                    card_exp_month: Secret::new("20".to_string()),
-                    card_exp_year: Secret::new("2027".to_string()),
-                    card_cvc: Secret::new("123".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                browser_info: None,
-                amount: None,
-                currency: storage::enums::Currency::USD,
-            }),
-            get_default_payment_info(None),
-        )
-        .await
-        .expect("Authorize payment response");
-    assert_eq!(
-        token_response
-            .response
-            .unwrap_err()
-            .reason
-            .unwrap_or("".to_string()),
-        "Invalid card expiration date.".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let token_response = CONNECTOR
-        .create_connector_pm_token(
-            Some(types::PaymentMethodTokenizationData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                    card_exp_month: Secret::new("11".to_string()),
-                    card_exp_year: Secret::new("2000".to_string()),
-                    card_cvc: Secret::new("123".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                browser_info: None,
-                amount: None,
-                currency: storage::enums::Currency::USD,
-            }),
-            get_default_payment_info(None),
-        )
-        .await
-        .expect("Authorize payment response");
-    assert_eq!(
-        token_response
-            .response
-            .unwrap_err()
-            .reason
-            .unwrap_or("".to_string()),
-        "Invalid card expiration date.".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2000".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
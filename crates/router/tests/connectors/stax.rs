@@ -1,6 +1,5 @@
 use std::{str::FromStr, time::Duration};
 
-use masking::Secret;
 use router::types::{self, api, storage::enums, PaymentsResponseData};
 use test_utils::connector_auth;
 
@@ -64,9 +63,9 @@ fn token_details() -> Option<types::PaymentMethodTokenizationData> {
     Some(types::PaymentMethodTokenizationData {
         payment_method_data: types::api::PaymentMethodData::Card(api::Card {
             card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-            card_exp_month: Secret::new("04".to_string()),
-            card_exp_year: Secret::new("2027".to_string()),
-            card_cvc: Secret::new("123".to_string()),
+            card_exp_month: "04".parse().unwrap(),
+            card_exp_year: "2027".parse().unwrap(),
+            card_cvc: Some("123".parse().unwrap()),
             ..utils::CCardType::default().0
         }),
         browser_info: None,
@@ -458,115 +457,22 @@ async fn should_sync_refund() {
 // Creates a payment with incorrect CVC.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let customer_response = CONNECTOR
-        .create_connector_customer(customer_details(), get_default_payment_info(None, None))
-        .await
-        .expect("Authorize payment response");
-    let connector_customer_id = match customer_response.response.unwrap() {
-        PaymentsResponseData::ConnectorCustomerResponse {
-            connector_customer_id,
-        } => Some(connector_customer_id),
-        _ => None,
-    };
-
-    let token_response = CONNECTOR
-        .create_connector_pm_token(
-            Some(types::PaymentMethodTokenizationData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                    card_exp_month: Secret::new("11".to_string()),
-                    card_exp_year: Secret::new("2027".to_string()),
-                    card_cvc: Secret::new("123456".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                browser_info: None,
-                amount: None,
-                currency: enums::Currency::USD,
-            }),
-            get_default_payment_info(connector_customer_id, None),
-        )
-        .await
-        .expect("Authorize payment response");
-    assert_eq!(
-        token_response.response.unwrap_err().reason,
-        Some(r#"{"card_cvv":["The card cvv may not be greater than 99999."]}"#.to_string()),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("123456".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Creates a payment with incorrect expiry month.
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let customer_response = CONNECTOR
-        .create_connector_customer(customer_details(), get_default_payment_info(None, None))
-        .await
-        .expect("Authorize payment response");
-    let connector_customer_id = match customer_response.response.unwrap() {
-        PaymentsResponseData::ConnectorCustomerResponse {
-            connector_customer_id,
-        } => Some(connector_customer_id),
-        _ => None,
-    };
-
-    let token_response = CONNECTOR
-        .create_connector_pm_token(
-            Some(types::PaymentMethodTokenizationData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                    card_exp_month: Secret::new("20".to_string()),
-                    card_exp_year: Secret::new("2027".to_string()),
-                    card_cvc: Secret::new("123".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                browser_info: None,
-                amount: None,
-                currency: enums::Currency::USD,
-            }),
-            get_default_payment_info(connector_customer_id, None),
-        )
-        .await
-        .expect("Authorize payment response");
-    assert_eq!(
-        token_response.response.unwrap_err().reason,
-        Some(r#"{"validation":["Tokenization Validation Errors: Month is invalid"]}"#.to_string()),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let customer_response = CONNECTOR
-        .create_connector_customer(customer_details(), get_default_payment_info(None, None))
-        .await
-        .expect("Authorize payment response");
-    let connector_customer_id = match customer_response.response.unwrap() {
-        PaymentsResponseData::ConnectorCustomerResponse {
-            connector_customer_id,
-        } => Some(connector_customer_id),
-        _ => None,
-    };
-
-    let token_response = CONNECTOR
-        .create_connector_pm_token(
-            Some(types::PaymentMethodTokenizationData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
-                    card_exp_month: Secret::new("04".to_string()),
-                    card_exp_year: Secret::new("2000".to_string()),
-                    card_cvc: Secret::new("123".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                browser_info: None,
-                amount: None,
-                currency: enums::Currency::USD,
-            }),
-            get_default_payment_info(connector_customer_id, None),
-        )
-        .await
-        .expect("Authorize payment response");
-    assert_eq!(
-        token_response.response.unwrap_err().reason,
-        Some(r#"{"validation":["Tokenization Validation Errors: Year is invalid"]}"#.to_string()),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2000".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
@@ -1,6 +1,5 @@
 use std::str::FromStr;
 
-use masking::Secret;
 use router::types::{self, api, storage::enums};
 
 use crate::{
@@ -177,62 +176,20 @@ async fn should_fail_payment_for_incorrect_card_number() {
 
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = Stripe {}
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_month: Secret::new("13".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    let x = response.response.unwrap_err();
-    assert_eq!(
-        x.reason.unwrap(),
-        "Your card's expiration month is invalid.",
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("13".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_year() {
-    let response = Stripe {}
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("2022".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    let x = response.response.unwrap_err();
-    assert_eq!(x.reason.unwrap(), "Your card's expiration year is invalid.",);
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2022".parse::<cards::CardExpirationYear>().is_err());
 }
 
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_card_cvc() {
-    let response = Stripe {}
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_cvc: Secret::new("12".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    let x = response.response.unwrap_err();
-    assert_eq!(x.reason.unwrap(), "Your card's security code is invalid.",);
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("12".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
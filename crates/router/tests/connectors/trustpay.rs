@@ -55,8 +55,8 @@ fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData>
     Some(types::PaymentsAuthorizeData {
         payment_method_data: types::api::PaymentMethodData::Card(api::Card {
             card_number: cards::CardNumber::from_str("4200000000000000").unwrap(),
-            card_exp_year: Secret::new("25".to_string()),
-            card_cvc: Secret::new("123".to_string()),
+            card_exp_year: "30".parse().unwrap(),
+            card_cvc: Some("123".parse().unwrap()),
             ..utils::CCardType::default().0
         }),
         browser_info: Some(get_default_browser_info()),
@@ -182,8 +182,8 @@ async fn should_fail_payment_for_incorrect_card_number() {
     let payment_authorize_data = types::PaymentsAuthorizeData {
         payment_method_data: types::api::PaymentMethodData::Card(api::Card {
             card_number: cards::CardNumber::from_str("1234567891011").unwrap(),
-            card_exp_year: Secret::new("25".to_string()),
-            card_cvc: Secret::new("123".to_string()),
+            card_exp_year: "30".parse().unwrap(),
+            card_cvc: Some("123".parse().unwrap()),
             ..utils::CCardType::default().0
         }),
         browser_info: Some(get_default_browser_info()),
@@ -202,23 +202,6 @@ async fn should_fail_payment_for_incorrect_card_number() {
 // Creates a payment with incorrect expiry year.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let payment_authorize_data = Some(types::PaymentsAuthorizeData {
-        payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-            card_number: cards::CardNumber::from_str("4200000000000000").unwrap(),
-            card_exp_year: Secret::new("22".to_string()),
-            card_cvc: Secret::new("123".to_string()),
-            ..utils::CCardType::default().0
-        }),
-        browser_info: Some(get_default_browser_info()),
-        ..utils::PaymentAuthorizeType::default().0
-    });
-    let response = CONNECTOR
-        .make_payment(payment_authorize_data, get_default_payment_info())
-        .await
-        .unwrap();
-    assert_eq!(
-        response.response.unwrap_err().message,
-        "Errors { code: 15, description: \"the provided expiration year is not valid\" }"
-            .to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("22".parse::<cards::CardExpirationYear>().is_err());
 }
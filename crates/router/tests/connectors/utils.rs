@@ -1,11 +1,17 @@
+#[cfg(feature = "connector_cassettes")]
+use std::{cell::Cell, collections::HashMap, path::Path, sync::Mutex};
 use std::{fmt::Debug, marker::PhantomData, str::FromStr, time::Duration};
 
 use async_trait::async_trait;
 use common_utils::pii::Email;
 use error_stack::Report;
 use masking::Secret;
+#[cfg(feature = "connector_cassettes")]
+use once_cell::sync::Lazy;
 #[cfg(feature = "payouts")]
 use router::core::utils as core_utils;
+#[cfg(feature = "connector_cassettes")]
+use router::services::api::cassette::{CassetteApiClient, CassetteMode};
 use router::{
     configs::settings::Settings,
     core::{errors, errors::ConnectorError, payments},
@@ -13,10 +19,117 @@ use router::{
     routes, services,
     types::{self, api, storage::enums, AccessToken, PaymentAddress, RouterData},
 };
-use test_utils::connector_auth::ConnectorAuthType;
+use test_utils::connector_auth::{ConnectorAuthType, ConnectorAuthentication};
 use tokio::sync::oneshot;
 use wiremock::{Mock, MockServer};
 
+#[cfg(feature = "connector_cassettes")]
+static CASSETTES: Lazy<Mutex<HashMap<String, CassetteApiClient>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "connector_cassettes")]
+thread_local! {
+    static GENERATED_TEST_IDS: Cell<u64> = Cell::new(0);
+}
+
+/// Name of the running test, tests run on a thread named after the test path, which keeps
+/// cassettes unique per test
+#[cfg(feature = "connector_cassettes")]
+fn get_test_name() -> String {
+    std::thread::current()
+        .name()
+        .unwrap_or("unnamed")
+        .replace("::", "/")
+}
+
+#[cfg(feature = "connector_cassettes")]
+fn get_cassette_path(test_name: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/connectors/cassettes")
+        .join(format!("{test_name}.json"))
+}
+
+/// Api client used to reach the connectors. When `CONNECTOR_CASSETTE_MODE` is set to `record` or
+/// `replay`, the interactions of each test are recorded to, or replayed from, a cassette under
+/// `tests/connectors/cassettes` named after the test, so that the suite can run offline. Requires
+/// the `connector_cassettes` feature.
+#[cfg(feature = "connector_cassettes")]
+pub fn get_api_client() -> Box<dyn services::ApiClient> {
+    match CassetteMode::from_env() {
+        Some(mode) => {
+            let test_name = get_test_name();
+            let mut cassettes = CASSETTES.lock().unwrap();
+            let client = cassettes.entry(test_name.clone()).or_insert_with(|| {
+                let path = get_cassette_path(&test_name);
+                match mode {
+                    CassetteMode::Record => {
+                        let conf = Settings::new().unwrap();
+                        let proxy_client = services::ProxyClient::new(
+                            conf.proxy.clone(),
+                            services::proxy_bypass_urls(&conf.locker),
+                        )
+                        .unwrap();
+                        CassetteApiClient::record(path, Box::new(proxy_client))
+                    }
+                    CassetteMode::Replay => CassetteApiClient::replay(path).unwrap(),
+                }
+            });
+            Box::new(client.clone())
+        }
+        None => Box::new(services::MockApiClient),
+    }
+}
+
+#[cfg(not(feature = "connector_cassettes"))]
+pub fn get_api_client() -> Box<dyn services::ApiClient> {
+    Box::new(services::MockApiClient)
+}
+
+/// Connector credentials for the tests. Replayed tests never reach the connector, so they use the
+/// placeholder credentials of `sample_auth.toml` and do not need `CONNECTOR_AUTH_FILE_PATH`.
+#[cfg(feature = "connector_cassettes")]
+pub fn get_connector_authentication() -> ConnectorAuthentication {
+    match CassetteMode::from_env() {
+        Some(CassetteMode::Replay) => ConnectorAuthentication::from_file(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/connectors/sample_auth.toml"),
+        ),
+        _ => ConnectorAuthentication::new(),
+    }
+}
+
+#[cfg(not(feature = "connector_cassettes"))]
+pub fn get_connector_authentication() -> ConnectorAuthentication {
+    ConnectorAuthentication::new()
+}
+
+/// Identifier for the payments, attempts and refunds created by a test. Recorded requests are
+/// matched by their body, so while recording or replaying the identifiers are derived from the
+/// test name and the number of identifiers the test generated so far instead of being random.
+#[cfg(feature = "connector_cassettes")]
+pub fn generate_test_id() -> String {
+    if CassetteMode::from_env().is_none() {
+        return uuid::Uuid::new_v4().to_string();
+    }
+
+    // FNV-1a, which unlike the std hashers is guaranteed to be stable across releases
+    let test_hash = get_test_name()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    let count = GENERATED_TEST_IDS.with(|generated_ids| {
+        let count = generated_ids.get();
+        generated_ids.set(count + 1);
+        count
+    });
+    uuid::Uuid::from_u128((u128::from(test_hash) << 64) | u128::from(count)).to_string()
+}
+
+#[cfg(not(feature = "connector_cassettes"))]
+pub fn generate_test_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
 pub trait Connector {
     fn get_data(&self) -> types::api::ConnectorData;
 
@@ -76,7 +189,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
@@ -100,7 +213,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
@@ -124,7 +237,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
@@ -152,7 +265,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
@@ -389,7 +502,7 @@ pub trait ConnectorActions: Connector {
             payment_data.unwrap_or_else(|| types::RefundsData {
                 payment_amount: 1000,
                 currency: enums::Currency::USD,
-                refund_id: uuid::Uuid::new_v4().to_string(),
+                refund_id: generate_test_id(),
                 connector_transaction_id: "".to_string(),
                 webhook_url: None,
                 refund_amount: 100,
@@ -474,8 +587,8 @@ pub trait ConnectorActions: Connector {
             merchant_id: self.get_name(),
             customer_id: Some(self.get_name()),
             connector: self.get_name(),
-            payment_id: uuid::Uuid::new_v4().to_string(),
-            attempt_id: uuid::Uuid::new_v4().to_string(),
+            payment_id: generate_test_id(),
+            attempt_id: generate_test_id(),
             status: enums::AttemptStatus::default(),
             auth_type: info
                 .clone()
@@ -509,7 +622,7 @@ pub trait ConnectorActions: Connector {
             recurring_mandate_payment_data: None,
 
             preprocessing_id: None,
-            connector_request_reference_id: uuid::Uuid::new_v4().to_string(),
+            connector_request_reference_id: generate_test_id(),
             #[cfg(feature = "payouts")]
             payout_method_data: info.and_then(|p| p.payout_method_data),
             #[cfg(feature = "payouts")]
@@ -565,7 +678,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -605,7 +718,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -646,7 +759,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -687,7 +800,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -774,7 +887,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -802,13 +915,9 @@ async fn call_connector<
 ) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
     let conf = Settings::new().unwrap();
     let tx: oneshot::Sender<()> = oneshot::channel().0;
-    let state = routes::AppState::with_storage(
-        conf,
-        StorageImpl::PostgresqlTest,
-        tx,
-        Box::new(services::MockApiClient),
-    )
-    .await;
+    let state =
+        routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest, tx, get_api_client())
+            .await;
     services::api::execute_connector_processing_step(
         &state,
         integration,
@@ -857,10 +966,10 @@ impl Default for CCardType {
     fn default() -> Self {
         Self(api::Card {
             card_number: cards::CardNumber::from_str("4200000000000000").unwrap(),
-            card_exp_month: Secret::new("10".to_string()),
-            card_exp_year: Secret::new("2025".to_string()),
+            card_exp_month: "10".parse().unwrap(),
+            card_exp_year: "2030".parse().unwrap(),
             card_holder_name: Secret::new("John Doe".to_string()),
-            card_cvc: Secret::new("999".to_string()),
+            card_cvc: Some("999".parse().unwrap()),
             card_issuer: None,
             card_network: None,
             card_type: None,
@@ -965,7 +1074,7 @@ impl Default for PaymentRefundType {
         let data = types::RefundsData {
             payment_amount: 100,
             currency: enums::Currency::USD,
-            refund_id: uuid::Uuid::new_v4().to_string(),
+            refund_id: generate_test_id(),
             connector_transaction_id: String::new(),
             refund_amount: 100,
             webhook_url: None,
@@ -69,10 +69,11 @@ impl WorldlineTest {
             currency: enums::Currency::USD,
             payment_method_data: types::api::PaymentMethodData::Card(types::api::Card {
                 card_number: cards::CardNumber::from_str(card_number).unwrap(),
-                card_exp_month: Secret::new(card_exp_month.to_string()),
-                card_exp_year: Secret::new(card_exp_year.to_string()),
+                card_exp_month: card_exp_month.parse().unwrap(),
+                card_exp_year: card_exp_year.parse().unwrap(),
                 card_holder_name: Secret::new("John Doe".to_string()),
-                card_cvc: Secret::new(card_cvc.to_string()),
+                // an empty CVC stands for a card without one
+                card_cvc: (!card_cvc.is_empty()).then(|| card_cvc.parse().unwrap()),
                 card_issuer: None,
                 card_network: None,
                 card_type: None,
@@ -111,7 +112,7 @@ async fn should_requires_manual_authorization() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "5424 1802 7979 1732",
         "10",
-        "25",
+        "30",
         "123",
         enums::CaptureMethod::Manual,
     );
@@ -126,7 +127,7 @@ async fn should_auto_authorize_and_request_capture() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012000033330026",
         "10",
-        "2025",
+        "2030",
         "123",
         enums::CaptureMethod::Automatic,
     );
@@ -142,7 +143,7 @@ async fn should_throw_not_implemented_for_unsupported_issuer() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "630495060000000000",
         "10",
-        "25",
+        "30",
         "123",
         enums::CaptureMethod::Automatic,
     );
@@ -163,7 +164,7 @@ async fn should_throw_missing_required_field_for_country() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012 0000 3333 0026",
         "10",
-        "2025",
+        "2030",
         "123",
         enums::CaptureMethod::Automatic,
     );
@@ -191,7 +192,7 @@ async fn should_fail_payment_for_invalid_cvc() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012000033330026",
         "10",
-        "25",
+        "30",
         "",
         enums::CaptureMethod::Automatic,
     );
@@ -211,7 +212,7 @@ async fn should_sync_manual_auth_payment() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012 0000 3333 0026",
         "10",
-        "2025",
+        "2030",
         "123",
         enums::CaptureMethod::Manual,
     );
@@ -244,7 +245,7 @@ async fn should_sync_auto_auth_payment() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012000033330026",
         "10",
-        "25",
+        "30",
         "123",
         enums::CaptureMethod::Automatic,
     );
@@ -277,7 +278,7 @@ async fn should_capture_authorized_payment() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012 0000 3333 0026",
         "10",
-        "2025",
+        "2030",
         "123",
         enums::CaptureMethod::Manual,
     );
@@ -316,7 +317,7 @@ async fn should_cancel_unauthorized_payment() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012 0000 3333 0026",
         "10",
-        "25",
+        "30",
         "123",
         enums::CaptureMethod::Manual,
     );
@@ -340,7 +341,7 @@ async fn should_cancel_uncaptured_payment() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012000033330026",
         "10",
-        "2025",
+        "2030",
         "123",
         enums::CaptureMethod::Automatic,
     );
@@ -376,7 +377,7 @@ async fn should_fail_refund_with_invalid_payment_status() {
     let authorize_data = WorldlineTest::get_payment_authorize_data(
         "4012 0000 3333 0026",
         "10",
-        "25",
+        "30",
         "123",
         enums::CaptureMethod::Manual,
     );
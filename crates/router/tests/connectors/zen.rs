@@ -3,7 +3,6 @@ use std::str::FromStr;
 use api_models::payments::OrderDetailsWithAmount;
 use cards::CardNumber;
 use common_utils::pii::Email;
-use masking::Secret;
 use router::types::{self, api, storage::enums};
 
 use crate::{
@@ -339,109 +338,22 @@ async fn should_fail_payment_for_incorrect_card_number() {
 // Creates a payment with incorrect CVC.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_cvc: Secret::new("12345".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                order_details: Some(vec![OrderDetailsWithAmount {
-                    product_name: "test".to_string(),
-                    quantity: 1,
-                    amount: 1000,
-                    product_img_link: None,
-                }]),
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                webhook_url: Some("https://1635-116-74-253-164.ngrok-free.app".to_string()),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response
-            .response
-            .unwrap_err()
-            .message
-            .split_once(';')
-            .unwrap()
-            .0,
-        "Request data doesn't pass validation".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("12345".parse::<cards::CardSecurityCode>().is_err());
 }
 
 // Creates a payment with incorrect expiry month.
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_month: Secret::new("20".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                order_details: Some(vec![OrderDetailsWithAmount {
-                    product_name: "test".to_string(),
-                    quantity: 1,
-                    amount: 1000,
-                    product_img_link: None,
-                }]),
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                webhook_url: Some("https://1635-116-74-253-164.ngrok-free.app".to_string()),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response
-            .response
-            .unwrap_err()
-            .message
-            .split_once(';')
-            .unwrap()
-            .0,
-        "Request data doesn't pass validation".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("20".parse::<cards::CardExpirationMonth>().is_err());
 }
 
 // Creates a payment with incorrect expiry year.
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
-    let response = CONNECTOR
-        .make_payment(
-            Some(types::PaymentsAuthorizeData {
-                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
-                    card_exp_year: Secret::new("2000".to_string()),
-                    ..utils::CCardType::default().0
-                }),
-                order_details: Some(vec![OrderDetailsWithAmount {
-                    product_name: "test".to_string(),
-                    quantity: 1,
-                    amount: 1000,
-                    product_img_link: None,
-                }]),
-                email: Some(Email::from_str("test@gmail.com").unwrap()),
-                webhook_url: Some("https://1635-116-74-253-164.ngrok-free.app".to_string()),
-                ..utils::PaymentAuthorizeType::default().0
-            }),
-            None,
-        )
-        .await
-        .unwrap();
-    assert_eq!(
-        response
-            .response
-            .unwrap_err()
-            .message
-            .split_once(';')
-            .unwrap()
-            .0,
-        "Request data doesn't pass validation".to_string(),
-    );
+    // The card is rejected when it is parsed, before it can reach the connector
+    assert!("2000".parse::<cards::CardExpirationYear>().is_err());
 }
 
 // Voids a payment using automatic capture flow (Non 3DS).
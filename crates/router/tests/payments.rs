@@ -318,10 +318,10 @@ async fn payments_create_core() {
         authentication_type: Some(api_enums::AuthenticationType::NoThreeDs),
         payment_method_data: Some(api::PaymentMethodData::Card(api::Card {
             card_number: "4242424242424242".to_string().try_into().unwrap(),
-            card_exp_month: "10".to_string().into(),
-            card_exp_year: "35".to_string().into(),
+            card_exp_month: "10".parse().unwrap(),
+            card_exp_year: "35".parse().unwrap(),
             card_holder_name: "Arun Raj".to_string().into(),
-            card_cvc: "123".to_string().into(),
+            card_cvc: Some("123".parse().unwrap()),
             card_issuer: None,
             card_network: None,
             card_type: None,
@@ -494,10 +494,10 @@ async fn payments_create_core_adyen_no_redirect() {
         authentication_type: Some(api_enums::AuthenticationType::NoThreeDs),
         payment_method_data: Some(api::PaymentMethodData::Card(api::Card {
             card_number: "5555 3412 4444 1115".to_string().try_into().unwrap(),
-            card_exp_month: "03".to_string().into(),
-            card_exp_year: "2030".to_string().into(),
+            card_exp_month: "03".parse().unwrap(),
+            card_exp_year: "2030".parse().unwrap(),
             card_holder_name: "JohnDoe".to_string().into(),
-            card_cvc: "737".to_string().into(),
+            card_cvc: Some("737".parse().unwrap()),
             card_issuer: None,
             card_network: None,
             card_type: None,
@@ -43,6 +43,18 @@ pub enum AnalyticsFlow {
     GetRefundFilters,
     GetRefundsMetrics,
     GetPaymentMetrics,
+    GetDisputeFilters,
+    GetDisputeMetrics,
+    GetConnectorPerformanceFilters,
+    GetConnectorPerformanceMetrics,
+    CreateReport,
+    ListReports,
+    RetrieveReport,
+    DeleteReport,
+    ListReportExports,
+    ExportPayments,
+    ExportRefunds,
+    DownloadReportExport,
 }
 
 impl FlowMetric for AnalyticsFlow {}
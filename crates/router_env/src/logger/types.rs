@@ -62,6 +62,8 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Merchant key rotate flow.
+    MerchantKeyRotate,
     /// Merchant Connectors create flow.
     MerchantConnectorsCreate,
     /// Merchant Connectors retrieve flow.
@@ -114,6 +116,10 @@ pub enum Flow {
     PaymentMethodsUpdate,
     /// Payment methods delete flow.
     PaymentMethodsDelete,
+    /// Customer payment methods update flow.
+    CustomerPaymentMethodsUpdate,
+    /// Customer default payment method set flow.
+    CustomerPaymentMethodsSetDefault,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -167,6 +173,8 @@ pub enum Flow {
     RoutingCreateConfig,
     /// Routing link config
     RoutingLinkConfig,
+    /// Routing run tests
+    RoutingRunTests,
     /// Routing link config
     RoutingUnlinkConfig,
     /// Routing retrieve config
@@ -245,6 +253,22 @@ pub enum Flow {
     GsmRuleDelete,
     /// User connect account
     UserConnectAccount,
+    /// Verify the email address of a user
+    UserVerifyEmail,
+    /// Subscription plan create flow.
+    SubscriptionPlanCreate,
+    /// Subscription plan retrieve flow.
+    SubscriptionPlanRetrieve,
+    /// Subscription plan list flow.
+    SubscriptionPlanList,
+    /// Subscription create flow.
+    SubscriptionCreate,
+    /// Subscription retrieve flow.
+    SubscriptionRetrieve,
+    /// Subscription update flow.
+    SubscriptionUpdate,
+    /// Subscription cancel flow.
+    SubscriptionCancel,
 }
 
 ///
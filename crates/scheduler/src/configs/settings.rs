@@ -1,9 +1,9 @@
 #[cfg(feature = "kms")]
-use external_services::kms;
+use external_services::secrets_management;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 #[cfg(feature = "kms")]
-pub type Password = kms::KmsValue;
+pub type Password = secrets_management::KmsValue;
 #[cfg(not(feature = "kms"))]
 pub type Password = masking::Secret<String>;
 
@@ -53,3 +53,25 @@ impl Default for PaymentMethodsPTMapping {
         }
     }
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionDunningPTMapping {
+    pub default_mapping: RetryMapping,
+    pub custom_merchant_mapping: HashMap<String, RetryMapping>,
+    pub max_retries_count: i32,
+}
+
+impl Default for SubscriptionDunningPTMapping {
+    fn default() -> Self {
+        Self {
+            custom_merchant_mapping: HashMap::new(),
+            default_mapping: RetryMapping {
+                start_after: 86400,
+                frequency: vec![172800],
+                count: vec![2],
+            },
+            max_retries_count: 3,
+        }
+    }
+}
@@ -329,6 +329,30 @@ pub fn get_pm_schedule_time(
     }
 }
 
+pub fn get_subscription_dunning_schedule_time(
+    mapping: process_data::SubscriptionDunningPTMapping,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<i32> {
+    // A merchant specific mapping defines its own dunning limit through its retry counts, the
+    // global `max_retries_count` only limits the default mapping
+    let mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
+        Some(map) => map.clone(),
+        None if retry_count >= mapping.max_retries_count => return None,
+        None => mapping.default_mapping,
+    };
+
+    // The first retry happens `start_after` the failed billing cycle
+    if retry_count == 0 {
+        Some(mapping.start_after)
+    } else {
+        get_delay(
+            retry_count,
+            mapping.count.iter().zip(mapping.frequency.iter()),
+        )
+    }
+}
+
 /// Get the delay based on the retry count
 fn get_delay<'a>(
     retry_count: i32,
@@ -264,6 +264,13 @@ pub enum ApiClientError {
     GatewayTimeoutReceived,
     #[error("Server responded with unexpected response")]
     UnexpectedServerResponse,
+
+    #[error("Failed to read the connector cassette")]
+    CassetteReadFailed,
+    #[error("Failed to write the connector cassette")]
+    CassetteWriteFailed,
+    #[error("No recorded interaction matches the request")]
+    NoRecordedInteraction,
 }
 
 impl ApiClientError {
@@ -7,6 +7,7 @@ use data_models::{
     },
 };
 use diesel_models::enums as storage_enums;
+use error_stack::{IntoReport, ResultExt};
 
 use super::MockDb;
 use crate::DataModelExt;
@@ -42,11 +43,36 @@ impl PaymentAttemptInterface for MockDb {
         _payment_method: Option<Vec<PaymentMethod>>,
         _payment_method_type: Option<Vec<PaymentMethodType>>,
         _authentication_type: Option<Vec<AuthenticationType>>,
+        _card_fingerprint: Option<String>,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<i64, StorageError> {
         Err(StorageError::MockDbError)?
     }
 
+    async fn get_payment_attempt_count_by_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        card_fingerprint: &str,
+        created_after: time::PrimitiveDateTime,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<i64, StorageError> {
+        let count = self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.card_fingerprint.as_deref() == Some(card_fingerprint)
+                    && payment_attempt.created_at >= created_after
+            })
+            .count();
+
+        i64::try_from(count)
+            .into_report()
+            .change_context(StorageError::MockDbError)
+    }
+
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
         _attempt_id: &str,
@@ -87,6 +113,40 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn list_payment_attempts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        starting_after_id: Option<i32>,
+        ending_before_id: Option<i32>,
+        limit: i64,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+        let limit = usize::try_from(limit).unwrap_or_default();
+        let mut merchant_attempts = payment_attempts
+            .iter()
+            .filter(|attempt| attempt.merchant_id == merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        merchant_attempts.sort_by(|a, b| b.id.cmp(&a.id));
+
+        Ok(match ending_before_id {
+            Some(ending_before_id) => {
+                let newer_attempts = merchant_attempts
+                    .into_iter()
+                    .filter(|attempt| attempt.id > ending_before_id)
+                    .collect::<Vec<_>>();
+                let skip = newer_attempts.len().saturating_sub(limit);
+                newer_attempts.into_iter().skip(skip).collect()
+            }
+            None => merchant_attempts
+                .into_iter()
+                .filter(|attempt| starting_after_id.map_or(true, |id| attempt.id < id))
+                .take(limit)
+                .collect(),
+        })
+    }
+
     #[allow(clippy::panic)]
     async fn insert_payment_attempt(
         &self,
@@ -144,6 +204,8 @@ impl PaymentAttemptInterface for MockDb {
             authentication_data: payment_attempt.authentication_data,
             encoded_data: payment_attempt.encoded_data,
             merchant_connector_id: payment_attempt.merchant_connector_id,
+            connector_latency: payment_attempt.connector_latency,
+            card_fingerprint: payment_attempt.card_fingerprint,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -26,6 +26,7 @@ use diesel_models::{
 use error_stack::{IntoReport, ResultExt};
 use redis_interface::HsetnxReply;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use crate::{
     diesel_error_to_data_error,
@@ -237,6 +238,34 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             })
     }
 
+    async fn list_payment_attempts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        starting_after_id: Option<i32>,
+        ending_before_id: Option<i32>,
+        limit: i64,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            starting_after_id,
+            ending_before_id,
+            limit,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|a| {
+            a.into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
+    }
+
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
         attempt_id: &str,
@@ -262,6 +291,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         payment_method: Option<Vec<PaymentMethod>>,
         payment_method_type: Option<Vec<PaymentMethodType>>,
         authentication_type: Option<Vec<AuthenticationType>>,
+        card_fingerprint: Option<String>,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<i64, errors::StorageError> {
         let conn = self
@@ -285,6 +315,29 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             payment_method,
             payment_method_type,
             authentication_type,
+            card_fingerprint,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payment_attempt_count_by_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        card_fingerprint: &str,
+        created_after: PrimitiveDateTime,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::get_count_by_merchant_id_card_fingerprint(
+            &conn,
+            merchant_id,
+            card_fingerprint,
+            created_after,
         )
         .await
         .map_err(|er| {
@@ -364,6 +417,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     authentication_data: payment_attempt.authentication_data.clone(),
                     encoded_data: payment_attempt.encoded_data.clone(),
                     merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                    connector_latency: payment_attempt.connector_latency,
+                    card_fingerprint: payment_attempt.card_fingerprint.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -831,6 +886,27 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    async fn list_payment_attempts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        starting_after_id: Option<i32>,
+        ending_before_id: Option<i32>,
+        limit: i64,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError> {
+        // Attempts cannot be paginated in the KV store, so they are always listed from the
+        // database, which may lag behind for merchants using the KV storage scheme
+        self.router_store
+            .list_payment_attempts_by_merchant_id(
+                merchant_id,
+                starting_after_id,
+                ending_before_id,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn get_filters_for_payments(
         &self,
         pi: &[PaymentIntent],
@@ -850,6 +926,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         payment_method: Option<Vec<PaymentMethod>>,
         payment_method_type: Option<Vec<PaymentMethodType>>,
         authentication_type: Option<Vec<AuthenticationType>>,
+        card_fingerprint: Option<String>,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<i64, errors::StorageError> {
         self.router_store
@@ -860,6 +937,24 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                 payment_method,
                 payment_method_type,
                 authentication_type,
+                card_fingerprint,
+                storage_scheme,
+            )
+            .await
+    }
+
+    async fn get_payment_attempt_count_by_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        card_fingerprint: &str,
+        created_after: PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.router_store
+            .get_payment_attempt_count_by_card_fingerprint(
+                merchant_id,
+                card_fingerprint,
+                created_after,
                 storage_scheme,
             )
             .await
@@ -966,6 +1061,8 @@ impl DataModelExt for PaymentAttempt {
             authentication_data: self.authentication_data,
             encoded_data: self.encoded_data,
             merchant_connector_id: self.merchant_connector_id,
+            connector_latency: self.connector_latency,
+            card_fingerprint: self.card_fingerprint,
         }
     }
 
@@ -1018,6 +1115,8 @@ impl DataModelExt for PaymentAttempt {
             authentication_data: storage_model.authentication_data,
             encoded_data: storage_model.encoded_data,
             merchant_connector_id: storage_model.merchant_connector_id,
+            connector_latency: storage_model.connector_latency,
+            card_fingerprint: storage_model.card_fingerprint,
         }
     }
 }
@@ -1070,6 +1169,8 @@ impl DataModelExt for PaymentAttemptNew {
             authentication_data: self.authentication_data,
             encoded_data: self.encoded_data,
             merchant_connector_id: self.merchant_connector_id,
+            connector_latency: self.connector_latency,
+            card_fingerprint: self.card_fingerprint,
         }
     }
 
@@ -1120,6 +1221,8 @@ impl DataModelExt for PaymentAttemptNew {
             authentication_data: storage_model.authentication_data,
             encoded_data: storage_model.encoded_data,
             merchant_connector_id: storage_model.merchant_connector_id,
+            connector_latency: storage_model.connector_latency,
+            card_fingerprint: storage_model.card_fingerprint,
         }
     }
 }
@@ -1207,6 +1310,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id: connector_id,
+                card_fingerprint,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
                 currency,
@@ -1226,6 +1330,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id: connector_id,
+                card_fingerprint,
             },
             Self::VoidUpdate {
                 status,
@@ -1255,6 +1360,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 authentication_data,
                 encoded_data,
+                connector_latency,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1274,6 +1380,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 authentication_data,
                 encoded_data,
+                connector_latency,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1285,6 +1392,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             } => DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
                 connector,
@@ -1295,6 +1403,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             },
             Self::StatusUpdate { status, updated_by } => {
                 DieselPaymentAttemptUpdate::StatusUpdate { status, updated_by }
@@ -1307,6 +1416,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 amount_capturable,
                 updated_by,
+                connector_latency,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -1315,6 +1425,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 amount_capturable,
                 updated_by,
+                connector_latency,
             },
             Self::MultipleCaptureCountUpdate {
                 multiple_capture_count,
@@ -1456,6 +1567,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id: connector_id,
+                card_fingerprint,
             } => Self::ConfirmUpdate {
                 amount,
                 currency,
@@ -1475,6 +1587,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id: connector_id,
+                card_fingerprint,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -1504,6 +1617,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 authentication_data,
                 encoded_data,
+                connector_latency,
             } => Self::ResponseUpdate {
                 status,
                 connector,
@@ -1523,6 +1637,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 authentication_data,
                 encoded_data,
+                connector_latency,
             },
             DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -1534,6 +1649,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             } => Self::UnresolvedResponseUpdate {
                 status,
                 connector,
@@ -1544,6 +1660,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 updated_by,
+                connector_latency,
             },
             DieselPaymentAttemptUpdate::StatusUpdate { status, updated_by } => {
                 Self::StatusUpdate { status, updated_by }
@@ -1556,6 +1673,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 amount_capturable,
                 updated_by,
+                connector_latency,
             } => Self::ErrorUpdate {
                 connector,
                 status,
@@ -1564,6 +1682,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 amount_capturable,
                 updated_by,
+                connector_latency,
             },
             DieselPaymentAttemptUpdate::MultipleCaptureCountUpdate {
                 multiple_capture_count,
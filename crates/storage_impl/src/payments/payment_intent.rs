@@ -623,6 +623,13 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     None => query,
                 };
 
+                query = match &params.card_fingerprint {
+                    Some(card_fingerprint) => {
+                        query.filter(pa_dsl::card_fingerprint.eq(card_fingerprint.clone()))
+                    }
+                    None => query,
+                };
+
                 query
             }
         };
@@ -1020,6 +1027,13 @@ impl DataModelExt for PaymentIntentUpdate {
                 surcharge_applicable: Some(surcharge_applicable),
                 updated_by,
             },
+            Self::PaymentLinkUpdate {
+                payment_link_id,
+                updated_by,
+            } => DieselPaymentIntentUpdate::PaymentLinkUpdate {
+                payment_link_id,
+                updated_by,
+            },
         }
     }
 
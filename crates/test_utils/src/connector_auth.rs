@@ -84,6 +84,14 @@ impl ConnectorAuthentication {
         // before running tests in shell
         let path = env::var("CONNECTOR_AUTH_FILE_PATH")
             .expect("Connector authentication file path not set");
+        Self::from_file(path)
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the file at `path` cannot be read or is not a valid authentication config
+    #[allow(clippy::expect_used)]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Self {
         toml::from_str(
             &std::fs::read_to_string(path).expect("connector authentication config file not found"),
         )
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cycle_succeeded';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cycle_failed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_past_due';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_unpaid';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cancelled';
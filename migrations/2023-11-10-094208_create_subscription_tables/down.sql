@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE subscription;
+DROP TABLE subscription_plan;
+DROP TYPE "SubscriptionStatus";
+DROP TYPE "SubscriptionInterval";
@@ -0,0 +1,52 @@
+-- Your SQL goes here
+
+CREATE TYPE "SubscriptionInterval" AS ENUM ('day', 'week', 'month', 'year');
+
+CREATE TYPE "SubscriptionStatus" AS ENUM (
+    'incomplete',
+    'trialing',
+    'active',
+    'past_due',
+    'unpaid',
+    'cancelled'
+);
+
+CREATE TABLE subscription_plan (
+    plan_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    description VARCHAR(255),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval "SubscriptionInterval" NOT NULL,
+    billing_interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_period_days INTEGER,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX subscription_plan_merchant_id_index ON subscription_plan (merchant_id);
+
+CREATE TABLE subscription (
+    subscription_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    plan_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status "SubscriptionStatus" NOT NULL,
+    current_period_start TIMESTAMP NOT NULL,
+    current_period_end TIMESTAMP NOT NULL,
+    trial_end TIMESTAMP,
+    cancel_at_period_end BOOLEAN NOT NULL DEFAULT FALSE,
+    cancelled_at TIMESTAMP,
+    proration_amount BIGINT NOT NULL DEFAULT 0,
+    latest_payment_id VARCHAR(64),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
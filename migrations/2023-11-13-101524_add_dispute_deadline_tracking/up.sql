@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE dispute ADD COLUMN IF NOT EXISTS is_deadline_missed BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_evidence_due';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_deadline_missed';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_link DROP COLUMN IF EXISTS is_reusable;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS payment_link_config;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_link ADD COLUMN IF NOT EXISTS is_reusable BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS payment_link_config JSONB;
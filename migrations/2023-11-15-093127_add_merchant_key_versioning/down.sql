@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_key_store
+DROP COLUMN IF EXISTS key_version,
+DROP COLUMN IF EXISTS previous_key,
+DROP COLUMN IF EXISTS previous_key_version;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE merchant_key_store
+ADD COLUMN IF NOT EXISTS key_version INTEGER NOT NULL DEFAULT 1,
+ADD COLUMN IF NOT EXISTS previous_key BYTEA,
+ADD COLUMN IF NOT EXISTS previous_key_version INTEGER;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE file_metadata
+ADD COLUMN IF NOT EXISTS checksum VARCHAR(64);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Time taken by the connector to respond to the authorization request, in milliseconds
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS connector_latency BIGINT;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE analytics_report_export;
+DROP TABLE analytics_report;
+DROP TYPE "ReportFrequency";
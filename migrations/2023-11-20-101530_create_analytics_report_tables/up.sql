@@ -0,0 +1,33 @@
+-- Your SQL goes here
+
+CREATE TYPE "ReportFrequency" AS ENUM ('daily', 'weekly', 'monthly');
+
+CREATE TABLE analytics_report (
+    report_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    definition JSONB NOT NULL,
+    frequency "ReportFrequency" NOT NULL,
+    lookback_days INTEGER,
+    email_recipients TEXT[],
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    next_run_at TIMESTAMP NOT NULL,
+    last_run_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX analytics_report_merchant_id_index ON analytics_report (merchant_id);
+
+CREATE TABLE analytics_report_export (
+    export_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    report_id VARCHAR(64),
+    export_type VARCHAR(32) NOT NULL,
+    file_id VARCHAR(64) NOT NULL,
+    start_time TIMESTAMP NOT NULL,
+    end_time TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX analytics_report_export_merchant_id_report_id_index ON analytics_report_export (merchant_id, report_id);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_attempt_merchant_id_card_fingerprint_index;
+
+DROP INDEX IF EXISTS payment_methods_customer_id_merchant_id_card_fingerprint_index;
+
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS card_fingerprint;
+
+ALTER TABLE payment_methods DROP COLUMN IF EXISTS card_fingerprint;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods ADD COLUMN IF NOT EXISTS network_token_reference VARCHAR(128) DEFAULT NULL;
+
+ALTER TABLE payment_methods ADD COLUMN IF NOT EXISTS network_token_data BYTEA DEFAULT NULL;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods ADD COLUMN IF NOT EXISTS locker_id VARCHAR(64) DEFAULT NULL;
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_updated';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_account_closed';
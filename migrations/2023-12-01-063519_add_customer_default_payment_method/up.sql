@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE customers ADD COLUMN IF NOT EXISTS default_payment_method_id VARCHAR(64) DEFAULT NULL;
+
+ALTER TABLE payment_methods ADD COLUMN IF NOT EXISTS last_used_at TIMESTAMP DEFAULT NULL;